@@ -57,7 +57,7 @@ impl Area2DVirtual for Player {
         self.base.hide();
     }
 
-    fn process(&mut self, delta: f64) {
+    fn process(&mut self, delta: real) {
         let mut animated_sprite = self
             .base
             .get_node_as::<AnimatedSprite2D>("AnimatedSprite2D");
@@ -100,7 +100,7 @@ impl Area2DVirtual for Player {
             animated_sprite.stop();
         }
 
-        let change = velocity * real::from_f64(delta);
+        let change = velocity * delta;
         let position = self.base.get_global_position() + change;
         let position = Vector2::new(
             position.x.clamp(0.0, self.screen_size.x),
@@ -22,6 +22,17 @@ use std::process::{Command, Output};
 const JSON_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/gen/extension_api.json");
 
 pub fn load_gdextension_json(watch: &mut StopWatch) -> String {
+    // A JSON supplied via GODOT4_EXTENSION_API_JSON is used as-is, so that no Godot binary is needed.
+    if let Some(custom_json_path) = locate_extension_api_json() {
+        rerun_on_changed(&custom_json_path);
+
+        let result = fs::read_to_string(&custom_json_path)
+            .unwrap_or_else(|_| panic!("failed to open file {}", custom_json_path.display()));
+
+        watch.record("read_api_json");
+        return result;
+    }
+
     let json_path = Path::new(JSON_PATH);
     rerun_on_changed(json_path);
 
@@ -54,7 +65,12 @@ pub fn write_gdextension_headers(
     // None=(unknown, no engine), Some=(version of Godot). Later verified by header itself.
     let is_engine_4_0;
     if is_h_provided {
-        is_engine_4_0 = None;
+        // Header comes from outside (e.g. the source tree of an engine fork). If an engine binary is available as well,
+        // use it to cross-check the header version; otherwise trust the header.
+        is_engine_4_0 = try_locate_godot_binary().map(|godot_bin| {
+            let version = read_godot_version(&godot_bin);
+            version.major == 4 && version.minor == 0
+        });
     } else {
         // No external C header file: Godot binary is present, we use it to dump C header
         let godot_bin = locate_godot_binary();
@@ -252,19 +268,105 @@ fn polyfill_legacy_header(c: &mut String) {
     );
 }
 
+/// Returns the path to a `gdextension_interface.h` supplied by the user, if any.
+///
+/// This is used for engine forks that add or modify interface functions: instead of dumping the header from a Godot binary,
+/// the header from the fork's source tree (`core/extension/gdextension_interface.h`) is passed through bindgen directly.
+pub(crate) fn locate_gdextension_header() -> Option<PathBuf> {
+    println!("cargo:rerun-if-env-changed=GODOT4_GDEXTENSION_H");
+
+    let string = std::env::var("GODOT4_GDEXTENSION_H").ok()?;
+    let path = PathBuf::from(string);
+    assert!(
+        path.is_file(),
+        "GODOT4_GDEXTENSION_H points to '{}', which is not a file",
+        path.display()
+    );
+
+    println!(
+        "Found GODOT4_GDEXTENSION_H with path to C header: '{}'",
+        path.display()
+    );
+    Some(path)
+}
+
+/// Returns the path to an `extension_api.json` supplied by the user, if any.
+///
+/// Together with [`locate_gdextension_header()`], this allows building against an engine fork without a runnable Godot binary.
+pub(crate) fn locate_extension_api_json() -> Option<PathBuf> {
+    println!("cargo:rerun-if-env-changed=GODOT4_EXTENSION_API_JSON");
+
+    let string = std::env::var("GODOT4_EXTENSION_API_JSON").ok()?;
+    let path = PathBuf::from(string);
+    assert!(
+        path.is_file(),
+        "GODOT4_EXTENSION_API_JSON points to '{}', which is not a file",
+        path.display()
+    );
+
+    println!(
+        "Found GODOT4_EXTENSION_API_JSON with path to API JSON: '{}'",
+        path.display()
+    );
+    Some(path)
+}
+
+/// Reads the Godot version from the `header` section of an `extension_api.json`.
+pub(crate) fn read_json_godot_version(json_path: &Path) -> GodotVersion {
+    let json = fs::read_to_string(json_path)
+        .unwrap_or_else(|_| panic!("failed to open file {}", json_path.display()));
+
+    // Same format as `godot --version`, prefixed with the engine name: "Godot Engine v4.1.1.stable.official".
+    let full_name = Regex::new(r#""version_full_name"\s*:\s*"Godot Engine v([^"]+)""#)
+        .expect("regex for version_full_name")
+        .captures(&json)
+        .and_then(|caps| caps.get(1))
+        .unwrap_or_else(|| panic!("no version_full_name in {}", json_path.display()))
+        .as_str();
+
+    parse_godot_version(full_name).unwrap_or_else(|e| {
+        panic!(
+            "failed to parse Godot version '{full_name}' from {}: {e}",
+            json_path.display()
+        )
+    })
+}
+
+/// Copies a user-supplied C header to the location where the build expects it.
+pub(crate) fn copy_header_file(in_h_path: &Path, out_h_path: &Path) {
+    rerun_on_changed(in_h_path);
+
+    let cwd = out_h_path.parent().unwrap();
+    fs::create_dir_all(cwd).unwrap_or_else(|_| panic!("create directory '{}'", cwd.display()));
+
+    fs::copy(in_h_path, out_h_path).unwrap_or_else(|e| {
+        panic!(
+            "failed to copy C header '{}' to '{}': {e}",
+            in_h_path.display(),
+            out_h_path.display()
+        )
+    });
+}
+
 pub(crate) fn locate_godot_binary() -> PathBuf {
+    try_locate_godot_binary().unwrap_or_else(|| {
+        panic!(
+            "gdext with `custom-godot` feature requires 'godot4' executable or a GODOT4_BIN \
+                 environment variable (with the path to the executable)."
+        )
+    })
+}
+
+fn try_locate_godot_binary() -> Option<PathBuf> {
     if let Ok(string) = std::env::var("GODOT4_BIN") {
         println!("Found GODOT4_BIN with path to executable: '{string}'");
         println!("cargo:rerun-if-env-changed=GODOT4_BIN");
-        PathBuf::from(string)
+        Some(PathBuf::from(string))
     } else if let Ok(path) = which::which("godot4") {
         println!("Found 'godot4' executable in PATH: {}", path.display());
-        path
+        Some(path)
     } else {
-        panic!(
-            "gdext with `custom-godot` feature requires 'godot4' executable or a GODOT4_BIN \
-                 environment variable (with the path to the executable)."
-        )
+        None
     }
 }
 
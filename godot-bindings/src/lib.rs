@@ -58,16 +58,31 @@ mod custom {
     }
 
     pub fn write_gdextension_headers(h_path: &Path, rs_path: &Path, watch: &mut StopWatch) {
-        godot_exe::write_gdextension_headers(h_path, rs_path, false, watch);
+        // A header supplied via GODOT4_GDEXTENSION_H takes precedence over the one dumped by the Godot binary.
+        if let Some(custom_h_path) = godot_exe::locate_gdextension_header() {
+            godot_exe::copy_header_file(&custom_h_path, h_path);
+            watch.record("copy_header_h");
+
+            write_gdextension_headers_from_c(h_path, rs_path, watch);
+        } else {
+            godot_exe::write_gdextension_headers(h_path, rs_path, false, watch);
+        }
     }
 
-    #[cfg(feature = "custom-godot-extheader")]
+    /// Generates the Rust bindings from the C header already present at `h_path`, without dumping it from a Godot binary.
+    ///
+    /// Used for `GODOT4_GDEXTENSION_H`, and by the generator of prebuilt artifacts. If a Godot binary is found, the header is
+    /// checked against its version.
     pub fn write_gdextension_headers_from_c(h_path: &Path, rs_path: &Path, watch: &mut StopWatch) {
         godot_exe::write_gdextension_headers(h_path, rs_path, true, watch);
     }
 
     pub(crate) fn get_godot_version() -> GodotVersion {
-        godot_exe::read_godot_version(&godot_exe::locate_godot_binary())
+        // Without a binary, the version is taken from the supplied API JSON.
+        match godot_exe::locate_extension_api_json() {
+            Some(json_path) => godot_exe::read_json_godot_version(&json_path),
+            None => godot_exe::read_godot_version(&godot_exe::locate_godot_binary()),
+        }
     }
 }
 
@@ -158,3 +173,29 @@ pub fn emit_godot_version_cfg() {
         println!(r#"cargo:rustc-cfg=gdextension_exact_api="{major}.{minor}""#);
     }
 }
+
+/// When building for the web, warns about missing compiler/linker flags that Godot's Emscripten export requires.
+///
+/// Build scripts of dependencies cannot pass link arguments to the final `cdylib`, so these flags must be configured by the user.
+pub fn emit_wasm_hints() {
+    if std::env::var("CARGO_CFG_TARGET_OS").as_deref() != Ok("emscripten") {
+        return;
+    }
+
+    // Flags are separated by 0x1f; see https://doc.rust-lang.org/cargo/reference/environment-variables.html.
+    let rustflags = std::env::var("CARGO_ENCODED_RUSTFLAGS").unwrap_or_default();
+    let required = [
+        ("SIDE_MODULE", "-C link-args=-sSIDE_MODULE=2"),
+        ("-pthread", "-C link-args=-pthread"),
+        (
+            "+atomics",
+            "-C target-feature=+atomics,+bulk-memory,+mutable-globals",
+        ),
+    ];
+
+    for (needle, flag) in required {
+        if !rustflags.contains(needle) {
+            println!("cargo:warning=Godot web export requires rustflag `{flag}`; see `experimental-wasm` in godot crate docs.");
+        }
+    }
+}
@@ -4,6 +4,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::fmt;
 use std::fs::File;
 use std::io::BufWriter;
 use std::path::Path;
@@ -40,6 +41,29 @@ impl StopWatch {
         let file = File::create(to_file).expect("failed to create stats file");
         let mut writer = BufWriter::new(file);
 
+        write!(&mut writer, "{self}").expect("failed to write to stats file");
+    }
+
+    fn write_metric(
+        f: &mut fmt::Formatter<'_>,
+        metric: &Metric,
+        lwidth: usize,
+        rwidth: usize,
+    ) -> fmt::Result {
+        writeln!(
+            f,
+            "{: >l$}: {: >r$} ms",
+            metric.name,
+            metric.duration.as_millis(),
+            l = lwidth,
+            r = rwidth,
+        )
+    }
+}
+
+/// One line per recorded metric, followed by the total.
+impl fmt::Display for StopWatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // Accumulate total
         let mut total = Duration::ZERO;
         for metric in self.metrics.iter() {
@@ -51,25 +75,11 @@ impl StopWatch {
             duration: total,
         };
 
-        // Write to file
         for metric in self.metrics.iter() {
-            Self::write_metric(&mut writer, metric, self.lwidth, rwidth);
+            Self::write_metric(f, metric, self.lwidth, rwidth)?;
         }
-        writeln!(&mut writer, "{}", "-".repeat(self.lwidth + rwidth + 5))
-            .expect("failed to write to stats file");
-        Self::write_metric(&mut writer, &total_metric, self.lwidth, rwidth);
-    }
-
-    fn write_metric(writer: &mut BufWriter<File>, metric: &Metric, lwidth: usize, rwidth: usize) {
-        writeln!(
-            writer,
-            "{: >l$}: {: >r$} ms",
-            metric.name,
-            metric.duration.as_millis(),
-            l = lwidth,
-            r = rwidth,
-        )
-        .expect("failed to write to stats file");
+        writeln!(f, "{}", "-".repeat(self.lwidth + rwidth + 5))?;
+        Self::write_metric(f, &total_metric, self.lwidth, rwidth)
     }
 }
 
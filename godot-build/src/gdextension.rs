@@ -0,0 +1,268 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Generation and maintenance of `.gdextension` files.
+
+/// Entry symbol exported by `#[gdextension]`, unless overridden with `entry_point = ...`.
+pub const DEFAULT_ENTRY_SYMBOL: &str = "gdext_rust_init";
+
+/// Platforms listed in generated `.gdextension` files: (feature tags, platform).
+const LIBRARY_TARGETS: &[(&str, Platform)] = &[
+    ("linux.debug.x86_64", Platform::Linux),
+    ("linux.release.x86_64", Platform::Linux),
+    ("windows.debug.x86_64", Platform::Windows),
+    ("windows.release.x86_64", Platform::Windows),
+    ("macos.debug", Platform::MacOs),
+    ("macos.release", Platform::MacOs),
+    ("macos.debug.arm64", Platform::MacOs),
+    ("macos.release.arm64", Platform::MacOs),
+];
+
+/// Operating system, as far as the library file name is concerned.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Platform {
+    Linux,
+    Windows,
+    MacOs,
+    Android,
+    Ios,
+    Web,
+}
+
+impl Platform {
+    /// Platform from the first feature tag of a `[libraries]` key, e.g. `linux` in `linux.debug.x86_64`.
+    pub fn from_feature_tags(key: &str) -> Option<Self> {
+        let platform = match key.split('.').next()? {
+            "linux" | "linuxbsd" => Self::Linux,
+            "windows" => Self::Windows,
+            "macos" => Self::MacOs,
+            "android" => Self::Android,
+            "ios" => Self::Ios,
+            "web" => Self::Web,
+            _ => return None,
+        };
+
+        Some(platform)
+    }
+
+    /// File name of the dynamic library that cargo builds for crate library `lib_name` on this platform.
+    pub fn library_file_name(self, lib_name: &str) -> String {
+        match self {
+            Self::Linux | Self::Android => format!("lib{lib_name}.so"),
+            Self::Windows => format!("{lib_name}.dll"),
+            Self::MacOs | Self::Ios => format!("lib{lib_name}.dylib"),
+            Self::Web => format!("{lib_name}.wasm"),
+        }
+    }
+}
+
+/// Renders a `.gdextension` file for crate library `lib_name`.
+///
+/// `target_dir` is the cargo target directory, as a `res://` path relative to the Godot project (e.g. `res://../rust/target`).
+pub fn render(lib_name: &str, target_dir: &str, compatibility_minimum: &str) -> String {
+    let target_dir = target_dir.trim_end_matches('/');
+    let mut out = format!(
+        "[configuration]\n\
+        entry_symbol = \"{DEFAULT_ENTRY_SYMBOL}\"\n\
+        compatibility_minimum = {compatibility_minimum}\n\
+        \n\
+        [libraries]\n"
+    );
+
+    for (tags, platform) in LIBRARY_TARGETS {
+        let profile = if tags.contains(".debug") {
+            "debug"
+        } else {
+            "release"
+        };
+        let file_name = platform.library_file_name(lib_name);
+
+        out.push_str(&format!(
+            "{tags} = \"{target_dir}/{profile}/{file_name}\"\n"
+        ));
+    }
+
+    out
+}
+
+/// Rewrites the file names of all `[libraries]` entries in `contents`, so they match crate library `lib_name`.
+///
+/// Directories, feature tags and all other sections are preserved. Returns the new contents and the number of entries changed.
+pub fn sync_library_names(contents: &str, lib_name: &str) -> (String, usize) {
+    let mut out = String::with_capacity(contents.len());
+    let mut in_libraries = false;
+    let mut changed = 0;
+
+    for line in contents.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            in_libraries = trimmed == "[libraries]";
+        } else if in_libraries {
+            if let Some(new_line) = sync_library_line(line, lib_name) {
+                if new_line != line {
+                    changed += 1;
+                }
+                out.push_str(&new_line);
+                continue;
+            }
+        }
+
+        out.push_str(line);
+    }
+
+    (out, changed)
+}
+
+/// Rewrites a single `tags = "path"` line; returns `None` if the line has a different shape.
+fn sync_library_line(line: &str, lib_name: &str) -> Option<String> {
+    let (key, value) = line.split_once('=')?;
+    let platform = Platform::from_feature_tags(key.trim())?;
+
+    let value = value.trim_end_matches(['\r', '\n']);
+    let line_ending = &line[line.trim_end_matches(['\r', '\n']).len()..];
+
+    let spacing = &value[..value.len() - value.trim_start().len()];
+
+    let path = value.trim().strip_prefix('"')?.strip_suffix('"')?;
+    let dir_end = path.rfind('/').map_or(0, |slash| slash + 1);
+    let new_path = format!(
+        "{}{}",
+        &path[..dir_end],
+        platform.library_file_name(lib_name)
+    );
+
+    Some(format!("{key}={spacing}\"{new_path}\"{line_ending}"))
+}
+
+/// Sets `key = value` in `[section]`, replacing an existing entry, or appending it (and the section, if missing).
+///
+/// `value` is written verbatim, so string values must include their quotes.
+pub fn set_value(contents: &str, section: &str, key: &str, value: &str) -> String {
+    let header = format!("[{section}]");
+    let entry = format!("{key} = {value}\n");
+
+    let mut out = String::with_capacity(contents.len() + entry.len());
+    let mut in_section = false;
+    let mut done = false;
+
+    for line in contents.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            if in_section && !done {
+                append_to_section(&mut out, &entry);
+                done = true;
+            }
+            in_section = trimmed == header;
+        } else if in_section && !done && entry_key(line) == Some(key) {
+            out.push_str(&entry);
+            done = true;
+            continue;
+        }
+
+        out.push_str(line);
+    }
+
+    if !done {
+        if !in_section {
+            let trimmed_len = out.trim_end().len();
+            out.truncate(trimmed_len);
+            if !out.is_empty() {
+                out.push_str("\n\n");
+            }
+            out.push_str(&header);
+            out.push('\n');
+        }
+        append_to_section(&mut out, &entry);
+    }
+
+    out
+}
+
+/// Appends `entry` after the last non-empty line of `out`, keeping blank lines that separate it from the next section.
+fn append_to_section(out: &mut String, entry: &str) {
+    let trimmed_len = out.trim_end().len();
+    let tail = out.split_off(trimmed_len);
+
+    out.push('\n');
+    out.push_str(entry);
+    out.push_str(tail.strip_prefix('\n').unwrap_or(&tail));
+}
+
+fn entry_key(line: &str) -> Option<&str> {
+    line.split_once('=').map(|(key, _)| key.trim())
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_lists_all_platforms() {
+        let rendered = render("my_game", "res://../rust/target/", "4.1");
+
+        assert!(rendered.starts_with("[configuration]\nentry_symbol = \"gdext_rust_init\"\n"));
+        assert!(rendered
+            .contains("linux.debug.x86_64 = \"res://../rust/target/debug/libmy_game.so\"\n"));
+        assert!(rendered
+            .contains("windows.release.x86_64 = \"res://../rust/target/release/my_game.dll\"\n"));
+        assert!(rendered
+            .contains("macos.debug.arm64 = \"res://../rust/target/debug/libmy_game.dylib\"\n"));
+    }
+
+    #[test]
+    fn sync_keeps_directories_and_other_sections() {
+        let original = "[configuration]\n\
+            entry_symbol = \"gdext_rust_init\"\n\
+            \n\
+            [libraries]\n\
+            linux.debug.x86_64 = \"res://bin/libold_name.so\"\n\
+            windows.release.x86_64 = \"res://bin/release/new_name.dll\"\n\
+            \n\
+            [icons]\n\
+            Player = \"res://icon.png\"\n";
+
+        let (synced, changed) = sync_library_names(original, "new_name");
+
+        assert_eq!(changed, 1);
+        assert!(synced.contains("linux.debug.x86_64 = \"res://bin/libnew_name.so\"\n"));
+        assert!(synced.contains("windows.release.x86_64 = \"res://bin/release/new_name.dll\"\n"));
+        assert!(synced.contains("Player = \"res://icon.png\"\n"));
+    }
+
+    #[test]
+    fn set_value_replaces_or_appends() {
+        let original = "[configuration]\n\
+            entry_symbol = \"old\"\n\
+            \n\
+            [libraries]\n\
+            linux.debug.x86_64 = \"res://a.so\"\n";
+
+        let updated = set_value(original, "configuration", "entry_symbol", "\"new\"");
+        let updated = set_value(&updated, "configuration", "compatibility_minimum", "4.1");
+        let updated = set_value(
+            &updated,
+            "libraries",
+            "linux.release.x86_64",
+            "\"res://b.so\"",
+        );
+
+        assert_eq!(
+            updated,
+            "[configuration]\n\
+            entry_symbol = \"new\"\n\
+            compatibility_minimum = 4.1\n\
+            \n\
+            [libraries]\n\
+            linux.debug.x86_64 = \"res://a.so\"\n\
+            linux.release.x86_64 = \"res://b.so\"\n"
+        );
+
+        let created = set_value("", "configuration", "entry_symbol", "\"init\"");
+        assert_eq!(created, "[configuration]\nentry_symbol = \"init\"\n");
+    }
+}
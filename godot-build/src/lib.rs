@@ -0,0 +1,237 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Build-script helpers for godot-rust extensions.
+//!
+//! The main functionality is [`GdextensionFile`], which keeps the `.gdextension` file of a Godot project in sync with the library
+//! that cargo is currently building. Add this crate as a build dependency:
+//!
+//! ```toml
+//! [build-dependencies]
+//! godot-build = { git = "https://github.com/godot-rust/gdext", branch = "master" }
+//! ```
+//!
+//! and call it from the `main()` function in `build.rs`:
+//!
+//! ```no_run
+//! godot_build::GdextensionFile::new("../godot/my_game.gdextension")
+//!     .compatibility_minimum("4.1")
+//!     .write()
+//!     .expect("failed to update .gdextension file");
+//! ```
+
+pub mod gdextension;
+pub mod target;
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+use gdextension::Platform;
+
+/// Creates or updates a `.gdextension` file from within a build script.
+///
+/// On every build, the entry for the current target and profile (e.g. `linux.debug.x86_64` for a debug build on Linux) is pointed
+/// to the library in cargo's target directory. Since entries for other targets are kept, building once in debug and once in release
+/// mode -- or cross-compiling with `--target` -- fills in all the paths, without editing them by hand. The `[configuration]` section
+/// receives `entry_symbol` and `compatibility_minimum`; all other sections and entries are preserved.
+///
+/// Library paths are written as `res://` paths, relative to the Godot project containing the file (the closest parent directory with
+/// a `project.godot`). The file is only rewritten if its contents change, so Godot does not reload the extension needlessly.
+#[derive(Clone, Debug)]
+pub struct GdextensionFile {
+    path: PathBuf,
+    entry_symbol: String,
+    compatibility_minimum: Option<String>,
+    lib_name: Option<String>,
+}
+
+impl GdextensionFile {
+    /// Targets the `.gdextension` file at `path`; relative paths are resolved against the crate directory.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            entry_symbol: gdextension::DEFAULT_ENTRY_SYMBOL.to_string(),
+            compatibility_minimum: None,
+            lib_name: None,
+        }
+    }
+
+    /// Sets the entry symbol; needed if `#[gdextension(entry_point = ...)]` is used.
+    pub fn entry_symbol(mut self, entry_symbol: impl Into<String>) -> Self {
+        self.entry_symbol = entry_symbol.into();
+        self
+    }
+
+    /// Sets the minimum Godot version, e.g. `"4.1"`.
+    ///
+    /// If not set, an existing value in the file is kept, and new files use `4.1`.
+    pub fn compatibility_minimum(mut self, version: impl Into<String>) -> Self {
+        self.compatibility_minimum = Some(version.into());
+        self
+    }
+
+    /// Sets the library name, if it differs from the package name (`[lib] name` in `Cargo.toml`).
+    pub fn lib_name(mut self, lib_name: impl Into<String>) -> Self {
+        self.lib_name = Some(lib_name.into());
+        self
+    }
+
+    /// Writes the file, using the environment variables that cargo sets for build scripts.
+    ///
+    /// Targets which Godot does not support (see [`target::feature_tags()`]) leave the libraries untouched.
+    pub fn write(self) -> io::Result<()> {
+        let manifest_dir = PathBuf::from(env_var("CARGO_MANIFEST_DIR")?);
+        let lib_name = match self.lib_name {
+            Some(name) => name,
+            None => env_var("CARGO_PKG_NAME")?.replace('-', "_"),
+        };
+
+        // OUT_DIR is `<target-dir>[/<triple>]/<profile>/build/<pkg>-<hash>/out`; the library is placed in the profile directory.
+        let out_dir = PathBuf::from(env_var("OUT_DIR")?);
+        let artifact_dir = out_dir
+            .ancestors()
+            .nth(3)
+            .ok_or_else(|| invalid_data(format!("unexpected OUT_DIR `{}`", out_dir.display())))?;
+
+        let debug = env_var("PROFILE")? == "debug";
+        let tags = target::feature_tags(&env_var("TARGET")?, debug);
+
+        let path = manifest_dir.join(&self.path);
+        let old_contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err),
+        };
+
+        let mut contents = gdextension::set_value(
+            &old_contents,
+            "configuration",
+            "entry_symbol",
+            &format!("\"{}\"", self.entry_symbol),
+        );
+
+        let compatibility_minimum = match self.compatibility_minimum {
+            Some(version) => Some(version),
+            None if !contents.contains("compatibility_minimum") => Some("4.1".to_string()),
+            None => None,
+        };
+        if let Some(version) = compatibility_minimum {
+            contents = gdextension::set_value(
+                &contents,
+                "configuration",
+                "compatibility_minimum",
+                &version,
+            );
+        }
+
+        if let Some(tags) = tags {
+            // unwrap: feature tags are only returned for known platforms.
+            let platform = Platform::from_feature_tags(&tags).unwrap();
+            let library = artifact_dir.join(platform.library_file_name(&lib_name));
+
+            let dir = path.parent().unwrap_or(Path::new("."));
+            fs::create_dir_all(dir)?;
+            let res_path = res_path(&find_project_root(dir)?, &library)?;
+
+            contents =
+                gdextension::set_value(&contents, "libraries", &tags, &format!("\"{res_path}\""));
+        }
+
+        if contents != old_contents {
+            fs::write(&path, contents)?;
+        }
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+fn env_var(name: &str) -> io::Result<String> {
+    env::var(name).map_err(|_| {
+        invalid_data(format!(
+            "environment variable `{name}` not set; GdextensionFile::write() must be called from a build script"
+        ))
+    })
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Closest directory containing `project.godot`, starting at `dir`; `dir` itself if there is none.
+fn find_project_root(dir: &Path) -> io::Result<PathBuf> {
+    let dir = dir.canonicalize()?;
+    let root = dir
+        .ancestors()
+        .find(|ancestor| ancestor.join("project.godot").is_file())
+        .unwrap_or(&dir);
+
+    Ok(root.to_path_buf())
+}
+
+/// `res://` path of `file`, relative to Godot project directory `project_root`.
+fn res_path(project_root: &Path, file: &Path) -> io::Result<String> {
+    let file_dir = file.parent().unwrap_or(file).canonicalize()?;
+    let file_name = file.file_name().unwrap_or_default().to_string_lossy();
+
+    let relative = relative_path(project_root, &file_dir);
+    let mut res_path = String::from("res://");
+    for component in relative.components() {
+        res_path.push_str(&component.as_os_str().to_string_lossy());
+        res_path.push('/');
+    }
+    res_path.push_str(&file_name);
+
+    Ok(res_path)
+}
+
+/// Path leading from directory `from` to `to`; both must be absolute.
+fn relative_path(from: &Path, to: &Path) -> PathBuf {
+    let from: Vec<Component> = from.components().collect();
+    let to: Vec<Component> = to.components().collect();
+
+    let common = from
+        .iter()
+        .zip(&to)
+        .take_while(|(from, to)| from == to)
+        .count();
+
+    let mut relative = PathBuf::new();
+    for _ in common..from.len() {
+        relative.push("..");
+    }
+    for component in &to[common..] {
+        relative.push(component);
+    }
+
+    relative
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relative_paths() {
+        let relative = relative_path(
+            Path::new("/game/godot"),
+            Path::new("/game/rust/target/debug"),
+        );
+        assert_eq!(relative, Path::new("../rust/target/debug"));
+
+        let relative = relative_path(Path::new("/game"), Path::new("/game/bin"));
+        assert_eq!(relative, Path::new("bin"));
+
+        let relative = relative_path(Path::new("/game"), Path::new("/game"));
+        assert_eq!(relative, Path::new(""));
+    }
+}
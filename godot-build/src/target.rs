@@ -0,0 +1,66 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Mapping from Rust target triples to Godot feature tags.
+
+/// Returns the feature tags of a `[libraries]` entry for Rust target `triple`, e.g. `linux.debug.x86_64`.
+///
+/// `debug` selects between `debug` and `release` tags. Returns `None` for targets that Godot does not run on.
+pub fn feature_tags(triple: &str, debug: bool) -> Option<String> {
+    let arch = match triple.split('-').next()? {
+        "x86_64" => "x86_64",
+        "i686" | "i586" => "x86_32",
+        "aarch64" => "arm64",
+        arch if arch.starts_with("armv7") || arch == "arm" => "arm32",
+        "riscv64gc" => "rv64",
+        "wasm32" => "wasm32",
+        _ => return None,
+    };
+
+    let os = if triple.contains("android") {
+        "android"
+    } else if triple.contains("apple-darwin") {
+        "macos"
+    } else if triple.contains("apple-ios") {
+        "ios"
+    } else if triple.contains("windows") {
+        "windows"
+    } else if triple.contains("linux") {
+        "linux"
+    } else if triple.contains("emscripten") {
+        "web"
+    } else {
+        return None;
+    };
+
+    let profile = if debug { "debug" } else { "release" };
+    Some(format!("{os}.{profile}.{arch}"))
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn triples_to_feature_tags() {
+        let cases = [
+            ("x86_64-unknown-linux-gnu", true, "linux.debug.x86_64"),
+            ("x86_64-pc-windows-msvc", false, "windows.release.x86_64"),
+            ("aarch64-apple-darwin", true, "macos.debug.arm64"),
+            ("aarch64-linux-android", false, "android.release.arm64"),
+            ("armv7-linux-androideabi", true, "android.debug.arm32"),
+            ("wasm32-unknown-emscripten", false, "web.release.wasm32"),
+        ];
+
+        for (triple, debug, expected) in cases {
+            assert_eq!(feature_tags(triple, debug).as_deref(), Some(expected));
+        }
+
+        assert_eq!(feature_tags("thumbv7em-none-eabihf", true), None);
+    }
+}
@@ -0,0 +1,208 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! `cargo godot`: creates and maintains godot-rust projects.
+//!
+//! - `cargo godot new <path> [--name <crate>] [--godot-version <version>]` creates a Rust crate, a Godot project and the
+//!   `.gdextension` file connecting both.
+//! - `cargo godot edit [--manifest-path <Cargo.toml>] [--gdextension <file>]` updates the library paths in the `.gdextension` file
+//!   after the crate (or its `[lib]` target) was renamed.
+
+mod manifest;
+mod scaffold;
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::{env, fs};
+
+use godot_build::gdextension;
+
+const USAGE: &str = "\
+Usage:
+    cargo godot new <path> [--name <crate>] [--godot-version <version>]
+    cargo godot edit [--manifest-path <Cargo.toml>] [--gdextension <file>]";
+
+type CliResult = Result<(), String>;
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1).peekable();
+
+    // When run as `cargo godot ...`, cargo passes the subcommand name as the first argument.
+    if args.peek().map(String::as_str) == Some("godot") {
+        args.next();
+    }
+
+    let args: Vec<String> = args.collect();
+    let result = match args.first().map(String::as_str) {
+        Some("new") => cmd_new(&args[1..]),
+        Some("edit") => cmd_edit(&args[1..]),
+        Some("help" | "--help" | "-h") => {
+            println!("{USAGE}");
+            Ok(())
+        }
+        _ => Err(format!("expected a command\n\n{USAGE}")),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn cmd_new(args: &[String]) -> CliResult {
+    let mut options = Options::parse(args, &["--name", "--godot-version"])?;
+    let [path] = options.positional.as_slice() else {
+        return Err(format!("`new` expects exactly one path\n\n{USAGE}"));
+    };
+    let path = path.clone();
+    let root = PathBuf::from(&path);
+
+    let crate_name = match options.take("--name") {
+        Some(name) => name,
+        None => root
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| format!("cannot derive crate name from `{path}`; pass --name"))?
+            .to_string(),
+    };
+
+    let project = scaffold::NewProject {
+        crate_name,
+        godot_version: options
+            .take("--godot-version")
+            .unwrap_or_else(|| "4.1".to_string()),
+    };
+
+    project.create(&root).map_err(|err| err.to_string())?;
+
+    println!(
+        "Created `{}`. Build the library with `cargo build` in {}, then open {} in Godot.",
+        project.crate_name,
+        root.join("rust").display(),
+        root.join("godot/project.godot").display(),
+    );
+    Ok(())
+}
+
+fn cmd_edit(args: &[String]) -> CliResult {
+    let mut options = Options::parse(args, &["--manifest-path", "--gdextension"])?;
+    if !options.positional.is_empty() {
+        return Err(format!("`edit` takes no positional arguments\n\n{USAGE}"));
+    }
+
+    let manifest_path = match options.take("--manifest-path") {
+        Some(path) => PathBuf::from(path),
+        None if Path::new("rust/Cargo.toml").exists() => PathBuf::from("rust/Cargo.toml"),
+        None => PathBuf::from("Cargo.toml"),
+    };
+
+    let manifest = read(&manifest_path)?;
+    let lib_name = manifest::library_name(&manifest)
+        .ok_or_else(|| format!("no package name in `{}`", manifest_path.display()))?;
+
+    let extension_path = match options.take("--gdextension") {
+        Some(path) => PathBuf::from(path),
+        None => find_gdextension(&manifest_path)?,
+    };
+
+    let contents = read(&extension_path)?;
+    let (synced, changed) = gdextension::sync_library_names(&contents, &lib_name);
+
+    if changed > 0 {
+        fs::write(&extension_path, synced)
+            .map_err(|err| format!("cannot write `{}`: {err}", extension_path.display()))?;
+    }
+
+    println!(
+        "Updated {changed} library path(s) in {} for library `{lib_name}`.",
+        extension_path.display()
+    );
+    Ok(())
+}
+
+/// Looks for a single `.gdextension` file in the usual places: the current directory, `./godot`, and `godot` next to the crate.
+fn find_gdextension(manifest_path: &Path) -> Result<PathBuf, String> {
+    let crate_dir = manifest_path.parent().unwrap_or(Path::new("."));
+    let candidates_dirs = [
+        PathBuf::from("."),
+        PathBuf::from("godot"),
+        crate_dir.join("../godot"),
+    ];
+
+    let mut found = Vec::new();
+    for dir in candidates_dirs {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "gdextension") {
+                let canonical = path.canonicalize().unwrap_or(path);
+                if !found.contains(&canonical) {
+                    found.push(canonical);
+                }
+            }
+        }
+    }
+
+    match found.len() {
+        1 => Ok(found.remove(0)),
+        0 => Err("no .gdextension file found; pass --gdextension".to_string()),
+        _ => Err(format!(
+            "several .gdextension files found ({}); pass --gdextension",
+            found
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("cannot read `{}`: {err}", path.display()))
+}
+
+/// Command-line arguments: `--key value` pairs and positional arguments.
+struct Options {
+    named: Vec<(String, String)>,
+    positional: Vec<String>,
+}
+
+impl Options {
+    fn parse(args: &[String], allowed: &[&str]) -> Result<Self, String> {
+        let mut named = Vec::new();
+        let mut positional = Vec::new();
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if !arg.starts_with("--") {
+                positional.push(arg.clone());
+                continue;
+            }
+
+            if !allowed.contains(&arg.as_str()) {
+                return Err(format!("unknown option `{arg}`\n\n{USAGE}"));
+            }
+
+            let value = args
+                .next()
+                .ok_or_else(|| format!("option `{arg}` expects a value"))?;
+            named.push((arg.clone(), value.clone()));
+        }
+
+        Ok(Self { named, positional })
+    }
+
+    fn take(&mut self, key: &str) -> Option<String> {
+        let index = self.named.iter().position(|(k, _)| k == key)?;
+        Some(self.named.remove(index).1)
+    }
+}
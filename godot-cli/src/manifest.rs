@@ -0,0 +1,73 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Minimal reading of `Cargo.toml`, enough to determine the name of the built library.
+
+/// Returns the name of the library target in `Cargo.toml` contents, as it appears in the file name of the built library.
+///
+/// This is `[lib] name` if present, otherwise `[package] name`, with `-` replaced by `_` (like cargo does).
+pub fn library_name(manifest: &str) -> Option<String> {
+    let mut section = "";
+    let mut package_name = None;
+    let mut lib_name = None;
+
+    for line in manifest.lines() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+
+        if let Some(header) = line.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            section = header.trim();
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+
+        if key.trim() != "name" {
+            continue;
+        }
+
+        let value = value.trim().trim_matches('"').to_string();
+        match section {
+            "package" => package_name = Some(value),
+            "lib" => lib_name = Some(value),
+            _ => {}
+        }
+    }
+
+    lib_name.or(package_name).map(|name| name.replace('-', "_"))
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn library_name_prefers_lib_section() {
+        let manifest = r#"
+            [package]
+            name = "my-game" # comment
+
+            [lib]
+            crate-type = ["cdylib"]
+        "#;
+        assert_eq!(library_name(manifest).as_deref(), Some("my_game"));
+
+        let manifest = r#"
+            [package]
+            name = "my-game"
+
+            [lib]
+            name = "game_core"
+
+            [dependencies]
+            name = "unrelated"
+        "#;
+        assert_eq!(library_name(manifest).as_deref(), Some("game_core"));
+    }
+}
@@ -0,0 +1,127 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Templates for `cargo godot new`.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use godot_build::gdextension;
+
+/// Options of a new project.
+pub struct NewProject {
+    /// Package name of the Rust crate, e.g. `my-game`.
+    pub crate_name: String,
+
+    /// Value of `compatibility_minimum` in the `.gdextension` file.
+    pub godot_version: String,
+}
+
+impl NewProject {
+    /// Creates the project in directory `root`, which must not exist yet or be empty.
+    ///
+    /// The layout is `root/rust` for the crate and `root/godot` for the Godot project, the latter referencing the libraries in
+    /// `root/rust/target`.
+    pub fn create(&self, root: &Path) -> io::Result<()> {
+        if root.exists() && fs::read_dir(root)?.next().is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("directory `{}` exists and is not empty", root.display()),
+            ));
+        }
+
+        let lib_name = self.crate_name.replace('-', "_");
+        let extension = gdextension::render(&lib_name, "res://../rust/target", &self.godot_version);
+
+        let files = [
+            (root.join("rust/Cargo.toml"), self.cargo_toml()),
+            (root.join("rust/src/lib.rs"), LIB_RS.to_string()),
+            (root.join("godot/project.godot"), self.project_godot()),
+            (
+                root.join(format!("godot/{lib_name}.gdextension")),
+                extension,
+            ),
+            (root.join(".gitignore"), GITIGNORE.to_string()),
+        ];
+
+        for (path, contents) in files {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&path, contents)?;
+        }
+
+        Ok(())
+    }
+
+    fn cargo_toml(&self) -> String {
+        format!(
+            r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+publish = false
+
+[lib]
+crate-type = ["cdylib"]
+
+[dependencies]
+godot = {{ git = "https://github.com/godot-rust/gdext", branch = "master" }}
+"#,
+            name = self.crate_name
+        )
+    }
+
+    fn project_godot(&self) -> String {
+        format!(
+            r#"; Engine configuration file.
+
+config_version=5
+
+[application]
+
+config/name="{name}"
+config/features=PackedStringArray("{version}")
+"#,
+            name = self.crate_name,
+            version = self.godot_version
+        )
+    }
+}
+
+const LIB_RS: &str = r#"use godot::engine::{Sprite2D, Sprite2DVirtual};
+use godot::prelude::*;
+
+struct MyExtension;
+
+#[gdextension]
+unsafe impl ExtensionLibrary for MyExtension {}
+
+/// Sample class: a sprite that rotates on its own. Add it to a scene in the editor to try it out.
+#[derive(GodotClass)]
+#[class(base=Sprite2D)]
+struct Spinner {
+    #[export]
+    speed: real,
+
+    #[base]
+    sprite: Base<Sprite2D>,
+}
+
+#[godot_api]
+impl Sprite2DVirtual for Spinner {
+    fn init(sprite: Base<Sprite2D>) -> Self {
+        Self { speed: 1.0, sprite }
+    }
+
+    fn process(&mut self, delta: real) {
+        self.sprite.rotate(self.speed * delta);
+    }
+}
+"#;
+
+const GITIGNORE: &str = "rust/target/\nrust/Cargo.lock\ngodot/.godot/\n";
@@ -72,7 +72,7 @@ pub struct Class {
     pub enums: Option<Vec<Enum>>,
     pub methods: Option<Vec<ClassMethod>>,
     // pub properties: Option<Vec<Property>>,
-    // pub signals: Option<Vec<Signal>>,
+    pub signals: Option<Vec<Signal>>,
 }
 
 #[derive(DeJson)]
@@ -157,8 +157,8 @@ pub struct Property {
 
 #[derive(DeJson)]
 pub struct Signal {
-    name: String,
-    arguments: Option<Vec<MethodArg>>,
+    pub name: String,
+    pub arguments: Option<Vec<MethodArg>>,
 }
 
 #[derive(DeJson)]
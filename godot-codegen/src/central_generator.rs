@@ -19,9 +19,13 @@ use crate::{codegen_special_cases, ident, special_cases, util, Context, SubmitFn
 
 struct CentralItems {
     opaque_types: [Vec<TokenStream>; 2],
+    builtin_sizes: [Vec<TokenStream>; 2],
+    variant_sizes: [usize; 2],
     variant_ty_enumerators_pascal: Vec<Ident>,
     variant_ty_enumerators_rust: Vec<TokenStream>,
     variant_ty_enumerators_ord: Vec<Literal>,
+    variant_ty_names_godot: Vec<String>,
+    variant_ty_names_rust: Vec<String>,
     variant_op_enumerators_pascal: Vec<Ident>,
     variant_op_enumerators_ord: Vec<Literal>,
     global_enum_defs: Vec<TokenStream>,
@@ -362,52 +366,33 @@ fn make_method_table(info: IndexedMethodTable) -> TokenStream {
     let unused_attr = (method_count == 0).then(|| quote! { #[allow(unused_variables)] });
     let named_method_api = make_named_accessors(&named_accessors, &fptr_type);
 
-    // Assumes that inits already have a trailing comma.
-    // This is necessary because some generators emit multiple lines (statements) per element.
     quote! {
         #imports
         use crate::StringCache;
-        use std::collections::HashMap;
-        use std::cell::RefCell;
-
-        // Exists to be stored inside RefCell.
-        struct InnerTable {
-            // 'static because at this point, the interface and lifecycle tables are globally available.
-            string_cache: StringCache<'static>,
-            function_pointers: HashMap<#lazy_key_type, #fptr_type>,
-        }
 
-        // Note: get_method_bind and other function pointers could potentially be stored as fields in table, to avoid interface_fn!.
+        // Holds no function pointers: each call site caches the one it needs, so that unused methods can be stripped by the linker.
         pub struct #table_name {
-            inner: RefCell<InnerTable>,
+            _private: (),
         }
 
         impl #table_name {
             pub const CLASS_COUNT: usize = #class_count;
             pub const METHOD_COUNT: usize = #method_count;
 
-            #unused_attr
             pub fn load() -> Self {
-                // SAFETY: interface and lifecycle tables are initialized at this point, so we can get 'static references to them.
+                Self { _private: () }
+            }
+
+            /// Loads the function pointer from Godot. Not cached; callers store the result.
+            #unused_attr
+            pub fn fptr_by_key(&self, key: #lazy_key_type) -> #fptr_type {
+                // SAFETY: interface and lifecycle tables are initialized at this point.
                 let (interface, lifecycle_table) = unsafe {
                     (crate::get_interface(), crate::method_table())
                 };
+                let mut string_cache = StringCache::new(interface, lifecycle_table);
 
-                Self {
-                    inner: RefCell::new(InnerTable {
-                        string_cache: StringCache::new(interface, lifecycle_table),
-                        function_pointers: HashMap::new(),
-                    }),
-                }
-            }
-
-            #[inline(always)]
-            pub fn fptr_by_key(&self, key: #lazy_key_type) -> #fptr_type {
-                let mut guard = self.inner.borrow_mut();
-                let inner = &mut *guard;
-                *inner.function_pointers.entry(key.clone()).or_insert_with(|| {
-                    #lazy_method_init
-                })
+                #lazy_method_init
             }
 
             #named_method_api
@@ -466,8 +451,12 @@ pub(crate) fn generate_core_central_file(
 fn make_sys_code(central_items: &CentralItems) -> TokenStream {
     let CentralItems {
         opaque_types,
+        builtin_sizes,
+        variant_sizes,
         variant_ty_enumerators_pascal,
         variant_ty_enumerators_ord,
+        variant_ty_names_godot,
+        variant_ty_names_rust,
         variant_op_enumerators_pascal,
         variant_op_enumerators_ord,
         godot_version,
@@ -476,6 +465,11 @@ fn make_sys_code(central_items: &CentralItems) -> TokenStream {
 
     let build_config_struct = make_build_config(godot_version);
     let [opaque_32bit, opaque_64bit] = opaque_types;
+    let [sizes_32bit, sizes_64bit] = builtin_sizes;
+    let [variant_size_32bit, variant_size_64bit] = variant_sizes;
+    let meta_types = util::META_TYPES
+        .iter()
+        .map(|(godot_ty, meta, rust_ty)| quote! { (#godot_ty, #meta, #rust_ty) });
 
     quote! {
         use crate::{GDExtensionVariantOperator, GDExtensionVariantType};
@@ -483,12 +477,41 @@ fn make_sys_code(central_items: &CentralItems) -> TokenStream {
         #[cfg(target_pointer_width = "32")]
         pub mod types {
             #(#opaque_32bit)*
+
+            #[cfg(feature = "experimental-sys")]
+            pub(crate) const VARIANT_SIZE: usize = #variant_size_32bit;
+            #[cfg(feature = "experimental-sys")]
+            pub(crate) const BUILTIN_SIZES: &[(&str, usize)] = &[
+                #( #sizes_32bit, )*
+            ];
         }
         #[cfg(target_pointer_width = "64")]
         pub mod types {
             #(#opaque_64bit)*
+
+            #[cfg(feature = "experimental-sys")]
+            pub(crate) const VARIANT_SIZE: usize = #variant_size_64bit;
+            #[cfg(feature = "experimental-sys")]
+            pub(crate) const BUILTIN_SIZES: &[(&str, usize)] = &[
+                #( #sizes_64bit, )*
+            ];
         }
 
+        // Tables for the `layout` module.
+        /// `(VariantType, Godot name, Rust name)` of all variant types except `Nil`.
+        #[cfg(feature = "experimental-sys")]
+        pub(crate) const VARIANT_TYPE_NAMES: &[(VariantType, &str, &str)] = &[
+            #(
+                (VariantType::#variant_ty_enumerators_pascal, #variant_ty_names_godot, #variant_ty_names_rust),
+            )*
+        ];
+
+        /// `(Godot type, argument metadata, Rust type)` of numeric types with metadata.
+        #[cfg(feature = "experimental-sys")]
+        pub(crate) const META_TYPE_NAMES: &[(&str, &str, &str)] = &[
+            #( #meta_types, )*
+        ];
+
 
         // ----------------------------------------------------------------------------------------------------------------------------------------------
 
@@ -686,11 +709,18 @@ fn make_central_items(
     ctx: &mut Context,
 ) -> CentralItems {
     let mut opaque_types = [Vec::new(), Vec::new()];
+    let mut builtin_sizes = [Vec::new(), Vec::new()];
+    let mut variant_sizes = [0; 2];
     for class in &api.builtin_class_sizes {
         for i in 0..2 {
             if class.build_configuration == build_config[i] {
                 for ClassSize { name, size } in &class.sizes {
                     opaque_types[i].push(make_opaque_type(name, *size));
+                    builtin_sizes[i].push(quote! { (#name, #size) });
+
+                    if name == "Variant" {
+                        variant_sizes[i] = *size;
+                    }
                 }
                 break;
             }
@@ -705,9 +735,13 @@ fn make_central_items(
 
     let mut result = CentralItems {
         opaque_types,
+        builtin_sizes,
+        variant_sizes,
         variant_ty_enumerators_pascal: Vec::with_capacity(len),
         variant_ty_enumerators_rust: Vec::with_capacity(len),
         variant_ty_enumerators_ord: Vec::with_capacity(len),
+        variant_ty_names_godot: Vec::with_capacity(len),
+        variant_ty_names_rust: Vec::with_capacity(len),
         variant_op_enumerators_pascal: Vec::new(),
         variant_op_enumerators_ord: Vec::new(),
         global_enum_defs: Vec::new(),
@@ -717,7 +751,17 @@ fn make_central_items(
     // Note: NIL is not part of this iteration, it will be added manually
     for ty in builtin_types.ordered() {
         let (pascal_name, rust_ty, ord) = make_enumerator(&ty.type_names, ty.value, ctx);
+        let godot_name = &ty.type_names.json_builtin_name;
+
+        // Only `Object` maps to a generic type; all others are plain identifiers.
+        let rust_name = if godot_name == "Object" {
+            "Gd<Object>".to_string()
+        } else {
+            rust_ty.to_string()
+        };
 
+        result.variant_ty_names_godot.push(godot_name.clone());
+        result.variant_ty_names_rust.push(rust_name);
         result.variant_ty_enumerators_pascal.push(pascal_name);
         result.variant_ty_enumerators_rust.push(rust_ty);
         result.variant_ty_enumerators_ord.push(ord);
@@ -825,7 +869,7 @@ fn make_class_method_table(
             let get_method_bind = crate::interface_fn!(classdb_get_method_bind);
             crate::load_class_method(
                 get_method_bind,
-                &mut inner.string_cache,
+                &mut string_cache,
                 None,
                 key.class_name,
                 key.method_name,
@@ -886,7 +930,8 @@ fn make_named_accessors(accessors: &[AccessorMethod], fptr: &TokenStream) -> Tok
             quote! {
                 #[inline(always)]
                 pub fn #name(&self) -> #fptr {
-                    self.fptr_by_key(#lazy_key)
+                    static FPTR: crate::LazyFptr<#fptr> = crate::LazyFptr::new();
+                    FPTR.get_or_load(|| self.fptr_by_key(#lazy_key))
                 }
             }
         } else {
@@ -926,7 +971,7 @@ fn make_builtin_method_table(
             let get_builtin_method = crate::interface_fn!(variant_get_ptr_builtin_method);
             crate::load_builtin_method(
                 get_builtin_method,
-                &mut inner.string_cache,
+                &mut string_cache,
                 key.variant_type.sys(),
                 key.variant_type_str,
                 key.method_name,
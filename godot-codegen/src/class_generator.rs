@@ -12,15 +12,16 @@ use std::path::Path;
 
 use crate::api_parser::*;
 use crate::central_generator::collect_builtin_types;
-use crate::context::NotificationEnum;
+use crate::context::{NotificationConstant, NotificationEnum};
 use crate::util::{
     ident, make_string_name, option_as_slice, parse_native_structures_format, safe_ident,
     to_pascal_case, to_rust_expr, to_rust_type, to_rust_type_abi, to_snake_case, ClassCodegenLevel,
     MethodTableKey, NativeStructuresField,
 };
 use crate::{
-    codegen_special_cases, special_cases, util, Context, GeneratedBuiltin, GeneratedBuiltinModule,
-    GeneratedClass, GeneratedClassModule, ModName, RustTy, SubmitFn, TyName,
+    codegen_special_cases, report, special_cases, util, Context, GeneratedBuiltin,
+    GeneratedBuiltinModule, GeneratedClass, GeneratedClassModule, ModName, RustTy, SubmitFn,
+    TyName,
 };
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -169,6 +170,8 @@ impl FnQualifier {
 
 struct FnSignature<'a> {
     function_name: &'a str,
+    /// Name in Godot's API, before any renaming (e.g. `_ready` for virtual `ready()`).
+    godot_name: &'a str,
     surrounding_class: Option<&'a TyName>, // None if global function
     is_private: bool,
     is_virtual: bool,
@@ -272,6 +275,8 @@ pub(crate) fn generate_class_files(
         });
     }
 
+    report::record_classes(modules.len(), api.classes.len());
+
     let out_path = gen_path.join("mod.rs");
     let mod_contents = make_module_file(modules);
 
@@ -451,12 +456,24 @@ fn make_constructor(class: &Class, ctx: &Context) -> TokenStream {
         // not strict Rust mutability, it makes the API much more usable).
         // As long as the user has multiple Gd smart pointers to the same singletons, only the internal raw pointers are aliased.
         // See also Deref/DerefMut impl for Gd.
+        let panic_msg = format!("singleton `{godot_class_name}` is not available");
+
         quote! {
+            /// ⚠️ Returns the singleton instance.
+            ///
+            /// # Panics
+            /// If the singleton is not registered, e.g. because the engine was built or started without the corresponding server.
+            /// Use [`try_singleton()`][Self::try_singleton] to handle this case.
             pub fn singleton() -> Gd<Self> {
+                Self::try_singleton().expect(#panic_msg)
+            }
+
+            /// Returns the singleton instance, or `None` if it is not registered.
+            pub fn try_singleton() -> Option<Gd<Self>> {
                 unsafe {
                     let __class_name = #godot_class_stringname;
                     let __object_ptr = sys::interface_fn!(global_get_singleton)(__class_name.string_sys());
-                    Gd::from_obj_sys(__object_ptr)
+                    Gd::from_obj_sys_or_none(__object_ptr)
                 }
             }
         }
@@ -515,6 +532,7 @@ fn make_class(class: &Class, class_name: &TyName, ctx: &mut Context) -> Generate
 
     let enums = make_enums(option_as_slice(&class.enums), class_name, ctx);
     let constants = make_constants(option_as_slice(&class.constants), class_name, ctx);
+    let signals = make_signal_constants(option_as_slice(&class.signals));
     let inherits_macro = format_ident!("inherits_transitive_{}", class_name.rust_ty);
 
     let (exportable_impl, exportable_macro_impl) = if ctx.is_exportable(class_name) {
@@ -568,6 +586,7 @@ fn make_class(class: &Class, class_name: &TyName, ctx: &mut Context) -> Generate
         has_sidecar_module,
     );
     let module_doc = make_module_doc(class_name);
+    let doc_alias = util::make_doc_alias(&class_name.godot_ty, &class_name.rust_ty);
     let virtual_trait = make_virtual_methods_trait(
         class,
         class_name,
@@ -601,6 +620,7 @@ fn make_class(class: &Class, class_name: &TyName, ctx: &mut Context) -> Generate
             use super::*;
 
             #[doc = #class_doc]
+            #doc_alias
             #[derive(Debug)]
             #[repr(C)]
             pub struct #class_name {
@@ -614,6 +634,7 @@ fn make_class(class: &Class, class_name: &TyName, ctx: &mut Context) -> Generate
                 #methods
                 #notify_methods
                 #constants
+                #signals
             }
             unsafe impl crate::obj::GodotClass for #class_name {
                 type Base = #base_ty;
@@ -744,9 +765,14 @@ fn make_notification_enum(
 
     let mut notification_enumerators_pascal = Vec::new();
     let mut notification_enumerators_ord = Vec::new();
-    for (constant_ident, constant_value) in all_constants {
-        notification_enumerators_pascal.push(constant_ident);
-        notification_enumerators_ord.push(util::make_enumerator_ord(constant_value));
+    let mut notification_enumerators_alias = Vec::new();
+    for constant in all_constants {
+        notification_enumerators_alias.push(util::make_doc_alias(
+            &constant.godot_name,
+            &constant.rust_name,
+        ));
+        notification_enumerators_pascal.push(constant.rust_name);
+        notification_enumerators_ord.push(util::make_enumerator_ord(constant.value));
     }
 
     let code = quote! {
@@ -758,6 +784,7 @@ fn make_notification_enum(
         #[repr(i32)]
         pub enum #enum_name {
             #(
+                #notification_enumerators_alias
                 #notification_enumerators_pascal = #notification_enumerators_ord,
             )*
 
@@ -797,14 +824,14 @@ fn make_notification_enum(
 ///
 /// Godot has a collision for two notification constants (DRAW, NODE_CACHE_REQUESTED) in the same inheritance branch (as of 4.0.2).
 /// This cannot be represented in a Rust enum, so we merge the two constants into a single enumerator.
-fn workaround_constant_collision(all_constants: &mut Vec<(Ident, i32)>) {
+fn workaround_constant_collision(all_constants: &mut Vec<NotificationConstant>) {
     for first in ["Draw", "VisibilityChanged"] {
         if let Some(index_of_draw) = all_constants
             .iter()
-            .position(|(constant_name, _)| constant_name == first)
+            .position(|constant| constant.rust_name == first)
         {
-            all_constants[index_of_draw].0 = format_ident!("{first}OrNodeRecacheRequested");
-            all_constants.retain(|(constant_name, _)| constant_name != "NodeRecacheRequested");
+            all_constants[index_of_draw].rust_name = format_ident!("{first}OrNodeRecacheRequested");
+            all_constants.retain(|constant| constant.rust_name != "NodeRecacheRequested");
         }
     }
 }
@@ -1094,6 +1121,30 @@ fn make_constants(
     }
 }
 
+/// Associated constants `SIGNAL_*` with the names of the signals that the class itself declares.
+fn make_signal_constants(signals: &[Signal]) -> TokenStream {
+    let definitions = signals.iter().map(|signal| {
+        let const_name = format_ident!("SIGNAL_{}", signal.name.to_uppercase());
+        let name = &signal.name;
+
+        let params = option_as_slice(&signal.arguments)
+            .iter()
+            .map(|arg| format!("{}: {}", arg.name, arg.type_))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let doc = format!("Signal `{name}({params})`.");
+
+        quote! {
+            #[doc = #doc]
+            pub const #const_name: crate::engine::SignalName<Self> = crate::engine::SignalName::__new(#name);
+        }
+    });
+
+    quote! {
+        #( #definitions )*
+    }
+}
+
 /// Depending on the built-in class, adds custom constructors and methods.
 fn make_special_builtin_methods(class_name: &TyName, _ctx: &Context) -> TokenStream {
     if class_name.godot_ty == "Array" {
@@ -1154,22 +1205,25 @@ fn make_class_method_definition(
         quote! { Some(self.instance_id) }
     };
 
-    let fptr_access = if cfg!(feature = "codegen-lazy-fptrs") {
+    let method_bind = if cfg!(feature = "codegen-lazy-fptrs") {
         let hash = method.hash.expect("hash present for class method");
-        quote! {
-            fptr_by_key(sys::lazy_keys::ClassMethodKey {
-                class_name: #class_name_str,
-                method_name: #method_name_str,
-                hash: #hash,
-            })
-        }
+        make_lazy_fptr_access(
+            quote! { sys::ClassMethodBind },
+            quote! {
+                sys::#get_method_table().fptr_by_key(sys::lazy_keys::ClassMethodKey {
+                    class_name: #class_name_str,
+                    method_name: #method_name_str,
+                    hash: #hash,
+                })
+            },
+        )
     } else {
-        quote! { fptr_by_index(#table_index) }
+        quote! { sys::#get_method_table().fptr_by_index(#table_index) }
     };
 
     let object_ptr = &receiver.ffi_arg;
     let ptrcall_invocation = quote! {
-        let method_bind = sys::#get_method_table().#fptr_access;
+        let method_bind = #method_bind;
 
         <CallSig as PtrcallSignatureTuple>::out_class_ptrcall::<RetMarshal>(
             method_bind,
@@ -1181,7 +1235,7 @@ fn make_class_method_definition(
     };
 
     let varcall_invocation = quote! {
-        let method_bind = sys::#get_method_table().#fptr_access;
+        let method_bind = #method_bind;
 
         <CallSig as VarcallSignatureTuple>::out_class_varcall(
             method_bind,
@@ -1196,6 +1250,7 @@ fn make_class_method_definition(
     make_function_definition(
         &FnSignature {
             function_name: method_name_str,
+            godot_name: &method.name,
             surrounding_class: Some(class_name),
             is_private: special_cases::is_private(class_name, &method.name),
             is_virtual: false,
@@ -1229,32 +1284,35 @@ fn make_builtin_method_definition(
         .as_deref()
         .map(MethodReturn::from_type_no_meta);
 
-    let fptr_access = if cfg!(feature = "codegen-lazy-fptrs") {
+    let method_bind = if cfg!(feature = "codegen-lazy-fptrs") {
         let variant_type = quote! { sys::VariantType::#builtin_name };
         let variant_type_str = &builtin_name.godot_ty;
         let hash = method.hash.expect("hash present for class method");
 
-        quote! {
-            fptr_by_key(sys::lazy_keys::BuiltinMethodKey {
-                variant_type: #variant_type,
-                variant_type_str: #variant_type_str,
-                method_name: #method_name_str,
-                hash: #hash,
-            })
-        }
+        make_lazy_fptr_access(
+            quote! { sys::BuiltinMethodBind },
+            quote! {
+                sys::builtin_method_table().fptr_by_key(sys::lazy_keys::BuiltinMethodKey {
+                    variant_type: #variant_type,
+                    variant_type_str: #variant_type_str,
+                    method_name: #method_name_str,
+                    hash: #hash,
+                })
+            },
+        )
     } else {
         let table_index = ctx.get_table_index(&MethodTableKey::BuiltinMethod {
             builtin_ty: builtin_name.clone(),
             method_name: method.name.clone(),
         });
-        quote! { fptr_by_index(#table_index) }
+        quote! { sys::builtin_method_table().fptr_by_index(#table_index) }
     };
 
     let receiver = make_receiver(method.is_static, method.is_const, quote! { self.sys_ptr });
     let object_ptr = &receiver.ffi_arg;
 
     let ptrcall_invocation = quote! {
-        let method_bind = sys::builtin_method_table().#fptr_access;
+        let method_bind = #method_bind;
 
         <CallSig as PtrcallSignatureTuple>::out_builtin_ptrcall::<RetMarshal>(
             method_bind,
@@ -1277,6 +1335,7 @@ fn make_builtin_method_definition(
     make_function_definition(
         &FnSignature {
             function_name: method_name_str,
+            godot_name: &method.name,
             surrounding_class: Some(inner_class_name),
             is_private: special_cases::is_private(builtin_name, &method.name),
             is_virtual: false,
@@ -1334,6 +1393,7 @@ pub(crate) fn make_utility_function_definition(
     let definition = make_function_definition(
         &FnSignature {
             function_name: function_name_str,
+            godot_name: &function.name,
             surrounding_class: None,
             is_private: false,
             is_virtual: false,
@@ -1353,6 +1413,19 @@ pub(crate) fn make_utility_function_definition(
     definition.into_functions_only()
 }
 
+/// Caches the function pointer in a `static` local to the generated method.
+///
+/// Only used with `codegen-lazy-fptrs`. Unlike an entry in a central table, such a static is only linked into the binary if the method
+/// itself is used.
+fn make_lazy_fptr_access(fptr_type: TokenStream, load_expr: TokenStream) -> TokenStream {
+    quote! {
+        {
+            static FPTR: sys::LazyFptr<#fptr_type> = sys::LazyFptr::new();
+            FPTR.get_or_load(|| #load_expr)
+        }
+    }
+}
+
 fn make_vis(is_private: bool) -> TokenStream {
     if is_private {
         quote! { pub(crate) }
@@ -1399,6 +1472,13 @@ fn make_function_definition(sig: &FnSignature, code: &FnCode) -> FnDefinition {
         (TokenStream::new(), TokenStream::new())
     };
 
+    // With default parameters, the alias goes to the short function instead of `*_full()`.
+    let doc_alias = if has_default_params {
+        TokenStream::new()
+    } else {
+        util::make_doc_alias(sig.godot_name, &primary_fn_name)
+    };
+
     let return_ty = &sig.return_value.type_tokens();
     let call_sig = quote! {
         ( #return_ty, #(#param_types),* )
@@ -1412,6 +1492,7 @@ fn make_function_definition(sig: &FnSignature, code: &FnCode) -> FnDefinition {
 
         quote! {
             #safety_doc
+            #doc_alias
             #maybe_unsafe fn #primary_fn_name(
                 #receiver_param
                 #( #params, )*
@@ -1428,6 +1509,7 @@ fn make_function_definition(sig: &FnSignature, code: &FnCode) -> FnDefinition {
         // TODO use Result instead of panic on error
         quote! {
             #safety_doc
+            #doc_alias
             #vis #maybe_unsafe fn #primary_fn_name(
                 #receiver_param
                 #( #params, )*
@@ -1458,6 +1540,7 @@ fn make_function_definition(sig: &FnSignature, code: &FnCode) -> FnDefinition {
 
         quote! {
             #safety_doc
+            #doc_alias
             #vis #maybe_unsafe fn #primary_fn_name(
                 #receiver_param
                 #( #params, )*
@@ -1495,6 +1578,7 @@ fn make_function_definition_with_defaults(
 
     let simple_fn_name = safe_ident(sig.function_name);
     let extended_fn_name = format_ident!("{}_ex", simple_fn_name);
+    let doc_alias = util::make_doc_alias(sig.godot_name, &simple_fn_name);
     let vis = make_vis(sig.is_private);
 
     let (builder_doc, surround_class_prefix) = make_extender_doc(sig, &extended_fn_name);
@@ -1559,6 +1643,7 @@ fn make_function_definition_with_defaults(
     };
 
     let functions = quote! {
+        #doc_alias
         #[inline]
         #vis fn #simple_fn_name(
             #receiver_param
@@ -1864,15 +1949,33 @@ fn make_virtual_method(method: &ClassMethod, ctx: &mut Context) -> TokenStream {
     // Virtual methods are never static.
     assert!(!method.is_static);
 
+    let mut params = FnParam::new_range(&method.arguments, ctx);
+    let mut has_real_param = false;
+    for (param, arg) in params.iter_mut().zip(option_as_slice(&method.arguments)) {
+        if special_cases::is_virtual_param_real(&method.name, &arg.name) {
+            param.type_ = RustTy::BuiltinIdent(ident("real"));
+            has_real_param = true;
+        }
+    }
+
+    let real_doc = has_real_param.then(|| {
+        quote! {
+            #[doc = "`delta` is [`real`][type@crate::builtin::real], which is `f64` only with the `double-precision` feature. Godot passes it"]
+            #[doc = "as `double`, so single-precision builds round it to `f32`. Code written for the former `delta: f64` needs to declare"]
+            #[doc = "`delta: real` instead, and can drop conversions such as `real::from_f64(delta)`."]
+        }
+    });
+
     let definition = make_function_definition(
         &FnSignature {
             function_name: method_name,
+            godot_name: &method.name,
             surrounding_class: None, // no default parameters needed for virtual methods
             is_private: false,
             is_virtual: true,
             is_vararg: false,
             qualifier: FnQualifier::for_method(method.is_const, method.is_static),
-            params: FnParam::new_range(&method.arguments, ctx),
+            params,
             return_value: FnReturn::new(&method.return_value, ctx),
         },
         &FnCode {
@@ -1884,7 +1987,12 @@ fn make_virtual_method(method: &ClassMethod, ctx: &mut Context) -> TokenStream {
     );
 
     // Virtual methods have no builders.
-    definition.into_functions_only()
+    let functions = definition.into_functions_only();
+
+    quote! {
+        #real_doc
+        #functions
+    }
 }
 
 fn make_all_virtual_methods(
@@ -0,0 +1,321 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! User-defined selection of engine classes, read from a `gdext-codegen.toml` file.
+//!
+//! The file is looked up in the root of the workspace (or package) that is being built, next to its `Cargo.toml`. The
+//! `GDEXT_CODEGEN_CONFIG` environment variable overrides this with a path to a different file. Without a config file, the
+//! `codegen-full` feature alone decides which classes are generated.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::api_parser::Class;
+
+const CONFIG_ENV_VAR: &str = "GDEXT_CODEGEN_CONFIG";
+const CONFIG_FILE_NAME: &str = "gdext-codegen.toml";
+
+static SELECTED: OnceLock<Option<HashSet<String>>> = OnceLock::new();
+
+/// Reads the config file, if any, and computes the selected classes. Later calls have no effect.
+pub(crate) fn init(classes: &[Class], default_selection: impl Fn(&str) -> bool, required: &[&str]) {
+    SELECTED.get_or_init(|| {
+        let path = locate_config()?;
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        let content = std::fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("cannot read codegen config `{}`: {e}", path.display()));
+        let config = CodegenConfig::parse(&content)
+            .unwrap_or_else(|e| panic!("invalid codegen config `{}`: {e}", path.display()));
+
+        Some(config.select(classes, default_selection, required))
+    });
+}
+
+/// Classes selected by the config file, or `None` if there is no config file.
+pub(crate) fn selected_classes() -> Option<&'static HashSet<String>> {
+    SELECTED.get().and_then(Option::as_ref)
+}
+
+/// Path of the config file: the one given by the environment variable, or otherwise `gdext-codegen.toml` in the workspace root.
+fn locate_config() -> Option<PathBuf> {
+    println!("cargo:rerun-if-env-changed={CONFIG_ENV_VAR}");
+    if let Some(path) = std::env::var_os(CONFIG_ENV_VAR) {
+        return Some(PathBuf::from(path));
+    }
+
+    // Build scripts are not told which crate depends on gdext. Its target directory is usually inside its workspace, so the first
+    // ancestor of OUT_DIR with a `Cargo.toml` is the workspace (or package) root. A custom target directory elsewhere needs the
+    // environment variable.
+    let out_dir = std::env::var_os("OUT_DIR")?;
+    let root = Path::new(&out_dir)
+        .ancestors()
+        .find(|dir| dir.join("Cargo.toml").is_file())?;
+
+    let path = root.join(CONFIG_FILE_NAME);
+    path.is_file().then_some(path)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Contents of `gdext-codegen.toml`:
+/// ```toml
+/// [include]
+/// classes = ["Sprite2D", "AnimationPlayer"]
+/// areas = ["physics_2d"]
+///
+/// [exclude]
+/// classes = ["WebRTCPeerConnection"]
+/// areas = ["editor", "xr"]
+/// ```
+///
+/// Only this subset of TOML is supported: the two tables, with arrays of strings (possibly spanning several lines) and comments.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub(crate) struct CodegenConfig {
+    pub include_classes: Vec<String>,
+    pub include_areas: Vec<String>,
+    pub exclude_classes: Vec<String>,
+    pub exclude_areas: Vec<String>,
+}
+
+impl CodegenConfig {
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut config = Self::default();
+        let mut table = None;
+
+        // Joins continuation lines until the closing `]` of an array.
+        let mut pending = String::new();
+        for (index, line) in content.lines().enumerate() {
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            pending.push_str(line);
+            pending.push(' ');
+            if pending.contains('=') && !pending.contains(']') {
+                continue;
+            }
+
+            let statement = std::mem::take(&mut pending);
+            let statement = statement.trim();
+            let line_no = index + 1;
+
+            if let Some(name) = statement
+                .strip_prefix('[')
+                .and_then(|rest| rest.strip_suffix(']'))
+            {
+                table = match name.trim() {
+                    "include" => Some("include"),
+                    "exclude" => Some("exclude"),
+                    name => return Err(format!("line {line_no}: unknown table `[{name}]`")),
+                };
+                continue;
+            }
+
+            let Some((key, value)) = statement.split_once('=') else {
+                return Err(format!("line {line_no}: expected `key = [...]`"));
+            };
+            let values = parse_string_array(value.trim())
+                .ok_or_else(|| format!("line {line_no}: expected an array of strings"))?;
+
+            let target = match (table, key.trim()) {
+                (Some("include"), "classes") => &mut config.include_classes,
+                (Some("include"), "areas") => &mut config.include_areas,
+                (Some("exclude"), "classes") => &mut config.exclude_classes,
+                (Some("exclude"), "areas") => &mut config.exclude_areas,
+                (None, key) => {
+                    return Err(format!("line {line_no}: key `{key}` outside of a table"))
+                }
+                (Some(table), key) => {
+                    return Err(format!("line {line_no}: unknown key `{table}.{key}`"))
+                }
+            };
+            target.extend(values);
+        }
+
+        if !pending.is_empty() {
+            return Err("unterminated array".to_string());
+        }
+
+        Ok(config)
+    }
+
+    /// Computes the selected classes.
+    ///
+    /// If `[include]` lists any classes or areas, only those are selected; otherwise, the classes accepted by `default_selection`.
+    /// The excluded ones are removed from that. Afterwards, `required` classes and the base classes of all selected classes are
+    /// added back, as generated code cannot do without them.
+    pub fn select(
+        &self,
+        classes: &[Class],
+        default_selection: impl Fn(&str) -> bool,
+        required: &[&str],
+    ) -> HashSet<String> {
+        let by_name: HashMap<&str, &Class> = classes
+            .iter()
+            .map(|class| (class.name.as_str(), class))
+            .collect();
+
+        let check_class = |name: &String| {
+            assert!(
+                by_name.contains_key(name.as_str()),
+                "codegen config: unknown class `{name}` (use the Godot name, e.g. `HTTPRequest`)"
+            );
+        };
+        self.include_classes.iter().for_each(check_class);
+        self.exclude_classes.iter().for_each(check_class);
+
+        let is_in_areas =
+            |class: &Class, areas: &[String]| {
+                areas.iter().any(|area| {
+                    let (_, belongs) = AREAS.iter().find(|(name, _)| name == area).unwrap_or_else(
+                        || {
+                            let known: Vec<_> = AREAS.iter().map(|(name, _)| *name).collect();
+                            panic!(
+                                "codegen config: unknown area `{area}`, expected one of {known:?}"
+                            )
+                        },
+                    );
+                    belongs(class)
+                })
+            };
+
+        // An allowlist replaces the default selection, which would otherwise contain every class with `codegen-full`.
+        let is_allowlist = !self.include_classes.is_empty() || !self.include_areas.is_empty();
+
+        let mut selected: HashSet<String> = classes
+            .iter()
+            .filter(|class| {
+                let included = if is_allowlist {
+                    self.include_classes.contains(&class.name)
+                        || is_in_areas(class, &self.include_areas)
+                } else {
+                    default_selection(&class.name)
+                };
+                let excluded = self.exclude_classes.contains(&class.name)
+                    || is_in_areas(class, &self.exclude_areas);
+
+                included && !excluded
+            })
+            .map(|class| class.name.clone())
+            .collect();
+
+        selected.extend(
+            required
+                .iter()
+                .filter(|name| by_name.contains_key(*name))
+                .map(|name| name.to_string()),
+        );
+
+        // Dependency closure: generated classes deref to their base.
+        let mut pending: Vec<String> = selected.iter().cloned().collect();
+        while let Some(name) = pending.pop() {
+            let base = by_name
+                .get(name.as_str())
+                .and_then(|class| class.inherits.as_ref());
+            if let Some(base) = base {
+                if selected.insert(base.clone()) {
+                    pending.push(base.clone());
+                }
+            }
+        }
+
+        selected
+    }
+}
+
+/// Removes a `#` comment from `line`, ignoring `#` inside strings.
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (index, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..index],
+            _ => {}
+        }
+    }
+
+    line
+}
+
+/// Parses `["a", "b"]`, allowing a trailing comma.
+fn parse_string_array(value: &str) -> Option<Vec<String>> {
+    let inner = value.strip_prefix('[')?.strip_suffix(']')?;
+
+    inner
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(|item| {
+            let item = item.strip_prefix('"')?.strip_suffix('"')?;
+            Some(item.to_string())
+        })
+        .collect()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// API areas
+
+/// Whether a class belongs to an area.
+type AreaFilter = fn(&Class) -> bool;
+
+/// Named groups of classes, usable in `areas = [...]`.
+const AREAS: &[(&str, AreaFilter)] = &[
+    ("editor", |class| class.api_type == "editor"),
+    ("xr", |class| {
+        ["XR", "OpenXR", "WebXR", "MobileVR"]
+            .iter()
+            .any(|prefix| class.name.starts_with(prefix))
+    }),
+    ("physics_2d", |class| is_physics(&class.name, "2D")),
+    ("physics_3d", |class| is_physics(&class.name, "3D")),
+    ("navigation", |class| class.name.starts_with("Navigation")),
+    ("audio", |class| class.name.starts_with("Audio")),
+    ("gltf", |class| class.name.starts_with("GLTF")),
+    ("visual_shader", |class| {
+        class.name.starts_with("VisualShader")
+    }),
+];
+
+fn is_physics(name: &str, dimension: &str) -> bool {
+    const PREFIXES: &[&str] = &[
+        "Physics",
+        "Area",
+        "CharacterBody",
+        "CollisionObject",
+        "CollisionPolygon",
+        "CollisionShape",
+        "AnimatableBody",
+        "KinematicCollision",
+        "PhysicalBone",
+        "RayCast",
+        "RigidBody",
+        "ShapeCast",
+        "SoftBody",
+        "SpringArm",
+        "StaticBody",
+        "VehicleBody",
+        "VehicleWheel",
+        "Joint",
+        "PinJoint",
+        "HingeJoint",
+        "SliderJoint",
+        "ConeTwistJoint",
+        "Generic6DOFJoint",
+        "GrooveJoint",
+        "DampedSpringJoint",
+    ];
+
+    // Shapes: `Shape3D`, `BoxShape3D`, `ConvexPolygonShape2D`, ...
+    let is_shape = name.ends_with(&format!("Shape{dimension}"));
+
+    name.contains(dimension) && (is_shape || PREFIXES.iter().any(|p| name.starts_with(p)))
+}
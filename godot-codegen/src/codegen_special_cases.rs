@@ -4,11 +4,11 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-//! Codegen-dependent exclusions. Can be removed if feature `codegen-full` is removed.
+//! Codegen-dependent exclusions, depending on feature `codegen-full` and the user's codegen config file.
 
-use crate::api_parser::{BuiltinClassMethod, ClassMethod, UtilityFunction};
+use crate::api_parser::{BuiltinClassMethod, Class, ClassMethod, UtilityFunction};
 use crate::context::Context;
-use crate::{special_cases, TyName};
+use crate::{codegen_filter, special_cases, TyName};
 
 pub(crate) fn is_builtin_method_excluded(method: &BuiltinClassMethod) -> bool {
     // Builtin class methods that need varcall are not currently available in GDExtension.
@@ -16,17 +16,32 @@ pub(crate) fn is_builtin_method_excluded(method: &BuiltinClassMethod) -> bool {
     method.is_vararg
 }
 
-#[cfg(not(feature = "codegen-full"))]
+/// Reads the codegen config file, if the user provided one. Must be called before any classes are checked for exclusion.
+pub(crate) fn init_class_selection(classes: &[Class]) {
+    codegen_filter::init(
+        classes,
+        |class| !is_class_excluded_by_feature(class),
+        SELECTED_CLASSES,
+    );
+}
+
 pub(crate) fn is_class_excluded(class: &str) -> bool {
+    match codegen_filter::selected_classes() {
+        Some(selected) => !selected.contains(class),
+        None => is_class_excluded_by_feature(class),
+    }
+}
+
+#[cfg(not(feature = "codegen-full"))]
+fn is_class_excluded_by_feature(class: &str) -> bool {
     !SELECTED_CLASSES.contains(&class)
 }
 
 #[cfg(feature = "codegen-full")]
-pub(crate) fn is_class_excluded(_class: &str) -> bool {
+fn is_class_excluded_by_feature(_class: &str) -> bool {
     false
 }
 
-#[cfg(not(feature = "codegen-full"))]
 fn is_type_excluded(ty: &str, ctx: &mut Context) -> bool {
     use crate::{util, RustTy};
 
@@ -53,17 +68,8 @@ pub(crate) fn is_method_excluded(
     is_virtual_impl: bool,
     ctx: &mut Context,
 ) -> bool {
-    let is_arg_or_return_excluded = |ty: &str, _ctx: &mut Context| {
-        let class_deleted = special_cases::is_class_deleted(&TyName::from_godot(ty));
-
-        #[cfg(not(feature = "codegen-full"))]
-        {
-            class_deleted || is_type_excluded(ty, _ctx)
-        }
-        #[cfg(feature = "codegen-full")]
-        {
-            class_deleted
-        }
+    let is_arg_or_return_excluded = |ty: &str, ctx: &mut Context| {
+        special_cases::is_class_deleted(&TyName::from_godot(ty)) || is_type_excluded(ty, ctx)
     };
 
     // Exclude if return type contains an excluded type.
@@ -89,12 +95,6 @@ pub(crate) fn is_method_excluded(
     false
 }
 
-#[cfg(feature = "codegen-full")]
-pub(crate) fn is_function_excluded(_function: &UtilityFunction, _ctx: &mut Context) -> bool {
-    false
-}
-
-#[cfg(not(feature = "codegen-full"))]
 pub(crate) fn is_function_excluded(function: &UtilityFunction, ctx: &mut Context) -> bool {
     function
         .return_type
@@ -109,10 +109,10 @@ pub(crate) fn is_function_excluded(function: &UtilityFunction, ctx: &mut Context
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Allowed-classes
 
-// Classes for minimal config
-#[cfg(not(feature = "codegen-full"))]
+// Classes for minimal config. These are also generated if a codegen config file excludes them, since gdext itself uses them.
 const SELECTED_CLASSES: &[&str] = &[
     "AnimatedSprite2D",
+    "Animation",
     "ArrayMesh",
     "Area2D",
     "AudioStreamPlayer",
@@ -126,30 +126,70 @@ const SELECTED_CLASSES: &[&str] = &[
     "ClassDB",
     "CollisionObject2D",
     "CollisionShape2D",
+    "ConfigFile",
     "Control",
+    "Curve",
+    "Curve2D",
+    "Curve3D",
+    "DirAccess",
+    "DisplayServer",
     "EditorPlugin",
+    "EditorUndoRedoManager",
     "Engine",
+    "EngineDebugger",
+    "EngineProfiler",
+    "FastNoiseLite",
     "FileAccess",
+    "Font",
+    "Gradient",
+    "HTTPClient",
     "HTTPRequest",
     "Image",
     "ImageTextureLayered",
     "Input",
     "InputEvent",
     "InputEventAction",
+    "InputEventFromWindow",
+    "InputEventJoypadButton",
+    "InputEventJoypadMotion",
+    "InputEventKey",
+    "InputEventMouse",
+    "InputEventMouseButton",
+    "InputEventWithModifiers",
+    "InputMap",
+    "JSON",
     "Label",
     "MainLoop",
     "Marker2D",
+    "Material",
     "Mesh",
+    "MultiMesh",
+    "NavigationAgent2D",
+    "NavigationAgent3D",
+    "NavigationServer2D",
+    "NavigationServer3D",
     "Node",
     "Node2D",
     "Node3D",
     "Node3DGizmo",
+    "Noise",
     "Object",
     "OS",
     "PackedScene",
     "PathFollow2D",
+    "Performance",
     "PhysicsBody2D",
+    "PhysicsDirectSpaceState2D",
+    "PhysicsDirectSpaceState3D",
+    "PhysicsPointQueryParameters2D",
+    "PhysicsPointQueryParameters3D",
+    "PhysicsRayQueryParameters2D",
+    "PhysicsRayQueryParameters3D",
+    "PhysicsShapeQueryParameters2D",
+    "PhysicsShapeQueryParameters3D",
     "PrimitiveMesh",
+    "ProjectSettings",
+    "RandomNumberGenerator",
     "RefCounted",
     "RenderingServer",
     "Resource",
@@ -157,15 +197,31 @@ const SELECTED_CLASSES: &[&str] = &[
     "ResourceLoader",
     "RigidBody2D",
     "SceneTree",
+    "SceneTreeTimer",
+    "Script",
+    "ScriptExtension",
+    "ScriptLanguage",
+    "Shader",
+    "ShaderMaterial",
+    "Shape2D",
+    "Shape3D",
     "Sprite2D",
     "SpriteFrames",
+    "StyleBox",
+    "StyleBoxFlat",
     "TextServer",
     "TextServerExtension",
     "Texture",
+    "Texture2D",
     "Texture2DArray",
     "TextureLayered",
     "Time",
     "Timer",
+    "Translation",
+    "TranslationServer",
+    "UndoRedo",
     "Window",
+    "World2D",
+    "World3D",
     "Viewport",
 ];
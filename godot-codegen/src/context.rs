@@ -19,7 +19,7 @@ pub(crate) struct Context<'a> {
     singletons: HashSet<&'a str>,
     inheritance_tree: InheritanceTree,
     cached_rust_types: HashMap<GodotTy, RustTy>,
-    notifications_by_class: HashMap<TyName, Vec<(Ident, i32)>>,
+    notifications_by_class: HashMap<TyName, Vec<NotificationConstant>>,
     notification_enum_names_by_class: HashMap<TyName, NotificationEnum>,
     method_table_indices: HashMap<MethodTableKey, usize>,
     method_table_next_index: HashMap<String, usize>,
@@ -50,6 +50,8 @@ impl<'a> Context<'a> {
             ctx.native_structures_types.insert(ty_name);
         }
 
+        codegen_special_cases::init_class_selection(&api.classes);
+
         for class in api.classes.iter() {
             let class_name = TyName::from_godot(&class.name);
 
@@ -148,7 +150,11 @@ impl<'a> Context<'a> {
                 ctx.notifications_by_class
                     .get_mut(class_name)
                     .expect("just inserted constants; must be present")
-                    .push((rust_constant, constant.value));
+                    .push(NotificationConstant {
+                        rust_name: rust_constant,
+                        godot_name: constant.name.clone(),
+                        value: constant.value,
+                    });
             }
         }
     }
@@ -263,7 +269,10 @@ impl<'a> Context<'a> {
         self.cached_rust_types.get(ty)
     }
 
-    pub fn notification_constants(&'a self, class_name: &TyName) -> Option<&Vec<(Ident, i32)>> {
+    pub fn notification_constants(
+        &'a self,
+        class_name: &TyName,
+    ) -> Option<&Vec<NotificationConstant>> {
         self.notifications_by_class.get(class_name)
     }
 
@@ -322,6 +331,18 @@ impl ToTokens for NotificationEnum {
     }
 }
 
+/// Single `NOTIFICATION_*` constant, later mapped to an enumerator of a notification enum.
+#[derive(Clone)]
+pub struct NotificationConstant {
+    /// Name of the enumerator, e.g. `Ready`.
+    pub rust_name: Ident,
+
+    /// Name of the constant in Godot, e.g. `NOTIFICATION_READY`.
+    pub godot_name: String,
+
+    pub value: i32,
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 /// Maintains class hierarchy. Uses Rust class names, not Godot ones.
@@ -7,9 +7,11 @@
 mod api_parser;
 mod central_generator;
 mod class_generator;
+mod codegen_filter;
 mod codegen_special_cases;
 mod context;
 mod interface_generator;
+mod report;
 mod special_cases;
 mod util;
 mod utilities_generator;
@@ -45,8 +47,10 @@ fn write_file(path: &Path, contents: String) {
     let dir = path.parent().unwrap();
     let _ = std::fs::create_dir_all(dir);
 
-    std::fs::write(path, contents)
+    std::fs::write(path, &contents)
         .unwrap_or_else(|e| panic!("failed to write code file to {};\n\t{}", path.display(), e));
+
+    report::record_file(path, &contents);
 }
 
 #[cfg(feature = "codegen-fmt")]
@@ -87,6 +91,8 @@ pub fn generate_sys_files(
     let is_godot_4_0 = api.header.version_major == 4 && api.header.version_minor == 0;
     generate_sys_interface_file(h_path, sys_gen_path, is_godot_4_0, &mut submit_fn);
     watch.record("generate_interface_file");
+
+    report::print_if_enabled("godot-ffi", watch);
 }
 
 pub fn generate_core_files(core_gen_path: &Path) {
@@ -133,6 +139,7 @@ pub fn generate_core_files(core_gen_path: &Path) {
     );
     watch.record("generate_native_structures_files");
 
+    report::print_if_enabled("godot-core", &watch);
     watch.write_stats_to(&core_gen_path.join("codegen-stats.txt"));
 }
 
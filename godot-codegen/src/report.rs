@@ -0,0 +1,142 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Opt-in summary of what codegen produced and how long it took, enabled through the `GDEXT_CODEGEN_REPORT` environment variable.
+//!
+//! Build scripts' regular output is hidden by Cargo, so the report is emitted as `cargo:warning` lines.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+use std::sync::Mutex;
+
+pub(crate) const REPORT_ENV_VAR: &str = "GDEXT_CODEGEN_REPORT";
+
+/// Each build script runs codegen in its own process, so process-wide state covers exactly one crate's generated files.
+static REPORT: Mutex<Report> = Mutex::new(Report::new());
+
+/// Counts a file written by codegen, grouped by its directory below the generated root (`classes`, `native`, ...).
+pub(crate) fn record_file(path: &Path, contents: &str) {
+    lock().record_file(path, contents);
+}
+
+/// Records how many of the API's engine classes were generated.
+pub(crate) fn record_classes(generated: usize, total: usize) {
+    lock().classes = Some((generated, total));
+}
+
+/// Prints the report for `crate_name`, if enabled.
+pub(crate) fn print_if_enabled(crate_name: &str, watch: &godot_bindings::StopWatch) {
+    println!("cargo:rerun-if-env-changed={REPORT_ENV_VAR}");
+    if std::env::var_os(REPORT_ENV_VAR).is_none() {
+        return;
+    }
+
+    let text = lock().format(crate_name, watch);
+    for line in text.lines() {
+        println!("cargo:warning={line}");
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+/// Output directories listed separately; other files are counted under `.`.
+const GROUPS: &[&str] = &["classes", "builtin_classes", "native"];
+
+#[derive(Default)]
+struct Output {
+    files: usize,
+    lines: usize,
+    bytes: usize,
+}
+
+pub(crate) struct Report {
+    classes: Option<(usize, usize)>,
+    outputs: BTreeMap<String, Output>,
+}
+
+impl Report {
+    pub const fn new() -> Self {
+        Self {
+            classes: None,
+            outputs: BTreeMap::new(),
+        }
+    }
+
+    pub fn record_file(&mut self, path: &Path, contents: &str) {
+        let group = path
+            .parent()
+            .and_then(Path::file_name)
+            .map(|dir| dir.to_string_lossy().into_owned())
+            .filter(|dir| GROUPS.contains(&dir.as_str()))
+            .unwrap_or_else(|| ".".to_string());
+
+        let output = self.outputs.entry(group).or_default();
+        output.files += 1;
+        output.lines += contents.lines().count();
+        output.bytes += contents.len();
+    }
+
+    pub fn format(&self, crate_name: &str, watch: &godot_bindings::StopWatch) -> String {
+        let mut text = String::new();
+        let _ = writeln!(text, "gdext codegen report for {crate_name}:");
+
+        if let Some((generated, total)) = self.classes {
+            let selection = if crate::codegen_filter::selected_classes().is_some() {
+                "selected by codegen config"
+            } else if cfg!(feature = "codegen-full") {
+                "codegen-full"
+            } else {
+                "minimal set"
+            };
+            let _ = writeln!(
+                text,
+                "  engine classes: {generated} of {total} generated ({selection})"
+            );
+        }
+
+        let mut total = Output::default();
+        for (group, output) in self.outputs.iter() {
+            let _ = writeln!(
+                text,
+                "  {group: <16} {: >5} files {: >9} lines {: >8} KiB",
+                output.files,
+                output.lines,
+                output.bytes / 1024
+            );
+            total.files += output.files;
+            total.lines += output.lines;
+            total.bytes += output.bytes;
+        }
+        let _ = writeln!(
+            text,
+            "  {: <16} {: >5} files {: >9} lines {: >8} KiB",
+            "total",
+            total.files,
+            total.lines,
+            total.bytes / 1024
+        );
+
+        if !cfg!(feature = "codegen-fmt") {
+            let _ = writeln!(
+                text,
+                "  (without the codegen-fmt feature, each file is emitted as one line)"
+            );
+        }
+
+        let _ = writeln!(text, "  phases:");
+        for line in watch.to_string().lines() {
+            let _ = writeln!(text, "    {line}");
+        }
+
+        text
+    }
+}
+
+fn lock() -> std::sync::MutexGuard<'static, Report> {
+    REPORT.lock().unwrap_or_else(|e| e.into_inner())
+}
@@ -184,6 +184,38 @@ pub(crate) fn is_excluded_from_default_params(class_name: Option<&TyName>, godot
     }
 }
 
+/// Whether a `float` parameter of a virtual method is exposed as `real` instead of `f64`.
+///
+/// Godot always passes frame deltas as `double`. Typing them as `real` lets user code multiply them with vectors and other `real`
+/// values without casts, for both single- and double-precision builds. `f32` parameters are converted from `double` by the callback.
+///
+/// Other `float` parameters of virtual methods, and `float` properties, keep the type declared by Godot (mostly `f64`): many of
+/// them, such as stream positions in seconds, need the full precision even in single-precision builds.
+pub(crate) fn is_virtual_param_real(godot_method_name: &str, param_name: &str) -> bool {
+    matches!(
+        (godot_method_name, param_name),
+        ("_process" | "_physics_process", "delta")
+    )
+}
+
+/// Whether the ordinals of an enum express a meaningful order, so that `PartialOrd`/`Ord` can be derived.
+///
+/// Most enums are just sets of named values; comparing e.g. two `BlendMode`s would compile but mean nothing.
+#[rustfmt::skip]
+pub(crate) fn is_enum_ordered(enum_name: &str) -> bool {
+    match enum_name {
+        // Severity/progression, from OK to increasingly specific failures.
+        | "Error"
+        // Node processing, from inheriting to always/never processing.
+        | "ProcessMode"
+        // Quality levels with increasing cost.
+        | "MSAA"
+        | "AnisotropicFiltering"
+
+        => true, _ => false
+    }
+}
+
 /// True if builtin method is excluded. Does NOT check for type exclusion; use [`is_builtin_type_deleted`] for that.
 pub(crate) fn is_builtin_deleted(_class_name: &TyName, method: &BuiltinClassMethod) -> bool {
     // Currently only deleted if codegen.
@@ -4,10 +4,16 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use crate::api_parser::Class;
+use crate::codegen_filter::CodegenConfig;
+use crate::report::Report;
 use crate::util::{
-    parse_native_structures_format, to_pascal_case, to_snake_case, NativeStructuresField,
+    ident, make_doc_alias, parse_native_structures_format, to_pascal_case, to_snake_case,
+    NativeStructuresField,
 };
 
+use std::path::Path;
+
 #[test]
 fn test_pascal_conversion() {
     // More in line with Rust identifiers, and eases recognition of other automation (like enumerator mapping).
@@ -208,3 +214,122 @@ fn test_parse_native_structures_format() {
     ];
     assert_eq!(actual.unwrap(), expected);
 }
+
+#[test]
+fn test_doc_alias() {
+    assert!(make_doc_alias("get_name", &ident("get_name")).is_empty());
+
+    let alias = make_doc_alias("_ready", &ident("ready")).to_string();
+    assert!(alias.contains("alias"), "{alias}");
+    assert!(alias.contains(r#""_ready""#), "{alias}");
+}
+
+#[test]
+fn test_codegen_config_parse() {
+    let config = CodegenConfig::parse(
+        r#"
+        # Only what the game needs.
+        [include]
+        classes = ["Sprite2D", "AnimationPlayer"]
+        areas = [
+            "physics_2d", # collision only
+        ]
+
+        [exclude]
+        areas = ["editor"]
+        "#,
+    )
+    .unwrap();
+
+    let strings = |items: &[&str]| items.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        config,
+        CodegenConfig {
+            include_classes: strings(&["Sprite2D", "AnimationPlayer"]),
+            include_areas: strings(&["physics_2d"]),
+            exclude_classes: vec![],
+            exclude_areas: strings(&["editor"]),
+        }
+    );
+
+    assert!(CodegenConfig::parse("classes = []").is_err());
+    assert!(CodegenConfig::parse("[other]").is_err());
+    assert!(CodegenConfig::parse("[include]\nnodes = []").is_err());
+    assert!(CodegenConfig::parse("[include]\nclasses = [Node]").is_err());
+    assert!(CodegenConfig::parse("[include]\nclasses = [\"Node\",").is_err());
+
+    // `#` only starts a comment outside of strings.
+    let config = CodegenConfig::parse("[include]\nclasses = [\"A#B\"] # comment").unwrap();
+    assert_eq!(config.include_classes, ["A#B"]);
+}
+
+#[test]
+fn test_codegen_config_select() {
+    let class = |name: &str, inherits: Option<&str>, api_type: &str| Class {
+        name: name.to_string(),
+        is_refcounted: false,
+        is_instantiable: true,
+        inherits: inherits.map(str::to_string),
+        api_type: api_type.to_string(),
+        constants: None,
+        enums: None,
+        methods: None,
+    };
+    let classes = [
+        class("Object", None, "core"),
+        class("Node", Some("Object"), "core"),
+        class("Node2D", Some("Node"), "core"),
+        class("CollisionObject2D", Some("Node2D"), "core"),
+        class("Area2D", Some("CollisionObject2D"), "core"),
+        class("EditorPlugin", Some("Node"), "editor"),
+        class("Timer", Some("Node"), "core"),
+    ];
+
+    let config = CodegenConfig::parse(
+        r#"
+        [include]
+        areas = ["physics_2d", "editor"]
+        [exclude]
+        classes = ["EditorPlugin", "Timer", "Object"]
+        "#,
+    )
+    .unwrap();
+
+    let selected = config.select(&classes, |name| name == "Timer", &["Node"]);
+    let mut selected: Vec<_> = selected.iter().map(String::as_str).collect();
+    selected.sort();
+
+    // `Area2D` pulls in its base classes, including the excluded `Object`; required `Node` is kept.
+    assert_eq!(
+        selected,
+        ["Area2D", "CollisionObject2D", "Node", "Node2D", "Object"]
+    );
+
+    // A non-empty `[include]` replaces the default selection, even if that accepts every class.
+    let config = CodegenConfig::parse("[include]\nclasses = [\"Timer\"]").unwrap();
+    let selected = config.select(&classes, |_| true, &[]);
+    let mut selected: Vec<_> = selected.iter().map(String::as_str).collect();
+    selected.sort();
+
+    assert_eq!(selected, ["Node", "Object", "Timer"]);
+}
+
+#[test]
+fn test_codegen_report() {
+    let mut report = Report::new();
+    report.record_file(Path::new("gen/classes/node.rs"), "line 1\nline 2\n");
+    report.record_file(Path::new("gen/classes/mod.rs"), "line 1\n");
+    report.record_file(Path::new("gen/central.rs"), "line 1\n");
+
+    let text = report.format("godot-core", &godot_bindings::StopWatch::start());
+    let row = |name: &str| {
+        text.lines()
+            .map(|line| line.split_whitespace().collect::<Vec<_>>())
+            .find(|words| words.first() == Some(&name))
+            .unwrap_or_else(|| panic!("no row `{name}` in report:\n{text}"))
+    };
+
+    assert_eq!(row("classes")[1..5], ["2", "files", "3", "lines"]);
+    assert_eq!(row(".")[1..5], ["1", "files", "1", "lines"]);
+    assert_eq!(row("total")[1..5], ["3", "files", "4", "lines"]);
+}
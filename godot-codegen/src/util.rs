@@ -7,7 +7,7 @@
 use crate::api_parser::{
     BuiltinClassMethod, Class, ClassConstant, ClassMethod, Enum, UtilityFunction,
 };
-use crate::special_cases::is_builtin_scalar;
+use crate::special_cases::{is_builtin_scalar, is_enum_ordered};
 use crate::{Context, GodotTy, ModName, RustTy, TyName};
 
 use proc_macro2::{Ident, Literal, TokenStream};
@@ -236,8 +236,10 @@ pub fn make_enum_definition(enum_: &Enum) -> TokenStream {
     for enumerator in values {
         let name = make_enumerator_name(&enumerator.name, &enum_.name);
         let ordinal = make_enumerator_ord(enumerator.value);
+        let doc_alias = make_doc_alias(&enumerator.name, &name);
 
         enumerators.push(quote! {
+            #doc_alias
             pub const #name: Self = Self { ord: #ordinal };
         });
         // matches.push(quote! {
@@ -250,6 +252,12 @@ pub fn make_enum_definition(enum_: &Enum) -> TokenStream {
     unique_ords.sort();
     unique_ords.dedup();
 
+    let bitfield_flags = if enum_.is_bitfield {
+        make_bitfield_flags(enum_, &enum_name)
+    } else {
+        TokenStream::new()
+    };
+
     let bitfield_ops = if enum_.is_bitfield {
         let tokens = quote! {
             // impl #enum_name {
@@ -262,6 +270,8 @@ pub fn make_enum_definition(enum_: &Enum) -> TokenStream {
                     Self { ord: self.ord | rhs.ord }
                 }
             }
+
+            #bitfield_flags
         };
 
         Some(tokens)
@@ -286,12 +296,27 @@ pub fn make_enum_definition(enum_: &Enum) -> TokenStream {
         }
     };
 
-    let mut derives = vec!["Copy", "Clone", "Eq", "PartialEq", "Debug", "Hash"];
+    let mut derives = vec!["Copy", "Clone", "Eq", "PartialEq", "Hash"];
 
     if enum_.is_bitfield {
+        // Debug is implemented manually, listing the set flags.
+        // Empty bitmask.
         derives.push("Default");
+    } else {
+        derives.push("Debug");
+
+        if is_enum_ordered(&enum_.name) {
+            derives.push("PartialOrd");
+            derives.push("Ord");
+        }
     }
 
+    let default_impl = if enum_.is_bitfield {
+        TokenStream::new()
+    } else {
+        make_enum_default_impl(enum_, &enum_name)
+    };
+
     let index_enum_impl = if let Some(enum_max) = try_count_index_enum(enum_) {
         quote! {
             impl crate::obj::IndexEnum for #enum_name {
@@ -335,6 +360,7 @@ pub fn make_enum_definition(enum_: &Enum) -> TokenStream {
             }
         }
         #index_enum_impl
+        #default_impl
 
         impl crate::builtin::meta::GodotConvert for #enum_name {
             type Via = i32;
@@ -356,6 +382,98 @@ pub fn make_enum_definition(enum_: &Enum) -> TokenStream {
     }
 }
 
+/// `iter()` and `Debug` for bitfields, based on the enumerators that represent a single bit.
+fn make_bitfield_flags(enum_: &Enum, enum_name: &Ident) -> TokenStream {
+    // Combined masks (e.g. `KEY_MODIFIER_MASK`) are not flags of their own. If several enumerators share the same bit, the first one wins.
+    let mut flag_values = Vec::new();
+    let mut flag_names = Vec::new();
+    let mut flag_strs = Vec::new();
+    for enumerator in enum_.values.iter() {
+        if enumerator.value.count_ones() != 1 || flag_values.contains(&enumerator.value) {
+            continue;
+        }
+
+        flag_values.push(enumerator.value);
+        flag_names.push(make_enumerator_name(&enumerator.name, &enum_.name));
+        flag_strs.push(enumerator.name.as_str());
+    }
+
+    let zero_str = enum_
+        .values
+        .iter()
+        .find(|enumerator| enumerator.value == 0)
+        .map_or("0", |enumerator| enumerator.name.as_str());
+
+    quote! {
+        impl #enum_name {
+            /// Returns an iterator over the individual flags set in `self`.
+            ///
+            /// Bits that do not correspond to a known flag are skipped.
+            pub fn iter(self) -> impl Iterator<Item = Self> {
+                const FLAGS: &[#enum_name] = &[ #( #enum_name::#flag_names ),* ];
+
+                FLAGS.iter().copied().filter(move |flag| self.ord & flag.ord != 0)
+            }
+        }
+
+        impl std::fmt::Debug for #enum_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                const NAMES: &[(i32, &str)] = &[ #( (#flag_values, #flag_strs) ),* ];
+
+                if self.ord == 0 {
+                    return f.write_str(#zero_str);
+                }
+
+                let mut remaining = self.ord;
+                let mut first = true;
+                for &(bit, name) in NAMES {
+                    if self.ord & bit == 0 {
+                        continue;
+                    }
+
+                    if !first {
+                        f.write_str(" | ")?;
+                    }
+                    f.write_str(name)?;
+                    remaining &= !bit;
+                    first = false;
+                }
+
+                if remaining != 0 {
+                    if !first {
+                        f.write_str(" | ")?;
+                    }
+                    write!(f, "{remaining:#x}")?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+/// `Default` for non-bitfield enums: the enumerator with ordinal 0 (usually the engine's default), otherwise the first declared one.
+fn make_enum_default_impl(enum_: &Enum, enum_name: &Ident) -> TokenStream {
+    let default = enum_
+        .values
+        .iter()
+        .find(|enumerator| enumerator.value == 0)
+        .or_else(|| enum_.values.first());
+
+    let Some(default) = default else {
+        return TokenStream::new();
+    };
+
+    let default_name = make_enumerator_name(&default.name, &enum_.name);
+    quote! {
+        impl Default for #enum_name {
+            fn default() -> Self {
+                Self::#default_name
+            }
+        }
+    }
+}
+
 pub fn make_constant_definition(constant: &ClassConstant) -> TokenStream {
     let ClassConstant { name, value } = constant;
     let name = ident(name);
@@ -517,6 +635,15 @@ pub fn safe_ident(s: &str) -> Ident {
     }
 }
 
+/// Emits `#[doc(alias = "...")]` if the Rust name of an item differs from its Godot name, so rustdoc search finds both.
+pub(crate) fn make_doc_alias(godot_name: &str, rust_name: &Ident) -> TokenStream {
+    if rust_name == godot_name {
+        TokenStream::new()
+    } else {
+        quote! { #[doc(alias = #godot_name)] }
+    }
+}
+
 /// Converts a potential "meta" type (like u32) to its canonical type (like i64).
 ///
 /// Avoids dragging along the meta type through [`RustTy::BuiltinIdent`].
@@ -541,25 +668,41 @@ pub(crate) fn unmap_meta(rust_ty: &RustTy) -> Option<Ident> {
     None
 }
 
+/// Godot types with argument metadata, as `(type, meta, Rust type)`.
+///
+/// Also emitted into the generated sys code, for `godot_ffi::layout`.
+pub(crate) const META_TYPES: &[(&str, &str, &str)] = &[
+    ("int", "int64", "i64"),
+    ("int", "int32", "i32"),
+    ("int", "int16", "i16"),
+    ("int", "int8", "i8"),
+    ("int", "uint64", "u64"),
+    ("int", "uint32", "u32"),
+    ("int", "uint16", "u16"),
+    ("int", "uint8", "u8"),
+    ("float", "double", "f64"),
+    ("float", "float", "f32"),
+];
+
 fn to_hardcoded_rust_ident(full_ty: &GodotTy) -> Option<&str> {
     let ty = full_ty.ty.as_str();
     let meta = full_ty.meta.as_deref();
 
+    if let Some(meta) = meta {
+        let mapped = META_TYPES
+            .iter()
+            .find(|(godot_ty, godot_meta, _)| *godot_ty == ty && *godot_meta == meta);
+
+        if let Some((_, _, rust_ty)) = mapped {
+            return Some(*rust_ty);
+        }
+    }
+
     let result = match (ty, meta) {
-        // Integers
-        ("int", Some("int64") | None) => "i64",
-        ("int", Some("int32")) => "i32",
-        ("int", Some("int16")) => "i16",
-        ("int", Some("int8")) => "i8",
-        ("int", Some("uint64")) => "u64",
-        ("int", Some("uint32")) => "u32",
-        ("int", Some("uint16")) => "u16",
-        ("int", Some("uint8")) => "u8",
+        // Integers and floats (with single precision builds); see META_TYPES for those with metadata
+        ("int", None) => "i64",
         ("int", Some(meta)) => panic!("unhandled type int with meta {meta:?}"),
-
-        // Floats (with single precision builds)
-        ("float", Some("double") | None) => "f64",
-        ("float", Some("float")) => "f32",
+        ("float", None) => "f64",
         ("float", Some(meta)) => panic!("unhandled type float with meta {meta:?}"),
 
         // Doubles (with double precision builds)
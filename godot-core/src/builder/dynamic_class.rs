@@ -0,0 +1,584 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+use sys::interface_fn;
+
+use crate::builtin::meta::{ClassName, GodotType, PropertyInfo};
+use crate::builtin::{GodotString, StringName, Variant, VariantType};
+use crate::engine::global::{MethodFlags, PropertyHint, PropertyUsageFlags};
+use crate::engine::Object;
+use crate::init::InitLevel;
+use crate::log;
+use crate::obj::{dom, Base, EngineEnum, Gd, GodotClass};
+
+use std::cell::RefCell;
+use std::ffi::c_void;
+use std::fmt;
+use std::panic::AssertUnwindSafe;
+use std::sync::Arc;
+
+type DynamicMethodFn =
+    dyn Fn(&mut DynamicInstance, &[&Variant]) -> Result<Variant, ()> + Send + Sync;
+
+/// Registers a class whose methods, properties and signals are only known at runtime.
+///
+/// `#[derive(GodotClass)]` requires the shape of a class to be known at compile time. Data-driven systems -- exposing the component
+/// types of an ECS, or bridging to another scripting language -- instead build classes from runtime information. Such classes look
+/// like any other class to Godot: they can be instantiated with `ClassDB.instantiate()` or `.new()` in GDScript, and their members
+/// show up in the editor.
+///
+/// Each instance stores the values of its properties in a [`DynamicInstance`], which is passed to method implementations. Methods
+/// take and return [`Variant`]s; properties are registered together with `get_<name>`/`set_<name>` accessor methods, and only
+/// accept values of the type of their default value.
+///
+/// Classes must be registered during [`ExtensionLibrary::on_level_init()`][crate::init::ExtensionLibrary::on_level_init], at
+/// the initialization level of their base class ([`InitLevel::Scene`] for most classes). They are unregistered together with the
+/// other classes of the extension.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::init::DynamicClassBuilder;
+///
+/// fn register_component(name: &str, fields: &[(&str, Variant)]) {
+///     let mut builder = DynamicClassBuilder::new::<RefCounted>(name);
+///     for (field, default) in fields {
+///         builder = builder.property(field, default.clone());
+///     }
+///
+///     builder
+///         .method("describe", &[], |instance, _args| {
+///             Ok(format!("component {}", instance.class_name()).to_variant())
+///         })
+///         .signal("changed", &[])
+///         .register();
+/// }
+/// ```
+pub struct DynamicClassBuilder {
+    class_name: ClassName,
+    base_class_name: ClassName,
+    init_level: InitLevel,
+    methods: Vec<DynamicMethod>,
+    properties: Vec<DynamicProperty>,
+    signals: Vec<DynamicSignal>,
+}
+
+impl DynamicClassBuilder {
+    /// Starts a class named `class_name`, inheriting the engine class `B`.
+    ///
+    /// # Panics
+    /// If `class_name` is not a valid identifier.
+    pub fn new<B>(class_name: &str) -> Self
+    where
+        B: GodotClass<Declarer = dom::EngineDomain>,
+    {
+        Self::new_with_base(
+            class_name,
+            B::class_name(),
+            B::INIT_LEVEL.unwrap_or(InitLevel::Scene),
+        )
+    }
+
+    /// Like [`new()`][Self::new], for a base class only known by name. The caller ensures that it is an engine class.
+    pub(crate) fn new_with_base(
+        class_name: &str,
+        base_class_name: ClassName,
+        init_level: InitLevel,
+    ) -> Self {
+        assert!(
+            is_identifier(class_name),
+            "dynamic class name `{class_name}` is not a valid identifier"
+        );
+
+        Self {
+            class_name: ClassName::alloc_leaked(class_name),
+            base_class_name,
+            init_level,
+            methods: Vec::new(),
+            properties: Vec::new(),
+            signals: Vec::new(),
+        }
+    }
+
+    /// Adds a method taking the parameters `param_names` and returning a variant.
+    ///
+    /// Godot reports an error to the caller if the argument count does not match, or if `method` returns `Err`.
+    pub fn method<F>(mut self, name: &str, param_names: &[&str], method: F) -> Self
+    where
+        F: Fn(&mut DynamicInstance, &[&Variant]) -> Result<Variant, ()> + Send + Sync + 'static,
+    {
+        self.methods.push(DynamicMethod {
+            name: name.to_string(),
+            param_names: param_names.iter().map(|param| param.to_string()).collect(),
+            returns_value: true,
+            method: Box::new(method),
+        });
+        self
+    }
+
+    /// Adds a property, whose type is that of `default`.
+    pub fn property(mut self, name: &str, default: Variant) -> Self {
+        let index = self.properties.len();
+        let ty = default.get_type();
+
+        self.properties.push(DynamicProperty {
+            name: name.to_string(),
+            default,
+        });
+
+        self.methods.push(DynamicMethod {
+            name: format!("get_{name}"),
+            param_names: Vec::new(),
+            returns_value: true,
+            method: Box::new(move |instance, _args| Ok(instance.values[index].clone())),
+        });
+        self.methods.push(DynamicMethod {
+            name: format!("set_{name}"),
+            param_names: vec!["value".to_string()],
+            returns_value: false,
+            method: Box::new(move |instance, args| {
+                if !accepts_type(ty, args[0]) {
+                    return Err(());
+                }
+
+                instance.values[index] = args[0].clone();
+                Ok(Variant::nil())
+            }),
+        });
+        self
+    }
+
+    /// Adds a signal with the given parameters. Parameters of type [`VariantType::Nil`] accept any variant.
+    pub fn signal(mut self, name: &str, params: &[(&str, VariantType)]) -> Self {
+        self.signals.push(DynamicSignal {
+            name: name.to_string(),
+            params: params
+                .iter()
+                .map(|(name, ty)| (name.to_string(), *ty))
+                .collect(),
+        });
+        self
+    }
+
+    /// Registers the class with Godot.
+    ///
+    /// # Panics
+    /// If a class with the same name has already been registered by godot-rust, or if Godot rejects the class.
+    pub fn register(self) {
+        let class_name = self.class_name;
+        let class = Arc::new(DynamicClass {
+            class_name,
+            base_class_name: self.base_class_name,
+            methods: self.methods,
+            properties: self.properties,
+        });
+
+        // Godot does not notify when a class is unregistered, so this reference is never released. Instances keep their own
+        // reference, so the class data stays valid for them in any case.
+        let class_userdata = Arc::into_raw(Arc::clone(&class)) as *mut c_void;
+
+        crate::registry::register_dynamic_class(
+            class_name,
+            class.base_class_name,
+            self.init_level,
+            create_instance,
+            free_instance,
+            class_userdata,
+        );
+
+        for method in &class.methods {
+            register_method(class_name, method);
+        }
+
+        for property in &class.properties {
+            register_property(class_name, property);
+        }
+
+        for signal in &self.signals {
+            register_signal(class_name, signal);
+        }
+    }
+}
+
+impl fmt::Debug for DynamicClassBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynamicClassBuilder")
+            .field("class_name", &self.class_name)
+            .field("base_class_name", &self.base_class_name)
+            .finish_non_exhaustive()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// State of an instance of a class registered through [`DynamicClassBuilder`].
+pub struct DynamicInstance {
+    base: Base<Object>,
+    class: Arc<DynamicClass>,
+    values: Vec<Variant>,
+}
+
+impl DynamicInstance {
+    /// The Godot object this instance belongs to.
+    pub fn base(&self) -> &Gd<Object> {
+        &self.base
+    }
+
+    /// The Godot object this instance belongs to, e.g. for emitting signals.
+    pub fn base_mut(&mut self) -> &mut Gd<Object> {
+        &mut self.base
+    }
+
+    /// Name of the dynamic class.
+    pub fn class_name(&self) -> ClassName {
+        self.class.class_name
+    }
+
+    /// Value of the property `name`, or `None` if the class has no such property.
+    pub fn get(&self, name: &str) -> Option<&Variant> {
+        let index = self.class.property_index(name)?;
+        Some(&self.values[index])
+    }
+
+    /// Sets the property `name`.
+    ///
+    /// Returns `false` if the class has no such property, or if `value` does not match the type of the property.
+    pub fn set(&mut self, name: &str, value: Variant) -> bool {
+        let Some(index) = self.class.property_index(name) else {
+            return false;
+        };
+
+        if !accepts_type(self.class.properties[index].default.get_type(), &value) {
+            return false;
+        }
+
+        self.values[index] = value;
+        true
+    }
+}
+
+impl fmt::Debug for DynamicInstance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("DynamicInstance");
+        debug.field("class_name", &self.class.class_name);
+        for (property, value) in self.class.properties.iter().zip(&self.values) {
+            debug.field(&property.name, value);
+        }
+        debug.finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+/// Class data shared by all instances.
+struct DynamicClass {
+    class_name: ClassName,
+    base_class_name: ClassName,
+    methods: Vec<DynamicMethod>,
+    properties: Vec<DynamicProperty>,
+}
+
+impl DynamicClass {
+    fn property_index(&self, name: &str) -> Option<usize> {
+        self.properties
+            .iter()
+            .position(|property| property.name == name)
+    }
+}
+
+struct DynamicMethod {
+    name: String,
+    param_names: Vec<String>,
+    returns_value: bool,
+    method: Box<DynamicMethodFn>,
+}
+
+struct DynamicProperty {
+    name: String,
+    default: Variant,
+}
+
+struct DynamicSignal {
+    name: String,
+    params: Vec<(String, VariantType)>,
+}
+
+/// Extension instance handed to Godot. Instances are borrowed mutably during method calls; re-entrant calls on the same object
+/// fail instead of aliasing.
+type InstanceCell = RefCell<DynamicInstance>;
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+
+    (first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Whether a property of type `ty` accepts `value`; `Nil` properties accept anything.
+fn accepts_type(ty: VariantType, value: &Variant) -> bool {
+    ty == VariantType::Nil || value.get_type() == ty
+}
+
+fn variant_property_info(name: &str) -> PropertyInfo {
+    <Variant as GodotType>::property_info(name)
+}
+
+/// Property info for type `ty`; `Nil` stands for any variant.
+fn typed_property_info(name: &str, ty: VariantType) -> PropertyInfo {
+    if ty == VariantType::Nil {
+        return variant_property_info(name);
+    }
+
+    PropertyInfo {
+        variant_type: ty,
+        class_name: ClassName::none(),
+        property_name: StringName::from(name),
+        hint: PropertyHint::PROPERTY_HINT_NONE,
+        hint_string: GodotString::new(),
+        usage: PropertyUsageFlags::PROPERTY_USAGE_DEFAULT,
+    }
+}
+
+fn register_method(class_name: ClassName, method: &DynamicMethod) {
+    let method_name = StringName::from(method.name.as_str());
+
+    let arguments: Vec<PropertyInfo> = method
+        .param_names
+        .iter()
+        .map(|name| variant_property_info(name))
+        .collect();
+    let mut arguments_sys: Vec<sys::GDExtensionPropertyInfo> =
+        arguments.iter().map(|info| info.property_sys()).collect();
+    let mut arguments_metadata: Vec<sys::GDExtensionClassMethodArgumentMetadata> = arguments
+        .iter()
+        .map(|_| <Variant as GodotType>::param_metadata())
+        .collect();
+
+    let return_info = variant_property_info("");
+    let mut return_sys = if method.returns_value {
+        return_info.property_sys()
+    } else {
+        PropertyInfo::empty_sys()
+    };
+
+    let method_info_sys = sys::GDExtensionClassMethodInfo {
+        name: method_name.string_sys(),
+        method_userdata: method as *const DynamicMethod as *mut c_void,
+        call_func: Some(call_method),
+        ptrcall_func: Some(ptrcall_method),
+        method_flags: MethodFlags::METHOD_FLAGS_DEFAULT.ord() as u32,
+        has_return_value: method.returns_value as u8,
+        return_value_info: std::ptr::addr_of_mut!(return_sys),
+        return_value_metadata: <Variant as GodotType>::param_metadata(),
+        argument_count: arguments_sys.len() as u32,
+        arguments_info: arguments_sys.as_mut_ptr(),
+        arguments_metadata: arguments_metadata.as_mut_ptr(),
+        default_argument_count: 0,
+        default_arguments: std::ptr::null_mut(),
+    };
+
+    // SAFETY: all pointers are valid for the duration of the call. `method_userdata` points into the class data, which is never freed.
+    unsafe {
+        interface_fn!(classdb_register_extension_class_method)(
+            sys::get_library(),
+            class_name.string_sys(),
+            std::ptr::addr_of!(method_info_sys),
+        );
+    }
+}
+
+fn register_property(class_name: ClassName, property: &DynamicProperty) {
+    let info = typed_property_info(&property.name, property.default.get_type());
+    let info_sys = info.property_sys();
+
+    let getter_name = StringName::from(format!("get_{}", property.name));
+    let setter_name = StringName::from(format!("set_{}", property.name));
+
+    // SAFETY: all pointers are valid for the duration of the call.
+    unsafe {
+        interface_fn!(classdb_register_extension_class_property)(
+            sys::get_library(),
+            class_name.string_sys(),
+            std::ptr::addr_of!(info_sys),
+            setter_name.string_sys(),
+            getter_name.string_sys(),
+        );
+    }
+}
+
+fn register_signal(class_name: ClassName, signal: &DynamicSignal) {
+    let params: Vec<PropertyInfo> = signal
+        .params
+        .iter()
+        .map(|(name, ty)| typed_property_info(name, *ty))
+        .collect();
+    let params_sys: Vec<sys::GDExtensionPropertyInfo> =
+        params.iter().map(|info| info.property_sys()).collect();
+
+    let signal_name = StringName::from(signal.name.as_str());
+
+    // SAFETY: all pointers are valid for the duration of the call.
+    unsafe {
+        interface_fn!(classdb_register_extension_class_signal)(
+            sys::get_library(),
+            class_name.string_sys(),
+            signal_name.string_sys(),
+            params_sys.as_ptr(),
+            params_sys.len() as sys::GDExtensionInt,
+        );
+    }
+}
+
+/// Runs `method` on the instance behind `instance_ptr`; `None` if the call failed (an error has been printed in that case).
+///
+/// # Safety
+/// `method_userdata` must point to a `DynamicMethod`, and `instance_ptr` to an `InstanceCell`, both valid for the duration of the call.
+unsafe fn invoke(
+    method_userdata: *mut c_void,
+    instance_ptr: sys::GDExtensionClassInstancePtr,
+    args: &[&Variant],
+) -> Option<Variant> {
+    let method = &*(method_userdata as *const DynamicMethod);
+    let cell = &*(instance_ptr as *const InstanceCell);
+
+    let ctx = || format!("error in dynamic method `{}`", method.name);
+    let result = crate::private::handle_panic(
+        ctx,
+        AssertUnwindSafe(|| {
+            let mut instance = cell.try_borrow_mut().unwrap_or_else(|_| {
+                panic!(
+                    "method `{}` called while another method of the same object is running",
+                    method.name
+                )
+            });
+
+            (method.method)(&mut instance, args)
+        }),
+    );
+
+    match result {
+        Some(Ok(value)) => Some(value),
+        Some(Err(())) => {
+            log::godot_error!("dynamic method `{}` failed", method.name);
+            None
+        }
+        None => None,
+    }
+}
+
+unsafe extern "C" fn call_method(
+    method_userdata: *mut c_void,
+    instance_ptr: sys::GDExtensionClassInstancePtr,
+    args_ptr: *const sys::GDExtensionConstVariantPtr,
+    arg_count: sys::GDExtensionInt,
+    ret: sys::GDExtensionVariantPtr,
+    err: *mut sys::GDExtensionCallError,
+) {
+    *err = sys::default_call_error();
+
+    if instance_ptr.is_null() {
+        (*err).error = sys::GDEXTENSION_CALL_ERROR_INSTANCE_IS_NULL;
+        return;
+    }
+
+    let method = &*(method_userdata as *const DynamicMethod);
+    let expected = method.param_names.len();
+    let arg_count = arg_count as usize;
+    if arg_count != expected {
+        (*err).error = if arg_count < expected {
+            sys::GDEXTENSION_CALL_ERROR_TOO_FEW_ARGUMENTS
+        } else {
+            sys::GDEXTENSION_CALL_ERROR_TOO_MANY_ARGUMENTS
+        };
+        (*err).argument = expected as i32;
+        return;
+    }
+
+    let args = Variant::unbounded_refs_from_sys(args_ptr, arg_count);
+    match invoke(method_userdata, instance_ptr, args) {
+        Some(value) => *(ret as *mut Variant) = value,
+
+        // Godot has no error code for failed calls. Blame the first argument, as it's mostly the cause (e.g. for setters).
+        None if expected > 0 => {
+            (*err).error = sys::GDEXTENSION_CALL_ERROR_INVALID_ARGUMENT;
+            (*err).argument = 0;
+            (*err).expected = VariantType::Nil.sys() as i32;
+        }
+        None => (*err).error = sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD,
+    }
+}
+
+unsafe extern "C" fn ptrcall_method(
+    method_userdata: *mut c_void,
+    instance_ptr: sys::GDExtensionClassInstancePtr,
+    args_ptr: *const sys::GDExtensionConstTypePtr,
+    ret: sys::GDExtensionTypePtr,
+) {
+    let method = &*(method_userdata as *const DynamicMethod);
+
+    // All parameters are declared as variants, so Godot passes variant pointers; the argument count is validated by Godot.
+    let args = Variant::unbounded_refs_from_sys(
+        args_ptr as *const sys::GDExtensionConstVariantPtr,
+        method.param_names.len(),
+    );
+
+    let value = invoke(method_userdata, instance_ptr, args).unwrap_or_default();
+    if method.returns_value {
+        *(ret as *mut Variant) = value;
+    }
+}
+
+unsafe extern "C" fn create_instance(class_userdata: *mut c_void) -> sys::GDExtensionObjectPtr {
+    let class_ptr = class_userdata as *const DynamicClass;
+    Arc::increment_strong_count(class_ptr);
+    let class = Arc::from_raw(class_ptr);
+
+    let base_ptr = interface_fn!(classdb_construct_object)(class.base_class_name.string_sys());
+    let class_name = class.class_name;
+
+    let instance = InstanceCell::new(DynamicInstance {
+        base: Base::from_sys(base_ptr),
+        values: class
+            .properties
+            .iter()
+            .map(|property| property.default.clone())
+            .collect(),
+        class,
+    });
+
+    let instance_ptr = Box::into_raw(Box::new(instance)) as sys::GDExtensionClassInstancePtr;
+    interface_fn!(object_set_instance)(base_ptr, class_name.string_sys(), instance_ptr);
+
+    base_ptr
+}
+
+unsafe extern "C" fn free_instance(
+    _class_userdata: *mut c_void,
+    instance_ptr: sys::GDExtensionClassInstancePtr,
+) {
+    let _drop = Box::from_raw(instance_ptr as *mut InstanceCell);
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identifiers() {
+        assert!(is_identifier("Health"));
+        assert!(is_identifier("_Component2"));
+        assert!(!is_identifier(""));
+        assert!(!is_identifier("2D"));
+        assert!(!is_identifier("My Class"));
+        assert!(!is_identifier("Größe"));
+    }
+}
@@ -7,7 +7,11 @@
 use crate::obj::GodotClass;
 use std::marker::PhantomData;
 
+mod dynamic_class;
 mod method;
+pub mod plugin_abi;
+
+pub use dynamic_class::{DynamicClassBuilder, DynamicInstance};
 
 pub struct ClassBuilder<C> {
     _c: PhantomData<C>,
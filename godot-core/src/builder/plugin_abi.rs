@@ -0,0 +1,781 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Stable C ABI through which other dynamic libraries register classes with an already loaded extension.
+//!
+//! A game built with godot-rust may want to load mods: separate libraries, possibly written in C, C++ or against an older version of
+//! the game's SDK. Embedding godot-rust (or another binding) in each of them would tie every mod to the exact binding version and
+//! duplicate the bindings per mod. Instead, the game hands its mods a [`PluginApi`] table, a `#[repr(C)]` struct of function
+//! pointers. Classes registered through it are [dynamic classes][crate::init::DynamicClassBuilder] owned by the game's extension:
+//! they appear in Godot like any other class, and are unregistered together with the game's classes.
+//!
+//! Loading the mod libraries is up to the game (e.g. with the `libloading` crate). By convention, a mod exports a function named
+//! [`PLUGIN_INIT_SYMBOL`] of type [`PluginInitFn`], which the game calls with [`init_plugin()`] from
+//! [`ExtensionLibrary::on_level_init()`][crate::init::ExtensionLibrary::on_level_init] at [`InitLevel::Scene`].
+//!
+//! ```no_run
+//! # use godot::prelude::*;
+//! use godot::init::plugin_abi::{self, PluginInitFn};
+//!
+//! struct Game;
+//!
+//! #[gdextension]
+//! unsafe impl ExtensionLibrary for Game {
+//!     fn on_level_init(level: InitLevel) {
+//!         if level == InitLevel::Scene {
+//!             for init in load_mods() {
+//!                 // SAFETY: the mods are trusted to implement the plugin ABI correctly.
+//!                 if !unsafe { plugin_abi::init_plugin(init) } {
+//!                     godot_warn!("a mod failed to initialize");
+//!                 }
+//!             }
+//!         }
+//!     }
+//! }
+//!
+//! /// Opens the mod libraries and looks up their `gdext_plugin_init` symbols.
+//! fn load_mods() -> Vec<PluginInitFn> {
+//!     # unimplemented!()
+//! }
+//! ```
+//!
+//! # Versioning
+//! The table starts with its [`version`][PluginApi::version] and [`size`][PluginApi::size]. New versions only append functions,
+//! so a mod built against version `n` works with every host providing version `n` or later; it should check `version` before
+//! using functions added after version 1. Fields are never removed or reordered.
+//!
+//! # Conventions
+//! - Strings are NUL-terminated UTF-8. Functions taking strings fail (return `false` or null) for invalid UTF-8.
+//! - Values are Godot variants behind the opaque [`PluginVariant`] pointer. Arguments passed to mods are borrowed for the duration of
+//!   the call; variants created with `variant_new` must be freed with `variant_free`.
+//! - Functions returning `bool` report success. Errors are also printed to Godot's output.
+//! - Except for the `variant_*` functions, the table may only be used on the main thread. Methods are called by Godot on the thread
+//!   that calls them, so their `userdata` must tolerate that.
+//!
+//! # C declaration
+//! ```c
+//! typedef struct GdextPluginClass GdextPluginClass;
+//! typedef struct GdextPluginInstance GdextPluginInstance;
+//! typedef struct GdextPluginVariant GdextPluginVariant;
+//!
+//! typedef bool (*GdextPluginMethod)(void *userdata, GdextPluginInstance *instance,
+//!     const GdextPluginVariant *const *args, size_t arg_count, GdextPluginVariant *ret);
+//!
+//! typedef struct {
+//!     uint32_t version;
+//!     size_t size;
+//!
+//!     GdextPluginClass *(*class_begin)(const char *class_name, const char *base_class_name);
+//!     bool (*class_add_method)(GdextPluginClass *class, const char *name, const char *const *param_names,
+//!         size_t param_count, GdextPluginMethod method, void *userdata);
+//!     bool (*class_add_property)(GdextPluginClass *class, const char *name, const GdextPluginVariant *default_value);
+//!     bool (*class_add_signal)(GdextPluginClass *class, const char *name, const char *const *param_names, size_t param_count);
+//!     bool (*class_register)(GdextPluginClass *class);
+//!     void (*class_discard)(GdextPluginClass *class);
+//!
+//!     void *(*instance_object)(GdextPluginInstance *instance);
+//!     bool (*instance_get)(GdextPluginInstance *instance, const char *property, GdextPluginVariant *out);
+//!     bool (*instance_set)(GdextPluginInstance *instance, const char *property, const GdextPluginVariant *value);
+//!     bool (*instance_emit_signal)(GdextPluginInstance *instance, const char *signal,
+//!         const GdextPluginVariant *const *args, size_t arg_count);
+//!
+//!     GdextPluginVariant *(*variant_new)(void);
+//!     void (*variant_free)(GdextPluginVariant *variant);
+//!     uint32_t (*variant_type)(const GdextPluginVariant *variant);
+//!     void (*variant_set_nil)(GdextPluginVariant *variant);
+//!     void (*variant_set_bool)(GdextPluginVariant *variant, bool value);
+//!     void (*variant_set_int)(GdextPluginVariant *variant, int64_t value);
+//!     void (*variant_set_float)(GdextPluginVariant *variant, double value);
+//!     bool (*variant_set_string)(GdextPluginVariant *variant, const char *utf8, size_t len);
+//!     bool (*variant_to_bool)(const GdextPluginVariant *variant, bool *out);
+//!     bool (*variant_to_int)(const GdextPluginVariant *variant, int64_t *out);
+//!     bool (*variant_to_float)(const GdextPluginVariant *variant, double *out);
+//!     bool (*variant_to_string)(const GdextPluginVariant *variant, char *buffer, size_t capacity, size_t *len);
+//! } GdextPluginApi;
+//!
+//! bool gdext_plugin_init(const GdextPluginApi *api);
+//! ```
+
+use std::ffi::{c_char, c_void, CStr};
+use std::panic::AssertUnwindSafe;
+
+use crate::builtin::meta::{ClassName, FromGodot, ToGodot};
+use crate::builtin::{GodotString, Variant, VariantType};
+use crate::engine::global::Error;
+use crate::engine::ClassDb;
+use crate::init::{is_main_thread, DynamicClassBuilder, DynamicInstance, InitLevel};
+use crate::log;
+
+/// Version of the [`PluginApi`] provided by this version of godot-rust.
+pub const PLUGIN_API_VERSION: u32 = 1;
+
+/// Name of the function through which mods receive the [`PluginApi`], by convention.
+pub const PLUGIN_INIT_SYMBOL: &str = "gdext_plugin_init";
+
+/// Entry point of a mod: registers its classes with `api` and returns whether it succeeded.
+pub type PluginInitFn = unsafe extern "C" fn(api: *const PluginApi) -> bool;
+
+/// Method implemented by a mod. Writes its result to `ret` (nil by default) and returns whether the call succeeded.
+pub type PluginMethodFn = unsafe extern "C" fn(
+    userdata: *mut c_void,
+    instance: *mut PluginInstance,
+    args: *const *const PluginVariant,
+    arg_count: usize,
+    ret: *mut PluginVariant,
+) -> bool;
+
+/// Class being built by a mod; created by `class_begin`, consumed by `class_register` or `class_discard`.
+#[repr(C)]
+pub struct PluginClass {
+    _opaque: [u8; 0],
+}
+
+/// Instance of a class registered by a mod, passed to its methods.
+#[repr(C)]
+pub struct PluginInstance {
+    _opaque: [u8; 0],
+}
+
+/// Godot variant, only accessed through the `variant_*` functions.
+#[repr(C)]
+pub struct PluginVariant {
+    _opaque: [u8; 0],
+}
+
+/// Function table handed to mods. See the [module documentation][self] for the conventions.
+#[repr(C)]
+pub struct PluginApi {
+    /// Version of the table, [`PLUGIN_API_VERSION`] of the host.
+    pub version: u32,
+
+    /// Size of the table in bytes.
+    pub size: usize,
+
+    /// Starts a class named `class_name` inheriting the engine class `base_class_name`. Returns null on error.
+    pub class_begin: unsafe extern "C" fn(
+        class_name: *const c_char,
+        base_class_name: *const c_char,
+    ) -> *mut PluginClass,
+
+    /// Adds a method; `method` is called with `userdata` each time the method is called.
+    pub class_add_method: unsafe extern "C" fn(
+        class: *mut PluginClass,
+        name: *const c_char,
+        param_names: *const *const c_char,
+        param_count: usize,
+        method: PluginMethodFn,
+        userdata: *mut c_void,
+    ) -> bool,
+
+    /// Adds a property, with `get_<name>`/`set_<name>` accessors, whose type is that of `default_value`.
+    pub class_add_property: unsafe extern "C" fn(
+        class: *mut PluginClass,
+        name: *const c_char,
+        default_value: *const PluginVariant,
+    ) -> bool,
+
+    /// Adds a signal, whose parameters accept any variant.
+    pub class_add_signal: unsafe extern "C" fn(
+        class: *mut PluginClass,
+        name: *const c_char,
+        param_names: *const *const c_char,
+        param_count: usize,
+    ) -> bool,
+
+    /// Registers the class with Godot and frees `class`, also on failure.
+    pub class_register: unsafe extern "C" fn(class: *mut PluginClass) -> bool,
+
+    /// Frees `class` without registering it.
+    pub class_discard: unsafe extern "C" fn(class: *mut PluginClass),
+
+    /// The `GDExtensionObjectPtr` of the instance, for mods that use the GDExtension interface directly.
+    pub instance_object: unsafe extern "C" fn(instance: *mut PluginInstance) -> *mut c_void,
+
+    /// Copies the value of a property of the instance into `out`.
+    pub instance_get: unsafe extern "C" fn(
+        instance: *mut PluginInstance,
+        property: *const c_char,
+        out: *mut PluginVariant,
+    ) -> bool,
+
+    /// Sets a property of the instance; fails if the value does not have the type of the property.
+    pub instance_set: unsafe extern "C" fn(
+        instance: *mut PluginInstance,
+        property: *const c_char,
+        value: *const PluginVariant,
+    ) -> bool,
+
+    /// Emits a signal of the instance.
+    pub instance_emit_signal: unsafe extern "C" fn(
+        instance: *mut PluginInstance,
+        signal: *const c_char,
+        args: *const *const PluginVariant,
+        arg_count: usize,
+    ) -> bool,
+
+    /// Creates a nil variant, to be freed with `variant_free`.
+    pub variant_new: unsafe extern "C" fn() -> *mut PluginVariant,
+
+    /// Frees a variant created by `variant_new`.
+    pub variant_free: unsafe extern "C" fn(variant: *mut PluginVariant),
+
+    /// The type of the variant, as in Godot's `Variant.Type` enum.
+    pub variant_type: unsafe extern "C" fn(variant: *const PluginVariant) -> u32,
+
+    pub variant_set_nil: unsafe extern "C" fn(variant: *mut PluginVariant),
+    pub variant_set_bool: unsafe extern "C" fn(variant: *mut PluginVariant, value: bool),
+    pub variant_set_int: unsafe extern "C" fn(variant: *mut PluginVariant, value: i64),
+    pub variant_set_float: unsafe extern "C" fn(variant: *mut PluginVariant, value: f64),
+
+    /// Sets the variant to a string of `len` bytes of UTF-8, not NUL-terminated.
+    pub variant_set_string:
+        unsafe extern "C" fn(variant: *mut PluginVariant, utf8: *const c_char, len: usize) -> bool,
+
+    /// Reads a `bool`; fails if the variant holds another type.
+    pub variant_to_bool:
+        unsafe extern "C" fn(variant: *const PluginVariant, out: *mut bool) -> bool,
+
+    /// Reads an `int`; fails if the variant holds another type.
+    pub variant_to_int: unsafe extern "C" fn(variant: *const PluginVariant, out: *mut i64) -> bool,
+
+    /// Reads a `float`, also accepting `int`s; fails for other types.
+    pub variant_to_float:
+        unsafe extern "C" fn(variant: *const PluginVariant, out: *mut f64) -> bool,
+
+    /// Reads a string as UTF-8 into `buffer`, NUL-terminated if there is space for it. `len` receives the length in bytes without
+    /// the terminator; if it is not less than `capacity`, the string was truncated. Fails if the variant does not hold a string.
+    pub variant_to_string: unsafe extern "C" fn(
+        variant: *const PluginVariant,
+        buffer: *mut c_char,
+        capacity: usize,
+        len: *mut usize,
+    ) -> bool,
+}
+
+/// The function table of this extension, to be passed to mods.
+pub fn plugin_api() -> &'static PluginApi {
+    &PLUGIN_API
+}
+
+/// Calls the entry point of a mod with [`plugin_api()`]. Returns the result of `init`.
+///
+/// Must be called from [`ExtensionLibrary::on_level_init()`][crate::init::ExtensionLibrary::on_level_init] at
+/// [`InitLevel::Scene`], where classes can be registered.
+///
+/// # Safety
+/// `init` must be a valid function following the conventions of the plugin ABI. It runs arbitrary native code, which Rust cannot
+/// check.
+pub unsafe fn init_plugin(init: PluginInitFn) -> bool {
+    init(plugin_api())
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+static PLUGIN_API: PluginApi = PluginApi {
+    version: PLUGIN_API_VERSION,
+    size: std::mem::size_of::<PluginApi>(),
+    class_begin,
+    class_add_method,
+    class_add_property,
+    class_add_signal,
+    class_register,
+    class_discard,
+    instance_object,
+    instance_get,
+    instance_set,
+    instance_emit_signal,
+    variant_new,
+    variant_free,
+    variant_type,
+    variant_set_nil,
+    variant_set_bool,
+    variant_set_int,
+    variant_set_float,
+    variant_set_string,
+    variant_to_bool,
+    variant_to_int,
+    variant_to_float,
+    variant_to_string,
+};
+
+/// What a [`PluginClass`] pointer points to. The builder is `None` once an update failed.
+struct ClassInProgress {
+    builder: Option<DynamicClassBuilder>,
+}
+
+/// Method of a mod, as stored in the dynamic class.
+struct PluginMethod {
+    method: PluginMethodFn,
+    userdata: *mut c_void,
+}
+
+// SAFETY: mods are responsible for `userdata` being usable from the threads that call the method, as documented in the ABI.
+unsafe impl Send for PluginMethod {}
+unsafe impl Sync for PluginMethod {}
+
+/// Runs `code` for the ABI function `function`, catching panics. Returns `default` on panic, or if not on the main thread.
+fn guarded<R>(function: &str, default: R, code: impl FnOnce() -> R) -> R {
+    if !is_main_thread() {
+        log::godot_error!("plugin ABI: `{function}` called outside the main thread");
+        return default;
+    }
+
+    let ctx = || format!("plugin ABI: error in `{function}`");
+    crate::private::handle_panic(ctx, AssertUnwindSafe(code)).unwrap_or(default)
+}
+
+/// Reads a NUL-terminated UTF-8 string; `None` (with an error) if null or invalid.
+unsafe fn read_str<'a>(function: &str, ptr: *const c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        log::godot_error!("plugin ABI: null string passed to `{function}`");
+        return None;
+    }
+
+    let string = CStr::from_ptr(ptr).to_str().ok();
+    if string.is_none() {
+        log::godot_error!("plugin ABI: invalid UTF-8 passed to `{function}`");
+    }
+    string
+}
+
+unsafe fn read_strs<'a>(
+    function: &str,
+    ptrs: *const *const c_char,
+    count: usize,
+) -> Option<Vec<&'a str>> {
+    if count == 0 {
+        return Some(Vec::new());
+    }
+
+    std::slice::from_raw_parts(ptrs, count)
+        .iter()
+        .map(|&ptr| read_str(function, ptr))
+        .collect()
+}
+
+unsafe fn variant_refs<'a>(args: *const *const PluginVariant, count: usize) -> Vec<&'a Variant> {
+    if count == 0 {
+        return Vec::new();
+    }
+
+    std::slice::from_raw_parts(args, count)
+        .iter()
+        .map(|&arg| &*(arg as *const Variant))
+        .collect()
+}
+
+/// Applies `update` to the builder of `class`; `false` if `class` is null or already failed.
+unsafe fn update_builder(
+    class: *mut PluginClass,
+    update: impl FnOnce(DynamicClassBuilder) -> DynamicClassBuilder,
+) -> bool {
+    let Some(class) = (class as *mut ClassInProgress).as_mut() else {
+        return false;
+    };
+
+    // Taken out while updating, so that a panic in `update` leaves no builder behind.
+    match class.builder.take() {
+        Some(builder) => {
+            class.builder = Some(update(builder));
+            true
+        }
+        None => false,
+    }
+}
+
+unsafe extern "C" fn class_begin(
+    class_name: *const c_char,
+    base_class_name: *const c_char,
+) -> *mut PluginClass {
+    const FUNCTION: &str = "class_begin";
+
+    guarded(FUNCTION, std::ptr::null_mut(), || {
+        let (Some(class_name), Some(base_class_name)) = (
+            read_str(FUNCTION, class_name),
+            read_str(FUNCTION, base_class_name),
+        ) else {
+            return std::ptr::null_mut();
+        };
+
+        if !ClassDb::singleton().class_exists(base_class_name.into()) {
+            log::godot_error!("plugin ABI: base class `{base_class_name}` does not exist");
+            return std::ptr::null_mut();
+        }
+
+        let builder = DynamicClassBuilder::new_with_base(
+            class_name,
+            ClassName::alloc_interned(base_class_name),
+            InitLevel::Scene,
+        );
+
+        let class = Box::new(ClassInProgress {
+            builder: Some(builder),
+        });
+        Box::into_raw(class) as *mut PluginClass
+    })
+}
+
+unsafe extern "C" fn class_add_method(
+    class: *mut PluginClass,
+    name: *const c_char,
+    param_names: *const *const c_char,
+    param_count: usize,
+    method: PluginMethodFn,
+    userdata: *mut c_void,
+) -> bool {
+    const FUNCTION: &str = "class_add_method";
+
+    guarded(FUNCTION, false, || {
+        let (Some(name), Some(param_names)) = (
+            read_str(FUNCTION, name),
+            read_strs(FUNCTION, param_names, param_count),
+        ) else {
+            return false;
+        };
+
+        let method = PluginMethod { method, userdata };
+        update_builder(class, |builder| {
+            builder.method(name, &param_names, move |instance, args| {
+                call_plugin_method(&method, instance, args)
+            })
+        })
+    })
+}
+
+unsafe extern "C" fn class_add_property(
+    class: *mut PluginClass,
+    name: *const c_char,
+    default_value: *const PluginVariant,
+) -> bool {
+    const FUNCTION: &str = "class_add_property";
+
+    guarded(FUNCTION, false, || {
+        let Some(name) = read_str(FUNCTION, name) else {
+            return false;
+        };
+
+        let default = match (default_value as *const Variant).as_ref() {
+            Some(default) => default.clone(),
+            None => Variant::nil(),
+        };
+
+        update_builder(class, |builder| builder.property(name, default))
+    })
+}
+
+unsafe extern "C" fn class_add_signal(
+    class: *mut PluginClass,
+    name: *const c_char,
+    param_names: *const *const c_char,
+    param_count: usize,
+) -> bool {
+    const FUNCTION: &str = "class_add_signal";
+
+    guarded(FUNCTION, false, || {
+        let (Some(name), Some(param_names)) = (
+            read_str(FUNCTION, name),
+            read_strs(FUNCTION, param_names, param_count),
+        ) else {
+            return false;
+        };
+
+        let params: Vec<(&str, VariantType)> = param_names
+            .into_iter()
+            .map(|param| (param, VariantType::Nil))
+            .collect();
+
+        update_builder(class, |builder| builder.signal(name, &params))
+    })
+}
+
+unsafe extern "C" fn class_register(class: *mut PluginClass) -> bool {
+    if class.is_null() {
+        return false;
+    }
+
+    let class = Box::from_raw(class as *mut ClassInProgress);
+    guarded("class_register", false, move || match class.builder {
+        Some(builder) => {
+            builder.register();
+            true
+        }
+        None => false,
+    })
+}
+
+unsafe extern "C" fn class_discard(class: *mut PluginClass) {
+    if !class.is_null() {
+        drop(Box::from_raw(class as *mut ClassInProgress));
+    }
+}
+
+unsafe fn call_plugin_method(
+    method: &PluginMethod,
+    instance: &mut DynamicInstance,
+    args: &[&Variant],
+) -> Result<Variant, ()> {
+    let args: Vec<*const PluginVariant> = args
+        .iter()
+        .map(|&arg| arg as *const Variant as *const PluginVariant)
+        .collect();
+
+    let mut ret = Variant::nil();
+    let success = (method.method)(
+        method.userdata,
+        instance as *mut DynamicInstance as *mut PluginInstance,
+        args.as_ptr(),
+        args.len(),
+        &mut ret as *mut Variant as *mut PluginVariant,
+    );
+
+    if success {
+        Ok(ret)
+    } else {
+        Err(())
+    }
+}
+
+unsafe fn as_instance<'a>(instance: *mut PluginInstance) -> Option<&'a mut DynamicInstance> {
+    (instance as *mut DynamicInstance).as_mut()
+}
+
+unsafe extern "C" fn instance_object(instance: *mut PluginInstance) -> *mut c_void {
+    guarded(
+        "instance_object",
+        std::ptr::null_mut(),
+        || match as_instance(instance) {
+            Some(instance) => instance.base().obj_sys() as *mut c_void,
+            None => std::ptr::null_mut(),
+        },
+    )
+}
+
+unsafe extern "C" fn instance_get(
+    instance: *mut PluginInstance,
+    property: *const c_char,
+    out: *mut PluginVariant,
+) -> bool {
+    const FUNCTION: &str = "instance_get";
+
+    guarded(FUNCTION, false, || {
+        let (Some(instance), Some(property)) =
+            (as_instance(instance), read_str(FUNCTION, property))
+        else {
+            return false;
+        };
+
+        match (instance.get(property), (out as *mut Variant).as_mut()) {
+            (Some(value), Some(out)) => {
+                *out = value.clone();
+                true
+            }
+            _ => false,
+        }
+    })
+}
+
+unsafe extern "C" fn instance_set(
+    instance: *mut PluginInstance,
+    property: *const c_char,
+    value: *const PluginVariant,
+) -> bool {
+    const FUNCTION: &str = "instance_set";
+
+    guarded(FUNCTION, false, || {
+        let (Some(instance), Some(property), Some(value)) = (
+            as_instance(instance),
+            read_str(FUNCTION, property),
+            (value as *const Variant).as_ref(),
+        ) else {
+            return false;
+        };
+
+        instance.set(property, value.clone())
+    })
+}
+
+unsafe extern "C" fn instance_emit_signal(
+    instance: *mut PluginInstance,
+    signal: *const c_char,
+    args: *const *const PluginVariant,
+    arg_count: usize,
+) -> bool {
+    const FUNCTION: &str = "instance_emit_signal";
+
+    guarded(FUNCTION, false, || {
+        let (Some(instance), Some(signal)) = (as_instance(instance), read_str(FUNCTION, signal))
+        else {
+            return false;
+        };
+
+        let args: Vec<Variant> = variant_refs(args, arg_count).into_iter().cloned().collect();
+
+        instance.base_mut().emit_signal(signal.into(), &args) == Error::OK
+    })
+}
+
+unsafe extern "C" fn variant_new() -> *mut PluginVariant {
+    Box::into_raw(Box::new(Variant::nil())) as *mut PluginVariant
+}
+
+unsafe extern "C" fn variant_free(variant: *mut PluginVariant) {
+    if !variant.is_null() {
+        drop(Box::from_raw(variant as *mut Variant));
+    }
+}
+
+unsafe extern "C" fn variant_type(variant: *const PluginVariant) -> u32 {
+    match (variant as *const Variant).as_ref() {
+        Some(variant) => variant.get_type().sys() as u32,
+        None => VariantType::Nil.sys() as u32,
+    }
+}
+
+/// Replaces the value of `variant`, if not null.
+unsafe fn set_variant(variant: *mut PluginVariant, value: Variant) {
+    if let Some(variant) = (variant as *mut Variant).as_mut() {
+        *variant = value;
+    }
+}
+
+unsafe extern "C" fn variant_set_nil(variant: *mut PluginVariant) {
+    set_variant(variant, Variant::nil());
+}
+
+unsafe extern "C" fn variant_set_bool(variant: *mut PluginVariant, value: bool) {
+    set_variant(variant, value.to_variant());
+}
+
+unsafe extern "C" fn variant_set_int(variant: *mut PluginVariant, value: i64) {
+    set_variant(variant, value.to_variant());
+}
+
+unsafe extern "C" fn variant_set_float(variant: *mut PluginVariant, value: f64) {
+    set_variant(variant, value.to_variant());
+}
+
+unsafe extern "C" fn variant_set_string(
+    variant: *mut PluginVariant,
+    utf8: *const c_char,
+    len: usize,
+) -> bool {
+    let bytes = if len == 0 {
+        &[][..]
+    } else if utf8.is_null() {
+        return false;
+    } else {
+        std::slice::from_raw_parts(utf8 as *const u8, len)
+    };
+
+    match std::str::from_utf8(bytes) {
+        Ok(string) if !variant.is_null() => {
+            set_variant(variant, GodotString::from(string).to_variant());
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Converts `variant` to `T` and writes it to `out`; fails if either is null or the variant does not hold exactly type `ty`.
+unsafe fn read_variant<T: FromGodot>(
+    variant: *const PluginVariant,
+    ty: VariantType,
+    out: *mut T,
+) -> bool {
+    let Some(variant) = (variant as *const Variant).as_ref() else {
+        return false;
+    };
+
+    if out.is_null() || variant.get_type() != ty {
+        return false;
+    }
+
+    match variant.try_to::<T>() {
+        Ok(value) => {
+            *out = value;
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+unsafe extern "C" fn variant_to_bool(variant: *const PluginVariant, out: *mut bool) -> bool {
+    read_variant(variant, VariantType::Bool, out)
+}
+
+unsafe extern "C" fn variant_to_int(variant: *const PluginVariant, out: *mut i64) -> bool {
+    read_variant(variant, VariantType::Int, out)
+}
+
+unsafe extern "C" fn variant_to_float(variant: *const PluginVariant, out: *mut f64) -> bool {
+    let mut int = 0i64;
+    if read_variant(variant, VariantType::Int, &mut int) {
+        *out = int as f64;
+        return true;
+    }
+
+    read_variant(variant, VariantType::Float, out)
+}
+
+unsafe extern "C" fn variant_to_string(
+    variant: *const PluginVariant,
+    buffer: *mut c_char,
+    capacity: usize,
+    len: *mut usize,
+) -> bool {
+    let mut string = GodotString::new();
+    if !read_variant(variant, VariantType::String, &mut string) {
+        return false;
+    }
+
+    let string = string.to_string();
+    let bytes = string.as_bytes();
+    if !len.is_null() {
+        *len = bytes.len();
+    }
+
+    if !buffer.is_null() && capacity > 0 {
+        copy_terminated(
+            bytes,
+            std::slice::from_raw_parts_mut(buffer as *mut u8, capacity),
+        );
+    }
+
+    true
+}
+
+/// Copies as much of `bytes` as fits into `buffer`, followed by a NUL if there is space left.
+fn copy_terminated(bytes: &[u8], buffer: &mut [u8]) {
+    let copied = bytes.len().min(buffer.len());
+    buffer[..copied].copy_from_slice(&bytes[..copied]);
+
+    if let Some(terminator) = buffer.get_mut(copied) {
+        *terminator = 0;
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::copy_terminated;
+
+    #[test]
+    fn copy_with_terminator() {
+        let mut buffer = [0xff; 6];
+        copy_terminated(b"mod", &mut buffer);
+        assert_eq!(&buffer, b"mod\0\xff\xff");
+
+        let mut buffer = [0xff; 3];
+        copy_terminated(b"mod", &mut buffer);
+        assert_eq!(&buffer, b"mod");
+
+        let mut buffer = [0xff; 2];
+        copy_terminated(b"mod", &mut buffer);
+        assert_eq!(&buffer, b"mo");
+    }
+}
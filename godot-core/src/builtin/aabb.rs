@@ -379,6 +379,14 @@ impl Aabb {
             self.size
         );
     }
+
+    /// Returns `true` if the two boxes are approximately equal, by comparing `position` and `size`.
+    ///
+    /// _Godot equivalent: `AABB.is_equal_approx()`_
+    #[inline]
+    pub fn is_equal_approx(&self, other: &Self) -> bool {
+        self.approx_eq(other)
+    }
 }
 
 impl std::fmt::Display for Aabb {
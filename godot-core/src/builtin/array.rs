@@ -13,7 +13,7 @@ use std::fmt;
 use std::marker::PhantomData;
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
-use super::meta::{FromGodot, GodotConvert, GodotFfiVariant, GodotType, ToGodot};
+use super::meta::{FromGodot, GodotConvert, GodotFfiVariant, GodotType, PropertyInfo, ToGodot};
 
 /// Godot's `Array` type.
 ///
@@ -747,6 +747,31 @@ impl<T: GodotType> GodotType for Array<T> {
     fn try_from_ffi(ffi: Self::Ffi) -> Option<Self> {
         Some(ffi)
     }
+
+    fn property_info(property_name: &str) -> PropertyInfo {
+        // Typed arrays show up as `Array[T]` in method signatures and the documentation.
+        let element = TypeInfo::of::<T>();
+        let (hint, hint_string) = if element.is_typed() {
+            (
+                crate::engine::global::PropertyHint::PROPERTY_HINT_ARRAY_TYPE,
+                element.type_name(),
+            )
+        } else {
+            (
+                crate::engine::global::PropertyHint::PROPERTY_HINT_NONE,
+                GodotString::new(),
+            )
+        };
+
+        PropertyInfo {
+            variant_type: Self::variant_type(),
+            class_name: Self::class_name(),
+            property_name: StringName::from(property_name),
+            hint,
+            hint_string,
+            usage: crate::engine::global::PropertyUsageFlags::PROPERTY_USAGE_DEFAULT,
+        }
+    }
 }
 
 impl<T: GodotType> GodotFfiVariant for Array<T> {
@@ -988,6 +1013,15 @@ impl TypeInfo {
     fn is_typed(&self) -> bool {
         self.variant_type != VariantType::Nil
     }
+
+    /// Name of the element type as written in GDScript, e.g. `int` or `Node`.
+    fn type_name(&self) -> GodotString {
+        if self.class_name.is_empty() {
+            crate::engine::utilities::type_string(self.variant_type as i64)
+        } else {
+            GodotString::from(&self.class_name)
+        }
+    }
 }
 
 impl fmt::Debug for TypeInfo {
@@ -503,6 +503,14 @@ impl Basis {
         self.rows[1].z = col.y;
         self.rows[2].z = col.z;
     }
+
+    /// Returns `true` if the two bases are approximately equal, by comparing each row.
+    ///
+    /// _Godot equivalent: `Basis.is_equal_approx()`_
+    #[inline]
+    pub fn is_equal_approx(&self, other: &Self) -> bool {
+        self.approx_eq(other)
+    }
 }
 
 impl Display for Basis {
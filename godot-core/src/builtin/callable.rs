@@ -113,7 +113,7 @@ impl Callable {
             token: ptr::null_mut(),
             object: ptr::null_mut(),
             call_func: Some(rust_callable_call_custom::<C>),
-            is_valid_func: None, // could be customized, but no real use case yet.
+            is_valid_func: Some(rust_callable_is_valid::<C>),
             free_func: Some(rust_callable_destroy::<C>),
             hash_func: Some(rust_callable_hash::<C>),
             equal_func: Some(rust_callable_equal::<C>),
@@ -334,6 +334,7 @@ mod custom_callable {
     use super::*;
     use crate::builtin::GodotString;
     use std::hash::Hash;
+    use std::panic::AssertUnwindSafe;
 
     pub struct CallableUserdata<T> {
         pub inner: T,
@@ -355,17 +356,27 @@ mod custom_callable {
 
     /// Represents a custom callable object defined in Rust.
     ///
-    /// This trait has a single method, `invoke`, which is called upon invocation.
+    /// The method `invoke` is called upon invocation; `is_valid` can optionally be overridden.
     ///
     /// Since callables can be invoked from anywhere, they must be self-contained (`'static`) and thread-safe (`Send + Sync`).
     /// They also should implement `Display` for the Godot string representation.
     /// Furthermore, `PartialEq` and `Hash` are required for equality checks and usage as a key in a `Dictionary`.
+    ///
+    /// Custom callables can carry arbitrary Rust state, e.g. a channel sender to dispatch calls to another thread, or a shared flag that
+    /// cancels the callable (see [`is_valid`][Self::is_valid]).
     pub trait RustCallable: 'static + PartialEq + Hash + fmt::Display + Send + Sync {
         /// Invokes the callable with the given arguments as `Variant` references.
         ///
         /// Return `Ok(...)` if the call succeeded, and `Err(())` otherwise.
-        /// Error handling is mostly needed in case argument number or types mismatch.
+        /// Error handling is mostly needed in case argument number or types mismatch. Panics are caught and reported as errors.
         fn invoke(&mut self, args: &[&Variant]) -> Result<Variant, ()>;
+
+        /// Whether the callable can still be invoked; `true` by default.
+        ///
+        /// Returning `false` cancels the callable: [`Callable::is_valid()`] returns `false`, and Godot no longer invokes it.
+        fn is_valid(&self) -> bool {
+            true
+        }
     }
 
     pub unsafe extern "C" fn rust_callable_call_custom<C: RustCallable>(
@@ -380,8 +391,11 @@ mod custom_callable {
 
         let c: &mut C = CallableUserdata::inner_from_raw(callable_userdata);
 
-        let result = c.invoke(arg_refs);
-        crate::builtin::meta::varcall_return_checked(result, r_return, r_error);
+        let result = crate::private::handle_panic(
+            || format!("error in custom callable `{}`", std::any::type_name::<C>()),
+            AssertUnwindSafe(|| c.invoke(arg_refs)),
+        );
+        crate::builtin::meta::varcall_return_checked(result.unwrap_or(Err(())), r_return, r_error);
     }
 
     pub unsafe extern "C" fn rust_callable_call_fn<F>(
@@ -397,9 +411,14 @@ mod custom_callable {
             Variant::unbounded_refs_from_sys(p_args, p_argument_count as usize);
 
         let w: &mut FnWrapper<F> = CallableUserdata::inner_from_raw(callable_userdata);
+        let name = &w.name;
+        let rust_function = &mut w.rust_function;
 
-        let result = (w.rust_function)(arg_refs);
-        crate::builtin::meta::varcall_return_checked(result, r_return, r_error);
+        let result = crate::private::handle_panic(
+            || format!("error in callable `{name}`"),
+            AssertUnwindSafe(|| rust_function(arg_refs)),
+        );
+        crate::builtin::meta::varcall_return_checked(result.unwrap_or(Err(())), r_return, r_error);
     }
 
     pub unsafe extern "C" fn rust_callable_destroy<T>(callable_userdata: *mut std::ffi::c_void) {
@@ -407,6 +426,14 @@ mod custom_callable {
         let _drop = Box::from_raw(rust_ptr);
     }
 
+    pub unsafe extern "C" fn rust_callable_is_valid<C: RustCallable>(
+        callable_userdata: *mut std::ffi::c_void,
+    ) -> sys::GDExtensionBool {
+        let c: &C = CallableUserdata::inner_from_raw(callable_userdata);
+
+        c.is_valid() as sys::GDExtensionBool
+    }
+
     pub unsafe extern "C" fn rust_callable_hash<T: Hash>(
         callable_userdata: *mut std::ffi::c_void,
     ) -> u32 {
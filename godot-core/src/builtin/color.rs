@@ -312,6 +312,14 @@ impl Color {
     fn as_inner(&self) -> InnerColor {
         InnerColor::from_outer(self)
     }
+
+    /// Returns `true` if the two colors are approximately equal, by comparing each channel.
+    ///
+    /// _Godot equivalent: `Color.is_equal_approx()`_
+    #[inline]
+    pub fn is_equal_approx(self, other: Self) -> bool {
+        self.approx_eq(&other)
+    }
 }
 
 // SAFETY:
@@ -328,8 +336,10 @@ impl_godot_as_self!(Color);
 
 impl ApproxEq for Color {
     fn approx_eq(&self, other: &Self) -> bool {
-        // TODO(bromeon): re-implement in Rust
-        self.as_inner().is_equal_approx(*other)
+        self.r.approx_eq(&other.r)
+            && self.g.approx_eq(&other.g)
+            && self.b.approx_eq(&other.b)
+            && self.a.approx_eq(&other.a)
     }
 }
 
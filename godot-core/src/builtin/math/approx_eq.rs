@@ -8,11 +8,12 @@
 
 /// Approximate equality-comparison of geometric types.
 ///
-/// The implementation is specific to the type. It's mostly used for gdext-internal tests, but you may use it for your own code.
-/// Note that we give no guarantees about precision, and implementation can change at any time.
+/// The implementation is specific to the type, and follows Godot's `is_equal_approx()`: floats are compared with a tolerance of
+/// `CMP_EPSILON`, scaled by the magnitude of the value; composite types compare their components. The math types additionally provide
+/// inherent `is_equal_approx()` methods with the same semantics, so the trait does not need to be imported.
 ///
-/// We currently also do not guarantee that this gives the same results as Godot's own `is_equal_approx()` function; although this may
-/// be the goal in the future.
+/// In tests, use [`assert_eq_approx!`][crate::assert_eq_approx] (alias [`assert_approx_eq!`][crate::assert_approx_eq]) and
+/// [`assert_ne_approx!`][crate::assert_ne_approx] instead of `assert_eq!`, since exact floating-point comparison is rarely what you want.
 pub trait ApproxEq: PartialEq {
     fn approx_eq(&self, other: &Self) -> bool;
 }
@@ -64,3 +65,13 @@ macro_rules! assert_ne_approx {
         }
     };
 }
+
+/// Asserts that two values are approximately equal, as by their `is_equal_approx()` methods.
+///
+/// Same as [`assert_eq_approx!`][crate::assert_eq_approx], accepting the same arguments; the name matches `is_equal_approx()`.
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($($t:tt)+) => {
+        $crate::assert_eq_approx!($($t)+)
+    };
+}
@@ -8,7 +8,7 @@ mod approx_eq;
 mod float;
 mod glam_helpers;
 
-pub use crate::{assert_eq_approx, assert_ne_approx};
+pub use crate::{assert_approx_eq, assert_eq_approx, assert_ne_approx};
 pub use approx_eq::ApproxEq;
 pub use float::FloatExt;
 
@@ -25,5 +25,7 @@ mod test {
         assert_ne_approx!(1.0, 2.0);
         assert_eq_approx!(1.0, 1.000001, "Message {}", "formatted");
         assert_ne_approx!(1.0, 2.0, "Message {}", "formatted");
+        assert_approx_eq!(1.0, 1.000001);
+        assert_approx_eq!(1.0, 1.000001, "Message {}", "formatted");
     }
 }
@@ -0,0 +1,134 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::ops::Deref;
+
+use crate::builtin::{GodotString, NodePath, StringName};
+
+/// Argument of type `T` that is either owned or borrowed from the caller.
+///
+/// Produced by [`AsArg::into_arg()`]. Borrowed arguments are only cloned if the callee needs an owned value.
+pub enum CowArg<'r, T> {
+    Owned(T),
+    Borrowed(&'r T),
+}
+
+impl<'r, T: Clone> CowArg<'r, T> {
+    /// Returns the owned value, cloning it if borrowed.
+    pub fn into_owned(self) -> T {
+        match self {
+            CowArg::Owned(value) => value,
+            CowArg::Borrowed(value) => value.clone(),
+        }
+    }
+}
+
+impl<'r, T> Deref for CowArg<'r, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match self {
+            CowArg::Owned(value) => value,
+            CowArg::Borrowed(value) => value,
+        }
+    }
+}
+
+impl<'r, T: fmt::Display> fmt::Display for CowArg<'r, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        T::fmt(self, f)
+    }
+}
+
+/// Value that can be passed where an argument of Godot string type `T` is expected.
+///
+/// Accepts the same types as `impl Into<T>`, and additionally `&T`, which is passed on without conversion. Converting `&str` to
+/// [`StringName`] reuses recently converted names on the main thread, so per-frame calls with literals don't repeat the lookup.
+///
+/// Currently, only the extension traits [`DynamicCallExt`][crate::engine::DynamicCallExt] and
+/// [`ShaderMaterialExt`][crate::engine::ShaderMaterialExt] accept `AsArg` parameters. Generated engine methods still take
+/// `StringName` and `GodotString` by value: for those, `"name".into()` uses the same name cache, but a `GodotString` is allocated
+/// on every call.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::DynamicCallExt;
+///
+/// fn update(mut node: Gd<Node>, speed: &StringName) {
+///     // Both avoid constructing a new name.
+///     let current: f32 = node.get_typed(speed);
+///     node.set_typed("speed", current * 2.0);
+/// }
+/// ```
+pub trait AsArg<T> {
+    fn into_arg<'r>(self) -> CowArg<'r, T>
+    where
+        Self: 'r;
+}
+
+impl<S: AsRef<str>> AsArg<StringName> for S {
+    fn into_arg<'r>(self) -> CowArg<'r, StringName>
+    where
+        Self: 'r,
+    {
+        CowArg::Owned(StringName::from(self.as_ref()))
+    }
+}
+
+impl<S: AsRef<str>> AsArg<GodotString> for S {
+    fn into_arg<'r>(self) -> CowArg<'r, GodotString>
+    where
+        Self: 'r,
+    {
+        CowArg::Owned(GodotString::from(self.as_ref()))
+    }
+}
+
+macro_rules! impl_as_arg {
+    ($Target:ty: $($Source:ty),+) => {
+        impl AsArg<$Target> for $Target {
+            fn into_arg<'r>(self) -> CowArg<'r, $Target>
+            where
+                Self: 'r,
+            {
+                CowArg::Owned(self)
+            }
+        }
+
+        impl<'a> AsArg<$Target> for &'a $Target {
+            fn into_arg<'r>(self) -> CowArg<'r, $Target>
+            where
+                Self: 'r,
+            {
+                CowArg::Borrowed(self)
+            }
+        }
+
+        $(
+            impl AsArg<$Target> for $Source {
+                fn into_arg<'r>(self) -> CowArg<'r, $Target>
+                where
+                    Self: 'r,
+                {
+                    CowArg::Owned(<$Target>::from(self))
+                }
+            }
+
+            impl<'a> AsArg<$Target> for &'a $Source {
+                fn into_arg<'r>(self) -> CowArg<'r, $Target>
+                where
+                    Self: 'r,
+                {
+                    CowArg::Owned(<$Target>::from(self))
+                }
+            }
+        )+
+    };
+}
+
+impl_as_arg!(StringName: GodotString, NodePath);
+impl_as_arg!(GodotString: StringName, NodePath);
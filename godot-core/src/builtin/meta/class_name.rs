@@ -25,6 +25,9 @@ use crate::builtin::*;
 static CACHED_STRING_NAMES: sync::Mutex<Option<HashMap<ClassName, Box<StringName>>>> =
     sync::Mutex::new(None);
 
+// Names read back from Godot at runtime (e.g. from reflection dictionaries), so each distinct name is leaked only once.
+static INTERNED_NAMES: sync::Mutex<Option<HashMap<String, ClassName>>> = sync::Mutex::new(None);
+
 /// Name of a class registered with Godot.
 ///
 /// Holds the Godot name, not the Rust name (they sometimes differ, e.g. Godot `CSGMesh3D` vs Rust `CsgMesh3D`).
@@ -48,6 +51,35 @@ impl ClassName {
         Self { c_str }
     }
 
+    /// Creates a class name from a string only known at runtime, e.g. for dynamically registered classes.
+    ///
+    /// The string is leaked, since class names must remain valid for the lifetime of the program.
+    pub(crate) fn alloc_leaked(name: &str) -> Self {
+        let mut bytes = Vec::with_capacity(name.len() + 1);
+        bytes.extend_from_slice(name.as_bytes());
+        bytes.push(0);
+
+        Self::from_ascii_cstr(bytes.leak())
+    }
+
+    /// Like [`alloc_leaked()`][Self::alloc_leaked], but returns the existing class name if `name` was seen before.
+    pub(crate) fn alloc_interned(name: &str) -> Self {
+        if name.is_empty() {
+            return Self::none();
+        }
+
+        let mut guard = INTERNED_NAMES.lock().unwrap();
+        let map = guard.get_or_insert_with(HashMap::new);
+
+        if let Some(class_name) = map.get(name) {
+            return *class_name;
+        }
+
+        let class_name = Self::alloc_leaked(name);
+        map.insert(name.to_string(), class_name);
+        class_name
+    }
+
     #[doc(hidden)]
     pub fn none() -> Self {
         // In Godot, an empty class name means "no class".
@@ -4,8 +4,11 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::builtin::meta::{impl_godot_as_self, FromGodot, GodotConvert, GodotType, ToGodot};
+use crate::builtin::meta::{
+    impl_godot_as_self, Clamped, FromGodot, GodotConvert, GodotType, ToGodot,
+};
 use godot_ffi as sys;
+use std::num::Wrapping;
 
 // The following ToGodot/FromGodot/Convert impls are auto-generated for each engine type, co-located with their definitions:
 // - enum
@@ -138,6 +141,10 @@ macro_rules! impl_godot_scalar {
     };
 
     ($T:ty as $Via:ty $(, $param_metadata:expr)?; lossy) => {
+        impl_godot_scalar!($T as $Via $(, $param_metadata)?; converted_by |ffi| Some(ffi as $T));
+    };
+
+    ($T:ty as $Via:ty $(, $param_metadata:expr)?; converted_by $try_from_ffi:expr) => {
         impl GodotType for $T {
             type Ffi = $Via;
 
@@ -150,7 +157,8 @@ macro_rules! impl_godot_scalar {
             }
 
             fn try_from_ffi(ffi: Self::Ffi) -> Option<Self> {
-                Some(ffi as $T)
+                let try_from_ffi: fn($Via) -> Option<$T> = $try_from_ffi;
+                try_from_ffi(ffi)
             }
 
             $(
@@ -216,9 +224,63 @@ impl_godot_scalar!(
 impl_godot_scalar!(
     f32 as f64,
     sys::GDEXTENSION_METHOD_ARGUMENT_METADATA_REAL_IS_FLOAT;
-    lossy
+    converted_by narrow_f64
 );
 
+/// Rounds to the nearest `f32`, failing for finite values beyond its range (which would become infinite).
+fn narrow_f64(value: f64) -> Option<f32> {
+    let narrowed = value as f32;
+    if narrowed.is_infinite() && value.is_finite() {
+        None
+    } else {
+        Some(narrowed)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Integers without range checks
+
+macro_rules! impl_unchecked_int {
+    ($($T:ty),*) => {
+        $(
+            impl GodotConvert for Wrapping<$T> {
+                type Via = i64;
+            }
+
+            impl ToGodot for Wrapping<$T> {
+                fn to_godot(&self) -> Self::Via {
+                    i64::from(self.0)
+                }
+            }
+
+            impl FromGodot for Wrapping<$T> {
+                fn try_from_godot(via: Self::Via) -> Option<Self> {
+                    Some(Wrapping(via as $T))
+                }
+            }
+
+            impl GodotConvert for Clamped<$T> {
+                type Via = i64;
+            }
+
+            impl ToGodot for Clamped<$T> {
+                fn to_godot(&self) -> Self::Via {
+                    i64::from(self.0)
+                }
+            }
+
+            impl FromGodot for Clamped<$T> {
+                fn try_from_godot(via: Self::Via) -> Option<Self> {
+                    let clamped = via.clamp(i64::from(<$T>::MIN), i64::from(<$T>::MAX));
+                    Some(Clamped(clamped as $T))
+                }
+            }
+        )*
+    };
+}
+
+impl_unchecked_int!(i8, i16, i32, u8, u16, u32);
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Raw pointers
 
@@ -42,6 +42,9 @@ pub trait ToGodot: Sized + GodotConvert {
 
     /// Converts this type to a [Variant].
     fn to_variant(&self) -> Variant {
+        #[cfg(feature = "debug-overlay")]
+        crate::engine::metrics::variant_conversion();
+
         self.to_godot().to_ffi().ffi_to_variant()
     }
 }
@@ -69,6 +72,9 @@ pub trait FromGodot: Sized + GodotConvert {
 
     /// Performs the conversion from a [`Variant`].
     fn try_from_variant(variant: &Variant) -> Result<Self, VariantConversionError> {
+        #[cfg(feature = "debug-overlay")]
+        crate::engine::metrics::variant_conversion();
+
         let ffi = <Self::Via as GodotType>::Ffi::ffi_from_variant(variant)?;
         let via = Self::Via::try_from_ffi(ffi).ok_or(VariantConversionError::BadValue)?;
         Self::try_from_godot(via).ok_or(VariantConversionError::BadValue)
@@ -83,6 +89,16 @@ pub trait FromGodot: Sized + GodotConvert {
     }
 }
 
+/// Integer that is clamped into its range when converted from Godot.
+///
+/// Godot passes integers as `i64`. Converting them to narrower types normally fails for values out of range; this wrapper clamps them
+/// to the nearest bound instead, e.g. for a `#[func]` parameter. For truncation, use [`std::num::Wrapping`].
+///
+/// Named differently from `std::num::Saturating`, so both can be imported: that type is only stable since Rust 1.74, above the
+/// supported minimum of 1.70, so the conversion cannot be implemented for it yet.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct Clamped<T>(pub T);
+
 pub(crate) fn into_ffi<T: ToGodot>(t: T) -> <T::Via as GodotType>::Ffi {
     let via = t.into_godot();
     via.into_ffi()
@@ -6,13 +6,17 @@
 
 pub mod registration;
 
+mod as_arg;
 mod class_name;
 mod godot_convert;
+mod reflection;
 mod return_marshal;
 mod signature;
 
+pub use as_arg::*;
 pub use class_name::*;
 pub use godot_convert::*;
+pub use reflection::*;
 #[doc(hidden)]
 pub use return_marshal::*;
 #[doc(hidden)]
@@ -224,7 +228,7 @@ where
 /// Rusty abstraction of `sys::GDExtensionPropertyInfo`.
 ///
 /// Keeps the actual allocated values (the `sys` equivalent only keeps pointers, which fall out of scope).
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 // Note: is not #[non_exhaustive], so adding fields is a breaking change. Mostly used internally at the moment though.
 pub struct PropertyInfo {
     pub variant_type: VariantType,
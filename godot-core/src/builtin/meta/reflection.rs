@@ -0,0 +1,473 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Dictionary representations of properties, methods and classes, as used by Godot's reflection APIs, and typed queries of `ClassDB`.
+
+use godot_ffi as sys;
+
+use crate::builtin::meta::{ClassName, PropertyInfo, ToGodot};
+use crate::builtin::*;
+use crate::engine::global::{MethodFlags, PropertyHint, PropertyUsageFlags};
+use crate::engine::ClassDb;
+use crate::obj::{EngineEnum, GodotClass};
+
+/// Builders and dictionary conversions.
+///
+/// Godot's reflection APIs, such as `Object::get_property_list()`, `Object::add_user_signal()` or `_get_property_list()` overrides in
+/// scripts, pass properties as dictionaries with the keys `name`, `type`, `class_name`, `hint`, `hint_string` and `usage`. These
+/// methods build and read such dictionaries, so that their keys and value types need not be spelled out by hand.
+///
+/// ```no_run
+/// use godot::builtin::meta::PropertyInfo;
+/// use godot::engine::global::PropertyHint;
+/// use godot::prelude::*;
+///
+/// let speed = PropertyInfo::new("speed", VariantType::Float)
+///     .with_hint(PropertyHint::PROPERTY_HINT_RANGE, "0,100,0.5");
+///
+/// let dict: Dictionary = speed.to_dictionary();
+/// ```
+impl PropertyInfo {
+    /// Property `property_name` of type `variant_type`, with no hint and default usage.
+    ///
+    /// For properties of type `Object`, use [`new_object()`][Self::new_object] instead, so that the class name is set.
+    pub fn new(property_name: impl Into<StringName>, variant_type: VariantType) -> Self {
+        Self {
+            variant_type,
+            class_name: ClassName::none(),
+            property_name: property_name.into(),
+            hint: PropertyHint::PROPERTY_HINT_NONE,
+            hint_string: GodotString::new(),
+            usage: PropertyUsageFlags::PROPERTY_USAGE_DEFAULT,
+        }
+    }
+
+    /// Property `property_name` that accepts any variant.
+    ///
+    /// Unlike `new(name, VariantType::Nil)`, this sets the `NIL_IS_VARIANT` usage flag, which tells Godot that the type is not "void".
+    pub fn new_variant(property_name: impl Into<StringName>) -> Self {
+        Self::new(property_name, VariantType::Nil).with_usage(
+            PropertyUsageFlags::PROPERTY_USAGE_DEFAULT
+                | PropertyUsageFlags::PROPERTY_USAGE_NIL_IS_VARIANT,
+        )
+    }
+
+    /// Property `property_name` holding an object of class `T`.
+    pub fn new_object<T: GodotClass>(property_name: impl Into<StringName>) -> Self {
+        Self {
+            class_name: T::class_name(),
+            ..Self::new(property_name, VariantType::Object)
+        }
+    }
+
+    /// Sets the editor hint, e.g. `PROPERTY_HINT_RANGE` with hint string `"0,100,1"`.
+    pub fn with_hint(mut self, hint: PropertyHint, hint_string: impl Into<GodotString>) -> Self {
+        self.hint = hint;
+        self.hint_string = hint_string.into();
+        self
+    }
+
+    /// Replaces the usage flags, which default to `PROPERTY_USAGE_DEFAULT`.
+    pub fn with_usage(mut self, usage: PropertyUsageFlags) -> Self {
+        self.usage = usage;
+        self
+    }
+
+    /// Dictionary with the keys expected by Godot.
+    pub fn to_dictionary(&self) -> Dictionary {
+        dict! {
+            "name": self.property_name.to_string(),
+            "type": self.variant_type.sys() as i64,
+            "class_name": self.class_name.to_string_name(),
+            "hint": self.hint.ord(),
+            "hint_string": self.hint_string.clone(),
+            "usage": self.usage.ord(),
+        }
+    }
+
+    /// Reads a dictionary as returned by Godot, e.g. an element of `Object::get_property_list()`.
+    ///
+    /// Returns `None` if `name` is missing. Other missing keys take the values of [`new()`][Self::new] with `VariantType::Nil`;
+    /// unknown hints are read as `PROPERTY_HINT_NONE`.
+    pub fn from_dictionary(dict: &Dictionary) -> Option<Self> {
+        let property_name = StringName::from(dict.get("name")?.to_string());
+        let mut info = Self::new(property_name, VariantType::Nil);
+
+        if let Some(ty) = get_int(dict, "type") {
+            info.variant_type = VariantType::from_sys(ty as sys::GDExtensionVariantType);
+        }
+
+        if let Some(class_name) = dict.get("class_name") {
+            info.class_name = ClassName::alloc_interned(&class_name.to_string());
+        }
+
+        if let Some(hint) = get_int(dict, "hint") {
+            info.hint =
+                PropertyHint::try_from_ord(hint as i32).unwrap_or(PropertyHint::PROPERTY_HINT_NONE);
+        }
+
+        if let Some(hint_string) = dict.get("hint_string") {
+            info.hint_string = GodotString::from(hint_string.to_string());
+        }
+
+        if let Some(usage) = get_int(dict, "usage") {
+            info.usage = PropertyUsageFlags::from_ord(usage as i32);
+        }
+
+        Some(info)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Method or signal, as described by `Object::get_method_list()` and `Object::get_signal_list()`.
+///
+/// Signals use the same representation, with only the name and arguments being relevant.
+///
+/// ```no_run
+/// use godot::builtin::meta::{MethodInfo, PropertyInfo};
+/// use godot::prelude::*;
+///
+/// fn add_hit_signal(object: &mut Gd<Object>) {
+///     let signal = MethodInfo::new("hit")
+///         .with_argument(PropertyInfo::new_object::<Node>("by"))
+///         .with_argument(PropertyInfo::new("damage", VariantType::Int));
+///
+///     object
+///         .add_user_signal_ex(signal.method_name.to_string().into())
+///         .arguments(signal.arguments_array())
+///         .done();
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct MethodInfo {
+    pub method_name: StringName,
+    pub return_type: PropertyInfo,
+    pub arguments: Vec<PropertyInfo>,
+
+    /// Default values of the last `default_arguments.len()` arguments.
+    pub default_arguments: Vec<Variant>,
+    pub flags: MethodFlags,
+    pub id: i32,
+}
+
+impl MethodInfo {
+    /// Method without arguments and without return value.
+    pub fn new(method_name: impl Into<StringName>) -> Self {
+        Self {
+            method_name: method_name.into(),
+            return_type: PropertyInfo::new("", VariantType::Nil),
+            arguments: Vec::new(),
+            default_arguments: Vec::new(),
+            flags: MethodFlags::METHOD_FLAGS_DEFAULT,
+            id: 0,
+        }
+    }
+
+    /// Appends an argument.
+    pub fn with_argument(mut self, argument: PropertyInfo) -> Self {
+        self.arguments.push(argument);
+        self
+    }
+
+    /// Sets the return type; its name is ignored by Godot.
+    pub fn with_return_type(mut self, return_type: PropertyInfo) -> Self {
+        self.return_type = return_type;
+        self
+    }
+
+    /// Replaces the flags, which default to `METHOD_FLAGS_DEFAULT`.
+    pub fn with_flags(mut self, flags: MethodFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// The arguments as array of dictionaries, as accepted by `Object::add_user_signal()`.
+    pub fn arguments_array(&self) -> VariantArray {
+        self.arguments
+            .iter()
+            .map(|argument| argument.to_dictionary().to_variant())
+            .collect()
+    }
+
+    /// Dictionary with the keys expected by Godot: `name`, `args`, `default_args`, `flags`, `id` and `return`.
+    pub fn to_dictionary(&self) -> Dictionary {
+        let default_args: VariantArray = self.default_arguments.iter().cloned().collect();
+
+        dict! {
+            "name": self.method_name.to_string(),
+            "args": self.arguments_array(),
+            "default_args": default_args,
+            "flags": self.flags.ord(),
+            "id": self.id,
+            "return": self.return_type.to_dictionary(),
+        }
+    }
+
+    /// Reads a dictionary as returned by Godot, e.g. an element of `Object::get_method_list()`.
+    ///
+    /// Returns `None` if `name` is missing, or if one of the arguments has no name.
+    pub fn from_dictionary(dict: &Dictionary) -> Option<Self> {
+        let method_name = StringName::from(dict.get("name")?.to_string());
+        let mut info = Self::new(method_name);
+
+        if let Some(args) = get_array(dict, "args") {
+            info.arguments = args
+                .iter_shared()
+                .map(|arg| PropertyInfo::from_dictionary(&arg.try_to::<Dictionary>().ok()?))
+                .collect::<Option<_>>()?;
+        }
+
+        if let Some(default_args) = get_array(dict, "default_args") {
+            info.default_arguments = default_args.iter_shared().collect();
+        }
+
+        if let Some(flags) = get_int(dict, "flags") {
+            info.flags = MethodFlags::from_ord(flags as i32);
+        }
+
+        if let Some(id) = get_int(dict, "id") {
+            info.id = id as i32;
+        }
+
+        if let Some(return_type) = dict
+            .get("return")
+            .and_then(|ret| ret.try_to::<Dictionary>().ok())
+        {
+            info.return_type = PropertyInfo::from_dictionary(&return_type)?;
+        }
+
+        Some(info)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Script class with a global name, as described by `ProjectSettings::get_global_class_list()`.
+///
+/// `base` is the name of the base class, which may itself be a script class and thus unknown to `ClassDb`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClassInfo {
+    pub class_name: StringName,
+    pub base: StringName,
+    pub language: StringName,
+    pub path: GodotString,
+    pub icon: GodotString,
+}
+
+impl ClassInfo {
+    /// Dictionary with the keys expected by Godot: `class`, `base`, `language`, `path` and `icon`.
+    pub fn to_dictionary(&self) -> Dictionary {
+        dict! {
+            "class": self.class_name.clone(),
+            "base": self.base.clone(),
+            "language": self.language.clone(),
+            "path": self.path.clone(),
+            "icon": self.icon.clone(),
+        }
+    }
+
+    /// Reads a dictionary as returned by Godot. Returns `None` if `class` is missing; other missing keys are read as empty.
+    pub fn from_dictionary(dict: &Dictionary) -> Option<Self> {
+        let get_string = |key: &str| {
+            dict.get(key)
+                .map(|value| value.to_string())
+                .unwrap_or_default()
+        };
+
+        Some(Self {
+            class_name: StringName::from(dict.get("class")?.to_string()),
+            base: StringName::from(get_string("base")),
+            language: StringName::from(get_string("language")),
+            path: GodotString::from(get_string("path")),
+            icon: GodotString::from(get_string("icon")),
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Members and metadata of a class registered in `ClassDB`, as returned by [`ClassDb::class_info()`].
+///
+/// Works the same for engine classes and Rust classes, listing what `#[func]`, `#[signal]`, `#[var]` and `#[constant]` registered.
+/// The lists only contain the class's own members; inherited ones are described by the ancestors from [`with_ancestors()`][Self::with_ancestors].
+///
+/// ```no_run
+/// use godot::engine::ClassDb;
+/// use godot::prelude::*;
+///
+/// for class in ClassDb::class_info::<Node3D>().with_ancestors() {
+///     for property in &class.properties {
+///         godot_print!("{}.{}: {:?}", class.class_name, property.property_name, property.variant_type);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ClassDescription {
+    pub class_name: StringName,
+
+    /// Direct base class; `None` for `Object`.
+    pub parent: Option<StringName>,
+
+    /// Whether `ClassDB.instantiate()` can create the class, i.e. it is neither abstract nor a singleton-only class.
+    pub is_instantiable: bool,
+
+    pub methods: Vec<MethodInfo>,
+    pub signals: Vec<MethodInfo>,
+
+    /// Properties, without the group and category entries that only structure the inspector.
+    pub properties: Vec<PropertyInfo>,
+
+    /// Integer constants, including enumerators.
+    pub constants: Vec<ConstantInfo>,
+}
+
+impl ClassDescription {
+    /// The method called `name`, if the class itself declares it.
+    pub fn method(&self, name: &str) -> Option<&MethodInfo> {
+        self.methods
+            .iter()
+            .find(|method| method.method_name == name.into())
+    }
+
+    /// The signal called `name`, if the class itself declares it.
+    pub fn signal(&self, name: &str) -> Option<&MethodInfo> {
+        self.signals
+            .iter()
+            .find(|signal| signal.method_name == name.into())
+    }
+
+    /// The property called `name`, if the class itself declares it.
+    pub fn property(&self, name: &str) -> Option<&PropertyInfo> {
+        self.properties
+            .iter()
+            .find(|property| property.property_name == name.into())
+    }
+
+    /// The description of the base class; `None` for `Object`.
+    pub fn parent_info(&self) -> Option<ClassDescription> {
+        self.parent
+            .as_ref()
+            .and_then(|parent| ClassDb::class_info_by_name(parent.clone()))
+    }
+
+    /// This class followed by its ancestors, up to `Object`.
+    pub fn with_ancestors(self) -> Vec<ClassDescription> {
+        let mut classes = vec![self];
+        while let Some(parent) = classes.last().and_then(ClassDescription::parent_info) {
+            classes.push(parent);
+        }
+
+        classes
+    }
+}
+
+/// Integer constant of a class, as part of a [`ClassDescription`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConstantInfo {
+    pub name: StringName,
+    pub value: i64,
+
+    /// The enum or bitfield the constant belongs to, if any.
+    pub enum_name: Option<StringName>,
+}
+
+impl ClassDb {
+    /// ⚠️ Describes the class `T`, as currently registered in `ClassDB`.
+    ///
+    /// # Panics
+    /// If `T` is not registered, e.g. a Rust class whose init level has not been loaded yet.
+    pub fn class_info<T: GodotClass>() -> ClassDescription {
+        let class_name = T::class_name();
+
+        Self::class_info_by_name(class_name.to_string_name())
+            .unwrap_or_else(|| panic!("class `{class_name}` is not registered in ClassDB"))
+    }
+
+    /// Describes the class called `class_name`; `None` if there is no such class in `ClassDB`.
+    ///
+    /// Useful for classes only known at runtime, e.g. from [`ClassDescription::parent`] or `ClassDB.get_class_list()`.
+    pub fn class_info_by_name(class_name: impl Into<StringName>) -> Option<ClassDescription> {
+        let db = ClassDb::singleton();
+        let class_name = class_name.into();
+        if !db.class_exists(class_name.clone()) {
+            return None;
+        }
+
+        let methods = db
+            .class_get_method_list_ex(class_name.clone())
+            .no_inheritance(true)
+            .done()
+            .iter_shared()
+            .filter_map(|method| MethodInfo::from_dictionary(&method))
+            .collect();
+
+        let signals = db
+            .class_get_signal_list_ex(class_name.clone())
+            .no_inheritance(true)
+            .done()
+            .iter_shared()
+            .filter_map(|signal| MethodInfo::from_dictionary(&signal))
+            .collect();
+
+        let layout_only = PropertyUsageFlags::PROPERTY_USAGE_CATEGORY.ord()
+            | PropertyUsageFlags::PROPERTY_USAGE_GROUP.ord()
+            | PropertyUsageFlags::PROPERTY_USAGE_SUBGROUP.ord();
+        let properties = db
+            .class_get_property_list_ex(class_name.clone())
+            .no_inheritance(true)
+            .done()
+            .iter_shared()
+            .filter_map(|property| PropertyInfo::from_dictionary(&property))
+            .filter(|property| property.usage.ord() & layout_only == 0)
+            .collect();
+
+        let constants = db
+            .class_get_integer_constant_list_ex(class_name.clone())
+            .no_inheritance(true)
+            .done()
+            .as_slice()
+            .iter()
+            .map(|name| {
+                let name = StringName::from(name.to_string());
+                let enum_name = db
+                    .class_get_integer_constant_enum_ex(class_name.clone(), name.clone())
+                    .no_inheritance(true)
+                    .done();
+
+                ConstantInfo {
+                    value: db.class_get_integer_constant(class_name.clone(), name.clone()),
+                    enum_name: (!enum_name.is_empty()).then_some(enum_name),
+                    name,
+                }
+            })
+            .collect();
+
+        let parent = db.get_parent_class(class_name.clone());
+
+        Some(ClassDescription {
+            parent: (!parent.is_empty()).then_some(parent),
+            is_instantiable: db.can_instantiate(class_name.clone()),
+            class_name,
+            methods,
+            signals,
+            properties,
+            constants,
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+fn get_int(dict: &Dictionary, key: &str) -> Option<i64> {
+    dict.get(key).and_then(|value| value.try_to::<i64>().ok())
+}
+
+fn get_array(dict: &Dictionary, key: &str) -> Option<VariantArray> {
+    dict.get(key)
+        .and_then(|value| value.try_to::<VariantArray>().ok())
+}
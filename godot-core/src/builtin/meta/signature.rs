@@ -28,13 +28,17 @@ pub trait VarcallSignatureTuple: PtrcallSignatureTuple {
     // TODO(uninit) - can we use this for varcall/ptrcall?
     // ret: sys::GDExtensionUninitializedVariantPtr
     // ret: sys::GDExtensionUninitializedTypePtr
+    /// Godot does not fill in omitted trailing arguments; they are taken from `default_args`, the values of the last
+    /// `default_args().len()` parameters.
     unsafe fn in_varcall(
         instance_ptr: sys::GDExtensionClassInstancePtr,
         args_ptr: *const sys::GDExtensionConstVariantPtr,
+        arg_count: sys::GDExtensionInt,
         ret: sys::GDExtensionVariantPtr,
         err: *mut sys::GDExtensionCallError,
         func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
         method_name: &str,
+        default_args: fn() -> Vec<Variant>,
     );
 
     unsafe fn out_class_varcall(
@@ -151,12 +155,27 @@ macro_rules! impl_varcall_signature_for_tuple {
             unsafe fn in_varcall(
                 instance_ptr: sys::GDExtensionClassInstancePtr,
                 args_ptr: *const sys::GDExtensionConstVariantPtr,
+                arg_count: sys::GDExtensionInt,
                 ret: sys::GDExtensionVariantPtr,
                 err: *mut sys::GDExtensionCallError,
                 func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
                 method_name: &str,
+                default_args: fn() -> Vec<Variant>,
             ) {
                 //$crate::out!("in_varcall: {method_name}");
+                let padded;
+                let args_ptr = if arg_count == $PARAM_COUNT {
+                    args_ptr
+                } else {
+                    match varcall_pad_args(args_ptr, arg_count, $PARAM_COUNT, default_args, err) {
+                        Some(args) => {
+                            padded = args;
+                            padded.as_ptr()
+                        }
+                        None => return,
+                    }
+                };
+
                 let args = ($(
                     unsafe { varcall_arg::<$Pn, $n>(args_ptr, method_name) },
                 )*) ;
@@ -174,6 +193,8 @@ macro_rules! impl_varcall_signature_for_tuple {
                 varargs: &[Variant],
             ) -> Self::Ret {
                 //$crate::out!("out_class_varcall: {method_name}");
+                #[cfg(feature = "debug-overlay")]
+                crate::engine::metrics::ffi_call();
 
                 // Note: varcalls are not safe from failing, if the happen through an object pointer -> validity check necessary.
                 if let Some(instance_id) = maybe_instance_id {
@@ -216,6 +237,8 @@ macro_rules! impl_varcall_signature_for_tuple {
                 varargs: &[Variant],
             ) -> Self::Ret {
                 //$crate::out!("out_utility_ptrcall_varargs: {method_name}");
+                #[cfg(feature = "debug-overlay")]
+                crate::engine::metrics::ffi_call();
                 let explicit_args: [Variant; $PARAM_COUNT] = [
                     $(
                         GodotFfiVariant::ffi_to_variant(&into_ffi($pn)),
@@ -287,6 +310,8 @@ macro_rules! impl_ptrcall_signature_for_tuple {
                 ($($pn,)*): Self::Params,
             ) -> Self::Ret {
                 // $crate::out!("out_class_ptrcall: {method_name}");
+                #[cfg(feature = "debug-overlay")]
+                crate::engine::metrics::ffi_call();
                 if let Some(instance_id) = maybe_instance_id {
                     crate::engine::ensure_object_alive(Some(instance_id), object_ptr, method_name);
                 }
@@ -318,6 +343,8 @@ macro_rules! impl_ptrcall_signature_for_tuple {
                 ($($pn,)*): Self::Params,
             ) -> Self::Ret {
                 // $crate::out!("out_builtin_ptrcall: {method_name}");
+                #[cfg(feature = "debug-overlay")]
+                crate::engine::metrics::ffi_call();
                 #[allow(clippy::let_unit_value)]
                 let marshalled_args = (
                     $(
@@ -342,6 +369,8 @@ macro_rules! impl_ptrcall_signature_for_tuple {
                 ($($pn,)*): Self::Params,
             ) -> Self::Ret {
                 // $crate::out!("out_utility_ptrcall: {method_name}");
+                #[cfg(feature = "debug-overlay")]
+                crate::engine::metrics::ffi_call();
                 #[allow(clippy::let_unit_value)]
                 let marshalled_args = (
                     $(
@@ -363,6 +392,66 @@ macro_rules! impl_ptrcall_signature_for_tuple {
     };
 }
 
+/// Argument pointers of a varcall that omitted trailing arguments, with the omitted ones replaced by their default values.
+///
+/// Returns `None` and sets `err` if the caller passed fewer arguments than without defaults, or more than `param_count`.
+///
+/// # Safety
+/// - It must be safe to dereference the first `arg_count` pointers at `args_ptr`.
+/// - It must be safe to write a `sys::GDExtensionCallError` once to `err`.
+unsafe fn varcall_pad_args(
+    args_ptr: *const sys::GDExtensionConstVariantPtr,
+    arg_count: sys::GDExtensionInt,
+    param_count: usize,
+    default_args: fn() -> Vec<Variant>,
+    err: *mut sys::GDExtensionCallError,
+) -> Option<PaddedArgs> {
+    let defaults = default_args();
+    let required = param_count - defaults.len();
+
+    let arg_count = match usize::try_from(arg_count) {
+        Ok(count) if (required..=param_count).contains(&count) => count,
+        _ => {
+            *err = sys::default_call_error();
+            if arg_count < required as sys::GDExtensionInt {
+                (*err).error = sys::GDEXTENSION_CALL_ERROR_TOO_FEW_ARGUMENTS;
+                (*err).expected = required as i32;
+            } else {
+                (*err).error = sys::GDEXTENSION_CALL_ERROR_TOO_MANY_ARGUMENTS;
+                (*err).expected = param_count as i32;
+            }
+            return None;
+        }
+    };
+
+    let ptrs = (0..arg_count)
+        .map(|i| *args_ptr.add(i))
+        .chain(
+            defaults[arg_count - required..]
+                .iter()
+                .map(|variant| variant.var_sys_const()),
+        )
+        .collect();
+
+    // The pointers stay valid when `defaults` is moved, as they point into its heap allocation.
+    Some(PaddedArgs {
+        ptrs,
+        _defaults: defaults,
+    })
+}
+
+/// Argument pointers for a varcall, together with the default values that some of them point to.
+struct PaddedArgs {
+    ptrs: Vec<sys::GDExtensionConstVariantPtr>,
+    _defaults: Vec<Variant>,
+}
+
+impl PaddedArgs {
+    fn as_ptr(&self) -> *const sys::GDExtensionConstVariantPtr {
+        self.ptrs.as_ptr()
+    }
+}
+
 /// Convert the `N`th argument of `args_ptr` into a value of type `P`.
 ///
 /// # Safety
@@ -427,7 +516,53 @@ unsafe fn ptrcall_arg<P: FromGodot, const N: isize>(
         call_type,
     );
 
-    try_from_ffi(ffi).unwrap_or_else(|| param_error::<P>(method_name, N as i32, &"TODO"))
+    #[cfg(debug_assertions)]
+    if <P::Via as GodotType>::Ffi::variant_type() == sys::VariantType::Object {
+        check_object_arg::<P>(
+            sys::GodotFfi::sys(&ffi) as sys::GDExtensionObjectPtr,
+            method_name,
+            N as i32,
+        );
+    }
+
+    try_from_ffi(ffi).unwrap_or_else(|| {
+        // The conversion consumed the value; read it again for the error message.
+        let ffi = <P::Via as GodotType>::Ffi::from_arg_ptr(
+            sys::force_mut_ptr(*args_ptr.offset(N)),
+            call_type,
+        );
+        param_error::<P>(method_name, N as i32, &ffi.ffi_to_variant())
+    })
+}
+
+/// Checks that an object passed via ptrcall has the class expected by the parameter.
+///
+/// Varcalls go through a checked cast, but ptrcalls hand over the raw object pointer. An inspector or script assigning e.g. a
+/// `Texture3D` to a `Gd<Texture2D>` property would otherwise be reinterpreted as the wrong class.
+///
+/// # Safety
+/// `obj` must be null or point to a live object.
+#[cfg(debug_assertions)]
+unsafe fn check_object_arg<P: FromGodot>(
+    obj: sys::GDExtensionObjectPtr,
+    method_name: &str,
+    index: i32,
+) {
+    if obj.is_null() {
+        return;
+    }
+
+    let expected = <P::Via as GodotType>::class_name();
+
+    // Weak reference: must not decrement the refcount on drop.
+    let object = std::mem::ManuallyDrop::new(
+        crate::obj::Gd::<crate::engine::Object>::from_obj_sys_weak(obj),
+    );
+
+    if !object.is_class(expected.to_godot_string()) {
+        let actual = object.get_class();
+        panic!("{method_name}: parameter [{index}] expects an object of class {expected}, but received {actual}");
+    }
 }
 
 /// Moves `ret_val` into `ret`.
@@ -298,6 +298,25 @@ macro_rules! impl_packed_array {
                 self.as_inner().append_array(other.clone());
             }
 
+            /// Appends all elements of a slice at the end of this array.
+            ///
+            /// Unlike [`Extend`], this resizes the array once and then copies the elements in bulk.
+            pub fn extend_from_slice(&mut self, slice: &[$Element]) {
+                if slice.is_empty() {
+                    return;
+                }
+
+                let old_len = self.len();
+                self.resize(old_len + slice.len());
+                self.as_mut_slice()[old_len..].clone_from_slice(slice);
+            }
+
+            /// Replaces the contents of this array with the elements of a slice, resizing it as needed.
+            pub fn set_from_slice(&mut self, slice: &[$Element]) {
+                self.resize(slice.len());
+                self.as_mut_slice().clone_from_slice(slice);
+            }
+
             /// Reverses the order of the elements in the array.
             pub fn reverse(&mut self) {
                 self.as_inner().reverse();
@@ -389,24 +408,18 @@ macro_rules! impl_packed_array {
         impl From<&[$Element]> for $PackedArray {
             fn from(slice: &[$Element]) -> Self {
                 let mut array = Self::new();
-                let len = slice.len();
-                if len == 0 {
-                    return array;
-                }
-                array.resize(len);
-                let ptr = array.ptr_mut(0);
-                for (i, element) in slice.iter().enumerate() {
-                    // SAFETY: The array contains exactly `len` elements, stored contiguously in memory.
-                    unsafe {
-                        // `GodotString` does not implement `Copy` so we have to call `.clone()`
-                        // here.
-                        *ptr.offset(to_isize(i)) = element.clone();
-                    }
-                }
+                array.set_from_slice(slice);
                 array
             }
         }
 
+        #[doc = concat!("Creates a `", stringify!($PackedArray), "` from the given vector.")]
+        impl From<Vec<$Element>> for $PackedArray {
+            fn from(vec: Vec<$Element>) -> Self {
+                Self::from(vec.as_slice())
+            }
+        }
+
         #[doc = concat!("Creates a `", stringify!($PackedArray), "` from an iterator.")]
         impl FromIterator<$Element> for $PackedArray {
             fn from_iter<I: IntoIterator<Item = $Element>>(iter: I) -> Self {
@@ -636,3 +649,18 @@ impl_packed_array!(
         PartialEq => packed_color_array_operator_equal;
     },
 );
+
+// `as_slice()` and the bulk copies reinterpret Godot's element storage as Rust types; make sure the layouts agree.
+const _: () = {
+    use std::mem::{align_of, size_of};
+
+    assert!(
+        size_of::<Vector2>() == 2 * size_of::<real>()
+            && align_of::<Vector2>() == align_of::<real>()
+    );
+    assert!(
+        size_of::<Vector3>() == 3 * size_of::<real>()
+            && align_of::<Vector3>() == align_of::<real>()
+    );
+    assert!(size_of::<Color>() == 4 * size_of::<f32>() && align_of::<Color>() == align_of::<f32>());
+};
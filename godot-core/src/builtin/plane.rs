@@ -35,6 +35,24 @@ pub struct Plane {
 }
 
 impl Plane {
+    /// Plane containing the Y and Z axes, with the normal pointing in +X direction.
+    pub const PLANE_YZ: Self = Self {
+        normal: Vector3::new(1.0, 0.0, 0.0),
+        d: 0.0,
+    };
+
+    /// Plane containing the X and Z axes, with the normal pointing in +Y direction.
+    pub const PLANE_XZ: Self = Self {
+        normal: Vector3::new(0.0, 1.0, 0.0),
+        d: 0.0,
+    };
+
+    /// Plane containing the X and Y axes, with the normal pointing in +Z direction.
+    pub const PLANE_XY: Self = Self {
+        normal: Vector3::new(0.0, 0.0, 1.0),
+        d: 0.0,
+    };
+
     /// Creates a new `Plane` from the `normal` and the distance from the origin `d`.
     ///
     /// # Panics
@@ -247,6 +265,24 @@ impl Plane {
             self.normal
         );
     }
+
+    /// Returns `true` if the two planes are approximately equal, by comparing `normal` and `d`.
+    ///
+    /// Opposite planes (with negated `normal` and `d`) are not considered equal; see [`is_equal_approx_any_side()`][Self::is_equal_approx_any_side].
+    ///
+    /// _Godot equivalent: `Plane.is_equal_approx()`_
+    #[inline]
+    pub fn is_equal_approx(&self, other: &Self) -> bool {
+        self.approx_eq(other)
+    }
+
+    /// Returns `true` if the two planes are approximately equal, or if one is approximately the negation of the other.
+    ///
+    /// _Godot equivalent: `Plane.is_equal_approx_any_side()`_
+    #[inline]
+    pub fn is_equal_approx_any_side(&self, other: &Self) -> bool {
+        self.approx_eq(other) || self.approx_eq(&-*other)
+    }
 }
 
 impl Neg for Plane {
@@ -272,15 +308,13 @@ unsafe impl GodotFfi for Plane {
 impl_godot_as_self!(Plane);
 
 impl ApproxEq for Plane {
-    /// Finds whether the two planes are approximately equal.
-    ///
     /// Returns if the two `Plane`s are approximately equal, by comparing `normal` and `d` separately.
-    /// If one plane is a negation of the other (both `normal` and `d` have opposite signs), they are considered approximately equal.
+    ///
+    /// Like in Godot, a plane is not considered equal to its negation (with opposite `normal` and `d`), even though both describe the
+    /// same set of points. Use [`Plane::is_equal_approx_any_side()`] for that.
     #[inline]
     fn approx_eq(&self, other: &Self) -> bool {
-        (Vector3::approx_eq(&self.normal, &other.normal) //.
-            && self.d.approx_eq(&other.d))
-            || (Vector3::approx_eq(&self.normal, &(-other.normal)) && self.d.approx_eq(&-other.d))
+        Vector3::approx_eq(&self.normal, &other.normal) && self.d.approx_eq(&other.d)
     }
 }
 
@@ -597,6 +631,11 @@ mod test {
         // Although considered approximately equal with `xy_plane`, `almost_xy_plane_a` is not considered approximately
         // equal with `almost_xy_plane_d` because the baseline comparison is tighter.
         assert_ne_approx!(almost_xy_plane_a, approx_xy_plane_a);
+
+        // Negated planes contain the same points, but only compare equal when ignoring the side.
+        assert_ne_approx!(approx_xy_plane_b, -approx_xy_plane_b);
+        assert!(approx_xy_plane_b.is_equal_approx_any_side(&-approx_xy_plane_b));
+        assert!(xy_plane.is_equal_approx_any_side(&-approx_xy_plane_b));
     }
 
     /// Tests `normalize()`.
@@ -24,7 +24,10 @@ pub struct Quaternion {
 }
 
 impl Quaternion {
-    pub fn new(x: real, y: real, z: real, w: real) -> Self {
+    /// The identity quaternion, representing no rotation.
+    pub const IDENTITY: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+
+    pub const fn new(x: real, y: real, z: real, w: real) -> Self {
         Self { x, y, z, w }
     }
 
@@ -192,6 +195,14 @@ impl Quaternion {
     pub fn as_inner(&self) -> inner::InnerQuaternion {
         inner::InnerQuaternion::from_outer(self)
     }
+
+    /// Returns `true` if the two quaternions are approximately equal, by comparing each component.
+    ///
+    /// _Godot equivalent: `Quaternion.is_equal_approx()`_
+    #[inline]
+    pub fn is_equal_approx(self, other: Self) -> bool {
+        self.approx_eq(&other)
+    }
 }
 
 impl Add for Quaternion {
@@ -267,7 +278,7 @@ impl std::fmt::Display for Quaternion {
 
 impl Default for Quaternion {
     fn default() -> Self {
-        Self::new(0.0, 0.0, 0.0, 1.0)
+        Self::IDENTITY
     }
 }
 
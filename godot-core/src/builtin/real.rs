@@ -38,6 +38,9 @@ mod real_mod {
     /// either 32-bit or 64-bit floats, for example [`Vector2`][crate::builtin::Vector2]. To convert between [`real`] and [`f32`] or
     /// [`f64`], see [`RealConv`](super::RealConv).
     ///
+    /// The frame `delta` of the virtual methods `process()` and `physics_process()` is also `real`. Other `float` parameters and
+    /// properties of engine classes keep the type declared by Godot, mostly `f64`.
+    ///
     /// See also the [Godot docs on float](https://docs.godotengine.org/en/stable/classes/class_float.html).
     // As this is a scalar value, we will use a non-standard type name.
     #[allow(non_camel_case_types)]
@@ -106,6 +109,9 @@ mod real_mod {
     /// either 32-bit or 64-bit floats, for example [`Vector2`](super::Vector2). To convert between [`real`] and [`f32`] or
     /// [`f64`], see [`RealConv`](super::RealConv).
     ///
+    /// The frame `delta` of the virtual methods `process()` and `physics_process()` is also `real`. Other `float` parameters and
+    /// properties of engine classes keep the type declared by Godot, mostly `f64`.
+    ///
     /// See also the [Godot docs on float](https://docs.godotengine.org/en/stable/classes/class_float.html).
     // As this is a scalar value, we will use a non-standard type name.
     #[allow(non_camel_case_types)]
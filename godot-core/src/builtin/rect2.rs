@@ -8,7 +8,7 @@ use godot_ffi as sys;
 use sys::{ffi_methods, GodotFfi};
 
 use crate::builtin::math::ApproxEq;
-use crate::builtin::{real, Rect2i, RectSide, Vector2};
+use crate::builtin::{real, FloatToIntError, Rect2i, RectSide, RoundingMode, Vector2};
 
 use super::meta::impl_godot_as_self;
 
@@ -251,6 +251,22 @@ impl Rect2 {
             self.size
         );
     }
+
+    /// Returns `true` if the two rectangles are approximately equal, by comparing `position` and `size`.
+    ///
+    /// _Godot equivalent: `Rect2.is_equal_approx()`_
+    #[inline]
+    pub fn is_equal_approx(&self, other: &Self) -> bool {
+        self.approx_eq(other)
+    }
+
+    /// Converts to a [`Rect2i`], rounding the components of position and size according to `mode`.
+    ///
+    /// To convert without loss, or fail otherwise, use `Rect2i::try_from()`.
+    #[inline]
+    pub fn to_rect2i(&self, mode: RoundingMode) -> Rect2i {
+        Rect2i::new(self.position.to_vector2i(mode), self.size.to_vector2i(mode))
+    }
 }
 
 // SAFETY:
@@ -274,6 +290,23 @@ impl ApproxEq for Rect2 {
     }
 }
 
+impl From<Rect2i> for Rect2 {
+    #[inline]
+    fn from(rect: Rect2i) -> Self {
+        Self::from_rect2i(rect)
+    }
+}
+
+/// Succeeds if position and size consist of integers in the `i32` range.
+impl TryFrom<Rect2> for Rect2i {
+    type Error = FloatToIntError;
+
+    #[inline]
+    fn try_from(rect: Rect2) -> Result<Self, Self::Error> {
+        Ok(Self::new(rect.position.try_into()?, rect.size.try_into()?))
+    }
+}
+
 impl std::fmt::Display for Rect2 {
     /// Formats `Rect2` to match Godot's string representation.
     ///
@@ -292,10 +325,31 @@ impl std::fmt::Display for Rect2 {
 
 #[cfg(test)]
 mod test {
+    use super::*;
+
+    #[test]
+    fn int_conversions() {
+        let rect = Rect2::from_components(0.5, -1.5, 2.0, 3.25);
+
+        assert_eq!(
+            rect.to_rect2i(RoundingMode::Floor),
+            Rect2i::from_components(0, -2, 2, 3)
+        );
+        assert_eq!(
+            Rect2::from(Rect2i::from_components(1, 2, 3, 4)),
+            Rect2::from_components(1.0, 2.0, 3.0, 4.0)
+        );
+        assert_eq!(
+            Rect2i::try_from(Rect2::from_components(1.0, 2.0, 3.0, 4.0)),
+            Ok(Rect2i::from_components(1, 2, 3, 4))
+        );
+        assert!(Rect2i::try_from(rect).is_err());
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
-        let rect = super::Rect2::default();
+        let rect = Rect2::default();
         let expected_json = "{\"position\":{\"x\":0.0,\"y\":0.0},\"size\":{\"x\":0.0,\"y\":0.0}}";
 
         crate::builtin::test_utils::roundtrip(&rect, expected_json);
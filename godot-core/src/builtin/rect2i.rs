@@ -58,10 +58,10 @@ impl Rect2i {
 
     /// Create a new `Rect2i` with the first corner at `position` and the opposite corner at `end`.
     #[inline]
-    pub fn from_corners(position: Vector2i, end: Vector2i) -> Self {
+    pub const fn from_corners(position: Vector2i, end: Vector2i) -> Self {
         Self {
             position,
-            size: end - position,
+            size: Vector2i::new(end.x - position.x, end.y - position.y),
         }
     }
 
@@ -11,11 +11,14 @@ mod macros;
 mod node_path;
 mod string_chars;
 mod string_name;
+mod string_name_cache;
 
 pub use godot_string::*;
 pub use node_path::*;
 pub use string_name::*;
 
+pub(crate) use string_name_cache::clear as clear_string_name_cache;
+
 use super::meta::{FromGodot, GodotConvert, ToGodot};
 
 impl GodotConvert for &str {
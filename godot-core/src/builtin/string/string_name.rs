@@ -187,15 +187,24 @@ impl<S> From<S> for StringName
 where
     S: AsRef<str>,
 {
-    #[cfg(before_api = "4.2")]
+    /// Converts a Rust string to a `StringName`.
+    ///
+    /// Recently converted short strings are cached per thread, so repeatedly passing the same literal to an engine API is cheap.
     fn from(string: S) -> Self {
-        let intermediate = GodotString::from(string.as_ref());
+        super::string_name_cache::get_or_insert(string.as_ref(), Self::from_str_uncached)
+    }
+}
+
+impl StringName {
+    #[cfg(before_api = "4.2")]
+    fn from_str_uncached(string: &str) -> Self {
+        let intermediate = GodotString::from(string);
         Self::from(&intermediate)
     }
 
     #[cfg(since_api = "4.2")]
-    fn from(string: S) -> Self {
-        let utf8 = string.as_ref().as_bytes();
+    fn from_str_uncached(string: &str) -> Self {
+        let utf8 = string.as_bytes();
 
         // SAFETY: Rust guarantees validity and range of string.
         unsafe {
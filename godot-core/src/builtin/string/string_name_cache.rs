@@ -0,0 +1,61 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Main-thread cache for `&str` -> `StringName` conversions.
+//!
+//! Engine APIs taking a `StringName` are often called every frame with the same literal, e.g. `is_action_pressed("jump".into())`.
+//! Without caching, each call allocates an intermediate string and looks the name up in Godot's global (locked) name table.
+//! Cloning a cached `StringName` only increments a reference count.
+//!
+//! Only the main thread caches: its cache is released at Core deinit, while the interface is still alive. Other threads may outlive
+//! the engine, so their thread-local destructors must not own any `StringName`.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate::builtin::StringName;
+
+/// Upper bound for cached entries. Once full, further names are converted without caching, so that the cached ones stay valid
+/// instead of being rebuilt over and over when more names are used per frame.
+const MAX_ENTRIES: usize = 512;
+
+/// Longer strings are unlikely to be names that are looked up repeatedly.
+const MAX_LEN: usize = 64;
+
+thread_local! {
+    static CACHE: RefCell<HashMap<Box<str>, StringName>> = RefCell::new(HashMap::new());
+}
+
+pub(crate) fn get_or_insert(string: &str, make: fn(&str) -> StringName) -> StringName {
+    if string.len() > MAX_LEN || !crate::init::is_main_thread() {
+        return make(string);
+    }
+
+    // try_with: fails during thread teardown, in which case we just don't cache.
+    CACHE
+        .try_with(|cache| {
+            let mut cache = cache.borrow_mut();
+            if let Some(cached) = cache.get(string) {
+                return cached.clone();
+            }
+
+            let name = make(string);
+            if cache.len() < MAX_ENTRIES {
+                cache.insert(string.into(), name.clone());
+            }
+
+            name
+        })
+        .unwrap_or_else(|_| make(string))
+}
+
+/// Releases all cached names. Must be called on the main thread.
+///
+/// Must be called before the GDExtension interface becomes unavailable; otherwise thread-local destructors would operate on
+/// a dead engine.
+pub(crate) fn clear() {
+    let _ = CACHE.try_with(|cache| cache.borrow_mut().clear());
+}
@@ -288,6 +288,14 @@ impl Transform2D {
     pub fn basis_xform_inv(&self, v: Vector2) -> Vector2 {
         self.basis().inverse() * v
     }
+
+    /// Returns `true` if the two transforms are approximately equal, by comparing basis vectors and origin.
+    ///
+    /// _Godot equivalent: `Transform2D.is_equal_approx()`_
+    #[inline]
+    pub fn is_equal_approx(&self, other: &Self) -> bool {
+        self.approx_eq(other)
+    }
 }
 
 impl Display for Transform2D {
@@ -263,6 +263,14 @@ impl Transform3D {
             origin: self.origin + (self.basis * offset),
         }
     }
+
+    /// Returns `true` if the two transforms are approximately equal, by comparing basis and origin.
+    ///
+    /// _Godot equivalent: `Transform3D.is_equal_approx()`_
+    #[inline]
+    pub fn is_equal_approx(&self, other: &Self) -> bool {
+        self.approx_eq(other)
+    }
 }
 
 impl Display for Transform3D {
@@ -6,6 +6,7 @@
 
 mod vector_macros;
 
+mod rounding;
 mod vector2;
 mod vector2i;
 mod vector3;
@@ -14,6 +15,7 @@ mod vector4;
 mod vector4i;
 mod vector_axis;
 
+pub use rounding::{FloatToIntError, RoundingMode};
 pub use vector2::*;
 pub use vector2i::*;
 pub use vector3::*;
@@ -22,4 +24,6 @@ pub use vector4::*;
 pub use vector4i::*;
 pub use vector_axis::*;
 
+pub(crate) use rounding::exact_i32;
+
 pub use crate::swizzle;
@@ -0,0 +1,115 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::real;
+
+/// How floating-point coordinates are converted to integers, e.g. in [`Vector2::to_vector2i()`][crate::builtin::Vector2::to_vector2i].
+///
+/// Values outside the `i32` range saturate to `i32::MIN` or `i32::MAX`, and NaN becomes `0`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum RoundingMode {
+    /// Rounds towards negative infinity. Maps a world position to the tile containing it.
+    Floor,
+
+    /// Rounds towards positive infinity.
+    Ceil,
+
+    /// Rounds to the nearest integer, with halfway cases away from zero (like Godot's `round()`).
+    Round,
+
+    /// Discards the fractional part, rounding towards zero. This is what a plain `as` cast does, and what Godot's `Vector2i(Vector2)`
+    /// constructor does.
+    Truncate,
+}
+
+impl RoundingMode {
+    pub(crate) fn apply(self, value: real) -> i32 {
+        let rounded = match self {
+            RoundingMode::Floor => value.floor(),
+            RoundingMode::Ceil => value.ceil(),
+            RoundingMode::Round => value.round(),
+            RoundingMode::Truncate => value.trunc(),
+        };
+
+        rounded as i32
+    }
+}
+
+/// Error when converting a floating-point vector or rectangle to its integer counterpart with `TryFrom`.
+///
+/// The conversion only succeeds if every component is an integer that fits into `i32`. Use the `to_*i()` methods with a
+/// [`RoundingMode`] to convert arbitrary values.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct FloatToIntError {
+    _private: (),
+}
+
+impl fmt::Display for FloatToIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "component is not an integer in the i32 range")
+    }
+}
+
+impl Error for FloatToIntError {}
+
+/// Converts `value` to `i32` if this is possible without loss.
+pub(crate) fn exact_i32(value: real) -> Result<i32, FloatToIntError> {
+    // Upper bound is exclusive: i32::MAX is not exactly representable as f32, and rounds up to 2^31.
+    let in_range = value >= i32::MIN as real && value < -(i32::MIN as real);
+
+    // NaN and infinities fail the fract() check.
+    if in_range && value.fract() == 0.0 {
+        Ok(value as i32)
+    } else {
+        Err(FloatToIntError { _private: () })
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rounding_modes() {
+        let cases = [
+            (1.5, [1, 2, 2, 1]),
+            (-1.5, [-2, -1, -2, -1]),
+            (2.25, [2, 3, 2, 2]),
+            (-0.75, [-1, 0, -1, 0]),
+        ];
+
+        for (value, expected) in cases {
+            let actual = [
+                RoundingMode::Floor.apply(value),
+                RoundingMode::Ceil.apply(value),
+                RoundingMode::Round.apply(value),
+                RoundingMode::Truncate.apply(value),
+            ];
+            assert_eq!(actual, expected, "value {value}");
+        }
+
+        assert_eq!(RoundingMode::Round.apply(real::NAN), 0);
+        assert_eq!(RoundingMode::Floor.apply(1e20), i32::MAX);
+        assert_eq!(RoundingMode::Floor.apply(-1e20), i32::MIN);
+    }
+
+    #[test]
+    fn exact_conversion() {
+        assert_eq!(exact_i32(3.0), Ok(3));
+        assert_eq!(exact_i32(-16777216.0), Ok(-16777216));
+        assert_eq!(exact_i32(i32::MIN as real), Ok(i32::MIN));
+
+        assert!(exact_i32(0.5).is_err());
+        assert!(exact_i32(2147483648.0).is_err());
+        assert!(exact_i32(real::NAN).is_err());
+        assert!(exact_i32(real::INFINITY).is_err());
+    }
+}
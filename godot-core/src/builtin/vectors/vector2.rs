@@ -243,6 +243,7 @@ impl fmt::Display for Vector2 {
 impl_common_vector_fns!(Vector2, real);
 impl_float_vector_glam_fns!(Vector2, real);
 impl_float_vector_component_fns!(Vector2, real, (x, y));
+impl_vector_int_conversions!(Vector2, Vector2i, to_vector2i, (x, y));
 impl_vector_operators!(Vector2, real, (x, y));
 impl_from_tuple_for_vector2x!(Vector2, real);
 
@@ -277,6 +278,7 @@ impl GlamType for RVec2 {
 #[cfg(test)]
 mod test {
     use crate::assert_eq_approx;
+    use crate::builtin::RoundingMode;
 
     use super::*;
 
@@ -289,6 +291,23 @@ mod test {
         assert_eq_approx!(a.coord_max(b), Vector2::new(1.2, 5.6));
     }
 
+    #[test]
+    fn int_conversions() {
+        let v = Vector2::new(2.5, -1.5);
+
+        assert_eq!(v.to_vector2i(RoundingMode::Floor), Vector2i::new(2, -2));
+        assert_eq!(v.to_vector2i(RoundingMode::Ceil), Vector2i::new(3, -1));
+        assert_eq!(v.to_vector2i(RoundingMode::Round), Vector2i::new(3, -2));
+        assert_eq!(v.to_vector2i(RoundingMode::Truncate), Vector2i::new(2, -1));
+
+        assert_eq!(Vector2::from(Vector2i::new(3, -4)), Vector2::new(3.0, -4.0));
+        assert_eq!(
+            Vector2i::try_from(Vector2::new(3.0, -4.0)),
+            Ok(Vector2i::new(3, -4))
+        );
+        assert!(Vector2i::try_from(v).is_err());
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
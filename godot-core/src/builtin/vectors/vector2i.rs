@@ -41,6 +41,12 @@ impl Vector2i {
     /// Vector with all components set to `1`.
     pub const ONE: Self = Self::splat(1);
 
+    /// Vector with all components set to `i32::MIN`.
+    pub const MIN: Self = Self::splat(i32::MIN);
+
+    /// Vector with all components set to `i32::MAX`.
+    pub const MAX: Self = Self::splat(i32::MAX);
+
     /// Unit vector in -X direction (right in 2D coordinate system).
     pub const LEFT: Self = Self::new(-1, 0);
 
@@ -158,6 +164,7 @@ impl GlamConv for Vector2i {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::builtin::Rect2i;
 
     #[test]
     fn coord_min_max() {
@@ -167,6 +174,21 @@ mod test {
         assert_eq!(a.coord_max(b), Vector2i::new(1, 5));
     }
 
+    #[test]
+    fn const_context() {
+        const NEIGHBORS: [Vector2i; 4] = [
+            Vector2i::UP,
+            Vector2i::RIGHT,
+            Vector2i::DOWN,
+            Vector2i::LEFT,
+        ];
+        const AREA: Rect2i = Rect2i::from_corners(Vector2i::new(1, 2), Vector2i::new(4, 6));
+
+        let sum = NEIGHBORS.iter().fold(Vector2i::ZERO, |acc, &dir| acc + dir);
+        assert_eq!(sum, Vector2i::ZERO);
+        assert_eq!(AREA.size, Vector2i::new(3, 4));
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
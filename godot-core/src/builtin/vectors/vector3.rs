@@ -45,6 +45,9 @@ impl Vector3 {
     /// Vector with all components set to `1.0`.
     pub const ONE: Self = Self::splat(1.0);
 
+    /// Vector with all components set to `real::INFINITY`.
+    pub const INF: Self = Self::splat(real::INFINITY);
+
     /// Unit vector in -X direction. Can be interpreted as left in an untransformed 3D world.
     pub const LEFT: Self = Self::new(-1.0, 0.0, 0.0);
 
@@ -274,6 +277,7 @@ impl fmt::Display for Vector3 {
 impl_common_vector_fns!(Vector3, real);
 impl_float_vector_glam_fns!(Vector3, real);
 impl_float_vector_component_fns!(Vector3, real, (x, y, z));
+impl_vector_int_conversions!(Vector3, Vector3i, to_vector3i, (x, y, z));
 impl_vector_operators!(Vector3, real, (x, y, z));
 impl_from_tuple_for_vector3x!(Vector3, real);
 
@@ -43,6 +43,7 @@ impl_vector_operators!(Vector4, real, (x, y, z, w));
 impl_common_vector_fns!(Vector4, real);
 impl_float_vector_glam_fns!(Vector4, real);
 impl_float_vector_component_fns!(Vector4, real, (x, y, z, w));
+impl_vector_int_conversions!(Vector4, Vector4i, to_vector4i, (x, y, z, w));
 impl_from_tuple_for_vector4x!(Vector4, real);
 
 impl Vector4 {
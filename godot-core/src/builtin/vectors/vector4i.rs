@@ -114,6 +114,12 @@ impl Vector4i {
     /// One vector, a vector with all components set to `1`.
     pub const ONE: Self = Self::splat(1);
 
+    /// Vector with all components set to `i32::MIN`.
+    pub const MIN: Self = Self::splat(i32::MIN);
+
+    /// Vector with all components set to `i32::MAX`.
+    pub const MAX: Self = Self::splat(i32::MAX);
+
     /// Converts the corresponding `glam` type to `Self`.
     fn from_glam(v: glam::IVec4) -> Self {
         Self::new(v.x, v.y, v.z, v.w)
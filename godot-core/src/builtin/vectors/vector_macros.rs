@@ -375,6 +375,14 @@ macro_rules! impl_float_vector_component_fns {
                 $(self.$comp.is_zero_approx())&&*
             }
 
+            /// Returns `true` if the two vectors are approximately equal, by comparing each component.
+            ///
+            /// The tolerance scales with the magnitude of the components, as in Godot's `is_equal_approx()`.
+            #[inline]
+            pub fn is_equal_approx(self, other: Self) -> bool {
+                $crate::builtin::math::ApproxEq::approx_eq(&self, &other)
+            }
+
             pub fn posmod(self, pmod: $Scalar) -> Self {
                 Self::new(
                     $( self.$comp.fposmod(pmod) ),*
@@ -488,3 +496,50 @@ macro_rules! impl_from_tuple_for_vector4x {
         }
     };
 }
+
+/// Implements conversions between a float vector type and its integer counterpart.
+macro_rules! impl_vector_int_conversions {
+    (
+        // Name of the float vector type.
+        $Vector:ty,
+        // Name of the integer vector type.
+        $IntVector:ty,
+        // Name of the rounding conversion method.
+        $to_int:ident,
+        // Names of the components, with parentheses, for example `(x, y)`.
+        ($($comp:ident),*)
+    ) => {
+        impl $Vector {
+            #[doc = concat!("Converts to a [`", stringify!($IntVector), "`], rounding each component according to `mode`.")]
+            ///
+            /// Components outside the `i32` range saturate, and NaN becomes `0`.
+            #[inline]
+            pub fn $to_int(self, mode: $crate::builtin::RoundingMode) -> $IntVector {
+                <$IntVector>::new(
+                    $( mode.apply(self.$comp) ),*
+                )
+            }
+        }
+
+        impl From<$IntVector> for $Vector {
+            #[inline]
+            fn from(v: $IntVector) -> Self {
+                Self::new(
+                    $( v.$comp as $crate::builtin::real ),*
+                )
+            }
+        }
+
+        /// Succeeds if all components are integers in the `i32` range; see [`FloatToIntError`][crate::builtin::FloatToIntError].
+        impl TryFrom<$Vector> for $IntVector {
+            type Error = $crate::builtin::FloatToIntError;
+
+            #[inline]
+            fn try_from(v: $Vector) -> Result<Self, Self::Error> {
+                Ok(Self::new(
+                    $( $crate::builtin::vectors::exact_i32(v.$comp)? ),*
+                ))
+            }
+        }
+    };
+}
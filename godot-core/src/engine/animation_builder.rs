@@ -0,0 +1,300 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::marker::PhantomData;
+
+use crate::builtin::meta::ToGodot;
+use crate::builtin::{dict, NodePath, Quaternion, StringName, Variant, VariantArray, Vector3};
+use crate::engine::animation::{InterpolationType, LoopMode, TrackType, UpdateMode};
+use crate::engine::Animation;
+use crate::obj::Gd;
+
+/// Typed builder for procedurally generated [`Animation`] resources.
+///
+/// Each track is started with one of the `*_track()` methods, which returns a [`TrackBuilder`]. Its keys are statically typed
+/// according to the kind of track, so a position track only accepts `Vector3` keys, a value track for `Color` only accepts colors,
+/// and so on. Call [`TrackBuilder::done()`] to return to the animation.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::animation::{InterpolationType, LoopMode};
+/// use godot::engine::AnimationBuilder;
+///
+/// let animation = AnimationBuilder::new(2.0)
+///     .loop_mode(LoopMode::LOOP_LINEAR)
+///     .value_track::<Color>("Sprite2D:modulate")
+///         .interpolation(InterpolationType::INTERPOLATION_CUBIC)
+///         .key(0.0, Color::WHITE)
+///         .key(2.0, Color::TRANSPARENT_WHITE)
+///         .done()
+///     .position_track("Body")
+///         .key(0.0, Vector3::ZERO)
+///         .key(1.0, Vector3::UP)
+///         .done()
+///     .build();
+/// ```
+pub struct AnimationBuilder {
+    animation: Gd<Animation>,
+}
+
+impl AnimationBuilder {
+    /// Starts a new animation lasting `length` seconds.
+    pub fn new(length: f32) -> Self {
+        let mut animation = Animation::new();
+        animation.set_length(length);
+
+        Self { animation }
+    }
+
+    /// Appends tracks to an existing animation, keeping the tracks it already has.
+    pub fn from_animation(animation: Gd<Animation>) -> Self {
+        Self { animation }
+    }
+
+    /// Sets how the animation behaves once it reaches its end.
+    pub fn loop_mode(mut self, loop_mode: LoopMode) -> Self {
+        self.animation.set_loop_mode(loop_mode);
+        self
+    }
+
+    /// Sets the step (in seconds) to which keys are snapped in the editor.
+    pub fn step(mut self, step: f32) -> Self {
+        self.animation.set_step(step);
+        self
+    }
+
+    /// Starts a track that animates the property at `path` (e.g. `"Sprite2D:modulate"`) with values of type `T`.
+    pub fn value_track<T: ToGodot>(self, path: impl Into<NodePath>) -> TrackBuilder<ValueTrack<T>> {
+        TrackBuilder::new(self, path.into())
+    }
+
+    /// Starts a 3D position track for the node (or skeleton bone) at `path`.
+    pub fn position_track(self, path: impl Into<NodePath>) -> TrackBuilder<PositionTrack> {
+        TrackBuilder::new(self, path.into())
+    }
+
+    /// Starts a 3D rotation track for the node (or skeleton bone) at `path`.
+    pub fn rotation_track(self, path: impl Into<NodePath>) -> TrackBuilder<RotationTrack> {
+        TrackBuilder::new(self, path.into())
+    }
+
+    /// Starts a 3D scale track for the node (or skeleton bone) at `path`.
+    pub fn scale_track(self, path: impl Into<NodePath>) -> TrackBuilder<ScaleTrack> {
+        TrackBuilder::new(self, path.into())
+    }
+
+    /// Starts a blend shape track; `path` has the form `"MeshInstance3D:blend_shape_name"`.
+    pub fn blend_shape_track(self, path: impl Into<NodePath>) -> TrackBuilder<BlendShapeTrack> {
+        TrackBuilder::new(self, path.into())
+    }
+
+    /// Starts a track that calls methods on the node at `path`.
+    pub fn method_track(self, path: impl Into<NodePath>) -> TrackBuilder<MethodTrack> {
+        TrackBuilder::new(self, path.into())
+    }
+
+    /// Finishes the animation.
+    #[must_use]
+    pub fn build(self) -> Gd<Animation> {
+        self.animation
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Builder for a single track of an [`AnimationBuilder`].
+///
+/// The type parameter `K` is one of the track kinds (e.g. [`ValueTrack`], [`PositionTrack`]) and determines the type of the keys.
+#[must_use = "call done() to return to the animation builder"]
+pub struct TrackBuilder<K: TrackKind> {
+    parent: AnimationBuilder,
+    track_idx: i32,
+    _kind: PhantomData<K>,
+}
+
+impl<K: TrackKind> TrackBuilder<K> {
+    fn new(mut parent: AnimationBuilder, path: NodePath) -> Self {
+        let track_idx = parent.animation.add_track(K::TRACK_TYPE);
+        parent.animation.track_set_path(track_idx, path);
+
+        Self {
+            parent,
+            track_idx,
+            _kind: PhantomData,
+        }
+    }
+
+    /// Inserts a key with value `key` at `time` seconds.
+    pub fn key(mut self, time: f64, key: K::Key) -> Self {
+        K::insert_key(&mut self.parent.animation, self.track_idx, time, key);
+        self
+    }
+
+    /// Inserts all `(time, key)` pairs from an iterator.
+    pub fn keys(mut self, keys: impl IntoIterator<Item = (f64, K::Key)>) -> Self {
+        for (time, key) in keys {
+            self = self.key(time, key);
+        }
+        self
+    }
+
+    /// Sets how values between keys are interpolated.
+    pub fn interpolation(mut self, interpolation: InterpolationType) -> Self {
+        self.parent
+            .animation
+            .track_set_interpolation_type(self.track_idx, interpolation);
+        self
+    }
+
+    /// Whether interpolation wraps around from the last key to the first one in looping animations.
+    pub fn loop_wrap(mut self, loop_wrap: bool) -> Self {
+        self.parent
+            .animation
+            .track_set_interpolation_loop_wrap(self.track_idx, loop_wrap);
+        self
+    }
+
+    /// The index of this track within the animation.
+    pub fn track_index(&self) -> i32 {
+        self.track_idx
+    }
+
+    /// Finishes this track and returns to the animation builder.
+    pub fn done(self) -> AnimationBuilder {
+        self.parent
+    }
+}
+
+impl<T: ToGodot> TrackBuilder<ValueTrack<T>> {
+    /// Sets when the property is updated, e.g. only at discrete keys or continuously.
+    pub fn update_mode(mut self, update_mode: UpdateMode) -> Self {
+        self.parent
+            .animation
+            .value_track_set_update_mode(self.track_idx, update_mode);
+        self
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Track kinds
+
+/// Kind of animation track, determining the type of its keys.
+///
+/// This trait is implemented for the marker types [`ValueTrack`], [`PositionTrack`], [`RotationTrack`], [`ScaleTrack`],
+/// [`BlendShapeTrack`] and [`MethodTrack`]; it is not meant to be implemented by users.
+pub trait TrackKind {
+    /// Value stored in each key of the track.
+    type Key;
+
+    #[doc(hidden)]
+    const TRACK_TYPE: TrackType;
+
+    #[doc(hidden)]
+    fn insert_key(animation: &mut Animation, track_idx: i32, time: f64, key: Self::Key);
+}
+
+/// Track animating an arbitrary property with values of type `T`.
+pub struct ValueTrack<T> {
+    _value: PhantomData<T>,
+}
+
+/// Track animating the 3D position of a node or bone.
+pub struct PositionTrack;
+
+/// Track animating the 3D rotation of a node or bone.
+pub struct RotationTrack;
+
+/// Track animating the 3D scale of a node or bone.
+pub struct ScaleTrack;
+
+/// Track animating the weight of a blend shape.
+pub struct BlendShapeTrack;
+
+/// Track calling methods at given times.
+pub struct MethodTrack;
+
+/// Key of a [`MethodTrack`]: the method to call, and its arguments.
+#[derive(Clone, Debug)]
+pub struct MethodKey {
+    pub method: StringName,
+    pub args: VariantArray,
+}
+
+impl MethodKey {
+    pub fn new(method: impl Into<StringName>, args: VariantArray) -> Self {
+        Self {
+            method: method.into(),
+            args,
+        }
+    }
+}
+
+impl<T: ToGodot> TrackKind for ValueTrack<T> {
+    type Key = T;
+
+    const TRACK_TYPE: TrackType = TrackType::TYPE_VALUE;
+
+    fn insert_key(animation: &mut Animation, track_idx: i32, time: f64, key: T) {
+        animation.track_insert_key(track_idx, time, key.to_variant());
+    }
+}
+
+impl TrackKind for PositionTrack {
+    type Key = Vector3;
+
+    const TRACK_TYPE: TrackType = TrackType::TYPE_POSITION_3D;
+
+    fn insert_key(animation: &mut Animation, track_idx: i32, time: f64, key: Vector3) {
+        animation.position_track_insert_key(track_idx, time, key);
+    }
+}
+
+impl TrackKind for RotationTrack {
+    type Key = Quaternion;
+
+    const TRACK_TYPE: TrackType = TrackType::TYPE_ROTATION_3D;
+
+    fn insert_key(animation: &mut Animation, track_idx: i32, time: f64, key: Quaternion) {
+        animation.rotation_track_insert_key(track_idx, time, key);
+    }
+}
+
+impl TrackKind for ScaleTrack {
+    type Key = Vector3;
+
+    const TRACK_TYPE: TrackType = TrackType::TYPE_SCALE_3D;
+
+    fn insert_key(animation: &mut Animation, track_idx: i32, time: f64, key: Vector3) {
+        animation.scale_track_insert_key(track_idx, time, key);
+    }
+}
+
+impl TrackKind for BlendShapeTrack {
+    type Key = f32;
+
+    const TRACK_TYPE: TrackType = TrackType::TYPE_BLEND_SHAPE;
+
+    fn insert_key(animation: &mut Animation, track_idx: i32, time: f64, key: f32) {
+        animation.blend_shape_track_insert_key(track_idx, time, key);
+    }
+}
+
+impl TrackKind for MethodTrack {
+    type Key = MethodKey;
+
+    const TRACK_TYPE: TrackType = TrackType::TYPE_METHOD;
+
+    fn insert_key(animation: &mut Animation, track_idx: i32, time: f64, key: MethodKey) {
+        let key: Variant = dict! {
+            "method": key.method,
+            "args": key.args,
+        }
+        .to_variant();
+
+        animation.track_insert_key(track_idx, time, key);
+    }
+}
@@ -0,0 +1,86 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::engine::notify::NodeNotification;
+
+/// Application lifecycle events, as sent by the OS to the main loop and all nodes in the scene tree.
+///
+/// Most of these are only emitted on mobile platforms (Android, iOS), where apps are routinely suspended and resumed. Since they
+/// arrive as regular notifications, they can be handled in any node's `on_notification()`:
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::notify::NodeNotification;
+/// use godot::engine::AppLifecycle;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct SaveOnPause {
+///     #[base]
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl NodeVirtual for SaveOnPause {
+///     fn on_notification(&mut self, what: NodeNotification) {
+///         if let Some(AppLifecycle::Paused) = AppLifecycle::from_notification(what) {
+///             // Persist state: the OS may terminate the app without further notice.
+///         }
+///     }
+/// }
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[non_exhaustive]
+pub enum AppLifecycle {
+    /// The app is sent to the background (Android `onPause`, iOS `applicationWillResignActive`).
+    ///
+    /// This is the last reliable point to save state; on mobile, the process may be killed afterwards without deinitialization.
+    Paused,
+
+    /// The app returns to the foreground (Android `onResume`, iOS `applicationDidBecomeActive`).
+    Resumed,
+
+    /// The app window received focus.
+    FocusIn,
+
+    /// The app window lost focus.
+    FocusOut,
+
+    /// The OS is low on memory; caches should be released (iOS `applicationDidReceiveMemoryWarning`).
+    MemoryWarning,
+
+    /// The user pressed the "back" button on Android.
+    GoBackRequest,
+}
+
+impl AppLifecycle {
+    /// Maps a notification to a lifecycle event, or returns `None` if it's an unrelated notification.
+    pub fn from_notification(what: NodeNotification) -> Option<Self> {
+        let event = match what {
+            NodeNotification::ApplicationPaused => Self::Paused,
+            NodeNotification::ApplicationResumed => Self::Resumed,
+            NodeNotification::ApplicationFocusIn => Self::FocusIn,
+            NodeNotification::ApplicationFocusOut => Self::FocusOut,
+            NodeNotification::OsMemoryWarning => Self::MemoryWarning,
+            NodeNotification::WmGoBackRequest => Self::GoBackRequest,
+            _ => return None,
+        };
+
+        Some(event)
+    }
+
+    /// The notification that carries this event.
+    pub fn to_notification(self) -> NodeNotification {
+        match self {
+            Self::Paused => NodeNotification::ApplicationPaused,
+            Self::Resumed => NodeNotification::ApplicationResumed,
+            Self::FocusIn => NodeNotification::ApplicationFocusIn,
+            Self::FocusOut => NodeNotification::ApplicationFocusOut,
+            Self::MemoryWarning => NodeNotification::OsMemoryWarning,
+            Self::GoBackRequest => NodeNotification::WmGoBackRequest,
+        }
+    }
+}
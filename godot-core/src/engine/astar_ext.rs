@@ -0,0 +1,255 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::{Vector2, Vector2i, Vector3};
+use crate::engine::{AStar2D, AStar3D, AStarGrid2D};
+use crate::obj::Gd;
+
+/// ID of a point in an [`AStar2D`] or [`AStar3D`] graph.
+///
+/// Godot uses non-negative `int` values for point IDs; this type makes them distinguishable from other integers in Rust signatures.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct PointId(u64);
+
+impl PointId {
+    /// ⚠️ Creates an ID from its number.
+    ///
+    /// # Panics
+    /// If `id` exceeds `i64::MAX`, which cannot be represented in Godot.
+    pub fn new(id: u64) -> Self {
+        assert!(id <= i64::MAX as u64, "point ID {id} exceeds i64::MAX");
+        Self(id)
+    }
+
+    /// Converts an ID as used in Godot's API and virtual methods; `None` for negative values such as the `-1` of "no point".
+    pub fn try_from_i64(id: i64) -> Option<Self> {
+        u64::try_from(id).ok().map(Self)
+    }
+
+    pub fn to_u64(self) -> u64 {
+        self.0
+    }
+
+    /// The ID as `int`, as passed to Godot.
+    pub fn to_i64(self) -> i64 {
+        self.0 as i64
+    }
+}
+
+impl From<u64> for PointId {
+    fn from(id: u64) -> Self {
+        Self::new(id)
+    }
+}
+
+impl fmt::Debug for PointId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PointId({})", self.0)
+    }
+}
+
+impl fmt::Display for PointId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Extension methods for [`AStar2D`] and [`AStar3D`], taking point IDs and returning `Vec`s instead of packed arrays.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{AStar2D, AStarExt, PointId};
+///
+/// let mut graph = AStar2D::new();
+/// let ids: Vec<PointId> = (0..4).map(PointId::new).collect();
+///
+/// graph.add_points(ids.iter().map(|&id| (id, Vector2::new(id.to_u64() as f32, 0.0))));
+/// graph.connect_points_from(ids.windows(2).map(|pair| (pair[0], pair[1])), true);
+///
+/// assert_eq!(graph.id_path(ids[0], ids[3]), ids);
+/// ```
+///
+/// # Custom heuristics
+/// The costs used by the search come from the virtual methods `_compute_cost()` (between connected points) and `_estimate_cost()`
+/// (remaining distance to the goal). Both default to the Euclidean distance. To replace them, write a class that inherits from
+/// `AStar2D` or `AStar3D` and implement its virtual trait; the IDs of the two points are passed as `int`:
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{AStar2D, AStar2DVirtual, PointId};
+///
+/// /// Moves along grid lines only.
+/// #[derive(GodotClass)]
+/// #[class(init, base=AStar2D)]
+/// struct ManhattanAStar {
+///     #[base]
+///     base: Base<AStar2D>,
+/// }
+///
+/// #[godot_api]
+/// impl AStar2DVirtual for ManhattanAStar {
+///     fn compute_cost(&self, from_id: i64, to_id: i64) -> f64 {
+///         self.manhattan(from_id, to_id)
+///     }
+///
+///     fn estimate_cost(&self, from_id: i64, to_id: i64) -> f64 {
+///         self.manhattan(from_id, to_id)
+///     }
+/// }
+///
+/// impl ManhattanAStar {
+///     fn manhattan(&self, from_id: i64, to_id: i64) -> f64 {
+///         let delta = self.base.get_point_position(from_id) - self.base.get_point_position(to_id);
+///         (delta.x.abs() + delta.y.abs()) as f64
+///     }
+/// }
+/// ```
+///
+/// The methods of this trait are available on such classes through their base, e.g. `self.base.id_path(from, to)`, or after
+/// upcasting with `Gd::upcast::<AStar2D>()`.
+pub trait AStarExt {
+    /// `Vector2` or `Vector3`, depending on the graph.
+    type Vector;
+
+    /// Adds (or moves) the points with the given IDs and positions, each with weight scale 1.
+    ///
+    /// Space for the new points is reserved up front, based on the iterator's size hint.
+    fn add_points<I>(&mut self, points: I)
+    where
+        I: IntoIterator<Item = (PointId, Self::Vector)>;
+
+    /// Connects each pair of points; in both directions if `bidirectional`.
+    fn connect_points_from<I>(&mut self, connections: I, bidirectional: bool)
+    where
+        I: IntoIterator<Item = (PointId, PointId)>;
+
+    /// An ID not used by any point yet.
+    fn next_point_id(&self) -> PointId;
+
+    /// The IDs of all points.
+    fn point_ids(&self) -> Vec<PointId>;
+
+    /// The point closest to `position`, ignoring disabled points; `None` if the graph has no enabled points.
+    fn closest_point_id(&self, position: Self::Vector) -> Option<PointId>;
+
+    /// The IDs along the cheapest path from `from` to `to`, including both; empty if there is no path.
+    fn id_path(&self, from: PointId, to: PointId) -> Vec<PointId>;
+
+    /// The positions along the cheapest path from `from` to `to`, including both; empty if there is no path.
+    fn point_path(&self, from: PointId, to: PointId) -> Vec<Self::Vector>;
+}
+
+macro_rules! impl_astar_ext {
+    ($AStar:ident, $Vector:ident) => {
+        impl AStarExt for Gd<$AStar> {
+            type Vector = $Vector;
+
+            fn add_points<I>(&mut self, points: I)
+            where
+                I: IntoIterator<Item = (PointId, $Vector)>,
+            {
+                let points = points.into_iter();
+
+                let additional = points.size_hint().0 as i64;
+                if additional > 0 {
+                    let capacity = self
+                        .get_point_capacity()
+                        .max(self.get_point_count() + additional);
+                    self.reserve_space(capacity);
+                }
+
+                for (id, position) in points {
+                    self.add_point(id.to_i64(), position);
+                }
+            }
+
+            fn connect_points_from<I>(&mut self, connections: I, bidirectional: bool)
+            where
+                I: IntoIterator<Item = (PointId, PointId)>,
+            {
+                for (from, to) in connections {
+                    self.connect_points_ex(from.to_i64(), to.to_i64())
+                        .bidirectional(bidirectional)
+                        .done();
+                }
+            }
+
+            fn next_point_id(&self) -> PointId {
+                PointId::try_from_i64(self.get_available_point_id())
+                    .expect("Godot returned negative point ID")
+            }
+
+            fn point_ids(&self) -> Vec<PointId> {
+                to_point_ids(self.get_point_ids().as_slice())
+            }
+
+            fn closest_point_id(&self, position: $Vector) -> Option<PointId> {
+                PointId::try_from_i64(self.get_closest_point(position))
+            }
+
+            fn id_path(&self, from: PointId, to: PointId) -> Vec<PointId> {
+                to_point_ids(self.get_id_path(from.to_i64(), to.to_i64()).as_slice())
+            }
+
+            fn point_path(&self, from: PointId, to: PointId) -> Vec<$Vector> {
+                self.get_point_path(from.to_i64(), to.to_i64()).to_vec()
+            }
+        }
+    };
+}
+
+impl_astar_ext!(AStar2D, Vector2);
+impl_astar_ext!(AStar3D, Vector3);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Extension methods for [`AStarGrid2D`], taking cells as `Vector2i` and returning `Vec`s.
+///
+/// Remember to call `update()` after changing the grid's region, cell size or offset, before marking cells or finding paths.
+pub trait AStarGridExt {
+    /// Marks all `cells` as solid (impassable) or passable.
+    fn set_solid_cells<I>(&mut self, cells: I, solid: bool)
+    where
+        I: IntoIterator<Item = Vector2i>;
+
+    /// The cells along the cheapest path from `from` to `to`, including both; empty if there is no path.
+    fn cell_path(&self, from: Vector2i, to: Vector2i) -> Vec<Vector2i>;
+
+    /// The positions of the cells along the cheapest path, taking cell size and offset into account.
+    fn point_path(&self, from: Vector2i, to: Vector2i) -> Vec<Vector2>;
+}
+
+impl AStarGridExt for Gd<AStarGrid2D> {
+    fn set_solid_cells<I>(&mut self, cells: I, solid: bool)
+    where
+        I: IntoIterator<Item = Vector2i>,
+    {
+        for cell in cells {
+            self.set_point_solid_ex(cell).solid(solid).done();
+        }
+    }
+
+    fn cell_path(&self, from: Vector2i, to: Vector2i) -> Vec<Vector2i> {
+        self.get_id_path(from, to).iter_shared().collect()
+    }
+
+    fn point_path(&self, from: Vector2i, to: Vector2i) -> Vec<Vector2> {
+        self.get_point_path(from, to).to_vec()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+fn to_point_ids(ids: &[i64]) -> Vec<PointId> {
+    ids.iter()
+        .map(|&id| PointId::try_from_i64(id).expect("Godot returned negative point ID"))
+        .collect()
+}
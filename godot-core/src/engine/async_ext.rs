@@ -0,0 +1,117 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{GodotString, StringName, Variant};
+use crate::engine::http::{self, ResponseFuture};
+use crate::engine::{AnimationPlayer, HttpRequest, SignalFuture, Tween};
+use crate::obj::Gd;
+
+/// `async` variant of [`Tween::play()`].
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{Sprite2D, TweenAsyncExt};
+///
+/// async fn fade_out(mut sprite: Gd<Sprite2D>) {
+///     let mut tween = sprite.create_tween().unwrap();
+///     tween.tween_property(sprite.clone().upcast(), "modulate:a".into(), 0.0.to_variant(), 0.5);
+///     tween.play_async().await;
+///
+///     sprite.queue_free();
+/// }
+/// ```
+pub trait TweenAsyncExt {
+    /// Starts or resumes the tween; the returned future completes once it has finished all its tweeners.
+    ///
+    /// A tween with infinite loops never finishes, and a tween that is killed does not emit `finished` either; in both cases the
+    /// future never completes.
+    fn play_async(&mut self) -> SignalFuture;
+}
+
+impl TweenAsyncExt for Gd<Tween> {
+    fn play_async(&mut self) -> SignalFuture {
+        // Connected before playing, so that a tween finishing within `play()` is not missed.
+        let future = SignalFuture::new(self.clone().upcast(), "finished");
+        self.play();
+
+        future
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// `async` variant of [`AnimationPlayer::play()`].
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{AnimationPlayer, AnimationPlayerAsyncExt};
+///
+/// async fn open_door(mut player: Gd<AnimationPlayer>) {
+///     let finished = player.play_async("open").await;
+///     godot_print!("animation {finished} done");
+/// }
+/// ```
+pub trait AnimationPlayerAsyncExt {
+    /// Plays the animation `name`; the returned future resolves once an animation of the player finishes.
+    ///
+    /// The output is the name of the finished animation, which usually is `name`. If another animation is played in the meantime,
+    /// `name` does not finish and the future resolves with the other animation instead. Looping animations never finish.
+    fn play_async(&mut self, name: impl Into<StringName>) -> AnimationFuture;
+}
+
+impl AnimationPlayerAsyncExt for Gd<AnimationPlayer> {
+    fn play_async(&mut self, name: impl Into<StringName>) -> AnimationFuture {
+        let future = AnimationFuture::with_mapper(
+            self.clone().upcast(),
+            "animation_finished",
+            |args: &[&Variant]| {
+                let [name] = args else {
+                    return None;
+                };
+                let name = name.try_to::<StringName>().ok()?;
+
+                Some(name.to_string())
+            },
+        );
+        self.play_ex().name(name.into()).done();
+
+        future
+    }
+}
+
+/// Future returned by [`AnimationPlayerAsyncExt::play_async()`], resolving to the name of the finished animation.
+pub type AnimationFuture = SignalFuture<String>;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// `async` variant of [`HttpRequest::request()`], for `HTTPRequest` nodes that are part of the scene.
+///
+/// Without an existing node, [`http::get()`] and related functions are more convenient.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{HttpRequest, HttpRequestAsyncExt};
+///
+/// async fn check_update(mut node: Gd<HttpRequest>) -> bool {
+///     match node.request_async("https://example.com/version").await {
+///         Ok(response) => response.text().trim() != "1.0.0",
+///         Err(_) => false,
+///     }
+/// }
+/// ```
+pub trait HttpRequestAsyncExt {
+    /// Sends a `GET` request to `url` with this node; the returned future resolves to the response.
+    ///
+    /// For other methods, headers or a body, use [`RequestBuilder::send_with()`][http::RequestBuilder::send_with].
+    fn request_async(&mut self, url: impl Into<GodotString>) -> ResponseFuture;
+}
+
+impl HttpRequestAsyncExt for Gd<HttpRequest> {
+    fn request_async(&mut self, url: impl Into<GodotString>) -> ResponseFuture {
+        http::get(url).send_with(self)
+    }
+}
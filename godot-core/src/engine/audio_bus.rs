@@ -0,0 +1,290 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{StringName, Vector2};
+use crate::engine::{
+    AudioEffect, AudioEffectSpectrumAnalyzer, AudioEffectSpectrumAnalyzerInstance, AudioServer,
+};
+use crate::obj::{Gd, GodotClass, Inherits};
+
+/// Volume in decibels, as used by Godot's audio API; 0 dB is unity gain.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Decibels(pub f32);
+
+impl Decibels {
+    /// Unity gain: the signal passes unchanged.
+    pub const UNITY: Self = Self(0.0);
+
+    /// The linear amplitude factor for this volume.
+    pub fn to_amplitude(self) -> Amplitude {
+        // Same factor as Godot's db_to_linear().
+        Amplitude((self.0 * 0.115_129_255).exp())
+    }
+}
+
+impl From<Amplitude> for Decibels {
+    fn from(amplitude: Amplitude) -> Self {
+        amplitude.to_decibels()
+    }
+}
+
+/// Linear amplitude factor, where 1.0 is unity gain and 0.5 halves the signal; useful for volume sliders.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Amplitude(pub f32);
+
+impl Amplitude {
+    /// Unity gain: the signal passes unchanged.
+    pub const UNITY: Self = Self(1.0);
+
+    /// The volume in decibels; negative infinity for 0.
+    pub fn to_decibels(self) -> Decibels {
+        // Same factor as Godot's linear_to_db().
+        Decibels(self.0.ln() * 8.685_889_6)
+    }
+}
+
+impl From<Decibels> for Amplitude {
+    fn from(decibels: Decibels) -> Self {
+        decibels.to_amplitude()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Bus of the [`AudioServer`], referred to by name.
+///
+/// `AudioServer` addresses buses and their effects by index, and indices shift whenever a bus is added, moved or removed. This type
+/// stores the bus name instead and looks up the index on each call, so it stays valid while the layout changes.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{Amplitude, AudioBus, AudioEffectReverb};
+///
+/// let mut music = AudioBus::create("Music");
+/// music.set_send(&AudioBus::master());
+/// music.set_volume(Amplitude(0.5));
+///
+/// let mut reverb = AudioEffectReverb::new();
+/// reverb.set_room_size(0.8);
+/// music.add_effect(reverb);
+///
+/// if let Some((_index, mut reverb)) = music.find_effect::<AudioEffectReverb>() {
+///     reverb.set_wet(0.2);
+/// }
+/// ```
+///
+/// Methods other than [`index()`][Self::index] panic if the bus no longer exists.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AudioBus {
+    name: StringName,
+}
+
+impl AudioBus {
+    /// The master bus, which all other buses eventually send to.
+    pub fn master() -> Self {
+        let name = AudioServer::singleton().get_bus_name(0);
+        Self { name: name.into() }
+    }
+
+    /// The bus called `name`, if it exists.
+    pub fn find(name: &str) -> Option<Self> {
+        let bus = Self { name: name.into() };
+        bus.index().map(|_| bus)
+    }
+
+    /// All buses, in mixer order.
+    pub fn all() -> Vec<Self> {
+        let server = AudioServer::singleton();
+
+        (0..server.get_bus_count())
+            .map(|index| Self {
+                name: server.get_bus_name(index).into(),
+            })
+            .collect()
+    }
+
+    /// Appends a new bus. If `name` is already taken, Godot appends a number, see [`name()`][Self::name].
+    pub fn create(name: &str) -> Self {
+        let mut server = AudioServer::singleton();
+        server.add_bus();
+
+        let index = server.get_bus_count() - 1;
+        server.set_bus_name(index, name.into());
+
+        Self {
+            name: server.get_bus_name(index).into(),
+        }
+    }
+
+    pub fn name(&self) -> &StringName {
+        &self.name
+    }
+
+    /// The current position of the bus in the mixer; `None` if it has been removed or renamed.
+    pub fn index(&self) -> Option<i32> {
+        let index = AudioServer::singleton().get_bus_index(self.name.clone());
+        (index >= 0).then_some(index)
+    }
+
+    /// ⚠️ Removes the bus.
+    ///
+    /// # Panics
+    /// If the bus no longer exists.
+    pub fn remove(self) {
+        AudioServer::singleton().remove_bus(self.expect_index());
+    }
+
+    pub fn volume(&self) -> Decibels {
+        Decibels(AudioServer::singleton().get_bus_volume_db(self.expect_index()))
+    }
+
+    /// Sets the volume, given in [`Decibels`] or as [`Amplitude`].
+    pub fn set_volume(&mut self, volume: impl Into<Decibels>) {
+        let index = self.expect_index();
+        AudioServer::singleton().set_bus_volume_db(index, volume.into().0);
+    }
+
+    /// The peak volume of the left and right channel during the last mix, e.g. for level meters.
+    pub fn peak_volume(&self) -> (Decibels, Decibels) {
+        let server = AudioServer::singleton();
+        let index = self.expect_index();
+
+        (
+            Decibels(server.get_bus_peak_volume_left_db(index, 0)),
+            Decibels(server.get_bus_peak_volume_right_db(index, 0)),
+        )
+    }
+
+    pub fn is_muted(&self) -> bool {
+        AudioServer::singleton().is_bus_mute(self.expect_index())
+    }
+
+    pub fn set_muted(&mut self, muted: bool) {
+        AudioServer::singleton().set_bus_mute(self.expect_index(), muted);
+    }
+
+    pub fn is_solo(&self) -> bool {
+        AudioServer::singleton().is_bus_solo(self.expect_index())
+    }
+
+    pub fn set_solo(&mut self, solo: bool) {
+        AudioServer::singleton().set_bus_solo(self.expect_index(), solo);
+    }
+
+    /// Whether the effects of this bus are skipped.
+    pub fn is_bypassing_effects(&self) -> bool {
+        AudioServer::singleton().is_bus_bypassing_effects(self.expect_index())
+    }
+
+    pub fn set_bypass_effects(&mut self, bypass: bool) {
+        AudioServer::singleton().set_bus_bypass_effects(self.expect_index(), bypass);
+    }
+
+    /// The bus this one sends its output to; `None` for the master bus.
+    pub fn send(&self) -> Option<Self> {
+        let name = AudioServer::singleton().get_bus_send(self.expect_index());
+        Self::find(&name.to_string())
+    }
+
+    pub fn set_send(&mut self, target: &AudioBus) {
+        let index = self.expect_index();
+        AudioServer::singleton().set_bus_send(index, target.name.clone());
+    }
+
+    /// Number of effects on the bus.
+    pub fn effect_count(&self) -> i32 {
+        AudioServer::singleton().get_bus_effect_count(self.expect_index())
+    }
+
+    /// Appends `effect` to the bus, returning its effect index.
+    pub fn add_effect<E>(&mut self, effect: Gd<E>) -> i32
+    where
+        E: GodotClass + Inherits<AudioEffect>,
+    {
+        let mut server = AudioServer::singleton();
+        let index = self.expect_index();
+
+        server.add_bus_effect(index, effect.upcast());
+        server.get_bus_effect_count(index) - 1
+    }
+
+    /// The effect at `effect_index`, if there is one of type `E`.
+    pub fn effect<E>(&self, effect_index: i32) -> Option<Gd<E>>
+    where
+        E: GodotClass + Inherits<AudioEffect>,
+    {
+        let index = self.expect_index();
+        if !(0..self.effect_count()).contains(&effect_index) {
+            return None;
+        }
+
+        AudioServer::singleton()
+            .get_bus_effect(index, effect_index)?
+            .try_cast::<E>()
+    }
+
+    /// The first effect of type `E`, with its effect index.
+    pub fn find_effect<E>(&self) -> Option<(i32, Gd<E>)>
+    where
+        E: GodotClass + Inherits<AudioEffect>,
+    {
+        (0..self.effect_count())
+            .find_map(|effect_index| Some((effect_index, self.effect::<E>(effect_index)?)))
+    }
+
+    pub fn remove_effect(&mut self, effect_index: i32) {
+        AudioServer::singleton().remove_bus_effect(self.expect_index(), effect_index);
+    }
+
+    pub fn set_effect_enabled(&mut self, effect_index: i32, enabled: bool) {
+        let index = self.expect_index();
+        AudioServer::singleton().set_bus_effect_enabled(index, effect_index, enabled);
+    }
+
+    /// The analyzer of the first [`AudioEffectSpectrumAnalyzer`] on this bus; `None` if the bus has none, or audio is not running.
+    pub fn spectrum(&self) -> Option<Spectrum> {
+        let (effect_index, _) = self.find_effect::<AudioEffectSpectrumAnalyzer>()?;
+
+        let instance = AudioServer::singleton()
+            .get_bus_effect_instance(self.expect_index(), effect_index)?
+            .try_cast::<AudioEffectSpectrumAnalyzerInstance>()?;
+
+        Some(Spectrum { instance })
+    }
+
+    fn expect_index(&self) -> i32 {
+        self.index()
+            .unwrap_or_else(|| panic!("audio bus `{}` does not exist", self.name))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Frequency analysis of the sound passing a bus, obtained from [`AudioBus::spectrum()`].
+pub struct Spectrum {
+    instance: Gd<AudioEffectSpectrumAnalyzerInstance>,
+}
+
+impl Spectrum {
+    /// The peak magnitude of the left and right channel in the frequency range `from_hz..to_hz`.
+    pub fn magnitude(&self, from_hz: f32, to_hz: f32) -> Vector2 {
+        self.instance
+            .get_magnitude_for_frequency_range(from_hz, to_hz)
+    }
+
+    /// Splits `from_hz..to_hz` into `bands.len()` equally wide bands and writes the peak magnitude of each band (louder channel) into
+    /// `bands`, e.g. for an equalizer display updated every frame without allocation.
+    pub fn fill_bands(&self, from_hz: f32, to_hz: f32, bands: &mut [f32]) {
+        let width = (to_hz - from_hz) / bands.len() as f32;
+
+        for (i, band) in bands.iter_mut().enumerate() {
+            let start = from_hz + width * i as f32;
+            let magnitude = self.magnitude(start, start + width);
+
+            *band = magnitude.x.max(magnitude.y) as f32;
+        }
+    }
+}
@@ -0,0 +1,209 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Transform2D, Vector2};
+use crate::engine::CanvasItem;
+use crate::obj::{Gd, GodotClass, Inherits};
+
+/// The transforms between the 2D coordinate spaces of a canvas item.
+///
+/// The spaces, from the item outwards:
+/// - **Local:** relative to the item itself, e.g. the points of a `Polygon2D`.
+/// - **Global:** the canvas the item is drawn on, as in `global_position`. Items in different `CanvasLayer`s have separate canvases.
+/// - **Canvas:** the viewport's visible area after the canvas layer's and camera's transforms, as in `Viewport::get_mouse_position()`
+///   and input event positions.
+/// - **Screen:** pixels of the screen, after the viewport's stretch transform and the window position, as in
+///   `DisplayServer::mouse_get_position()`.
+///
+/// The transforms are captured when this is created, see [`CanvasSpaceExt::canvas_spaces()`]; the conversions themselves are pure.
+/// Each method `a_to_b()` converts a position from space `a` to space `b`.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::CanvasSpaceExt;
+///
+/// // Places a UI marker (in a CanvasLayer) over an enemy in the world, which a Camera2D may have scrolled.
+/// fn place_marker(enemy: &Gd<Node2D>, marker: &mut Gd<Control>) {
+///     let screen = enemy.canvas_spaces().local_to_screen(Vector2::ZERO);
+///     let position = marker.canvas_spaces().screen_to_global(screen);
+///     marker.set_global_position(position);
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CanvasSpaces {
+    /// Local space to global space, like `CanvasItem::get_global_transform()`.
+    pub global: Transform2D,
+
+    /// Global space to canvas space, like `CanvasItem::get_canvas_transform()`.
+    pub canvas: Transform2D,
+
+    /// Canvas space to screen space, like `Viewport::get_screen_transform()`.
+    pub screen: Transform2D,
+}
+
+impl CanvasSpaces {
+    pub fn local_to_global(&self, position: Vector2) -> Vector2 {
+        self.global * position
+    }
+
+    pub fn global_to_local(&self, position: Vector2) -> Vector2 {
+        self.global.affine_inverse() * position
+    }
+
+    pub fn global_to_canvas(&self, position: Vector2) -> Vector2 {
+        self.canvas * position
+    }
+
+    pub fn canvas_to_global(&self, position: Vector2) -> Vector2 {
+        self.canvas.affine_inverse() * position
+    }
+
+    pub fn canvas_to_screen(&self, position: Vector2) -> Vector2 {
+        self.screen * position
+    }
+
+    pub fn screen_to_canvas(&self, position: Vector2) -> Vector2 {
+        self.screen.affine_inverse() * position
+    }
+
+    pub fn local_to_canvas(&self, position: Vector2) -> Vector2 {
+        self.local_to_canvas_transform() * position
+    }
+
+    pub fn canvas_to_local(&self, position: Vector2) -> Vector2 {
+        self.local_to_canvas_transform().affine_inverse() * position
+    }
+
+    pub fn global_to_screen(&self, position: Vector2) -> Vector2 {
+        self.screen * self.canvas * position
+    }
+
+    pub fn screen_to_global(&self, position: Vector2) -> Vector2 {
+        (self.screen * self.canvas).affine_inverse() * position
+    }
+
+    pub fn local_to_screen(&self, position: Vector2) -> Vector2 {
+        self.local_to_screen_transform() * position
+    }
+
+    pub fn screen_to_local(&self, position: Vector2) -> Vector2 {
+        self.local_to_screen_transform().affine_inverse() * position
+    }
+
+    /// Local space to canvas space, like `CanvasItem::get_global_transform_with_canvas()`.
+    pub fn local_to_canvas_transform(&self) -> Transform2D {
+        self.canvas * self.global
+    }
+
+    /// Local space to screen space, like `CanvasItem::get_screen_transform()` in the engine's C++ code.
+    pub fn local_to_screen_transform(&self) -> Transform2D {
+        self.screen * self.canvas * self.global
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Extension methods for canvas items, converting positions between the spaces described in [`CanvasSpaces`].
+pub trait CanvasSpaceExt {
+    /// ⚠️ The current transforms of this item's spaces.
+    ///
+    /// # Panics
+    /// If the item is not inside the scene tree.
+    fn canvas_spaces(&self) -> CanvasSpaces;
+
+    /// ⚠️ Converts `position` from local space to screen space.
+    ///
+    /// # Panics
+    /// If the item is not inside the scene tree.
+    fn local_to_screen(&self, position: Vector2) -> Vector2 {
+        self.canvas_spaces().local_to_screen(position)
+    }
+
+    /// ⚠️ Converts `position` from screen space to local space.
+    ///
+    /// # Panics
+    /// If the item is not inside the scene tree.
+    fn screen_to_local(&self, position: Vector2) -> Vector2 {
+        self.canvas_spaces().screen_to_local(position)
+    }
+
+    /// ⚠️ Converts `position` from local space to canvas space, e.g. to compare it with input event positions.
+    ///
+    /// # Panics
+    /// If the item is not inside the scene tree.
+    fn local_to_canvas(&self, position: Vector2) -> Vector2 {
+        self.canvas_spaces().local_to_canvas(position)
+    }
+
+    /// ⚠️ Converts `position` from canvas space to local space, e.g. an input event position.
+    ///
+    /// # Panics
+    /// If the item is not inside the scene tree.
+    fn canvas_to_local(&self, position: Vector2) -> Vector2 {
+        self.canvas_spaces().canvas_to_local(position)
+    }
+}
+
+impl<U> CanvasSpaceExt for Gd<U>
+where
+    U: GodotClass + Inherits<CanvasItem>,
+{
+    fn canvas_spaces(&self) -> CanvasSpaces {
+        let item = self.clone().upcast::<CanvasItem>();
+        let viewport = item.get_viewport().unwrap_or_else(|| {
+            panic!(
+                "canvas item `{}` is not inside the scene tree",
+                item.get_name()
+            )
+        });
+
+        CanvasSpaces {
+            global: item.get_global_transform(),
+            canvas: item.get_canvas_transform(),
+            screen: viewport.get_screen_transform(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::CanvasSpaces;
+    use crate::builtin::{real_consts, Transform2D, Vector2};
+
+    #[test]
+    fn canvas_spaces_round_trip() {
+        let spaces = CanvasSpaces {
+            global: Transform2D::from_angle_origin(
+                real_consts::FRAC_PI_2,
+                Vector2::new(100.0, 50.0),
+            ),
+            // Camera scrolled by (-30, 0) and zoomed in 2x.
+            canvas: Transform2D::IDENTITY
+                .scaled(Vector2::splat(2.0))
+                .translated(Vector2::new(-60.0, 0.0)),
+            // Window at (10, 20) on the screen.
+            screen: Transform2D::IDENTITY.translated(Vector2::new(10.0, 20.0)),
+        };
+
+        let local = Vector2::new(5.0, 0.0);
+        let global = spaces.local_to_global(local);
+        assert!(global.is_equal_approx(Vector2::new(100.0, 55.0)));
+
+        let canvas = spaces.global_to_canvas(global);
+        assert!(canvas.is_equal_approx(Vector2::new(140.0, 110.0)));
+        assert!(spaces.local_to_canvas(local).is_equal_approx(canvas));
+
+        let screen = spaces.local_to_screen(local);
+        assert!(screen.is_equal_approx(Vector2::new(150.0, 130.0)));
+        assert!(spaces.global_to_screen(global).is_equal_approx(screen));
+
+        assert!(spaces.screen_to_local(screen).is_equal_approx(local));
+        assert!(spaces.screen_to_global(screen).is_equal_approx(global));
+        assert!(spaces.canvas_to_local(canvas).is_equal_approx(local));
+    }
+}
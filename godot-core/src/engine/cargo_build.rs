@@ -0,0 +1,511 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::SystemTime;
+
+use godot_ffi as sys;
+
+use crate::builtin::meta::{ClassName, PtrcallSignatureTuple, ToGodot};
+use crate::builtin::{varray, Callable, GodotString, Variant};
+use crate::engine::editor_plugin::CustomControlContainer;
+use crate::engine::global::Error;
+use crate::engine::{
+    Button, ConfigFile, EditorPlugin, GDExtensionManager, Node, Object, ProjectSettings,
+};
+use crate::init::InitLevel;
+use crate::log::{godot_error, godot_print, godot_warn};
+use crate::obj::{cap, dom, Base, Gd, GodotClass, Inherits};
+use crate::private::{as_storage, callbacks, ClassPlugin, PluginComponent};
+
+/// Editor plugin that runs `cargo build` from a toolbar button and hot-reloads the extension when the build succeeds.
+///
+/// Registered in Godot as `GdextCargoBuild` and added to the editor automatically. Reloading requires Godot 4.2 and
+/// `reloadable = true` in the `[configuration]` section of the `.gdextension` file.
+///
+/// The plugin watches the library files listed in all reloadable `.gdextension` files, which usually live in cargo's target
+/// directory. When one of them changes -- after a build from the button, or from a terminal or IDE -- its extension is reloaded.
+///
+/// The build is configured through project settings, added on first start:
+///
+/// | Setting                      | Default          | Meaning                                                             |
+/// |------------------------------|------------------|---------------------------------------------------------------------|
+/// | `gdext/cargo/crate_dir`      | `res://../rust`  | Directory in which `cargo build` runs; `res://` paths are resolved. |
+/// | `gdext/cargo/executable`     | `cargo`          | Cargo binary, for editors started without cargo in `PATH`.          |
+/// | `gdext/cargo/extra_args`     | (empty)          | Arguments after `cargo build`, separated by spaces.                 |
+/// | `gdext/cargo/reload_on_change` | `true`         | Also reload after builds outside the editor.                        |
+///
+/// Cargo's output is printed to the editor's output panel when the build fails.
+pub struct CargoBuildPlugin {
+    base: Base<EditorPlugin>,
+    build: Option<Receiver<Result<(), String>>>,
+    libraries: Option<Vec<WatchedLibrary>>,
+    since_scan: f64,
+}
+
+impl CargoBuildPlugin {
+    /// Starts `cargo build` in the background, unless a build is already running.
+    pub fn start_build(&mut self) {
+        if self.is_building() {
+            return;
+        }
+
+        let crate_dir = setting_string(CRATE_DIR);
+        let crate_dir = PathBuf::from(
+            ProjectSettings::singleton()
+                .globalize_path(crate_dir.into())
+                .to_string(),
+        );
+        let executable = setting_string(EXECUTABLE);
+        let extra_args = setting_string(EXTRA_ARGS);
+        let args = extra_args.split_whitespace().map(String::from).collect();
+
+        godot_print!("cargo build in {}...", crate_dir.display());
+        self.build = Some(spawn_build(executable, crate_dir, args));
+        self.update_button();
+    }
+
+    /// Whether a build started by the plugin is still running.
+    pub fn is_building(&self) -> bool {
+        self.build.is_some()
+    }
+
+    fn enter_tree(&mut self) {
+        register_settings();
+
+        if self.button().is_some() {
+            return;
+        }
+
+        let mut button = Button::new_alloc();
+        button.set_text(BUTTON_TEXT.into());
+        button.set_tooltip_text("Run `cargo build` and reload the extension on success".into());
+
+        // Targets an engine method instead of a Rust closure, so the connection stays valid when the library is reloaded.
+        let request = Callable::from_object_method((*self.base).clone(), "set_meta")
+            .as_inner()
+            .bindv(varray![BUILD_REQUEST_META, true]);
+        button.connect("pressed".into(), request);
+
+        self.base.add_control_to_container(
+            CustomControlContainer::CONTAINER_TOOLBAR,
+            button.clone().upcast(),
+        );
+        self.base.set_meta(BUTTON_META.into(), button.to_variant());
+    }
+
+    fn exit_tree(&mut self) {
+        if let Some(mut button) = self.button() {
+            self.base.remove_control_from_container(
+                CustomControlContainer::CONTAINER_TOOLBAR,
+                button.clone().upcast(),
+            );
+            button.queue_free();
+        }
+
+        self.base.remove_meta(BUTTON_META.into());
+    }
+
+    fn process(&mut self, delta: f64) {
+        if self.base.has_meta(BUILD_REQUEST_META.into()) {
+            self.base.remove_meta(BUILD_REQUEST_META.into());
+            self.start_build();
+        }
+
+        // Fields are reset when the library is reloaded, so the libraries are scanned again on first use.
+        if self.libraries.is_none() {
+            self.libraries = Some(watched_libraries());
+        }
+
+        if let Some(result) = self.poll_build() {
+            self.build = None;
+            self.update_button();
+            self.finish_build(result);
+            return;
+        }
+
+        self.since_scan += delta;
+        if self.since_scan >= SCAN_INTERVAL && !self.is_building() {
+            self.since_scan = 0.0;
+
+            if setting_bool(RELOAD_ON_CHANGE) {
+                self.reload_changed();
+            }
+        }
+    }
+
+    fn poll_build(&self) -> Option<Result<(), String>> {
+        match self.build.as_ref()?.try_recv() {
+            Ok(result) => Some(result),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => Some(Err("build thread ended unexpectedly".into())),
+        }
+    }
+
+    fn finish_build(&mut self, result: Result<(), String>) {
+        if let Err(output) = result {
+            godot_print!("{output}");
+            godot_error!("cargo build failed; see output above");
+            return;
+        }
+
+        if self.libraries.as_ref().is_some_and(Vec::is_empty) {
+            godot_warn!(
+                "cargo build succeeded, but no loaded .gdextension file has `reloadable = true`; restart the editor to load the new build"
+            );
+            return;
+        }
+
+        godot_print!("cargo build succeeded");
+        if !self.reload_changed() {
+            godot_print!("libraries unchanged, nothing to reload");
+        }
+    }
+
+    /// Reloads the extensions whose libraries changed since the last check; returns whether there were any.
+    fn reload_changed(&mut self) -> bool {
+        let Some(libraries) = self.libraries.as_mut() else {
+            return false;
+        };
+
+        let mut changed: Vec<GodotString> = Vec::new();
+        for library in libraries.iter_mut() {
+            let modified = modified_time(&library.path);
+            if modified != library.modified {
+                library.modified = modified;
+
+                if !changed.contains(&library.extension) {
+                    changed.push(library.extension.clone());
+                }
+            }
+        }
+
+        // Deferred, so that reloading the library does not happen while its code is running.
+        for extension in &changed {
+            godot_print!("reloading {extension}");
+            GDExtensionManager::singleton()
+                .call_deferred("reload_extension".into(), &[extension.to_variant()]);
+        }
+
+        !changed.is_empty()
+    }
+
+    fn button(&self) -> Option<Gd<Button>> {
+        if !self.base.has_meta(BUTTON_META.into()) {
+            return None;
+        }
+
+        self.base
+            .get_meta(BUTTON_META.into())
+            .try_to::<Gd<Button>>()
+            .ok()
+    }
+
+    fn update_button(&self) {
+        if let Some(mut button) = self.button() {
+            let building = self.is_building();
+            button.set_disabled(building);
+            button.set_text(if building { "Building..." } else { BUTTON_TEXT }.into());
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+const CRATE_DIR: &str = "gdext/cargo/crate_dir";
+const EXECUTABLE: &str = "gdext/cargo/executable";
+const EXTRA_ARGS: &str = "gdext/cargo/extra_args";
+const RELOAD_ON_CHANGE: &str = "gdext/cargo/reload_on_change";
+
+/// Stored on the plugin object rather than in Rust fields, so they survive reloading the library.
+const BUTTON_META: &str = "_gdext_cargo_button";
+const BUILD_REQUEST_META: &str = "_gdext_cargo_build_requested";
+
+const BUTTON_TEXT: &str = "Cargo Build";
+
+/// Seconds between checks of the library files.
+const SCAN_INTERVAL: f64 = 1.0;
+
+struct WatchedLibrary {
+    /// Path of the `.gdextension` file, as passed to `GDExtensionManager::reload_extension()`.
+    extension: GodotString,
+    path: PathBuf,
+    modified: Option<SystemTime>,
+}
+
+fn register_settings() {
+    let mut settings = ProjectSettings::singleton();
+    let defaults = [
+        (CRATE_DIR, "res://../rust".to_variant()),
+        (EXECUTABLE, "cargo".to_variant()),
+        (EXTRA_ARGS, "".to_variant()),
+        (RELOAD_ON_CHANGE, true.to_variant()),
+    ];
+
+    for (name, default) in defaults {
+        if !settings.has_setting(name.into()) {
+            settings.set_setting(name.into(), default.clone());
+        }
+
+        // Settings with their initial value are not written to project.godot.
+        settings.set_initial_value(name.into(), default);
+    }
+}
+
+fn setting(name: &str) -> Variant {
+    ProjectSettings::singleton().get_setting(name.into())
+}
+
+fn setting_string(name: &str) -> String {
+    setting(name).to_string()
+}
+
+fn setting_bool(name: &str) -> bool {
+    setting(name).try_to::<bool>().unwrap_or(false)
+}
+
+fn spawn_build(
+    executable: String,
+    dir: PathBuf,
+    args: Vec<String>,
+) -> Receiver<Result<(), String>> {
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        let result = match Command::new(&executable)
+            .arg("build")
+            .args(&args)
+            .current_dir(&dir)
+            .output()
+        {
+            Ok(output) if output.status.success() => Ok(()),
+            Ok(output) => Err(String::from_utf8_lossy(&output.stderr).into_owned()),
+            Err(err) => Err(format!(
+                "cannot run `{executable}` in {}: {err}",
+                dir.display()
+            )),
+        };
+
+        // The plugin may have been freed in the meantime.
+        let _ = sender.send(result);
+    });
+
+    receiver
+}
+
+/// Libraries of all loaded extensions that can be reloaded, with their current modification times.
+fn watched_libraries() -> Vec<WatchedLibrary> {
+    let manager = GDExtensionManager::singleton();
+    let settings = ProjectSettings::singleton();
+    let mut libraries = Vec::new();
+
+    for extension in manager.get_loaded_extensions().as_slice() {
+        let mut config = ConfigFile::new();
+        if config.load(extension.clone()) != Error::OK {
+            continue;
+        }
+
+        let reloadable = config
+            .get_value("configuration".into(), "reloadable".into())
+            .try_to::<bool>()
+            .unwrap_or(false);
+        if !reloadable || !config.has_section("libraries".into()) {
+            continue;
+        }
+
+        for key in config.get_section_keys("libraries".into()).as_slice() {
+            let library = config
+                .get_value("libraries".into(), key.clone())
+                .to_string();
+            let library = resolve_library(&extension.to_string(), &library);
+            let path = PathBuf::from(settings.globalize_path(library.into()).to_string());
+
+            if path.exists() {
+                libraries.push(WatchedLibrary {
+                    extension: extension.clone(),
+                    modified: modified_time(&path),
+                    path,
+                });
+            }
+        }
+    }
+
+    libraries
+}
+
+/// Paths in `.gdextension` files are either `res://` or relative to the file itself.
+fn resolve_library(extension: &str, library: &str) -> String {
+    if library.contains("://") || Path::new(library).is_absolute() {
+        return library.to_string();
+    }
+
+    match extension.rsplit_once('/') {
+        Some((dir, _file)) => format!("{dir}/{library}"),
+        None => library.to_string(),
+    }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    path.metadata().and_then(|meta| meta.modified()).ok()
+}
+
+unsafe impl GodotClass for CargoBuildPlugin {
+    type Base = EditorPlugin;
+    type Declarer = dom::UserDomain;
+    type Mem = <EditorPlugin as GodotClass>::Mem;
+    const INIT_LEVEL: Option<InitLevel> = <EditorPlugin as GodotClass>::INIT_LEVEL;
+
+    fn class_name() -> ClassName {
+        ClassName::from_ascii_cstr(b"GdextCargoBuild\0")
+    }
+}
+
+impl Inherits<EditorPlugin> for CargoBuildPlugin {}
+impl Inherits<Node> for CargoBuildPlugin {}
+impl Inherits<Object> for CargoBuildPlugin {}
+
+impl cap::GodotInit for CargoBuildPlugin {
+    fn __godot_init(base: Base<EditorPlugin>) -> Self {
+        Self {
+            base,
+            build: None,
+            libraries: None,
+            since_scan: 0.0,
+        }
+    }
+}
+
+impl cap::ImplementsGodotVirtual for CargoBuildPlugin {
+    fn __virtual_call(name: &str) -> sys::GDExtensionClassCallVirtual {
+        // Editor-only class, so no check for tool classes.
+        match name {
+            "_enter_tree" => {
+                unsafe extern "C" fn function(
+                    instance_ptr: sys::GDExtensionClassInstancePtr,
+                    args_ptr: *const sys::GDExtensionConstTypePtr,
+                    ret: sys::GDExtensionTypePtr,
+                ) {
+                    <((),) as PtrcallSignatureTuple>::in_ptrcall(
+                        instance_ptr,
+                        args_ptr,
+                        ret,
+                        |instance_ptr, ()| {
+                            let storage = unsafe { as_storage::<CargoBuildPlugin>(instance_ptr) };
+                            storage.get_mut().enter_tree();
+                        },
+                        "enter_tree",
+                        sys::PtrcallType::Virtual,
+                    )
+                }
+                Some(function)
+            }
+            "_exit_tree" => {
+                unsafe extern "C" fn function(
+                    instance_ptr: sys::GDExtensionClassInstancePtr,
+                    args_ptr: *const sys::GDExtensionConstTypePtr,
+                    ret: sys::GDExtensionTypePtr,
+                ) {
+                    <((),) as PtrcallSignatureTuple>::in_ptrcall(
+                        instance_ptr,
+                        args_ptr,
+                        ret,
+                        |instance_ptr, ()| {
+                            let storage = unsafe { as_storage::<CargoBuildPlugin>(instance_ptr) };
+                            storage.get_mut().exit_tree();
+                        },
+                        "exit_tree",
+                        sys::PtrcallType::Virtual,
+                    )
+                }
+                Some(function)
+            }
+            "_process" => {
+                unsafe extern "C" fn function(
+                    instance_ptr: sys::GDExtensionClassInstancePtr,
+                    args_ptr: *const sys::GDExtensionConstTypePtr,
+                    ret: sys::GDExtensionTypePtr,
+                ) {
+                    <((), f64) as PtrcallSignatureTuple>::in_ptrcall(
+                        instance_ptr,
+                        args_ptr,
+                        ret,
+                        |instance_ptr, (delta,)| {
+                            let storage = unsafe { as_storage::<CargoBuildPlugin>(instance_ptr) };
+                            storage.get_mut().process(delta);
+                        },
+                        "process",
+                        sys::PtrcallType::Virtual,
+                    )
+                }
+                Some(function)
+            }
+            _ => None,
+        }
+    }
+}
+
+sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in crate::private; ClassPlugin {
+    class_name: CargoBuildPlugin::class_name(),
+    component: PluginComponent::ClassDef {
+        base_class_name: EditorPlugin::class_name(),
+        generated_create_fn: Some(callbacks::create::<CargoBuildPlugin>),
+        generated_recreate_fn: Some(callbacks::recreate::<CargoBuildPlugin>),
+        free_fn: callbacks::free::<CargoBuildPlugin>,
+        is_instantiable: true,
+        is_auto_registered: true,
+        register_if: None,
+        crate_name: env!("CARGO_PKG_NAME"),
+    },
+    init_level: CargoBuildPlugin::INIT_LEVEL,
+});
+
+sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in crate::private; ClassPlugin {
+    class_name: CargoBuildPlugin::class_name(),
+    component: PluginComponent::UserVirtuals {
+        user_register_fn: None,
+        user_create_fn: None,
+        user_recreate_fn: None,
+        user_to_string_fn: None,
+        user_on_notification_fn: None,
+        get_virtual_fn: callbacks::get_virtual::<CargoBuildPlugin>,
+    },
+    init_level: CargoBuildPlugin::INIT_LEVEL,
+});
+
+sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in crate::private; ClassPlugin {
+    class_name: CargoBuildPlugin::class_name(),
+    component: PluginComponent::EditorPlugin,
+    init_level: CargoBuildPlugin::INIT_LEVEL,
+});
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::resolve_library;
+
+    #[test]
+    fn library_paths() {
+        let extension = "res://addons/game/game.gdextension";
+
+        assert_eq!(
+            resolve_library(extension, "res://../rust/target/debug/libgame.so"),
+            "res://../rust/target/debug/libgame.so"
+        );
+        assert_eq!(
+            resolve_library(extension, "bin/libgame.so"),
+            "res://addons/game/bin/libgame.so"
+        );
+        assert_eq!(
+            resolve_library(extension, "/opt/game/libgame.so"),
+            "/opt/game/libgame.so"
+        );
+    }
+}
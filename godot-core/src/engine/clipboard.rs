@@ -0,0 +1,62 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::engine::DisplayServer;
+
+/// Text on the system clipboard.
+///
+/// On Linux, there is also the [primary selection](Self::primary): the text selected last, pasted with the middle mouse button.
+/// Elsewhere, the primary selection is always empty.
+///
+/// ```no_run
+/// use godot::engine::Clipboard;
+///
+/// fn copy_seed(seed: u64) {
+///     Clipboard::set(&seed.to_string());
+/// }
+///
+/// fn paste_seed() -> Option<u64> {
+///     Clipboard::get()?.trim().parse().ok()
+/// }
+/// ```
+pub struct Clipboard {
+    _private: (),
+}
+
+impl Clipboard {
+    /// The text on the clipboard, or `None` if it holds no text.
+    pub fn get() -> Option<String> {
+        let display = DisplayServer::singleton();
+
+        display
+            .clipboard_has()
+            .then(|| display.clipboard_get().to_string())
+    }
+
+    /// Puts `text` on the clipboard.
+    pub fn set(text: &str) {
+        DisplayServer::singleton().clipboard_set(text.into());
+    }
+
+    /// Whether the clipboard holds text.
+    pub fn has_text() -> bool {
+        DisplayServer::singleton().clipboard_has()
+    }
+
+    /// The primary selection, or `None` if it is empty or not supported.
+    pub fn primary() -> Option<String> {
+        let text = DisplayServer::singleton()
+            .clipboard_get_primary()
+            .to_string();
+
+        (!text.is_empty()).then_some(text)
+    }
+
+    /// Replaces the primary selection; no effect where it is not supported.
+    pub fn set_primary(text: &str) {
+        DisplayServer::singleton().clipboard_set_primary(text.into());
+    }
+}
@@ -0,0 +1,229 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not};
+
+use crate::builtin::meta::FromGodot;
+use crate::builtin::GodotString;
+use crate::engine::ProjectSettings;
+
+/// Set of layers, as used for the collision layer and mask of physics objects, render layers, navigation layers and others.
+///
+/// Godot numbers layers from 1 to 32 in the editor and in project settings, while the APIs take `u32` bit masks in which layer
+/// `n` is bit `n - 1`. This type takes layer numbers and converts to masks with [`bits()`][Self::bits]:
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{CharacterBody2D, CollisionLayers, LayerKind};
+///
+/// const PLAYER: CollisionLayers = CollisionLayers::layer(1);
+/// const WALLS: CollisionLayers = CollisionLayers::layer(2);
+/// const ENEMIES: CollisionLayers = CollisionLayers::layer(3);
+///
+/// fn setup(body: &mut Gd<CharacterBody2D>) {
+///     body.set_collision_layer(PLAYER.bits());
+///     body.set_collision_mask((WALLS | ENEMIES).bits());
+///
+///     // Layers can also be looked up by the names given in the project settings.
+///     let hazards = CollisionLayers::named(LayerKind::Physics2D, "hazards").expect("layer `hazards`");
+///     let mask = CollisionLayers::from_bits(body.get_collision_mask());
+///     body.set_collision_mask((mask | hazards).bits());
+/// }
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub struct CollisionLayers(u32);
+
+impl CollisionLayers {
+    /// No layer.
+    pub const NONE: Self = Self(0);
+
+    /// All 32 layers.
+    pub const ALL: Self = Self(u32::MAX);
+
+    /// ⚠️ The single layer `number`, counting from 1 like the editor.
+    ///
+    /// # Panics
+    /// If `number` is not in `1..=32`.
+    pub const fn layer(number: u32) -> Self {
+        assert!(matches!(number, 1..=32), "layer number must be in 1..=32");
+        Self(1 << (number - 1))
+    }
+
+    /// The layers of a bit mask, as returned by e.g. `get_collision_mask()`.
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    /// The bit mask, as taken by e.g. `set_collision_layer()`.
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// ⚠️ These layers plus layer `number`.
+    ///
+    /// # Panics
+    /// If `number` is not in `1..=32`.
+    pub const fn with(self, number: u32) -> Self {
+        Self(self.0 | Self::layer(number).0)
+    }
+
+    /// ⚠️ These layers without layer `number`.
+    ///
+    /// # Panics
+    /// If `number` is not in `1..=32`.
+    pub const fn without(self, number: u32) -> Self {
+        Self(self.0 & !Self::layer(number).0)
+    }
+
+    /// ⚠️ Whether layer `number` is part of the set.
+    ///
+    /// # Panics
+    /// If `number` is not in `1..=32`.
+    pub const fn contains(self, number: u32) -> bool {
+        self.0 & Self::layer(number).0 != 0
+    }
+
+    /// Whether both sets have a layer in common, i.e. an object on layers `self` is detected by a mask `other`.
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The numbers of the layers in the set, in ascending order.
+    pub fn numbers(self) -> impl Iterator<Item = u32> {
+        (1..=32).filter(move |&number| self.contains(number))
+    }
+
+    /// The layer called `name` in the project settings, e.g. `layer_names/2d_physics/layer_3`; `None` if no layer has that name.
+    pub fn named(kind: LayerKind, name: &str) -> Option<Self> {
+        (1..=kind.layer_count())
+            .find(|&number| layer_name(kind, number).as_deref() == Some(name))
+            .map(Self::layer)
+    }
+
+    /// The layers with the given names in the project settings.
+    ///
+    /// Returns `Err` with the first name that does not belong to any layer.
+    pub fn from_names<'a>(kind: LayerKind, names: &[&'a str]) -> Result<Self, &'a str> {
+        names.iter().try_fold(Self::NONE, |layers, &name| {
+            Self::named(kind, name)
+                .map(|layer| layers | layer)
+                .ok_or(name)
+        })
+    }
+}
+
+impl fmt::Debug for CollisionLayers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CollisionLayers")
+            .field(&self.numbers().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl From<u32> for CollisionLayers {
+    fn from(bits: u32) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<CollisionLayers> for u32 {
+    fn from(layers: CollisionLayers) -> Self {
+        layers.0
+    }
+}
+
+impl BitOr for CollisionLayers {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for CollisionLayers {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitAnd for CollisionLayers {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl BitAndAssign for CollisionLayers {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.0 &= rhs.0;
+    }
+}
+
+impl Not for CollisionLayers {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self(!self.0)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Kind of layer whose names are configured under `layer_names/` in the project settings.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum LayerKind {
+    Physics2D,
+    Physics3D,
+    Render2D,
+    Render3D,
+    Navigation2D,
+    Navigation3D,
+    Avoidance,
+}
+
+impl LayerKind {
+    /// The settings section, e.g. `2d_physics` for `layer_names/2d_physics/layer_1`.
+    pub fn settings_section(self) -> &'static str {
+        match self {
+            Self::Physics2D => "2d_physics",
+            Self::Physics3D => "3d_physics",
+            Self::Render2D => "2d_render",
+            Self::Render3D => "3d_render",
+            Self::Navigation2D => "2d_navigation",
+            Self::Navigation3D => "3d_navigation",
+            Self::Avoidance => "avoidance",
+        }
+    }
+
+    /// Number of layers that can be named; render layers have 20, all others 32.
+    pub fn layer_count(self) -> u32 {
+        match self {
+            Self::Render2D | Self::Render3D => 20,
+            _ => 32,
+        }
+    }
+}
+
+/// The name of layer `number` in the project settings; `None` if the layer is unnamed.
+pub fn layer_name(kind: LayerKind, number: u32) -> Option<String> {
+    let setting = format!("layer_names/{}/layer_{number}", kind.settings_section());
+    let settings = ProjectSettings::singleton();
+
+    if !settings.has_setting(setting.clone().into()) {
+        return None;
+    }
+
+    let name = GodotString::try_from_variant(&settings.get_setting(setting.into())).ok()?;
+    let name = name.to_string();
+
+    (!name.is_empty()).then_some(name)
+}
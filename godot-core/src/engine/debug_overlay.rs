@@ -0,0 +1,338 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use godot_ffi as sys;
+
+use crate::builtin::meta::{ClassName, PtrcallSignatureTuple};
+use crate::builtin::{Color, Vector2};
+use crate::engine::{CanvasLayer, Label, Node, Object};
+use crate::init::InitLevel;
+use crate::obj::{cap, dom, Base, Gd, GodotClass, Inherits};
+use crate::private::{as_storage, callbacks, ClassPlugin, PluginComponent};
+
+/// Counters of the work done by the binding layer, such as calls into Godot and `Variant` conversions.
+///
+/// Counting only happens with the `debug-overlay` feature. The counters are cumulative since the library was loaded; subtract two
+/// snapshots with [`since()`][Self::since] to get the work within a period, as [`DebugOverlay`] does for each frame.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct BindingMetrics {
+    /// Calls from Rust into Godot: class methods, builtin methods and utility functions.
+    pub ffi_calls: u64,
+
+    /// Conversions of Rust values to and from `Variant`.
+    ///
+    /// Counted in the provided methods [`ToGodot::to_variant()`][crate::builtin::meta::ToGodot::to_variant] and
+    /// [`FromGodot::try_from_variant()`][crate::builtin::meta::FromGodot::try_from_variant], which also back `#[func]` varcalls.
+    /// Implementations overriding these methods, and the arguments of calls to vararg engine methods, are not counted.
+    pub variant_conversions: u64,
+
+    /// Times that `Gd::bind()` or `Gd::bind_mut()` had to wait for another thread to release the object.
+    ///
+    /// Only counted with `experimental-threads`. Without it, such a bind panics instead of waiting, so this stays 0.
+    pub bind_contentions: u64,
+
+    /// [`SignalFuture`][crate::engine::SignalFuture]s still waiting for their signal. Unlike the other fields, this is the current
+    /// value and not a count.
+    pub pending_futures: u64,
+}
+
+impl BindingMetrics {
+    /// The counters as of now.
+    pub fn current() -> Self {
+        Self {
+            ffi_calls: FFI_CALLS.load(Ordering::Relaxed),
+            variant_conversions: VARIANT_CONVERSIONS.load(Ordering::Relaxed),
+            bind_contentions: BIND_CONTENTIONS.load(Ordering::Relaxed),
+            pending_futures: PENDING_FUTURES.load(Ordering::Relaxed),
+        }
+    }
+
+    /// The work done between the snapshot `earlier` and this one; `pending_futures` is taken from this snapshot.
+    pub fn since(&self, earlier: &Self) -> Self {
+        Self {
+            ffi_calls: self.ffi_calls.saturating_sub(earlier.ffi_calls),
+            variant_conversions: self
+                .variant_conversions
+                .saturating_sub(earlier.variant_conversions),
+            bind_contentions: self
+                .bind_contentions
+                .saturating_sub(earlier.bind_contentions),
+            pending_futures: self.pending_futures,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// On-screen display of [`BindingMetrics`] per frame, to find hotspots in the binding layer.
+///
+/// Registered in Godot as `GdextDebugOverlay`, a `CanvasLayer` drawn above the game. Add it anywhere in the scene tree, from Rust
+/// with [`add_to()`][Self::add_to] or from GDScript with `add_child(GdextDebugOverlay.new())`, for example in an autoload.
+///
+/// The shown values are averages per process frame, refreshed twice per second by default. They include the few calls the overlay
+/// itself makes to update its text. Like other classes without `#[class(tool)]`, the overlay is inactive in the editor.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::DebugOverlay;
+///
+/// fn show_metrics(root: &Gd<Node>) {
+///     let mut overlay = DebugOverlay::add_to(root);
+///     overlay.bind_mut().set_refresh_interval(1.0);
+/// }
+/// ```
+pub struct DebugOverlay {
+    base: Base<CanvasLayer>,
+    label: Option<Gd<Label>>,
+    refresh_interval: f64,
+    elapsed: f64,
+    frames: u64,
+    last: BindingMetrics,
+}
+
+impl DebugOverlay {
+    /// Creates an overlay and adds it as child of `parent`.
+    pub fn add_to<N: Inherits<Node>>(parent: &Gd<N>) -> Gd<Self> {
+        let overlay = Gd::<Self>::new_default();
+        parent
+            .clone()
+            .upcast::<Node>()
+            .add_child(overlay.clone().upcast());
+
+        overlay
+    }
+
+    /// Seconds between refreshes of the shown values.
+    pub fn refresh_interval(&self) -> f64 {
+        self.refresh_interval
+    }
+
+    pub fn set_refresh_interval(&mut self, seconds: f64) {
+        self.refresh_interval = seconds;
+    }
+
+    fn process(&mut self, delta: f64) {
+        if self.label.is_none() {
+            self.label = Some(self.create_label());
+            self.last = BindingMetrics::current();
+            return;
+        }
+
+        self.elapsed += delta;
+        self.frames += 1;
+        if self.elapsed < self.refresh_interval {
+            return;
+        }
+
+        let current = BindingMetrics::current();
+        let text = format_metrics(&current.since(&self.last), self.frames);
+
+        if let Some(label) = self.label.as_mut() {
+            label.set_text(text.into());
+        }
+
+        // Taken after updating the text, so the update is counted towards the next period.
+        self.last = BindingMetrics::current();
+        self.elapsed = 0.0;
+        self.frames = 0;
+    }
+
+    fn create_label(&mut self) -> Gd<Label> {
+        let mut label = Label::new_alloc();
+        label.set_position(Vector2::new(8.0, 8.0));
+        label.add_theme_color_override("font_outline_color".into(), Color::BLACK);
+        label.add_theme_constant_override("outline_size".into(), 4);
+
+        // Above regular canvas layers; there is no upper limit.
+        self.base.set_layer(128);
+        self.base.add_child(label.clone().upcast());
+
+        label
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+static FFI_CALLS: AtomicU64 = AtomicU64::new(0);
+static VARIANT_CONVERSIONS: AtomicU64 = AtomicU64::new(0);
+static BIND_CONTENTIONS: AtomicU64 = AtomicU64::new(0);
+static PENDING_FUTURES: AtomicU64 = AtomicU64::new(0);
+
+/// Hooks for the counters, called from the binding code.
+pub(crate) mod metrics {
+    use super::*;
+
+    pub fn ffi_call() {
+        FFI_CALLS.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn variant_conversion() {
+        VARIANT_CONVERSIONS.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn bind_contention() {
+        BIND_CONTENTIONS.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn future_created() {
+        PENDING_FUTURES.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn future_settled() {
+        PENDING_FUTURES.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+fn format_metrics(metrics: &BindingMetrics, frames: u64) -> String {
+    let per_frame = |count: u64| count as f64 / frames.max(1) as f64;
+
+    let mut text = String::from("gdext, per frame\n");
+    let _ = writeln!(text, "FFI calls: {:.0}", per_frame(metrics.ffi_calls));
+    let _ = writeln!(
+        text,
+        "Variant conversions: {:.0}",
+        per_frame(metrics.variant_conversions)
+    );
+    let _ = writeln!(
+        text,
+        "Bind contentions: {:.1}",
+        per_frame(metrics.bind_contentions)
+    );
+    let _ = write!(text, "Pending futures: {}", metrics.pending_futures);
+
+    text
+}
+
+unsafe impl GodotClass for DebugOverlay {
+    type Base = CanvasLayer;
+    type Declarer = dom::UserDomain;
+    type Mem = <CanvasLayer as GodotClass>::Mem;
+    const INIT_LEVEL: Option<InitLevel> = <CanvasLayer as GodotClass>::INIT_LEVEL;
+
+    fn class_name() -> ClassName {
+        ClassName::from_ascii_cstr(b"GdextDebugOverlay\0")
+    }
+}
+
+impl Inherits<CanvasLayer> for DebugOverlay {}
+impl Inherits<Node> for DebugOverlay {}
+impl Inherits<Object> for DebugOverlay {}
+
+impl cap::GodotInit for DebugOverlay {
+    fn __godot_init(base: Base<CanvasLayer>) -> Self {
+        Self {
+            base,
+            label: None,
+            refresh_interval: 0.5,
+            elapsed: 0.0,
+            frames: 0,
+            last: BindingMetrics::default(),
+        }
+    }
+}
+
+impl cap::ImplementsGodotVirtual for DebugOverlay {
+    fn __virtual_call(name: &str) -> sys::GDExtensionClassCallVirtual {
+        if crate::private::is_class_inactive(false) {
+            return None;
+        }
+
+        match name {
+            "_process" => {
+                unsafe extern "C" fn function(
+                    instance_ptr: sys::GDExtensionClassInstancePtr,
+                    args_ptr: *const sys::GDExtensionConstTypePtr,
+                    ret: sys::GDExtensionTypePtr,
+                ) {
+                    <((), f64) as PtrcallSignatureTuple>::in_ptrcall(
+                        instance_ptr,
+                        args_ptr,
+                        ret,
+                        |instance_ptr, (delta,)| {
+                            let storage = unsafe { as_storage::<DebugOverlay>(instance_ptr) };
+                            storage.get_mut().process(delta);
+                        },
+                        "process",
+                        sys::PtrcallType::Virtual,
+                    )
+                }
+                Some(function)
+            }
+            _ => None,
+        }
+    }
+}
+
+sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in crate::private; ClassPlugin {
+    class_name: DebugOverlay::class_name(),
+    component: PluginComponent::ClassDef {
+        base_class_name: CanvasLayer::class_name(),
+        generated_create_fn: Some(callbacks::create::<DebugOverlay>),
+        generated_recreate_fn: None,
+        free_fn: callbacks::free::<DebugOverlay>,
+        is_instantiable: true,
+        is_auto_registered: true,
+        register_if: None,
+        crate_name: env!("CARGO_PKG_NAME"),
+    },
+    init_level: DebugOverlay::INIT_LEVEL,
+});
+
+sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in crate::private; ClassPlugin {
+    class_name: DebugOverlay::class_name(),
+    component: PluginComponent::UserVirtuals {
+        user_register_fn: None,
+        user_create_fn: None,
+        user_recreate_fn: None,
+        user_to_string_fn: None,
+        user_on_notification_fn: None,
+        get_virtual_fn: callbacks::get_virtual::<DebugOverlay>,
+    },
+    init_level: DebugOverlay::INIT_LEVEL,
+});
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::{format_metrics, BindingMetrics};
+
+    #[test]
+    fn metrics_since_and_format() {
+        let earlier = BindingMetrics {
+            ffi_calls: 100,
+            variant_conversions: 10,
+            bind_contentions: 0,
+            pending_futures: 5,
+        };
+        let later = BindingMetrics {
+            ffi_calls: 400,
+            variant_conversions: 70,
+            bind_contentions: 1,
+            pending_futures: 2,
+        };
+
+        let period = later.since(&earlier);
+        assert_eq!(
+            period,
+            BindingMetrics {
+                ffi_calls: 300,
+                variant_conversions: 60,
+                bind_contentions: 1,
+                pending_futures: 2,
+            }
+        );
+
+        assert_eq!(
+            format_metrics(&period, 3),
+            "gdext, per frame\nFFI calls: 100\nVariant conversions: 20\nBind contentions: 0.3\nPending futures: 2"
+        );
+    }
+}
@@ -0,0 +1,324 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error as StdError;
+use std::fmt;
+
+use crate::builtin::VariantConversionError;
+use crate::engine::global::Error;
+use crate::engine::{
+    ConfigError, ConnectError, ExpressionError, PackedStateError, SaveStateError, SceneError,
+    VariantDecodeError,
+};
+
+/// Result of engine interaction that can fail, with the error carrying where it happened.
+pub type GodotResult<T> = Result<T, GodotError>;
+
+/// Error from interacting with the engine, together with the chain of operations during which it happened.
+///
+/// An engine call reporting `ERR_FILE_NOT_FOUND` deep inside a loading routine says little by itself. While the error is
+/// propagated, each level can attach a [`ErrorContext`] frame with the [`Context`] methods: the class and method that was called,
+/// the resource or node involved, or a free-form note. The frames are shown from the outermost to the innermost:
+///
+/// ```text
+/// while loading `res://levels/3.tscn`: in ResourceLoader::load(): no value returned
+/// ```
+///
+/// `GodotError` implements [`std::error::Error`] and is `Send + Sync`, so it converts into `anyhow::Error` with `?`, and can
+/// be wrapped by `thiserror` types with `#[from]` or `#[source]`. Errors of other libraries are wrapped with
+/// [`from_source()`][Self::from_source] and remain available through [`source()`][StdError::source].
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{Context, GodotResult, ResourceLoader};
+///
+/// fn load_level(index: u32) -> GodotResult<Gd<PackedScene>> {
+///     let path = format!("res://levels/{index}.tscn");
+///
+///     ResourceLoader::singleton()
+///         .load(path.as_str().into())
+///         .in_call("ResourceLoader", "load")
+///         .for_resource(&path)?
+///         .try_cast::<PackedScene>()
+///         .context("resource is not a scene")
+///         .context(format!("loading level {index}"))
+/// }
+/// ```
+pub struct GodotError {
+    kind: ErrorKind,
+
+    /// Innermost frame first, in the order they were attached.
+    context: Vec<ErrorContext>,
+}
+
+impl GodotError {
+    /// Error described by `message`, without an error code.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self::with_kind(ErrorKind::Message(message.into()))
+    }
+
+    /// Error for an engine call that returned `code`.
+    pub fn from_code(code: Error) -> Self {
+        Self::with_kind(ErrorKind::Code(code))
+    }
+
+    /// Wraps an error of another library, which remains available through [`source()`][StdError::source].
+    pub fn from_source(source: impl StdError + Send + Sync + 'static) -> Self {
+        Self::with_kind(ErrorKind::Source(Box::new(source)))
+    }
+
+    /// Adds a frame, which describes the operation during which the error happened.
+    pub fn with_context(mut self, frame: ErrorContext) -> Self {
+        self.context.push(frame);
+        self
+    }
+
+    /// The code returned by Godot, if the error originated from one.
+    pub fn code(&self) -> Option<Error> {
+        match &self.kind {
+            ErrorKind::Code(code) => Some(*code),
+            _ => None,
+        }
+    }
+
+    /// The attached frames, innermost first.
+    pub fn context(&self) -> &[ErrorContext] {
+        &self.context
+    }
+
+    /// The resource path of the innermost [`ErrorContext::Resource`] frame, if any.
+    pub fn resource_path(&self) -> Option<&str> {
+        self.context.iter().find_map(|frame| match frame {
+            ErrorContext::Resource(path) => Some(path.as_str()),
+            _ => None,
+        })
+    }
+
+    fn with_kind(kind: ErrorKind) -> Self {
+        Self {
+            kind,
+            context: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for GodotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for frame in self.context.iter().rev() {
+            write!(f, "{frame}: ")?;
+        }
+
+        match &self.kind {
+            ErrorKind::Message(message) => f.write_str(message),
+            ErrorKind::Code(code) => write!(f, "engine returned {code:?}"),
+            ErrorKind::Source(source) => write!(f, "{source}"),
+        }
+    }
+}
+
+impl fmt::Debug for GodotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Like Display, so that `unwrap()` and `fn main() -> GodotResult<()>` show the chain.
+        write!(f, "GodotError(\"{self}\")")
+    }
+}
+
+impl StdError for GodotError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match &self.kind {
+            ErrorKind::Source(source) => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for GodotError {
+    fn from(code: Error) -> Self {
+        Self::from_code(code)
+    }
+}
+
+macro_rules! impl_from_error {
+    ($($Error:ty),* $(,)?) => {
+        $(
+            impl From<$Error> for GodotError {
+                fn from(error: $Error) -> Self {
+                    Self::from_source(error)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_error!(
+    ConfigError,
+    ConnectError,
+    ExpressionError,
+    PackedStateError,
+    SaveStateError,
+    SceneError,
+    VariantConversionError,
+    VariantDecodeError,
+);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Frame of a [`GodotError`], describing one operation it happened in.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorContext {
+    /// Call of the engine or script method `method` on `class`.
+    Call { class: String, method: String },
+
+    /// Operation on the resource at this path.
+    Resource(String),
+
+    /// Operation on the node at this path.
+    Node(String),
+
+    /// Free-form description, e.g. of the user-level operation.
+    Message(String),
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Call { class, method } => write!(f, "in {class}::{method}()"),
+            Self::Resource(path) => write!(f, "while loading `{path}`"),
+            Self::Node(path) => write!(f, "at node `{path}`"),
+            Self::Message(message) => f.write_str(message),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Attaches [`ErrorContext`] frames while propagating errors, converting them to [`GodotError`].
+///
+/// Implemented for `Result`s whose error converts into `GodotError` (Godot's `Error` codes and the error types of this crate),
+/// and for `Option`, whose `None` becomes an error "no value returned".
+pub trait Context<T> {
+    /// Converts the error, adding `frame`.
+    fn with_frame(self, frame: impl FnOnce() -> ErrorContext) -> GodotResult<T>;
+
+    /// Adds a free-form description of the failed operation.
+    fn context(self, message: impl Into<String>) -> GodotResult<T>
+    where
+        Self: Sized,
+    {
+        self.with_frame(|| ErrorContext::Message(message.into()))
+    }
+
+    /// Adds the engine method that failed.
+    fn in_call(self, class: &str, method: &str) -> GodotResult<T>
+    where
+        Self: Sized,
+    {
+        self.with_frame(|| ErrorContext::Call {
+            class: class.to_string(),
+            method: method.to_string(),
+        })
+    }
+
+    /// Adds the path of the resource being processed.
+    fn for_resource(self, path: &str) -> GodotResult<T>
+    where
+        Self: Sized,
+    {
+        self.with_frame(|| ErrorContext::Resource(path.to_string()))
+    }
+
+    /// Adds the path of the node being processed.
+    fn for_node(self, path: &str) -> GodotResult<T>
+    where
+        Self: Sized,
+    {
+        self.with_frame(|| ErrorContext::Node(path.to_string()))
+    }
+}
+
+impl<T, E: Into<GodotError>> Context<T> for Result<T, E> {
+    fn with_frame(self, frame: impl FnOnce() -> ErrorContext) -> GodotResult<T> {
+        self.map_err(|error| error.into().with_context(frame()))
+    }
+}
+
+impl<T> Context<T> for Option<T> {
+    fn with_frame(self, frame: impl FnOnce() -> ErrorContext) -> GodotResult<T> {
+        self.ok_or_else(|| GodotError::new("no value returned").with_context(frame()))
+    }
+}
+
+/// Converts Godot's [`Error`] codes into [`GodotResult`]s, for use with `?`.
+pub trait ErrorCodeExt {
+    /// `Ok(())` for [`Error::OK`], otherwise the code as [`GodotError`].
+    fn into_result(self) -> GodotResult<()>;
+}
+
+impl ErrorCodeExt for Error {
+    fn into_result(self) -> GodotResult<()> {
+        match self {
+            Error::OK => Ok(()),
+            code => Err(GodotError::from_code(code)),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+enum ErrorKind {
+    Message(String),
+    Code(Error),
+    Source(Box<dyn StdError + Send + Sync>),
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::{Context, ErrorCodeExt, ErrorContext, GodotResult};
+    use crate::engine::global::Error;
+
+    fn open_save(slot: u32) -> GodotResult<()> {
+        Error::ERR_FILE_NOT_FOUND
+            .into_result()
+            .in_call("FileAccess", "open")
+            .for_resource(&format!("user://save_{slot}.dat"))
+            .context("restoring the game")
+    }
+
+    #[test]
+    fn error_context_chain() {
+        let error = open_save(2).unwrap_err();
+
+        assert_eq!(error.code(), Some(Error::ERR_FILE_NOT_FOUND));
+        assert_eq!(error.resource_path(), Some("user://save_2.dat"));
+        assert_eq!(error.context().len(), 3);
+        assert_eq!(
+            error.to_string(),
+            "restoring the game: while loading `user://save_2.dat`: in FileAccess::open(): engine returned ERR_FILE_NOT_FOUND"
+        );
+    }
+
+    #[test]
+    fn error_context_option() {
+        let error = None::<i32>.for_node("Player/Camera").unwrap_err();
+
+        assert_eq!(error.code(), None);
+        assert_eq!(
+            error.context(),
+            &[ErrorContext::Node("Player/Camera".to_string())]
+        );
+        assert_eq!(
+            error.to_string(),
+            "at node `Player/Camera`: no value returned"
+        );
+
+        assert_eq!(Some(5).context("unused").unwrap(), 5);
+        assert!(Error::OK.into_result().is_ok());
+    }
+}
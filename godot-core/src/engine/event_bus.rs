@@ -0,0 +1,303 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::builtin::meta::{FromGodot, ToGodot};
+use crate::builtin::{Callable, RustCallable, StringName, Variant};
+use crate::engine::{utilities, Object};
+use crate::obj::{Gd, GodotClass, Inherits, InstanceId};
+
+/// Event type that can be sent over an [`EventBus`].
+///
+/// Each event type corresponds to one user signal of the bus, named [`NAME`][Self::NAME]. The event is passed as the signal's only
+/// argument, so GDScript code connecting to the signal receives `to_variant()` of the event.
+pub trait Event: ToGodot + FromGodot + 'static {
+    /// Name of the signal carrying this event.
+    const NAME: &'static str;
+}
+
+/// Typed publish/subscribe hub built on Godot signals.
+///
+/// The bus owns a plain `Object`, on which every registered [`Event`] type gets a user signal. Rust code subscribes with closures,
+/// GDScript code can connect to the same signals through [`object()`][Self::object].
+///
+/// Subscriptions made with [`subscribe_for()`][Self::subscribe_for] are tied to another object: once that object is freed, the closure
+/// is no longer invoked, and the subscription is disconnected on the next emission.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{Event, EventBus};
+///
+/// struct ScoreChanged(i64);
+///
+/// impl GodotConvert for ScoreChanged {
+///     type Via = i64;
+/// }
+///
+/// impl ToGodot for ScoreChanged {
+///     fn to_godot(&self) -> i64 {
+///         self.0
+///     }
+/// }
+///
+/// impl FromGodot for ScoreChanged {
+///     fn try_from_godot(via: i64) -> Option<Self> {
+///         Some(Self(via))
+///     }
+/// }
+///
+/// impl Event for ScoreChanged {
+///     const NAME: &'static str = "score_changed";
+/// }
+///
+/// fn wire_up(bus: &mut EventBus, hud: &Gd<Node>) {
+///     bus.subscribe_for(hud, |event: ScoreChanged| godot_print!("score: {}", event.0));
+///     bus.emit(ScoreChanged(42));
+/// }
+/// ```
+pub struct EventBus {
+    object: Gd<Object>,
+    subscriptions: Vec<SubscriptionEntry>,
+}
+
+struct SubscriptionEntry {
+    subscription: Subscription,
+    callable: Callable,
+    owner: Option<InstanceId>,
+}
+
+impl EventBus {
+    /// Creates an empty bus, without any registered events.
+    pub fn new() -> Self {
+        Self {
+            object: Object::new_alloc(),
+            subscriptions: Vec::new(),
+        }
+    }
+
+    /// Adds the signal for events of type `E`, if not yet present.
+    ///
+    /// Only needed if GDScript connects to the signal before Rust emits or subscribes to it; [`emit()`][Self::emit] and the
+    /// `subscribe*()` methods register the event themselves.
+    pub fn register<E: Event>(&mut self) {
+        if !self.object.has_signal(E::NAME.into()) {
+            self.object.add_user_signal(E::NAME.into());
+        }
+    }
+
+    /// Sends `event` to all subscribers of `E`, in the order they subscribed.
+    pub fn emit<E: Event>(&mut self, event: E) {
+        self.register::<E>();
+        self.prune();
+
+        self.object
+            .emit_signal(E::NAME.into(), &[event.to_variant()]);
+    }
+
+    /// Invokes `listener` for every event of type `E`, until [`unsubscribe()`][Self::unsubscribe] is called.
+    pub fn subscribe<E, F>(&mut self, listener: F) -> Subscription
+    where
+        E: Event,
+        F: FnMut(E) + Send + Sync + 'static,
+    {
+        self.subscribe_impl(None, listener)
+    }
+
+    /// Invokes `listener` for every event of type `E`, as long as `owner` is alive.
+    pub fn subscribe_for<E, F, T>(&mut self, owner: &Gd<T>, listener: F) -> Subscription
+    where
+        E: Event,
+        F: FnMut(E) + Send + Sync + 'static,
+        T: GodotClass + Inherits<Object>,
+    {
+        self.subscribe_impl(Some(owner.instance_id()), listener)
+    }
+
+    /// Disconnects a subscription. Returns `false` if it was already disconnected.
+    pub fn unsubscribe(&mut self, subscription: Subscription) -> bool {
+        let Some(index) = self
+            .subscriptions
+            .iter()
+            .position(|entry| entry.subscription == subscription)
+        else {
+            return false;
+        };
+
+        let entry = self.subscriptions.remove(index);
+        self.disconnect(&entry);
+        true
+    }
+
+    /// Number of active subscriptions, over all event types.
+    ///
+    /// Subscriptions whose owner has been freed are counted until the next [`emit()`][Self::emit].
+    pub fn subscription_count(&self) -> usize {
+        self.subscriptions.len()
+    }
+
+    /// The object carrying the signals, e.g. to connect from GDScript.
+    pub fn object(&self) -> Gd<Object> {
+        self.object.clone()
+    }
+
+    fn subscribe_impl<E, F>(&mut self, owner: Option<InstanceId>, listener: F) -> Subscription
+    where
+        E: Event,
+        F: FnMut(E) + Send + Sync + 'static,
+    {
+        self.register::<E>();
+
+        let subscription = Subscription::next(E::NAME);
+        let callable = Callable::from_custom(Listener {
+            id: subscription.id,
+            owner,
+            listener,
+            _event: PhantomData::<fn(E)>,
+        });
+
+        self.object.connect(E::NAME.into(), callable.clone());
+
+        self.subscriptions.push(SubscriptionEntry {
+            subscription: subscription.clone(),
+            callable,
+            owner,
+        });
+
+        subscription
+    }
+
+    /// Disconnects all subscriptions whose owner has been freed.
+    fn prune(&mut self) {
+        let (alive, dead): (Vec<_>, Vec<_>) = std::mem::take(&mut self.subscriptions)
+            .into_iter()
+            .partition(|entry| entry.owner.map_or(true, is_alive));
+
+        for entry in &dead {
+            self.disconnect(entry);
+        }
+        self.subscriptions = alive;
+    }
+
+    fn disconnect(&mut self, entry: &SubscriptionEntry) {
+        let signal = StringName::from(entry.subscription.event);
+
+        if self
+            .object
+            .is_connected(signal.clone(), entry.callable.clone())
+        {
+            self.object.disconnect(signal, entry.callable.clone());
+        }
+    }
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for EventBus {
+    fn drop(&mut self) {
+        self.object.clone().free();
+    }
+}
+
+impl fmt::Debug for EventBus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventBus")
+            .field("object", &self.object.instance_id())
+            .field("subscriptions", &self.subscriptions.len())
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Handle to a subscription on an [`EventBus`], returned by its `subscribe*()` methods.
+///
+/// Dropping the handle does not unsubscribe; pass it to [`EventBus::unsubscribe()`] for that.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Subscription {
+    id: u64,
+    event: &'static str,
+}
+
+impl Subscription {
+    fn next(event: &'static str) -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            event,
+        }
+    }
+
+    /// Name of the event this subscription listens to.
+    pub fn event_name(&self) -> &'static str {
+        self.event
+    }
+}
+
+/// Custom callable forwarding signal arguments to a typed closure.
+struct Listener<E, F> {
+    id: u64,
+    owner: Option<InstanceId>,
+    listener: F,
+    _event: PhantomData<fn(E)>,
+}
+
+impl<E, F> RustCallable for Listener<E, F>
+where
+    E: Event,
+    F: FnMut(E) + Send + Sync + 'static,
+{
+    fn invoke(&mut self, args: &[&Variant]) -> Result<Variant, ()> {
+        // Between the owner's death and the next emit, Godot may still call us.
+        if !self.is_valid() {
+            return Ok(Variant::nil());
+        }
+
+        let [arg] = args else {
+            return Err(());
+        };
+
+        let event = E::try_from_variant(arg).map_err(|_| ())?;
+        (self.listener)(event);
+
+        Ok(Variant::nil())
+    }
+
+    fn is_valid(&self) -> bool {
+        self.owner.map_or(true, is_alive)
+    }
+}
+
+impl<E, F> PartialEq for Listener<E, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<E, F> Hash for Listener<E, F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl<E: Event, F> fmt::Display for Listener<E, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "EventBus::{}#{}", E::NAME, self.id)
+    }
+}
+
+fn is_alive(instance_id: InstanceId) -> bool {
+    utilities::is_instance_id_valid(instance_id.to_i64())
+}
@@ -0,0 +1,267 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::builtin::meta::{FromGodot, ToGodot};
+use crate::builtin::{
+    GodotString, PackedStringArray, Variant, VariantArray, VariantConversionError,
+};
+use crate::engine::global::Error;
+use crate::engine::{Expression, Object};
+use crate::obj::{Gd, GodotClass, Inherits};
+
+/// Expression in Godot's expression language, parsed once and evaluated many times with different inputs.
+///
+/// Inputs are declared by name when parsing, and bound from Rust values for each evaluation. Inputs that are not bound are `null`.
+/// The result is converted to the requested type, so formulas stored in resources or mod files can be used like Rust functions.
+///
+/// ```no_run
+/// use godot::engine::ParsedExpression;
+///
+/// let damage = ParsedExpression::parse("base * pow(1.1, level)", &["base", "level"])
+///     .expect("valid formula");
+///
+/// let value: f64 = damage
+///     .bind("base", 12.0)
+///     .bind("level", 3)
+///     .execute()
+///     .expect("formula returns a number");
+/// ```
+///
+/// For expressions that are only known at runtime and evaluated repeatedly, [`ExpressionCache`] avoids parsing them again.
+#[derive(Clone)]
+pub struct ParsedExpression {
+    expression: Gd<Expression>,
+    source: Rc<str>,
+    input_names: Rc<[String]>,
+}
+
+impl ParsedExpression {
+    /// Parses `source`, which may refer to the variables `input_names`.
+    pub fn parse(source: &str, input_names: &[&str]) -> Result<Self, ExpressionError> {
+        let mut expression = Expression::new();
+        let names: PackedStringArray = input_names
+            .iter()
+            .map(|&name| GodotString::from(name))
+            .collect();
+
+        let error = expression.parse_ex(source.into()).input_names(names).done();
+
+        if error != Error::OK {
+            return Err(ExpressionError::Parse {
+                source: source.to_string(),
+                message: expression.get_error_text().to_string(),
+            });
+        }
+
+        Ok(Self {
+            expression,
+            source: source.into(),
+            input_names: input_names.iter().map(|&name| name.to_string()).collect(),
+        })
+    }
+
+    /// The source text, as passed to [`parse()`][Self::parse].
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// The names of the inputs, in declaration order.
+    pub fn input_names(&self) -> &[String] {
+        &self.input_names
+    }
+
+    /// Starts an evaluation, binding `name` to `value`.
+    ///
+    /// # Panics
+    /// If `name` is not one of the input names.
+    pub fn bind(&self, name: &str, value: impl ToGodot) -> Evaluation<'_> {
+        self.evaluation().bind(name, value)
+    }
+
+    /// Starts an evaluation, in which all inputs are `null` until bound.
+    pub fn evaluation(&self) -> Evaluation<'_> {
+        Evaluation {
+            expression: self,
+            inputs: vec![Variant::nil(); self.input_names.len()],
+            base_instance: None,
+            const_calls_only: false,
+        }
+    }
+
+    /// Evaluates the expression with all inputs `null`, e.g. for constant expressions.
+    pub fn execute<T: FromGodot>(&self) -> Result<T, ExpressionError> {
+        self.evaluation().execute()
+    }
+}
+
+impl fmt::Debug for ParsedExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParsedExpression")
+            .field("source", &self.source)
+            .field("input_names", &self.input_names)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Inputs and options for one evaluation of a [`ParsedExpression`].
+#[must_use]
+pub struct Evaluation<'a> {
+    expression: &'a ParsedExpression,
+    inputs: Vec<Variant>,
+    base_instance: Option<Gd<Object>>,
+    const_calls_only: bool,
+}
+
+impl Evaluation<'_> {
+    /// Binds input `name` to `value`.
+    ///
+    /// # Panics
+    /// If `name` is not one of the input names of the expression.
+    pub fn bind(mut self, name: &str, value: impl ToGodot) -> Self {
+        let Some(index) = self.expression.input_names.iter().position(|n| n == name) else {
+            panic!(
+                "expression `{}` has no input `{name}`; inputs are {:?}",
+                self.expression.source, self.expression.input_names
+            );
+        };
+
+        self.inputs[index] = value.to_variant();
+        self
+    }
+
+    /// Object whose methods and properties the expression can use without qualification, like `self` in GDScript.
+    pub fn with_base_instance<T>(mut self, base: &Gd<T>) -> Self
+    where
+        T: GodotClass + Inherits<Object>,
+    {
+        self.base_instance = Some(base.clone().upcast());
+        self
+    }
+
+    /// Only allows calls of `const` methods, so that expressions from untrusted sources cannot modify objects.
+    pub fn const_calls_only(mut self) -> Self {
+        self.const_calls_only = true;
+        self
+    }
+
+    /// Evaluates the expression, converting the result to `T`.
+    pub fn execute<T: FromGodot>(self) -> Result<T, ExpressionError> {
+        let Self {
+            expression,
+            inputs,
+            base_instance,
+            const_calls_only,
+        } = self;
+
+        let mut godot_expression = expression.expression.clone();
+        let inputs: VariantArray = inputs.into_iter().collect();
+
+        let mut call = godot_expression
+            .execute_ex()
+            .inputs(inputs)
+            .show_error(false)
+            .const_calls_only(const_calls_only);
+        if let Some(base) = base_instance {
+            call = call.base_instance(base);
+        }
+        let result = call.done();
+
+        if godot_expression.has_execute_failed() {
+            return Err(ExpressionError::Execution {
+                source: expression.source.to_string(),
+                message: godot_expression.get_error_text().to_string(),
+            });
+        }
+
+        T::try_from_variant(&result).map_err(ExpressionError::InvalidResult)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Parsed expressions by source text and input names, for expressions that are evaluated repeatedly.
+///
+/// Parse errors are not cached; parsing an invalid expression again reports the error again.
+#[derive(Debug, Default)]
+pub struct ExpressionCache {
+    entries: HashMap<(String, Vec<String>), ParsedExpression>,
+}
+
+impl ExpressionCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the expression parsed from `source` with `input_names`, parsing it on first use.
+    pub fn get_or_parse(
+        &mut self,
+        source: &str,
+        input_names: &[&str],
+    ) -> Result<&ParsedExpression, ExpressionError> {
+        let key = (
+            source.to_string(),
+            input_names.iter().map(|&name| name.to_string()).collect(),
+        );
+
+        if !self.entries.contains_key(&key) {
+            let expression = ParsedExpression::parse(source, input_names)?;
+            self.entries.insert(key.clone(), expression);
+        }
+
+        Ok(&self.entries[&key])
+    }
+
+    /// Number of cached expressions.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes all cached expressions.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Error while parsing or evaluating a [`ParsedExpression`].
+#[derive(Debug, Eq, PartialEq)]
+pub enum ExpressionError {
+    /// The source text is not a valid expression.
+    Parse { source: String, message: String },
+
+    /// Evaluation failed, e.g. because of a call to an unknown function or an invalid operand.
+    Execution { source: String, message: String },
+
+    /// The result could not be converted to the requested type.
+    InvalidResult(VariantConversionError),
+}
+
+impl fmt::Display for ExpressionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse { source, message } => {
+                write!(f, "cannot parse expression `{source}`: {message}")
+            }
+            Self::Execution { source, message } => {
+                write!(f, "cannot evaluate expression `{source}`: {message}")
+            }
+            Self::InvalidResult(error) => write!(f, "expression result has wrong type: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for ExpressionError {}
@@ -0,0 +1,191 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{
+    real, Basis, Color, Quaternion, RealConv, Transform2D, Transform3D, Vector2, Vector3, Vector4,
+};
+use crate::engine::Engine;
+
+/// Values that can be blended between two simulation states.
+///
+/// `weight` is usually the interpolation fraction of a fixed timestep: [`FixedTicker::alpha()`][crate::engine::FixedTicker::alpha]
+/// or `Engine.get_physics_interpolation_fraction()`. It is `f64` for all types, so that it can be passed on as-is.
+pub trait Interpolate {
+    /// Returns the value at `weight` between `self` (0.0) and `to` (1.0).
+    fn interpolate(&self, to: &Self, weight: f64) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, to: &Self, weight: f64) -> Self {
+        self + (to - self) * weight as f32
+    }
+}
+
+impl Interpolate for f64 {
+    fn interpolate(&self, to: &Self, weight: f64) -> Self {
+        self + (to - self) * weight
+    }
+}
+
+macro_rules! impl_interpolate {
+    ($( $Type:ty => $method:ident ),* $(,)?) => {
+        $(
+            impl Interpolate for $Type {
+                fn interpolate(&self, to: &Self, weight: f64) -> Self {
+                    self.$method(*to, real::from_f64(weight))
+                }
+            }
+        )*
+    };
+}
+
+impl_interpolate! {
+    Vector2 => lerp,
+    Vector3 => lerp,
+    Vector4 => lerp,
+    Quaternion => slerp,
+    Basis => slerp,
+    Transform2D => interpolate_with,
+    Transform3D => interpolate_with,
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, to: &Self, weight: f64) -> Self {
+        self.lerp(*to, weight)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Previous and current state of a fixed-timestep simulation, for smooth rendering in between.
+///
+/// A simulation stepping at a fixed rate moves objects in discrete jumps, which stutter when the display refresh rate differs from
+/// the tick rate. Rendering the state interpolated between the last two ticks hides this, at the cost of one tick of latency.
+///
+/// Call [`push()`][Self::push] once per tick, and [`at()`][Self::at] when rendering. For objects that jump instead of moving
+/// (spawning, teleporting), use [`reset()`][Self::reset], so they are not visibly dragged to the new place.
+///
+/// # Example
+/// A node simulated in `physics_process()`, which renders its interpolated transform every frame:
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::Interpolated;
+///
+/// #[derive(GodotClass)]
+/// #[class(base=Node2D)]
+/// struct Ship {
+///     velocity: Vector2,
+///     transform: Interpolated<Transform2D>,
+///     #[base]
+///     base: Base<Node2D>,
+/// }
+///
+/// #[godot_api]
+/// impl Node2DVirtual for Ship {
+///     fn init(base: Base<Node2D>) -> Self {
+///         Self {
+///             velocity: Vector2::new(100.0, 0.0),
+///             transform: Interpolated::new(Transform2D::IDENTITY),
+///             base,
+///         }
+///     }
+///
+///     fn physics_process(&mut self, delta: real) {
+///         let mut next = *self.transform.current();
+///         next.origin += self.velocity * delta;
+///         self.transform.push(next);
+///     }
+///
+///     fn process(&mut self, _delta: real) {
+///         let rendered = self.transform.at_physics_fraction();
+///         self.base.set_transform(rendered);
+///     }
+/// }
+/// ```
+///
+/// With a custom tick rate, drive the simulation with a [`FixedTicker`][crate::engine::FixedTicker] in `process()` instead, and
+/// render `transform.at(ticker.alpha())`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Interpolated<T> {
+    previous: T,
+    current: T,
+}
+
+impl<T: Clone> Interpolated<T> {
+    /// Starts at `state`, without any motion to interpolate.
+    pub fn new(state: T) -> Self {
+        Self {
+            previous: state.clone(),
+            current: state,
+        }
+    }
+
+    /// Jumps to `state`, discarding the previous state so that nothing is interpolated.
+    pub fn reset(&mut self, state: T) {
+        self.previous = state.clone();
+        self.current = state;
+    }
+}
+
+impl<T> Interpolated<T> {
+    /// Records `state` as the result of a new tick; the former current state becomes the previous one.
+    pub fn push(&mut self, state: T) {
+        self.previous = std::mem::replace(&mut self.current, state);
+    }
+
+    /// The state of the latest tick.
+    pub fn current(&self) -> &T {
+        &self.current
+    }
+
+    /// The state of the tick before the latest one.
+    pub fn previous(&self) -> &T {
+        &self.previous
+    }
+}
+
+impl<T: Interpolate> Interpolated<T> {
+    /// The state at `alpha` between the previous (0.0) and the current (1.0) tick. `alpha` is clamped to this range.
+    pub fn at(&self, alpha: f64) -> T {
+        self.previous
+            .interpolate(&self.current, alpha.clamp(0.0, 1.0))
+    }
+
+    /// The state at the current position between two physics ticks, for simulations stepped in `physics_process()`.
+    ///
+    /// Uses `Engine.get_physics_interpolation_fraction()`, which is only meaningful when called from `process()`.
+    pub fn at_physics_fraction(&self) -> T {
+        self.at(Engine::singleton().get_physics_interpolation_fraction())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolated_push_and_at() {
+        let mut position = Interpolated::new(0.0_f64);
+        position.push(10.0);
+        position.push(20.0);
+
+        assert_eq!(*position.previous(), 10.0);
+        assert_eq!(*position.current(), 20.0);
+        assert_eq!(position.at(0.25), 12.5);
+        assert_eq!(position.at(2.0), 20.0, "alpha is clamped");
+    }
+
+    #[test]
+    fn interpolated_reset() {
+        let mut position = Interpolated::new(Vector2::ZERO);
+        position.push(Vector2::new(4.0, 2.0));
+        position.reset(Vector2::new(-8.0, 0.0));
+
+        assert_eq!(position.at(0.5), Vector2::new(-8.0, 0.0));
+    }
+}
@@ -0,0 +1,253 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! `std::io` and `std::fs`-style access to Godot's virtual file system.
+//!
+//! `Gd<FileAccess>` implements [`Read`], [`Write`] and [`Seek`], so Rust parsers and serializers can work on `res://` and `user://`
+//! files (including files inside exported PCK archives) like on any other stream. Directories are traversed with [`read_dir()`] and
+//! [`walk_dir()`].
+//!
+//! Note that with `Read`/`Write`/`Seek` in scope, `file.flush()` and `file.seek(...)` refer to the trait methods rather than
+//! `FileAccess.flush()` and `FileAccess.seek()`.
+//!
+//! # Example
+//! ```no_run
+//! use std::io::{BufRead, BufReader};
+//! use godot::engine::file_access::ModeFlags;
+//! use godot::engine::fs;
+//!
+//! fn count_lines(path: &str) -> std::io::Result<usize> {
+//!     let file = fs::open(path, ModeFlags::READ)?;
+//!     Ok(BufReader::new(file).lines().count())
+//! }
+//! ```
+
+use std::io::{self, Read, Seek, SeekFrom, Write};
+
+use crate::builtin::{GodotString, PackedByteArray};
+use crate::engine::file_access::ModeFlags;
+use crate::engine::global::Error;
+use crate::engine::{DirAccess, FileAccess};
+use crate::obj::Gd;
+
+/// Opens the file at `path` in `mode`, reporting failure as [`io::Error`].
+pub fn open(path: impl Into<GodotString>, mode: ModeFlags) -> io::Result<Gd<FileAccess>> {
+    FileAccess::open(path.into(), mode).ok_or_else(|| io_error(FileAccess::get_open_error()))
+}
+
+/// Converts a Godot error code to an `io::Error` of the closest kind.
+pub fn io_error(error: Error) -> io::Error {
+    let kind = match error {
+        Error::ERR_FILE_NOT_FOUND | Error::ERR_FILE_BAD_PATH => io::ErrorKind::NotFound,
+        Error::ERR_FILE_NO_PERMISSION | Error::ERR_UNAUTHORIZED => io::ErrorKind::PermissionDenied,
+        Error::ERR_FILE_ALREADY_IN_USE | Error::ERR_BUSY => io::ErrorKind::WouldBlock,
+        Error::ERR_ALREADY_EXISTS => io::ErrorKind::AlreadyExists,
+        Error::ERR_FILE_EOF => io::ErrorKind::UnexpectedEof,
+        Error::ERR_INVALID_PARAMETER => io::ErrorKind::InvalidInput,
+        Error::ERR_FILE_CORRUPT | Error::ERR_INVALID_DATA => io::ErrorKind::InvalidData,
+        _ => io::ErrorKind::Other,
+    };
+
+    io::Error::new(kind, format!("Godot error {error:?}"))
+}
+
+/// Fails with the file's last error, if any. End of file is not an error for streams.
+fn check(file: &FileAccess) -> io::Result<()> {
+    match file.get_error() {
+        Error::OK | Error::ERR_FILE_EOF => Ok(()),
+        error => Err(io_error(error)),
+    }
+}
+
+impl Read for Gd<FileAccess> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let remaining = self.get_length().saturating_sub(self.get_position());
+        let len = (buf.len() as u64).min(remaining) as usize;
+        if len == 0 {
+            return Ok(0);
+        }
+
+        let bytes = self.get_buffer(len as i64);
+        check(self)?;
+
+        let bytes = bytes.as_slice();
+        buf[..bytes.len()].copy_from_slice(bytes);
+        Ok(bytes.len())
+    }
+}
+
+impl Write for Gd<FileAccess> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.store_buffer(PackedByteArray::from(buf));
+        check(self)?;
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        FileAccess::flush(self);
+        check(self)
+    }
+}
+
+impl Seek for Gd<FileAccess> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let target = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.get_length().checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.get_position().checked_add_signed(offset),
+        };
+
+        let target = target.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "seek to a negative or overflowing position",
+            )
+        })?;
+
+        FileAccess::seek(self, target);
+        check(self)?;
+
+        Ok(self.get_position())
+    }
+
+    fn stream_position(&mut self) -> io::Result<u64> {
+        Ok(self.get_position())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Entry of a directory listing, returned by [`read_dir()`] and [`walk_dir()`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct DirEntry {
+    path: String,
+    name_start: usize,
+    is_dir: bool,
+}
+
+impl DirEntry {
+    fn new(dir: &str, name: &str, is_dir: bool) -> Self {
+        let mut path = String::with_capacity(dir.len() + name.len() + 1);
+        path.push_str(dir);
+        if !path.ends_with('/') {
+            path.push('/');
+        }
+
+        let name_start = path.len();
+        path.push_str(name);
+
+        Self {
+            path,
+            name_start,
+            is_dir,
+        }
+    }
+
+    /// The full path of the entry, such as `res://levels/intro.tscn`.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The file or directory name, without the parent path.
+    pub fn file_name(&self) -> &str {
+        &self.path[self.name_start..]
+    }
+
+    /// Whether the entry is a directory.
+    pub fn is_dir(&self) -> bool {
+        self.is_dir
+    }
+}
+
+/// Iterator over the entries of one directory, created by [`read_dir()`].
+///
+/// Directories come first, then files, each group in the order reported by Godot (alphabetical).
+#[derive(Clone, Debug)]
+pub struct ReadDir {
+    entries: std::vec::IntoIter<DirEntry>,
+}
+
+impl Iterator for ReadDir {
+    type Item = DirEntry;
+
+    fn next(&mut self) -> Option<DirEntry> {
+        self.entries.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl ExactSizeIterator for ReadDir {}
+
+/// Lists the directories and files directly inside `path`, excluding `.` and `..`.
+pub fn read_dir(path: impl Into<GodotString>) -> io::Result<ReadDir> {
+    let path = path.into();
+    let mut dir =
+        DirAccess::open(path.clone()).ok_or_else(|| io_error(DirAccess::get_open_error()))?;
+
+    let path = path.to_string();
+    let dirs = dir.get_directories();
+    let files = dir.get_files();
+
+    let entries: Vec<DirEntry> = dirs
+        .as_slice()
+        .iter()
+        .map(|name| DirEntry::new(&path, &name.to_string(), true))
+        .chain(
+            files
+                .as_slice()
+                .iter()
+                .map(|name| DirEntry::new(&path, &name.to_string(), false)),
+        )
+        .collect();
+
+    Ok(ReadDir {
+        entries: entries.into_iter(),
+    })
+}
+
+/// Recursive iterator over a directory tree, created by [`walk_dir()`].
+///
+/// Each directory is yielded before its contents. Subdirectories are listed lazily, when the iteration reaches them; if one cannot be
+/// opened, its error is yielded in place of its contents and the iteration continues.
+#[derive(Debug)]
+pub struct WalkDir {
+    stack: Vec<ReadDir>,
+}
+
+impl Iterator for WalkDir {
+    type Item = io::Result<DirEntry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let current = self.stack.last_mut()?;
+
+            let Some(entry) = current.next() else {
+                self.stack.pop();
+                continue;
+            };
+
+            if entry.is_dir() {
+                match read_dir(entry.path()) {
+                    Ok(children) => self.stack.push(children),
+                    Err(error) => return Some(Err(error)),
+                }
+            }
+
+            return Some(Ok(entry));
+        }
+    }
+}
+
+/// Lists all directories and files below `path`, recursively.
+pub fn walk_dir(path: impl Into<GodotString>) -> io::Result<WalkDir> {
+    Ok(WalkDir {
+        stack: vec![read_dir(path)?],
+    })
+}
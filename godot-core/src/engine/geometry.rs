@@ -0,0 +1,334 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{
+    Array, PackedInt32Array, PackedVector2Array, PackedVector3Array, Plane, Vector2, Vector3,
+};
+use crate::engine::geometry_2d::PolyJoinType;
+use crate::engine::{Geometry2D, Geometry3D};
+use crate::obj::Gd;
+
+/// Triangle of a triangulation, as indices into the triangulated points.
+pub type Triangle = [usize; 3];
+
+/// Extension methods for the [`Geometry2D`] singleton, taking slices and returning `Vec`s and `Option`s.
+///
+/// Polygons are given as their vertices in order, without repeating the first one at the end. Boolean operations may produce
+/// several polygons; holes are returned as clockwise polygons, see `Geometry2D::is_polygon_clockwise()`.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{Geometry2D, Geometry2DExt};
+///
+/// fn carve(wall: &[Vector2], explosion: &[Vector2]) -> Vec<Vec<Vector2>> {
+///     Geometry2D::singleton().clip(wall, explosion)
+/// }
+/// ```
+pub trait Geometry2DExt {
+    /// Union of the polygons `a` and `b`.
+    fn merge(&mut self, a: &[Vector2], b: &[Vector2]) -> Vec<Vec<Vector2>>;
+
+    /// The parts of `a` outside of `b`.
+    fn clip(&mut self, a: &[Vector2], b: &[Vector2]) -> Vec<Vec<Vector2>>;
+
+    /// The parts of `a` inside of `b`.
+    fn intersect(&mut self, a: &[Vector2], b: &[Vector2]) -> Vec<Vec<Vector2>>;
+
+    /// The parts of `a` and `b` not covered by the other polygon.
+    fn exclude(&mut self, a: &[Vector2], b: &[Vector2]) -> Vec<Vec<Vector2>>;
+
+    /// Grows the polygon by `delta` (shrinks it if negative), with corners shaped by `join`.
+    fn offset(&mut self, polygon: &[Vector2], delta: f32, join: PolyJoinType) -> Vec<Vec<Vector2>>;
+
+    /// Splits the polygon into convex polygons.
+    fn convex_decomposition(&mut self, polygon: &[Vector2]) -> Vec<Vec<Vector2>>;
+
+    /// Triangulates the polygon by ear clipping, or `None` if it is degenerate or self-intersecting.
+    fn triangulate(&mut self, polygon: &[Vector2]) -> Option<Vec<Triangle>>;
+
+    /// Delaunay triangulation of the points, or `None` if they are fewer than 3 or collinear.
+    fn delaunay(&mut self, points: &[Vector2]) -> Option<Vec<Triangle>>;
+
+    /// Convex hull of the points, counterclockwise.
+    fn hull(&mut self, points: &[Vector2]) -> Vec<Vector2>;
+
+    /// Whether `point` lies inside the polygon.
+    fn contains_point(&mut self, polygon: &[Vector2], point: Vector2) -> bool;
+
+    /// Intersection point of the segments `a` and `b`, or `None` if they do not intersect.
+    fn segment_intersection(
+        &mut self,
+        a: (Vector2, Vector2),
+        b: (Vector2, Vector2),
+    ) -> Option<Vector2>;
+
+    /// Intersection point of the infinite lines through `from_a` and `from_b`, or `None` if they are parallel.
+    fn line_intersection(
+        &mut self,
+        from_a: Vector2,
+        dir_a: Vector2,
+        from_b: Vector2,
+        dir_b: Vector2,
+    ) -> Option<Vector2>;
+
+    /// Where the segment first enters the circle, as fraction between 0 (`from`) and 1 (`to`); `None` if it misses.
+    fn segment_circle_intersection(
+        &mut self,
+        from: Vector2,
+        to: Vector2,
+        center: Vector2,
+        radius: f32,
+    ) -> Option<f32>;
+}
+
+impl Geometry2DExt for Gd<Geometry2D> {
+    fn merge(&mut self, a: &[Vector2], b: &[Vector2]) -> Vec<Vec<Vector2>> {
+        to_polygons(self.merge_polygons(a.into(), b.into()))
+    }
+
+    fn clip(&mut self, a: &[Vector2], b: &[Vector2]) -> Vec<Vec<Vector2>> {
+        to_polygons(self.clip_polygons(a.into(), b.into()))
+    }
+
+    fn intersect(&mut self, a: &[Vector2], b: &[Vector2]) -> Vec<Vec<Vector2>> {
+        to_polygons(self.intersect_polygons(a.into(), b.into()))
+    }
+
+    fn exclude(&mut self, a: &[Vector2], b: &[Vector2]) -> Vec<Vec<Vector2>> {
+        to_polygons(self.exclude_polygons(a.into(), b.into()))
+    }
+
+    fn offset(&mut self, polygon: &[Vector2], delta: f32, join: PolyJoinType) -> Vec<Vec<Vector2>> {
+        let polygons = self
+            .offset_polygon_ex(polygon.into(), delta)
+            .join_type(join)
+            .done();
+
+        to_polygons(polygons)
+    }
+
+    fn convex_decomposition(&mut self, polygon: &[Vector2]) -> Vec<Vec<Vector2>> {
+        to_polygons(self.decompose_polygon_in_convex(polygon.into()))
+    }
+
+    fn triangulate(&mut self, polygon: &[Vector2]) -> Option<Vec<Triangle>> {
+        to_triangles(self.triangulate_polygon(polygon.into()))
+    }
+
+    fn delaunay(&mut self, points: &[Vector2]) -> Option<Vec<Triangle>> {
+        to_triangles(self.triangulate_delaunay(points.into()))
+    }
+
+    fn hull(&mut self, points: &[Vector2]) -> Vec<Vector2> {
+        let mut hull = self.convex_hull(points.into()).to_vec();
+
+        // Godot closes the hull by repeating the first point.
+        if hull.len() > 1 && hull.first() == hull.last() {
+            hull.pop();
+        }
+        hull
+    }
+
+    fn contains_point(&mut self, polygon: &[Vector2], point: Vector2) -> bool {
+        self.is_point_in_polygon(point, polygon.into())
+    }
+
+    fn segment_intersection(
+        &mut self,
+        a: (Vector2, Vector2),
+        b: (Vector2, Vector2),
+    ) -> Option<Vector2> {
+        self.segment_intersects_segment(a.0, a.1, b.0, b.1)
+            .try_to::<Vector2>()
+            .ok()
+    }
+
+    fn line_intersection(
+        &mut self,
+        from_a: Vector2,
+        dir_a: Vector2,
+        from_b: Vector2,
+        dir_b: Vector2,
+    ) -> Option<Vector2> {
+        self.line_intersects_line(from_a, dir_a, from_b, dir_b)
+            .try_to::<Vector2>()
+            .ok()
+    }
+
+    fn segment_circle_intersection(
+        &mut self,
+        from: Vector2,
+        to: Vector2,
+        center: Vector2,
+        radius: f32,
+    ) -> Option<f32> {
+        let fraction = self.segment_intersects_circle(from, to, center, radius);
+
+        // Godot returns -1 for a miss.
+        (fraction >= 0.0).then_some(fraction)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Point where a segment enters a surface, with the surface normal at that point.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SurfaceHit {
+    pub point: Vector3,
+    pub normal: Vector3,
+}
+
+/// Extension methods for the [`Geometry3D`] singleton, returning `Option`s for intersection tests that can miss.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{Geometry3D, Geometry3DExt};
+///
+/// fn hits_shield(from: Vector3, to: Vector3, shield_center: Vector3) -> Option<Vector3> {
+///     let hit = Geometry3D::singleton().segment_sphere_intersection(from, to, shield_center, 2.0)?;
+///     Some(hit.normal)
+/// }
+/// ```
+pub trait Geometry3DExt {
+    /// Intersection point of the segment with the triangle, or `None` if it misses.
+    fn segment_triangle_intersection(
+        &mut self,
+        from: Vector3,
+        to: Vector3,
+        triangle: [Vector3; 3],
+    ) -> Option<Vector3>;
+
+    /// Intersection point of the ray from `from` towards `dir` with the triangle, or `None` if it misses.
+    fn ray_triangle_intersection(
+        &mut self,
+        from: Vector3,
+        dir: Vector3,
+        triangle: [Vector3; 3],
+    ) -> Option<Vector3>;
+
+    /// Where the segment enters the sphere, or `None` if it misses.
+    fn segment_sphere_intersection(
+        &mut self,
+        from: Vector3,
+        to: Vector3,
+        center: Vector3,
+        radius: f32,
+    ) -> Option<SurfaceHit>;
+
+    /// Where the segment enters the cylinder centered at the origin along the Z axis, or `None` if it misses.
+    fn segment_cylinder_intersection(
+        &mut self,
+        from: Vector3,
+        to: Vector3,
+        height: f32,
+        radius: f32,
+    ) -> Option<SurfaceHit>;
+
+    /// The closest points between the segments `a` and `b`: the one on `a` first, then the one on `b`.
+    fn closest_points_between(
+        &mut self,
+        a: (Vector3, Vector3),
+        b: (Vector3, Vector3),
+    ) -> (Vector3, Vector3);
+
+    /// The part of the polygon in front of `plane` (on the side its normal points to).
+    fn clip_by_plane(&mut self, polygon: &[Vector3], plane: Plane) -> Vec<Vector3>;
+}
+
+impl Geometry3DExt for Gd<Geometry3D> {
+    fn segment_triangle_intersection(
+        &mut self,
+        from: Vector3,
+        to: Vector3,
+        triangle: [Vector3; 3],
+    ) -> Option<Vector3> {
+        let [a, b, c] = triangle;
+
+        self.segment_intersects_triangle(from, to, a, b, c)
+            .try_to::<Vector3>()
+            .ok()
+    }
+
+    fn ray_triangle_intersection(
+        &mut self,
+        from: Vector3,
+        dir: Vector3,
+        triangle: [Vector3; 3],
+    ) -> Option<Vector3> {
+        let [a, b, c] = triangle;
+
+        self.ray_intersects_triangle(from, dir, a, b, c)
+            .try_to::<Vector3>()
+            .ok()
+    }
+
+    fn segment_sphere_intersection(
+        &mut self,
+        from: Vector3,
+        to: Vector3,
+        center: Vector3,
+        radius: f32,
+    ) -> Option<SurfaceHit> {
+        to_surface_hit(self.segment_intersects_sphere(from, to, center, radius))
+    }
+
+    fn segment_cylinder_intersection(
+        &mut self,
+        from: Vector3,
+        to: Vector3,
+        height: f32,
+        radius: f32,
+    ) -> Option<SurfaceHit> {
+        to_surface_hit(self.segment_intersects_cylinder(from, to, height, radius))
+    }
+
+    fn closest_points_between(
+        &mut self,
+        a: (Vector3, Vector3),
+        b: (Vector3, Vector3),
+    ) -> (Vector3, Vector3) {
+        let points = self.get_closest_points_between_segments(a.0, a.1, b.0, b.1);
+        let points = points.as_slice();
+
+        (points[0], points[1])
+    }
+
+    fn clip_by_plane(&mut self, polygon: &[Vector3], plane: Plane) -> Vec<Vector3> {
+        self.clip_polygon(polygon.into(), plane).to_vec()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+fn to_polygons(polygons: Array<PackedVector2Array>) -> Vec<Vec<Vector2>> {
+    polygons
+        .iter_shared()
+        .map(|polygon| polygon.to_vec())
+        .collect()
+}
+
+/// Groups the flat index list into triangles; `None` for an empty list, which Godot returns on failure.
+fn to_triangles(indices: PackedInt32Array) -> Option<Vec<Triangle>> {
+    let triangles: Vec<Triangle> = indices
+        .as_slice()
+        .chunks_exact(3)
+        .map(|tri| [tri[0] as usize, tri[1] as usize, tri[2] as usize])
+        .collect();
+
+    (!triangles.is_empty()).then_some(triangles)
+}
+
+/// Godot returns `[point, normal]` for a hit, and an empty array for a miss.
+fn to_surface_hit(result: PackedVector3Array) -> Option<SurfaceHit> {
+    match result.as_slice() {
+        [point, normal, ..] => Some(SurfaceHit {
+            point: *point,
+            normal: *normal,
+        }),
+        _ => None,
+    }
+}
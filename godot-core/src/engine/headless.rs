@@ -0,0 +1,160 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::real;
+use crate::engine::{DisplayServer, Os};
+
+/// Whether Godot runs without a display, e.g. started with `--headless` or on a dedicated server.
+///
+/// In headless mode, rendering and audio are served by dummy implementations: their singletons exist and can be called, but do not
+/// produce any output.
+pub fn is_headless() -> bool {
+    DisplayServer::try_singleton()
+        .map_or(true, |display| display.get_name().to_string() == "headless")
+}
+
+/// Whether the running binary was exported as a dedicated server (feature tag `dedicated_server`).
+///
+/// Unlike [`is_headless()`], this is `false` when a regular build is merely started with `--headless`.
+pub fn is_dedicated_server() -> bool {
+    Os::singleton().has_feature("dedicated_server".into())
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Converts variable frame times into a fixed number of simulation ticks.
+///
+/// Deterministic simulations and authoritative servers usually advance at a fixed rate, independent of how often the engine iterates.
+/// Feed the `delta` of each frame into [`advance()`][Self::advance], then run as many ticks as it returns. If the simulation falls
+/// behind, at most [`max_ticks_per_frame`][Self::with_max_ticks_per_frame] ticks are run per frame and the remaining backlog is
+/// dropped, so a single hiccup does not cause a spiral of ever-longer frames.
+///
+/// To render smoothly between ticks, keep the simulated state in an [`Interpolated`][crate::engine::Interpolated] and draw it at
+/// [`alpha()`][Self::alpha].
+///
+/// # Example
+/// A headless server that runs its game logic at 30 ticks per second, using a custom main loop
+/// (see [`set_main_loop_type()`][crate::engine::set_main_loop_type]):
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{FixedTicker, SceneTreeVirtual};
+///
+/// #[derive(GodotClass)]
+/// #[class(base=SceneTree)]
+/// struct ServerTree {
+///     ticker: FixedTicker,
+/// }
+///
+/// #[godot_api]
+/// impl SceneTreeVirtual for ServerTree {
+///     fn init(_base: Base<SceneTree>) -> Self {
+///         Self { ticker: FixedTicker::new(30.0) }
+///     }
+///
+///     fn process(&mut self, delta: real) -> bool {
+///         for _ in 0..self.ticker.advance(delta) {
+///             // Step the simulation by `self.ticker.tick_duration()` seconds.
+///         }
+///         false
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct FixedTicker {
+    tick_duration: f64,
+    max_ticks_per_frame: u32,
+    accumulator: f64,
+    tick_count: u64,
+}
+
+impl FixedTicker {
+    /// Creates a ticker running at `ticks_per_second`, with at most 8 ticks per frame.
+    ///
+    /// # Panics
+    /// If `ticks_per_second` is not a positive, finite number.
+    pub fn new(ticks_per_second: f64) -> Self {
+        assert!(
+            ticks_per_second.is_finite() && ticks_per_second > 0.0,
+            "ticks per second must be positive, got {ticks_per_second}"
+        );
+
+        Self {
+            tick_duration: 1.0 / ticks_per_second,
+            max_ticks_per_frame: 8,
+            accumulator: 0.0,
+            tick_count: 0,
+        }
+    }
+
+    /// Limits how many ticks a single call to [`advance()`][Self::advance] can return.
+    pub fn with_max_ticks_per_frame(mut self, max_ticks_per_frame: u32) -> Self {
+        self.max_ticks_per_frame = max_ticks_per_frame;
+        self
+    }
+
+    /// Adds `delta` seconds of elapsed time, and returns how many ticks are now due.
+    pub fn advance(&mut self, delta: real) -> u32 {
+        self.accumulator += f64::from(delta).max(0.0);
+
+        let mut ticks = 0;
+        while self.accumulator >= self.tick_duration && ticks < self.max_ticks_per_frame {
+            self.accumulator -= self.tick_duration;
+            ticks += 1;
+        }
+
+        // Drop the backlog that could not be caught up with.
+        if ticks == self.max_ticks_per_frame {
+            self.accumulator = self.accumulator.min(self.tick_duration);
+        }
+
+        self.tick_count += u64::from(ticks);
+        ticks
+    }
+
+    /// Duration of one tick, in seconds.
+    pub fn tick_duration(&self) -> f64 {
+        self.tick_duration
+    }
+
+    /// Total number of ticks returned by [`advance()`][Self::advance] so far.
+    pub fn tick_count(&self) -> u64 {
+        self.tick_count
+    }
+
+    /// Fraction of the next tick that has already elapsed, between 0 and 1; useful to interpolate between two simulation states.
+    pub fn alpha(&self) -> f64 {
+        (self.accumulator / self.tick_duration).min(1.0)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ticker_accumulates() {
+        let mut ticker = FixedTicker::new(10.0);
+
+        assert_eq!(ticker.advance(0.05), 0);
+        assert_eq!(ticker.advance(0.06), 1);
+        assert_eq!(ticker.advance(0.25), 2);
+        assert_eq!(ticker.tick_count(), 3);
+        // Deltas are `f32` in single-precision builds.
+        assert!((ticker.alpha() - 0.6).abs() < 1e-6);
+    }
+
+    #[test]
+    fn ticker_drops_backlog() {
+        let mut ticker = FixedTicker::new(10.0).with_max_ticks_per_frame(3);
+
+        assert_eq!(ticker.advance(10.0), 3);
+        assert_eq!(ticker.advance(0.0), 1);
+        assert_eq!(ticker.advance(0.0), 0);
+        assert_eq!(ticker.tick_count(), 4);
+    }
+}
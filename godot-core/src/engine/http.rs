@@ -0,0 +1,280 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Future-based HTTP requests on top of [`HttpRequest`].
+//!
+//! A request is described with a [`RequestBuilder`] and sent with [`send()`][RequestBuilder::send], which returns a future resolving
+//! to the [`Response`]. Godot performs the transfer on its own; the future only needs to be polled by whatever executor drives your
+//! async code. Internally, each request creates a temporary `HTTPRequest` node, which is freed once the response has arrived.
+//!
+//! # Example
+//! ```no_run
+//! use godot::prelude::*;
+//! use godot::engine::http;
+//!
+//! async fn fetch_scores() -> Option<Variant> {
+//!     let response = http::get("https://example.com/scores.json")
+//!         .header("Accept", "application/json")
+//!         .send()
+//!         .await
+//!         .ok()?;
+//!
+//!     response.json()
+//! }
+//! ```
+
+use std::fmt;
+
+use crate::builtin::{GodotString, PackedByteArray, PackedStringArray, StringName, Variant};
+use crate::engine::global::Error;
+use crate::engine::http_request::Result as RequestResult;
+use crate::engine::{Engine, HttpRequest, Json, Node, SceneTree, SignalFuture};
+use crate::obj::{EngineEnum, Gd, Inherits};
+
+pub use crate::engine::http_client::Method;
+
+/// Starts a `GET` request to `url`.
+pub fn get(url: impl Into<GodotString>) -> RequestBuilder {
+    request(Method::METHOD_GET, url)
+}
+
+/// Starts a `POST` request to `url`; set its body with [`RequestBuilder::body()`] or [`RequestBuilder::json()`].
+pub fn post(url: impl Into<GodotString>) -> RequestBuilder {
+    request(Method::METHOD_POST, url)
+}
+
+/// Starts a request to `url` with an arbitrary method.
+pub fn request(method: Method, url: impl Into<GodotString>) -> RequestBuilder {
+    RequestBuilder {
+        method,
+        url: url.into(),
+        headers: PackedStringArray::new(),
+        body: PackedByteArray::new(),
+        timeout: None,
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// HTTP request under construction, created by [`get()`], [`post()`] or [`request()`].
+#[must_use = "requests are only performed once sent"]
+pub struct RequestBuilder {
+    method: Method,
+    url: GodotString,
+    headers: PackedStringArray,
+    body: PackedByteArray,
+    timeout: Option<f64>,
+}
+
+impl RequestBuilder {
+    /// Adds the header `name: value`.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push(format!("{name}: {value}").into());
+        self
+    }
+
+    /// Sets the raw request body.
+    pub fn body(mut self, body: PackedByteArray) -> Self {
+        self.body = body;
+        self
+    }
+
+    /// Sets a UTF-8 text body.
+    pub fn text(self, text: &str) -> Self {
+        self.body(PackedByteArray::from(text.as_bytes()))
+    }
+
+    /// Sets `data`, serialized as JSON, as body, and adds the matching `Content-Type` header.
+    pub fn json(self, data: &Variant) -> Self {
+        let text = Json::stringify(data.clone()).to_string();
+        self.header("Content-Type", "application/json").text(&text)
+    }
+
+    /// Fails the request with [`RequestResult::RESULT_TIMEOUT`] after `seconds`; by default, requests never time out.
+    pub fn timeout(mut self, seconds: f64) -> Self {
+        self.timeout = Some(seconds);
+        self
+    }
+
+    /// ⚠️ Sends the request, using the root of the scene tree to host the `HTTPRequest` node.
+    ///
+    /// # Panics
+    /// If the main loop is not a `SceneTree`.
+    pub fn send(self) -> ResponseFuture {
+        let root = Engine::singleton()
+            .get_main_loop()
+            .and_then(|main_loop| main_loop.try_cast::<SceneTree>())
+            .and_then(|tree| tree.get_root())
+            .expect("http::send() requires a SceneTree main loop");
+
+        self.send_from(&root)
+    }
+
+    /// Sends the request, adding the `HTTPRequest` node as a child of `parent`.
+    ///
+    /// The request is aborted if `parent` leaves the tree before it completes; the future then never resolves.
+    pub fn send_from<N: Inherits<Node>>(self, parent: &Gd<N>) -> ResponseFuture {
+        let mut node = HttpRequest::new_alloc();
+        parent
+            .clone()
+            .upcast::<Node>()
+            .add_child(node.clone().upcast());
+
+        self.start(&mut node, true)
+    }
+
+    /// Sends the request with an existing `HTTPRequest` node, which must be inside the tree and is kept afterwards.
+    ///
+    /// This allows reusing a node configured in the editor (proxy, TLS options, download file). Its timeout is only replaced
+    /// if [`timeout()`][Self::timeout] was set. A node can only perform one request at a time; while it is busy, the future
+    /// resolves to [`HttpError::Request`] with `ERR_BUSY`.
+    pub fn send_with(self, node: &mut Gd<HttpRequest>) -> ResponseFuture {
+        self.start(node, false)
+    }
+
+    fn start(self, node: &mut Gd<HttpRequest>, owns_node: bool) -> ResponseFuture {
+        if let Some(timeout) = self.timeout {
+            node.set_timeout(timeout);
+        }
+
+        // Captures the node's instance ID instead of the node, as callables must be `Send`.
+        let node_to_free = owns_node.then(|| node.instance_id());
+        let signal = StringName::from("request_completed");
+        let (future, callable) =
+            ResponseFuture::connect_mapped(node.clone().upcast(), signal.clone(), move |args| {
+                let outcome = parse_completion(args)?;
+                if let Some(mut node) = node_to_free.and_then(Gd::<Node>::try_from_instance_id) {
+                    node.queue_free();
+                }
+
+                Some(outcome)
+            });
+
+        let error = node
+            .request_raw_ex(self.url)
+            .custom_headers(self.headers)
+            .method(self.method)
+            .request_data_raw(self.body)
+            .done();
+
+        if error != Error::OK {
+            // No completion follows; a kept node must not report its next request to this future.
+            node.disconnect(signal, callable);
+            if owns_node {
+                node.queue_free();
+            }
+            future.resolve(Err(HttpError::Request(error)));
+        }
+
+        future
+    }
+}
+
+fn parse_completion(args: &[&Variant]) -> Option<Result<Response, HttpError>> {
+    let [result, status, headers, body] = args else {
+        return None;
+    };
+
+    let result = RequestResult::try_from_ord(result.try_to::<i32>().ok()?)?;
+    if result != RequestResult::RESULT_SUCCESS {
+        return Some(Err(HttpError::Failed(result)));
+    }
+
+    let headers = headers.try_to::<PackedStringArray>().ok()?;
+    let body = body.try_to::<PackedByteArray>().ok()?;
+
+    Some(Ok(Response {
+        status: status.try_to().ok()?,
+        headers: headers
+            .as_slice()
+            .iter()
+            .map(GodotString::to_string)
+            .collect(),
+        body: body.to_vec(),
+    }))
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Future returned by [`RequestBuilder::send()`] and its variants, resolving once the whole response has been received.
+pub type ResponseFuture = SignalFuture<Result<Response, HttpError>>;
+
+/// Response to an HTTP request.
+///
+/// Headers and body are copied into Rust types, so the response can be sent across threads.
+///
+/// Any status code counts as a response, including 4xx and 5xx errors; check [`is_success()`][Self::is_success] as needed.
+#[derive(Clone, Debug)]
+pub struct Response {
+    /// The HTTP status code, such as 200 or 404.
+    pub status: i64,
+
+    /// The response headers, each in the form `Name: value`.
+    pub headers: Vec<String>,
+
+    /// The response body, decompressed if the server used gzip or deflate.
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    /// Whether the status code is in the 2xx range.
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// The value of the first header called `name` (case-insensitive), if present.
+    pub fn header(&self, name: &str) -> Option<String> {
+        self.headers.iter().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+
+            key.trim()
+                .eq_ignore_ascii_case(name)
+                .then(|| value.trim().to_string())
+        })
+    }
+
+    /// The body decoded as UTF-8, with invalid sequences replaced.
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// The body as a packed array, e.g. to pass it to `Image.load_png_from_buffer()`.
+    pub fn body_packed(&self) -> PackedByteArray {
+        PackedByteArray::from(self.body.as_slice())
+    }
+
+    /// The body parsed as JSON, or `None` if it is not valid JSON.
+    pub fn json(&self) -> Option<Variant> {
+        let mut json = Json::new();
+
+        if json.parse(self.text().into()) == Error::OK {
+            Some(json.get_data())
+        } else {
+            None
+        }
+    }
+}
+
+/// Reason why an HTTP request did not produce a [`Response`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum HttpError {
+    /// The request could not be started, e.g. because the URL is malformed.
+    Request(Error),
+
+    /// The request was started, but failed before a response arrived, e.g. on connection errors or timeouts.
+    Failed(RequestResult),
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HttpError::Request(error) => write!(f, "HTTP request could not be started: {error:?}"),
+            HttpError::Failed(result) => write!(f, "HTTP request failed: {result:?}"),
+        }
+    }
+}
+
+impl std::error::Error for HttpError {}
@@ -0,0 +1,388 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::StringName;
+use crate::engine::global::{JoyAxis, JoyButton, Key, MouseButton};
+use crate::engine::{
+    InputEvent, InputEventJoypadButton, InputEventJoypadMotion, InputEventKey,
+    InputEventMouseButton, InputEventWithModifiers, InputMap,
+};
+use crate::obj::Gd;
+
+/// Device an [`InputBinding`] reacts to.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum InputDevice {
+    /// Any device; the usual choice for actions, and what the project settings use.
+    #[default]
+    All,
+
+    /// Only the device with this index, e.g. to give each local player their own gamepad.
+    Id(i32),
+}
+
+impl InputDevice {
+    /// Godot's `InputMap.ALL_DEVICES`.
+    const ALL_DEVICES: i32 = -1;
+
+    fn to_id(self) -> i32 {
+        match self {
+            InputDevice::All => Self::ALL_DEVICES,
+            InputDevice::Id(id) => id,
+        }
+    }
+
+    fn from_id(device: i32) -> Self {
+        match device {
+            Self::ALL_DEVICES => InputDevice::All,
+            id => InputDevice::Id(id),
+        }
+    }
+}
+
+/// Physical input that triggers an action, without any `InputEvent` boilerplate.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum InputSource {
+    /// Key identified by the character it produces on the current keyboard layout (`InputEventKey.keycode`).
+    Key(Key),
+
+    /// Key identified by its position on a US QWERTY keyboard, regardless of layout (`InputEventKey.physical_keycode`).
+    PhysicalKey(Key),
+
+    /// Mouse button (`InputEventMouseButton`).
+    MouseButton(MouseButton),
+
+    /// Gamepad button (`InputEventJoypadButton`).
+    JoyButton(JoyButton),
+
+    /// One direction of a gamepad axis (`InputEventJoypadMotion`); `positive` selects the direction of `axis_value`.
+    JoyAxis { axis: JoyAxis, positive: bool },
+}
+
+/// Input event bound to an action, built from typed values.
+///
+/// Converts to and from the `InputEvent` objects stored in [`InputMap`]. Modifiers only apply to keys and mouse buttons.
+///
+/// # Example
+/// ```no_run
+/// use godot::engine::global::{JoyButton, Key};
+/// use godot::engine::{InputBinding, InputDevice, InputMap, InputMapExt};
+///
+/// InputMap::singleton()
+///     .define_action("save")
+///     .bind(InputBinding::key(Key::KEY_S).with_ctrl())
+///     .done();
+///
+/// InputMap::singleton()
+///     .define_action("p2_jump")
+///     .bind(InputBinding::joy_button(JoyButton::JOY_BUTTON_A).with_device(InputDevice::Id(1)))
+///     .done();
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct InputBinding {
+    /// Key, button or axis triggering the action.
+    pub source: InputSource,
+
+    /// Device the binding is restricted to.
+    pub device: InputDevice,
+
+    /// Whether Shift must be held.
+    pub shift: bool,
+
+    /// Whether Ctrl must be held.
+    pub ctrl: bool,
+
+    /// Whether Alt must be held.
+    pub alt: bool,
+
+    /// Whether Meta must be held.
+    pub meta: bool,
+}
+
+impl InputBinding {
+    /// Binding for `source` on all devices, without modifiers.
+    pub fn new(source: InputSource) -> Self {
+        Self {
+            source,
+            device: InputDevice::All,
+            shift: false,
+            ctrl: false,
+            alt: false,
+            meta: false,
+        }
+    }
+
+    /// Binding for a key, by keycode.
+    pub fn key(key: Key) -> Self {
+        Self::new(InputSource::Key(key))
+    }
+
+    /// Binding for a key, by physical position.
+    pub fn physical_key(key: Key) -> Self {
+        Self::new(InputSource::PhysicalKey(key))
+    }
+
+    /// Binding for a mouse button.
+    pub fn mouse_button(button: MouseButton) -> Self {
+        Self::new(InputSource::MouseButton(button))
+    }
+
+    /// Binding for a gamepad button.
+    pub fn joy_button(button: JoyButton) -> Self {
+        Self::new(InputSource::JoyButton(button))
+    }
+
+    /// Binding for one direction of a gamepad axis.
+    pub fn joy_axis(axis: JoyAxis, positive: bool) -> Self {
+        Self::new(InputSource::JoyAxis { axis, positive })
+    }
+
+    /// Restricts the binding to `device`.
+    pub fn with_device(mut self, device: InputDevice) -> Self {
+        self.device = device;
+        self
+    }
+
+    /// Requires Shift to be held.
+    pub fn with_shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    /// Requires Ctrl to be held.
+    pub fn with_ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    /// Requires Alt (Option on macOS) to be held.
+    pub fn with_alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+
+    /// Requires Meta (Command on macOS, Windows key elsewhere) to be held.
+    pub fn with_meta(mut self) -> Self {
+        self.meta = true;
+        self
+    }
+
+    /// Creates the `InputEvent` representing this binding.
+    pub fn to_event(&self) -> Gd<InputEvent> {
+        let mut event: Gd<InputEvent> = match self.source {
+            InputSource::Key(key) => {
+                let mut event = InputEventKey::new();
+                event.set_keycode(key);
+                self.apply_modifiers(event.clone().upcast());
+                event.upcast()
+            }
+            InputSource::PhysicalKey(key) => {
+                let mut event = InputEventKey::new();
+                event.set_physical_keycode(key);
+                self.apply_modifiers(event.clone().upcast());
+                event.upcast()
+            }
+            InputSource::MouseButton(button) => {
+                let mut event = InputEventMouseButton::new();
+                event.set_button_index(button);
+                self.apply_modifiers(event.clone().upcast());
+                event.upcast()
+            }
+            InputSource::JoyButton(button) => {
+                let mut event = InputEventJoypadButton::new();
+                event.set_button_index(button);
+                event.upcast()
+            }
+            InputSource::JoyAxis { axis, positive } => {
+                let mut event = InputEventJoypadMotion::new();
+                event.set_axis(axis);
+                event.set_axis_value(if positive { 1.0 } else { -1.0 });
+                event.upcast()
+            }
+        };
+
+        event.set_device(self.device.to_id());
+        event
+    }
+
+    /// Reads a binding from an event, as returned by `InputMap.action_get_events()`.
+    ///
+    /// Returns `None` for event types that cannot be bound this way, such as touch or MIDI events.
+    pub fn from_event(event: &Gd<InputEvent>) -> Option<Self> {
+        let event = event.clone();
+        let device = InputDevice::from_id(event.get_device());
+
+        let binding = if let Some(key) = event.clone().try_cast::<InputEventKey>() {
+            let keycode = key.get_keycode();
+            let source = if keycode == Key::KEY_NONE {
+                InputSource::PhysicalKey(key.get_physical_keycode())
+            } else {
+                InputSource::Key(keycode)
+            };
+            Self::new(source).with_modifiers_of(key.upcast())
+        } else if let Some(button) = event.clone().try_cast::<InputEventMouseButton>() {
+            Self::mouse_button(button.get_button_index()).with_modifiers_of(button.upcast())
+        } else if let Some(button) = event.clone().try_cast::<InputEventJoypadButton>() {
+            Self::joy_button(button.get_button_index())
+        } else if let Some(motion) = event.try_cast::<InputEventJoypadMotion>() {
+            Self::joy_axis(motion.get_axis(), motion.get_axis_value() >= 0.0)
+        } else {
+            return None;
+        };
+
+        Some(binding.with_device(device))
+    }
+
+    fn apply_modifiers(&self, mut event: Gd<InputEventWithModifiers>) {
+        event.set_shift_pressed(self.shift);
+        event.set_ctrl_pressed(self.ctrl);
+        event.set_alt_pressed(self.alt);
+        event.set_meta_pressed(self.meta);
+    }
+
+    fn with_modifiers_of(mut self, event: Gd<InputEventWithModifiers>) -> Self {
+        self.shift = event.is_shift_pressed();
+        self.ctrl = event.is_ctrl_pressed();
+        self.alt = event.is_alt_pressed();
+        self.meta = event.is_meta_pressed();
+        self
+    }
+}
+
+impl From<InputSource> for InputBinding {
+    fn from(source: InputSource) -> Self {
+        Self::new(source)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Extension trait to define and rebind input actions at runtime, using [`InputBinding`]s instead of hand-built events.
+///
+/// Changes only affect the running game; to persist rebinds, store the bindings yourself (e.g. in a config file) and reapply them
+/// at startup.
+pub trait InputMapExt {
+    /// Creates `action`, or removes all its events if it already exists, and returns a builder to add bindings.
+    fn define_action(&mut self, action: impl Into<StringName>) -> ActionBuilder<'_>;
+
+    /// The bindings of `action`, in the order they were added. Events that cannot be represented as bindings are skipped.
+    ///
+    /// Returns an empty list if the action does not exist.
+    fn action_bindings(&self, action: impl Into<StringName>) -> Vec<InputBinding>;
+
+    /// Replaces all events of `action` with `bindings`, keeping its deadzone.
+    ///
+    /// # Panics
+    /// If the action does not exist.
+    fn set_action_bindings(&mut self, action: impl Into<StringName>, bindings: &[InputBinding]);
+
+    /// Replaces the first binding of `action` equal to `old` with `new`, keeping the position of all other bindings.
+    ///
+    /// Returns `false` if `action` has no binding equal to `old`, in which case nothing is changed.
+    fn rebind(
+        &mut self,
+        action: impl Into<StringName>,
+        old: &InputBinding,
+        new: InputBinding,
+    ) -> bool;
+}
+
+impl InputMapExt for Gd<InputMap> {
+    fn define_action(&mut self, action: impl Into<StringName>) -> ActionBuilder<'_> {
+        let action = action.into();
+
+        if self.has_action(action.clone()) {
+            self.action_erase_events(action.clone());
+        } else {
+            self.add_action(action.clone());
+        }
+
+        ActionBuilder {
+            input_map: self,
+            action,
+        }
+    }
+
+    fn action_bindings(&self, action: impl Into<StringName>) -> Vec<InputBinding> {
+        let action = action.into();
+        if !self.has_action(action.clone()) {
+            return Vec::new();
+        }
+
+        // `action_get_events()` is not const in Godot, hence the clone.
+        self.clone()
+            .action_get_events(action)
+            .iter_shared()
+            .filter_map(|event| InputBinding::from_event(&event))
+            .collect()
+    }
+
+    fn set_action_bindings(&mut self, action: impl Into<StringName>, bindings: &[InputBinding]) {
+        let action = action.into();
+        assert!(
+            self.has_action(action.clone()),
+            "input action `{action}` does not exist"
+        );
+
+        self.action_erase_events(action.clone());
+        for binding in bindings {
+            self.action_add_event(action.clone(), binding.to_event());
+        }
+    }
+
+    fn rebind(
+        &mut self,
+        action: impl Into<StringName>,
+        old: &InputBinding,
+        new: InputBinding,
+    ) -> bool {
+        let action = action.into();
+
+        let mut bindings = self.action_bindings(action.clone());
+        let Some(slot) = bindings.iter_mut().find(|binding| **binding == *old) else {
+            return false;
+        };
+
+        *slot = new;
+        self.set_action_bindings(action, &bindings);
+        true
+    }
+}
+
+/// Adds bindings to an action, created by [`InputMapExt::define_action()`].
+#[must_use = "call done() to finish the action definition"]
+pub struct ActionBuilder<'a> {
+    input_map: &'a mut Gd<InputMap>,
+    action: StringName,
+}
+
+impl ActionBuilder<'_> {
+    /// Strength below which analog input (axes, triggers) does not trigger the action; 0.5 by default.
+    pub fn deadzone(self, deadzone: f32) -> Self {
+        self.input_map
+            .action_set_deadzone(self.action.clone(), deadzone);
+        self
+    }
+
+    /// Adds a binding to the action.
+    pub fn bind(self, binding: impl Into<InputBinding>) -> Self {
+        self.input_map
+            .action_add_event(self.action.clone(), binding.into().to_event());
+        self
+    }
+
+    /// Adds several bindings to the action.
+    pub fn bind_all(mut self, bindings: impl IntoIterator<Item = InputBinding>) -> Self {
+        for binding in bindings {
+            self = self.bind(binding);
+        }
+        self
+    }
+
+    /// Finishes the definition and returns the action name.
+    pub fn done(self) -> StringName {
+        self.action
+    }
+}
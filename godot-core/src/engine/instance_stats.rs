@@ -0,0 +1,200 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+
+use crate::builtin::meta::ClassName;
+use crate::obj::GodotClass;
+
+#[cfg(since_api = "4.2")]
+use crate::engine::{Performance, PerformanceExt};
+
+/// Number of instances of a user class, to notice objects that are created but never freed.
+///
+/// Instances are only counted in debug builds (with `debug_assertions`); in release builds, all counts are zero, see
+/// [`is_enabled()`][Self::is_enabled]. An instance counts as live from its construction until Godot frees it, regardless of
+/// whether it is in the scene tree.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::InstanceStats;
+///
+/// fn print_leak_candidates() {
+///     for stats in InstanceStats::all() {
+///         if stats.live > 1000 {
+///             godot_warn!("{stats}");
+///         }
+///     }
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct InstanceStats {
+    pub class_name: ClassName,
+
+    /// Instances that currently exist.
+    pub live: usize,
+
+    /// Highest number of instances that existed at the same time.
+    pub peak: usize,
+
+    /// Instances created since the library was loaded, including the freed ones.
+    pub created: u64,
+}
+
+impl InstanceStats {
+    /// Whether instances are counted in this build.
+    pub const fn is_enabled() -> bool {
+        cfg!(debug_assertions)
+    }
+
+    /// The counts for class `T`; all zero if no instance was created yet.
+    pub fn of<T: GodotClass>() -> Self {
+        let class_name = T::class_name();
+
+        lock_counts()
+            .as_ref()
+            .and_then(|counts| counts.get(&class_name))
+            .map(|counter| counter.stats(class_name))
+            .unwrap_or(Self {
+                class_name,
+                live: 0,
+                peak: 0,
+                created: 0,
+            })
+    }
+
+    /// The counts for every class that had an instance so far, the classes with most live instances first.
+    pub fn all() -> Vec<Self> {
+        let mut all: Vec<Self> = lock_counts()
+            .iter()
+            .flatten()
+            .map(|(class_name, counter)| counter.stats(*class_name))
+            .collect();
+
+        all.sort_by(|a, b| {
+            b.live
+                .cmp(&a.live)
+                .then_with(|| a.class_name.to_string().cmp(&b.class_name.to_string()))
+        });
+        all
+    }
+
+    /// ⚠️ Adds the custom monitor `Instances/<class name>`, which shows the live instances of `T` in the editor's debugger.
+    ///
+    /// # Panics
+    /// If the monitor was already added.
+    #[cfg(since_api = "4.2")]
+    pub fn add_monitor<T: GodotClass>() {
+        let id = format!("Instances/{}", T::class_name());
+
+        Performance::singleton().add_monitor(id, || Self::of::<T>().live as f64);
+    }
+}
+
+impl fmt::Display for InstanceStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} live (peak {}, {} created)",
+            self.class_name, self.live, self.peak, self.created
+        )
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+static COUNTS: Mutex<Option<HashMap<ClassName, Counter>>> = Mutex::new(None);
+
+// Never constructed in release builds, where instances are not counted.
+#[cfg_attr(not(debug_assertions), allow(dead_code))]
+#[derive(Default)]
+struct Counter {
+    live: usize,
+    peak: usize,
+    created: u64,
+}
+
+impl Counter {
+    fn stats(&self, class_name: ClassName) -> InstanceStats {
+        InstanceStats {
+            class_name,
+            live: self.live,
+            peak: self.peak,
+            created: self.created,
+        }
+    }
+}
+
+fn lock_counts() -> std::sync::MutexGuard<'static, Option<HashMap<ClassName, Counter>>> {
+    // Counts stay meaningful if a panic happened while they were locked.
+    COUNTS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Hooks for the counters, called from the instance storage.
+#[cfg(debug_assertions)]
+pub(crate) mod instance_counts {
+    use super::*;
+
+    pub fn instance_created(class_name: ClassName) {
+        let mut guard = lock_counts();
+        let counter = guard
+            .get_or_insert_with(HashMap::new)
+            .entry(class_name)
+            .or_default();
+
+        counter.live += 1;
+        counter.peak = counter.peak.max(counter.live);
+        counter.created += 1;
+    }
+
+    pub fn instance_destroyed(class_name: ClassName) {
+        let mut guard = lock_counts();
+
+        if let Some(counter) = guard
+            .as_mut()
+            .and_then(|counts| counts.get_mut(&class_name))
+        {
+            counter.live = counter.live.saturating_sub(1);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(all(test, debug_assertions))]
+mod tests {
+    use super::instance_counts::{instance_created, instance_destroyed};
+    use super::InstanceStats;
+    use crate::builtin::meta::ClassName;
+
+    #[test]
+    fn instance_stats_counting() {
+        let class_name = ClassName::from_ascii_cstr(b"StatsTestEnemy\0");
+        let stats = || {
+            InstanceStats::all()
+                .into_iter()
+                .find(|stats| stats.class_name == class_name)
+                .expect("class counted")
+        };
+
+        for _ in 0..3 {
+            instance_created(class_name);
+        }
+        instance_destroyed(class_name);
+        instance_created(class_name);
+        instance_destroyed(class_name);
+
+        let stats = stats();
+        assert_eq!((stats.live, stats.peak, stats.created), (2, 3, 4));
+        assert_eq!(
+            stats.to_string(),
+            "StatsTestEnemy: 2 live (peak 3, 4 created)"
+        );
+    }
+}
@@ -0,0 +1,496 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{real, real_consts, Rid, Vector2, Vector3};
+use crate::engine::{
+    CharacterBody2D, CharacterBody3D, KinematicCollision2D, KinematicCollision3D, Node,
+    PhysicsBody2D, PhysicsBody3D,
+};
+use crate::obj::{Gd, GodotClass, Inherits, InstanceId};
+
+/// Classification of a collision surface relative to the up direction, like `CharacterBody2D::is_on_floor()` and friends.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum SurfaceKind {
+    /// The surface is at most the floor's maximum angle steep.
+    Floor,
+
+    /// The surface is neither floor nor ceiling.
+    Wall,
+
+    /// The surface faces down, at most the floor's maximum angle off from straight down.
+    Ceiling,
+}
+
+impl SurfaceKind {
+    /// Classifies the surface with the given `normal`, for a body whose up direction is `up`.
+    ///
+    /// `floor_max_angle` is in radians, as returned by `get_floor_max_angle()`. Uses the same tolerance as Godot.
+    pub fn classify_2d(normal: Vector2, up: Vector2, floor_max_angle: real) -> Self {
+        classify(normal.angle_to(up).abs(), floor_max_angle)
+    }
+
+    /// Classifies the surface with the given `normal`, for a body whose up direction is `up`.
+    ///
+    /// `floor_max_angle` is in radians, as returned by `get_floor_max_angle()`. Uses the same tolerance as Godot.
+    pub fn classify_3d(normal: Vector3, up: Vector3, floor_max_angle: real) -> Self {
+        classify(normal.angle_to(up), floor_max_angle)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Collision reported by `move_and_collide()` or `move_and_slide()` of a 2D body, read from its `KinematicCollision2D`.
+#[derive(Clone, Debug)]
+pub struct KinematicHit2D {
+    /// Point of contact, in global coordinates.
+    pub position: Vector2,
+
+    /// Normal of the colliding surface at the point of contact.
+    pub normal: Vector2,
+
+    /// The part of the motion that was performed before the collision.
+    pub travel: Vector2,
+
+    /// The part of the motion that was blocked by the collision.
+    pub remainder: Vector2,
+
+    /// How deep the body overlapped the collider.
+    pub depth: f32,
+
+    /// The colliding node, or `None` if it has been freed or is no node (e.g. a body created on the physics server).
+    pub collider: Option<Gd<Node>>,
+
+    /// Instance ID of the colliding object.
+    pub collider_id: Option<InstanceId>,
+
+    /// RID of the colliding body on the physics server.
+    pub collider_rid: Rid,
+
+    /// Index of the colliding shape within the collider.
+    pub collider_shape_index: usize,
+
+    /// Velocity of the collider, e.g. of a moving platform.
+    pub collider_velocity: Vector2,
+}
+
+impl KinematicHit2D {
+    /// Reads all values of `collision`.
+    pub fn from_collision(collision: &Gd<KinematicCollision2D>) -> Self {
+        Self {
+            position: collision.get_position(),
+            normal: collision.get_normal(),
+            travel: collision.get_travel(),
+            remainder: collision.get_remainder(),
+            depth: collision.get_depth(),
+            collider: collision
+                .get_collider()
+                .and_then(|collider| collider.try_cast::<Node>()),
+            collider_id: InstanceId::try_from_u64(collision.get_collider_id()),
+            collider_rid: collision.get_collider_rid(),
+            collider_shape_index: collision.get_collider_shape_index() as usize,
+            collider_velocity: collision.get_collider_velocity(),
+        }
+    }
+
+    /// Angle between the surface normal and `up`, in radians.
+    pub fn angle(&self, up: Vector2) -> real {
+        self.normal.angle_to(up).abs()
+    }
+
+    /// Classifies the colliding surface, see [`SurfaceKind::classify_2d()`].
+    pub fn surface_kind(&self, up: Vector2, floor_max_angle: real) -> SurfaceKind {
+        SurfaceKind::classify_2d(self.normal, up, floor_max_angle)
+    }
+
+    /// The collider as `T`, or `None` if it is of another class.
+    pub fn collider_as<T>(&self) -> Option<Gd<T>>
+    where
+        T: GodotClass + Inherits<Node>,
+    {
+        self.collider.clone()?.try_cast::<T>()
+    }
+}
+
+/// Contact point of a [`KinematicHit3D`]; a 3D collision can touch several colliders at once.
+#[derive(Clone, Debug)]
+pub struct Contact3D {
+    /// Point of contact, in global coordinates.
+    pub position: Vector3,
+
+    /// Normal of the colliding surface at the point of contact.
+    pub normal: Vector3,
+
+    /// The colliding node, or `None` if it has been freed or is no node (e.g. a body created on the physics server).
+    pub collider: Option<Gd<Node>>,
+
+    /// Instance ID of the colliding object.
+    pub collider_id: Option<InstanceId>,
+
+    /// RID of the colliding body on the physics server.
+    pub collider_rid: Rid,
+
+    /// Index of the colliding shape within the collider.
+    pub collider_shape_index: usize,
+
+    /// Velocity of the collider, e.g. of a moving platform.
+    pub collider_velocity: Vector3,
+}
+
+impl Contact3D {
+    /// Angle between the surface normal and `up`, in radians.
+    pub fn angle(&self, up: Vector3) -> real {
+        self.normal.angle_to(up)
+    }
+
+    /// Classifies the colliding surface, see [`SurfaceKind::classify_3d()`].
+    pub fn surface_kind(&self, up: Vector3, floor_max_angle: real) -> SurfaceKind {
+        SurfaceKind::classify_3d(self.normal, up, floor_max_angle)
+    }
+
+    /// The collider as `T`, or `None` if it is of another class.
+    pub fn collider_as<T>(&self) -> Option<Gd<T>>
+    where
+        T: GodotClass + Inherits<Node>,
+    {
+        self.collider.clone()?.try_cast::<T>()
+    }
+}
+
+/// Collision reported by `move_and_collide()` or `move_and_slide()` of a 3D body, read from its `KinematicCollision3D`.
+#[derive(Clone, Debug)]
+pub struct KinematicHit3D {
+    /// The part of the motion that was performed before the collision.
+    pub travel: Vector3,
+
+    /// The part of the motion that was blocked by the collision.
+    pub remainder: Vector3,
+
+    /// How deep the body overlapped the colliders.
+    pub depth: f32,
+
+    /// The contact points, at least one.
+    pub contacts: Vec<Contact3D>,
+}
+
+impl KinematicHit3D {
+    /// Reads all values of `collision`, including each of its contacts.
+    pub fn from_collision(collision: &Gd<KinematicCollision3D>) -> Self {
+        let contacts = (0..collision.get_collision_count())
+            .map(|index| Contact3D {
+                position: collision.get_position_ex().collision_index(index).done(),
+                normal: collision.get_normal_ex().collision_index(index).done(),
+                collider: collision
+                    .get_collider_ex()
+                    .collision_index(index)
+                    .done()
+                    .and_then(|collider| collider.try_cast::<Node>()),
+                collider_id: InstanceId::try_from_u64(
+                    collision.get_collider_id_ex().collision_index(index).done(),
+                ),
+                collider_rid: collision
+                    .get_collider_rid_ex()
+                    .collision_index(index)
+                    .done(),
+                collider_shape_index: collision
+                    .get_collider_shape_index_ex()
+                    .collision_index(index)
+                    .done() as usize,
+                collider_velocity: collision
+                    .get_collider_velocity_ex()
+                    .collision_index(index)
+                    .done(),
+            })
+            .collect();
+
+        Self {
+            travel: collision.get_travel(),
+            remainder: collision.get_remainder(),
+            depth: collision.get_depth(),
+            contacts,
+        }
+    }
+
+    /// The first contact point.
+    pub fn contact(&self) -> &Contact3D {
+        &self.contacts[0]
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Extension methods for 2D physics bodies, returning [`KinematicHit2D`]s from `move_and_collide()`.
+pub trait PhysicsBody2DExt {
+    /// Moves the body by `motion` until it collides, and returns the collision, if any.
+    fn move_and_collide_hit(&mut self, motion: Vector2) -> Option<KinematicHit2D>;
+
+    /// Like [`move_and_collide_hit()`][Self::move_and_collide_hit], but without moving the body.
+    fn test_collide_hit(&mut self, motion: Vector2) -> Option<KinematicHit2D>;
+}
+
+impl<U> PhysicsBody2DExt for Gd<U>
+where
+    U: GodotClass + Inherits<PhysicsBody2D>,
+{
+    fn move_and_collide_hit(&mut self, motion: Vector2) -> Option<KinematicHit2D> {
+        let collision = self
+            .clone()
+            .upcast::<PhysicsBody2D>()
+            .move_and_collide(motion)?;
+
+        Some(KinematicHit2D::from_collision(&collision))
+    }
+
+    fn test_collide_hit(&mut self, motion: Vector2) -> Option<KinematicHit2D> {
+        let collision = self
+            .clone()
+            .upcast::<PhysicsBody2D>()
+            .move_and_collide_ex(motion)
+            .test_only(true)
+            .done()?;
+
+        Some(KinematicHit2D::from_collision(&collision))
+    }
+}
+
+/// Extension methods for 3D physics bodies, returning [`KinematicHit3D`]s from `move_and_collide()`.
+pub trait PhysicsBody3DExt {
+    /// Moves the body by `motion` until it collides, and returns the collision with up to `max_contacts` contacts, if any.
+    fn move_and_collide_hit(
+        &mut self,
+        motion: Vector3,
+        max_contacts: usize,
+    ) -> Option<KinematicHit3D>;
+
+    /// Like [`move_and_collide_hit()`][Self::move_and_collide_hit], but without moving the body.
+    fn test_collide_hit(&mut self, motion: Vector3, max_contacts: usize) -> Option<KinematicHit3D>;
+}
+
+impl<U> PhysicsBody3DExt for Gd<U>
+where
+    U: GodotClass + Inherits<PhysicsBody3D>,
+{
+    fn move_and_collide_hit(
+        &mut self,
+        motion: Vector3,
+        max_contacts: usize,
+    ) -> Option<KinematicHit3D> {
+        move_and_collide_3d(self.clone().upcast(), motion, max_contacts, false)
+    }
+
+    fn test_collide_hit(&mut self, motion: Vector3, max_contacts: usize) -> Option<KinematicHit3D> {
+        move_and_collide_3d(self.clone().upcast(), motion, max_contacts, true)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Extension methods for `CharacterBody2D`, reading the collisions of the last `move_and_slide()` as [`KinematicHit2D`]s.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{CharacterBody2D, CharacterBody2DExt};
+///
+/// fn bump_heads(body: &mut Gd<CharacterBody2D>) {
+///     body.move_and_slide();
+///
+///     for hit in body.slide_hits() {
+///         if let Some(mut block) = hit.collider_as::<Node2D>() {
+///             block.emit_signal("bumped".into(), &[]);
+///         }
+///     }
+/// }
+/// ```
+pub trait CharacterBody2DExt {
+    /// All collisions of the last `move_and_slide()`.
+    fn slide_hits(&mut self) -> Vec<KinematicHit2D>;
+
+    /// The last collision of the last `move_and_slide()`, or `None` if there was none.
+    fn last_slide_hit(&mut self) -> Option<KinematicHit2D>;
+
+    /// `velocity` turned to run along the floor while keeping its speed, so that walking up or down a slope is not slower.
+    ///
+    /// Returns `velocity` unchanged if the body is not on the floor.
+    fn along_floor(&mut self, velocity: Vector2) -> Vector2;
+}
+
+impl<U> CharacterBody2DExt for Gd<U>
+where
+    U: GodotClass + Inherits<CharacterBody2D>,
+{
+    fn slide_hits(&mut self) -> Vec<KinematicHit2D> {
+        let mut body = self.clone().upcast::<CharacterBody2D>();
+
+        (0..body.get_slide_collision_count())
+            .filter_map(|index| body.get_slide_collision(index))
+            .map(|collision| KinematicHit2D::from_collision(&collision))
+            .collect()
+    }
+
+    fn last_slide_hit(&mut self) -> Option<KinematicHit2D> {
+        let collision = self
+            .clone()
+            .upcast::<CharacterBody2D>()
+            .get_last_slide_collision()?;
+
+        Some(KinematicHit2D::from_collision(&collision))
+    }
+
+    fn along_floor(&mut self, velocity: Vector2) -> Vector2 {
+        let body = self.clone().upcast::<CharacterBody2D>();
+        if !body.is_on_floor() {
+            return velocity;
+        }
+
+        along_slope_2d(velocity, body.get_floor_normal())
+    }
+}
+
+/// Extension methods for `CharacterBody3D`, reading the collisions of the last `move_and_slide()` as [`KinematicHit3D`]s.
+pub trait CharacterBody3DExt {
+    /// All collisions of the last `move_and_slide()`.
+    fn slide_hits(&mut self) -> Vec<KinematicHit3D>;
+
+    /// The last collision of the last `move_and_slide()`, or `None` if there was none.
+    fn last_slide_hit(&mut self) -> Option<KinematicHit3D>;
+
+    /// `velocity` turned to run along the floor while keeping its speed, so that walking up or down a slope is not slower.
+    ///
+    /// Returns `velocity` unchanged if the body is not on the floor.
+    fn along_floor(&mut self, velocity: Vector3) -> Vector3;
+}
+
+impl<U> CharacterBody3DExt for Gd<U>
+where
+    U: GodotClass + Inherits<CharacterBody3D>,
+{
+    fn slide_hits(&mut self) -> Vec<KinematicHit3D> {
+        let mut body = self.clone().upcast::<CharacterBody3D>();
+
+        (0..body.get_slide_collision_count())
+            .filter_map(|index| body.get_slide_collision(index))
+            .map(|collision| KinematicHit3D::from_collision(&collision))
+            .collect()
+    }
+
+    fn last_slide_hit(&mut self) -> Option<KinematicHit3D> {
+        let collision = self
+            .clone()
+            .upcast::<CharacterBody3D>()
+            .get_last_slide_collision()?;
+
+        Some(KinematicHit3D::from_collision(&collision))
+    }
+
+    fn along_floor(&mut self, velocity: Vector3) -> Vector3 {
+        let body = self.clone().upcast::<CharacterBody3D>();
+        if !body.is_on_floor() {
+            return velocity;
+        }
+
+        along_slope_3d(velocity, body.get_floor_normal())
+    }
+}
+
+/// Projects `velocity` onto the surface with `normal`, keeping its length.
+pub fn along_slope_2d(velocity: Vector2, normal: Vector2) -> Vector2 {
+    let slid = velocity.slide(normal);
+    if slid.is_zero_approx() {
+        return slid;
+    }
+
+    slid.normalized() * velocity.length()
+}
+
+/// Projects `velocity` onto the surface with `normal`, keeping its length.
+pub fn along_slope_3d(velocity: Vector3, normal: Vector3) -> Vector3 {
+    let slid = velocity.slide(normal);
+    if slid.is_zero_approx() {
+        return slid;
+    }
+
+    slid.normalized() * velocity.length()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+/// Godot's tolerance when comparing against the floor's maximum angle.
+const FLOOR_ANGLE_THRESHOLD: real = 0.01;
+
+fn classify(angle_to_up: real, floor_max_angle: real) -> SurfaceKind {
+    let limit = floor_max_angle + FLOOR_ANGLE_THRESHOLD;
+
+    if angle_to_up <= limit {
+        SurfaceKind::Floor
+    } else if real_consts::PI - angle_to_up <= limit {
+        SurfaceKind::Ceiling
+    } else {
+        SurfaceKind::Wall
+    }
+}
+
+fn move_and_collide_3d(
+    mut body: Gd<PhysicsBody3D>,
+    motion: Vector3,
+    max_contacts: usize,
+    test_only: bool,
+) -> Option<KinematicHit3D> {
+    let collision = body
+        .move_and_collide_ex(motion)
+        .test_only(test_only)
+        .max_collisions(i32::try_from(max_contacts.max(1)).unwrap_or(i32::MAX))
+        .done()?;
+
+    Some(KinematicHit3D::from_collision(&collision))
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::{along_slope_2d, SurfaceKind};
+    use crate::builtin::{real_consts, Vector2};
+
+    #[test]
+    fn surface_kind_classify() {
+        let up = Vector2::UP;
+        let max_angle = real_consts::FRAC_PI_4;
+
+        let slope = Vector2::new(1.0, -1.0).normalized();
+        let steep = Vector2::new(2.0, -1.0).normalized();
+
+        assert_eq!(
+            SurfaceKind::classify_2d(up, up, max_angle),
+            SurfaceKind::Floor
+        );
+        assert_eq!(
+            SurfaceKind::classify_2d(slope, up, max_angle),
+            SurfaceKind::Floor
+        );
+        assert_eq!(
+            SurfaceKind::classify_2d(steep, up, max_angle),
+            SurfaceKind::Wall
+        );
+        assert_eq!(
+            SurfaceKind::classify_2d(Vector2::RIGHT, up, max_angle),
+            SurfaceKind::Wall
+        );
+        assert_eq!(
+            SurfaceKind::classify_2d(Vector2::DOWN, up, max_angle),
+            SurfaceKind::Ceiling
+        );
+    }
+
+    #[test]
+    fn along_slope_keeps_speed() {
+        let normal = Vector2::new(1.0, -1.0).normalized();
+        let velocity = along_slope_2d(Vector2::new(10.0, 0.0), normal);
+
+        assert!((velocity.length() - 10.0).abs() < 1e-4);
+        assert!(velocity.dot(normal).abs() < 1e-4);
+        assert!(along_slope_2d(-normal, normal).is_zero_approx());
+    }
+}
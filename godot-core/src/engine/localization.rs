@@ -0,0 +1,186 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::meta::{FromGodot, GodotConvert, ToGodot};
+use crate::builtin::{GodotString, StringName, Variant};
+use crate::engine::TranslationServer;
+use crate::property::{Export, ExportInfo, Property};
+
+/// Translates a message through Godot's localization system, substituting `{name}` placeholders.
+///
+/// The message is looked up with `TranslationServer.translate()`, which is what `Object.tr()` uses as well. Afterwards, every
+/// `{name}` in the translated text is replaced with the stringified value of the argument `name`. Placeholders without a
+/// matching argument are left as-is, so translators can see what is missing.
+///
+/// For plural forms, pass the count before a semicolon, followed by the singular and plural message (like `Object.tr_n()`).
+/// The count is not substituted automatically; pass it as an argument if the text needs it.
+///
+/// Returns a [`GodotString`][crate::builtin::GodotString].
+///
+/// # Example
+/// ```no_run
+/// use godot::engine::tr;
+///
+/// let player_name = "Ferris";
+/// let apples = 3;
+///
+/// let greeting = tr!("GREETING", name = player_name);
+/// let inventory = tr!(apples; "APPLE_COUNT", "APPLE_COUNT_PLURAL", count = apples);
+/// ```
+#[macro_export]
+macro_rules! tr {
+    ($n:expr; $key:expr, $plural:expr $(, $name:ident = $value:expr)* $(,)?) => {
+        $crate::engine::__tr_format(
+            $crate::engine::TranslationServer::singleton().translate_plural(
+                $crate::builtin::StringName::from($key),
+                $crate::builtin::StringName::from($plural),
+                $n as i32,
+            ),
+            &[$( (stringify!($name), $crate::builtin::meta::ToGodot::to_variant(&$value)) ),*],
+        )
+    };
+    ($key:expr $(, $name:ident = $value:expr)* $(,)?) => {
+        $crate::engine::__tr_format(
+            $crate::engine::TranslationServer::singleton().translate(
+                $crate::builtin::StringName::from($key),
+            ),
+            &[$( (stringify!($name), $crate::builtin::meta::ToGodot::to_variant(&$value)) ),*],
+        )
+    };
+}
+
+/// Replaces `{name}` placeholders in `translated`. Used by [`tr!`].
+#[doc(hidden)]
+pub fn __tr_format(translated: StringName, args: &[(&str, Variant)]) -> GodotString {
+    if args.is_empty() {
+        return translated.into();
+    }
+
+    let text = translated.to_string();
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text.as_str();
+
+    // Single pass, so that substituted values are never scanned for placeholders themselves.
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let value = rest.find('}').and_then(|end| {
+            let name = &rest[1..end];
+            let (_, value) = args.iter().find(|(arg, _)| *arg == name)?;
+            Some((end, value))
+        });
+
+        match value {
+            Some((end, value)) => {
+                result.push_str(&value.stringify().to_string());
+                rest = &rest[end + 1..];
+            }
+            None => {
+                result.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+
+    result.into()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Translation key that is resolved to the current locale when displayed.
+///
+/// In Godot, a `TranslatableString` is a plain `String` holding the key, so it can be used as `#[var]` or `#[export]` field and
+/// edited in the inspector. On the Rust side, [`translated()`][Self::translated] and the `Display` impl look the key up in the
+/// `TranslationServer`, so the text follows locale changes without any extra bookkeeping.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::TranslatableString;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct QuestGiver {
+///     #[export]
+///     greeting: TranslatableString,
+/// }
+///
+/// impl QuestGiver {
+///     fn greet(&self) {
+///         godot_print!("{}", self.greeting);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct TranslatableString {
+    key: GodotString,
+}
+
+impl TranslatableString {
+    /// Creates a string that translates the message `key`.
+    pub fn new(key: impl Into<GodotString>) -> Self {
+        Self { key: key.into() }
+    }
+
+    /// The untranslated key.
+    pub fn key(&self) -> &GodotString {
+        &self.key
+    }
+
+    /// Translates the key to the current locale.
+    pub fn translated(&self) -> GodotString {
+        TranslationServer::singleton()
+            .translate(StringName::from(&self.key))
+            .into()
+    }
+}
+
+impl fmt::Display for TranslatableString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.translated().fmt(f)
+    }
+}
+
+impl GodotConvert for TranslatableString {
+    type Via = GodotString;
+}
+
+impl ToGodot for TranslatableString {
+    fn to_godot(&self) -> Self::Via {
+        self.key.clone()
+    }
+
+    fn into_godot(self) -> Self::Via {
+        self.key
+    }
+}
+
+impl FromGodot for TranslatableString {
+    fn try_from_godot(via: Self::Via) -> Option<Self> {
+        Some(Self { key: via })
+    }
+}
+
+impl Property for TranslatableString {
+    type Intermediate = GodotString;
+
+    fn get_property(&self) -> GodotString {
+        self.key.clone()
+    }
+
+    fn set_property(&mut self, value: GodotString) {
+        self.key = value;
+    }
+}
+
+impl Export for TranslatableString {
+    fn default_export_info() -> ExportInfo {
+        ExportInfo::with_hint_none()
+    }
+}
@@ -0,0 +1,72 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::meta::ToGodot;
+use crate::builtin::GodotString;
+use crate::engine::{Engine, MainLoop, ProjectSettings};
+use crate::obj::{GodotClass, Inherits};
+
+const MAIN_LOOP_SETTING: &str = "application/run/main_loop_type";
+
+/// Makes Godot run the user class `T` as the main loop, instead of the default `SceneTree`.
+///
+/// `T` can inherit [`MainLoop`] directly -- for example, for a headless simulation server that does not need any nodes -- or
+/// [`SceneTree`][crate::engine::SceneTree], to keep the node tree but add custom logic around each frame. The per-frame hooks are the
+/// virtual methods of [`MainLoopVirtual`][crate::engine::MainLoopVirtual] (or `SceneTreeVirtual`):
+///
+/// - `initialize()` is called once, before the first frame.
+/// - `process(delta)` and `physics_process(delta)` are called every idle and physics frame; returning `true` ends the main loop.
+/// - `finalize()` is called once, before the engine shuts down.
+///
+/// This overrides the `application/run/main_loop_type` project setting for the current run, without saving it to `project.godot`.
+/// For it to take effect, call this function from [`ExtensionLibrary::on_level_init()`][crate::init::ExtensionLibrary::on_level_init]
+/// at [`InitLevel::Scene`][crate::init::InitLevel::Scene]; the main loop is created after all extensions are initialized.
+/// Inside the editor, nothing happens, so the editor keeps its own main loop.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{set_main_loop_type, MainLoop, MainLoopVirtual};
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=MainLoop)]
+/// struct Simulation {
+///     ticks: u64,
+/// }
+///
+/// #[godot_api]
+/// impl MainLoopVirtual for Simulation {
+///     fn physics_process(&mut self, _delta: real) -> bool {
+///         self.ticks += 1;
+///         self.ticks >= 1000 // Quit after 1000 ticks.
+///     }
+/// }
+///
+/// struct MyExtension;
+///
+/// #[gdextension]
+/// unsafe impl ExtensionLibrary for MyExtension {
+///     fn on_level_init(level: InitLevel) {
+///         if level == InitLevel::Scene {
+///             set_main_loop_type::<Simulation>();
+///         }
+///     }
+/// }
+/// ```
+pub fn set_main_loop_type<T>()
+where
+    T: GodotClass + Inherits<MainLoop>,
+{
+    // The editor may save project settings at any time; the override must not end up in the project file.
+    if Engine::singleton().is_editor_hint() {
+        return;
+    }
+
+    ProjectSettings::singleton().set_setting(
+        GodotString::from(MAIN_LOOP_SETTING),
+        T::class_name().to_godot_string().to_variant(),
+    );
+}
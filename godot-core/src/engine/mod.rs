@@ -0,0 +1,488 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Godot engine classes and methods.
+
+// Re-exports of generated symbols
+use crate::builtin::{GodotString, NodePath};
+use crate::obj::dom::EngineDomain;
+use crate::obj::{Gd, GodotClass, Inherits, InstanceId};
+
+pub use crate::gen::central::global;
+pub use crate::gen::classes::*;
+pub use crate::gen::utilities;
+
+use crate::sys;
+
+mod animation_builder;
+mod app_lifecycle;
+mod astar_ext;
+#[cfg(since_api = "4.2")]
+mod async_ext;
+mod audio_bus;
+mod canvas_space;
+#[cfg(all(feature = "cargo-build-plugin", since_api = "4.2"))]
+mod cargo_build;
+mod clipboard;
+mod collision_layers;
+#[cfg(feature = "debug-overlay")]
+mod debug_overlay;
+mod error_context;
+#[cfg(since_api = "4.2")]
+mod event_bus;
+mod expression_eval;
+mod frame_pacing;
+pub mod fs;
+mod geometry;
+mod headless;
+#[cfg(since_api = "4.2")]
+pub mod http;
+mod input_map;
+mod instance_stats;
+mod kinematics;
+mod localization;
+mod main_loop;
+mod multimesh_buffer;
+mod navigation;
+mod os_env;
+mod packed_state;
+mod physics_query;
+#[cfg(since_api = "4.2")]
+mod profiling;
+#[cfg(since_api = "4.2")]
+mod property_binding;
+mod res_path;
+mod res_uid;
+#[cfg(feature = "rand")]
+mod rng;
+mod sampling;
+mod save_state;
+mod scene_snapshot;
+mod script_instance;
+mod script_interop;
+mod shader_material;
+mod shader_source;
+mod signal_connect;
+#[cfg(since_api = "4.2")]
+mod signal_future;
+mod skeleton_ext;
+#[cfg(since_api = "4.2")]
+mod structural_defer;
+mod theme_ext;
+mod tile_map_ext;
+mod tts;
+mod typed_config;
+mod undo_redo_ext;
+mod variant_codec;
+mod window_ext;
+#[cfg(since_api = "4.2")]
+mod worker_pool;
+
+pub use animation_builder::{
+    AnimationBuilder, BlendShapeTrack, MethodKey, MethodTrack, PositionTrack, RotationTrack,
+    ScaleTrack, TrackBuilder, TrackKind, ValueTrack,
+};
+pub use app_lifecycle::AppLifecycle;
+pub use astar_ext::{AStarExt, AStarGridExt, PointId};
+#[cfg(since_api = "4.2")]
+pub use async_ext::{AnimationFuture, AnimationPlayerAsyncExt, HttpRequestAsyncExt, TweenAsyncExt};
+pub use audio_bus::{Amplitude, AudioBus, Decibels, Spectrum};
+pub use canvas_space::{CanvasSpaceExt, CanvasSpaces};
+#[cfg(all(feature = "cargo-build-plugin", since_api = "4.2"))]
+pub use cargo_build::CargoBuildPlugin;
+pub use clipboard::Clipboard;
+pub use collision_layers::{layer_name, CollisionLayers, LayerKind};
+#[cfg(feature = "debug-overlay")]
+pub(crate) use debug_overlay::metrics;
+#[cfg(feature = "debug-overlay")]
+pub use debug_overlay::{BindingMetrics, DebugOverlay};
+pub use error_context::{Context, ErrorCodeExt, ErrorContext, GodotError, GodotResult};
+#[cfg(since_api = "4.2")]
+pub use event_bus::{Event, EventBus, Subscription};
+pub use expression_eval::{Evaluation, ExpressionCache, ExpressionError, ParsedExpression};
+pub use frame_pacing::{Interpolate, Interpolated};
+pub use geometry::{Geometry2DExt, Geometry3DExt, SurfaceHit, Triangle};
+pub use headless::{is_dedicated_server, is_headless, FixedTicker};
+pub use input_map::{ActionBuilder, InputBinding, InputDevice, InputMapExt, InputSource};
+#[cfg(debug_assertions)]
+pub(crate) use instance_stats::instance_counts;
+pub use instance_stats::InstanceStats;
+pub use kinematics::{
+    along_slope_2d, along_slope_3d, CharacterBody2DExt, CharacterBody3DExt, Contact3D,
+    KinematicHit2D, KinematicHit3D, PhysicsBody2DExt, PhysicsBody3DExt, SurfaceKind,
+};
+pub use localization::TranslatableString;
+pub use main_loop::set_main_loop_type;
+pub use multimesh_buffer::{InstanceData, MultiMeshExt};
+pub use navigation::{NavigationAgentExt, NavigationQuery2D, NavigationQuery3D};
+pub use os_env::{
+    cmdline_args, cmdline_user_args, env_var, has_feature, remove_env_var, set_env_var, FeatureTag,
+    FeatureTags, FrameInfo,
+};
+pub use packed_state::{PackedField, PackedState, PackedStateError, PackedSyncExt, PackedSyncMode};
+pub use physics_query::{
+    MotionCast, PhysicsQuery2D, PhysicsQuery3D, PointQuery2D, PointQuery3D, QueryHit, RayHit2D,
+    RayHit3D, RayQuery2D, RayQuery3D, ShapeQuery2D, ShapeQuery3D,
+};
+#[cfg(since_api = "4.2")]
+pub use profiling::{PerformanceExt, ProfileScope, ScopeTiming};
+#[cfg(since_api = "4.2")]
+pub use property_binding::{BindingHandle, PropertyBinding};
+pub use res_path::ResPath;
+pub use res_uid::ResUid;
+#[cfg(feature = "rand")]
+pub use rng::{seed_global_rng, GodotGlobalRng, GodotRng};
+pub use sampling::{BakedCurve2D, BakedCurve3D, CurveSampler, GradientSampler, NoiseExt};
+pub use save_state::{SaveState, SaveStateError};
+pub use scene_snapshot::{NodeSnapshot, SnapshotDiff, SnapshotOptions, SnapshotParseError};
+pub use script_instance::{create_script_instance, ScriptInstance, ScriptMethodInfo};
+pub use script_interop::{csharp_member_name, godot_member_name, DynamicCallExt};
+pub use shader_material::{ShaderMaterialExt, ShaderUniforms};
+pub use shader_source::{GlslProgram, ShaderDiagnostic, ShaderError, ShaderSource, SourceLocation};
+pub use signal_connect::{ConnectError, ConnectExt, SignalName, TypedConnectExt};
+#[cfg(since_api = "4.2")]
+pub use signal_future::{SignalFuture, TreeAwaitExt};
+pub use skeleton_ext::{BoneId, SkeletonExt};
+#[cfg(since_api = "4.2")]
+pub use structural_defer::{DeferStructuralExt, StructuralBatch};
+pub use theme_ext::{StyleBoxFlatBuilder, ThemeExt, ThemeItem};
+pub use tile_map_ext::{AtlasSourceBuilder, TileCell, TileMapExt};
+pub use tts::{TextToSpeech, TtsVoice, Utterance, UtteranceEvent, UtteranceId};
+pub use typed_config::{ConfigError, TypedConfig};
+pub use undo_redo_ext::{UndoRedoExt, UndoRedoOps};
+pub use variant_codec::{VariantCodec, VariantDecodeError};
+pub use window_ext::{WindowBuilder, WindowExt};
+#[cfg(since_api = "4.2")]
+pub use worker_pool::{GroupHandle, PoolTask, TaskHandle};
+
+// Re-export macros.
+#[cfg(since_api = "4.2")]
+pub use crate::profile_scope;
+pub use crate::shader_source;
+pub use crate::tr;
+
+#[doc(hidden)]
+pub use localization::__tr_format;
+
+/// Support for Godot _native structures_.
+///
+/// Native structures are a niche API in Godot. These are low-level data types that are passed as pointers to/from the engine.
+/// In Rust, they are represented as `#[repr(C)]` structs.
+///
+/// There is unfortunately not much official documentation available; you may need to look at Godot source code.
+/// Most users will not need native structures, as they are very specialized.
+pub mod native {
+    pub use crate::gen::native::*;
+}
+
+/// Extension trait for convenience functions on `PackedScene`
+pub trait PackedSceneExt {
+    /// ⚠️ Instantiates the scene as type `T`, panicking if not found or bad type.
+    ///
+    /// # Panics
+    /// If the scene cannot be instantiated, or its root node is not type `T` or inherited.
+    fn instantiate_as<T>(&self) -> Gd<T>
+    where
+        T: Inherits<Node>,
+    {
+        self.try_instantiate_as::<T>()
+            .unwrap_or_else(|err| panic!("failed to instantiate scene: {err}"))
+    }
+
+    /// Instantiates the scene as type `T` (fallible).
+    ///
+    /// If the scene cannot be instantiated, or its root node is not type `T` or inherited, an error is returned. In the latter case,
+    /// the instantiated node is freed again.
+    fn try_instantiate_as<T>(&self) -> Result<Gd<T>, SceneError>
+    where
+        T: Inherits<Node>;
+}
+
+impl PackedSceneExt for PackedScene {
+    fn try_instantiate_as<T>(&self) -> Result<Gd<T>, SceneError>
+    where
+        T: Inherits<Node>,
+    {
+        let node = self.instantiate().ok_or(SceneError::Instantiate)?;
+
+        // Check before casting, as a failed cast would leak the (manually managed) node.
+        if !node.is_class(T::class_name().to_godot_string()) {
+            let actual = node.get_class();
+            node.free();
+
+            return Err(SceneError::RootType {
+                expected: T::class_name().to_string(),
+                actual: actual.to_string(),
+            });
+        }
+
+        Ok(node.cast::<T>())
+    }
+}
+
+/// Error when loading or instantiating a scene as a specific node type.
+///
+/// See [`PackedSceneExt::try_instantiate_as()`] and [`try_load_scene()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SceneError {
+    /// The resource at `path` could not be loaded, or is not a `PackedScene`.
+    Load { path: String },
+
+    /// The scene could not be instantiated, e.g. because it is empty or has broken dependencies.
+    Instantiate,
+
+    /// The root node of the scene has class `actual`, which does not inherit `expected`.
+    RootType { expected: String, actual: String },
+}
+
+impl std::fmt::Display for SceneError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Load { path } => write!(f, "cannot load PackedScene at path `{path}`"),
+            Self::Instantiate => write!(f, "PackedScene::instantiate() returned null"),
+            Self::RootType { expected, actual } => {
+                write!(
+                    f,
+                    "scene root has class `{actual}`, expected `{expected}` or derived"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for SceneError {}
+
+/// Extension trait with convenience functions for the node tree.
+pub trait NodeExt {
+    /// Retrieves the node at path `path`, panicking if not found or bad type.
+    ///
+    /// # Panics
+    /// If the node is not found, or if it does not have type `T` or inherited.
+    fn get_node_as<T>(&self, path: impl Into<NodePath>) -> Gd<T>
+    where
+        T: GodotClass + Inherits<Node>,
+    {
+        let path = path.into();
+        let copy = path.clone(); // TODO avoid copy
+
+        self.try_get_node_as(path).unwrap_or_else(|| {
+            panic!(
+                "There is no node of type {ty} path `{copy}`",
+                ty = T::class_name()
+            )
+        })
+    }
+
+    /// Retrieves the node at path `path` (fallible).
+    ///
+    /// If the node is not found, or if it does not have type `T` or inherited,
+    /// `None` will be returned.
+    fn try_get_node_as<T>(&self, path: impl Into<NodePath>) -> Option<Gd<T>>
+    where
+        T: GodotClass + Inherits<Node>;
+}
+
+impl NodeExt for Node {
+    fn try_get_node_as<T>(&self, path: impl Into<NodePath>) -> Option<Gd<T>>
+    where
+        T: GodotClass + Inherits<Node>,
+    {
+        let path = path.into();
+
+        // TODO differentiate errors (not found, bad type) with Result
+        self.get_node_or_null(path)
+            .and_then(|node| node.try_cast::<T>())
+    }
+}
+
+impl<U> NodeExt for Gd<U>
+where
+    U: GodotClass<Declarer = EngineDomain> + Inherits<Node>,
+{
+    fn try_get_node_as<T>(&self, path: impl Into<NodePath>) -> Option<Gd<T>>
+    where
+        T: GodotClass + Inherits<Node>,
+    {
+        // TODO this could be implemented without share(), but currently lacks the proper bounds
+        // This would need more sophisticated upcast design, e.g. T::upcast_{ref|mut}::<U>() for indirect relations
+        // to make the indirect Deref more explicit
+
+        let path = path.into();
+        let node = self.clone().upcast::<Node>();
+
+        <Node as NodeExt>::try_get_node_as(&*node, path)
+    }
+}
+
+/// Loads a resource from the filesystem located at `path`, panicking on error.
+///
+/// See [`try_load`] for more information.
+///
+/// # Example
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// let scene = load::<PackedScene>("res://path/to/Main.tscn");
+/// ```
+///
+/// # Panics
+/// If the resource cannot be loaded, or is not of type `T` or inherited.
+#[inline]
+pub fn load<T>(path: impl Into<GodotString>) -> Gd<T>
+where
+    T: GodotClass + Inherits<Resource>,
+{
+    let path = path.into();
+    load_impl(&path).unwrap_or_else(|| panic!("failed to load node at path `{path}`"))
+}
+
+/// Loads a resource from the filesystem located at `path`.
+///
+/// The resource is loaded on the method call (unless it's referenced already elsewhere, e.g. in another script or in the scene),
+/// which might cause slight delay, especially when loading scenes.
+///
+/// If the resource cannot be loaded, or is not of type `T` or inherited, this method returns `None`.
+///
+/// This method is a simplified version of [`ResourceLoader::load()`][crate::engine::ResourceLoader::load],
+/// which can be used for more advanced scenarios.
+///
+/// # Note:
+/// Resource paths can be obtained by right-clicking on a resource in the Godot editor (_FileSystem_ dock) and choosing "Copy Path",
+/// or by dragging the file from the _FileSystem_ dock into the script.
+///
+/// The path must be absolute (typically starting with `res://`), a local path will fail. A [`ResUid`] in its `uid://` form
+/// can be used instead of the path, and keeps working when the file is moved.
+///
+/// # Example
+/// Loads a scene called `Main` located in the `path/to` subdirectory of the Godot project and caches it in a variable.
+/// The resource is directly stored with type `PackedScene`.
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// if let Some(scene) = try_load::<PackedScene>("res://path/to/Main.tscn") {
+///     // all good
+/// } else {
+///     // handle error
+/// }
+/// ```
+// TODO Result to differentiate 2 errors
+#[inline]
+pub fn try_load<T>(path: impl Into<GodotString>) -> Option<Gd<T>>
+where
+    T: GodotClass + Inherits<Resource>,
+{
+    load_impl(&path.into())
+}
+
+/// ⚠️ Loads the scene at `path` and instantiates it as type `T`.
+///
+/// This combines [`load::<PackedScene>()`][load] and [`PackedSceneExt::instantiate_as()`].
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{load_scene, CharacterBody2D};
+///
+/// let player = load_scene::<CharacterBody2D>("res://scenes/Player.tscn");
+/// ```
+///
+/// # Panics
+/// If the scene cannot be loaded or instantiated, or its root node is not type `T` or inherited.
+pub fn load_scene<T>(path: impl Into<GodotString>) -> Gd<T>
+where
+    T: Inherits<Node>,
+{
+    try_load_scene::<T>(path).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Loads the scene at `path` and instantiates it as type `T` (fallible).
+///
+/// See [`SceneError`] for the possible errors.
+pub fn try_load_scene<T>(path: impl Into<GodotString>) -> Result<Gd<T>, SceneError>
+where
+    T: Inherits<Node>,
+{
+    let path = path.into();
+    let scene = load_impl::<PackedScene>(&path).ok_or_else(|| SceneError::Load {
+        path: path.to_string(),
+    })?;
+
+    scene.try_instantiate_as::<T>()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Utilities for crate
+
+pub(crate) fn debug_string<T: GodotClass>(
+    obj: &Gd<T>,
+    f: &mut std::fmt::Formatter<'_>,
+    ty: &str,
+) -> std::fmt::Result {
+    if let Some(id) = obj.instance_id_or_none() {
+        let class: GodotString = obj.raw.as_object(|obj| Object::get_class(obj));
+        write!(f, "{ty} {{ id: {id}, class: {class} }}")
+    } else {
+        write!(f, "{ty} {{ freed obj }}")
+    }
+}
+
+pub(crate) fn display_string<T: GodotClass>(
+    obj: &Gd<T>,
+    f: &mut std::fmt::Formatter<'_>,
+) -> std::fmt::Result {
+    let string: GodotString = obj.raw.as_object(Object::to_string);
+    <GodotString as std::fmt::Display>::fmt(&string, f)
+}
+
+pub(crate) fn object_ptr_from_id(instance_id: InstanceId) -> sys::GDExtensionObjectPtr {
+    // SAFETY: Godot looks up ID in ObjectDB and returns null if not found.
+    unsafe { sys::interface_fn!(object_get_instance_from_id)(instance_id.to_u64()) }
+}
+
+pub(crate) fn ensure_object_alive(
+    instance_id: Option<InstanceId>,
+    old_object_ptr: sys::GDExtensionObjectPtr,
+    method_name: &'static str,
+) {
+    let Some(instance_id) = instance_id else {
+        panic!("{method_name}: cannot call method on null object")
+    };
+
+    let new_object_ptr = object_ptr_from_id(instance_id);
+
+    assert!(
+        !new_object_ptr.is_null(),
+        "{method_name}: access to instance with ID {instance_id} after it has been freed"
+    );
+
+    // This should not happen, as reuse of instance IDs was fixed according to https://github.com/godotengine/godot/issues/32383,
+    // namely in PR https://github.com/godotengine/godot/pull/36189. Double-check to make sure.
+    assert_eq!(
+        new_object_ptr, old_object_ptr,
+        "{method_name}: instance ID {instance_id} points to a stale, reused object. Please report this to gdext maintainers."
+    );
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+// Separate function, to avoid constructing string twice
+// Note that more optimizations than that likely make no sense, as loading is quite expensive
+fn load_impl<T>(path: &GodotString) -> Option<Gd<T>>
+where
+    T: GodotClass + Inherits<Resource>,
+{
+    // Godot resolves UIDs itself, but prints an error for unknown ones; resolving them here lets try_load() fail quietly.
+    let path = res_uid::resolve_uid_path(path)?;
+
+    ResourceLoader::singleton()
+        .load_ex(path)
+        .type_hint(T::class_name().to_godot_string())
+        .done()
+        .and_then(|res| res.try_cast::<T>())
+}
@@ -0,0 +1,194 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Color, PackedFloat32Array, RealConv, Transform2D, Transform3D};
+use crate::engine::multi_mesh::TransformFormat;
+use crate::engine::MultiMesh;
+use crate::obj::Gd;
+
+/// Transform and optional per-instance data of one `MultiMesh` instance, for [`MultiMeshExt`].
+///
+/// Converts from a plain `Transform2D` or `Transform3D`, for multimeshes without colors or custom data.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct InstanceData<T> {
+    /// Placement of the instance, relative to the `MultiMeshInstance` node.
+    pub transform: T,
+
+    /// Instance color, only uploaded if the multimesh uses colors; white by default.
+    pub color: Color,
+
+    /// Four arbitrary values for shaders (`INSTANCE_CUSTOM`), only uploaded if the multimesh uses custom data; all zero by default.
+    pub custom_data: Color,
+}
+
+impl<T> InstanceData<T> {
+    /// Instance at `transform`, with default color and custom data.
+    pub fn new(transform: T) -> Self {
+        Self {
+            transform,
+            color: Color::WHITE,
+            custom_data: Color::from_rgba(0.0, 0.0, 0.0, 0.0),
+        }
+    }
+
+    /// Sets the instance color.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Sets the custom data, read as `INSTANCE_CUSTOM` in shaders.
+    pub fn with_custom_data(mut self, custom_data: Color) -> Self {
+        self.custom_data = custom_data;
+        self
+    }
+}
+
+impl<T> From<T> for InstanceData<T> {
+    fn from(transform: T) -> Self {
+        Self::new(transform)
+    }
+}
+
+/// Extension trait for `MultiMesh`, uploading all instances in a single buffer write.
+///
+/// Setting instances one by one with `set_instance_transform()` costs an engine call (and a GPU buffer update) each, which quickly
+/// dominates the frame for tens of thousands of instances. These methods pack all instances into one `PackedFloat32Array` in the
+/// layout expected by `MultiMesh.buffer`, and set it at once.
+///
+/// The instance count is adjusted to the number of uploaded instances. Colors and custom data are only written if enabled with
+/// `use_colors` and `use_custom_data`; these and `transform_format` must be set before the first upload.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::multi_mesh::TransformFormat;
+/// use godot::engine::{InstanceData, MultiMesh, MultiMeshExt};
+///
+/// let mut multimesh = MultiMesh::new();
+/// multimesh.set_transform_format(TransformFormat::TRANSFORM_3D);
+/// multimesh.set_use_colors(true);
+///
+/// multimesh.upload_3d((0..10_000).map(|i| {
+///     let position = Vector3::new((i % 100) as real, 0.0, (i / 100) as real);
+///     InstanceData::new(Transform3D::IDENTITY.translated(position))
+///         .with_color(Color::from_rgba(0.2, 0.8, 0.2, 1.0))
+/// }));
+/// ```
+pub trait MultiMeshExt {
+    /// ⚠️ Replaces all instances of a multimesh using `TransformFormat::TRANSFORM_3D`.
+    ///
+    /// # Panics
+    /// If the multimesh uses 2D transforms.
+    fn upload_3d<I>(&mut self, instances: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<InstanceData<Transform3D>>;
+
+    /// ⚠️ Replaces all instances of a multimesh using `TransformFormat::TRANSFORM_2D`.
+    ///
+    /// # Panics
+    /// If the multimesh uses 3D transforms.
+    fn upload_2d<I>(&mut self, instances: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<InstanceData<Transform2D>>;
+}
+
+impl MultiMeshExt for Gd<MultiMesh> {
+    fn upload_3d<I>(&mut self, instances: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<InstanceData<Transform3D>>,
+    {
+        let instances = instances.into_iter().map(Into::into);
+
+        upload(
+            self,
+            TransformFormat::TRANSFORM_3D,
+            12,
+            instances,
+            |t, buffer| {
+                // Rows of the 3x4 matrix: basis row followed by the matching origin component.
+                for (row, origin) in t
+                    .basis
+                    .rows
+                    .iter()
+                    .zip([t.origin.x, t.origin.y, t.origin.z])
+                {
+                    buffer.extend([row.x, row.y, row.z, origin].map(RealConv::as_f32));
+                }
+            },
+        );
+    }
+
+    fn upload_2d<I>(&mut self, instances: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<InstanceData<Transform2D>>,
+    {
+        let instances = instances.into_iter().map(Into::into);
+
+        upload(
+            self,
+            TransformFormat::TRANSFORM_2D,
+            8,
+            instances,
+            |t, buffer| {
+                // Same 2x4 row layout as in 3D, with an unused third column.
+                buffer.extend([t.a.x, t.b.x, 0.0, t.origin.x].map(RealConv::as_f32));
+                buffer.extend([t.a.y, t.b.y, 0.0, t.origin.y].map(RealConv::as_f32));
+            },
+        );
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+fn upload<T>(
+    multimesh: &mut Gd<MultiMesh>,
+    format: TransformFormat,
+    transform_floats: usize,
+    instances: impl Iterator<Item = InstanceData<T>>,
+    write_transform: impl Fn(&T, &mut Vec<f32>),
+) {
+    let actual_format = multimesh.get_transform_format();
+    assert_eq!(
+        actual_format, format,
+        "multimesh has transform format {actual_format:?}, cannot upload {format:?} instances"
+    );
+
+    let use_colors = multimesh.is_using_colors();
+    let use_custom_data = multimesh.is_using_custom_data();
+    let stride = transform_floats + 4 * usize::from(use_colors) + 4 * usize::from(use_custom_data);
+
+    let mut buffer = Vec::with_capacity(instances.size_hint().0 * stride);
+    let mut count: usize = 0;
+    for instance in instances {
+        write_transform(&instance.transform, &mut buffer);
+
+        if use_colors {
+            push_color(&mut buffer, instance.color);
+        }
+        if use_custom_data {
+            push_color(&mut buffer, instance.custom_data);
+        }
+
+        count += 1;
+    }
+
+    let count = i32::try_from(count).expect("too many multimesh instances");
+    if multimesh.get_instance_count() != count {
+        multimesh.set_instance_count(count);
+    }
+
+    multimesh.set_buffer(PackedFloat32Array::from(buffer));
+}
+
+fn push_color(buffer: &mut Vec<f32>, color: Color) {
+    buffer.extend([color.r, color.g, color.b, color.a]);
+}
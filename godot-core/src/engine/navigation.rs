@@ -0,0 +1,246 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Rid, Vector2, Vector3};
+use crate::engine::{
+    CanvasItem, NavigationAgent2D, NavigationAgent3D, NavigationServer2D, NavigationServer3D,
+    Node3D,
+};
+use crate::obj::{Gd, Inherits};
+
+#[cfg(since_api = "4.2")]
+use crate::builtin::{Callable, Variant};
+#[cfg(since_api = "4.2")]
+use crate::engine::SignalFuture;
+
+macro_rules! impl_navigation_query {
+    (
+        dim: $dim:literal,
+        query: $Query:ident,
+        server: $Server:ident,
+        vector: $Vector:ident
+    ) => {
+        #[doc = concat!("Path queries on a ", $dim, " navigation map, returning plain `Vec`s instead of packed arrays.")]
+        ///
+        /// The query is a lightweight description of the map and the path options; creating it does not call into the engine.
+        #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+        pub struct $Query {
+            map: Rid,
+            optimize: bool,
+            navigation_layers: u32,
+        }
+
+        impl $Query {
+            #[doc = concat!("Queries the map `map`, as returned by `", stringify!($Server), ".map_create()` or `World", $dim, ".navigation_map`.")]
+            pub fn new(map: Rid) -> Self {
+                Self {
+                    map,
+                    optimize: true,
+                    navigation_layers: 1,
+                }
+            }
+
+            /// Whether paths are shortened along the polygon edges (funnel algorithm); `true` by default.
+            ///
+            /// Without optimization, paths go through the edge centers of the traversed polygons.
+            pub fn with_optimize(mut self, optimize: bool) -> Self {
+                self.optimize = optimize;
+                self
+            }
+
+            /// Only uses regions in one of the navigation layers of `layers`; layer 1 by default.
+            pub fn with_navigation_layers(mut self, layers: u32) -> Self {
+                self.navigation_layers = layers;
+                self
+            }
+
+            /// The navigation map this query runs on.
+            pub fn map(&self) -> Rid {
+                self.map
+            }
+
+            /// Computes a path from `from` to `to`, in global coordinates.
+            ///
+            /// The path is empty if the map has no navigation mesh between the two points, for example because it has not been
+            /// synchronized yet (see [`map_changed()`][Self::map_changed]).
+            pub fn path(&self, from: $Vector, to: $Vector) -> Vec<$Vector> {
+                $Server::singleton()
+                    .map_get_path_ex(self.map, from, to, self.optimize)
+                    .navigation_layers(self.navigation_layers)
+                    .done()
+                    .to_vec()
+            }
+
+            /// Returns the point on the navigation mesh closest to `point`.
+            pub fn closest_point(&self, point: $Vector) -> $Vector {
+                $Server::singleton().map_get_closest_point(self.map, point)
+            }
+
+            #[doc = concat!("Future that completes after the next synchronization of any ", $dim, " navigation map.")]
+            ///
+            /// Changes to regions and maps take effect on synchronization, which happens once per physics frame. Await this
+            /// before querying paths on a map that was just set up.
+            #[cfg(since_api = "4.2")]
+            pub fn map_changed(&self) -> SignalFuture {
+                SignalFuture::new($Server::singleton().upcast(), "map_changed")
+            }
+        }
+    };
+}
+
+impl_navigation_query! {
+    dim: "2D",
+    query: NavigationQuery2D,
+    server: NavigationServer2D,
+    vector: Vector2
+}
+
+impl_navigation_query! {
+    dim: "3D",
+    query: NavigationQuery3D,
+    server: NavigationServer3D,
+    vector: Vector3
+}
+
+impl NavigationQuery2D {
+    /// ⚠️ Creates a query for the default navigation map of the 2D world of `node`.
+    ///
+    /// # Panics
+    /// If `node` is not inside the scene tree.
+    pub fn for_node<T: Inherits<CanvasItem>>(node: &Gd<T>) -> Self {
+        let world = node
+            .clone()
+            .upcast::<CanvasItem>()
+            .get_world_2d()
+            .expect("node has no 2D world; is it inside the scene tree?");
+
+        Self::new(world.get_navigation_map())
+    }
+}
+
+impl NavigationQuery3D {
+    /// ⚠️ Creates a query for the default navigation map of the 3D world of `node`.
+    ///
+    /// # Panics
+    /// If `node` is not inside the scene tree.
+    pub fn for_node<T: Inherits<Node3D>>(node: &Gd<T>) -> Self {
+        let world = node
+            .clone()
+            .upcast::<Node3D>()
+            .get_world_3d()
+            .expect("node has no 3D world; is it inside the scene tree?");
+
+        Self::new(world.get_navigation_map())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Extension trait for `NavigationAgent2D` and `NavigationAgent3D`, with typed paths and signal helpers.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{NavigationAgent2D, NavigationAgentExt};
+///
+/// fn print_route(agent: &Gd<NavigationAgent2D>) {
+///     for waypoint in agent.remaining_path() {
+///         godot_print!("next: {waypoint}");
+///     }
+/// }
+/// ```
+pub trait NavigationAgentExt {
+    /// `Vector2` or `Vector3`, depending on the agent.
+    type Vector;
+
+    /// The full path the agent currently follows, in global coordinates.
+    fn current_path(&self) -> Vec<Self::Vector>;
+
+    /// The waypoints of the current path that the agent has not reached yet, starting with the next one.
+    fn remaining_path(&self) -> Vec<Self::Vector>;
+
+    /// Future that completes the next time the agent's path is recomputed (signal `path_changed`).
+    ///
+    /// Paths are computed lazily on the physics frame after the target changed, so await this before reading the new path:
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # use godot::engine::{NavigationAgent2D, NavigationAgentExt};
+    /// async fn walk_to(mut agent: Gd<NavigationAgent2D>, target: Vector2) {
+    ///     agent.set_target_position(target);
+    ///     agent.path_changed().await;
+    ///     godot_print!("route: {:?}", agent.current_path());
+    ///
+    ///     agent.navigation_finished().await;
+    /// }
+    /// ```
+    #[cfg(since_api = "4.2")]
+    fn path_changed(&self) -> SignalFuture;
+
+    /// Future that completes when the agent reaches the end of its path (signal `navigation_finished`).
+    #[cfg(since_api = "4.2")]
+    fn navigation_finished(&self) -> SignalFuture;
+
+    /// Invokes `callback` with the safe velocity computed by avoidance (signal `velocity_computed`).
+    ///
+    /// The callback stays connected as long as the agent lives.
+    #[cfg(since_api = "4.2")]
+    fn on_velocity_computed<F>(&mut self, callback: F)
+    where
+        F: FnMut(Self::Vector) + Send + Sync + 'static;
+}
+
+macro_rules! impl_navigation_agent_ext {
+    ($Agent:ident, $Vector:ident) => {
+        impl NavigationAgentExt for Gd<$Agent> {
+            type Vector = $Vector;
+
+            fn current_path(&self) -> Vec<$Vector> {
+                self.get_current_navigation_path().to_vec()
+            }
+
+            fn remaining_path(&self) -> Vec<$Vector> {
+                let path = self.get_current_navigation_path();
+                let next_index = self.get_current_navigation_path_index().max(0) as usize;
+
+                path.as_slice()
+                    .get(next_index..)
+                    .unwrap_or_default()
+                    .to_vec()
+            }
+
+            #[cfg(since_api = "4.2")]
+            fn path_changed(&self) -> SignalFuture {
+                SignalFuture::new(self.clone().upcast(), "path_changed")
+            }
+
+            #[cfg(since_api = "4.2")]
+            fn navigation_finished(&self) -> SignalFuture {
+                SignalFuture::new(self.clone().upcast(), "navigation_finished")
+            }
+
+            #[cfg(since_api = "4.2")]
+            fn on_velocity_computed<F>(&mut self, mut callback: F)
+            where
+                F: FnMut($Vector) + Send + Sync + 'static,
+            {
+                let name = concat!(stringify!($Agent), "::velocity_computed");
+                let callable = Callable::from_fn(name, move |args: &[&Variant]| {
+                    let [velocity] = args else {
+                        return Err(());
+                    };
+
+                    callback(velocity.try_to::<$Vector>().map_err(|_| ())?);
+                    Ok(Variant::nil())
+                });
+
+                self.connect("velocity_computed".into(), callable);
+            }
+        }
+    };
+}
+
+impl_navigation_agent_ext!(NavigationAgent2D, Vector2);
+impl_navigation_agent_ext!(NavigationAgent3D, Vector3);
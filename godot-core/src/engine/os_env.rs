@@ -0,0 +1,290 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::PackedStringArray;
+use crate::engine::{Engine, Os};
+
+/// The command-line arguments of the engine, without the program name and without user arguments.
+///
+/// Godot consumes options like `--headless` itself, but they are listed here as well. See [`cmdline_user_args()`] for the arguments
+/// meant for the game.
+pub fn cmdline_args() -> Vec<String> {
+    to_strings(Os::singleton().get_cmdline_args())
+}
+
+/// The user arguments: everything after a standalone `--` (or `++`) on the command line.
+///
+/// ```text
+/// godot --headless -- --port 9000 --map arena
+/// ```
+/// yields `["--port", "9000", "--map", "arena"]`.
+pub fn cmdline_user_args() -> Vec<String> {
+    to_strings(Os::singleton().get_cmdline_user_args())
+}
+
+/// The value of the environment variable `name`, or `None` if it is not set.
+///
+/// Unlike `std::env::var()`, this goes through Godot, which matters on platforms where Godot provides its own environment (such as
+/// Android and the web).
+pub fn env_var(name: &str) -> Option<String> {
+    let os = Os::singleton();
+
+    os.has_environment(name.into())
+        .then(|| os.get_environment(name.into()).to_string())
+}
+
+/// Sets the environment variable `name` to `value`, for this process and the processes it starts.
+///
+/// # Panics
+/// If `name` is empty or contains `=`.
+pub fn set_env_var(name: &str, value: &str) {
+    assert!(
+        !name.is_empty() && !name.contains('='),
+        "invalid environment variable name `{name}`"
+    );
+
+    Os::singleton().set_environment(name.into(), value.into());
+}
+
+/// Removes the environment variable `name`, if set.
+pub fn remove_env_var(name: &str) {
+    Os::singleton().unset_environment(name.into());
+}
+
+/// Whether the feature tag `name` is active, including custom tags defined in the export preset.
+///
+/// For the standard tags, [`FeatureTag`] and [`FeatureTags`] avoid typos in tag names.
+pub fn has_feature(name: &str) -> bool {
+    Os::singleton().has_feature(name.into())
+}
+
+fn to_strings(array: PackedStringArray) -> Vec<String> {
+    array.as_slice().iter().map(|arg| arg.to_string()).collect()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+macro_rules! feature_tags {
+    ($( $(#[$attr:meta])* $Tag:ident => $name:literal, )*) => {
+        /// Standard feature tag, describing the platform or build of the running binary.
+        ///
+        /// See the [Godot documentation](https://docs.godotengine.org/en/stable/tutorials/export/feature_tags.html) for details.
+        #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+        #[repr(u8)]
+        pub enum FeatureTag {
+            $( $(#[$attr])* $Tag, )*
+        }
+
+        impl FeatureTag {
+            /// All standard tags, in declaration order.
+            pub const ALL: &'static [FeatureTag] = &[ $( FeatureTag::$Tag, )* ];
+
+            /// The tag as passed to `OS.has_feature()`.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $( FeatureTag::$Tag => $name, )*
+                }
+            }
+        }
+    };
+}
+
+feature_tags! {
+    /// Running in the editor, or a build with editor capabilities.
+    Editor => "editor",
+    /// Running an export template (exported project).
+    Template => "template",
+    /// Debug build, i.e. the editor or a debug export template.
+    Debug => "debug",
+    /// Release export template.
+    Release => "release",
+    /// Engine built with double-precision floats.
+    Double => "double",
+    /// Engine built with single-precision floats.
+    Single => "single",
+    /// Desktop platform.
+    Pc => "pc",
+    /// Mobile platform.
+    Mobile => "mobile",
+    /// Web browser, on any host OS.
+    Web => "web",
+    /// Windows.
+    Windows => "windows",
+    /// macOS.
+    Macos => "macos",
+    /// Linux.
+    Linux => "linux",
+    /// BSD.
+    Bsd => "bsd",
+    /// Android.
+    Android => "android",
+    /// iOS.
+    Ios => "ios",
+    /// 64-bit x86 CPU.
+    X86_64 => "x86_64",
+    /// 32-bit x86 CPU.
+    X86_32 => "x86_32",
+    /// 64-bit ARM CPU.
+    Arm64 => "arm64",
+    /// 32-bit ARM CPU.
+    Arm32 => "arm32",
+    /// 64-bit RISC-V CPU.
+    Rv64 => "rv64",
+    /// 32-bit WebAssembly.
+    Wasm32 => "wasm32",
+    /// Exported as a dedicated server.
+    DedicatedServer => "dedicated_server",
+    /// Recording with Movie Maker mode.
+    Movie => "movie",
+}
+
+impl FeatureTag {
+    /// Whether this tag is active in the running binary.
+    pub fn is_active(self) -> bool {
+        has_feature(self.name())
+    }
+}
+
+impl fmt::Display for FeatureTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Set of [`FeatureTag`]s.
+///
+/// [`current()`][Self::current] queries all standard tags at once, so repeated checks do not call into the engine:
+/// ```no_run
+/// use godot::engine::{FeatureTag, FeatureTags};
+///
+/// let features = FeatureTags::current();
+/// if features.contains(FeatureTag::Mobile) && !features.contains(FeatureTag::Debug) {
+///     // Reduce effects on release builds for phones.
+/// }
+/// ```
+#[derive(Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct FeatureTags {
+    bits: u32,
+}
+
+impl FeatureTags {
+    /// The empty set.
+    pub const fn new() -> Self {
+        Self { bits: 0 }
+    }
+
+    /// The standard tags active in the running binary.
+    pub fn current() -> Self {
+        FeatureTag::ALL
+            .iter()
+            .copied()
+            .filter(|tag| tag.is_active())
+            .collect()
+    }
+
+    /// Whether `tag` is in the set.
+    pub fn contains(&self, tag: FeatureTag) -> bool {
+        self.bits & Self::bit(tag) != 0
+    }
+
+    /// Adds `tag` to the set.
+    pub fn insert(&mut self, tag: FeatureTag) {
+        self.bits |= Self::bit(tag);
+    }
+
+    /// Removes `tag` from the set.
+    pub fn remove(&mut self, tag: FeatureTag) {
+        self.bits &= !Self::bit(tag);
+    }
+
+    /// Whether the set contains no tags.
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// The number of tags in the set.
+    pub fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Iterates over the tags in the set, in declaration order of [`FeatureTag`].
+    pub fn iter(&self) -> impl Iterator<Item = FeatureTag> + '_ {
+        FeatureTag::ALL
+            .iter()
+            .copied()
+            .filter(|&tag| self.contains(tag))
+    }
+
+    fn bit(tag: FeatureTag) -> u32 {
+        1 << tag as u8
+    }
+}
+
+impl FromIterator<FeatureTag> for FeatureTags {
+    fn from_iter<I: IntoIterator<Item = FeatureTag>>(iter: I) -> Self {
+        let mut tags = Self::new();
+        for tag in iter {
+            tags.insert(tag);
+        }
+        tags
+    }
+}
+
+impl fmt::Debug for FeatureTags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Snapshot of the engine's frame counters and timing settings, read from the `Engine` singleton.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FrameInfo {
+    /// Number of frames rendered since startup; stays 0 in headless mode.
+    pub frames_drawn: i32,
+
+    /// Number of process (idle) frames since startup.
+    pub process_frames: u64,
+
+    /// Number of physics ticks since startup.
+    pub physics_frames: u64,
+
+    /// Frames rendered per second, averaged over the last second.
+    pub frames_per_second: f64,
+
+    /// Fixed rate of physics ticks.
+    pub physics_ticks_per_second: i32,
+
+    /// Speed of game time relative to real time; 1.0 by default.
+    pub time_scale: f64,
+
+    /// Whether the current code runs inside a physics tick.
+    pub in_physics_frame: bool,
+
+    /// Progress between the last two physics ticks, from 0 to 1; used for interpolation in process frames.
+    pub physics_interpolation_fraction: f64,
+}
+
+impl FrameInfo {
+    /// Reads the current values.
+    pub fn current() -> Self {
+        let engine = Engine::singleton();
+
+        Self {
+            frames_drawn: engine.get_frames_drawn(),
+            process_frames: engine.get_process_frames(),
+            physics_frames: engine.get_physics_frames(),
+            frames_per_second: engine.get_frames_per_second(),
+            physics_ticks_per_second: engine.get_physics_ticks_per_second(),
+            time_scale: engine.get_time_scale(),
+            in_physics_frame: engine.is_in_physics_frame(),
+            physics_interpolation_fraction: engine.get_physics_interpolation_fraction(),
+        }
+    }
+}
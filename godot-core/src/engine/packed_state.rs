@@ -0,0 +1,352 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::{
+    real, Color, NodePath, PackedByteArray, Quaternion, Vector2, Vector2i, Vector3, Vector3i,
+    Vector4, Vector4i,
+};
+use crate::engine::{MultiplayerSynchronizer, SceneReplicationConfig};
+use crate::obj::{Gd, GodotClass, Inherits};
+
+/// State of fixed size that is packed into a `PackedByteArray`, e.g. to replicate it over the network.
+///
+/// This trait is usually derived with `#[derive(PackedState)]`, for structs with named fields whose types implement
+/// [`PackedField`]. The layout is stable: a version byte, followed by each field in declaration order, little-endian and without
+/// padding. The version is set with `#[packed(version = N)]` on the struct (0 by default), and should be increased whenever
+/// the fields change, so that peers running different builds reject each other's data instead of misreading it. Fields
+/// annotated with `#[packed(skip)]` are not packed and set to `Default::default()` when unpacking.
+///
+/// Components of vector types are [`real`], so builds with and without the `double-precision` feature have different layouts.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::PackedState;
+///
+/// #[derive(PackedState)]
+/// #[packed(version = 1)]
+/// struct PlayerState {
+///     position: Vector2,
+///     health: u16,
+///     is_crouching: bool,
+///     #[packed(skip)]
+///     predicted: bool,
+/// }
+///
+/// // 1 version byte + 8 (position) + 2 (health) + 1 (is_crouching).
+/// assert_eq!(PlayerState::SIZE, 11);
+/// ```
+///
+/// To replicate such state with a `MultiplayerSynchronizer`, expose it as a `PackedByteArray` property and register that property
+/// with [`PackedSyncExt::add_packed_property()`].
+pub trait PackedState: Sized {
+    /// Version byte at the start of the packed data.
+    const VERSION: u8;
+
+    /// Size of the packed data in bytes, including the version byte.
+    const SIZE: usize;
+
+    /// Appends the fields, without version byte, to `bytes`.
+    fn pack_fields(&self, bytes: &mut Vec<u8>);
+
+    /// Reads the fields from `bytes`, which contains exactly `SIZE - 1` bytes.
+    fn unpack_fields(bytes: &[u8]) -> Result<Self, PackedStateError>;
+
+    /// Packs the state into a byte array of [`SIZE`][Self::SIZE] bytes.
+    fn pack(&self) -> PackedByteArray {
+        let mut bytes = Vec::with_capacity(Self::SIZE);
+        bytes.push(Self::VERSION);
+        self.pack_fields(&mut bytes);
+
+        debug_assert_eq!(bytes.len(), Self::SIZE, "packed state has wrong size");
+        PackedByteArray::from(bytes.as_slice())
+    }
+
+    /// Unpacks a state from `bytes`, as produced by [`pack()`][Self::pack].
+    fn unpack(bytes: &PackedByteArray) -> Result<Self, PackedStateError> {
+        let bytes = bytes.as_slice();
+
+        let Some((&version, fields)) = bytes.split_first() else {
+            return Err(PackedStateError::WrongSize {
+                expected: Self::SIZE,
+                found: 0,
+            });
+        };
+
+        if version != Self::VERSION {
+            return Err(PackedStateError::VersionMismatch {
+                expected: Self::VERSION,
+                found: version,
+            });
+        }
+
+        if bytes.len() != Self::SIZE {
+            return Err(PackedStateError::WrongSize {
+                expected: Self::SIZE,
+                found: bytes.len(),
+            });
+        }
+
+        Self::unpack_fields(fields)
+    }
+
+    /// Unpacks `bytes` into `self`; on error, `self` is left unchanged.
+    ///
+    /// Convenient in the setter of the replicated property, which should not panic on malformed data from other peers.
+    fn unpack_into(&mut self, bytes: &PackedByteArray) -> Result<(), PackedStateError> {
+        *self = Self::unpack(bytes)?;
+        Ok(())
+    }
+}
+
+/// Error while unpacking a [`PackedState`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PackedStateError {
+    /// The data was packed with a different version of the state.
+    VersionMismatch { expected: u8, found: u8 },
+
+    /// The data has a different size than the state, including the version byte.
+    WrongSize { expected: usize, found: usize },
+
+    /// The bytes of `field` are not a valid value of its type, e.g. a `bool` that is neither 0 nor 1.
+    InvalidField { field: &'static str },
+}
+
+impl fmt::Display for PackedStateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::VersionMismatch { expected, found } => {
+                write!(f, "packed state has version {found}, expected {expected}")
+            }
+            Self::WrongSize { expected, found } => {
+                write!(f, "packed state has {found} bytes, expected {expected}")
+            }
+            Self::InvalidField { field } => write!(f, "invalid packed value for `{field}`"),
+        }
+    }
+}
+
+impl std::error::Error for PackedStateError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Value of fixed size that can be a field of a [`PackedState`].
+///
+/// Implemented for `bool`, integers, floats, the vector types, `Quaternion`, `Color`, and arrays of these.
+pub trait PackedField: Sized {
+    /// Size of the packed value in bytes.
+    const SIZE: usize;
+
+    /// Appends the little-endian bytes of the value to `bytes`.
+    fn write(&self, bytes: &mut Vec<u8>);
+
+    /// Reads a value from exactly [`SIZE`][Self::SIZE] bytes, or returns `None` if they do not form a valid value.
+    fn read(bytes: &[u8]) -> Option<Self>;
+}
+
+impl PackedField for bool {
+    const SIZE: usize = 1;
+
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.push(u8::from(*self));
+    }
+
+    fn read(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            [0] => Some(false),
+            [1] => Some(true),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_packed_number {
+    ($($Number:ty),* $(,)?) => {
+        $(
+            impl PackedField for $Number {
+                const SIZE: usize = std::mem::size_of::<$Number>();
+
+                fn write(&self, bytes: &mut Vec<u8>) {
+                    bytes.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn read(bytes: &[u8]) -> Option<Self> {
+                    bytes.try_into().ok().map(<$Number>::from_le_bytes)
+                }
+            }
+        )*
+    };
+}
+
+impl_packed_number!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+macro_rules! impl_packed_components {
+    ($($Type:ty: $Component:ty => { $($field:ident),+ }),* $(,)?) => {
+        $(
+            impl PackedField for $Type {
+                const SIZE: usize = <$Component as PackedField>::SIZE * [$(stringify!($field)),+].len();
+
+                fn write(&self, bytes: &mut Vec<u8>) {
+                    $( self.$field.write(bytes); )+
+                }
+
+                fn read(bytes: &[u8]) -> Option<Self> {
+                    let mut chunks = bytes.chunks_exact(<$Component as PackedField>::SIZE);
+                    let mut value = <$Type>::default();
+                    $( value.$field = <$Component>::read(chunks.next()?)?; )+
+
+                    Some(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_packed_components!(
+    Vector2: real => { x, y },
+    Vector3: real => { x, y, z },
+    Vector4: real => { x, y, z, w },
+    Vector2i: i32 => { x, y },
+    Vector3i: i32 => { x, y, z },
+    Vector4i: i32 => { x, y, z, w },
+    Quaternion: real => { x, y, z, w },
+    Color: f32 => { r, g, b, a },
+);
+
+impl<T: PackedField, const N: usize> PackedField for [T; N] {
+    const SIZE: usize = T::SIZE * N;
+
+    fn write(&self, bytes: &mut Vec<u8>) {
+        for element in self {
+            element.write(bytes);
+        }
+    }
+
+    fn read(bytes: &[u8]) -> Option<Self> {
+        let elements: Vec<T> = bytes
+            .chunks_exact(T::SIZE)
+            .map(T::read)
+            .collect::<Option<_>>()?;
+
+        elements.try_into().ok()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// How a property registered with [`PackedSyncExt::add_packed_property()`] is replicated.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum PackedSyncMode {
+    /// Only sent when the node is spawned on other peers.
+    SpawnOnly,
+
+    /// Sent on spawn, and in every synchronization interval.
+    Always,
+
+    /// Sent on spawn, and in the delta interval whenever the value changed.
+    ///
+    /// Requires Godot 4.1; before that, behaves like [`Always`][Self::Always].
+    OnChange,
+}
+
+/// Extension methods for `MultiplayerSynchronizer`, to replicate [`PackedState`] properties.
+pub trait PackedSyncExt {
+    /// Adds `property` to the replication config, creating the config if the synchronizer has none.
+    ///
+    /// `property` is relative to the synchronizer's root path, e.g. `".:net_state"` for the property `net_state` of the root
+    /// node. It is typically a `PackedByteArray` whose getter calls [`PackedState::pack()`] and whose setter calls
+    /// [`PackedState::unpack_into()`]. If the property is already replicated, only its mode is changed.
+    fn add_packed_property(&mut self, property: impl Into<NodePath>, mode: PackedSyncMode);
+}
+
+impl<U> PackedSyncExt for Gd<U>
+where
+    U: GodotClass + Inherits<MultiplayerSynchronizer>,
+{
+    fn add_packed_property(&mut self, property: impl Into<NodePath>, mode: PackedSyncMode) {
+        let mut synchronizer = self.clone().upcast::<MultiplayerSynchronizer>();
+        let mut config = synchronizer.get_replication_config().unwrap_or_else(|| {
+            let config = SceneReplicationConfig::new();
+            synchronizer.set_replication_config(config.clone());
+            config
+        });
+
+        let property = property.into();
+        if !config.has_property(property.clone()) {
+            config.add_property(property.clone());
+        }
+
+        config.property_set_spawn(property.clone(), true);
+        set_sync_mode(&mut config, property, mode);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+#[cfg(since_api = "4.1")]
+fn set_sync_mode(
+    config: &mut Gd<SceneReplicationConfig>,
+    property: NodePath,
+    mode: PackedSyncMode,
+) {
+    config.property_set_sync(property.clone(), mode == PackedSyncMode::Always);
+    config.property_set_watch(property, mode == PackedSyncMode::OnChange);
+}
+
+#[cfg(before_api = "4.1")]
+fn set_sync_mode(
+    config: &mut Gd<SceneReplicationConfig>,
+    property: NodePath,
+    mode: PackedSyncMode,
+) {
+    config.property_set_sync(property, mode != PackedSyncMode::SpawnOnly);
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::PackedField;
+    use crate::builtin::{Color, Vector2i, Vector3};
+
+    fn round_trip<T: PackedField + PartialEq + std::fmt::Debug>(value: T) {
+        let mut bytes = Vec::new();
+        value.write(&mut bytes);
+
+        assert_eq!(bytes.len(), T::SIZE);
+        assert_eq!(T::read(&bytes), Some(value));
+    }
+
+    #[test]
+    fn packed_field_round_trip() {
+        round_trip(true);
+        round_trip(-12345i32);
+        round_trip(u64::MAX);
+        round_trip(0.25f64);
+        round_trip(Vector3::new(1.0, -2.5, 3.0));
+        round_trip(Vector2i::new(-7, 9));
+        round_trip(Color::from_rgba(0.1, 0.2, 0.3, 1.0));
+        round_trip([3u16, 1, 4]);
+    }
+
+    #[test]
+    fn packed_field_layout() {
+        let mut bytes = Vec::new();
+        0x0102u16.write(&mut bytes);
+        Vector2i::new(1, -1).write(&mut bytes);
+
+        assert_eq!(bytes, [0x02, 0x01, 1, 0, 0, 0, 0xff, 0xff, 0xff, 0xff]);
+    }
+
+    #[test]
+    fn packed_field_invalid() {
+        assert_eq!(bool::read(&[2]), None);
+        assert_eq!(u32::read(&[1, 2]), None);
+        assert_eq!(<[bool; 2]>::read(&[1, 7]), None);
+    }
+}
@@ -0,0 +1,508 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::meta::FromGodot;
+use crate::builtin::{real, Array, Dictionary, Rid, Transform2D, Transform3D, Vector2, Vector3};
+use crate::engine::{
+    CanvasItem, Node3D, Object, PhysicsDirectSpaceState2D, PhysicsDirectSpaceState3D,
+    PhysicsPointQueryParameters2D, PhysicsPointQueryParameters3D, PhysicsRayQueryParameters2D,
+    PhysicsRayQueryParameters3D, PhysicsShapeQueryParameters2D, PhysicsShapeQueryParameters3D,
+    Resource, Shape2D, Shape3D,
+};
+use crate::obj::{Gd, Inherits, InstanceId};
+
+/// Object found by a point or shape query.
+#[derive(Clone, Debug)]
+pub struct QueryHit {
+    /// The colliding object, or `None` if it has been freed or belongs to no object (e.g. a body created on the physics server).
+    pub collider: Option<Gd<Object>>,
+
+    /// Instance ID of the colliding object.
+    pub collider_id: Option<InstanceId>,
+
+    /// RID of the colliding body or area on the physics server.
+    pub rid: Rid,
+
+    /// Index of the colliding shape within the collider.
+    pub shape_index: usize,
+}
+
+impl QueryHit {
+    /// Parses one element of the result of `intersect_point()` or `intersect_shape()`.
+    ///
+    /// Returns `None` if the dictionary lacks the `rid` entry.
+    pub fn from_dictionary(dict: &Dictionary) -> Option<Self> {
+        Some(Self {
+            collider: field::<Option<Gd<Object>>>(dict, "collider").flatten(),
+            collider_id: field::<i64>(dict, "collider_id").and_then(InstanceId::try_from_i64),
+            rid: field(dict, "rid")?,
+            shape_index: field::<i64>(dict, "shape").map_or(0, |index| index as usize),
+        })
+    }
+}
+
+/// Result of a shape's [motion cast][ShapeQuery2D::cast_motion], as fractions of the motion vector.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MotionCast {
+    /// How far the shape can move without colliding, between 0 and 1.
+    pub safe_fraction: f32,
+
+    /// How far the shape can move until it collides, between 0 and 1.
+    pub unsafe_fraction: f32,
+}
+
+impl MotionCast {
+    /// Whether the shape collides anywhere along the motion.
+    pub fn collides(&self) -> bool {
+        self.unsafe_fraction < 1.0
+    }
+}
+
+/// Reads `key` from a query result, or `None` if it is absent or has a different type.
+fn field<T: FromGodot>(dict: &Dictionary, key: &str) -> Option<T> {
+    dict.get(key)?.try_to::<T>().ok()
+}
+
+fn max_results_arg(max_results: usize) -> i32 {
+    i32::try_from(max_results).unwrap_or(i32::MAX)
+}
+
+/// Collision filter common to all query kinds, in Godot's defaults.
+#[derive(Clone)]
+struct Filter {
+    collision_mask: u32,
+    exclude: Option<Array<Rid>>,
+    collide_with_bodies: bool,
+    collide_with_areas: bool,
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Self {
+            collision_mask: u32::MAX,
+            exclude: None,
+            collide_with_bodies: true,
+            collide_with_areas: false,
+        }
+    }
+}
+
+/// Builder methods for the [`Filter`] field of a query builder.
+macro_rules! impl_filter_methods {
+    () => {
+        /// Only considers objects in one of the physics layers of `mask`; all layers by default.
+        pub fn collision_mask(mut self, mask: u32) -> Self {
+            self.filter.collision_mask = mask;
+            self
+        }
+
+        /// Ignores the objects with the given RIDs, for example the querying body itself.
+        pub fn exclude(mut self, rids: &[Rid]) -> Self {
+            self.filter.exclude = Some(Array::from(rids));
+            self
+        }
+
+        /// Whether physics bodies are considered; `true` by default.
+        pub fn collide_with_bodies(mut self, enabled: bool) -> Self {
+            self.filter.collide_with_bodies = enabled;
+            self
+        }
+
+        /// Whether areas are considered; `false` by default.
+        pub fn collide_with_areas(mut self, enabled: bool) -> Self {
+            self.filter.collide_with_areas = enabled;
+            self
+        }
+    };
+}
+
+/// Writes a [`Filter`] to a reused parameter object, resetting the exclusion list only if a previous query set it.
+macro_rules! apply_filter {
+    ($params:expr, $filter:expr, $has_exclude:expr) => {{
+        let params = &mut $params;
+        let filter = $filter;
+
+        params.set_collision_mask(filter.collision_mask);
+        params.set_collide_with_bodies(filter.collide_with_bodies);
+        params.set_collide_with_areas(filter.collide_with_areas);
+
+        match filter.exclude {
+            Some(exclude) => {
+                params.set_exclude(exclude);
+                *$has_exclude = true;
+            }
+            None if *$has_exclude => {
+                params.set_exclude(Array::new());
+                *$has_exclude = false;
+            }
+            None => {}
+        }
+    }};
+}
+
+macro_rules! impl_physics_query {
+    (
+        dim: $dim:literal,
+        query: $Query:ident,
+        space: $Space:ident,
+        ray: $RayQuery:ident($RayParams:ident) -> $RayHit:ident,
+        point: $PointQuery:ident($PointParams:ident),
+        shape: $ShapeQuery:ident($ShapeParams:ident, $Shape:ident),
+        vector: $Vector:ident,
+        transform: $Transform:ident,
+        ray_flags: { $( $(#[$flag_attr:meta])* $flag:ident($flag_setter:ident) = $flag_default:expr ),* $(,)? }
+    ) => {
+        #[doc = concat!("Typed queries against a `", stringify!($Space), "`.")]
+        ///
+        /// Each kind of query has a builder, which sets up the query and runs it in a final method, returning typed results instead of
+        /// dictionaries. The underlying `Physics*QueryParameters` objects are allocated once and reused across queries, so keep the
+        /// query object around (e.g. as a field) for per-frame raycasts.
+        ///
+        /// Options not set on a builder have Godot's default values, regardless of previous queries.
+        ///
+        /// Like the space state itself, queries are only safe to run during `physics_process()`.
+        pub struct $Query {
+            space: Gd<$Space>,
+            ray_params: Gd<$RayParams>,
+            point_params: Gd<$PointParams>,
+            shape_params: Gd<$ShapeParams>,
+            ray_has_exclude: bool,
+            point_has_exclude: bool,
+            shape_has_exclude: bool,
+        }
+
+        impl $Query {
+            #[doc = concat!("Creates a query object for the given `", stringify!($Space), "`.")]
+            pub fn new(space: Gd<$Space>) -> Self {
+                Self {
+                    space,
+                    ray_params: $RayParams::new(),
+                    point_params: $PointParams::new(),
+                    shape_params: $ShapeParams::new(),
+                    ray_has_exclude: false,
+                    point_has_exclude: false,
+                    shape_has_exclude: false,
+                }
+            }
+
+            /// Starts a ray query from `from` to `to`, in global coordinates.
+            pub fn ray(&mut self, from: $Vector, to: $Vector) -> $RayQuery<'_> {
+                $RayQuery {
+                    query: self,
+                    from,
+                    to,
+                    filter: Filter::default(),
+                    $( $flag: $flag_default, )*
+                }
+            }
+
+            /// Starts a query for the objects containing `position`, in global coordinates.
+            pub fn point(&mut self, position: $Vector) -> $PointQuery<'_> {
+                $PointQuery {
+                    query: self,
+                    position,
+                    filter: Filter::default(),
+                    max_results: 32,
+                }
+            }
+
+            /// Starts a query with `shape`, placed at `transform`.
+            pub fn shape<S>(&mut self, shape: &Gd<S>, transform: $Transform) -> $ShapeQuery<'_>
+            where
+                S: Inherits<$Shape>,
+            {
+                let shape = shape.clone().upcast::<$Shape>().upcast::<Resource>();
+                self.shape_params.set_shape(shape);
+                self.shape_query(transform)
+            }
+
+            /// Starts a query with the shape `shape_rid`, created on the physics server and placed at `transform`.
+            pub fn shape_rid(&mut self, shape_rid: Rid, transform: $Transform) -> $ShapeQuery<'_> {
+                self.shape_params.set_shape_rid(shape_rid);
+                self.shape_query(transform)
+            }
+
+            /// The space state this object queries.
+            pub fn space(&self) -> Gd<$Space> {
+                self.space.clone()
+            }
+
+            fn shape_query(&mut self, transform: $Transform) -> $ShapeQuery<'_> {
+                $ShapeQuery {
+                    query: self,
+                    transform,
+                    motion: $Vector::ZERO,
+                    margin: 0.0,
+                    filter: Filter::default(),
+                    max_results: 32,
+                }
+            }
+        }
+
+        // ------------------------------------------------------------------------------------------------------------------------------------------
+
+        #[doc = concat!("Ray query, created by [`", stringify!($Query), "::ray()`].")]
+        #[must_use = "queries do nothing until cast"]
+        pub struct $RayQuery<'a> {
+            query: &'a mut $Query,
+            from: $Vector,
+            to: $Vector,
+            filter: Filter,
+            $( $flag: bool, )*
+        }
+
+        impl $RayQuery<'_> {
+            impl_filter_methods!();
+
+            $(
+                $(#[$flag_attr])*
+                pub fn $flag(mut self, enabled: bool) -> Self {
+                    self.$flag = enabled;
+                    self
+                }
+            )*
+
+            /// Casts the ray and returns the closest hit, if any.
+            pub fn cast(self) -> Option<$RayHit> {
+                let query = self.query;
+                let params = &mut query.ray_params;
+
+                params.set_from(self.from);
+                params.set_to(self.to);
+                $( params.$flag_setter(self.$flag); )*
+                apply_filter!(*params, self.filter, &mut query.ray_has_exclude);
+
+                let result = query.space.intersect_ray(params.clone());
+                $RayHit::from_dictionary(&result)
+            }
+        }
+
+        #[doc = concat!("Closest hit of a [", $dim, " ray query][", stringify!($RayQuery), "].")]
+        #[derive(Clone, Debug)]
+        pub struct $RayHit {
+            /// Point of the hit, in global coordinates.
+            pub position: $Vector,
+
+            /// Surface normal at the hit point. Zero if the ray started inside the shape, with `hit_from_inside` enabled.
+            pub normal: $Vector,
+
+            /// The colliding object, or `None` if it has been freed or belongs to no object.
+            pub collider: Option<Gd<Object>>,
+
+            /// Instance ID of the colliding object.
+            pub collider_id: Option<InstanceId>,
+
+            /// RID of the colliding body or area on the physics server.
+            pub rid: Rid,
+
+            /// Index of the colliding shape within the collider.
+            pub shape_index: usize,
+        }
+
+        impl $RayHit {
+            /// Parses the result of `intersect_ray()`.
+            ///
+            /// Returns `None` if the dictionary is empty, i.e. nothing was hit.
+            pub fn from_dictionary(dict: &Dictionary) -> Option<Self> {
+                let hit = QueryHit::from_dictionary(dict)?;
+
+                Some(Self {
+                    position: field(dict, "position")?,
+                    normal: field(dict, "normal").unwrap_or($Vector::ZERO),
+                    collider: hit.collider,
+                    collider_id: hit.collider_id,
+                    rid: hit.rid,
+                    shape_index: hit.shape_index,
+                })
+            }
+        }
+
+        // ------------------------------------------------------------------------------------------------------------------------------------------
+
+        #[doc = concat!("Point query, created by [`", stringify!($Query), "::point()`].")]
+        #[must_use = "queries do nothing until run"]
+        pub struct $PointQuery<'a> {
+            query: &'a mut $Query,
+            position: $Vector,
+            filter: Filter,
+            max_results: usize,
+        }
+
+        impl $PointQuery<'_> {
+            impl_filter_methods!();
+
+            /// Limits the number of returned objects; 32 by default.
+            pub fn max_results(mut self, max_results: usize) -> Self {
+                self.max_results = max_results;
+                self
+            }
+
+            /// Runs the query and returns all objects containing the point.
+            pub fn intersect(self) -> Vec<QueryHit> {
+                let query = self.query;
+                let params = &mut query.point_params;
+
+                params.set_position(self.position);
+                apply_filter!(*params, self.filter, &mut query.point_has_exclude);
+
+                let results = query
+                    .space
+                    .intersect_point_ex(params.clone())
+                    .max_results(max_results_arg(self.max_results))
+                    .done();
+
+                parse_hits(results)
+            }
+        }
+
+        // ------------------------------------------------------------------------------------------------------------------------------------------
+
+        #[doc = concat!("Shape query, created by [`", stringify!($Query), "::shape()`] or [`", stringify!($Query), "::shape_rid()`].")]
+        #[must_use = "queries do nothing until run"]
+        pub struct $ShapeQuery<'a> {
+            query: &'a mut $Query,
+            transform: $Transform,
+            motion: $Vector,
+            margin: real,
+            filter: Filter,
+            max_results: usize,
+        }
+
+        impl<'a> $ShapeQuery<'a> {
+            impl_filter_methods!();
+
+            /// Motion of the shape, used by [`cast_motion()`][Self::cast_motion]; also makes [`intersect()`][Self::intersect] test
+            /// the whole swept shape.
+            pub fn motion(mut self, motion: $Vector) -> Self {
+                self.motion = motion;
+                self
+            }
+
+            /// Collision margin of the shape; 0 by default.
+            pub fn margin(mut self, margin: real) -> Self {
+                self.margin = margin;
+                self
+            }
+
+            /// Limits the number of objects returned by [`intersect()`][Self::intersect]; 32 by default.
+            pub fn max_results(mut self, max_results: usize) -> Self {
+                self.max_results = max_results;
+                self
+            }
+
+            /// Runs the query and returns all objects overlapping the shape.
+            pub fn intersect(self) -> Vec<QueryHit> {
+                let max_results = max_results_arg(self.max_results);
+                let (space, params) = self.apply();
+
+                let results = space.intersect_shape_ex(params).max_results(max_results).done();
+                parse_hits(results)
+            }
+
+            /// Moves the shape along [`motion`][Self::motion] and returns how far it can go.
+            ///
+            /// Returns `None` if Godot rejects the query, e.g. because the shape is not valid.
+            pub fn cast_motion(self) -> Option<MotionCast> {
+                let (space, params) = self.apply();
+
+                let fractions = space.cast_motion(params);
+                match fractions.as_slice() {
+                    &[safe_fraction, unsafe_fraction] => Some(MotionCast {
+                        safe_fraction,
+                        unsafe_fraction,
+                    }),
+                    _ => None,
+                }
+            }
+
+            fn apply(self) -> (&'a mut Gd<$Space>, Gd<$ShapeParams>) {
+                let query = self.query;
+                let params = &mut query.shape_params;
+
+                params.set_transform(self.transform);
+                params.set_motion(self.motion);
+                params.set_margin(self.margin);
+                apply_filter!(*params, self.filter, &mut query.shape_has_exclude);
+
+                (&mut query.space, params.clone())
+            }
+        }
+    };
+}
+
+fn parse_hits(results: Array<Dictionary>) -> Vec<QueryHit> {
+    results
+        .iter_shared()
+        .filter_map(|dict| QueryHit::from_dictionary(&dict))
+        .collect()
+}
+
+impl_physics_query! {
+    dim: "2D",
+    query: PhysicsQuery2D,
+    space: PhysicsDirectSpaceState2D,
+    ray: RayQuery2D(PhysicsRayQueryParameters2D) -> RayHit2D,
+    point: PointQuery2D(PhysicsPointQueryParameters2D),
+    shape: ShapeQuery2D(PhysicsShapeQueryParameters2D, Shape2D),
+    vector: Vector2,
+    transform: Transform2D,
+    ray_flags: {
+        /// Whether a ray starting inside a shape hits it (with a zero normal); `false` by default.
+        hit_from_inside(set_hit_from_inside) = false,
+    }
+}
+
+impl_physics_query! {
+    dim: "3D",
+    query: PhysicsQuery3D,
+    space: PhysicsDirectSpaceState3D,
+    ray: RayQuery3D(PhysicsRayQueryParameters3D) -> RayHit3D,
+    point: PointQuery3D(PhysicsPointQueryParameters3D),
+    shape: ShapeQuery3D(PhysicsShapeQueryParameters3D, Shape3D),
+    vector: Vector3,
+    transform: Transform3D,
+    ray_flags: {
+        /// Whether a ray starting inside a shape hits it (with a zero normal); `false` by default.
+        hit_from_inside(set_hit_from_inside) = false,
+
+        /// Whether back faces of concave shapes and height maps are hit; `true` by default.
+        hit_back_faces(set_hit_back_faces) = true,
+    }
+}
+
+impl PhysicsQuery2D {
+    /// ⚠️ Creates a query object for the 2D world of `node`.
+    ///
+    /// # Panics
+    /// If `node` is not inside the scene tree.
+    pub fn for_node<T: Inherits<CanvasItem>>(node: &Gd<T>) -> Self {
+        let space = node
+            .clone()
+            .upcast::<CanvasItem>()
+            .get_world_2d()
+            .and_then(|mut world| world.get_direct_space_state())
+            .expect("node has no 2D physics space; is it inside the scene tree?");
+
+        Self::new(space)
+    }
+}
+
+impl PhysicsQuery3D {
+    /// ⚠️ Creates a query object for the 3D world of `node`.
+    ///
+    /// # Panics
+    /// If `node` is not inside the scene tree.
+    pub fn for_node<T: Inherits<Node3D>>(node: &Gd<T>) -> Self {
+        let space = node
+            .clone()
+            .upcast::<Node3D>()
+            .get_world_3d()
+            .and_then(|mut world| world.get_direct_space_state())
+            .expect("node has no 3D physics space; is it inside the scene tree?");
+
+        Self::new(space)
+    }
+}
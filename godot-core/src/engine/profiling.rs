@@ -0,0 +1,251 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::builtin::meta::ToGodot;
+use crate::builtin::{Callable, StringName, Variant, VariantArray};
+use crate::engine::{Engine, EngineDebugger, Performance};
+use crate::obj::Gd;
+
+/// Extension trait for `Performance`, registering custom monitors backed by Rust closures.
+///
+/// Custom monitors are listed in the editor's _Debugger > Monitors_ panel, next to the built-in ones. Their ID has the form
+/// `"category/name"`; monitors without a category are shown under _Custom_.
+///
+/// # Example
+/// ```no_run
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use godot::engine::{Performance, PerformanceExt};
+///
+/// static ACTIVE_ENEMIES: AtomicUsize = AtomicUsize::new(0);
+///
+/// Performance::singleton().add_monitor("Game/Active enemies", || {
+///     ACTIVE_ENEMIES.load(Ordering::Relaxed) as f64
+/// });
+/// ```
+pub trait PerformanceExt {
+    /// ⚠️ Adds the custom monitor `id`, whose value is computed by `value` each time the monitor is read.
+    ///
+    /// Remove it again with `Performance::remove_custom_monitor()`.
+    ///
+    /// # Panics
+    /// If a custom monitor `id` already exists.
+    fn add_monitor<F>(&mut self, id: impl Into<StringName>, value: F)
+    where
+        F: FnMut() -> f64 + Send + Sync + 'static;
+}
+
+impl PerformanceExt for Gd<Performance> {
+    fn add_monitor<F>(&mut self, id: impl Into<StringName>, mut value: F)
+    where
+        F: FnMut() -> f64 + Send + Sync + 'static,
+    {
+        let id = id.into();
+        assert!(
+            !self.has_custom_monitor(id.clone()),
+            "custom monitor `{id}` already exists"
+        );
+
+        let callable =
+            Callable::from_fn(format!("Performance::{id}"), move |_args: &[&Variant]| {
+                Ok(value().to_variant())
+            });
+
+        self.add_custom_monitor(id, callable);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Times the rest of the enclosing block and reports it to Godot's profiling tools under `name`.
+///
+/// This expands to a [`ProfileScope`] guard, which is dropped at the end of the block. See its documentation for where the
+/// timings show up.
+///
+/// # Example
+/// ```no_run
+/// use godot::engine::profile_scope;
+///
+/// fn update_flocks() {
+///     profile_scope!("AI/Flocking");
+///
+///     // ...
+/// }
+/// ```
+#[macro_export]
+macro_rules! profile_scope {
+    ($name:expr) => {
+        let _profile_scope_guard = $crate::engine::ProfileScope::enter($name);
+    };
+}
+
+/// Guard measuring how long a scope takes, usually created by [`profile_scope!`].
+///
+/// Timings of all scopes with the same name are summed up per process frame. The previous frame's total is reported in two ways:
+/// - As the custom monitor `Rust/<name>` in milliseconds, registered when the scope is first entered. It is listed in the editor's
+///   _Debugger > Monitors_ panel.
+/// - As frame data of the profiler [`PROFILER_NAME`][Self::PROFILER_NAME], while such an `EngineProfiler` is registered with
+///   `EngineDebugger` and enabled. Its `_add_frame()` receives an array of alternating scope names and milliseconds, once per frame.
+///
+/// Like most engine APIs, profile scopes are meant to be used on the main thread.
+#[must_use = "the scope is measured until the guard is dropped"]
+pub struct ProfileScope {
+    name: &'static str,
+    start: Instant,
+}
+
+impl ProfileScope {
+    /// Name of the `EngineProfiler` receiving the timings of profile scopes.
+    pub const PROFILER_NAME: &'static str = "rust";
+
+    /// Starts measuring the scope `name`; the measurement ends when the guard is dropped.
+    pub fn enter(name: &'static str) -> Self {
+        Self {
+            name,
+            start: Instant::now(),
+        }
+    }
+
+    /// Total time spent in the scope `name` during the last completed frame, and how often it was entered.
+    ///
+    /// Returns `None` if the scope has never been entered.
+    pub fn last_frame(name: &str) -> Option<ScopeTiming> {
+        let frame = Engine::singleton().get_process_frames();
+        let registry = REGISTRY.lock().unwrap();
+        let registry = registry.as_ref()?;
+        let scope = registry.scopes.get(name)?;
+
+        // The registry only moves on to a new frame when a scope is recorded in it.
+        let timing = match frame.saturating_sub(registry.frame) {
+            0 => scope.last,
+            1 => scope.current,
+            _ => ScopeTiming::default(),
+        };
+
+        Some(timing)
+    }
+}
+
+impl Drop for ProfileScope {
+    fn drop(&mut self) {
+        record(self.name, self.start.elapsed());
+    }
+}
+
+/// Accumulated timing of a profile scope within one frame, see [`ProfileScope::last_frame()`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct ScopeTiming {
+    /// Sum of the durations of all entries into the scope.
+    pub total: Duration,
+
+    /// Number of times the scope was entered.
+    pub calls: u32,
+}
+
+impl ScopeTiming {
+    fn add(&mut self, elapsed: Duration) {
+        self.total += elapsed;
+        self.calls += 1;
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+static REGISTRY: Mutex<Option<Registry>> = Mutex::new(None);
+
+#[derive(Default)]
+struct Registry {
+    /// Process frame that `ScopeStats::current` refers to.
+    frame: u64,
+    scopes: HashMap<&'static str, ScopeStats>,
+}
+
+#[derive(Default)]
+struct ScopeStats {
+    current: ScopeTiming,
+    last: ScopeTiming,
+}
+
+impl Registry {
+    /// Moves on to `frame`, completing the frame recorded so far. Returns the frame data of the completed frame, if any.
+    fn advance(&mut self, frame: u64) -> Option<VariantArray> {
+        if frame == self.frame {
+            return None;
+        }
+
+        // If frames were skipped without any recording, the scopes were not entered during the last one.
+        let skipped = frame > self.frame + 1;
+        let mut data = VariantArray::new();
+
+        for (name, scope) in self.scopes.iter_mut() {
+            scope.last = if skipped {
+                ScopeTiming::default()
+            } else {
+                scope.current
+            };
+            scope.current = ScopeTiming::default();
+
+            data.push(name.to_variant());
+            data.push(millis(scope.last.total).to_variant());
+        }
+
+        self.frame = frame;
+        Some(data)
+    }
+}
+
+fn record(name: &'static str, elapsed: Duration) {
+    let frame = Engine::singleton().get_process_frames();
+
+    let (completed_frame, is_new) = {
+        let mut registry = REGISTRY.lock().unwrap();
+        let registry = registry.get_or_insert_with(Registry::default);
+
+        let completed_frame = registry.advance(frame);
+        let is_new = !registry.scopes.contains_key(name);
+        registry
+            .scopes
+            .entry(name)
+            .or_default()
+            .current
+            .add(elapsed);
+
+        (completed_frame, is_new)
+    };
+
+    // Engine calls happen outside the lock, as they may run other profile scopes.
+    if is_new {
+        add_scope_monitor(name);
+    }
+
+    if let Some(data) = completed_frame {
+        let mut debugger = EngineDebugger::singleton();
+        let profiler = StringName::from(ProfileScope::PROFILER_NAME);
+
+        if debugger.is_profiling(profiler.clone()) {
+            debugger.profiler_add_frame_data(profiler, data);
+        }
+    }
+}
+
+fn add_scope_monitor(name: &'static str) {
+    let id = StringName::from(format!("Rust/{name}"));
+    let mut performance = Performance::singleton();
+
+    if !performance.has_custom_monitor(id.clone()) {
+        performance.add_monitor(id, move || {
+            ProfileScope::last_frame(name).map_or(0.0, |timing| millis(timing.total))
+        });
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
@@ -0,0 +1,323 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use crate::builtin::meta::{FromGodot, ToGodot};
+use crate::builtin::{Callable, RustCallable, StringName, Variant};
+use crate::engine::Object;
+use crate::obj::{Gd, GodotClass, Inherits, InstanceId};
+
+type Transform = Arc<dyn Fn(&Variant) -> Option<Variant> + Send + Sync>;
+
+/// One-way data binding from a property of one object to a property or method of another.
+///
+/// The source property must announce its changes through a signal, typically a Rust property declared with `#[export(notify)]`.
+/// Whenever it changes, the new value is (optionally transformed and) written to the target. This replaces the hand-written signal
+/// handlers that UI code otherwise needs for every displayed value.
+///
+/// By default, the binding listens to `property_changed(property, old_value, new_value)` and ignores changes of other properties.
+/// Use [`with_signal()`][Self::with_signal] for a property with its own signal, such as `#[export(notify = health_changed)]`, or for
+/// engine signals like `Range.value_changed`. For those, the signal's last argument is taken as the new value.
+///
+/// The binding lives as long as both objects: freeing the source removes its connections, and once the target is freed, the binding
+/// disconnects itself on the next change. It can also be removed earlier with [`BindingHandle::unbind()`].
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{Label, PropertyBinding};
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     #[export(notify)]
+///     health: i64,
+///
+///     #[base]
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Player {}
+///
+/// fn show_health(player: &Gd<Player>, label: &Gd<Label>) {
+///     PropertyBinding::new(player, "health")
+///         .with_transform(|health: i64| GodotString::from(format!("HP: {health}")))
+///         .to_property(label, "text");
+/// }
+/// ```
+///
+/// Both directions of a two-way binding would write to each other from within the setters, which re-enters the first object while
+/// it is still bound; bindings are therefore one-way only.
+pub struct PropertyBinding {
+    source: Gd<Object>,
+    property: StringName,
+    signal: Option<StringName>,
+    transform: Option<Transform>,
+}
+
+impl PropertyBinding {
+    /// Starts a binding of `property` of `source`.
+    pub fn new<S>(source: &Gd<S>, property: impl Into<StringName>) -> Self
+    where
+        S: GodotClass + Inherits<Object>,
+    {
+        Self {
+            source: source.clone().upcast(),
+            property: property.into(),
+            signal: None,
+            transform: None,
+        }
+    }
+
+    /// Listens to `signal` instead of `property_changed`, taking its last argument as the new value.
+    pub fn with_signal(mut self, signal: impl Into<StringName>) -> Self {
+        self.signal = Some(signal.into());
+        self
+    }
+
+    /// Converts values before they are passed on to the target.
+    ///
+    /// If a value cannot be converted to `A`, the target is not updated and Godot reports a failed call.
+    pub fn with_transform<A, B, F>(mut self, transform: F) -> Self
+    where
+        A: FromGodot,
+        B: ToGodot,
+        F: Fn(A) -> B + Send + Sync + 'static,
+    {
+        self.transform = Some(Arc::new(move |value: &Variant| {
+            let value = A::try_from_variant(value).ok()?;
+            Some(transform(value).to_variant())
+        }));
+        self
+    }
+
+    /// Writes the value to `target_property` of `target`, starting with the current value.
+    pub fn to_property<T>(
+        self,
+        target: &Gd<T>,
+        target_property: impl Into<StringName>,
+    ) -> BindingHandle
+    where
+        T: GodotClass + Inherits<Object>,
+    {
+        self.connect(target, TargetAction::SetProperty(target_property.into()))
+    }
+
+    /// Calls `method` of `target` with the value as its only argument, starting with the current value.
+    pub fn to_method<T>(self, target: &Gd<T>, method: impl Into<StringName>) -> BindingHandle
+    where
+        T: GodotClass + Inherits<Object>,
+    {
+        self.connect(target, TargetAction::CallMethod(method.into()))
+    }
+
+    fn connect<T>(self, target: &Gd<T>, action: TargetAction) -> BindingHandle
+    where
+        T: GodotClass + Inherits<Object>,
+    {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        let Self {
+            mut source,
+            property,
+            signal,
+            transform,
+        } = self;
+
+        let binder = Binder {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            source: source.instance_id(),
+            filter_property: signal.is_none().then(|| property.clone()),
+            signal: signal.unwrap_or_else(|| StringName::from(SHARED_SIGNAL)),
+            target: target.instance_id(),
+            action,
+            transform,
+        };
+
+        // Bring the target up to date, so it does not show a stale value until the first change.
+        let current = source.get(property);
+        binder.apply(&current);
+
+        let handle = BindingHandle {
+            id: binder.id,
+            source: binder.source,
+            signal: binder.signal.clone(),
+        };
+
+        let signal = binder.signal.clone();
+        source.connect(signal, Callable::from_custom(binder));
+
+        handle
+    }
+}
+
+impl fmt::Debug for PropertyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PropertyBinding")
+            .field("source", &self.source.instance_id())
+            .field("property", &self.property)
+            .field("signal", &self.signal)
+            .field("has_transform", &self.transform.is_some())
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Connected [`PropertyBinding`], returned by its `to_*()` methods.
+///
+/// Dropping the handle keeps the binding; only [`unbind()`][Self::unbind] or the end of either object's lifetime removes it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BindingHandle {
+    id: u64,
+    source: InstanceId,
+    signal: StringName,
+}
+
+impl BindingHandle {
+    /// Whether the binding is still connected.
+    ///
+    /// After the target is freed, this stays `true` until the next change of the source property disconnects the binding.
+    pub fn is_bound(&self) -> bool {
+        let Some(source) = Gd::<Object>::try_from_instance_id(self.source) else {
+            return false;
+        };
+
+        source.is_connected(self.signal.clone(), self.probe())
+    }
+
+    /// Disconnects the binding. Returns `false` if it was no longer connected.
+    pub fn unbind(self) -> bool {
+        let Some(source) = Gd::<Object>::try_from_instance_id(self.source) else {
+            return false;
+        };
+
+        disconnect(source, &self.signal, self.id)
+    }
+
+    fn probe(&self) -> Callable {
+        Binder::probe(self.id, self.source, self.signal.clone())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+/// Signal emitted by `#[export(notify)]` properties without a dedicated signal.
+const SHARED_SIGNAL: &str = "property_changed";
+
+enum TargetAction {
+    SetProperty(StringName),
+    CallMethod(StringName),
+}
+
+/// Custom callable connected to the source signal; equal to another binder with the same `id`.
+struct Binder {
+    id: u64,
+    source: InstanceId,
+    signal: StringName,
+
+    /// For the shared signal, the property whose changes are forwarded.
+    filter_property: Option<StringName>,
+
+    target: InstanceId,
+    action: TargetAction,
+    transform: Option<Transform>,
+}
+
+impl Binder {
+    /// Callable that compares equal to the binder `id`, for `is_connected()` and `disconnect()`.
+    fn probe(id: u64, source: InstanceId, signal: StringName) -> Callable {
+        Callable::from_custom(Binder {
+            id,
+            source,
+            signal,
+            filter_property: None,
+            target: source,
+            action: TargetAction::CallMethod(StringName::default()),
+            transform: None,
+        })
+    }
+
+    /// Passes `value` on to the target. Returns `None` if the target is gone or the transform rejected the value.
+    fn apply(&self, value: &Variant) -> Option<()> {
+        let mut target = Gd::<Object>::try_from_instance_id(self.target)?;
+
+        let value = match &self.transform {
+            Some(transform) => transform(value)?,
+            None => value.clone(),
+        };
+
+        match &self.action {
+            TargetAction::SetProperty(property) => target.set(property.clone(), value),
+            TargetAction::CallMethod(method) => {
+                target.call(method.clone(), &[value]);
+            }
+        }
+
+        Some(())
+    }
+}
+
+impl RustCallable for Binder {
+    fn invoke(&mut self, args: &[&Variant]) -> Result<Variant, ()> {
+        if Gd::<Object>::try_from_instance_id(self.target).is_none() {
+            if let Some(source) = Gd::<Object>::try_from_instance_id(self.source) {
+                disconnect(source, &self.signal, self.id);
+            }
+            return Ok(Variant::nil());
+        }
+
+        let value = match &self.filter_property {
+            Some(property) => {
+                let [changed, _old_value, new_value] = args else {
+                    return Err(());
+                };
+                if StringName::try_from_variant(changed).ok().as_ref() != Some(property) {
+                    return Ok(Variant::nil());
+                }
+                *new_value
+            }
+            None => *args.last().ok_or(())?,
+        };
+
+        self.apply(value).ok_or(())?;
+        Ok(Variant::nil())
+    }
+}
+
+impl PartialEq for Binder {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Hash for Binder {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl fmt::Display for Binder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PropertyBinding::{}#{}", self.signal, self.id)
+    }
+}
+
+fn disconnect(mut source: Gd<Object>, signal: &StringName, id: u64) -> bool {
+    let probe = Binder::probe(id, source.instance_id(), signal.clone());
+    if !source.is_connected(signal.clone(), probe.clone()) {
+        return false;
+    }
+
+    source.disconnect(signal.clone(), probe);
+    true
+}
@@ -0,0 +1,81 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::GodotString;
+use crate::engine::Resource;
+use crate::obj::{Gd, GodotClass, Inherits};
+
+/// A `res://` path that was checked to exist in the Godot project at compile time.
+///
+/// Created with the `res!` macro, which fails compilation if the file does not exist:
+/// ```ignore
+/// use godot::prelude::*;
+///
+/// let scene = res!("res://scenes/player.tscn").load::<PackedScene>();
+/// ```
+///
+/// The project directory is the closest ancestor of the crate's manifest directory that contains a `project.godot` file
+/// (directly, or in a `godot` subdirectory). Set the `GODOT_PROJECT_DIR` environment variable (absolute, or relative to the
+/// manifest directory) to use another location.
+///
+/// Cargo does not track the checked files; if an asset is removed after a successful build, rebuild the crate to detect it.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ResPath {
+    path: &'static str,
+}
+
+impl ResPath {
+    #[doc(hidden)]
+    pub const fn __new_unchecked(path: &'static str) -> Self {
+        Self { path }
+    }
+
+    /// The full path, including the `res://` prefix.
+    pub const fn as_str(&self) -> &'static str {
+        self.path
+    }
+
+    /// ⚠️ Loads the resource at this path.
+    ///
+    /// # Panics
+    /// If the resource cannot be loaded, or is not of type `T` or inherited.
+    pub fn load<T>(&self) -> Gd<T>
+    where
+        T: GodotClass + Inherits<Resource>,
+    {
+        super::load(self.path)
+    }
+
+    /// Loads the resource at this path (fallible).
+    ///
+    /// The file is known to exist, but `None` is still returned if it cannot be imported or is not of type `T`.
+    pub fn try_load<T>(&self) -> Option<Gd<T>>
+    where
+        T: GodotClass + Inherits<Resource>,
+    {
+        super::try_load(self.path)
+    }
+}
+
+impl fmt::Display for ResPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.path)
+    }
+}
+
+impl fmt::Debug for ResPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "res!({:?})", self.path)
+    }
+}
+
+impl From<ResPath> for GodotString {
+    fn from(path: ResPath) -> Self {
+        GodotString::from(path.path)
+    }
+}
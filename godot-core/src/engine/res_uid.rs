@@ -0,0 +1,218 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::GodotString;
+use crate::engine::{Resource, ResourceLoader, ResourceUid};
+use crate::obj::{Gd, GodotClass, Inherits};
+
+/// Unique ID of a resource file, which stays valid when the file is moved or renamed in the editor.
+///
+/// Godot stores the ID in the file itself (or in the `.import` file), and refers to other resources by ID as well as by path. The
+/// text form is `uid://` followed by lowercase letters and digits, as shown in the editor's _Copy UID_ menu of the _FileSystem_
+/// dock. Paths in this form are accepted by [`load()`][super::load] and the other loading functions.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::ResUid;
+///
+/// let uid = ResUid::parse("uid://cecaux1sm7mo0").expect("valid UID");
+/// let scene = uid.load::<PackedScene>();
+///
+/// let same_scene = load::<PackedScene>("uid://cecaux1sm7mo0");
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ResUid {
+    /// Non-negative; Godot uses -1 for invalid IDs.
+    id: i64,
+}
+
+impl ResUid {
+    /// The UID with the integer ID `id`, or `None` if it is negative (Godot's `ResourceUID.INVALID_ID`).
+    pub fn from_i64(id: i64) -> Option<Self> {
+        (id >= 0).then_some(Self { id })
+    }
+
+    /// Parses the text form `uid://...`, or returns `None` if `text` is not of that form.
+    ///
+    /// This does not check whether a resource with the UID exists; see [`path()`][Self::path].
+    pub fn parse(text: &str) -> Option<Self> {
+        let digits = text.strip_prefix(PREFIX)?;
+        if digits.is_empty() {
+            return None;
+        }
+
+        let mut id: u64 = 0;
+        for ch in digits.bytes() {
+            let digit = match ch {
+                b'a'..=b'z' => ch - b'a',
+                b'0'..=b'9' => ch - b'0' + 26,
+                _ => return None,
+            };
+
+            // Same wrapping arithmetic as Godot, so that both agree on overlong texts.
+            id = id.wrapping_mul(BASE).wrapping_add(u64::from(digit));
+        }
+
+        Self::from_i64((id & i64::MAX as u64) as i64)
+    }
+
+    /// Generates a new random UID, e.g. for a resource created in Rust before saving it.
+    ///
+    /// The UID is not associated with a path until [`register()`][Self::register] is called.
+    pub fn create() -> Self {
+        let id = ResourceUid::singleton().create_id();
+
+        Self::from_i64(id).expect("ResourceUID.create_id() returned an invalid ID")
+    }
+
+    /// The UID of the resource file at `path`, or `None` if the file has none (e.g. a file type without UID support).
+    pub fn of_path(path: &str) -> Option<Self> {
+        let id = ResourceLoader::singleton().get_resource_uid(GodotString::from(path));
+
+        Self::from_i64(id)
+    }
+
+    /// The integer ID, as used by `ResourceUID` methods.
+    pub fn to_i64(self) -> i64 {
+        self.id
+    }
+
+    /// The path of the resource file with this UID, or `None` if no resource has it.
+    pub fn path(self) -> Option<String> {
+        let uids = ResourceUid::singleton();
+        if !uids.has_id(self.id) {
+            return None;
+        }
+
+        Some(uids.get_id_path(self.id).to_string())
+    }
+
+    /// Associates this UID with `path`, replacing any previous path, until the engine exits.
+    ///
+    /// Godot only makes the association permanent when the resource is saved with this UID, e.g. by the editor.
+    pub fn register(self, path: &str) {
+        let mut uids = ResourceUid::singleton();
+        let path = GodotString::from(path);
+
+        if uids.has_id(self.id) {
+            uids.set_id(self.id, path);
+        } else {
+            uids.add_id(self.id, path);
+        }
+    }
+
+    /// Removes the association of this UID with a path, if any.
+    pub fn unregister(self) {
+        let mut uids = ResourceUid::singleton();
+
+        if uids.has_id(self.id) {
+            uids.remove_id(self.id);
+        }
+    }
+
+    /// ⚠️ Loads the resource with this UID.
+    ///
+    /// # Panics
+    /// If no resource has this UID, or it cannot be loaded, or is not of type `T` or inherited.
+    pub fn load<T>(self) -> Gd<T>
+    where
+        T: GodotClass + Inherits<Resource>,
+    {
+        super::load(self.to_string())
+    }
+
+    /// Loads the resource with this UID (fallible).
+    pub fn try_load<T>(self) -> Option<Gd<T>>
+    where
+        T: GodotClass + Inherits<Resource>,
+    {
+        super::try_load(self.to_string())
+    }
+}
+
+impl fmt::Display for ResUid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Most significant digit first, like Godot's `ResourceUID.id_to_text()`.
+        let mut digits = Vec::new();
+        let mut id = self.id as u64;
+
+        while id > 0 {
+            let digit = (id % BASE) as u8;
+            digits.push(if digit < 26 {
+                b'a' + digit
+            } else {
+                b'0' + digit - 26
+            });
+            id /= BASE;
+        }
+        digits.reverse();
+
+        f.write_str(PREFIX)?;
+        f.write_str(std::str::from_utf8(&digits).expect("ASCII digits"))
+    }
+}
+
+impl fmt::Debug for ResUid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ResUid({self})")
+    }
+}
+
+impl From<ResUid> for GodotString {
+    fn from(uid: ResUid) -> Self {
+        GodotString::from(uid.to_string())
+    }
+}
+
+/// Replaces a `uid://` path with the path of its resource file; other paths are returned unchanged.
+///
+/// Returns `None` for unknown UIDs.
+pub(crate) fn resolve_uid_path(path: &GodotString) -> Option<GodotString> {
+    let text = path.to_string();
+    if !text.starts_with(PREFIX) {
+        return Some(path.clone());
+    }
+
+    let resolved = ResUid::parse(&text)?.path()?;
+    Some(GodotString::from(resolved))
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+const PREFIX: &str = "uid://";
+
+/// Lowercase letters and digits.
+const BASE: u64 = 36;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::ResUid;
+
+    #[test]
+    fn res_uid_text_round_trip() {
+        let uid = ResUid::parse("uid://cecaux1sm7mo0").unwrap();
+        assert_eq!(uid.to_string(), "uid://cecaux1sm7mo0");
+        assert_eq!(ResUid::from_i64(uid.to_i64()), Some(uid));
+
+        let small = ResUid::from_i64(36 * 2 + 27).unwrap();
+        assert_eq!(small.to_string(), "uid://c1");
+        assert_eq!(ResUid::parse("uid://c1"), Some(small));
+    }
+
+    #[test]
+    fn res_uid_invalid() {
+        assert_eq!(ResUid::from_i64(-1), None);
+        assert_eq!(ResUid::parse("res://icon.svg"), None);
+        assert_eq!(ResUid::parse("uid://"), None);
+        assert_eq!(ResUid::parse("uid://<invalid>"), None);
+        assert_eq!(ResUid::parse("uid://ABC"), None);
+    }
+}
@@ -0,0 +1,133 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use rand_core::{impls, Error, RngCore, SeedableRng};
+
+use crate::engine::{utilities, RandomNumberGenerator};
+use crate::obj::Gd;
+
+/// Adapter using a Godot `RandomNumberGenerator` as [`RngCore`], so that it can drive `rand` distributions.
+///
+/// The generator is shared with Godot: numbers drawn through this adapter advance the same state as calls from GDScript, and
+/// seeding it with [`SeedableRng`] is equivalent to setting `RandomNumberGenerator.seed`. Runs with the same seed therefore produce
+/// the same sequence, no matter from which side the numbers are requested.
+///
+/// # Example
+/// ```ignore
+/// use godot::engine::GodotRng;
+/// use rand::{Rng, SeedableRng};
+///
+/// let mut rng = GodotRng::seed_from_u64(1234);
+/// let damage: u32 = rng.gen_range(10..20);
+/// ```
+///
+/// _Requires the `rand` Cargo feature._
+#[derive(Clone, Debug)]
+pub struct GodotRng {
+    rng: Gd<RandomNumberGenerator>,
+}
+
+impl GodotRng {
+    /// Creates a new generator with a random seed, like `RandomNumberGenerator.new()` in GDScript.
+    pub fn new() -> Self {
+        Self::from_generator(RandomNumberGenerator::new())
+    }
+
+    /// Draws numbers from an existing Godot generator.
+    pub fn from_generator(rng: Gd<RandomNumberGenerator>) -> Self {
+        Self { rng }
+    }
+
+    /// The underlying Godot generator.
+    pub fn generator(&self) -> &Gd<RandomNumberGenerator> {
+        &self.rng
+    }
+
+    /// Returns the underlying Godot generator.
+    pub fn into_generator(self) -> Gd<RandomNumberGenerator> {
+        self.rng
+    }
+}
+
+impl Default for GodotRng {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RngCore for GodotRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.randi()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_u32(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl SeedableRng for GodotRng {
+    type Seed = [u8; 8];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::seed_from_u64(u64::from_le_bytes(seed))
+    }
+
+    // Godot seeds are 64-bit values; use them directly instead of the default PCG expansion.
+    fn seed_from_u64(seed: u64) -> Self {
+        let mut rng = RandomNumberGenerator::new();
+        rng.set_seed(seed);
+
+        Self::from_generator(rng)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Adapter using Godot's global random number generator (`@GlobalScope.randi()` etc.) as [`RngCore`].
+///
+/// Use [`seed_global_rng()`] to seed the global generator from Rust, e.g. to make a whole session reproducible.
+///
+/// _Requires the `rand` Cargo feature._
+#[derive(Copy, Clone, Debug, Default)]
+pub struct GodotGlobalRng;
+
+impl RngCore for GodotGlobalRng {
+    fn next_u32(&mut self) -> u32 {
+        // randi() returns values in the full u32 range.
+        utilities::randi() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_u32(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// Seeds Godot's global random number generator with a value drawn from `rng`.
+///
+/// _Godot equivalent: @GlobalScope.seed()_
+///
+/// _Requires the `rand` Cargo feature._
+pub fn seed_global_rng<R: RngCore + ?Sized>(rng: &mut R) {
+    utilities::seed(rng.next_u64() as i64);
+}
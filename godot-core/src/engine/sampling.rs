@@ -0,0 +1,312 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::math::FloatExt;
+use crate::builtin::{
+    real, Color, PackedFloat32Array, PackedVector2Array, PackedVector3Array, Vector2, Vector2i,
+    Vector3,
+};
+use crate::engine::gradient::InterpolationMode;
+use crate::engine::{Curve, Curve2D, Curve3D, Gradient, Noise};
+
+/// Lookup table of a [`Curve`], sampled in Rust without calling into the engine.
+///
+/// The curve is evaluated once at evenly spaced offsets in its domain `0.0 ..= 1.0`; [`sample()`][Self::sample] then
+/// interpolates linearly between those values. This is the same approach as `Curve.sample_baked()`, but each sample is a plain
+/// Rust computation, which pays off when a curve is queried many times per frame.
+///
+/// The table is a snapshot: create a new one after the curve has been modified.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CurveSampler {
+    values: Vec<f32>,
+}
+
+impl CurveSampler {
+    /// Tabulates `curve` with its own bake resolution (`Curve.bake_resolution`).
+    pub fn new(curve: &Curve) -> Self {
+        Self::with_resolution(curve, curve.get_bake_resolution() as usize)
+    }
+
+    /// Tabulates `curve` at `resolution` intervals, i.e. `resolution + 1` values.
+    ///
+    /// # Panics
+    /// If `resolution` is zero.
+    pub fn with_resolution(curve: &Curve, resolution: usize) -> Self {
+        assert!(resolution > 0, "curve resolution must be positive");
+
+        let values = (0..=resolution)
+            .map(|i| curve.sample(i as f32 / resolution as f32))
+            .collect();
+
+        Self { values }
+    }
+
+    /// Returns the curve's value at `offset`, clamped to `0.0 ..= 1.0`.
+    pub fn sample(&self, offset: f32) -> f32 {
+        let last = self.values.len() - 1;
+        let pos = offset.clamp(0.0, 1.0) * last as f32;
+
+        let index = (pos as usize).min(last - 1);
+        let weight = pos - index as f32;
+
+        self.values[index].lerp(self.values[index + 1], weight)
+    }
+
+    /// The tabulated values, evenly spaced over the domain.
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Baked points of a [`Curve2D`], with sampling by distance in Rust.
+///
+/// This takes a snapshot of `Curve2D.get_baked_points()`; the points are available as a slice via [`points()`][Self::points].
+/// [`sample()`][Self::sample] interpolates linearly between them, like `Curve2D.sample_baked()` without cubic interpolation.
+#[derive(Clone, Debug)]
+pub struct BakedCurve2D {
+    points: PackedVector2Array,
+    distances: Vec<real>,
+}
+
+impl BakedCurve2D {
+    /// Bakes `curve` (if necessary) and copies its baked points.
+    pub fn new(curve: &Curve2D) -> Self {
+        let points = curve.get_baked_points();
+        let distances = cumulative_distances(points.as_slice(), Vector2::distance_to);
+
+        Self { points, distances }
+    }
+
+    /// The baked points, spaced approximately `Curve2D.bake_interval` apart.
+    pub fn points(&self) -> &[Vector2] {
+        self.points.as_slice()
+    }
+
+    /// Total length of the baked curve.
+    pub fn length(&self) -> real {
+        self.distances.last().copied().unwrap_or(0.0)
+    }
+
+    /// Returns the point at `offset` along the curve, clamped to `0.0 ..= length()`.
+    ///
+    /// Returns `Vector2::ZERO` if the curve has no points.
+    pub fn sample(&self, offset: real) -> Vector2 {
+        sample_polyline(self.points(), &self.distances, offset, Vector2::lerp)
+            .unwrap_or(Vector2::ZERO)
+    }
+}
+
+/// Baked points of a [`Curve3D`], with sampling by distance in Rust.
+///
+/// See [`BakedCurve2D`]; in addition, the baked tilts are available via [`tilts()`][Self::tilts].
+#[derive(Clone, Debug)]
+pub struct BakedCurve3D {
+    points: PackedVector3Array,
+    tilts: PackedFloat32Array,
+    distances: Vec<real>,
+}
+
+impl BakedCurve3D {
+    /// Bakes `curve` (if necessary) and copies its baked points and tilts.
+    pub fn new(curve: &Curve3D) -> Self {
+        let points = curve.get_baked_points();
+        let tilts = curve.get_baked_tilts();
+        let distances = cumulative_distances(points.as_slice(), Vector3::distance_to);
+
+        Self {
+            points,
+            tilts,
+            distances,
+        }
+    }
+
+    /// The baked points, spaced approximately `Curve3D.bake_interval` apart.
+    pub fn points(&self) -> &[Vector3] {
+        self.points.as_slice()
+    }
+
+    /// The tilt angle (in radians) at each baked point.
+    pub fn tilts(&self) -> &[f32] {
+        self.tilts.as_slice()
+    }
+
+    /// Total length of the baked curve.
+    pub fn length(&self) -> real {
+        self.distances.last().copied().unwrap_or(0.0)
+    }
+
+    /// Returns the point at `offset` along the curve, clamped to `0.0 ..= length()`.
+    ///
+    /// Returns `Vector3::ZERO` if the curve has no points.
+    pub fn sample(&self, offset: real) -> Vector3 {
+        sample_polyline(self.points(), &self.distances, offset, Vector3::lerp)
+            .unwrap_or(Vector3::ZERO)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Color stops of a [`Gradient`], with interpolation in Rust.
+///
+/// Supports all of the gradient's interpolation modes (linear, constant and cubic), and returns the same colors as
+/// `Gradient.sample()`. Interpolation happens on the stored components; for gradients that use a different interpolation color space
+/// (available since Godot 4.2), use `Gradient.sample()` instead.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GradientSampler {
+    offsets: Vec<f32>,
+    colors: Vec<Color>,
+    mode: InterpolationMode,
+}
+
+impl GradientSampler {
+    /// Copies the color stops of `gradient`.
+    pub fn new(gradient: &Gradient) -> Self {
+        let mut stops = gradient
+            .get_offsets()
+            .as_slice()
+            .iter()
+            .copied()
+            .zip(gradient.get_colors().as_slice().iter().copied())
+            .collect::<Vec<_>>();
+
+        // Godot keeps the points sorted internally, but the property arrays are in insertion order.
+        stops.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        let (offsets, colors) = stops.into_iter().unzip();
+
+        Self {
+            offsets,
+            colors,
+            mode: gradient.get_interpolation_mode(),
+        }
+    }
+
+    /// Returns the color at `offset`.
+    ///
+    /// Offsets before the first or after the last stop return the color of that stop. Returns `Color::BLACK` if the gradient has
+    /// no stops, like Godot.
+    pub fn sample(&self, offset: f32) -> Color {
+        let (Some(&first), Some(&last)) = (self.offsets.first(), self.offsets.last()) else {
+            return Color::BLACK;
+        };
+
+        if offset <= first {
+            return self.colors[0];
+        }
+        if offset >= last {
+            return self.colors[self.colors.len() - 1];
+        }
+
+        // First stop strictly after offset; exists because offset < last.
+        let high = self.offsets.partition_point(|&o| o <= offset);
+        let low = high - 1;
+
+        if self.mode == InterpolationMode::GRADIENT_INTERPOLATE_CONSTANT {
+            return self.colors[low];
+        }
+
+        let weight = (offset - self.offsets[low]) / (self.offsets[high] - self.offsets[low]);
+        let (from, to) = (self.colors[low], self.colors[high]);
+
+        if self.mode == InterpolationMode::GRADIENT_INTERPOLATE_CUBIC {
+            let pre = self.colors[low.saturating_sub(1)];
+            let post = self.colors[(high + 1).min(self.colors.len() - 1)];
+
+            Color::from_rgba(
+                from.r.cubic_interpolate(to.r, pre.r, post.r, weight),
+                from.g.cubic_interpolate(to.g, pre.g, post.g, weight),
+                from.b.cubic_interpolate(to.b, pre.b, post.b, weight),
+                from.a.cubic_interpolate(to.a, pre.a, post.a, weight),
+            )
+        } else {
+            from.lerp(to, weight as f64)
+        }
+    }
+
+    /// Offsets of the color stops, in ascending order.
+    pub fn offsets(&self) -> &[f32] {
+        &self.offsets
+    }
+
+    /// Colors of the stops, in the same order as [`offsets()`][Self::offsets].
+    pub fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Extension trait for sampling [`Noise`] (e.g. `FastNoiseLite`) in bulk.
+pub trait NoiseExt {
+    /// Samples a grid of `size.x * size.y` values, starting at `origin` and advancing `step` units per cell.
+    ///
+    /// The values are returned in row-major order, i.e. the value for cell `(x, y)` is at index `y * size.x + x`.
+    fn sample_grid_2d(&self, origin: Vector2, size: Vector2i, step: real) -> Vec<f32>;
+}
+
+impl NoiseExt for Noise {
+    fn sample_grid_2d(&self, origin: Vector2, size: Vector2i, step: real) -> Vec<f32> {
+        let width = size.x.max(0);
+        let height = size.y.max(0);
+
+        let mut values = Vec::with_capacity(width as usize * height as usize);
+        for y in 0..height {
+            let pos_y = origin.y + y as real * step;
+            for x in 0..width {
+                let pos_x = origin.x + x as real * step;
+                values.push(self.get_noise_2d(pos_x as f32, pos_y as f32));
+            }
+        }
+
+        values
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+fn cumulative_distances<V: Copy>(points: &[V], distance: fn(V, V) -> real) -> Vec<real> {
+    let mut total = 0.0;
+    let mut prev = points.first().copied();
+
+    points
+        .iter()
+        .map(|&point| {
+            if let Some(prev) = prev.replace(point) {
+                total += distance(prev, point);
+            }
+            total
+        })
+        .collect()
+}
+
+fn sample_polyline<V: Copy>(
+    points: &[V],
+    distances: &[real],
+    offset: real,
+    lerp: fn(V, V, real) -> V,
+) -> Option<V> {
+    let (&first, &length) = (points.first()?, distances.last()?);
+    if points.len() == 1 || length <= 0.0 {
+        return Some(first);
+    }
+
+    let offset = offset.clamp(0.0, length);
+    let high = distances
+        .partition_point(|&d| d <= offset)
+        .clamp(1, points.len() - 1);
+    let low = high - 1;
+
+    let segment = distances[high] - distances[low];
+    let weight = if segment > 0.0 {
+        (offset - distances[low]) / segment
+    } else {
+        0.0
+    };
+
+    Some(lerp(points[low], points[high], weight))
+}
@@ -0,0 +1,135 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::meta::ToGodot;
+use crate::builtin::{Dictionary, GodotString, VariantConversionError};
+use crate::engine::file_access::ModeFlags;
+use crate::engine::global::Error;
+use crate::engine::{ConfigFile, FileAccess};
+
+/// Persistent state of a Rust object, stored as a `Dictionary` from keys to field values.
+///
+/// This trait is usually derived with `#[derive(SaveState)]`. Only fields annotated with `#[save]` take part; the dictionary
+/// key is the field name, unless overridden with `#[save(key = "...")]`. Field types must implement both `ToGodot` and
+/// `FromGodot`.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::SaveState;
+///
+/// #[derive(GodotClass, SaveState)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     #[save]
+///     health: i32,
+///     #[save(key = "pos")]
+///     position: Vector2,
+///     // Not persisted.
+///     is_dashing: bool,
+/// }
+///
+/// fn save_game(player: &Player) {
+///     player.save_to_file("user://savegame.dat").expect("failed to save");
+/// }
+/// ```
+pub trait SaveState {
+    /// Collects the persisted fields into a dictionary.
+    fn save_state(&self) -> Dictionary;
+
+    /// Restores the persisted fields from `state`.
+    ///
+    /// Keys missing in `state` leave their fields unchanged, so that save files written by older versions remain loadable.
+    /// If any value has the wrong type, an error is returned and no field is modified.
+    fn load_state(&mut self, state: &Dictionary) -> Result<(), SaveStateError>;
+
+    /// Writes the state as individual keys of `section` in a `ConfigFile`.
+    ///
+    /// This does not save the config file itself; call `ConfigFile::save()` afterwards.
+    fn save_to_config(&self, config: &mut ConfigFile, section: impl Into<GodotString>) {
+        let section = section.into();
+        for (key, value) in self.save_state().iter_shared() {
+            config.set_value(section.clone(), key.stringify(), value);
+        }
+    }
+
+    /// Restores the state from the keys of `section` in a `ConfigFile`.
+    fn load_from_config(
+        &mut self,
+        config: &ConfigFile,
+        section: impl Into<GodotString>,
+    ) -> Result<(), SaveStateError> {
+        let section = section.into();
+        let mut state = Dictionary::new();
+
+        if config.has_section(section.clone()) {
+            for key in config.get_section_keys(section.clone()).as_slice() {
+                state.set(key.clone(), config.get_value(section.clone(), key.clone()));
+            }
+        }
+
+        self.load_state(&state)
+    }
+
+    /// Writes the state to the file at `path` (e.g. `user://savegame.dat`), in Godot's binary `Variant` serialization.
+    fn save_to_file(&self, path: impl Into<GodotString>) -> Result<(), SaveStateError> {
+        let mut file = FileAccess::open(path.into(), ModeFlags::WRITE).ok_or_else(open_error)?;
+        file.store_var(self.save_state().to_variant());
+
+        match file.get_error() {
+            Error::OK => Ok(()),
+            error => Err(SaveStateError::Io(error)),
+        }
+    }
+
+    /// Restores the state from a file written by [`save_to_file()`][Self::save_to_file].
+    fn load_from_file(&mut self, path: impl Into<GodotString>) -> Result<(), SaveStateError> {
+        let mut file = FileAccess::open(path.into(), ModeFlags::READ).ok_or_else(open_error)?;
+
+        let state = file
+            .get_var()
+            .try_to::<Dictionary>()
+            .map_err(|_| SaveStateError::NotADictionary)?;
+
+        self.load_state(&state)
+    }
+}
+
+/// Error while saving or restoring a [`SaveState`].
+#[derive(Debug, Eq, PartialEq)]
+pub enum SaveStateError {
+    /// The value stored under `key` could not be converted to the field's type.
+    InvalidValue {
+        key: String,
+        error: VariantConversionError,
+    },
+
+    /// The loaded data is not a dictionary.
+    NotADictionary,
+
+    /// A file could not be opened, read or written.
+    Io(Error),
+}
+
+impl fmt::Display for SaveStateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidValue { key, error } => write!(f, "invalid value for `{key}`: {error}"),
+            Self::NotADictionary => f.write_str("saved state is not a dictionary"),
+            Self::Io(error) => write!(f, "file access failed: {error:?}"),
+        }
+    }
+}
+
+impl std::error::Error for SaveStateError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+fn open_error() -> SaveStateError {
+    SaveStateError::Io(FileAccess::get_open_error())
+}
@@ -0,0 +1,673 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use crate::builtin::{StringName, Variant, VariantType};
+use crate::engine::global::PropertyUsageFlags;
+use crate::engine::{ClassDb, Node, Object, Resource};
+use crate::obj::{EngineEnum, Gd, Inherits};
+
+/// Canonical, engine-independent copy of a node subtree: classes, stored properties and children.
+///
+/// Snapshots make integration tests of scene-building code precise. Capture the scene your code generated, then compare it against
+/// a snapshot stored next to the test with [`assert_matches_file()`][Self::assert_matches_file], or inspect it directly:
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::NodeSnapshot;
+///
+/// fn check_level(level: &Gd<Node3D>) {
+///     let snapshot = NodeSnapshot::capture(level);
+///     snapshot.assert_matches_file(concat!(env!("CARGO_MANIFEST_DIR"), "/snapshots/level.snap"));
+/// }
+/// ```
+///
+/// Properties are those Godot would save in a scene file (usage `PROPERTY_USAGE_STORAGE`), and by default only where they differ
+/// from a new instance of the class. Values are kept as text from `Variant::stringify()`, except for objects, which are written as
+/// `<Class>` or, for resources loaded from a file, `<Class "res://path">` -- instance IDs differ between runs.
+///
+/// Children are identified by name, so give generated nodes explicit names; names Godot generates (`@Node3D@12`) depend on what
+/// else was created before.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NodeSnapshot {
+    pub name: String,
+    pub class: String,
+
+    /// Property names and their values in text form.
+    pub properties: BTreeMap<String, String>,
+
+    pub children: Vec<NodeSnapshot>,
+}
+
+impl NodeSnapshot {
+    /// Captures `node` and its descendants, with default options.
+    pub fn capture<N: Inherits<Node>>(node: &Gd<N>) -> Self {
+        Self::capture_with(node, &SnapshotOptions::default())
+    }
+
+    pub fn capture_with<N: Inherits<Node>>(node: &Gd<N>, options: &SnapshotOptions) -> Self {
+        let mut defaults = DefaultsCache::default();
+        capture_node(&node.clone().upcast(), options, &mut defaults)
+    }
+
+    /// Parses the text form written by [`to_text()`][Self::to_text].
+    pub fn from_text(text: &str) -> Result<Self, SnapshotParseError> {
+        parse(text)
+    }
+
+    /// Writes the snapshot as indented text, one line per node and property, e.g. for storing in a file.
+    ///
+    /// The output only depends on the snapshot's contents: properties are sorted by name, children keep their order in the tree.
+    pub fn to_text(&self) -> String {
+        self.to_string()
+    }
+
+    /// The descendant at `path`, relative to this node (e.g. `"Level/Player"`); `"."` for this node.
+    pub fn find(&self, path: &str) -> Option<&NodeSnapshot> {
+        if path == "." {
+            return Some(self);
+        }
+
+        path.split('/').try_fold(self, |node, name| {
+            node.children.iter().find(|child| child.name == name)
+        })
+    }
+
+    /// Differences from `self` (the expected snapshot) to `actual`; empty if both are equal.
+    ///
+    /// The names of the two root nodes are not compared, only their contents.
+    pub fn diff(&self, actual: &NodeSnapshot) -> Vec<SnapshotDiff> {
+        let mut diffs = Vec::new();
+        diff_nodes(self, actual, ".", &mut diffs);
+        diffs
+    }
+
+    /// ⚠️ Asserts that this snapshot matches `expected`.
+    ///
+    /// # Panics
+    /// If there are differences, which are listed in the panic message.
+    pub fn assert_matches(&self, expected: &NodeSnapshot) {
+        let diffs = expected.diff(self);
+        if !diffs.is_empty() {
+            panic!(
+                "scene differs from expected snapshot:\n{}",
+                format_diffs(&diffs)
+            );
+        }
+    }
+
+    /// ⚠️ Asserts that this snapshot matches the one stored in the file at `path`.
+    ///
+    /// If the file does not exist yet, or the environment variable `GDEXT_UPDATE_SNAPSHOTS` is set, the file is written with this
+    /// snapshot instead -- to record a new test, or to accept intended changes. `path` is a native file system path, not a `res://` one.
+    ///
+    /// # Panics
+    /// - If the stored snapshot differs, listing the differences.
+    /// - If the file cannot be read, parsed or written.
+    pub fn assert_matches_file(&self, path: impl AsRef<Path>) {
+        let path = path.as_ref();
+
+        if std::env::var_os(UPDATE_ENV_VAR).is_some() || !path.exists() {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir).unwrap_or_else(|err| {
+                    panic!("cannot create snapshot directory {}: {err}", dir.display())
+                });
+            }
+
+            fs::write(path, self.to_text())
+                .unwrap_or_else(|err| panic!("cannot write snapshot {}: {err}", path.display()));
+            return;
+        }
+
+        let text = fs::read_to_string(path)
+            .unwrap_or_else(|err| panic!("cannot read snapshot {}: {err}", path.display()));
+        let expected = Self::from_text(&text)
+            .unwrap_or_else(|err| panic!("cannot parse snapshot {}: {err}", path.display()));
+
+        let diffs = expected.diff(self);
+        if !diffs.is_empty() {
+            panic!(
+                "scene differs from snapshot {}:\n{}\n\nSet {UPDATE_ENV_VAR}=1 to accept the changes.",
+                path.display(),
+                format_diffs(&diffs)
+            );
+        }
+    }
+}
+
+impl fmt::Display for NodeSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_node(f, self, 0)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// What [`NodeSnapshot::capture_with()`] records.
+#[derive(Clone, Debug, Default)]
+pub struct SnapshotOptions {
+    include_defaults: bool,
+    ignored: BTreeSet<String>,
+}
+
+impl SnapshotOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether to record properties that have their class's default value; off by default.
+    pub fn include_defaults(mut self, include: bool) -> Self {
+        self.include_defaults = include;
+        self
+    }
+
+    /// Leaves out `property` on all nodes, e.g. values that change between runs such as random seeds.
+    pub fn ignore(mut self, property: impl Into<String>) -> Self {
+        self.ignored.insert(property.into());
+        self
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// A difference between two [`NodeSnapshot`]s, as returned by [`NodeSnapshot::diff()`].
+///
+/// Paths are relative to the root node, which itself has the path `"."`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SnapshotDiff {
+    /// A node exists only in the actual snapshot.
+    NodeAdded { path: String, class: String },
+
+    /// A node exists only in the expected snapshot.
+    NodeRemoved { path: String, class: String },
+
+    /// A node has a different class. Properties and children of such nodes are not compared.
+    ClassChanged {
+        path: String,
+        expected: String,
+        actual: String,
+    },
+
+    /// A property differs; `None` if the property is not recorded, e.g. because it has the default value.
+    PropertyChanged {
+        path: String,
+        property: String,
+        expected: Option<String>,
+        actual: Option<String>,
+    },
+
+    /// The children present in both snapshots are in a different order.
+    ChildOrderChanged { path: String },
+}
+
+impl fmt::Display for SnapshotDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NodeAdded { path, class } => write!(f, "+ {path} ({class})"),
+            Self::NodeRemoved { path, class } => write!(f, "- {path} ({class})"),
+            Self::ClassChanged {
+                path,
+                expected,
+                actual,
+            } => write!(f, "~ {path}: class {expected} -> {actual}"),
+            Self::PropertyChanged {
+                path,
+                property,
+                expected,
+                actual,
+            } => {
+                let expected = expected.as_deref().unwrap_or("(default)");
+                let actual = actual.as_deref().unwrap_or("(default)");
+                write!(f, "~ {path}: .{property} {expected} -> {actual}")
+            }
+            Self::ChildOrderChanged { path } => write!(f, "~ {path}: children reordered"),
+        }
+    }
+}
+
+/// Error parsing the text form of a [`NodeSnapshot`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SnapshotParseError {
+    /// Line of the error, starting at 1.
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for SnapshotParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for SnapshotParseError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+const UPDATE_ENV_VAR: &str = "GDEXT_UPDATE_SNAPSHOTS";
+
+/// Default property values per class, from a new instance each.
+#[derive(Default)]
+struct DefaultsCache {
+    by_class: HashMap<String, BTreeMap<String, String>>,
+}
+
+impl DefaultsCache {
+    fn get(&mut self, class: &str) -> &BTreeMap<String, String> {
+        self.by_class
+            .entry(class.to_string())
+            .or_insert_with(|| class_defaults(class))
+    }
+}
+
+fn capture_node(
+    node: &Gd<Node>,
+    options: &SnapshotOptions,
+    defaults: &mut DefaultsCache,
+) -> NodeSnapshot {
+    let class = node.get_class().to_string();
+    let object = node.clone().upcast::<Object>();
+
+    let class_defaults = (!options.include_defaults).then(|| defaults.get(&class));
+    let properties = stored_properties(&object)
+        .into_iter()
+        .filter(|name| !options.ignored.contains(name))
+        .map(|name| {
+            let value = canonical_value(&object.get(StringName::from(name.as_str())));
+            (name, value)
+        })
+        .filter(|(name, value)| class_defaults.map_or(true, |d| d.get(name) != Some(value)))
+        .collect();
+
+    let children = node
+        .get_children()
+        .iter_shared()
+        .map(|child| capture_node(&child, options, defaults))
+        .collect();
+
+    NodeSnapshot {
+        name: node.get_name().to_string(),
+        class,
+        properties,
+        children,
+    }
+}
+
+/// Names of the properties that Godot saves in scene files.
+fn stored_properties(object: &Gd<Object>) -> Vec<String> {
+    let storage = PropertyUsageFlags::PROPERTY_USAGE_STORAGE.ord() as i64;
+
+    object
+        .get_property_list()
+        .iter_shared()
+        .filter(|info| {
+            let usage = info
+                .get("usage")
+                .and_then(|usage| usage.try_to::<i64>().ok())
+                .unwrap_or(0);
+            usage & storage != 0
+        })
+        .map(|info| info.get_or_nil("name").to_string())
+        .collect()
+}
+
+/// Values of a new instance of `class`; empty if the class cannot be instantiated, in which case all properties are recorded.
+fn class_defaults(class: &str) -> BTreeMap<String, String> {
+    let db = ClassDb::singleton();
+    let class_name = StringName::from(class);
+    if !db.can_instantiate(class_name.clone()) {
+        return BTreeMap::new();
+    }
+
+    let Ok(object) = db.instantiate(class_name).try_to::<Gd<Object>>() else {
+        return BTreeMap::new();
+    };
+
+    let defaults = stored_properties(&object)
+        .into_iter()
+        .map(|name| {
+            let value = canonical_value(&object.get(StringName::from(name.as_str())));
+            (name, value)
+        })
+        .collect();
+
+    // Reference-counted instances go away with the last `Gd`.
+    if !object.is_class("RefCounted".into()) {
+        object.free();
+    }
+
+    defaults
+}
+
+fn canonical_value(value: &Variant) -> String {
+    if value.get_type() != VariantType::Object {
+        return value.stringify().to_string();
+    }
+
+    let Ok(object) = value.try_to::<Gd<Object>>() else {
+        return "null".to_string();
+    };
+
+    let class = object.get_class();
+    let path = object
+        .try_cast::<Resource>()
+        .map(|resource| resource.get_path().to_string())
+        .unwrap_or_default();
+
+    // Sub-resources embedded in a scene have paths like `res://level.tscn::Mesh_x3f1`, which are not stable.
+    if path.is_empty() || path.contains("::") {
+        format!("<{class}>")
+    } else {
+        format!("<{class} \"{path}\">")
+    }
+}
+
+fn diff_nodes(
+    expected: &NodeSnapshot,
+    actual: &NodeSnapshot,
+    path: &str,
+    diffs: &mut Vec<SnapshotDiff>,
+) {
+    if expected.class != actual.class {
+        diffs.push(SnapshotDiff::ClassChanged {
+            path: path.to_string(),
+            expected: expected.class.clone(),
+            actual: actual.class.clone(),
+        });
+        return;
+    }
+
+    let property_names: BTreeSet<&String> = expected
+        .properties
+        .keys()
+        .chain(actual.properties.keys())
+        .collect();
+
+    for property in property_names {
+        let expected_value = expected.properties.get(property);
+        let actual_value = actual.properties.get(property);
+
+        if expected_value != actual_value {
+            diffs.push(SnapshotDiff::PropertyChanged {
+                path: path.to_string(),
+                property: property.clone(),
+                expected: expected_value.cloned(),
+                actual: actual_value.cloned(),
+            });
+        }
+    }
+
+    let has_child = |node: &NodeSnapshot, name: &str| node.children.iter().any(|c| c.name == name);
+
+    for child in &expected.children {
+        let child_path = join_path(path, &child.name);
+        match actual.children.iter().find(|c| c.name == child.name) {
+            Some(actual_child) => diff_nodes(child, actual_child, &child_path, diffs),
+            None => diffs.push(SnapshotDiff::NodeRemoved {
+                path: child_path,
+                class: child.class.clone(),
+            }),
+        }
+    }
+
+    for child in &actual.children {
+        if !has_child(expected, &child.name) {
+            diffs.push(SnapshotDiff::NodeAdded {
+                path: join_path(path, &child.name),
+                class: child.class.clone(),
+            });
+        }
+    }
+
+    let common_order = |node: &NodeSnapshot, other: &NodeSnapshot| -> Vec<String> {
+        node.children
+            .iter()
+            .filter(|child| has_child(other, &child.name))
+            .map(|child| child.name.clone())
+            .collect()
+    };
+
+    if common_order(expected, actual) != common_order(actual, expected) {
+        diffs.push(SnapshotDiff::ChildOrderChanged {
+            path: path.to_string(),
+        });
+    }
+}
+
+fn join_path(parent: &str, name: &str) -> String {
+    if parent == "." {
+        name.to_string()
+    } else {
+        format!("{parent}/{name}")
+    }
+}
+
+fn format_diffs(diffs: &[SnapshotDiff]) -> String {
+    diffs
+        .iter()
+        .map(|diff| format!("  {diff}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+const INDENT: &str = "  ";
+
+fn write_node(f: &mut fmt::Formatter<'_>, node: &NodeSnapshot, depth: usize) -> fmt::Result {
+    let indent = INDENT.repeat(depth);
+    writeln!(f, "{indent}{}: {}", node.name, node.class)?;
+
+    for (name, value) in &node.properties {
+        writeln!(f, "{indent}{INDENT}.{name} = {}", escape(value))?;
+    }
+
+    for child in &node.children {
+        write_node(f, child, depth + 1)?;
+    }
+
+    Ok(())
+}
+
+/// Keeps each value on one line.
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+
+    result
+}
+
+/// Parses the output of `write_node()`: node lines `name: Class` and property lines `.name = value`, indented by depth.
+fn parse(text: &str) -> Result<NodeSnapshot, SnapshotParseError> {
+    // Nodes from the root to the current one; children are attached to their parent when popped.
+    let mut stack: Vec<NodeSnapshot> = Vec::new();
+
+    for (index, line) in text.lines().enumerate() {
+        let error = |message: &str| SnapshotParseError {
+            line: index + 1,
+            message: message.to_string(),
+        };
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let content = line.trim_start_matches(' ');
+        let spaces = line.len() - content.len();
+        if spaces % INDENT.len() != 0 {
+            return Err(error("indentation is not a multiple of two spaces"));
+        }
+        let depth = spaces / INDENT.len();
+
+        if let Some(property) = content.strip_prefix('.') {
+            let Some((name, value)) = property.split_once(" = ") else {
+                return Err(error("expected `.name = value`"));
+            };
+            if depth == 0 || depth > stack.len() {
+                return Err(error("property is not indented below a node"));
+            }
+
+            pop_to(&mut stack, depth);
+            let node = stack.last_mut().expect("depth checked");
+            node.properties.insert(name.to_string(), unescape(value));
+        } else {
+            let Some((name, class)) = content.split_once(": ") else {
+                return Err(error("expected `name: Class`"));
+            };
+            if depth > stack.len() {
+                return Err(error("node is indented too deep"));
+            }
+            if depth == 0 && !stack.is_empty() {
+                return Err(error("more than one root node"));
+            }
+
+            pop_to(&mut stack, depth);
+
+            stack.push(NodeSnapshot {
+                name: name.to_string(),
+                class: class.to_string(),
+                properties: BTreeMap::new(),
+                children: Vec::new(),
+            });
+        }
+    }
+
+    pop_to(&mut stack, 1);
+    stack.pop().ok_or_else(|| SnapshotParseError {
+        line: 1,
+        message: "no root node".to_string(),
+    })
+}
+
+/// Pops nodes until `depth` remain, attaching each to its parent.
+fn pop_to(stack: &mut Vec<NodeSnapshot>, depth: usize) {
+    while stack.len() > depth.max(1) {
+        let node = stack.pop().expect("stack not empty");
+        stack.last_mut().expect("parent exists").children.push(node);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(
+        name: &str,
+        class: &str,
+        properties: &[(&str, &str)],
+        children: Vec<NodeSnapshot>,
+    ) -> NodeSnapshot {
+        NodeSnapshot {
+            name: name.to_string(),
+            class: class.to_string(),
+            properties: properties
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            children,
+        }
+    }
+
+    fn sample() -> NodeSnapshot {
+        node(
+            "Level",
+            "Node3D",
+            &[("position", "(1, 2, 3)")],
+            vec![
+                node(
+                    "Floor",
+                    "MeshInstance3D",
+                    &[("mesh", "<BoxMesh>")],
+                    vec![node("Collider", "StaticBody3D", &[], vec![])],
+                ),
+                node("Sign", "Label3D", &[("text", "Line 1\nC:\\path")], vec![]),
+            ],
+        )
+    }
+
+    #[test]
+    fn text_roundtrip() {
+        let snapshot = sample();
+        let text = snapshot.to_text();
+
+        assert_eq!(
+            text,
+            "Level: Node3D\n  .position = (1, 2, 3)\n  Floor: MeshInstance3D\n    .mesh = <BoxMesh>\n    Collider: StaticBody3D\n  \
+             Sign: Label3D\n    .text = Line 1\\nC:\\\\path\n"
+        );
+        assert_eq!(NodeSnapshot::from_text(&text), Ok(snapshot));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = NodeSnapshot::from_text("Root: Node\n   .x = 1\n").unwrap_err();
+        assert_eq!(err.line, 2);
+
+        let err = NodeSnapshot::from_text("Root: Node\n    Child: Node\n").unwrap_err();
+        assert_eq!(err.line, 2);
+
+        let err = NodeSnapshot::from_text("Root: Node\nOther: Node\n").unwrap_err();
+        assert_eq!(err.line, 2);
+
+        assert!(NodeSnapshot::from_text("\n").is_err());
+    }
+
+    #[test]
+    fn diff_and_find() {
+        let expected = sample();
+        let mut actual = sample();
+        assert!(expected.diff(&actual).is_empty());
+
+        actual.properties.remove("position");
+        actual.children[0].children[0].class = "Area3D".to_string();
+        actual.children.swap(0, 1);
+        actual.children[0]
+            .children
+            .push(node("Glow", "OmniLight3D", &[], vec![]));
+        actual.children.push(node("Extra", "Node", &[], vec![]));
+
+        let diffs = expected.diff(&actual);
+        let lines: Vec<String> = diffs.iter().map(|d| d.to_string()).collect();
+        assert_eq!(
+            lines,
+            [
+                "~ .: .position (1, 2, 3) -> (default)",
+                "~ Floor/Collider: class StaticBody3D -> Area3D",
+                "+ Sign/Glow (OmniLight3D)",
+                "+ Extra (Node)",
+                "~ .: children reordered",
+            ]
+        );
+
+        assert_eq!(
+            expected.find("Floor/Collider").map(|n| n.class.as_str()),
+            Some("StaticBody3D")
+        );
+        assert_eq!(expected.find("."), Some(&expected));
+        assert_eq!(expected.find("Floor/Missing"), None);
+    }
+}
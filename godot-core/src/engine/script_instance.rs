@@ -0,0 +1,541 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+use sys::interface_fn;
+
+use crate::builtin::meta::{GodotType, PropertyInfo};
+use crate::builtin::{GodotString, StringName, Variant, VariantType};
+use crate::engine::global::MethodFlags;
+use crate::engine::{Object, Script, ScriptLanguage};
+use crate::obj::{EngineEnum, Gd};
+
+use std::cell::RefCell;
+use std::ffi::c_void;
+use std::panic::AssertUnwindSafe;
+
+/// Per-object state of a script, implemented in Rust.
+///
+/// Godot attaches a _script instance_ to every object that has a script. The instance decides which properties and methods the
+/// object has on top of those of its class, so implementing this trait is the main step in bringing a scripting language to Godot.
+/// Instances are created in [`ScriptExtensionVirtual::instance_create()`][crate::engine::ScriptExtensionVirtual::instance_create]
+/// via [`create_script_instance()`].
+///
+/// Godot routes `get()`, `set()` and `call()` on the object through the script instance first; the class members are only used if
+/// the instance does not handle the request (returns `false`, `None` or an invalid-method error).
+///
+/// The instance is borrowed for each call. Methods that take `&mut self` must not call back into the same object (e.g. through
+/// `Object::call()` on the owner); such calls panic, and Godot reports an error.
+pub trait ScriptInstance: 'static {
+    /// Sets the property `name`; returns `false` if the script has no such property.
+    fn set(&mut self, name: StringName, value: &Variant) -> bool;
+
+    /// Value of the property `name`, or `None` if the script has no such property.
+    fn get(&self, name: StringName) -> Option<Variant>;
+
+    /// Calls the method `method`.
+    ///
+    /// Return [`GDEXTENSION_CALL_ERROR_INVALID_METHOD`][sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD] for unknown methods, so that the
+    /// methods of the object's class can be called.
+    fn call(
+        &mut self,
+        method: StringName,
+        args: &[&Variant],
+    ) -> Result<Variant, sys::GDExtensionCallErrorType>;
+
+    /// The script this instance was created from.
+    fn get_script(&self) -> &Gd<Script>;
+
+    /// The language of the script.
+    fn get_language(&self) -> Gd<ScriptLanguage>;
+
+    /// Properties that the script adds to the object, e.g. for the inspector.
+    fn get_property_list(&self) -> Vec<PropertyInfo> {
+        Vec::new()
+    }
+
+    /// Methods that the script adds to the object.
+    fn get_method_list(&self) -> Vec<ScriptMethodInfo> {
+        Vec::new()
+    }
+
+    /// Whether the script has a method `method`; by default looked up in [`get_method_list()`][Self::get_method_list].
+    fn has_method(&self, method: StringName) -> bool {
+        self.get_method_list()
+            .iter()
+            .any(|info| info.method_name == method)
+    }
+
+    /// Type of the property `name`; by default looked up in [`get_property_list()`][Self::get_property_list].
+    fn get_property_type(&self, name: StringName) -> Option<VariantType> {
+        self.get_property_list()
+            .into_iter()
+            .find(|info| info.property_name == name)
+            .map(|info| info.variant_type)
+    }
+
+    /// Values to store when the object is serialized (e.g. in a scene); by default all properties in
+    /// [`get_property_list()`][Self::get_property_list].
+    fn get_property_state(&self) -> Vec<(StringName, Variant)> {
+        self.get_property_list()
+            .into_iter()
+            .filter_map(|info| {
+                let value = self.get(info.property_name.clone())?;
+                Some((info.property_name, value))
+            })
+            .collect()
+    }
+
+    /// String representation of the object, or `None` to use the default one.
+    fn to_string(&self) -> Option<GodotString> {
+        None
+    }
+
+    /// Called for each notification the object receives.
+    fn on_notification(&mut self, what: i32) {
+        let _ = what;
+    }
+
+    /// Called when the reference count of a `RefCounted` owner is incremented.
+    fn on_refcount_incremented(&self) {}
+
+    /// Called when the reference count of a `RefCounted` owner is decremented; returns whether the object may be freed.
+    fn on_refcount_decremented(&self) -> bool {
+        true
+    }
+
+    /// Whether this is a placeholder instance, as created in the editor for scripts that cannot run there.
+    fn is_placeholder(&self) -> bool {
+        false
+    }
+}
+
+/// Method exposed by a [`ScriptInstance`].
+#[derive(Debug)]
+pub struct ScriptMethodInfo {
+    pub method_name: StringName,
+    pub return_type: PropertyInfo,
+    pub arguments: Vec<PropertyInfo>,
+    pub flags: MethodFlags,
+}
+
+impl ScriptMethodInfo {
+    /// Method with the given parameters, each accepting any variant, and returning a variant.
+    pub fn new(method_name: impl Into<StringName>, param_names: &[&str]) -> Self {
+        Self {
+            method_name: method_name.into(),
+            return_type: variant_property_info(""),
+            arguments: param_names
+                .iter()
+                .map(|name| variant_property_info(name))
+                .collect(),
+            flags: MethodFlags::METHOD_FLAGS_DEFAULT,
+        }
+    }
+}
+
+/// Hands `instance` to Godot as the script instance of `for_object`.
+///
+/// The returned pointer must be returned from
+/// [`ScriptExtensionVirtual::instance_create()`][crate::engine::ScriptExtensionVirtual::instance_create], which transfers
+/// ownership to Godot. It frees the instance together with the object, or when the object's script is replaced.
+#[must_use]
+pub fn create_script_instance<T: ScriptInstance>(
+    instance: T,
+    for_object: &Gd<Object>,
+) -> *mut c_void {
+    // Godot keeps a pointer to the info struct, so it lives as long as the instance and is freed with it. Godot 4.2 deprecates this
+    // struct in favor of `GDExtensionScriptInstanceInfo2`, but still accepts it; the additions are not needed here.
+    let info = Box::new(sys::GDExtensionScriptInstanceInfo {
+        set_func: Some(callbacks::set::<T>),
+        get_func: Some(callbacks::get::<T>),
+        get_property_list_func: Some(callbacks::get_property_list::<T>),
+        free_property_list_func: Some(callbacks::free_property_list::<T>),
+        property_can_revert_func: None,
+        property_get_revert_func: None,
+        get_owner_func: Some(callbacks::get_owner::<T>),
+        get_property_state_func: Some(callbacks::get_property_state::<T>),
+        get_method_list_func: Some(callbacks::get_method_list::<T>),
+        free_method_list_func: Some(callbacks::free_method_list::<T>),
+        get_property_type_func: Some(callbacks::get_property_type::<T>),
+        has_method_func: Some(callbacks::has_method::<T>),
+        call_func: Some(callbacks::call::<T>),
+        notification_func: Some(callbacks::notification::<T>),
+        to_string_func: Some(callbacks::to_string::<T>),
+        refcount_incremented_func: Some(callbacks::refcount_incremented::<T>),
+        refcount_decremented_func: Some(callbacks::refcount_decremented::<T>),
+        get_script_func: Some(callbacks::get_script::<T>),
+        is_placeholder_func: Some(callbacks::is_placeholder::<T>),
+        set_fallback_func: None,
+        get_fallback_func: None,
+        get_language_func: Some(callbacks::get_language::<T>),
+        free_func: Some(callbacks::free::<T>),
+    });
+
+    let info_ptr: *const sys::GDExtensionScriptInstanceInfo = &*info;
+    let data = Box::new(ScriptInstanceData {
+        instance: RefCell::new(instance),
+        owner: for_object.obj_sys(),
+        property_lists: RefCell::new(Vec::new()),
+        method_lists: RefCell::new(Vec::new()),
+        _info: info,
+    });
+
+    // SAFETY: `info_ptr` and the data pointer stay valid until Godot calls `free_func`, which releases both.
+    unsafe { interface_fn!(script_instance_create)(info_ptr, Box::into_raw(data) as *mut c_void) }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+/// Data behind `GDExtensionScriptInstanceDataPtr`.
+struct ScriptInstanceData<T> {
+    instance: RefCell<T>,
+
+    /// Not a `Gd`, as it would keep a `RefCounted` owner alive.
+    owner: sys::GDExtensionObjectPtr,
+
+    /// Lists handed to Godot, until it frees them.
+    property_lists: RefCell<Vec<PropertyList>>,
+    method_lists: RefCell<Vec<MethodList>>,
+
+    _info: Box<sys::GDExtensionScriptInstanceInfo>,
+}
+
+/// Keeps the strings referenced by `sys` allocated.
+struct PropertyList {
+    _infos: Vec<PropertyInfo>,
+    sys: Vec<sys::GDExtensionPropertyInfo>,
+}
+
+struct MethodList {
+    _methods: Vec<ScriptMethodInfo>,
+    _arguments_sys: Vec<Vec<sys::GDExtensionPropertyInfo>>,
+    sys: Vec<sys::GDExtensionMethodInfo>,
+}
+
+impl PropertyList {
+    fn new(infos: Vec<PropertyInfo>) -> Self {
+        let sys = infos.iter().map(|info| info.property_sys()).collect();
+        Self { _infos: infos, sys }
+    }
+}
+
+impl MethodList {
+    fn new(methods: Vec<ScriptMethodInfo>) -> Self {
+        let mut arguments_sys: Vec<Vec<sys::GDExtensionPropertyInfo>> = methods
+            .iter()
+            .map(|method| {
+                method
+                    .arguments
+                    .iter()
+                    .map(|arg| arg.property_sys())
+                    .collect()
+            })
+            .collect();
+
+        let sys = methods
+            .iter()
+            .zip(&mut arguments_sys)
+            .map(|(method, arguments)| sys::GDExtensionMethodInfo {
+                name: method.method_name.string_sys(),
+                return_value: method.return_type.property_sys(),
+                flags: method.flags.ord() as u32,
+                id: 0,
+                argument_count: arguments.len() as u32,
+                arguments: arguments.as_mut_ptr(),
+                default_argument_count: 0,
+                default_arguments: std::ptr::null_mut(),
+            })
+            .collect();
+
+        Self {
+            _methods: methods,
+            _arguments_sys: arguments_sys,
+            sys,
+        }
+    }
+}
+
+fn variant_property_info(name: &str) -> PropertyInfo {
+    <Variant as GodotType>::property_info(name)
+}
+
+mod callbacks {
+    use super::*;
+
+    /// Runs `f` on the data behind `instance_ptr`, returning `default` if it panics (the panic has been printed in that case).
+    ///
+    /// # Safety
+    /// `instance_ptr` must point to a live `ScriptInstanceData<T>`.
+    unsafe fn with_data<T: ScriptInstance, R>(
+        instance_ptr: sys::GDExtensionScriptInstanceDataPtr,
+        callback: &str,
+        default: R,
+        f: impl FnOnce(&ScriptInstanceData<T>) -> R,
+    ) -> R {
+        let data = &*(instance_ptr as *const ScriptInstanceData<T>);
+        let ctx = || format!("error in script instance callback `{callback}`");
+
+        crate::private::handle_panic(ctx, AssertUnwindSafe(|| f(data))).unwrap_or(default)
+    }
+
+    /// # Safety
+    /// `ptr` must point to a string name that outlives the returned reference.
+    unsafe fn borrow_name<'a>(ptr: sys::GDExtensionConstStringNamePtr) -> &'a StringName {
+        &*(ptr as *const StringName)
+    }
+
+    fn borrow_mut<T: ScriptInstance>(data: &ScriptInstanceData<T>) -> std::cell::RefMut<'_, T> {
+        data.instance
+            .try_borrow_mut()
+            .expect("script instance called while another of its methods is running")
+    }
+
+    fn borrow<T: ScriptInstance>(data: &ScriptInstanceData<T>) -> std::cell::Ref<'_, T> {
+        data.instance
+            .try_borrow()
+            .expect("script instance called while one of its `&mut self` methods is running")
+    }
+
+    pub(super) unsafe extern "C" fn set<T: ScriptInstance>(
+        instance_ptr: sys::GDExtensionScriptInstanceDataPtr,
+        name: sys::GDExtensionConstStringNamePtr,
+        value: sys::GDExtensionConstVariantPtr,
+    ) -> sys::GDExtensionBool {
+        let name = borrow_name(name).clone();
+        let value = &*(value as *const Variant);
+
+        let done = with_data::<T, _>(instance_ptr, "set", false, |data| {
+            borrow_mut(data).set(name, value)
+        });
+        done as sys::GDExtensionBool
+    }
+
+    pub(super) unsafe extern "C" fn get<T: ScriptInstance>(
+        instance_ptr: sys::GDExtensionScriptInstanceDataPtr,
+        name: sys::GDExtensionConstStringNamePtr,
+        ret: sys::GDExtensionVariantPtr,
+    ) -> sys::GDExtensionBool {
+        let name = borrow_name(name).clone();
+
+        let value = with_data::<T, _>(instance_ptr, "get", None, |data| borrow(data).get(name));
+        match value {
+            Some(value) => {
+                *(ret as *mut Variant) = value;
+                true as sys::GDExtensionBool
+            }
+            None => false as sys::GDExtensionBool,
+        }
+    }
+
+    pub(super) unsafe extern "C" fn get_property_list<T: ScriptInstance>(
+        instance_ptr: sys::GDExtensionScriptInstanceDataPtr,
+        count: *mut u32,
+    ) -> *const sys::GDExtensionPropertyInfo {
+        let list = with_data::<T, _>(instance_ptr, "get_property_list", Vec::new(), |data| {
+            borrow(data).get_property_list()
+        });
+
+        let list = PropertyList::new(list);
+        let list_ptr = list.sys.as_ptr();
+        *count = list.sys.len() as u32;
+
+        let data = &*(instance_ptr as *const ScriptInstanceData<T>);
+        data.property_lists.borrow_mut().push(list);
+        list_ptr
+    }
+
+    pub(super) unsafe extern "C" fn free_property_list<T: ScriptInstance>(
+        instance_ptr: sys::GDExtensionScriptInstanceDataPtr,
+        list_ptr: *const sys::GDExtensionPropertyInfo,
+    ) {
+        let data = &*(instance_ptr as *const ScriptInstanceData<T>);
+        let mut lists = data.property_lists.borrow_mut();
+        if let Some(index) = lists.iter().position(|list| list.sys.as_ptr() == list_ptr) {
+            lists.swap_remove(index);
+        }
+    }
+
+    pub(super) unsafe extern "C" fn get_owner<T: ScriptInstance>(
+        instance_ptr: sys::GDExtensionScriptInstanceDataPtr,
+    ) -> sys::GDExtensionObjectPtr {
+        let data = &*(instance_ptr as *const ScriptInstanceData<T>);
+        data.owner
+    }
+
+    pub(super) unsafe extern "C" fn get_property_state<T: ScriptInstance>(
+        instance_ptr: sys::GDExtensionScriptInstanceDataPtr,
+        add_func: sys::GDExtensionScriptInstancePropertyStateAdd,
+        userdata: *mut c_void,
+    ) {
+        let Some(add_func) = add_func else {
+            return;
+        };
+
+        let state = with_data::<T, _>(instance_ptr, "get_property_state", Vec::new(), |data| {
+            borrow(data).get_property_state()
+        });
+
+        for (name, value) in state {
+            add_func(name.string_sys(), value.var_sys(), userdata);
+        }
+    }
+
+    pub(super) unsafe extern "C" fn get_method_list<T: ScriptInstance>(
+        instance_ptr: sys::GDExtensionScriptInstanceDataPtr,
+        count: *mut u32,
+    ) -> *const sys::GDExtensionMethodInfo {
+        let list = with_data::<T, _>(instance_ptr, "get_method_list", Vec::new(), |data| {
+            borrow(data).get_method_list()
+        });
+
+        let list = MethodList::new(list);
+        let list_ptr = list.sys.as_ptr();
+        *count = list.sys.len() as u32;
+
+        let data = &*(instance_ptr as *const ScriptInstanceData<T>);
+        data.method_lists.borrow_mut().push(list);
+        list_ptr
+    }
+
+    pub(super) unsafe extern "C" fn free_method_list<T: ScriptInstance>(
+        instance_ptr: sys::GDExtensionScriptInstanceDataPtr,
+        list_ptr: *const sys::GDExtensionMethodInfo,
+    ) {
+        let data = &*(instance_ptr as *const ScriptInstanceData<T>);
+        let mut lists = data.method_lists.borrow_mut();
+        if let Some(index) = lists.iter().position(|list| list.sys.as_ptr() == list_ptr) {
+            lists.swap_remove(index);
+        }
+    }
+
+    pub(super) unsafe extern "C" fn get_property_type<T: ScriptInstance>(
+        instance_ptr: sys::GDExtensionScriptInstanceDataPtr,
+        name: sys::GDExtensionConstStringNamePtr,
+        is_valid: *mut sys::GDExtensionBool,
+    ) -> sys::GDExtensionVariantType {
+        let name = borrow_name(name).clone();
+
+        let ty = with_data::<T, _>(instance_ptr, "get_property_type", None, |data| {
+            borrow(data).get_property_type(name)
+        });
+
+        *is_valid = ty.is_some() as sys::GDExtensionBool;
+        ty.unwrap_or(VariantType::Nil).sys()
+    }
+
+    pub(super) unsafe extern "C" fn has_method<T: ScriptInstance>(
+        instance_ptr: sys::GDExtensionScriptInstanceDataPtr,
+        method: sys::GDExtensionConstStringNamePtr,
+    ) -> sys::GDExtensionBool {
+        let method = borrow_name(method).clone();
+
+        let has = with_data::<T, _>(instance_ptr, "has_method", false, |data| {
+            borrow(data).has_method(method)
+        });
+        has as sys::GDExtensionBool
+    }
+
+    pub(super) unsafe extern "C" fn call<T: ScriptInstance>(
+        instance_ptr: sys::GDExtensionScriptInstanceDataPtr,
+        method: sys::GDExtensionConstStringNamePtr,
+        args_ptr: *const sys::GDExtensionConstVariantPtr,
+        arg_count: sys::GDExtensionInt,
+        ret: sys::GDExtensionVariantPtr,
+        err: *mut sys::GDExtensionCallError,
+    ) {
+        *err = sys::default_call_error();
+
+        let method = borrow_name(method).clone();
+        let args = Variant::unbounded_refs_from_sys(args_ptr, arg_count as usize);
+
+        // A panic has already been reported; classes do not get to handle the call in that case.
+        let panicked = Err(sys::GDEXTENSION_CALL_ERROR_INSTANCE_IS_NULL);
+        let result = with_data::<T, _>(instance_ptr, "call", panicked, |data| {
+            borrow_mut(data).call(method, args)
+        });
+
+        match result {
+            Ok(value) => *(ret as *mut Variant) = value,
+            Err(error) => (*err).error = error,
+        }
+    }
+
+    pub(super) unsafe extern "C" fn notification<T: ScriptInstance>(
+        instance_ptr: sys::GDExtensionScriptInstanceDataPtr,
+        what: i32,
+    ) {
+        with_data::<T, _>(instance_ptr, "notification", (), |data| {
+            borrow_mut(data).on_notification(what)
+        });
+    }
+
+    pub(super) unsafe extern "C" fn to_string<T: ScriptInstance>(
+        instance_ptr: sys::GDExtensionScriptInstanceDataPtr,
+        is_valid: *mut sys::GDExtensionBool,
+        out_string: sys::GDExtensionStringPtr,
+    ) {
+        let string = with_data::<T, _>(instance_ptr, "to_string", None, |data| {
+            borrow(data).to_string()
+        });
+
+        *is_valid = string.is_some() as sys::GDExtensionBool;
+        if let Some(string) = string {
+            string.move_string_ptr(out_string);
+        }
+    }
+
+    pub(super) unsafe extern "C" fn refcount_incremented<T: ScriptInstance>(
+        instance_ptr: sys::GDExtensionScriptInstanceDataPtr,
+    ) {
+        with_data::<T, _>(instance_ptr, "refcount_incremented", (), |data| {
+            borrow(data).on_refcount_incremented()
+        });
+    }
+
+    pub(super) unsafe extern "C" fn refcount_decremented<T: ScriptInstance>(
+        instance_ptr: sys::GDExtensionScriptInstanceDataPtr,
+    ) -> sys::GDExtensionBool {
+        let can_die = with_data::<T, _>(instance_ptr, "refcount_decremented", true, |data| {
+            borrow(data).on_refcount_decremented()
+        });
+        can_die as sys::GDExtensionBool
+    }
+
+    pub(super) unsafe extern "C" fn get_script<T: ScriptInstance>(
+        instance_ptr: sys::GDExtensionScriptInstanceDataPtr,
+    ) -> sys::GDExtensionObjectPtr {
+        // The script is kept alive by the instance, so the pointer stays valid after the borrow ends.
+        with_data::<T, _>(instance_ptr, "get_script", std::ptr::null_mut(), |data| {
+            borrow(data).get_script().obj_sys()
+        })
+    }
+
+    pub(super) unsafe extern "C" fn is_placeholder<T: ScriptInstance>(
+        instance_ptr: sys::GDExtensionScriptInstanceDataPtr,
+    ) -> sys::GDExtensionBool {
+        let placeholder = with_data::<T, _>(instance_ptr, "is_placeholder", false, |data| {
+            borrow(data).is_placeholder()
+        });
+        placeholder as sys::GDExtensionBool
+    }
+
+    pub(super) unsafe extern "C" fn get_language<T: ScriptInstance>(
+        instance_ptr: sys::GDExtensionScriptInstanceDataPtr,
+    ) -> sys::GDExtensionScriptLanguagePtr {
+        // Languages are not reference-counted; Godot keeps them registered as long as scripts use them.
+        with_data::<T, _>(instance_ptr, "get_language", std::ptr::null_mut(), |data| {
+            borrow(data).get_language().obj_sys() as sys::GDExtensionScriptLanguagePtr
+        })
+    }
+
+    pub(super) unsafe extern "C" fn free<T: ScriptInstance>(
+        instance_ptr: sys::GDExtensionScriptInstanceDataPtr,
+    ) {
+        let _drop = Box::from_raw(instance_ptr as *mut ScriptInstanceData<T>);
+    }
+}
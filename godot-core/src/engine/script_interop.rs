@@ -0,0 +1,191 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::meta::{AsArg, FromGodot, ToGodot};
+use crate::builtin::{StringName, Variant, VariantConversionError};
+use crate::engine::Object;
+use crate::obj::{Gd, GodotClass, Inherits};
+
+/// Extension trait for typed, dynamic access to methods and properties that are not known at compile time.
+///
+/// This is mostly useful for members defined in scripts -- GDScript, or C# scripts in the same project. For the latter, keep in mind
+/// that Godot exposes C# members under their C# names, so a method `TakeDamage()` must be called as `"TakeDamage"`, not
+/// `"take_damage"`; see [`csharp_member_name()`] and [`godot_member_name()`] to translate between both conventions.
+///
+/// Rust classes are registered in `ClassDB` like engine classes, so C# (and GDScript) code can use them without further setup: through
+/// `ClassDB.Instantiate("MyClass")`, or the generated `GodotObject` methods `Call()`, `Get()` and `Set()`, using the snake-case names
+/// of `#[func]` and `#[var]` members.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{csharp_member_name, DynamicCallExt};
+///
+/// fn damage(mut enemy: Gd<Node>) {
+///     // Enemy has a C# script with `public int TakeDamage(int amount)` and `[Export] public int Health`.
+///     let remaining: i32 = enemy.call_typed(csharp_member_name("take_damage"), &[10.to_variant()]);
+///     let health: i32 = enemy.get_typed("Health");
+///     assert_eq!(remaining, health);
+/// }
+/// ```
+pub trait DynamicCallExt {
+    /// ⚠️ Calls `method` with `args`, converting its return value to `R`.
+    ///
+    /// # Panics
+    /// If the return value cannot be converted to `R`. Calls to unknown methods are reported by Godot and return `null`, which then
+    /// fails to convert unless `R` accepts nil (e.g. `Variant` or `()`).
+    fn call_typed<R: FromGodot>(&mut self, method: impl AsArg<StringName>, args: &[Variant]) -> R {
+        let method = method.into_arg();
+        self.try_call_typed(&*method, args)
+            .unwrap_or_else(|err| panic!("return value of `{method}` cannot be converted: {err}"))
+    }
+
+    /// Calls `method` with `args`, converting its return value to `R` (fallible).
+    fn try_call_typed<R: FromGodot>(
+        &mut self,
+        method: impl AsArg<StringName>,
+        args: &[Variant],
+    ) -> Result<R, VariantConversionError>;
+
+    /// ⚠️ Returns the value of `property`, converted to `T`.
+    ///
+    /// # Panics
+    /// If the property does not exist or its value cannot be converted to `T`.
+    fn get_typed<T: FromGodot>(&self, property: impl AsArg<StringName>) -> T {
+        let property = property.into_arg();
+        self.try_get_typed(&*property)
+            .unwrap_or_else(|err| panic!("property `{property}` cannot be converted: {err}"))
+    }
+
+    /// Returns the value of `property`, converted to `T` (fallible).
+    fn try_get_typed<T: FromGodot>(
+        &self,
+        property: impl AsArg<StringName>,
+    ) -> Result<T, VariantConversionError>;
+
+    /// Sets `property` to `value`.
+    fn set_typed<T: ToGodot>(&mut self, property: impl AsArg<StringName>, value: T);
+}
+
+impl DynamicCallExt for Object {
+    fn try_call_typed<R: FromGodot>(
+        &mut self,
+        method: impl AsArg<StringName>,
+        args: &[Variant],
+    ) -> Result<R, VariantConversionError> {
+        self.call(method.into_arg().into_owned(), args)
+            .try_to::<R>()
+    }
+
+    fn try_get_typed<T: FromGodot>(
+        &self,
+        property: impl AsArg<StringName>,
+    ) -> Result<T, VariantConversionError> {
+        self.get(property.into_arg().into_owned()).try_to::<T>()
+    }
+
+    fn set_typed<T: ToGodot>(&mut self, property: impl AsArg<StringName>, value: T) {
+        self.set(property.into_arg().into_owned(), value.to_variant());
+    }
+}
+
+impl<U> DynamicCallExt for Gd<U>
+where
+    U: GodotClass + Inherits<Object>,
+{
+    fn try_call_typed<R: FromGodot>(
+        &mut self,
+        method: impl AsArg<StringName>,
+        args: &[Variant],
+    ) -> Result<R, VariantConversionError> {
+        let mut object = self.clone().upcast::<Object>();
+        <Object as DynamicCallExt>::try_call_typed(&mut *object, method, args)
+    }
+
+    fn try_get_typed<T: FromGodot>(
+        &self,
+        property: impl AsArg<StringName>,
+    ) -> Result<T, VariantConversionError> {
+        let object = self.clone().upcast::<Object>();
+        <Object as DynamicCallExt>::try_get_typed(&*object, property)
+    }
+
+    fn set_typed<T: ToGodot>(&mut self, property: impl AsArg<StringName>, value: T) {
+        let mut object = self.clone().upcast::<Object>();
+        <Object as DynamicCallExt>::set_typed(&mut *object, property, value)
+    }
+}
+
+/// Converts a Godot member name (`snake_case`) to the name of the corresponding C# member (`PascalCase`).
+///
+/// This follows the convention of Godot's C# bindings, e.g. `get_node` becomes `GetNode` and `_ready` becomes `_Ready`.
+pub fn csharp_member_name(godot_name: &str) -> String {
+    let trimmed = godot_name.trim_start_matches('_');
+    let mut result = String::with_capacity(godot_name.len());
+    result.push_str(&godot_name[..godot_name.len() - trimmed.len()]);
+
+    for part in trimmed.split('_') {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            result.extend(first.to_uppercase());
+            result.push_str(chars.as_str());
+        }
+    }
+
+    result
+}
+
+/// Converts a C# member name (`PascalCase`) to the corresponding Godot name (`snake_case`).
+///
+/// Acronyms are kept together, so `GetHTTPClient` becomes `get_http_client`. This is the inverse of [`csharp_member_name()`] for
+/// names without acronyms.
+pub fn godot_member_name(csharp_name: &str) -> String {
+    let chars: Vec<char> = csharp_name.chars().collect();
+    let mut result = String::with_capacity(csharp_name.len() + 4);
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).map_or(false, |next| next.is_lowercase());
+
+            if prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next_is_lower)
+            {
+                result.push('_');
+            }
+        }
+
+        result.extend(c.to_lowercase());
+    }
+
+    result
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn member_names() {
+        let cases = [
+            ("get_node", "GetNode"),
+            ("_ready", "_Ready"),
+            ("take_damage", "TakeDamage"),
+            ("vector2_value", "Vector2Value"),
+            ("health", "Health"),
+        ];
+
+        for (godot, csharp) in cases {
+            assert_eq!(csharp_member_name(godot), csharp);
+            assert_eq!(godot_member_name(csharp), godot);
+        }
+
+        assert_eq!(godot_member_name("GetHTTPClient"), "get_http_client");
+    }
+}
@@ -0,0 +1,184 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::meta::{AsArg, FromGodot, ToGodot};
+use crate::builtin::StringName;
+use crate::engine::ShaderMaterial;
+use crate::obj::Gd;
+
+#[cfg(debug_assertions)]
+use crate::{
+    builtin::{Dictionary, Variant, VariantType},
+    engine::Shader,
+    sys,
+};
+
+/// Extension trait for typed access to the uniforms of a `ShaderMaterial`.
+///
+/// In debug builds, every write is checked against the uniforms declared by the material's shader: misspelled names and values
+/// of an incompatible type cause a panic, instead of being silently ignored by Godot. In release builds, the values are forwarded
+/// to [`ShaderMaterial::set_shader_parameter()`] without any checks. Materials without a shader, or whose shader does not report any
+/// uniforms (e.g. because it failed to compile), are not validated either.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{ShaderMaterial, ShaderMaterialExt};
+///
+/// let mut material = ShaderMaterial::new();
+/// material.set_parameter_typed("albedo_tint", Color::from_rgb(1.0, 0.5, 0.0));
+/// ```
+pub trait ShaderMaterialExt {
+    /// Sets the shader uniform `name` to `value`.
+    ///
+    /// # Panics
+    /// In debug builds, if the shader has no uniform `name`, or if the uniform's type does not accept `value`.
+    fn set_parameter_typed<T: ToGodot>(&mut self, name: impl AsArg<StringName>, value: T);
+
+    /// ⚠️ Returns the current value of the shader uniform `name`, converted to `T`.
+    ///
+    /// # Panics
+    /// If the value cannot be converted to `T`.
+    fn get_parameter_typed<T: FromGodot>(&self, name: impl AsArg<StringName>) -> T {
+        let name = name.into_arg();
+        self.try_get_parameter_typed(&*name).unwrap_or_else(|| {
+            panic!("shader parameter `{name}` cannot be converted to the requested type")
+        })
+    }
+
+    /// Returns the current value of the shader uniform `name`, converted to `T` (fallible).
+    ///
+    /// If the value is unset or cannot be converted to `T`, `None` is returned.
+    fn try_get_parameter_typed<T: FromGodot>(&self, name: impl AsArg<StringName>) -> Option<T>;
+
+    /// Writes all uniforms of `uniforms` into this material.
+    ///
+    /// See [`ShaderUniforms`] for how to derive the mapping from a Rust struct.
+    fn set_uniforms<U: ShaderUniforms>(&mut self, uniforms: &U) {
+        uniforms.apply_to(self);
+    }
+}
+
+impl ShaderMaterialExt for ShaderMaterial {
+    fn set_parameter_typed<T: ToGodot>(&mut self, name: impl AsArg<StringName>, value: T) {
+        let name = name.into_arg().into_owned();
+        let value = value.to_variant();
+
+        #[cfg(debug_assertions)]
+        if let Some(shader) = self.get_shader() {
+            validate_uniform(&shader, &name, &value);
+        }
+
+        self.set_shader_parameter(name, value);
+    }
+
+    fn try_get_parameter_typed<T: FromGodot>(&self, name: impl AsArg<StringName>) -> Option<T> {
+        let value = self.get_shader_parameter(name.into_arg().into_owned());
+        if value.is_nil() {
+            return None;
+        }
+
+        value.try_to::<T>().ok()
+    }
+}
+
+impl ShaderMaterialExt for Gd<ShaderMaterial> {
+    fn set_parameter_typed<T: ToGodot>(&mut self, name: impl AsArg<StringName>, value: T) {
+        <ShaderMaterial as ShaderMaterialExt>::set_parameter_typed(&mut **self, name, value)
+    }
+
+    fn try_get_parameter_typed<T: FromGodot>(&self, name: impl AsArg<StringName>) -> Option<T> {
+        <ShaderMaterial as ShaderMaterialExt>::try_get_parameter_typed(&**self, name)
+    }
+}
+
+/// A set of shader uniforms that can be written to a [`ShaderMaterial`] in one go.
+///
+/// This trait is usually derived with `#[derive(ShaderUniforms)]`. Each field maps to the uniform of the same name;
+/// use `#[uniform(name = "...")]` on a field to map it to a differently-named uniform, or `#[uniform(skip)]` to exclude it.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{ShaderMaterial, ShaderMaterialExt};
+///
+/// #[derive(ShaderUniforms)]
+/// struct WaterUniforms {
+///     albedo_tint: Color,
+///     #[uniform(name = "wave_speed")]
+///     speed: f32,
+/// }
+///
+/// let mut material = ShaderMaterial::new();
+/// material.set_uniforms(&WaterUniforms { albedo_tint: Color::WHITE, speed: 2.0 });
+/// ```
+pub trait ShaderUniforms {
+    /// Writes every uniform into `material`, with the same validation as [`ShaderMaterialExt::set_parameter_typed()`].
+    fn apply_to<M: ShaderMaterialExt + ?Sized>(&self, material: &mut M);
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+#[cfg(debug_assertions)]
+fn validate_uniform(shader: &Gd<Shader>, name: &StringName, value: &Variant) {
+    let name_str = name.to_string();
+    let uniforms = shader.get_shader_uniform_list();
+
+    // Shaders that failed to compile (or run on a dummy rendering server) report no uniforms; nothing to validate against.
+    if uniforms.is_empty() {
+        return;
+    }
+
+    let declared = uniforms.iter_shared().find_map(|uniform| {
+        let uniform = uniform.try_to::<Dictionary>().ok()?;
+        let uniform_name = uniform.get("name")?.try_to::<String>().ok()?;
+
+        (uniform_name == name_str).then(|| uniform.get("type"))
+    });
+
+    let Some(declared) = declared else {
+        let available = uniforms
+            .iter_shared()
+            .filter_map(|uniform| uniform.try_to::<Dictionary>().ok()?.get("name"))
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        panic!("shader has no uniform `{name_str}`; declared uniforms: [{available}]");
+    };
+
+    let Some(declared_ty) = declared.and_then(|ty| ty.try_to::<i64>().ok()) else {
+        return;
+    };
+
+    let declared_ty = VariantType::from_sys(declared_ty as sys::GDExtensionVariantType);
+    let actual_ty = value.get_type();
+
+    assert!(
+        is_uniform_compatible(declared_ty, actual_ty),
+        "shader uniform `{name_str}` has type {declared_ty:?}, but was assigned a value of type {actual_ty:?}"
+    );
+}
+
+/// Whether Godot accepts a value of type `actual` for a uniform reported as `declared`.
+#[cfg(debug_assertions)]
+fn is_uniform_compatible(declared: VariantType, actual: VariantType) -> bool {
+    use VariantType as V;
+
+    match (declared, actual) {
+        // Setting `null` resets the uniform to its default.
+        (_, V::Nil) => true,
+        (d, a) if d == a => true,
+
+        // `vec3`/`vec4` uniforms accept colors, and `source_color` uniforms accept vectors.
+        (V::Color, V::Vector3 | V::Vector4) | (V::Vector3 | V::Vector4, V::Color) => true,
+
+        // Scalars are converted by the rendering server.
+        (V::Float, V::Int) => true,
+
+        _ => false,
+    }
+}
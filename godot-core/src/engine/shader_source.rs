@@ -0,0 +1,516 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::builtin::GodotString;
+use crate::engine::rendering_device::{ShaderLanguage, ShaderStage};
+use crate::engine::{RdShaderSource, RdShaderSpirv, RenderingDevice, Shader};
+use crate::obj::Gd;
+
+/// Shader code assembled in Rust, with `#include`s resolved and `#define`s added before it is passed to Godot.
+///
+/// Each line of the assembled code remembers where it came from, so that compilation errors can be reported against the original
+/// file and line, e.g. the Rust file containing an inline shader. Create sources with the [`shader_source!`][crate::shader_source]
+/// macro to record that location, or with [`new()`][Self::new] for generated code.
+///
+/// Includes registered with [`include()`][Self::include] replace lines of the form `#include "name"`, also within other
+/// includes. Other `#include` lines are passed on unchanged, so that Godot can resolve `res://` includes of `.gdshader` code itself.
+///
+/// ```no_run
+/// use godot::engine::{shader_source, ShaderSource};
+///
+/// let shader = shader_source!(file = "shaders/water.gdshader")
+///     .include("noise.gdshaderinc", shader_source!(file = "shaders/noise.gdshaderinc"))
+///     .define("FOAM")
+///     .define_value("WAVE_COUNT", 4)
+///     .to_shader()
+///     .expect("shader includes are valid");
+/// ```
+#[derive(Clone, Debug)]
+pub struct ShaderSource {
+    code: String,
+    origin: String,
+    first_line: u32,
+    defines: Vec<(String, String)>,
+    includes: HashMap<String, ShaderSource>,
+}
+
+impl ShaderSource {
+    /// Code without a file of origin; errors are reported against `<shader>` and the line within `code`.
+    pub fn new(code: impl Into<String>) -> Self {
+        Self::with_origin(code, "<shader>", 1)
+    }
+
+    /// Code whose first line is line `first_line` of the file `origin`.
+    pub fn with_origin(
+        code: impl Into<String>,
+        origin: impl Into<String>,
+        first_line: u32,
+    ) -> Self {
+        Self {
+            code: code.into(),
+            origin: origin.into(),
+            first_line,
+            defines: Vec::new(),
+            includes: HashMap::new(),
+        }
+    }
+
+    /// Adds `#define name`, for use with `#ifdef name`.
+    pub fn define(self, name: &str) -> Self {
+        self.define_value(name, "")
+    }
+
+    /// Adds `#define name value`.
+    pub fn define_value(mut self, name: &str, value: impl fmt::Display) -> Self {
+        self.defines.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Substitutes `source` for the lines `#include "name"`.
+    pub fn include(mut self, name: &str, source: ShaderSource) -> Self {
+        self.includes.insert(name.to_string(), source);
+        self
+    }
+
+    /// The assembled code, as passed to Godot.
+    ///
+    /// The defines follow the first `shader_type` or `#version` line, which must stay first; without one, they precede the code.
+    pub fn expand(&self) -> Result<String, ShaderError> {
+        Ok(self.expand_mapped()?.code)
+    }
+
+    /// Creates a `Shader` resource with the assembled code.
+    ///
+    /// Godot reports errors in `.gdshader` code only to the output log, against the lines of the assembled code.
+    pub fn to_shader(&self) -> Result<Gd<Shader>, ShaderError> {
+        let mut shader = Shader::new();
+        shader.set_code(GodotString::from(self.expand()?));
+
+        Ok(shader)
+    }
+
+    fn expand_mapped(&self) -> Result<Expanded, ShaderError> {
+        let header = self
+            .code
+            .lines()
+            .position(|line| {
+                let line = line.trim_start();
+                line.starts_with("shader_type") || line.starts_with("#version")
+            })
+            .map(|index| index + 1)
+            .unwrap_or(0);
+
+        let mut expanded = Expanded::default();
+        let mut include_stack = Vec::new();
+
+        self.expand_lines(
+            &mut expanded,
+            &self.includes,
+            &mut include_stack,
+            Some(header),
+        )?;
+        Ok(expanded)
+    }
+
+    fn expand_lines(
+        &self,
+        expanded: &mut Expanded,
+        includes: &HashMap<String, ShaderSource>,
+        include_stack: &mut Vec<String>,
+        defines_after: Option<usize>,
+    ) -> Result<(), ShaderError> {
+        for (index, line) in self.code.lines().enumerate() {
+            if defines_after == Some(index) {
+                self.expand_defines(expanded);
+            }
+
+            let included = parse_include(line).and_then(|name| Some((name, includes.get(name)?)));
+            if let Some((name, include)) = included {
+                if include_stack.iter().any(|open| open == name) {
+                    return Err(ShaderError::IncludeCycle {
+                        name: name.to_string(),
+                    });
+                }
+
+                include_stack.push(name.to_string());
+                include.expand_lines(expanded, includes, include_stack, None)?;
+                include_stack.pop();
+                continue;
+            }
+
+            expanded.push(
+                line,
+                Some(SourceLocation {
+                    origin: self.origin.clone(),
+                    line: self.first_line + index as u32,
+                }),
+            );
+        }
+
+        // A header on the last line, or no code at all.
+        if defines_after.is_some_and(|after| after >= self.code.lines().count()) {
+            self.expand_defines(expanded);
+        }
+        Ok(())
+    }
+
+    fn expand_defines(&self, expanded: &mut Expanded) {
+        for (name, value) in &self.defines {
+            let line = if value.is_empty() {
+                format!("#define {name}")
+            } else {
+                format!("#define {name} {value}")
+            };
+
+            expanded.push(&line, None);
+        }
+    }
+}
+
+/// Creates a [`ShaderSource`] that reports errors against the Rust source.
+///
+/// - `shader_source!(file = "path")` embeds the file at `path`, relative to the current Rust file like `include_str!`.
+///   Errors are reported against `path`.
+/// - `shader_source!(r#"..."#)` uses an inline literal. Errors are reported against the line of the Rust file, assuming that the
+///   literal starts on the line of the macro invocation.
+///
+/// ```no_run
+/// use godot::engine::shader_source;
+///
+/// let shader = shader_source!(r#"
+/// shader_type canvas_item;
+///
+/// void fragment() {
+///     COLOR.rgb = vec3(1.0) - COLOR.rgb;
+/// }
+/// "#);
+/// ```
+#[macro_export]
+macro_rules! shader_source {
+    (file = $path:literal) => {
+        $crate::engine::ShaderSource::with_origin(include_str!($path), $path, 1)
+    };
+    ($code:expr) => {
+        $crate::engine::ShaderSource::with_origin($code, file!(), line!())
+    };
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// GLSL stages compiled to SPIR-V for the [`RenderingDevice`], e.g. for compute shaders.
+///
+/// Unlike `.gdshader` code, GLSL is compiled synchronously, so errors are returned as [`ShaderDiagnostic`]s pointing to the
+/// original lines of each stage's [`ShaderSource`].
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::rendering_device::ShaderStage;
+/// use godot::engine::{shader_source, GlslProgram, RenderingServer};
+///
+/// let mut device = RenderingServer::singleton()
+///     .create_local_rendering_device()
+///     .expect("Forward+ or Mobile renderer required");
+///
+/// let spirv = GlslProgram::new()
+///     .stage(ShaderStage::SHADER_STAGE_COMPUTE, shader_source!(file = "shaders/blur.glsl"))
+///     .compile(&mut device);
+///
+/// match spirv {
+///     Ok(spirv) => { /* device.shader_create_from_spirv(spirv) */ }
+///     Err(error) => godot_error!("{error}"),
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct GlslProgram {
+    stages: Vec<(ShaderStage, ShaderSource)>,
+}
+
+impl GlslProgram {
+    /// A program without stages.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the code of `stage`, replacing previous code for it.
+    pub fn stage(mut self, stage: ShaderStage, source: ShaderSource) -> Self {
+        self.stages.retain(|(existing, _)| *existing != stage);
+        self.stages.push((stage, source));
+        self
+    }
+
+    /// Creates an `RDShaderSource` with the assembled code of each stage.
+    pub fn to_rd_source(&self) -> Result<Gd<RdShaderSource>, ShaderError> {
+        let (rd_source, _) = self.assemble()?;
+        Ok(rd_source)
+    }
+
+    /// Compiles all stages on `device`.
+    pub fn compile(
+        &self,
+        device: &mut Gd<RenderingDevice>,
+    ) -> Result<Gd<RdShaderSpirv>, ShaderError> {
+        let (rd_source, expanded_stages) = self.assemble()?;
+
+        let Some(spirv) = device.shader_compile_spirv_from_source(rd_source) else {
+            return Err(ShaderError::Compile {
+                diagnostics: vec![ShaderDiagnostic {
+                    stage: None,
+                    location: None,
+                    message: "rendering device did not compile the shader".to_string(),
+                }],
+            });
+        };
+
+        let diagnostics: Vec<ShaderDiagnostic> = expanded_stages
+            .iter()
+            .flat_map(|(stage, expanded)| {
+                let log = spirv.get_stage_compile_error(*stage).to_string();
+                parse_compile_log(&log, *stage, expanded)
+            })
+            .collect();
+
+        if diagnostics.is_empty() {
+            Ok(spirv)
+        } else {
+            Err(ShaderError::Compile { diagnostics })
+        }
+    }
+
+    fn assemble(&self) -> Result<(Gd<RdShaderSource>, Vec<(ShaderStage, Expanded)>), ShaderError> {
+        let mut rd_source = RdShaderSource::new();
+        rd_source.set_language(ShaderLanguage::SHADER_LANGUAGE_GLSL);
+
+        let mut expanded_stages = Vec::with_capacity(self.stages.len());
+        for (stage, source) in &self.stages {
+            let expanded = source.expand_mapped()?;
+            rd_source.set_stage_source(*stage, GodotString::from(expanded.code.as_str()));
+            expanded_stages.push((*stage, expanded));
+        }
+
+        Ok((rd_source, expanded_stages))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Line of a file that shader code originates from.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SourceLocation {
+    /// File of the code, as passed to [`ShaderSource::with_origin()`].
+    pub origin: String,
+
+    /// Line within `origin`, starting at 1.
+    pub line: u32,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.origin, self.line)
+    }
+}
+
+/// Error reported by the shader compiler.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShaderDiagnostic {
+    /// The stage whose code caused the error, if known.
+    pub stage: Option<ShaderStage>,
+
+    /// Where the error is in the original code, if the compiler reported a line that does not stem from a `#define`.
+    pub location: Option<SourceLocation>,
+
+    /// The compiler's description of the error.
+    pub message: String,
+}
+
+impl fmt::Display for ShaderDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.location, self.stage) {
+            (Some(location), _) => write!(f, "{location}: {}", self.message),
+            (None, Some(stage)) => write!(f, "{stage:?}: {}", self.message),
+            (None, None) => f.write_str(&self.message),
+        }
+    }
+}
+
+/// Error while assembling or compiling shader code.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ShaderError {
+    /// The include `name` includes itself, directly or indirectly.
+    IncludeCycle { name: String },
+
+    /// The code did not compile.
+    Compile { diagnostics: Vec<ShaderDiagnostic> },
+}
+
+impl fmt::Display for ShaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IncludeCycle { name } => write!(f, "shader include `{name}` includes itself"),
+            Self::Compile { diagnostics } => {
+                write!(f, "shader compilation failed")?;
+                for diagnostic in diagnostics {
+                    write!(f, "\n  {diagnostic}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ShaderError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+/// Assembled code, with the origin of each line.
+#[derive(Default)]
+struct Expanded {
+    code: String,
+
+    /// Per line of `code`; `None` for generated lines.
+    lines: Vec<Option<SourceLocation>>,
+}
+
+impl Expanded {
+    fn push(&mut self, line: &str, location: Option<SourceLocation>) {
+        self.code.push_str(line);
+        self.code.push('\n');
+        self.lines.push(location);
+    }
+
+    /// The origin of line `line` of the assembled code, starting at 1.
+    fn location(&self, line: usize) -> Option<SourceLocation> {
+        self.lines.get(line.checked_sub(1)?)?.clone()
+    }
+}
+
+/// The name in `#include "name"`, or `None` for other lines.
+fn parse_include(line: &str) -> Option<&str> {
+    let rest = line.trim().strip_prefix("#include")?.trim();
+
+    rest.strip_prefix('"')?.strip_suffix('"')
+}
+
+/// Parses the glslang log, with errors of the form `ERROR: 0:<line>: <message>`.
+fn parse_compile_log(log: &str, stage: ShaderStage, expanded: &Expanded) -> Vec<ShaderDiagnostic> {
+    log.lines()
+        .filter_map(|line| line.trim().strip_prefix("ERROR:"))
+        .map(str::trim)
+        // Summary line, e.g. "1 compilation errors.  No code generated."
+        .filter(|message| !message.contains("compilation errors"))
+        .map(|message| {
+            let mut parts = message.splitn(3, ':');
+            let located = match (parts.next(), parts.next(), parts.next()) {
+                (Some(string), Some(line), Some(rest)) if string.trim().parse::<u32>().is_ok() => {
+                    line.trim()
+                        .parse::<usize>()
+                        .ok()
+                        .map(|line| (line, rest.trim()))
+                }
+                _ => None,
+            };
+
+            match located {
+                Some((line, rest)) => ShaderDiagnostic {
+                    stage: Some(stage),
+                    location: expanded.location(line),
+                    message: rest.to_string(),
+                },
+                None => ShaderDiagnostic {
+                    stage: Some(stage),
+                    location: None,
+                    message: message.to_string(),
+                },
+            }
+        })
+        .collect()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_compile_log, ShaderError, ShaderSource, SourceLocation};
+    use crate::engine::rendering_device::ShaderStage;
+
+    fn location(origin: &str, line: u32) -> Option<SourceLocation> {
+        Some(SourceLocation {
+            origin: origin.to_string(),
+            line,
+        })
+    }
+
+    #[test]
+    fn shader_source_expand() {
+        let noise =
+            ShaderSource::with_origin("float noise() {\n    return 0.5;\n}", "noise.glsl", 1);
+        let source = ShaderSource::with_origin(
+            "#version 450\n#include \"noise.glsl\"\nvoid main() {}",
+            "compute.rs",
+            40,
+        )
+        .include("noise.glsl", noise)
+        .define("FAST")
+        .define_value("SIZE", 8);
+
+        let expanded = source.expand_mapped().unwrap();
+        assert_eq!(
+            expanded.code,
+            "#version 450\n#define FAST\n#define SIZE 8\nfloat noise() {\n    return 0.5;\n}\nvoid main() {}\n"
+        );
+
+        assert_eq!(expanded.location(1), location("compute.rs", 40));
+        assert_eq!(expanded.location(2), None);
+        assert_eq!(expanded.location(5), location("noise.glsl", 2));
+        assert_eq!(expanded.location(7), location("compute.rs", 42));
+        assert_eq!(expanded.location(8), None);
+    }
+
+    #[test]
+    fn shader_source_include_cycle() {
+        let a = ShaderSource::new("#include \"b\"");
+        let b = ShaderSource::new("#include \"a\"");
+        let source = ShaderSource::new("#include \"a\"")
+            .include("a", a)
+            .include("b", b);
+
+        assert_eq!(
+            source.expand(),
+            Err(ShaderError::IncludeCycle {
+                name: "a".to_string()
+            })
+        );
+
+        let unknown = ShaderSource::new("#include \"res://lib.gdshaderinc\"").define("X");
+        assert_eq!(
+            unknown.expand().unwrap(),
+            "#define X\n#include \"res://lib.gdshaderinc\"\n"
+        );
+    }
+
+    #[test]
+    fn shader_compile_log_mapped() {
+        let source =
+            ShaderSource::with_origin("#version 450\nvoid main() {\n    foo();\n}", "blur.rs", 10)
+                .define("RADIUS");
+        let expanded = source.expand_mapped().unwrap();
+
+        let log = "Failed parse:\nERROR: 0:4: 'foo' : no matching overloaded function found\n\
+                   ERROR: 0:1: '' : compilation terminated\nERROR: 1 compilation errors.  No code generated.\n";
+        let diagnostics = parse_compile_log(log, ShaderStage::SHADER_STAGE_COMPUTE, &expanded);
+
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].location, location("blur.rs", 12));
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "blur.rs:12: 'foo' : no matching overloaded function found"
+        );
+        assert_eq!(diagnostics[1].location, location("blur.rs", 10));
+    }
+}
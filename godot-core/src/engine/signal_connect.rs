@@ -0,0 +1,471 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::builtin::{Array, Callable, Dictionary, StringName, VariantArray, VariantType};
+use crate::engine::global::{Error, MethodFlags};
+use crate::engine::{ClassDb, Object};
+use crate::obj::{EngineEnum, Gd, GodotClass, Inherits};
+use crate::sys;
+
+/// Extension trait to connect signals with a check of the receiving method's signature.
+///
+/// [`Object::connect()`] accepts any callable; if the target method's parameters do not match the signal, this only shows up once the
+/// signal is emitted -- as a Godot error that is easy to miss, or as a conversion panic inside a Rust `#[func]`. In debug builds,
+/// [`connect_checked()`][Self::connect_checked] compares both signatures (as registered in `ClassDB`) upfront and panics with a
+/// description of the mismatch. In release builds, it is equivalent to `connect()`.
+///
+/// For signals of engine classes, [`TypedConnectExt`] additionally checks the signal name at compile time.
+///
+/// Only standard callables (object + method name) are checked. Custom callables, such as those from [`Callable::from_fn()`] or
+/// GDScript lambdas, receive arguments as a list of variants and accept any signature.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{Button, ConnectExt};
+///
+/// fn wire_up(mut button: Gd<Button>, receiver: Gd<Node>) {
+///     // Panics in debug builds if `on_toggled` does not take exactly one `bool` parameter.
+///     button.connect_checked("toggled", receiver.callable("on_toggled"));
+/// }
+/// ```
+pub trait ConnectExt {
+    /// Connects `signal` to `callable`, validating their signatures in debug builds.
+    ///
+    /// Returns the error code of [`Object::connect()`]. To pass connect flags, call
+    /// [`validate_connection()`][Self::validate_connection] followed by [`Object::connect_ex()`].
+    ///
+    /// # Panics
+    /// In debug builds, if [`validate_connection()`][Self::validate_connection] fails.
+    fn connect_checked(&mut self, signal: impl Into<StringName>, callable: Callable) -> Error;
+
+    /// Checks whether `callable` can receive the arguments of `signal`, without connecting anything.
+    fn validate_connection(
+        &self,
+        signal: impl Into<StringName>,
+        callable: &Callable,
+    ) -> Result<(), ConnectError>;
+}
+
+impl ConnectExt for Object {
+    fn connect_checked(&mut self, signal: impl Into<StringName>, callable: Callable) -> Error {
+        let signal = signal.into();
+
+        #[cfg(debug_assertions)]
+        if let Err(err) = self.validate_connection(signal.clone(), &callable) {
+            panic!("cannot connect signal: {err}");
+        }
+
+        self.connect(signal, callable)
+    }
+
+    fn validate_connection(
+        &self,
+        signal: impl Into<StringName>,
+        callable: &Callable,
+    ) -> Result<(), ConnectError> {
+        validate_connection(self, &signal.into(), callable)
+    }
+}
+
+impl<U> ConnectExt for Gd<U>
+where
+    U: GodotClass + Inherits<Object>,
+{
+    fn connect_checked(&mut self, signal: impl Into<StringName>, callable: Callable) -> Error {
+        let mut object = self.clone().upcast::<Object>();
+        <Object as ConnectExt>::connect_checked(&mut *object, signal, callable)
+    }
+
+    fn validate_connection(
+        &self,
+        signal: impl Into<StringName>,
+        callable: &Callable,
+    ) -> Result<(), ConnectError> {
+        let object = self.clone().upcast::<Object>();
+        <Object as ConnectExt>::validate_connection(&*object, signal, callable)
+    }
+}
+
+/// Name of a signal declared by the engine class `C`.
+///
+/// Generated as associated constant of each engine class, such as [`Node::SIGNAL_RENAMED`][crate::engine::Node::SIGNAL_RENAMED]
+/// for the signal `renamed`. Signals are constants of the class declaring them, as in Godot's documentation: `pressed` is
+/// `BaseButton::SIGNAL_PRESSED`, not `Button::SIGNAL_PRESSED`.
+///
+/// Connecting through [`TypedConnectExt::connect_signal()`] checks at compile time that the signal exists and that the emitting
+/// object's class has it. It converts to `StringName` for other APIs taking a signal name.
+pub struct SignalName<C: GodotClass> {
+    name: &'static str,
+    _class: PhantomData<fn() -> C>,
+}
+
+impl<C: GodotClass> SignalName<C> {
+    #[doc(hidden)]
+    pub const fn __new(name: &'static str) -> Self {
+        Self {
+            name,
+            _class: PhantomData,
+        }
+    }
+
+    /// The signal name as used in Godot, e.g. `"tree_entered"`.
+    pub const fn as_str(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn to_string_name(&self) -> StringName {
+        StringName::from(self.name)
+    }
+}
+
+impl<C: GodotClass> Clone for SignalName<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: GodotClass> Copy for SignalName<C> {}
+
+impl<C: GodotClass> PartialEq for SignalName<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl<C: GodotClass> Eq for SignalName<C> {}
+
+impl<C: GodotClass> fmt::Debug for SignalName<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SignalName({}::{})", C::class_name(), self.name)
+    }
+}
+
+impl<C: GodotClass> fmt::Display for SignalName<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+impl<C: GodotClass> From<SignalName<C>> for StringName {
+    fn from(signal: SignalName<C>) -> Self {
+        signal.to_string_name()
+    }
+}
+
+/// Extension trait to connect engine signals given as [`SignalName`] constants.
+///
+/// Unlike string names, a misspelled constant does not compile, and neither does a signal of a class the object does not inherit.
+/// The connections are validated like [`ConnectExt::connect_checked()`], which also catches mismatching method signatures.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{BaseButton, Button, TypedConnectExt};
+///
+/// fn wire_up(mut button: Gd<Button>, receiver: Gd<Node>) {
+///     button.connect_signal(BaseButton::SIGNAL_PRESSED, receiver.callable("on_pressed"));
+/// }
+/// ```
+///
+/// Signals declared by Rust classes with `#[signal]` or by scripts have no constants; connect these with [`ConnectExt`].
+pub trait TypedConnectExt<C: GodotClass> {
+    /// Connects `signal` to `callable`, validating their signatures in debug builds.
+    ///
+    /// # Panics
+    /// In debug builds, if the signatures do not match; see [`ConnectExt::validate_connection()`].
+    fn connect_signal(&mut self, signal: SignalName<C>, callable: Callable) -> Error;
+
+    /// Whether `signal` is connected to `callable`.
+    fn is_signal_connected(&self, signal: SignalName<C>, callable: &Callable) -> bool;
+
+    fn disconnect_signal(&mut self, signal: SignalName<C>, callable: &Callable);
+}
+
+impl<U, C> TypedConnectExt<C> for Gd<U>
+where
+    U: GodotClass + Inherits<C> + Inherits<Object>,
+    C: GodotClass,
+{
+    fn connect_signal(&mut self, signal: SignalName<C>, callable: Callable) -> Error {
+        self.connect_checked(signal, callable)
+    }
+
+    fn is_signal_connected(&self, signal: SignalName<C>, callable: &Callable) -> bool {
+        let object = self.clone().upcast::<Object>();
+        object.is_connected(signal.into(), callable.clone())
+    }
+
+    fn disconnect_signal(&mut self, signal: SignalName<C>, callable: &Callable) {
+        let mut object = self.clone().upcast::<Object>();
+        object.disconnect(signal.into(), callable.clone());
+    }
+}
+
+/// Mismatch between a signal and the method it is connected to.
+///
+/// See [`ConnectExt::validate_connection()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConnectError {
+    /// The emitting object of class `class` has no signal `signal`.
+    UnknownSignal { class: String, signal: String },
+
+    /// The target object of class `class` has no method `method`.
+    UnknownMethod { class: String, method: String },
+
+    /// The signal provides `signal_args` arguments, but the method needs between `method_min` and `method_max`.
+    ArgCount {
+        signal: String,
+        method: String,
+        signal_args: usize,
+        method_min: usize,
+        method_max: usize,
+    },
+
+    /// The signal's argument at position `index` has a type which the method's corresponding parameter does not accept.
+    ArgType {
+        signal: String,
+        method: String,
+        index: usize,
+        signal_ty: String,
+        method_ty: String,
+    },
+}
+
+impl fmt::Display for ConnectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownSignal { class, signal } => {
+                write!(f, "class `{class}` has no signal `{signal}`")
+            }
+            Self::UnknownMethod { class, method } => {
+                write!(f, "class `{class}` has no method `{method}`")
+            }
+            Self::ArgCount {
+                signal,
+                method,
+                signal_args,
+                method_min,
+                method_max,
+            } => {
+                let expected = if method_min == method_max {
+                    format!("{method_min}")
+                } else {
+                    format!("{method_min} to {method_max}")
+                };
+
+                write!(
+                    f,
+                    "signal `{signal}` has {signal_args} argument(s), but method `{method}` takes {expected}"
+                )
+            }
+            Self::ArgType {
+                signal,
+                method,
+                index,
+                signal_ty,
+                method_ty,
+            } => write!(
+                f,
+                "argument #{index} of signal `{signal}` has type `{signal_ty}`, \
+                but method `{method}` expects `{method_ty}`"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConnectError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+/// Type of a signal argument or method parameter, as reported by `get_signal_list()`/`get_method_list()`.
+struct ArgInfo {
+    ty: VariantType,
+    class_name: String,
+}
+
+impl ArgInfo {
+    fn from_dict(dict: &Dictionary) -> Self {
+        let ty = dict
+            .get("type")
+            .and_then(|ty| ty.try_to::<i64>().ok())
+            .map(|ty| VariantType::from_sys(ty as sys::GDExtensionVariantType))
+            .unwrap_or(VariantType::Nil);
+
+        let class_name = dict
+            .get("class_name")
+            .map(|name| name.to_string())
+            .unwrap_or_default();
+
+        Self { ty, class_name }
+    }
+
+    /// Whether a parameter of this type accepts arguments of type `arg`.
+    ///
+    /// With `strict`, argument and parameter types must be identical; otherwise, any type that `Variant` can convert is accepted.
+    fn accepts(&self, arg: &ArgInfo, strict: bool) -> bool {
+        match (self.ty, arg.ty) {
+            // Untyped (Variant) on either side: only known at emission.
+            (VariantType::Nil, _) | (_, VariantType::Nil) => true,
+
+            (VariantType::Object, VariantType::Object) => {
+                let db = ClassDb::singleton();
+                let param_class = StringName::from(self.class_name.as_str());
+                let arg_class = StringName::from(arg.class_name.as_str());
+
+                // Script classes (GDScript `class_name`) are not known to ClassDB; give them the benefit of the doubt.
+                if !db.class_exists(param_class.clone()) || !db.class_exists(arg_class.clone()) {
+                    return true;
+                }
+
+                db.is_parent_class(arg_class, param_class)
+            }
+
+            // Rust methods convert variants strictly, so e.g. an `int` argument is not accepted by an `f64` parameter.
+            (param, arg) if strict => param == arg,
+
+            // Engine and script methods coerce arguments, e.g. `int` to `float`.
+            // SAFETY: only takes two variant types.
+            (param, arg) => unsafe {
+                sys::interface_fn!(variant_can_convert)(arg.sys(), param.sys()) != 0
+            },
+        }
+    }
+}
+
+impl fmt::Display for ArgInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.ty {
+            VariantType::Nil => write!(f, "Variant"),
+            VariantType::Object if !self.class_name.is_empty() => write!(f, "{}", self.class_name),
+            ty => write!(f, "{ty:?}"),
+        }
+    }
+}
+
+fn validate_connection(
+    object: &Object,
+    signal: &StringName,
+    callable: &Callable,
+) -> Result<(), ConnectError> {
+    let signal_str = signal.to_string();
+
+    let Some(signal_info) = find_by_name(object.get_signal_list(), &signal_str) else {
+        return Err(ConnectError::UnknownSignal {
+            class: object.get_class().to_string(),
+            signal: signal_str,
+        });
+    };
+
+    // Custom callables take `&[&Variant]` (or are bound/unbound, which changes the arity); nothing to check.
+    if callable.is_custom() {
+        return Ok(());
+    }
+
+    // Null callables are rejected by Godot itself.
+    let (Some(target), Some(method)) = (callable.object(), callable.method_name()) else {
+        return Ok(());
+    };
+
+    let method_str = method.to_string();
+    let Some(method_info) = find_by_name(target.get_method_list(), &method_str) else {
+        return Err(ConnectError::UnknownMethod {
+            class: target.get_class().to_string(),
+            method: method_str,
+        });
+    };
+
+    let flags = method_info
+        .get("flags")
+        .and_then(|flags| flags.try_to::<i64>().ok())
+        .unwrap_or(0);
+
+    if flags & MethodFlags::METHOD_FLAG_VARARG.ord() as i64 != 0 {
+        return Ok(());
+    }
+
+    let strict = is_rust_method(&target, &method);
+    let signal_args = arg_infos(&signal_info);
+    let method_params = arg_infos(&method_info);
+    let default_count = method_info
+        .get("default_args")
+        .and_then(|args| args.try_to::<VariantArray>().ok())
+        .map_or(0, |args| args.len());
+
+    let method_max = method_params.len();
+    let method_min = method_max.saturating_sub(default_count);
+
+    if !(method_min..=method_max).contains(&signal_args.len()) {
+        return Err(ConnectError::ArgCount {
+            signal: signal_str,
+            method: method_str,
+            signal_args: signal_args.len(),
+            method_min,
+            method_max,
+        });
+    }
+
+    for (index, (arg, param)) in signal_args.iter().zip(&method_params).enumerate() {
+        if !param.accepts(arg, strict) {
+            return Err(ConnectError::ArgType {
+                signal: signal_str,
+                method: method_str,
+                index,
+                signal_ty: arg.to_string(),
+                method_ty: param.to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether `method` is declared by a Rust class, i.e. a `#[func]`, as opposed to an engine or script method.
+fn is_rust_method(target: &Object, method: &StringName) -> bool {
+    let db = ClassDb::singleton();
+    let mut class = StringName::from(target.get_class());
+
+    while !class.is_empty() {
+        let declares_method = db
+            .class_has_method_ex(class.clone(), method.clone())
+            .no_inheritance(true)
+            .done();
+
+        if declares_method {
+            let class_str = class.to_string();
+            return crate::registry::registered_classes()
+                .iter()
+                .any(|registered| registered.class_name.as_str() == class_str);
+        }
+
+        class = db.get_parent_class(class);
+    }
+
+    // Only declared by a script.
+    false
+}
+
+fn find_by_name(list: Array<Dictionary>, name: &str) -> Option<Dictionary> {
+    list.iter_shared()
+        .find(|entry| entry.get_or_nil("name").to_string() == name)
+}
+
+fn arg_infos(info: &Dictionary) -> Vec<ArgInfo> {
+    let Some(args) = info
+        .get("args")
+        .and_then(|args| args.try_to::<VariantArray>().ok())
+    else {
+        return Vec::new();
+    };
+
+    args.iter_shared()
+        .filter_map(|arg| arg.try_to::<Dictionary>().ok())
+        .map(|arg| ArgInfo::from_dict(&arg))
+        .collect()
+}
@@ -0,0 +1,238 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use crate::builtin::{Callable, StringName, Variant};
+use crate::engine::object::ConnectFlags;
+use crate::engine::{Node, Object, SceneTree};
+use crate::obj::{EngineEnum, Gd, GodotClass, Inherits};
+
+/// Future that completes the next time an object emits a given signal.
+///
+/// The signal is connected (as one-shot) when the future is created, not when it is first polled, so emissions between creation
+/// and the first `.await` are not missed. The future does not depend on any particular executor: once the signal fires, the waker of
+/// the last poll is woken, from the thread that emitted the signal.
+///
+/// [`new()`][Self::new] creates a future without output. With [`with_mapper()`][Self::with_mapper], the signal arguments are
+/// converted into the output `T` instead.
+///
+/// If the object is freed before emitting the signal, the future never completes.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{Button, SignalFuture};
+///
+/// async fn wait_for_click(button: Gd<Button>) {
+///     SignalFuture::new(button.upcast(), "pressed").await;
+///     godot_print!("clicked!");
+/// }
+/// ```
+#[must_use = "futures do nothing unless awaited"]
+pub struct SignalFuture<T = ()> {
+    state: Arc<Mutex<SignalState<T>>>,
+}
+
+struct SignalState<T> {
+    emitted: bool,
+    output: Option<T>,
+    waker: Option<Waker>,
+}
+
+impl SignalFuture {
+    /// Connects to `signal` on `object` and returns a future completing on its next emission.
+    pub fn new(object: Gd<Object>, signal: impl Into<StringName>) -> Self {
+        Self::with_mapper(object, signal, |_args| Some(()))
+    }
+}
+
+impl<T: Send + 'static> SignalFuture<T> {
+    /// Connects to `signal` on `object` and returns a future resolving to `map(args)` on its next emission.
+    ///
+    /// If `map` returns `None`, the arguments are rejected as invalid, and the future never completes.
+    pub fn with_mapper<F>(object: Gd<Object>, signal: impl Into<StringName>, map: F) -> Self
+    where
+        F: FnMut(&[&Variant]) -> Option<T> + Send + Sync + 'static,
+    {
+        Self::connect_mapped(object, signal.into(), map).0
+    }
+
+    /// Like [`with_mapper()`][Self::with_mapper], but also returns the connected callable, so it can be disconnected again.
+    pub(crate) fn connect_mapped<F>(
+        mut object: Gd<Object>,
+        signal: StringName,
+        mut map: F,
+    ) -> (Self, Callable)
+    where
+        F: FnMut(&[&Variant]) -> Option<T> + Send + Sync + 'static,
+    {
+        let state = Arc::new(Mutex::new(SignalState {
+            emitted: false,
+            output: None,
+            waker: None,
+        }));
+
+        let callback_state = Arc::clone(&state);
+        let callable = Callable::from_fn("SignalFuture", move |args: &[&Variant]| {
+            let output = map(args).ok_or(())?;
+            settle(&callback_state, output);
+
+            Ok(Variant::nil())
+        });
+
+        object
+            .connect_ex(signal, callable.clone())
+            .flags(ConnectFlags::CONNECT_ONE_SHOT.ord() as u32)
+            .done();
+
+        #[cfg(feature = "debug-overlay")]
+        crate::engine::metrics::future_created();
+
+        (Self { state }, callable)
+    }
+
+    /// Completes the future with `output` without waiting for the signal, unless it has completed already.
+    pub(crate) fn resolve(&self, output: T) {
+        settle(&self.state, output);
+    }
+}
+
+impl<T> SignalFuture<T> {
+    /// Whether the signal has been emitted since this future was created.
+    pub fn is_emitted(&self) -> bool {
+        self.state.lock().unwrap().emitted
+    }
+}
+
+impl<T> Future for SignalFuture<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut state = self.state.lock().unwrap();
+
+        match state.output.take() {
+            Some(output) => Poll::Ready(output),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(feature = "debug-overlay")]
+impl<T> Drop for SignalFuture<T> {
+    fn drop(&mut self) {
+        let mut state = self.state.lock().unwrap();
+
+        // Marked as emitted, so that a later emission is not counted again.
+        if !state.emitted {
+            state.emitted = true;
+            crate::engine::metrics::future_settled();
+        }
+    }
+}
+
+/// Stores `output` and wakes the last poll, if the future has not completed yet.
+fn settle<T>(state: &Mutex<SignalState<T>>, output: T) {
+    let waker = {
+        let mut state = state.lock().unwrap();
+        if state.emitted {
+            return;
+        }
+
+        #[cfg(feature = "debug-overlay")]
+        crate::engine::metrics::future_settled();
+
+        state.emitted = true;
+        state.output = Some(output);
+        state.waker.take()
+    };
+
+    // Wake outside the lock, in case the executor polls synchronously.
+    if let Some(waker) = waker {
+        waker.wake();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Extension trait to await frames and timers of the scene tree, similar to GDScript's `await get_tree().process_frame`.
+///
+/// Implemented for `Gd<SceneTree>` as well as all nodes; the latter use the tree they are currently in.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::TreeAwaitExt;
+///
+/// async fn blink(mut node: Gd<Node2D>) {
+///     for _ in 0..3 {
+///         node.hide();
+///         node.sleep(0.5).await;
+///         node.show();
+///         node.sleep(0.5).await;
+///     }
+///
+///     // Continue on the next physics frame.
+///     node.physics_frame().await;
+/// }
+/// ```
+pub trait TreeAwaitExt {
+    /// ⚠️ Returns a future that completes after `seconds` of (scaled) game time, using a [`SceneTreeTimer`][crate::engine::SceneTreeTimer].
+    ///
+    /// # Panics
+    /// If called on a node that is not inside the scene tree.
+    fn sleep(&self, seconds: f64) -> SignalFuture {
+        let mut tree = self.scene_tree();
+        let timer = tree
+            .create_timer(seconds)
+            .expect("SceneTree::create_timer() returned null");
+
+        SignalFuture::new(timer.upcast(), "timeout")
+    }
+
+    /// ⚠️ Returns a future that completes at the start of the next process frame.
+    ///
+    /// # Panics
+    /// If called on a node that is not inside the scene tree.
+    fn process_frame(&self) -> SignalFuture {
+        SignalFuture::new(self.scene_tree().upcast(), "process_frame")
+    }
+
+    /// ⚠️ Returns a future that completes at the start of the next physics frame.
+    ///
+    /// # Panics
+    /// If called on a node that is not inside the scene tree.
+    fn physics_frame(&self) -> SignalFuture {
+        SignalFuture::new(self.scene_tree().upcast(), "physics_frame")
+    }
+
+    #[doc(hidden)]
+    fn scene_tree(&self) -> Gd<SceneTree>;
+}
+
+impl TreeAwaitExt for Gd<SceneTree> {
+    fn scene_tree(&self) -> Gd<SceneTree> {
+        self.clone()
+    }
+}
+
+impl<U> TreeAwaitExt for Gd<U>
+where
+    U: GodotClass + Inherits<Node>,
+{
+    fn scene_tree(&self) -> Gd<SceneTree> {
+        let node = self.clone().upcast::<Node>();
+
+        node.get_tree()
+            .unwrap_or_else(|| panic!("node `{}` is not inside the scene tree", node.get_name()))
+    }
+}
@@ -0,0 +1,269 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::{GodotString, Transform3D};
+use crate::engine::Skeleton3D;
+use crate::obj::Gd;
+
+/// Index of a bone in a [`Skeleton3D`].
+///
+/// Godot passes bones as `int` indices, with `-1` for "no bone"; this type makes them distinguishable from other integers.
+/// Indices stay valid as long as no bones are added to or removed from the skeleton.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct BoneId(u32);
+
+impl BoneId {
+    /// ⚠️ Creates an ID from the bone index.
+    ///
+    /// # Panics
+    /// If `index` exceeds `i32::MAX`, which cannot be represented in Godot.
+    pub fn new(index: u32) -> Self {
+        assert!(
+            index <= i32::MAX as u32,
+            "bone index {index} exceeds i32::MAX"
+        );
+        Self(index)
+    }
+
+    /// Converts an index as used in Godot's API; `None` for negative values such as the `-1` of "no bone".
+    pub fn try_from_i32(index: i32) -> Option<Self> {
+        u32::try_from(index).ok().map(Self)
+    }
+
+    pub fn to_u32(self) -> u32 {
+        self.0
+    }
+
+    /// The index as `int`, as passed to Godot.
+    pub fn to_i32(self) -> i32 {
+        self.0 as i32
+    }
+}
+
+impl From<u32> for BoneId {
+    fn from(index: u32) -> Self {
+        Self::new(index)
+    }
+}
+
+impl fmt::Debug for BoneId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BoneId({})", self.0)
+    }
+}
+
+impl fmt::Display for BoneId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Extension methods for [`Skeleton3D`], taking [`BoneId`]s and whole [`Transform3D`] poses.
+///
+/// Poses are relative to the parent bone; global poses are relative to the skeleton node (not the world), matching
+/// `get_bone_global_pose()`.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{Skeleton3D, SkeletonExt};
+///
+/// /// Turns the head to look at `target`, given in skeleton space.
+/// fn look_at(skeleton: &mut Gd<Skeleton3D>, target: Vector3) {
+///     let head = skeleton.find_bone_id("Head").expect("skeleton has a head");
+///
+///     let global = skeleton.global_pose(head);
+///     let looking = global.looking_at(target, Vector3::UP, false);
+///     skeleton.set_global_pose(head, looking);
+/// }
+/// ```
+///
+/// ⚠️ Methods taking a `BoneId` panic if the skeleton has no bone with that index.
+pub trait SkeletonExt {
+    /// The bone called `name`, if it exists.
+    fn find_bone_id(&self, name: &str) -> Option<BoneId>;
+
+    fn bone_name(&self, bone: BoneId) -> GodotString;
+
+    /// All bones, in index order.
+    fn bone_ids(&self) -> Vec<BoneId>;
+
+    /// The parent of `bone`; `None` for root bones.
+    fn bone_parent(&self, bone: BoneId) -> Option<BoneId>;
+
+    /// The direct children of `bone`.
+    fn bone_children(&self, bone: BoneId) -> Vec<BoneId>;
+
+    /// The bones without parent.
+    fn root_bones(&self) -> Vec<BoneId>;
+
+    /// All bones in depth-first order, each parent before its children, e.g. to compute global poses in one pass.
+    fn bones_top_down(&self) -> Vec<BoneId>;
+
+    /// The bones from `root` down to `tip`, including both; `None` if `root` is not an ancestor of `tip`.
+    ///
+    /// Useful to collect the joints of an IK chain, such as from the upper arm to the hand.
+    fn bone_chain(&self, root: BoneId, tip: BoneId) -> Option<Vec<BoneId>>;
+
+    /// The pose of `bone`, relative to its parent.
+    fn pose(&self, bone: BoneId) -> Transform3D;
+
+    /// Sets the pose of `bone`, relative to its parent.
+    ///
+    /// Godot stores poses as position, rotation and scale, so shear in `pose` is lost.
+    fn set_pose(&mut self, bone: BoneId, pose: Transform3D);
+
+    /// The pose of `bone`, relative to the skeleton.
+    fn global_pose(&self, bone: BoneId) -> Transform3D;
+
+    /// Sets the pose of `bone` so that it ends up at `global_pose`, relative to the skeleton.
+    ///
+    /// The pose is stored relative to the parent, so moving the parent later moves this bone as well.
+    fn set_global_pose(&mut self, bone: BoneId, global_pose: Transform3D);
+
+    /// Sets the poses of several bones, relative to their parents.
+    fn set_poses<I>(&mut self, poses: I)
+    where
+        I: IntoIterator<Item = (BoneId, Transform3D)>;
+
+    /// Sets the global poses of several bones, in the given order.
+    ///
+    /// Since a bone moves with its parent, parents should come before their children, as in [`bones_top_down()`][Self::bones_top_down].
+    fn set_global_poses<I>(&mut self, global_poses: I)
+    where
+        I: IntoIterator<Item = (BoneId, Transform3D)>;
+
+    /// The rest pose of `bone`, relative to its parent.
+    fn rest(&self, bone: BoneId) -> Transform3D;
+}
+
+impl SkeletonExt for Gd<Skeleton3D> {
+    fn find_bone_id(&self, name: &str) -> Option<BoneId> {
+        BoneId::try_from_i32(self.find_bone(name.into()))
+    }
+
+    fn bone_name(&self, bone: BoneId) -> GodotString {
+        self.get_bone_name(checked(self, bone))
+    }
+
+    fn bone_ids(&self) -> Vec<BoneId> {
+        (0..self.get_bone_count() as u32).map(BoneId).collect()
+    }
+
+    fn bone_parent(&self, bone: BoneId) -> Option<BoneId> {
+        BoneId::try_from_i32(self.get_bone_parent(checked(self, bone)))
+    }
+
+    fn bone_children(&self, bone: BoneId) -> Vec<BoneId> {
+        to_bone_ids(self.get_bone_children(checked(self, bone)).as_slice())
+    }
+
+    fn root_bones(&self) -> Vec<BoneId> {
+        to_bone_ids(self.get_parentless_bones().as_slice())
+    }
+
+    fn bones_top_down(&self) -> Vec<BoneId> {
+        let mut ordered = Vec::with_capacity(self.get_bone_count() as usize);
+
+        // Reversed, so that the stack pops siblings in index order.
+        let mut stack = self.root_bones();
+        stack.reverse();
+
+        while let Some(bone) = stack.pop() {
+            ordered.push(bone);
+            stack.extend(self.bone_children(bone).into_iter().rev());
+        }
+
+        ordered
+    }
+
+    fn bone_chain(&self, root: BoneId, tip: BoneId) -> Option<Vec<BoneId>> {
+        checked(self, root);
+
+        let mut chain = vec![tip];
+        let mut current = tip;
+
+        while current != root {
+            current = self.bone_parent(current)?;
+            chain.push(current);
+        }
+
+        chain.reverse();
+        Some(chain)
+    }
+
+    fn pose(&self, bone: BoneId) -> Transform3D {
+        self.get_bone_pose(checked(self, bone))
+    }
+
+    fn set_pose(&mut self, bone: BoneId, pose: Transform3D) {
+        let index = checked(self, bone);
+
+        self.set_bone_pose_position(index, pose.origin);
+        self.set_bone_pose_rotation(index, pose.basis.to_quat());
+        self.set_bone_pose_scale(index, pose.basis.scale());
+    }
+
+    fn global_pose(&self, bone: BoneId) -> Transform3D {
+        self.get_bone_global_pose(checked(self, bone))
+    }
+
+    fn set_global_pose(&mut self, bone: BoneId, global_pose: Transform3D) {
+        let pose = match self.bone_parent(bone) {
+            Some(parent) => self.global_pose(parent).affine_inverse() * global_pose,
+            None => global_pose,
+        };
+
+        self.set_pose(bone, pose);
+    }
+
+    fn set_poses<I>(&mut self, poses: I)
+    where
+        I: IntoIterator<Item = (BoneId, Transform3D)>,
+    {
+        for (bone, pose) in poses {
+            self.set_pose(bone, pose);
+        }
+    }
+
+    fn set_global_poses<I>(&mut self, global_poses: I)
+    where
+        I: IntoIterator<Item = (BoneId, Transform3D)>,
+    {
+        for (bone, global_pose) in global_poses {
+            self.set_global_pose(bone, global_pose);
+        }
+    }
+
+    fn rest(&self, bone: BoneId) -> Transform3D {
+        self.get_bone_rest(checked(self, bone))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+/// Returns the index of `bone`, panicking if the skeleton has no such bone.
+fn checked(skeleton: &Gd<Skeleton3D>, bone: BoneId) -> i32 {
+    let count = skeleton.get_bone_count();
+    assert!(
+        bone.to_i32() < count,
+        "bone {bone} out of range; skeleton has {count} bones"
+    );
+
+    bone.to_i32()
+}
+
+fn to_bone_ids(indices: &[i32]) -> Vec<BoneId> {
+    indices
+        .iter()
+        .map(|&index| BoneId::try_from_i32(index).expect("Godot returned negative bone index"))
+        .collect()
+}
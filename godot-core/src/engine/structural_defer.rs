@@ -0,0 +1,215 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::{Cell, RefCell};
+
+use crate::builtin::{Callable, Variant};
+use crate::engine::object::ConnectFlags;
+use crate::engine::{Node, SceneTree};
+use crate::obj::{EngineEnum, Gd, Inherits};
+
+/// Changes to the scene tree's structure, recorded by [`DeferStructuralExt::defer_structural()`] and applied later.
+///
+/// Godot refuses to add or remove nodes while a parent is setting up its children (e.g. from `_ready()` or `tree_entered`), and
+/// physics bodies cannot be added or removed while physics callbacks such as `body_entered` run. The batch records such changes
+/// instead, to apply them when the scene tree is not busy.
+///
+/// If a node involved in an operation has been freed by the time the batch is applied, that operation is skipped.
+pub struct StructuralBatch {
+    ops: Vec<StructuralOp>,
+}
+
+impl StructuralBatch {
+    /// Adds `child` to `parent`.
+    pub fn add_child<P, C>(&mut self, parent: &Gd<P>, child: Gd<C>)
+    where
+        P: Inherits<Node>,
+        C: Inherits<Node>,
+    {
+        self.ops.push(StructuralOp::AddChild {
+            parent: parent.clone().upcast(),
+            child: child.upcast(),
+        });
+    }
+
+    /// Removes `child` from `parent`, without freeing it. Skipped if `child` is no longer a child of `parent`.
+    pub fn remove_child<P, C>(&mut self, parent: &Gd<P>, child: &Gd<C>)
+    where
+        P: Inherits<Node>,
+        C: Inherits<Node>,
+    {
+        self.ops.push(StructuralOp::RemoveChild {
+            parent: parent.clone().upcast(),
+            child: child.clone().upcast(),
+        });
+    }
+
+    /// Moves `node` to `new_parent`, keeping its global transform.
+    pub fn reparent<N, P>(&mut self, node: &Gd<N>, new_parent: &Gd<P>)
+    where
+        N: Inherits<Node>,
+        P: Inherits<Node>,
+    {
+        self.ops.push(StructuralOp::Reparent {
+            node: node.clone().upcast(),
+            new_parent: new_parent.clone().upcast(),
+        });
+    }
+
+    /// Frees `node` and its children, removing it from its parent.
+    pub fn free<N: Inherits<Node>>(&mut self, node: Gd<N>) {
+        self.ops.push(StructuralOp::Free(node.upcast()));
+    }
+
+    /// Number of recorded operations.
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Extension trait to batch structural changes of the scene tree and apply them at a safe point.
+///
+/// Operations recorded in [`defer_structural()`][Self::defer_structural] are applied in order at the start of the next process
+/// frame (when the tree emits `process_frame`), which is outside of physics steps and of any node's setup. This replaces sprinkling
+/// `call_deferred("add_child", ...)` over callbacks, and keeps the order of operations from different callbacks.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{Area2D, DeferStructuralExt};
+///
+/// /// Connected to `body_entered`: replaces the pickup with an effect, which cannot happen during the physics callback.
+/// fn on_pickup(pickup: Gd<Area2D>, effect: Gd<Node2D>) {
+///     let tree = pickup.get_tree().expect("pickup is in the tree");
+///     let parent = pickup.get_parent().expect("pickup has a parent");
+///
+///     tree.defer_structural(|batch| {
+///         batch.add_child(&parent, effect);
+///         batch.free(pickup);
+///     });
+/// }
+/// ```
+pub trait DeferStructuralExt {
+    /// Records operations in `scope`, to apply them at the start of the next process frame.
+    ///
+    /// Can be called several times per frame; all batches are applied together, in the order in which their scopes ended.
+    fn defer_structural<R>(&self, scope: impl FnOnce(&mut StructuralBatch) -> R) -> R;
+
+    /// Applies the pending operations now, e.g. at a point known to be safe, or in tests.
+    fn flush_structural(&self);
+
+    /// Number of operations waiting to be applied.
+    fn pending_structural(&self) -> usize;
+}
+
+impl DeferStructuralExt for Gd<SceneTree> {
+    fn defer_structural<R>(&self, scope: impl FnOnce(&mut StructuralBatch) -> R) -> R {
+        let mut batch = StructuralBatch { ops: Vec::new() };
+        let result = scope(&mut batch);
+
+        if !batch.is_empty() {
+            PENDING.with(|pending| pending.borrow_mut().append(&mut batch.ops));
+            schedule_flush(self.clone());
+        }
+
+        result
+    }
+
+    fn flush_structural(&self) {
+        flush();
+    }
+
+    fn pending_structural(&self) -> usize {
+        PENDING.with(|pending| pending.borrow().len())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+enum StructuralOp {
+    AddChild {
+        parent: Gd<Node>,
+        child: Gd<Node>,
+    },
+    RemoveChild {
+        parent: Gd<Node>,
+        child: Gd<Node>,
+    },
+    Reparent {
+        node: Gd<Node>,
+        new_parent: Gd<Node>,
+    },
+    Free(Gd<Node>),
+}
+
+thread_local! {
+    // Nodes are only modified on the main thread, so the queue does not need to be shared.
+    static PENDING: RefCell<Vec<StructuralOp>> = RefCell::new(Vec::new());
+    static FLUSH_SCHEDULED: Cell<bool> = Cell::new(false);
+}
+
+fn schedule_flush(mut tree: Gd<SceneTree>) {
+    if FLUSH_SCHEDULED.with(|scheduled| scheduled.replace(true)) {
+        return;
+    }
+
+    let callable = Callable::from_fn("flush_structural", |_args: &[&Variant]| {
+        flush();
+        Ok(Variant::nil())
+    });
+
+    tree.connect_ex("process_frame".into(), callable)
+        .flags(ConnectFlags::CONNECT_ONE_SHOT.ord() as u32)
+        .done();
+}
+
+fn flush() {
+    FLUSH_SCHEDULED.with(|scheduled| scheduled.set(false));
+
+    // Taken out first: applying operations emits signals, whose handlers may record new operations for the next flush.
+    let ops = PENDING.with(|pending| std::mem::take(&mut *pending.borrow_mut()));
+    for op in ops {
+        apply(op);
+    }
+}
+
+fn apply(op: StructuralOp) {
+    match op {
+        StructuralOp::AddChild { mut parent, child } => {
+            if parent.is_instance_valid() && child.is_instance_valid() {
+                parent.add_child(child);
+            }
+        }
+        StructuralOp::RemoveChild { mut parent, child } => {
+            if parent.is_instance_valid()
+                && child.is_instance_valid()
+                && child.get_parent().as_ref() == Some(&parent)
+            {
+                parent.remove_child(child);
+            }
+        }
+        StructuralOp::Reparent {
+            mut node,
+            new_parent,
+        } => {
+            if node.is_instance_valid() && new_parent.is_instance_valid() {
+                node.reparent(new_parent);
+            }
+        }
+        StructuralOp::Free(node) => {
+            if node.is_instance_valid() {
+                node.free();
+            }
+        }
+    }
+}
@@ -0,0 +1,351 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Color, StringName, Vector2};
+use crate::engine::global::{Corner, Side};
+use crate::engine::{Control, Font, StyleBox, StyleBoxFlat, Texture2D};
+use crate::obj::{Gd, Inherits};
+
+/// Kind of a theme item, for the methods of [`ThemeExt`] that work on all kinds.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ThemeItem {
+    /// `Color` items, such as `font_color`.
+    Color,
+
+    /// Integer constants, such as `separation` or `outline_size`.
+    Constant,
+
+    /// `Font` resources.
+    Font,
+
+    /// Font sizes in pixels, separate from the fonts themselves.
+    FontSize,
+
+    /// `Texture2D` icons, such as `checked` of a `CheckBox`.
+    Icon,
+
+    /// `StyleBox` resources, such as `normal` or `panel`.
+    StyleBox,
+}
+
+/// Extension trait for typed access to the theme items of a `Control`, and for overriding them.
+///
+/// Each getter takes the item's `name` and the `theme_type` it belongs to, usually a class name such as `"Label"` or `"Button"`.
+/// An empty `theme_type` stands for the control's own class (and its theme type variation). The lookup follows Godot's rules:
+/// overrides on the control first, then its theme and the themes of its ancestors, then the project and default themes.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{Label, StyleBoxFlatBuilder, ThemeExt};
+///
+/// fn highlight(mut label: Gd<Label>) {
+///     let accent = label.theme_color("font_color", "Button");
+///     label.override_theme_color("font_color", accent);
+///
+///     let background = StyleBoxFlatBuilder::new()
+///         .bg_color(Color::from_rgba(0.1, 0.1, 0.1, 0.8))
+///         .corner_radius(6)
+///         .build();
+///     label.override_theme_stylebox("normal", background);
+/// }
+/// ```
+pub trait ThemeExt {
+    /// The color item `name` of `theme_type`, or black if it does not exist.
+    fn theme_color(&self, name: impl Into<StringName>, theme_type: impl Into<StringName>) -> Color;
+
+    /// The constant item `name` of `theme_type`, or 0 if it does not exist.
+    fn theme_constant(&self, name: impl Into<StringName>, theme_type: impl Into<StringName>)
+        -> i32;
+
+    /// The font item `name` of `theme_type`, falling back to the default font.
+    fn theme_font(
+        &self,
+        name: impl Into<StringName>,
+        theme_type: impl Into<StringName>,
+    ) -> Option<Gd<Font>>;
+
+    /// The font size item `name` of `theme_type`, falling back to the default font size.
+    fn theme_font_size(
+        &self,
+        name: impl Into<StringName>,
+        theme_type: impl Into<StringName>,
+    ) -> i32;
+
+    /// The icon item `name` of `theme_type`.
+    fn theme_icon(
+        &self,
+        name: impl Into<StringName>,
+        theme_type: impl Into<StringName>,
+    ) -> Option<Gd<Texture2D>>;
+
+    /// The style box item `name` of `theme_type`.
+    fn theme_stylebox(
+        &self,
+        name: impl Into<StringName>,
+        theme_type: impl Into<StringName>,
+    ) -> Option<Gd<StyleBox>>;
+
+    /// Overrides the color item `name` for this control only.
+    fn override_theme_color(&mut self, name: impl Into<StringName>, color: Color);
+
+    /// Overrides the constant item `name` for this control only.
+    fn override_theme_constant(&mut self, name: impl Into<StringName>, constant: i32);
+
+    /// Overrides the font item `name` for this control only.
+    fn override_theme_font<F: Inherits<Font>>(&mut self, name: impl Into<StringName>, font: Gd<F>);
+
+    /// Overrides the font size item `name` for this control only.
+    fn override_theme_font_size(&mut self, name: impl Into<StringName>, font_size: i32);
+
+    /// Overrides the icon item `name` for this control only.
+    fn override_theme_icon<T: Inherits<Texture2D>>(
+        &mut self,
+        name: impl Into<StringName>,
+        icon: Gd<T>,
+    );
+
+    /// Overrides the style box item `name` for this control only.
+    fn override_theme_stylebox<S: Inherits<StyleBox>>(
+        &mut self,
+        name: impl Into<StringName>,
+        stylebox: Gd<S>,
+    );
+
+    /// Whether this control overrides the item `name` of kind `item`.
+    fn has_theme_override(&self, item: ThemeItem, name: impl Into<StringName>) -> bool;
+
+    /// Removes the override of the item `name` of kind `item`, if any.
+    fn remove_theme_override(&mut self, item: ThemeItem, name: impl Into<StringName>);
+}
+
+impl ThemeExt for Control {
+    fn theme_color(&self, name: impl Into<StringName>, theme_type: impl Into<StringName>) -> Color {
+        self.get_theme_color_ex(name.into())
+            .theme_type(theme_type.into())
+            .done()
+    }
+
+    fn theme_constant(
+        &self,
+        name: impl Into<StringName>,
+        theme_type: impl Into<StringName>,
+    ) -> i32 {
+        self.get_theme_constant_ex(name.into())
+            .theme_type(theme_type.into())
+            .done()
+    }
+
+    fn theme_font(
+        &self,
+        name: impl Into<StringName>,
+        theme_type: impl Into<StringName>,
+    ) -> Option<Gd<Font>> {
+        self.get_theme_font_ex(name.into())
+            .theme_type(theme_type.into())
+            .done()
+    }
+
+    fn theme_font_size(
+        &self,
+        name: impl Into<StringName>,
+        theme_type: impl Into<StringName>,
+    ) -> i32 {
+        self.get_theme_font_size_ex(name.into())
+            .theme_type(theme_type.into())
+            .done()
+    }
+
+    fn theme_icon(
+        &self,
+        name: impl Into<StringName>,
+        theme_type: impl Into<StringName>,
+    ) -> Option<Gd<Texture2D>> {
+        self.get_theme_icon_ex(name.into())
+            .theme_type(theme_type.into())
+            .done()
+    }
+
+    fn theme_stylebox(
+        &self,
+        name: impl Into<StringName>,
+        theme_type: impl Into<StringName>,
+    ) -> Option<Gd<StyleBox>> {
+        self.get_theme_stylebox_ex(name.into())
+            .theme_type(theme_type.into())
+            .done()
+    }
+
+    fn override_theme_color(&mut self, name: impl Into<StringName>, color: Color) {
+        self.add_theme_color_override(name.into(), color);
+    }
+
+    fn override_theme_constant(&mut self, name: impl Into<StringName>, constant: i32) {
+        self.add_theme_constant_override(name.into(), constant);
+    }
+
+    fn override_theme_font<F: Inherits<Font>>(&mut self, name: impl Into<StringName>, font: Gd<F>) {
+        self.add_theme_font_override(name.into(), font.upcast());
+    }
+
+    fn override_theme_font_size(&mut self, name: impl Into<StringName>, font_size: i32) {
+        self.add_theme_font_size_override(name.into(), font_size);
+    }
+
+    fn override_theme_icon<T: Inherits<Texture2D>>(
+        &mut self,
+        name: impl Into<StringName>,
+        icon: Gd<T>,
+    ) {
+        self.add_theme_icon_override(name.into(), icon.upcast());
+    }
+
+    fn override_theme_stylebox<S: Inherits<StyleBox>>(
+        &mut self,
+        name: impl Into<StringName>,
+        stylebox: Gd<S>,
+    ) {
+        self.add_theme_stylebox_override(name.into(), stylebox.upcast());
+    }
+
+    fn has_theme_override(&self, item: ThemeItem, name: impl Into<StringName>) -> bool {
+        let name = name.into();
+        match item {
+            ThemeItem::Color => self.has_theme_color_override(name),
+            ThemeItem::Constant => self.has_theme_constant_override(name),
+            ThemeItem::Font => self.has_theme_font_override(name),
+            ThemeItem::FontSize => self.has_theme_font_size_override(name),
+            ThemeItem::Icon => self.has_theme_icon_override(name),
+            ThemeItem::StyleBox => self.has_theme_stylebox_override(name),
+        }
+    }
+
+    fn remove_theme_override(&mut self, item: ThemeItem, name: impl Into<StringName>) {
+        let name = name.into();
+        match item {
+            ThemeItem::Color => self.remove_theme_color_override(name),
+            ThemeItem::Constant => self.remove_theme_constant_override(name),
+            ThemeItem::Font => self.remove_theme_font_override(name),
+            ThemeItem::FontSize => self.remove_theme_font_size_override(name),
+            ThemeItem::Icon => self.remove_theme_icon_override(name),
+            ThemeItem::StyleBox => self.remove_theme_stylebox_override(name),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Builder for [`StyleBoxFlat`] resources, the usual style box for panels and buttons drawn from code.
+///
+/// Starts from Godot's defaults: a gray background, no border, square corners and no shadow.
+pub struct StyleBoxFlatBuilder {
+    stylebox: Gd<StyleBoxFlat>,
+}
+
+impl StyleBoxFlatBuilder {
+    /// Starts a new style box.
+    pub fn new() -> Self {
+        Self {
+            stylebox: StyleBoxFlat::new(),
+        }
+    }
+
+    /// Starts from a copy of an existing style box, which is left unchanged.
+    pub fn from_stylebox(stylebox: &Gd<StyleBoxFlat>) -> Self {
+        let stylebox = stylebox
+            .duplicate()
+            .and_then(|copy| copy.try_cast::<StyleBoxFlat>())
+            .expect("StyleBoxFlat::duplicate() returns a StyleBoxFlat");
+
+        Self { stylebox }
+    }
+
+    /// Fill color of the box.
+    pub fn bg_color(mut self, color: Color) -> Self {
+        self.stylebox.set_bg_color(color);
+        self
+    }
+
+    /// Whether the background is drawn; `false` gives a frame with only borders.
+    pub fn draw_center(mut self, draw_center: bool) -> Self {
+        self.stylebox.set_draw_center(draw_center);
+        self
+    }
+
+    /// Border of `width` pixels on all sides, in `color`.
+    pub fn border(mut self, width: i32, color: Color) -> Self {
+        self.stylebox.set_border_width_all(width);
+        self.stylebox.set_border_color(color);
+        self
+    }
+
+    /// Border width of a single side, keeping the others.
+    pub fn border_width(mut self, side: Side, width: i32) -> Self {
+        self.stylebox.set_border_width(side, width);
+        self
+    }
+
+    /// Whether the border is blended with the background at its inner edge.
+    pub fn border_blend(mut self, blend: bool) -> Self {
+        self.stylebox.set_border_blend(blend);
+        self
+    }
+
+    /// Radius of all four corners, in pixels.
+    pub fn corner_radius(mut self, radius: i32) -> Self {
+        self.stylebox.set_corner_radius_all(radius);
+        self
+    }
+
+    /// Radius of a single corner, keeping the others.
+    pub fn corner_radius_at(mut self, corner: Corner, radius: i32) -> Self {
+        self.stylebox.set_corner_radius(corner, radius);
+        self
+    }
+
+    /// Margin between the box and its content, on all sides.
+    pub fn content_margin(mut self, margin: f32) -> Self {
+        self.stylebox.set_content_margin_all(margin);
+        self
+    }
+
+    /// Margin between the box and its content, for a single side.
+    pub fn content_margin_side(mut self, side: Side, margin: f32) -> Self {
+        self.stylebox.set_content_margin(side, margin);
+        self
+    }
+
+    /// Extends the drawn box beyond the control's rectangle by `margin` pixels on all sides.
+    pub fn expand_margin(mut self, margin: f32) -> Self {
+        self.stylebox.set_expand_margin_all(margin);
+        self
+    }
+
+    /// Drop shadow of `size` pixels in `color`, displaced by `offset`.
+    pub fn shadow(mut self, color: Color, size: i32, offset: Vector2) -> Self {
+        self.stylebox.set_shadow_color(color);
+        self.stylebox.set_shadow_size(size);
+        self.stylebox.set_shadow_offset(offset);
+        self
+    }
+
+    /// Whether borders and rounded corners are anti-aliased; `true` by default.
+    pub fn anti_aliased(mut self, anti_aliased: bool) -> Self {
+        self.stylebox.set_anti_aliased(anti_aliased);
+        self
+    }
+
+    /// Finishes the style box.
+    pub fn build(self) -> Gd<StyleBoxFlat> {
+        self.stylebox
+    }
+}
+
+impl Default for StyleBoxFlatBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,278 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::ops::Range;
+
+use crate::builtin::Vector2i;
+use crate::engine::{Texture2D, TileMap, TileSet, TileSetAtlasSource};
+use crate::obj::Gd;
+
+/// Tile placed in a [`TileMap`] cell: a tile of an atlas source, optionally one of its alternatives.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TileCell {
+    /// ID of the source in the map's `TileSet`.
+    pub source_id: i32,
+
+    /// Coordinates of the tile in the atlas, in tiles.
+    pub atlas_coords: Vector2i,
+
+    /// Alternative tile; 0 for the tile itself.
+    pub alternative: i32,
+}
+
+impl TileCell {
+    /// The tile at `atlas_coords` in source `source_id`, without alternative.
+    pub fn new(source_id: i32, atlas_coords: Vector2i) -> Self {
+        Self {
+            source_id,
+            atlas_coords,
+            alternative: 0,
+        }
+    }
+
+    /// The same tile, with alternative `alternative`.
+    pub fn with_alternative(self, alternative: i32) -> Self {
+        Self {
+            alternative,
+            ..self
+        }
+    }
+}
+
+/// Extension methods for [`TileMap`], for editing many cells at once.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{TileCell, TileMap, TileMapExt};
+///
+/// fn generate(map: &mut Gd<TileMap>) {
+///     let wall = Some(TileCell::new(0, Vector2i::new(0, 0)));
+///     let floor = Some(TileCell::new(0, Vector2i::new(1, 0)));
+///
+///     // Rows from top to bottom; `None` leaves a cell empty.
+///     map.set_cells_from_grid(0, Vector2i::ZERO, &[
+///         [wall, wall, wall],
+///         [wall, floor, None],
+///     ]);
+///
+///     let (columns, rows) = map.used_cell_ranges();
+///     for y in rows {
+///         for x in columns.clone() {
+///             godot_print!("{x},{y}: {:?}", map.cell(0, Vector2i::new(x, y)));
+///         }
+///     }
+/// }
+/// ```
+pub trait TileMapExt {
+    /// Sets each cell of `cells` in `layer`.
+    fn set_cells<I>(&mut self, layer: i32, cells: I)
+    where
+        I: IntoIterator<Item = (Vector2i, TileCell)>;
+
+    /// Sets a rectangle of cells in `layer` from rows of tiles, starting at `origin` (top-left).
+    ///
+    /// `rows[y][x]` is placed at `origin + (x, y)`. Rows may differ in length. Cells that are `None` are erased.
+    fn set_cells_from_grid<R>(&mut self, layer: i32, origin: Vector2i, rows: &[R])
+    where
+        R: AsRef<[Option<TileCell>]>;
+
+    /// Erases each cell of `cells` in `layer`.
+    fn erase_cells<I>(&mut self, layer: i32, cells: I)
+    where
+        I: IntoIterator<Item = Vector2i>;
+
+    /// The tile in cell `coords` of `layer`; `None` if the cell is empty.
+    fn cell(&self, layer: i32, coords: Vector2i) -> Option<TileCell>;
+
+    /// The coordinates of all non-empty cells in `layer`.
+    fn used_cells_vec(&self, layer: i32) -> Vec<Vector2i>;
+
+    /// Column and row ranges of the cells used in any layer; both empty if the map is empty.
+    ///
+    /// Unlike `get_used_rect()`, the ends are exclusive, so the ranges can be iterated directly.
+    fn used_cell_ranges(&self) -> (Range<i32>, Range<i32>);
+}
+
+impl TileMapExt for Gd<TileMap> {
+    fn set_cells<I>(&mut self, layer: i32, cells: I)
+    where
+        I: IntoIterator<Item = (Vector2i, TileCell)>,
+    {
+        for (coords, tile) in cells {
+            set_tile(self, layer, coords, tile);
+        }
+    }
+
+    fn set_cells_from_grid<R>(&mut self, layer: i32, origin: Vector2i, rows: &[R])
+    where
+        R: AsRef<[Option<TileCell>]>,
+    {
+        for (y, row) in rows.iter().enumerate() {
+            for (x, tile) in row.as_ref().iter().enumerate() {
+                let coords = origin + Vector2i::new(x as i32, y as i32);
+
+                match tile {
+                    Some(tile) => set_tile(self, layer, coords, *tile),
+                    None => self.erase_cell(layer, coords),
+                }
+            }
+        }
+    }
+
+    fn erase_cells<I>(&mut self, layer: i32, cells: I)
+    where
+        I: IntoIterator<Item = Vector2i>,
+    {
+        for coords in cells {
+            self.erase_cell(layer, coords);
+        }
+    }
+
+    fn cell(&self, layer: i32, coords: Vector2i) -> Option<TileCell> {
+        let source_id = self.get_cell_source_id(layer, coords);
+        if source_id == -1 {
+            return None;
+        }
+
+        Some(TileCell {
+            source_id,
+            atlas_coords: self.get_cell_atlas_coords(layer, coords),
+            alternative: self.get_cell_alternative_tile(layer, coords),
+        })
+    }
+
+    fn used_cells_vec(&self, layer: i32) -> Vec<Vector2i> {
+        self.get_used_cells(layer).iter_shared().collect()
+    }
+
+    fn used_cell_ranges(&self) -> (Range<i32>, Range<i32>) {
+        let rect = self.get_used_rect();
+        let end = rect.position + rect.size;
+
+        (rect.position.x..end.x, rect.position.y..end.y)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Builder for [`TileSetAtlasSource`], the tiles cut from a texture in a regular grid.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{AtlasSourceBuilder, Texture2D, TileSet};
+///
+/// fn make_tile_set(texture: Gd<Texture2D>) -> Gd<TileSet> {
+///     let mut tile_set = TileSet::new();
+///     tile_set.set_tile_size(Vector2i::new(16, 16));
+///
+///     let source_id = AtlasSourceBuilder::new(texture, Vector2i::new(16, 16))
+///         .separation(Vector2i::new(1, 1))
+///         .all_tiles()
+///         .add_to(&mut tile_set);
+///     assert_eq!(source_id, 0);
+///
+///     tile_set
+/// }
+/// ```
+pub struct AtlasSourceBuilder {
+    source: Gd<TileSetAtlasSource>,
+    tiles: Vec<(Vector2i, Vector2i)>,
+    all_tiles: bool,
+}
+
+impl AtlasSourceBuilder {
+    /// Starts an atlas cut from `texture`, with tiles of `tile_size` pixels.
+    pub fn new(texture: Gd<Texture2D>, tile_size: Vector2i) -> Self {
+        let mut source = TileSetAtlasSource::new();
+        source.set_texture(texture);
+        source.set_texture_region_size(tile_size);
+
+        Self {
+            source,
+            tiles: Vec::new(),
+            all_tiles: false,
+        }
+    }
+
+    /// Pixels skipped at the top-left of the texture before the first tile.
+    pub fn margins(mut self, margins: Vector2i) -> Self {
+        self.source.set_margins(margins);
+        self
+    }
+
+    /// Pixels between neighboring tiles.
+    pub fn separation(mut self, separation: Vector2i) -> Self {
+        self.source.set_separation(separation);
+        self
+    }
+
+    /// Creates the single-cell tile at `atlas_coords`.
+    pub fn tile(self, atlas_coords: Vector2i) -> Self {
+        self.large_tile(atlas_coords, Vector2i::new(1, 1))
+    }
+
+    /// Creates a tile at `atlas_coords` that covers `size` grid cells of the atlas.
+    pub fn large_tile(mut self, atlas_coords: Vector2i, size: Vector2i) -> Self {
+        self.tiles.push((atlas_coords, size));
+        self
+    }
+
+    /// Creates a single-cell tile for every grid cell of the texture that is not covered by another tile.
+    pub fn all_tiles(mut self) -> Self {
+        self.all_tiles = true;
+        self
+    }
+
+    /// Finishes the atlas source.
+    ///
+    /// Tiles are created once margins, separation and texture are known. Tiles that overlap previously created ones, or lie outside
+    /// the texture, are skipped with a Godot error.
+    pub fn build(self) -> Gd<TileSetAtlasSource> {
+        let Self {
+            mut source,
+            tiles,
+            all_tiles,
+        } = self;
+
+        for (atlas_coords, size) in tiles {
+            source.create_tile_ex(atlas_coords).size(size).done();
+        }
+
+        if all_tiles {
+            let grid = source.get_atlas_grid_size();
+            for y in 0..grid.y {
+                for x in 0..grid.x {
+                    let coords = Vector2i::new(x, y);
+
+                    // (-1, -1) if no tile covers the cell, including large tiles anchored elsewhere.
+                    if source.get_tile_at_coords(coords) == Vector2i::new(-1, -1) {
+                        source.create_tile(coords);
+                    }
+                }
+            }
+        }
+
+        source
+    }
+
+    /// Finishes the atlas source and adds it to `tile_set`, returning its source ID.
+    pub fn add_to(self, tile_set: &mut Gd<TileSet>) -> i32 {
+        let source = self.build();
+        tile_set.add_source(source.upcast())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+fn set_tile(map: &mut Gd<TileMap>, layer: i32, coords: Vector2i, tile: TileCell) {
+    map.set_cell_ex(layer, coords)
+        .source_id(tile.source_id)
+        .atlas_coords(tile.atlas_coords)
+        .alternative_tile(tile.alternative)
+        .done();
+}
@@ -0,0 +1,297 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::atomic::{AtomicI64, Ordering};
+
+use crate::builtin::{Dictionary, GodotString};
+use crate::engine::DisplayServer;
+
+#[cfg(since_api = "4.2")]
+use crate::builtin::{Callable, Variant};
+#[cfg(since_api = "4.2")]
+use crate::engine::display_server::TTSUtteranceEvent;
+#[cfg(since_api = "4.2")]
+use std::sync::{Arc, Mutex};
+
+/// A voice of the operating system's speech synthesizer, as listed by [`TextToSpeech::voices()`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TtsVoice {
+    /// Identifier passed to the synthesizer; stable across runs on the same system.
+    pub id: String,
+
+    /// Human-readable name, e.g. for a settings menu.
+    pub name: String,
+
+    /// Language code of the voice, in the form `language_Variant` (e.g. `en_US`).
+    pub language: String,
+}
+
+/// Text to speak with [`TextToSpeech::speak()`], together with the voice and its parameters.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Utterance {
+    text: String,
+    voice: String,
+    volume: i32,
+    pitch: f32,
+    rate: f32,
+    interrupt: bool,
+}
+
+impl Utterance {
+    /// Speaks `text` with the given voice, at normal volume, pitch and rate.
+    pub fn new(text: impl Into<String>, voice: &TtsVoice) -> Self {
+        Self {
+            text: text.into(),
+            voice: voice.id.clone(),
+            volume: 50,
+            pitch: 1.0,
+            rate: 1.0,
+            interrupt: false,
+        }
+    }
+
+    /// Volume from 0 to 100; 50 by default.
+    pub fn volume(mut self, volume: i32) -> Self {
+        self.volume = volume.clamp(0, 100);
+        self
+    }
+
+    /// Pitch from 0.0 to 2.0; 1.0 by default.
+    pub fn pitch(mut self, pitch: f32) -> Self {
+        self.pitch = pitch.clamp(0.0, 2.0);
+        self
+    }
+
+    /// Speed from 0.1 to 10.0; 1.0 by default.
+    pub fn rate(mut self, rate: f32) -> Self {
+        self.rate = rate.clamp(0.1, 10.0);
+        self
+    }
+
+    /// Stops and discards the queued utterances before speaking this one, instead of queueing it.
+    pub fn interrupt(mut self, interrupt: bool) -> Self {
+        self.interrupt = interrupt;
+        self
+    }
+}
+
+/// Identifier of a spoken [`Utterance`], reported in [`UtteranceEvent`]s.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct UtteranceId(pub i64);
+
+/// Progress of an utterance, passed to the callback of [`TextToSpeech::on_utterance()`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum UtteranceEvent {
+    /// The synthesizer started speaking the utterance.
+    Started(UtteranceId),
+
+    /// The utterance was spoken completely.
+    Ended(UtteranceId),
+
+    /// The utterance was stopped or discarded before its end.
+    Canceled(UtteranceId),
+
+    /// The synthesizer reached a word boundary, at the given character index of the text. Not reported on all platforms.
+    Boundary { id: UtteranceId, char_index: i64 },
+}
+
+/// Speech synthesis through the operating system, for screen-reader-like accessibility features.
+///
+/// Requires the project setting `audio/general/text_to_speech` to be enabled; otherwise, no voices are listed. Voices and their
+/// languages depend on the system, so the voice to use is typically picked by language:
+///
+/// ```no_run
+/// use godot::engine::{TextToSpeech, Utterance};
+///
+/// fn read_aloud(text: &str) {
+///     if let Some(voice) = TextToSpeech::voices_for_language("en").first() {
+///         TextToSpeech::speak(&Utterance::new(text, voice).rate(1.2).interrupt(true));
+///     }
+/// }
+/// ```
+pub struct TextToSpeech {
+    _private: (),
+}
+
+impl TextToSpeech {
+    /// All voices of the system.
+    pub fn voices() -> Vec<TtsVoice> {
+        DisplayServer::singleton()
+            .tts_get_voices()
+            .iter_shared()
+            .map(|voice| parse_voice(&voice))
+            .collect()
+    }
+
+    /// The voices for `language`, either a language code (`en`) or a code with variant (`en_GB`).
+    pub fn voices_for_language(language: &str) -> Vec<TtsVoice> {
+        let ids = DisplayServer::singleton().tts_get_voices_for_language(language.into());
+        let ids: Vec<String> = ids.as_slice().iter().map(|id| id.to_string()).collect();
+
+        Self::voices()
+            .into_iter()
+            .filter(|voice| ids.contains(&voice.id))
+            .collect()
+    }
+
+    /// Queues `utterance` for speaking, and returns the identifier under which it appears in [`UtteranceEvent`]s.
+    pub fn speak(utterance: &Utterance) -> UtteranceId {
+        let id = UtteranceId(NEXT_UTTERANCE_ID.fetch_add(1, Ordering::Relaxed));
+
+        DisplayServer::singleton()
+            .tts_speak_ex(
+                GodotString::from(utterance.text.as_str()),
+                GodotString::from(utterance.voice.as_str()),
+            )
+            .volume(utterance.volume)
+            .pitch(utterance.pitch)
+            .rate(utterance.rate)
+            .utterance_id(id.0)
+            .interrupt(utterance.interrupt)
+            .done();
+
+        id
+    }
+
+    /// Whether an utterance is being spoken.
+    pub fn is_speaking() -> bool {
+        DisplayServer::singleton().tts_is_speaking()
+    }
+
+    /// Whether speaking is paused with [`pause()`][Self::pause].
+    pub fn is_paused() -> bool {
+        DisplayServer::singleton().tts_is_paused()
+    }
+
+    /// Pauses speaking, keeping the queued utterances.
+    pub fn pause() {
+        DisplayServer::singleton().tts_pause();
+    }
+
+    /// Continues after [`pause()`][Self::pause].
+    pub fn resume() {
+        DisplayServer::singleton().tts_resume();
+    }
+
+    /// Stops speaking and discards all queued utterances.
+    pub fn stop() {
+        DisplayServer::singleton().tts_stop();
+    }
+
+    /// Calls `callback` whenever an utterance starts, ends, is canceled or reaches a word boundary.
+    ///
+    /// Replaces the previous callback; Godot keeps one per event. Godot may call it from a thread of the synthesizer.
+    #[cfg(since_api = "4.2")]
+    pub fn on_utterance<F>(callback: F)
+    where
+        F: FnMut(UtteranceEvent) + Send + 'static,
+    {
+        let callback = Arc::new(Mutex::new(callback));
+        let mut display = DisplayServer::singleton();
+
+        for event in [
+            TTSUtteranceEvent::TTS_UTTERANCE_STARTED,
+            TTSUtteranceEvent::TTS_UTTERANCE_ENDED,
+            TTSUtteranceEvent::TTS_UTTERANCE_CANCELED,
+            TTSUtteranceEvent::TTS_UTTERANCE_BOUNDARY,
+        ] {
+            let callback = Arc::clone(&callback);
+            let callable = Callable::from_fn("on_utterance", move |args: &[&Variant]| {
+                let event = parse_event(event, args).ok_or(())?;
+                let mut callback = callback.lock().unwrap_or_else(|e| e.into_inner());
+                callback(event);
+
+                Ok(Variant::nil())
+            });
+
+            display.tts_set_utterance_callback(event, callable);
+        }
+    }
+
+    /// Removes the callback set with [`on_utterance()`][Self::on_utterance].
+    #[cfg(since_api = "4.2")]
+    pub fn clear_on_utterance() {
+        let mut display = DisplayServer::singleton();
+
+        for event in [
+            TTSUtteranceEvent::TTS_UTTERANCE_STARTED,
+            TTSUtteranceEvent::TTS_UTTERANCE_ENDED,
+            TTSUtteranceEvent::TTS_UTTERANCE_CANCELED,
+            TTSUtteranceEvent::TTS_UTTERANCE_BOUNDARY,
+        ] {
+            display.tts_set_utterance_callback(event, Callable::invalid());
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+/// Starts above 0, which Godot uses for utterances spoken without an identifier.
+static NEXT_UTTERANCE_ID: AtomicI64 = AtomicI64::new(1);
+
+fn parse_voice(voice: &Dictionary) -> TtsVoice {
+    let field = |key: &str| {
+        voice
+            .get(key)
+            .and_then(|value| value.try_to::<GodotString>().ok())
+            .map(|value| value.to_string())
+            .unwrap_or_default()
+    };
+
+    TtsVoice {
+        id: field("id"),
+        name: field("name"),
+        language: field("language"),
+    }
+}
+
+/// Converts the arguments of an utterance callback: `(utterance_id)`, or `(char_index, utterance_id)` for boundaries.
+#[cfg(since_api = "4.2")]
+fn parse_event(event: TTSUtteranceEvent, args: &[&Variant]) -> Option<UtteranceEvent> {
+    let int = |index: usize| args.get(index)?.try_to::<i64>().ok();
+
+    let event = match event {
+        TTSUtteranceEvent::TTS_UTTERANCE_STARTED => UtteranceEvent::Started(UtteranceId(int(0)?)),
+        TTSUtteranceEvent::TTS_UTTERANCE_ENDED => UtteranceEvent::Ended(UtteranceId(int(0)?)),
+        TTSUtteranceEvent::TTS_UTTERANCE_CANCELED => UtteranceEvent::Canceled(UtteranceId(int(0)?)),
+        TTSUtteranceEvent::TTS_UTTERANCE_BOUNDARY => UtteranceEvent::Boundary {
+            id: UtteranceId(int(1)?),
+            char_index: int(0)?,
+        },
+        _ => return None,
+    };
+
+    Some(event)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::{TtsVoice, Utterance};
+
+    #[test]
+    fn utterance_parameters_clamped() {
+        let voice = TtsVoice {
+            id: "voice-1".to_string(),
+            name: "Voice".to_string(),
+            language: "en_US".to_string(),
+        };
+
+        let utterance = Utterance::new("hello", &voice)
+            .volume(150)
+            .pitch(-1.0)
+            .rate(0.0)
+            .interrupt(true);
+
+        assert_eq!(utterance.voice, "voice-1");
+        assert_eq!(utterance.volume, 100);
+        assert_eq!(utterance.pitch, 0.0);
+        assert_eq!(utterance.rate, 0.1);
+        assert!(utterance.interrupt);
+    }
+}
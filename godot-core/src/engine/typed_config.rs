@@ -0,0 +1,290 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::meta::{FromGodot, ToGodot};
+use crate::builtin::{Dictionary, GodotString, Variant, VariantConversionError};
+use crate::engine::global::Error;
+use crate::engine::ConfigFile;
+use crate::obj::Gd;
+
+/// Typed access to a [`ConfigFile`], converting values with `ToGodot`/`FromGodot` and reporting which key is wrong.
+///
+/// Besides single values, whole structs can be stored as one section with [`set_struct()`][Self::set_struct] and
+/// [`get_struct()`][Self::get_struct]. These use the representation of `#[derive(ToGodot, FromGodot)]` on structs with named fields:
+/// each field becomes a key of the section.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::TypedConfig;
+///
+/// #[derive(GodotConvert, ToGodot, FromGodot, Default)]
+/// struct Video {
+///     fullscreen: bool,
+///     scale: f64,
+/// }
+///
+/// let mut config = TypedConfig::load("user://settings.cfg").unwrap_or_default();
+/// let volume: f64 = config.get_or("audio", "volume", 0.8).expect("volume must be a number");
+/// let video: Video = config.get_struct("video").unwrap_or_default();
+///
+/// config.set("audio", "volume", volume);
+/// config.set_struct("video", &video);
+/// config.save("user://settings.cfg").expect("cannot write settings");
+/// ```
+#[derive(Debug)]
+pub struct TypedConfig {
+    config: Gd<ConfigFile>,
+}
+
+impl TypedConfig {
+    /// Creates an empty configuration.
+    pub fn new() -> Self {
+        Self::from_config(ConfigFile::new())
+    }
+
+    /// Wraps an existing `ConfigFile`; changes are visible through both.
+    pub fn from_config(config: Gd<ConfigFile>) -> Self {
+        Self { config }
+    }
+
+    /// Loads the file at `path`, e.g. `user://settings.cfg`.
+    pub fn load(path: impl Into<GodotString>) -> Result<Self, ConfigError> {
+        let path = path.into();
+        let mut config = ConfigFile::new();
+
+        match config.load(path.clone()) {
+            Error::OK => Ok(Self::from_config(config)),
+            error => Err(ConfigError::Io {
+                path: path.to_string(),
+                error,
+            }),
+        }
+    }
+
+    /// Writes the configuration to the file at `path`.
+    pub fn save(&mut self, path: impl Into<GodotString>) -> Result<(), ConfigError> {
+        let path = path.into();
+
+        match self.config.save(path.clone()) {
+            Error::OK => Ok(()),
+            error => Err(ConfigError::Io {
+                path: path.to_string(),
+                error,
+            }),
+        }
+    }
+
+    /// Whether `key` exists in `section`.
+    pub fn has(&self, section: &str, key: &str) -> bool {
+        self.config.has_section_key(section.into(), key.into())
+    }
+
+    /// The value of `key` in `section`, converted to `T`.
+    pub fn get<T: FromGodot>(&self, section: &str, key: &str) -> Result<T, ConfigError> {
+        self.get_optional(section, key)?
+            .ok_or_else(|| ConfigError::MissingKey {
+                section: section.to_string(),
+                key: key.to_string(),
+            })
+    }
+
+    /// The value of `key` in `section`, or `default` if the key does not exist.
+    ///
+    /// A value of the wrong type is still an error rather than silently replaced, so that typos in hand-edited files are noticed.
+    pub fn get_or<T: FromGodot>(
+        &self,
+        section: &str,
+        key: &str,
+        default: T,
+    ) -> Result<T, ConfigError> {
+        Ok(self.get_optional(section, key)?.unwrap_or(default))
+    }
+
+    /// The value of `key` in `section`, or `T::default()` if the key does not exist.
+    pub fn get_or_default<T: FromGodot + Default>(
+        &self,
+        section: &str,
+        key: &str,
+    ) -> Result<T, ConfigError> {
+        Ok(self.get_optional(section, key)?.unwrap_or_default())
+    }
+
+    /// Sets `key` in `section` to `value`, creating the section if needed.
+    pub fn set<T: ToGodot>(&mut self, section: &str, key: &str, value: T) {
+        self.config
+            .set_value(section.into(), key.into(), value.to_variant());
+    }
+
+    /// Removes `key` from `section`, if present.
+    pub fn remove(&mut self, section: &str, key: &str) {
+        if self.has(section, key) {
+            self.config.erase_section_key(section.into(), key.into());
+        }
+    }
+
+    /// Stores the fields of `value` as the keys of `section`, replacing the previous contents of the section.
+    ///
+    /// # Panics
+    /// If `T` does not convert to a struct representation, i.e. a dictionary mapping the type name to a dictionary of fields.
+    pub fn set_struct<T: ToGodot>(&mut self, section: &str, value: &T) {
+        let fields = struct_fields(&value.to_variant()).unwrap_or_else(|| {
+            panic!(
+                "{} does not convert to a struct with named fields",
+                std::any::type_name::<T>()
+            )
+        });
+
+        if self.config.has_section(section.into()) {
+            self.config.erase_section(section.into());
+        }
+
+        for (key, value) in fields.iter_shared() {
+            self.config
+                .set_value(section.into(), key.stringify(), value);
+        }
+    }
+
+    /// Reads a struct stored with [`set_struct()`][Self::set_struct] from `section`.
+    ///
+    /// Fails if the section does not exist, or if any field is missing or has the wrong type.
+    pub fn get_struct<T: FromGodot>(&self, section: &str) -> Result<T, ConfigError> {
+        if !self.config.has_section(section.into()) {
+            return Err(ConfigError::MissingSection {
+                section: section.to_string(),
+            });
+        }
+
+        let mut fields = Dictionary::new();
+        for key in self.config.get_section_keys(section.into()).as_slice() {
+            let value = self.config.get_value(section.into(), key.clone());
+            fields.set(key.clone(), value);
+        }
+
+        let mut root = Dictionary::new();
+        root.set(struct_name::<T>(), fields);
+
+        root.to_variant()
+            .try_to::<T>()
+            .map_err(|_| ConfigError::InvalidStruct {
+                section: section.to_string(),
+                type_name: std::any::type_name::<T>(),
+            })
+    }
+
+    /// The wrapped `ConfigFile`.
+    pub fn config(&self) -> &Gd<ConfigFile> {
+        &self.config
+    }
+
+    /// Returns the wrapped `ConfigFile`.
+    pub fn into_config(self) -> Gd<ConfigFile> {
+        self.config
+    }
+
+    fn get_optional<T: FromGodot>(
+        &self,
+        section: &str,
+        key: &str,
+    ) -> Result<Option<T>, ConfigError> {
+        if !self.has(section, key) {
+            return Ok(None);
+        }
+
+        let value = self.config.get_value(section.into(), key.into());
+        value
+            .try_to::<T>()
+            .map(Some)
+            .map_err(|error| ConfigError::InvalidValue {
+                section: section.to_string(),
+                key: key.to_string(),
+                error,
+            })
+    }
+}
+
+impl Default for TypedConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Error while loading, saving or reading a [`TypedConfig`].
+#[derive(Debug, Eq, PartialEq)]
+pub enum ConfigError {
+    /// The file at `path` could not be read or written.
+    Io { path: String, error: Error },
+
+    /// `section` does not contain `key`.
+    MissingKey { section: String, key: String },
+
+    /// `section` does not exist.
+    MissingSection { section: String },
+
+    /// The value of `key` in `section` could not be converted to the requested type.
+    InvalidValue {
+        section: String,
+        key: String,
+        error: VariantConversionError,
+    },
+
+    /// The keys of `section` do not match the fields of the struct `type_name`.
+    InvalidStruct {
+        section: String,
+        type_name: &'static str,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, error } => write!(f, "cannot access config file `{path}`: {error:?}"),
+            Self::MissingKey { section, key } => {
+                write!(f, "missing config key `[{section}] {key}`")
+            }
+            Self::MissingSection { section } => write!(f, "missing config section `[{section}]`"),
+            Self::InvalidValue {
+                section,
+                key,
+                error,
+            } => write!(
+                f,
+                "invalid value for config key `[{section}] {key}`: {error}"
+            ),
+            Self::InvalidStruct { section, type_name } => {
+                write!(
+                    f,
+                    "config section `[{section}]` does not match the fields of {type_name}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+/// Extracts the field dictionary from the derived representation `{ "TypeName": { fields... } }`.
+fn struct_fields(variant: &Variant) -> Option<Dictionary> {
+    let root = variant.try_to::<Dictionary>().ok()?;
+    if root.len() != 1 {
+        return None;
+    }
+
+    root.values_array().get(0).try_to::<Dictionary>().ok()
+}
+
+/// The key used by the `FromGodot` derive for `T`: the type's identifier, without module path or generic arguments.
+fn struct_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    let name = name.split('<').next().unwrap_or(name);
+
+    name.rsplit("::").next().unwrap_or(name)
+}
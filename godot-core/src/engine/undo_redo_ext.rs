@@ -0,0 +1,261 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::meta::ToGodot;
+use crate::builtin::{Callable, GodotString, StringName, Variant, VariantArray};
+use crate::engine::undo_redo::MergeMode;
+use crate::engine::{EditorUndoRedoManager, Object, UndoRedo};
+use crate::obj::{Gd, Inherits};
+
+/// Extension trait recording undoable actions through a closure, for `EditorUndoRedoManager` and `UndoRedo`.
+///
+/// [`action()`][Self::action] creates an action, lets the closure record what the action does and how to revert it, and then
+/// commits the action (which also executes the "do" part). This replaces manual chains of `create_action()`, `add_do_method()`,
+/// `add_undo_property()` etc. and makes it impossible to forget `commit_action()`.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{EditorUndoRedoManager, Line2D, UndoRedoExt};
+///
+/// fn move_points(undo_redo: &mut Gd<EditorUndoRedoManager>, line: &Gd<Line2D>, new_points: PackedVector2Array) {
+///     let old_points = line.get_points();
+///
+///     undo_redo.action("Move points", |do_, undo| {
+///         do_.property(line, "points", new_points);
+///         undo.property(line, "points", old_points);
+///     });
+/// }
+/// ```
+pub trait UndoRedoExt {
+    /// Records and commits an action named `name`, which is not merged with previous actions.
+    ///
+    /// `record` receives the operations to perform when doing (or redoing) the action, and the ones to revert it.
+    fn action<F>(&mut self, name: impl Into<GodotString>, record: F)
+    where
+        F: FnOnce(&mut UndoRedoOps, &mut UndoRedoOps),
+    {
+        self.action_merged(name, MergeMode::MERGE_DISABLE, record)
+    }
+
+    /// Like [`action()`][Self::action], but with a custom merge mode for consecutive actions of the same name.
+    ///
+    /// This is useful for continuous edits, such as dragging a handle, which should be undone in one step.
+    fn action_merged<F>(&mut self, name: impl Into<GodotString>, merge_mode: MergeMode, record: F)
+    where
+        F: FnOnce(&mut UndoRedoOps, &mut UndoRedoOps);
+}
+
+impl UndoRedoExt for EditorUndoRedoManager {
+    fn action_merged<F>(&mut self, name: impl Into<GodotString>, merge_mode: MergeMode, record: F)
+    where
+        F: FnOnce(&mut UndoRedoOps, &mut UndoRedoOps),
+    {
+        let (do_ops, undo_ops) = UndoRedoOps::record(record);
+
+        self.create_action_ex(name.into())
+            .merge_mode(merge_mode)
+            .done();
+
+        for op in do_ops.ops {
+            match op {
+                Op::Method {
+                    object,
+                    method,
+                    args,
+                } => self.add_do_method(object, method, &args),
+                Op::Property {
+                    object,
+                    property,
+                    value,
+                } => self.add_do_property(object, property, value),
+                Op::Reference(object) => self.add_do_reference(object),
+            }
+        }
+
+        for op in undo_ops.ops {
+            match op {
+                Op::Method {
+                    object,
+                    method,
+                    args,
+                } => self.add_undo_method(object, method, &args),
+                Op::Property {
+                    object,
+                    property,
+                    value,
+                } => self.add_undo_property(object, property, value),
+                Op::Reference(object) => self.add_undo_reference(object),
+            }
+        }
+
+        self.commit_action();
+    }
+}
+
+impl UndoRedoExt for UndoRedo {
+    fn action_merged<F>(&mut self, name: impl Into<GodotString>, merge_mode: MergeMode, record: F)
+    where
+        F: FnOnce(&mut UndoRedoOps, &mut UndoRedoOps),
+    {
+        let (do_ops, undo_ops) = UndoRedoOps::record(record);
+
+        self.create_action_ex(name.into())
+            .merge_mode(merge_mode)
+            .done();
+
+        // Unlike the editor manager, UndoRedo takes methods as callables.
+        for op in do_ops.ops {
+            match op {
+                Op::Method {
+                    object,
+                    method,
+                    args,
+                } => self.add_do_method(bound_callable(object, method, args)),
+                Op::Property {
+                    object,
+                    property,
+                    value,
+                } => self.add_do_property(object, property, value),
+                Op::Reference(object) => self.add_do_reference(object),
+            }
+        }
+
+        for op in undo_ops.ops {
+            match op {
+                Op::Method {
+                    object,
+                    method,
+                    args,
+                } => self.add_undo_method(bound_callable(object, method, args)),
+                Op::Property {
+                    object,
+                    property,
+                    value,
+                } => self.add_undo_property(object, property, value),
+                Op::Reference(object) => self.add_undo_reference(object),
+            }
+        }
+
+        self.commit_action();
+    }
+}
+
+impl UndoRedoExt for Gd<EditorUndoRedoManager> {
+    fn action_merged<F>(&mut self, name: impl Into<GodotString>, merge_mode: MergeMode, record: F)
+    where
+        F: FnOnce(&mut UndoRedoOps, &mut UndoRedoOps),
+    {
+        <EditorUndoRedoManager as UndoRedoExt>::action_merged(&mut **self, name, merge_mode, record)
+    }
+}
+
+impl UndoRedoExt for Gd<UndoRedo> {
+    fn action_merged<F>(&mut self, name: impl Into<GodotString>, merge_mode: MergeMode, record: F)
+    where
+        F: FnOnce(&mut UndoRedoOps, &mut UndoRedoOps),
+    {
+        <UndoRedo as UndoRedoExt>::action_merged(&mut **self, name, merge_mode, record)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Operations recorded for one direction (do or undo) of an action; see [`UndoRedoExt`].
+///
+/// Operations are applied in the order in which they are recorded.
+pub struct UndoRedoOps {
+    ops: Vec<Op>,
+}
+
+impl UndoRedoOps {
+    /// Calls `method` on `object` with the given arguments.
+    pub fn method<T>(
+        &mut self,
+        object: &Gd<T>,
+        method: impl Into<StringName>,
+        args: &[Variant],
+    ) -> &mut Self
+    where
+        T: Inherits<Object>,
+    {
+        self.ops.push(Op::Method {
+            object: object.clone().upcast(),
+            method: method.into(),
+            args: args.to_vec(),
+        });
+        self
+    }
+
+    /// Sets `property` of `object` to `value`.
+    pub fn property<T, V>(
+        &mut self,
+        object: &Gd<T>,
+        property: impl Into<StringName>,
+        value: V,
+    ) -> &mut Self
+    where
+        T: Inherits<Object>,
+        V: ToGodot,
+    {
+        self.ops.push(Op::Property {
+            object: object.clone().upcast(),
+            property: property.into(),
+            value: value.to_variant(),
+        });
+        self
+    }
+
+    /// Keeps `object` alive as long as this direction of the action can still be applied.
+    ///
+    /// Godot frees the object when the action is discarded from the history. Typically, nodes removed by an action are
+    /// referenced on the "do" side, and nodes added by an action on the "undo" side.
+    pub fn reference<T>(&mut self, object: &Gd<T>) -> &mut Self
+    where
+        T: Inherits<Object>,
+    {
+        self.ops.push(Op::Reference(object.clone().upcast()));
+        self
+    }
+
+    fn record<F>(record: F) -> (Self, Self)
+    where
+        F: FnOnce(&mut UndoRedoOps, &mut UndoRedoOps),
+    {
+        let mut do_ops = Self { ops: Vec::new() };
+        let mut undo_ops = Self { ops: Vec::new() };
+        record(&mut do_ops, &mut undo_ops);
+
+        (do_ops, undo_ops)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+enum Op {
+    Method {
+        object: Gd<Object>,
+        method: StringName,
+        args: Vec<Variant>,
+    },
+    Property {
+        object: Gd<Object>,
+        property: StringName,
+        value: Variant,
+    },
+    Reference(Gd<Object>),
+}
+
+fn bound_callable(object: Gd<Object>, method: StringName, args: Vec<Variant>) -> Callable {
+    let callable = Callable::from_object_method(object, method);
+    if args.is_empty() {
+        return callable;
+    }
+
+    let args = args.into_iter().collect::<VariantArray>();
+    callable.as_inner().bindv(args)
+}
@@ -0,0 +1,454 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::builtin::meta::{FromGodot, ToGodot};
+use crate::builtin::{PackedByteArray, VariantConversionError};
+use crate::engine::utilities;
+use crate::obj::GodotClass;
+
+/// Typed access to Godot's binary `Variant` serialization (`var_to_bytes()`/`bytes_to_var()`).
+///
+/// Decoding objects from untrusted bytes is dangerous: `bytes_to_var_with_objects()` instantiates any class named in the data and
+/// sets its properties, including `script`, which allows a crafted save file or network message to run arbitrary code. A codec
+/// therefore decodes objects only of classes that were explicitly allowed with [`allow_class()`][Self::allow_class]. Before
+/// anything is decoded, the bytes are checked as a whole; if they name another class, decoding fails and nothing is instantiated.
+///
+/// Classes are matched exactly, not by inheritance. Allowing `Resource` does not allow its subclasses, which would include
+/// `GDScript`.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{Gradient, VariantCodec};
+///
+/// // Plain data: no objects at all.
+/// let codec = VariantCodec::new();
+/// let bytes = codec.encode(&dict! { "level": 3, "name": "Ada" });
+/// let state: Dictionary = codec.decode(&bytes).expect("valid save data");
+///
+/// // Also decodes `Gradient` objects, but no other classes.
+/// let codec = VariantCodec::new().allow_class::<Gradient>();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct VariantCodec {
+    allowed_classes: HashSet<String>,
+}
+
+impl VariantCodec {
+    /// Codec that encodes and decodes values without objects.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allows objects of class `T` (but not its subclasses) to be encoded with their properties, and decoded.
+    pub fn allow_class<T: GodotClass>(self) -> Self {
+        self.allow_class_name(T::class_name().as_str())
+    }
+
+    /// Like [`allow_class()`][Self::allow_class], for classes only known by name, such as script classes.
+    pub fn allow_class_name(mut self, class_name: impl Into<String>) -> Self {
+        self.allowed_classes.insert(class_name.into());
+        self
+    }
+
+    /// Whether any class is allowed, in which case the `_with_objects` variants of Godot's functions are used.
+    pub fn allows_objects(&self) -> bool {
+        !self.allowed_classes.is_empty()
+    }
+
+    /// Serializes `value`.
+    ///
+    /// If the codec [allows objects][Self::allows_objects], objects are encoded with their stored properties, whatever their class.
+    /// Otherwise, they are encoded as instance IDs, which only make sense within the running process.
+    pub fn encode<T: ToGodot>(&self, value: &T) -> PackedByteArray {
+        let variant = value.to_variant();
+
+        if self.allows_objects() {
+            utilities::var_to_bytes_with_objects(variant)
+        } else {
+            utilities::var_to_bytes(variant)
+        }
+    }
+
+    /// Deserializes a value of type `T`.
+    ///
+    /// Fails without decoding anything if `bytes` is not a complete, well-formed serialization, or if it contains an object whose
+    /// class is not allowed. Objects encoded as instance IDs are represented by class `EncodedObjectAsID`.
+    ///
+    /// Decoded objects that are not reference-counted are owned by the caller and must be freed manually.
+    pub fn decode<T: FromGodot>(&self, bytes: &PackedByteArray) -> Result<T, VariantDecodeError> {
+        Scanner {
+            bytes: bytes.as_slice(),
+            pos: 0,
+            allowed_classes: &self.allowed_classes,
+        }
+        .scan()?;
+
+        let variant = if self.allows_objects() {
+            utilities::bytes_to_var_with_objects(bytes.clone())
+        } else {
+            utilities::bytes_to_var(bytes.clone())
+        };
+
+        T::try_from_variant(&variant).map_err(VariantDecodeError::InvalidValue)
+    }
+}
+
+/// Error returned by [`VariantCodec::decode()`].
+#[derive(Debug, Eq, PartialEq)]
+pub enum VariantDecodeError {
+    /// The bytes are truncated, have trailing data, or are not a serialization understood by this version of Godot.
+    Malformed,
+
+    /// The bytes contain an object of a class that the codec does not allow.
+    ClassNotAllowed { class: String },
+
+    /// The decoded value could not be converted to the requested type.
+    InvalidValue(VariantConversionError),
+}
+
+impl fmt::Display for VariantDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed => f.write_str("malformed variant serialization"),
+            Self::ClassNotAllowed { class } => {
+                write!(f, "serialized object of class `{class}` is not allowed")
+            }
+            Self::InvalidValue(error) => write!(f, "decoded value has wrong type: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for VariantDecodeError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+// Layout of Godot's encoding, see `core/io/marshalls.cpp`. Each value starts with a 32-bit header holding the variant type in its
+// lowest byte and flags above. The only flag understood here means "64-bit" for numbers and vectors, and "encoded as ID" for objects.
+const HEADER_TYPE_MASK: u32 = 0xFF;
+const FLAG_64: u32 = 1 << 16;
+
+/// Godot's `Variant::MAX_RECURSION_DEPTH`; deeper data is rejected by Godot as well.
+const MAX_DEPTH: usize = 1024;
+
+// Variant type ordinals, as in `VariantType`.
+mod ty {
+    pub const NIL: u32 = 0;
+    pub const BOOL: u32 = 1;
+    pub const INT: u32 = 2;
+    pub const FLOAT: u32 = 3;
+    pub const STRING: u32 = 4;
+    pub const VECTOR2: u32 = 5;
+    pub const VECTOR2I: u32 = 6;
+    pub const RECT2: u32 = 7;
+    pub const RECT2I: u32 = 8;
+    pub const VECTOR3: u32 = 9;
+    pub const VECTOR3I: u32 = 10;
+    pub const TRANSFORM2D: u32 = 11;
+    pub const VECTOR4: u32 = 12;
+    pub const VECTOR4I: u32 = 13;
+    pub const PLANE: u32 = 14;
+    pub const QUATERNION: u32 = 15;
+    pub const AABB: u32 = 16;
+    pub const BASIS: u32 = 17;
+    pub const TRANSFORM3D: u32 = 18;
+    pub const PROJECTION: u32 = 19;
+    pub const COLOR: u32 = 20;
+    pub const STRING_NAME: u32 = 21;
+    pub const NODE_PATH: u32 = 22;
+    pub const RID: u32 = 23;
+    pub const OBJECT: u32 = 24;
+    pub const CALLABLE: u32 = 25;
+    pub const SIGNAL: u32 = 26;
+    pub const DICTIONARY: u32 = 27;
+    pub const ARRAY: u32 = 28;
+    pub const PACKED_BYTE_ARRAY: u32 = 29;
+    pub const PACKED_INT32_ARRAY: u32 = 30;
+    pub const PACKED_INT64_ARRAY: u32 = 31;
+    pub const PACKED_FLOAT32_ARRAY: u32 = 32;
+    pub const PACKED_FLOAT64_ARRAY: u32 = 33;
+    pub const PACKED_STRING_ARRAY: u32 = 34;
+    pub const PACKED_VECTOR2_ARRAY: u32 = 35;
+    pub const PACKED_VECTOR3_ARRAY: u32 = 36;
+    pub const PACKED_COLOR_ARRAY: u32 = 37;
+}
+
+/// Walks the serialized data without decoding it, checking its structure and the classes of all contained objects.
+struct Scanner<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    allowed_classes: &'a HashSet<String>,
+}
+
+type ScanResult<T = ()> = Result<T, VariantDecodeError>;
+
+impl Scanner<'_> {
+    fn scan(mut self) -> ScanResult {
+        self.variant(0)?;
+
+        if self.pos != self.bytes.len() {
+            return Err(VariantDecodeError::Malformed);
+        }
+        Ok(())
+    }
+
+    fn variant(&mut self, depth: usize) -> ScanResult {
+        if depth > MAX_DEPTH {
+            return Err(VariantDecodeError::Malformed);
+        }
+
+        let header = self.u32()?;
+        let flags = header & !HEADER_TYPE_MASK;
+        if flags & !FLAG_64 != 0 {
+            return Err(VariantDecodeError::Malformed);
+        }
+
+        let is_64 = flags & FLAG_64 != 0;
+        let real = if is_64 { 8 } else { 4 };
+
+        match header & HEADER_TYPE_MASK {
+            ty::NIL | ty::CALLABLE => Ok(()),
+            ty::BOOL => self.skip(4),
+            ty::INT | ty::FLOAT => self.skip(real),
+
+            ty::VECTOR2 => self.skip(2 * real),
+            ty::VECTOR3 => self.skip(3 * real),
+            ty::RECT2 | ty::VECTOR4 | ty::PLANE | ty::QUATERNION => self.skip(4 * real),
+            ty::TRANSFORM2D | ty::AABB => self.skip(6 * real),
+            ty::BASIS => self.skip(9 * real),
+            ty::TRANSFORM3D => self.skip(12 * real),
+            ty::PROJECTION => self.skip(16 * real),
+
+            ty::VECTOR2I | ty::RID => self.skip(8),
+            ty::VECTOR3I => self.skip(12),
+            ty::RECT2I | ty::VECTOR4I | ty::COLOR => self.skip(16),
+
+            ty::STRING | ty::STRING_NAME => self.string().map(drop),
+            ty::NODE_PATH => self.node_path(),
+            ty::SIGNAL => {
+                self.string()?;
+                self.skip(8)
+            }
+
+            ty::OBJECT if is_64 => {
+                self.check_class("EncodedObjectAsID")?;
+                self.skip(8)
+            }
+            ty::OBJECT => self.object(depth),
+
+            ty::DICTIONARY => {
+                let len = self.container_len()?;
+                for _ in 0..len {
+                    self.variant(depth + 1)?;
+                    self.variant(depth + 1)?;
+                }
+                Ok(())
+            }
+            ty::ARRAY => {
+                let len = self.container_len()?;
+                for _ in 0..len {
+                    self.variant(depth + 1)?;
+                }
+                Ok(())
+            }
+
+            ty::PACKED_BYTE_ARRAY => {
+                let len = self.u32()? as usize;
+                self.skip_padded(len)
+            }
+            ty::PACKED_INT32_ARRAY | ty::PACKED_FLOAT32_ARRAY => self.packed(4),
+            ty::PACKED_INT64_ARRAY | ty::PACKED_FLOAT64_ARRAY => self.packed(8),
+            ty::PACKED_VECTOR2_ARRAY => self.packed(2 * real),
+            ty::PACKED_VECTOR3_ARRAY => self.packed(3 * real),
+            ty::PACKED_COLOR_ARRAY => self.packed(16),
+            ty::PACKED_STRING_ARRAY => {
+                let len = self.u32()?;
+                for _ in 0..len {
+                    self.string()?;
+                }
+                Ok(())
+            }
+
+            _ => Err(VariantDecodeError::Malformed),
+        }
+    }
+
+    /// Object with its class name and stored properties; an empty class name stands for a null object.
+    fn object(&mut self, depth: usize) -> ScanResult {
+        let class = self.string()?;
+        if class.is_empty() {
+            return Ok(());
+        }
+
+        self.check_class(&class)?;
+
+        let property_count = self.u32()?;
+        for _ in 0..property_count {
+            self.string()?;
+            self.variant(depth + 1)?;
+        }
+        Ok(())
+    }
+
+    fn node_path(&mut self) -> ScanResult {
+        // Only the "new" format written since Godot 3.0 is supported; it is marked by the highest bit.
+        let name_count = self.u32()?;
+        if name_count & 0x8000_0000 == 0 {
+            return Err(VariantDecodeError::Malformed);
+        }
+
+        let subname_count = self.u32()?;
+        self.skip(4)?; // absolute flag
+
+        for _ in 0..(name_count & 0x7FFF_FFFF) + subname_count {
+            self.string()?;
+        }
+        Ok(())
+    }
+
+    fn check_class(&self, class: &str) -> ScanResult {
+        if self.allowed_classes.contains(class) {
+            Ok(())
+        } else {
+            Err(VariantDecodeError::ClassNotAllowed {
+                class: class.to_string(),
+            })
+        }
+    }
+
+    /// Element count of arrays and dictionaries; the highest bit marks shared containers.
+    fn container_len(&mut self) -> ScanResult<u32> {
+        Ok(self.u32()? & 0x7FFF_FFFF)
+    }
+
+    fn packed(&mut self, element_size: usize) -> ScanResult {
+        let len = self.u32()? as usize;
+        let size = len
+            .checked_mul(element_size)
+            .ok_or(VariantDecodeError::Malformed)?;
+        self.skip(size)
+    }
+
+    /// Length-prefixed UTF-8, padded to 4 bytes.
+    fn string(&mut self) -> ScanResult<String> {
+        let len = self.u32()? as usize;
+        let start = self.pos;
+        self.skip_padded(len)?;
+
+        std::str::from_utf8(&self.bytes[start..start + len])
+            .map(|s| s.trim_end_matches('\0').to_string())
+            .map_err(|_| VariantDecodeError::Malformed)
+    }
+
+    fn u32(&mut self) -> ScanResult<u32> {
+        let start = self.pos;
+        self.skip(4)?;
+
+        let bytes = self.bytes[start..start + 4].try_into().unwrap();
+        Ok(u32::from_le_bytes(bytes))
+    }
+
+    fn skip_padded(&mut self, len: usize) -> ScanResult {
+        let padded = len.checked_add(3).ok_or(VariantDecodeError::Malformed)? & !3;
+        self.skip(padded)
+    }
+
+    fn skip(&mut self, len: usize) -> ScanResult {
+        match self.pos.checked_add(len) {
+            Some(end) if end <= self.bytes.len() => {
+                self.pos = end;
+                Ok(())
+            }
+            _ => Err(VariantDecodeError::Malformed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scan(bytes: &[u8], allowed: &[&str]) -> ScanResult {
+        let allowed_classes = allowed.iter().map(|class| class.to_string()).collect();
+        Scanner {
+            bytes,
+            pos: 0,
+            allowed_classes: &allowed_classes,
+        }
+        .scan()
+    }
+
+    fn words(words: &[u32]) -> Vec<u8> {
+        words.iter().flat_map(|word| word.to_le_bytes()).collect()
+    }
+
+    /// Object with one property `script` holding an object of class `class`, which has no properties.
+    fn object_with_script(class: &str) -> Vec<u8> {
+        let mut bytes = words(&[ty::OBJECT, 8]);
+        bytes.extend(b"Resource");
+        bytes.extend(words(&[1, 6]));
+        bytes.extend(b"script\0\0");
+        bytes.extend(words(&[ty::OBJECT, class.len() as u32]));
+        bytes.extend(class.as_bytes());
+        bytes.resize((bytes.len() + 3) & !3, 0);
+        bytes.extend(words(&[0]));
+        bytes
+    }
+
+    #[test]
+    fn scan_plain_values() {
+        // [1, "ab"] as 32-bit values.
+        let mut array = words(&[ty::ARRAY, 2, ty::INT, 1, ty::STRING, 2]);
+        array.extend(b"ab\0\0");
+        assert_eq!(scan(&array, &[]), Ok(()));
+
+        // 64-bit float, truncated and with trailing bytes.
+        let float = words(&[ty::FLOAT | FLAG_64, 0, 0]);
+        assert_eq!(scan(&float, &[]), Ok(()));
+        assert_eq!(scan(&float[..8], &[]), Err(VariantDecodeError::Malformed));
+        assert_eq!(
+            scan(&words(&[ty::NIL, 0]), &[]),
+            Err(VariantDecodeError::Malformed)
+        );
+
+        // Unknown type and unknown flags.
+        assert_eq!(
+            scan(&words(&[200]), &[]),
+            Err(VariantDecodeError::Malformed)
+        );
+        assert_eq!(
+            scan(&words(&[ty::NIL | 1 << 20]), &[]),
+            Err(VariantDecodeError::Malformed)
+        );
+    }
+
+    #[test]
+    fn scan_nested_objects() {
+        let bytes = object_with_script("GDScript");
+
+        assert_eq!(
+            scan(&bytes, &[]),
+            Err(VariantDecodeError::ClassNotAllowed {
+                class: "Resource".to_string()
+            })
+        );
+        assert_eq!(
+            scan(&bytes, &["Resource"]),
+            Err(VariantDecodeError::ClassNotAllowed {
+                class: "GDScript".to_string()
+            })
+        );
+        assert_eq!(scan(&bytes, &["Resource", "GDScript"]), Ok(()));
+
+        // Objects encoded as IDs.
+        let id = words(&[ty::OBJECT | FLAG_64, 1, 0]);
+        assert!(scan(&id, &[]).is_err());
+        assert_eq!(scan(&id, &["EncodedObjectAsID"]), Ok(()));
+    }
+}
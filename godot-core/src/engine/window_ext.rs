@@ -0,0 +1,259 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::meta::ToGodot;
+use crate::builtin::{Transform2D, Vector2, Vector2i};
+use crate::engine::window::Mode;
+use crate::engine::{Node, Window};
+use crate::obj::{Gd, Inherits};
+
+#[cfg(since_api = "4.2")]
+use crate::builtin::{Callable, Variant};
+#[cfg(since_api = "4.2")]
+use crate::engine::DisplayServer;
+
+/// Builder for a [`Window`], setting size, placement and flags in one expression.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::window::Mode;
+/// use godot::engine::WindowBuilder;
+///
+/// fn open_inspector(parent: &Gd<Node>) {
+///     let window = WindowBuilder::new("Inspector")
+///         .size(Vector2i::new(400, 600))
+///         .position(Vector2i::new(100, 100))
+///         .always_on_top(true)
+///         .add_to(parent);
+///
+///     godot_print!("opened window {}", window.get_window_id());
+/// }
+/// ```
+pub struct WindowBuilder {
+    window: Gd<Window>,
+}
+
+impl WindowBuilder {
+    /// Starts a window with the given title.
+    pub fn new(title: &str) -> Self {
+        let mut window = Window::new_alloc();
+        window.set_title(title.into());
+
+        Self { window }
+    }
+
+    /// Size of the window's content area, in pixels.
+    pub fn size(mut self, size: Vector2i) -> Self {
+        self.window.set_size(size);
+        self
+    }
+
+    /// Minimum size the user can resize the window to.
+    pub fn min_size(mut self, size: Vector2i) -> Self {
+        self.window.set_min_size(size);
+        self
+    }
+
+    /// Position of the top-left corner, in screen coordinates (or relative to the embedder, for embedded windows).
+    pub fn position(mut self, position: Vector2i) -> Self {
+        self.window.set_position(position);
+        self
+    }
+
+    /// Windowed, minimized, maximized or fullscreen.
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.window.set_mode(mode);
+        self
+    }
+
+    /// Whether the window is shown when added to the tree; `true` by default.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.window.set_visible(visible);
+        self
+    }
+
+    /// Without title bar and border.
+    pub fn borderless(self, borderless: bool) -> Self {
+        self.flag("borderless", borderless)
+    }
+
+    /// Kept above all other windows.
+    pub fn always_on_top(self, always_on_top: bool) -> Self {
+        self.flag("always_on_top", always_on_top)
+    }
+
+    /// Whether the user can resize the window; `true` by default.
+    pub fn resizable(self, resizable: bool) -> Self {
+        self.flag("unresizable", !resizable)
+    }
+
+    /// With transparent background, if the project allows per-pixel transparency.
+    pub fn transparent(self, transparent: bool) -> Self {
+        self.flag("transparent", transparent)
+    }
+
+    /// Attached to its parent window: it stays above it and is minimized and closed with it.
+    pub fn transient(mut self, transient: bool) -> Self {
+        self.window.set_transient(transient);
+        self
+    }
+
+    /// Blocks input to the parent window while open; requires [`transient()`][Self::transient].
+    pub fn exclusive(mut self, exclusive: bool) -> Self {
+        self.window.set_exclusive(exclusive);
+        self
+    }
+
+    /// Scale of the content, e.g. 2.0 to draw everything twice as large.
+    pub fn content_scale_factor(mut self, factor: f32) -> Self {
+        self.window.set_content_scale_factor(factor);
+        self
+    }
+
+    /// Finishes the window, without adding it to the tree; it opens once added.
+    pub fn build(self) -> Gd<Window> {
+        self.window
+    }
+
+    /// Finishes the window and adds it as child of `parent`, which opens it unless it was made invisible.
+    pub fn add_to<N: Inherits<Node>>(self, parent: &Gd<N>) -> Gd<Window> {
+        let window = self.build();
+        parent
+            .clone()
+            .upcast::<Node>()
+            .add_child(window.clone().upcast());
+
+        window
+    }
+
+    fn flag(mut self, property: &str, enabled: bool) -> Self {
+        self.window.set(property.into(), enabled.to_variant());
+        self
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Extension methods for [`Window`], converting between coordinate spaces and reacting to size changes.
+///
+/// There are three coordinate spaces:
+/// - **Screen**: pixels on the desktop, as used by `DisplayServer` (for embedded windows: the embedder's viewport).
+/// - **Window**: pixels of the window's content area, with the origin at its top-left corner.
+/// - **Canvas**: 2D world coordinates, as used by `Node2D` positions. They differ from window coordinates by the content scale
+///   (stretch mode) and the canvas transform (e.g. from a `Camera2D`).
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{DisplayServer, Window, WindowExt};
+///
+/// fn hovered_world_position(window: &Gd<Window>) -> Vector2 {
+///     let mouse = DisplayServer::singleton().mouse_get_position();
+///     window.screen_to_canvas(mouse)
+/// }
+/// ```
+pub trait WindowExt {
+    /// Converts a position on the screen to the window's content area.
+    fn screen_to_window(&self, position: Vector2i) -> Vector2i;
+
+    /// Converts a position in the window's content area to the screen.
+    fn window_to_screen(&self, position: Vector2i) -> Vector2i;
+
+    /// Converts a position in the window's content area to canvas (2D world) coordinates.
+    fn window_to_canvas(&self, position: Vector2) -> Vector2;
+
+    /// Converts canvas (2D world) coordinates to a position in the window's content area.
+    fn canvas_to_window(&self, position: Vector2) -> Vector2;
+
+    /// Converts a position on the screen to canvas (2D world) coordinates.
+    fn screen_to_canvas(&self, position: Vector2i) -> Vector2 {
+        self.window_to_canvas(Vector2::from_vector2i(self.screen_to_window(position)))
+    }
+
+    /// Calls `on_resized` with the new size whenever the window is resized.
+    ///
+    /// Returns the connected callable, which can be passed to `disconnect("size_changed", callable)` to stop the notifications.
+    #[cfg(since_api = "4.2")]
+    fn on_size_changed<F>(&mut self, on_resized: F) -> Callable
+    where
+        F: FnMut(Vector2i) + Send + Sync + 'static;
+
+    /// Calls `on_dpi_changed` with the new DPI of the window's screen whenever it changes, e.g. when the window is moved to another
+    /// monitor. Godot only reports DPI changes on macOS.
+    ///
+    /// Returns the connected callable, which can be passed to `disconnect("dpi_changed", callable)` to stop the notifications.
+    #[cfg(since_api = "4.2")]
+    fn on_dpi_changed<F>(&mut self, on_dpi_changed: F) -> Callable
+    where
+        F: FnMut(i32) + Send + Sync + 'static;
+}
+
+impl WindowExt for Gd<Window> {
+    fn screen_to_window(&self, position: Vector2i) -> Vector2i {
+        position - self.get_position()
+    }
+
+    fn window_to_screen(&self, position: Vector2i) -> Vector2i {
+        position + self.get_position()
+    }
+
+    fn window_to_canvas(&self, position: Vector2) -> Vector2 {
+        canvas_to_window_transform(self).affine_inverse() * position
+    }
+
+    fn canvas_to_window(&self, position: Vector2) -> Vector2 {
+        canvas_to_window_transform(self) * position
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn on_size_changed<F>(&mut self, mut on_resized: F) -> Callable
+    where
+        F: FnMut(Vector2i) + Send + Sync + 'static,
+    {
+        let window_id = self.instance_id();
+        let callable = Callable::from_fn("WindowExt::on_size_changed", move |_args| {
+            if let Some(window) = Gd::<Window>::try_from_instance_id(window_id) {
+                on_resized(window.get_size());
+            }
+
+            Ok(Variant::nil())
+        });
+
+        self.connect("size_changed".into(), callable.clone());
+        callable
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn on_dpi_changed<F>(&mut self, mut on_dpi_changed: F) -> Callable
+    where
+        F: FnMut(i32) + Send + Sync + 'static,
+    {
+        let window_id = self.instance_id();
+        let callable = Callable::from_fn("WindowExt::on_dpi_changed", move |_args| {
+            if let Some(window) = Gd::<Window>::try_from_instance_id(window_id) {
+                let dpi = DisplayServer::singleton()
+                    .screen_get_dpi_ex()
+                    .screen(window.get_current_screen())
+                    .done();
+                on_dpi_changed(dpi);
+            }
+
+            Ok(Variant::nil())
+        });
+
+        self.connect("dpi_changed".into(), callable.clone());
+        callable
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+/// Maps canvas coordinates to window pixels: canvas transform, then global canvas transform, then content scale.
+fn canvas_to_window_transform(window: &Gd<Window>) -> Transform2D {
+    window.get_final_transform()
+        * window.get_global_canvas_transform()
+        * window.get_canvas_transform()
+}
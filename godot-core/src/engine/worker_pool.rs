@@ -0,0 +1,341 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::builtin::{Callable, GodotString, Variant};
+use crate::engine::global::Error;
+use crate::engine::{Os, WorkerThreadPool};
+
+/// Task for Godot's [`WorkerThreadPool`], which runs Rust closures on the engine's worker threads instead of new OS threads.
+///
+/// A task is started with one of the final methods: [`spawn()`][Self::spawn] runs one closure, [`spawn_group()`][Self::spawn_group]
+/// runs a closure for each index of a range, and [`for_each()`][Self::for_each] does the same with a borrowing closure, waiting for
+/// it to finish. Panics inside the closures are caught on the worker thread and passed to the thread that joins the task.
+///
+/// ```no_run
+/// use godot::engine::PoolTask;
+///
+/// let generate = PoolTask::new()
+///     .description("Generate terrain")
+///     .spawn(|| (0..256 * 256).map(|i| (i % 7) as f32).collect::<Vec<_>>());
+///
+/// // ... other work on the main thread ...
+///
+/// let heights = generate.join().expect("terrain generation panicked");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PoolTask {
+    high_priority: bool,
+    description: String,
+}
+
+impl PoolTask {
+    /// A low-priority task without description.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the task is scheduled before low-priority ones; `false` by default.
+    ///
+    /// Godot limits how many threads run low-priority tasks, so that high-priority tasks always find a free thread.
+    pub fn high_priority(mut self, high_priority: bool) -> Self {
+        self.high_priority = high_priority;
+        self
+    }
+
+    /// Description shown in the debugger.
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = description.to_string();
+        self
+    }
+
+    /// Runs `task` on a worker thread, returning a handle to its result.
+    pub fn spawn<F, R>(self, task: F) -> TaskHandle<R>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let result = Arc::new(Mutex::new(None));
+        let slot = Arc::clone(&result);
+        let task = Mutex::new(Some(task));
+
+        let callable = Callable::from_fn(self.name(), move |_args: &[&Variant]| {
+            // Godot calls a task exactly once; the Option only moves the FnOnce out of the closure.
+            if let Some(task) = lock(&task).take() {
+                *lock(&slot) = Some(panic::catch_unwind(AssertUnwindSafe(task)));
+            }
+            Ok(Variant::nil())
+        });
+
+        TaskHandle {
+            id: Some(self.add_task(callable)),
+            result,
+        }
+    }
+
+    /// Runs `element(index)` for every index in `0..count`, spread over the worker threads.
+    pub fn spawn_group<F>(self, count: usize, element: F) -> GroupHandle
+    where
+        F: Fn(usize) + Send + Sync + 'static,
+    {
+        self.start_group(count, Arc::new(element))
+    }
+
+    /// Like [`spawn_group()`][Self::spawn_group], but blocks until all elements are processed, so `element` can borrow local data.
+    ///
+    /// # Panics
+    /// If `element` panicked for one of the indices, that panic is resumed on the calling thread.
+    pub fn for_each<F>(self, count: usize, element: F)
+    where
+        F: Fn(usize) + Sync,
+    {
+        let element: &(dyn Fn(usize) + Sync) = &element;
+
+        // SAFETY: the group is waited for before this function returns, also when unwinding, since `start_group()` and the handle
+        // wait for all queued tasks on drop. Afterwards, the tasks never call `element` again. The tasks' callables may be dropped
+        // later by Godot, which only drops the reference.
+        let element: &'static (dyn Fn(usize) + Sync) = unsafe { std::mem::transmute(element) };
+
+        let group = self.start_group(count, Arc::new(move |index| element(index)));
+        if let Err(payload) = group.join() {
+            panic::resume_unwind(payload);
+        }
+    }
+
+    fn start_group(self, count: usize, element: Arc<dyn Fn(usize) + Send + Sync>) -> GroupHandle {
+        let state = Arc::new(GroupState {
+            count,
+            next: AtomicUsize::new(0),
+            processed: AtomicUsize::new(0),
+            panic: Mutex::new(None),
+        });
+
+        // Instead of Godot's group tasks, several regular tasks take indices from a shared counter: a group task calls the same
+        // callable from many threads at once, while the closure of `Callable::from_fn()` may only run on one thread at a time.
+        let threads = usize::try_from(Os::singleton().get_processor_count()).unwrap_or(1);
+        let tasks = threads.clamp(1, count.max(1));
+
+        // The handle exists before the first task is queued, so that a panic while queuing the others still waits for it on drop.
+        let mut group = GroupHandle {
+            ids: Vec::with_capacity(tasks),
+            state,
+        };
+
+        for _ in 0..tasks {
+            let state = Arc::clone(&group.state);
+            let element = Arc::clone(&element);
+
+            let callable = Callable::from_fn(self.name(), move |_args: &[&Variant]| {
+                state.run(&*element);
+                Ok(Variant::nil())
+            });
+
+            let id = self.add_task(callable);
+            group.ids.push(id);
+        }
+
+        group
+    }
+
+    fn add_task(&self, callable: Callable) -> i64 {
+        WorkerThreadPool::singleton()
+            .add_task_ex(callable)
+            .high_priority(self.high_priority)
+            .description(GodotString::from(self.description.as_str()))
+            .done()
+    }
+
+    fn name(&self) -> String {
+        if self.description.is_empty() {
+            "PoolTask".to_string()
+        } else {
+            format!("PoolTask({})", self.description)
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Running task started with [`PoolTask::spawn()`].
+///
+/// Godot requires each task to be waited for, so dropping the handle without [`join()`][Self::join] blocks until the task
+/// finishes, discarding its result.
+pub struct TaskHandle<R> {
+    /// `None` once waited for.
+    id: Option<i64>,
+    result: Arc<Mutex<Option<thread::Result<R>>>>,
+}
+
+impl<R> TaskHandle<R> {
+    /// Whether the task has finished, so that [`join()`][Self::join] returns without blocking.
+    pub fn is_finished(&self) -> bool {
+        match self.id {
+            Some(id) => WorkerThreadPool::singleton().is_task_completed(id),
+            None => true,
+        }
+    }
+
+    /// Waits for the task to finish, and returns its result, or the payload of its panic like `std::thread::JoinHandle::join()`.
+    pub fn join(mut self) -> thread::Result<R> {
+        self.wait();
+
+        lock(&self.result)
+            .take()
+            .expect("task finished without storing its result")
+    }
+
+    fn wait(&mut self) {
+        if let Some(id) = self.id.take() {
+            wait_for(WorkerThreadPool::singleton().wait_for_task_completion(id));
+        }
+    }
+}
+
+impl<R> Drop for TaskHandle<R> {
+    fn drop(&mut self) {
+        self.wait();
+    }
+}
+
+/// Running group of tasks started with [`PoolTask::spawn_group()`].
+///
+/// Like [`TaskHandle`], dropping the handle without [`join()`][Self::join] blocks until all elements are processed.
+pub struct GroupHandle {
+    /// Empty once waited for.
+    ids: Vec<i64>,
+    state: Arc<GroupState>,
+}
+
+impl GroupHandle {
+    /// How many elements have been processed so far.
+    pub fn processed_count(&self) -> usize {
+        self.state.processed.load(Ordering::Acquire)
+    }
+
+    /// Whether all elements have been processed, so that [`join()`][Self::join] returns without blocking.
+    pub fn is_finished(&self) -> bool {
+        let mut pool = WorkerThreadPool::singleton();
+
+        self.ids.iter().all(|id| pool.is_task_completed(*id))
+    }
+
+    /// Waits until all elements are processed.
+    ///
+    /// Returns the payload of the first panic, if `element` panicked; the elements not started by then are skipped.
+    pub fn join(mut self) -> thread::Result<()> {
+        self.wait();
+
+        match lock(&self.state.panic).take() {
+            Some(payload) => Err(payload),
+            None => Ok(()),
+        }
+    }
+
+    fn wait(&mut self) {
+        let mut pool = WorkerThreadPool::singleton();
+
+        // Unlike `drain()`, keeps the ids not waited for yet, so that they are still waited for on drop if this panics.
+        while let Some(id) = self.ids.pop() {
+            wait_for(pool.wait_for_task_completion(id));
+        }
+    }
+}
+
+impl Drop for GroupHandle {
+    fn drop(&mut self) {
+        self.wait();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+struct GroupState {
+    count: usize,
+    next: AtomicUsize,
+    processed: AtomicUsize,
+    panic: Mutex<Option<Box<dyn Any + Send>>>,
+}
+
+impl GroupState {
+    /// Processes elements until none are left, or until one of them panicked.
+    fn run(&self, element: &(dyn Fn(usize) + Sync)) {
+        loop {
+            let index = self.next.fetch_add(1, Ordering::Relaxed);
+            if index >= self.count {
+                return;
+            }
+
+            if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| element(index))) {
+                // Stops the other tasks from starting further elements.
+                self.next.store(self.count, Ordering::Relaxed);
+                lock(&self.panic).get_or_insert(payload);
+                return;
+            }
+
+            self.processed.fetch_add(1, Ordering::Release);
+        }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    // Panics are caught before they can poison the results.
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn wait_for(error: Error) {
+    // ERR_INVALID_PARAMETER would mean a task was waited for twice, which the handles rule out.
+    debug_assert_eq!(error, Error::OK, "WorkerThreadPool failed to wait for task");
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    use super::GroupState;
+
+    fn group(count: usize) -> GroupState {
+        GroupState {
+            count,
+            next: AtomicUsize::new(0),
+            processed: AtomicUsize::new(0),
+            panic: Mutex::new(None),
+        }
+    }
+
+    #[test]
+    fn group_state_processes_all() {
+        let state = group(10);
+        let sum = AtomicUsize::new(0);
+
+        state.run(&|index| {
+            sum.fetch_add(index, Ordering::Relaxed);
+        });
+
+        assert_eq!(sum.load(Ordering::Relaxed), 45);
+        assert_eq!(state.processed.load(Ordering::Relaxed), 10);
+        assert!(state.panic.lock().unwrap().is_none());
+    }
+
+    #[test]
+    fn group_state_stops_after_panic() {
+        let state = group(10);
+
+        state.run(&|index| assert!(index < 3, "element {index}"));
+        state.run(&|_| unreachable!("no elements left after a panic"));
+
+        assert_eq!(state.processed.load(Ordering::Relaxed), 3);
+        assert!(state.panic.lock().unwrap().is_some());
+    }
+}
@@ -0,0 +1,255 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::{Ref, RefCell, RefMut};
+use std::sync::{Mutex, OnceLock};
+use std::thread::{self, ThreadId};
+
+use super::InitLevel;
+
+/// Global state of the extension, created lazily on the main thread and dropped when its init level is unloaded.
+///
+/// Replaces `static mut` and `lazy_static` for state that holds Godot objects or is otherwise tied to the engine's lifetime:
+/// - The value is created on first access, with the function given to [`new()`][Self::new]. Access is only allowed once `level`
+///   has been loaded.
+/// - It can only be accessed from the main thread. Other threads panic, so the value does not need to be `Send` or `Sync`.
+/// - When Godot unloads `level`, the value is dropped, after [`ExtensionLibrary::on_level_deinit()`][super::ExtensionLibrary::on_level_deinit]
+///   has run; for levels below [`ExtensionLibrary::min_level()`][super::ExtensionLibrary::min_level], when the minimum level is
+///   unloaded. If the level is loaded again (e.g. on hot reload), the next access creates a new value.
+///
+/// Borrowing follows the rules of [`RefCell`]: any number of [`borrow()`][Self::borrow]s or one [`borrow_mut()`][Self::borrow_mut]
+/// at a time, checked at runtime.
+///
+/// Usually declared with [`main_thread_static!`][crate::main_thread_static]:
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::init::main_thread_static;
+///
+/// main_thread_static! {
+///     /// Points per player name.
+///     static SCORES: Dictionary = Dictionary::new();
+/// }
+///
+/// fn add_points(player: &str, points: i64) {
+///     let mut scores = SCORES.borrow_mut();
+///     let current = scores.get_or_nil(player).try_to::<i64>().unwrap_or(0);
+///     scores.set(player, current + points);
+/// }
+/// ```
+pub struct GodotCell<T: 'static> {
+    level: InitLevel,
+    init: fn() -> T,
+    value: RefCell<Option<T>>,
+}
+
+// SAFETY: the value is only accessed from the main thread, which every accessor checks before touching it. It is created, borrowed
+// and dropped there, so it is never shared with or sent to other threads.
+unsafe impl<T: 'static> Sync for GodotCell<T> {}
+
+impl<T: 'static> GodotCell<T> {
+    /// Creates a cell whose value is created by `init`, once `level` is loaded.
+    pub const fn new(level: InitLevel, init: fn() -> T) -> Self {
+        Self {
+            level,
+            init,
+            value: RefCell::new(None),
+        }
+    }
+
+    /// The level at which the value becomes available.
+    pub fn level(&self) -> InitLevel {
+        self.level
+    }
+
+    /// ⚠️ Borrows the value, creating it if necessary.
+    ///
+    /// # Panics
+    /// - If not called on the main thread.
+    /// - If [`level()`][Self::level] is not loaded.
+    /// - If the value is currently borrowed mutably.
+    pub fn borrow(&'static self) -> Ref<'static, T> {
+        self.ensure_initialized();
+
+        Ref::map(self.value.borrow(), |value| {
+            value.as_ref().expect("value initialized")
+        })
+    }
+
+    /// ⚠️ Borrows the value mutably, creating it if necessary.
+    ///
+    /// # Panics
+    /// - If not called on the main thread.
+    /// - If [`level()`][Self::level] is not loaded.
+    /// - If the value is currently borrowed.
+    pub fn borrow_mut(&'static self) -> RefMut<'static, T> {
+        self.ensure_initialized();
+
+        RefMut::map(self.value.borrow_mut(), |value| {
+            value.as_mut().expect("value initialized")
+        })
+    }
+
+    /// ⚠️ Whether the value has been created, and not torn down since.
+    ///
+    /// # Panics
+    /// If not called on the main thread.
+    pub fn is_initialized(&self) -> bool {
+        self.check_main_thread();
+        self.value.borrow().is_some()
+    }
+
+    fn ensure_initialized(&'static self) {
+        self.check_main_thread();
+        assert!(
+            is_level_loaded(self.level),
+            "GodotCell accessed before InitLevel::{:?} was loaded",
+            self.level
+        );
+
+        if self.value.borrow().is_some() {
+            return;
+        }
+
+        // Created before borrowing, so that `init` can access other cells (though not this one).
+        let value = (self.init)();
+        *self.value.borrow_mut() = Some(value);
+
+        registered_cells().push(self);
+    }
+
+    fn check_main_thread(&self) {
+        assert!(
+            is_main_thread(),
+            "GodotCell can only be accessed from the main thread"
+        );
+    }
+}
+
+/// Declares [`GodotCell`] statics, with the value expression evaluated on first access.
+///
+/// Values are available from [`InitLevel::Scene`] on, unless a different level is given after the value:
+/// ```no_run
+/// use godot::init::{main_thread_static, InitLevel};
+/// use std::collections::HashMap;
+///
+/// main_thread_static! {
+///     static NAMES: Vec<String> = Vec::new();
+///     pub(crate) static LOOKUP: HashMap<u32, String> = HashMap::new(), level = InitLevel::Servers;
+/// }
+/// ```
+#[macro_export]
+macro_rules! main_thread_static {
+    () => {};
+
+    ($(#[$attr:meta])* $vis:vis static $name:ident: $ty:ty = $init:expr, level = $level:expr; $($rest:tt)*) => {
+        $(#[$attr])*
+        $vis static $name: $crate::init::GodotCell<$ty> = $crate::init::GodotCell::new($level, || $init);
+
+        $crate::main_thread_static!($($rest)*);
+    };
+
+    ($(#[$attr:meta])* $vis:vis static $name:ident: $ty:ty = $init:expr; $($rest:tt)*) => {
+        $crate::main_thread_static!(
+            $(#[$attr])* $vis static $name: $ty = $init, level = $crate::init::InitLevel::Scene;
+            $($rest)*
+        );
+    };
+}
+
+/// Whether the calling thread is Godot's main thread, i.e. the one that loaded the extension.
+///
+/// Returns `false` before the first init level is loaded.
+pub fn is_main_thread() -> bool {
+    MAIN_THREAD.get() == Some(&thread::current().id())
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+static MAIN_THREAD: OnceLock<ThreadId> = OnceLock::new();
+
+/// Lowest and highest loaded level; `None` before the first level is loaded and after it is unloaded.
+static LOADED_LEVELS: Mutex<Option<(InitLevel, InitLevel)>> = Mutex::new(None);
+
+static CELLS: Mutex<Vec<&'static dyn TeardownCell>> = Mutex::new(Vec::new());
+
+/// Type-erased cell, to drop values of any type at deinit.
+trait TeardownCell: Sync {
+    fn level(&self) -> InitLevel;
+    fn teardown(&self);
+}
+
+impl<T: 'static> TeardownCell for GodotCell<T> {
+    fn level(&self) -> InitLevel {
+        self.level
+    }
+
+    fn teardown(&self) {
+        // Moved out before dropping, so that `Drop` impls of the value can still check other cells.
+        let value = self.value.borrow_mut().take();
+        drop(value);
+    }
+}
+
+fn registered_cells() -> std::sync::MutexGuard<'static, Vec<&'static dyn TeardownCell>> {
+    CELLS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn is_level_loaded(level: InitLevel) -> bool {
+    let loaded = LOADED_LEVELS.lock().unwrap();
+    loaded.is_some_and(|(_lowest, highest)| highest >= level)
+}
+
+/// Called before user code when a level is loaded.
+pub(super) fn on_level_init(level: InitLevel) {
+    MAIN_THREAD.get_or_init(|| thread::current().id());
+
+    let mut loaded = LOADED_LEVELS.lock().unwrap();
+    *loaded = Some(match *loaded {
+        Some((lowest, highest)) => (lowest.min(level), highest.max(level)),
+        None => (level, level),
+    });
+}
+
+/// Called after user code when a level is unloaded; drops the values of that level and above.
+///
+/// Levels below the extension's minimum level are never unloaded, so their values are dropped together with the lowest loaded level.
+pub(super) fn on_level_deinit(level: InitLevel) {
+    let remaining = {
+        let mut loaded = LOADED_LEVELS.lock().unwrap();
+        let remaining = loaded
+            .and_then(|(lowest, _highest)| (lowest < level).then(|| (lowest, level_below(level))));
+
+        *loaded = remaining;
+        remaining
+    };
+
+    let cells = {
+        let mut cells = registered_cells();
+        let (torn_down, kept) = cells
+            .drain(..)
+            .partition(|cell| remaining.is_none() || cell.level() >= level);
+        *cells = kept;
+        torn_down
+    };
+
+    // Dropped in reverse order of creation, without holding the lock.
+    for cell in cells.into_iter().rev() {
+        cell.teardown();
+    }
+}
+
+/// The level loaded before `level`; only called for levels above the lowest loaded one.
+fn level_below(level: InitLevel) -> InitLevel {
+    match level {
+        InitLevel::Core => unreachable!("no level below Core"),
+        InitLevel::Servers => InitLevel::Core,
+        InitLevel::Scene => InitLevel::Servers,
+        InitLevel::Editor => InitLevel::Scene,
+    }
+}
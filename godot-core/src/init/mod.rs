@@ -8,7 +8,19 @@ use godot_ffi as sys;
 
 use std::cell;
 
-pub use sys::GdextBuild;
+use crate::builtin::meta::ClassName;
+use crate::{log, out};
+
+mod godot_cell;
+mod startup_report;
+mod version;
+
+pub use crate::builder::{plugin_abi, DynamicClassBuilder, DynamicInstance};
+pub use crate::main_thread_static;
+pub use crate::registry::{register_user_class, registered_classes, RegisteredClass};
+pub use godot_cell::{is_main_thread, GodotCell};
+pub use sys::{GdextBuild, GodotAllocator};
+pub use version::{has_class, GodotVersion};
 
 #[doc(hidden)]
 // TODO consider body safe despite unsafe function, and explicitly mark unsafe {} locations
@@ -17,7 +29,13 @@ pub unsafe fn __gdext_load_library<E: ExtensionLibrary>(
     library: sys::GDExtensionClassLibraryPtr,
     init: *mut sys::GDExtensionInitialization,
 ) -> sys::GDExtensionBool {
+    // Side modules on the web don't run their static constructors on their own, so the plugin registry would stay empty.
+    #[cfg(target_family = "wasm")]
+    run_wasm_constructors();
+
     let init_code = || {
+        startup_report::on_library_load();
+
         let tool_only_in_editor = match E::editor_run_behavior() {
             EditorRunBehavior::ToolClassesOnly => true,
             EditorRunBehavior::AllClasses => false,
@@ -28,7 +46,21 @@ pub unsafe fn __gdext_load_library<E: ExtensionLibrary>(
             is_editor: cell::OnceCell::new(),
         };
 
-        sys::initialize(interface_or_get_proc_address, library, config);
+        // If the binary defines several entry points, only the first one initializes the binding; the others share it.
+        // Their library pointer is carried through `userdata`, so that each entry point registers its own classes.
+        if sys::is_initialized() {
+            out!("Additional GDExtension entry point; reusing binding.");
+
+            // The configuration is global, so the one of the first entry point applies to all of them.
+            if sys::config().tool_only_in_editor != config.tool_only_in_editor {
+                log::godot_warn!(
+                    "GDExtension entry points of this library declare different `editor_run_behavior()`; \
+                    using the one of the first loaded entry point"
+                );
+            }
+        } else {
+            sys::initialize(interface_or_get_proc_address, library, config);
+        }
 
         // Currently no way to express failure; could be exposed to E if necessary.
         // No early exit, unclear if Godot still requires output parameters to be set.
@@ -36,7 +68,7 @@ pub unsafe fn __gdext_load_library<E: ExtensionLibrary>(
 
         let godot_init_params = sys::GDExtensionInitialization {
             minimum_initialization_level: E::min_level().to_sys(),
-            userdata: std::ptr::null_mut(),
+            userdata: library as *mut std::ffi::c_void,
             initialize: Some(ffi_initialize_layer::<E>),
             deinitialize: Some(ffi_deinitialize_layer::<E>),
         };
@@ -52,60 +84,128 @@ pub unsafe fn __gdext_load_library<E: ExtensionLibrary>(
     is_success.unwrap_or(0)
 }
 
+#[cfg(target_family = "wasm")]
+fn run_wasm_constructors() {
+    extern "C" {
+        fn __wasm_call_ctors();
+    }
+
+    // Must happen exactly once, even if the binary defines multiple entry points.
+    static CTORS: std::sync::Once = std::sync::Once::new();
+
+    // SAFETY: `__wasm_call_ctors` is generated by the linker and only runs the module's `.init_array` functions.
+    CTORS.call_once(|| unsafe { __wasm_call_ctors() });
+    out!("Ran WebAssembly static constructors.");
+}
+
 unsafe extern "C" fn ffi_initialize_layer<E: ExtensionLibrary>(
-    _userdata: *mut std::ffi::c_void,
+    userdata: *mut std::ffi::c_void,
     init_level: sys::GDExtensionInitializationLevel,
 ) {
     let level = InitLevel::from_sys(init_level);
     let ctx = || format!("failed to initialize GDExtension level `{:?}`", level);
+    let library = userdata as sys::GDExtensionClassLibraryPtr;
 
     // Swallow panics. TODO consider crashing if gdext init fails.
-    let _ = crate::private::handle_panic(ctx, || {
-        gdext_on_level_init(level);
-        E::on_level_init(level);
+    sys::with_active_library(library, || {
+        let _ = crate::private::handle_panic(ctx, || {
+            startup_report::time_level_init(
+                level,
+                || gdext_on_level_init(level, E::registers_class),
+                || E::on_level_init(level),
+            );
+        });
     });
 }
 
 unsafe extern "C" fn ffi_deinitialize_layer<E: ExtensionLibrary>(
-    _userdata: *mut std::ffi::c_void,
+    userdata: *mut std::ffi::c_void,
     init_level: sys::GDExtensionInitializationLevel,
 ) {
     let level = InitLevel::from_sys(init_level);
     let ctx = || format!("failed to deinitialize GDExtension level `{:?}`", level);
+    let library = userdata as sys::GDExtensionClassLibraryPtr;
 
     // Swallow panics.
-    let _ = crate::private::handle_panic(ctx, || {
-        E::on_level_deinit(level);
-        gdext_on_level_deinit(level);
+    sys::with_active_library(library, || {
+        let _ = crate::private::handle_panic(ctx, || {
+            E::on_level_deinit(level);
+            gdext_on_level_deinit(level);
+        });
     });
 }
 
 /// Tasks needed to be done by gdext internally upon loading an initialization level. Called before user code.
-fn gdext_on_level_init(level: InitLevel) {
-    // SAFETY: we are in the main thread, during initialization, no other logic is happening.
-    // TODO: in theory, a user could start a thread in one of the early levels, and run concurrent code that messes with the global state
-    // (e.g. class registration). This would break the assumption that the load_class_method_table() calls are exclusive.
-    // We could maybe protect globals with a mutex until initialization is complete, and then move it to a directly-accessible, read-only static.
-    unsafe {
-        match level {
-            InitLevel::Core => {}
-            InitLevel::Servers => {
-                sys::load_class_method_table(sys::ClassApiLevel::Server);
-            }
-            InitLevel::Scene => {
-                sys::load_class_method_table(sys::ClassApiLevel::Scene);
-            }
-            InitLevel::Editor => {
-                sys::load_class_method_table(sys::ClassApiLevel::Editor);
+fn gdext_on_level_init(level: InitLevel, accepts_class: fn(ClassName) -> bool) {
+    // Global state is set up by the first entry point loading the level; classes are registered by each of them.
+    if entry_points::on_level_init(level) {
+        godot_cell::on_level_init(level);
+
+        // SAFETY: we are in the main thread, during initialization, no other logic is happening.
+        // TODO: in theory, a user could start a thread in one of the early levels, and run concurrent code that messes with the global state
+        // (e.g. class registration). This would break the assumption that the load_class_method_table() calls are exclusive.
+        // We could maybe protect globals with a mutex until initialization is complete, and then move it to a directly-accessible, read-only static.
+        unsafe {
+            match level {
+                InitLevel::Core => {}
+                InitLevel::Servers => {
+                    sys::load_class_method_table(sys::ClassApiLevel::Server);
+                }
+                InitLevel::Scene => {
+                    sys::load_class_method_table(sys::ClassApiLevel::Scene);
+                }
+                InitLevel::Editor => {
+                    sys::load_class_method_table(sys::ClassApiLevel::Editor);
+                }
             }
         }
-        crate::auto_register_classes(level);
     }
+
+    crate::auto_register_classes(level, accepts_class);
 }
 
 /// Tasks needed to be done by gdext internally upon unloading an initialization level. Called after user code.
 fn gdext_on_level_deinit(level: InitLevel) {
+    // Global state is torn down by the last entry point unloading the level, since the others may still use it.
+    let is_last = entry_points::on_level_deinit(level);
+
+    if is_last {
+        godot_cell::on_level_deinit(level);
+    }
+
+    // Only unregisters the classes of the entry point being unloaded.
     crate::unregister_classes(level);
+
+    if is_last && level == InitLevel::Core {
+        // Cached names must be released while the interface is still alive.
+        crate::builtin::clear_string_name_cache();
+    }
+}
+
+/// Bookkeeping of how many entry points of the binary have loaded each level.
+mod entry_points {
+    use super::InitLevel;
+    use std::sync::Mutex;
+
+    /// Number of entry points that loaded each level, indexed by `InitLevel as usize`.
+    static LOADED: Mutex<[usize; 4]> = Mutex::new([0; 4]);
+
+    /// Counts an entry point loading `level`; returns whether it is the first one.
+    pub fn on_level_init(level: InitLevel) -> bool {
+        let mut loaded = LOADED.lock().unwrap();
+        loaded[level as usize] += 1;
+
+        loaded[level as usize] == 1
+    }
+
+    /// Counts an entry point unloading `level`; returns whether it was the last one.
+    pub fn on_level_deinit(level: InitLevel) -> bool {
+        let mut loaded = LOADED.lock().unwrap();
+        let count = &mut loaded[level as usize];
+        *count = count.saturating_sub(1);
+
+        *count == 0
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -153,6 +253,18 @@ pub unsafe trait ExtensionLibrary {
         InitLevel::Scene
     }
 
+    /// Whether this extension registers the class named `class_name`.
+    ///
+    /// This is only relevant if a binary defines several `#[gdextension]` entry points (with distinct `entry_symbol`s), which Godot
+    /// loads as separate extensions. Every class is registered at most once: by the first loaded extension that accepts it.
+    /// By default, all classes are accepted.
+    ///
+    /// The entry points share the binding: it is configured by the first one loaded (a differing
+    /// [`editor_run_behavior()`][Self::editor_run_behavior] causes a warning), and torn down when the last one is unloaded.
+    fn registers_class(_class_name: ClassName) -> bool {
+        true
+    }
+
     /// Custom logic when a certain init-level of Godot is loaded.
     ///
     /// This will only be invoked for levels >= [`Self::min_level()`], in ascending order. Use `if` or `match` to hook to specific levels.
@@ -0,0 +1,59 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Per-level timing of the extension's initialization, logged if the `GDEXT_STARTUP_REPORT` environment variable is set.
+
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+use crate::init::InitLevel;
+
+const REPORT_ENV_VAR: &str = "GDEXT_STARTUP_REPORT";
+
+/// Set when the library is loaded; `None` if the report is disabled.
+static LOAD_INSTANT: OnceLock<Option<Instant>> = OnceLock::new();
+
+/// Called when Godot loads the library, before any level is initialized.
+pub(crate) fn on_library_load() {
+    LOAD_INSTANT.get_or_init(|| std::env::var_os(REPORT_ENV_VAR).map(|_| Instant::now()));
+}
+
+/// Runs the initialization of `level`, split into gdext's part (method tables, class registration) and the user's part.
+pub(crate) fn time_level_init(
+    level: InitLevel,
+    gdext_part: impl FnOnce(),
+    user_part: impl FnOnce(),
+) {
+    let Some(load_instant) = LOAD_INSTANT.get().copied().flatten() else {
+        gdext_part();
+        user_part();
+        return;
+    };
+
+    let begin = Instant::now();
+    gdext_part();
+    let gdext = begin.elapsed();
+
+    let begin = Instant::now();
+    user_part();
+    let user = begin.elapsed();
+
+    crate::log::godot_print!(
+        "[gdext] init level {:?}: {} (gdext {}, on_level_init {}); {} since library load",
+        level,
+        ms(gdext + user),
+        ms(gdext),
+        ms(user),
+        ms(load_instant.elapsed()),
+    );
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+fn ms(duration: Duration) -> String {
+    format!("{:.2} ms", duration.as_secs_f64() * 1000.0)
+}
@@ -0,0 +1,163 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+
+use std::fmt;
+use std::sync::OnceLock;
+
+use crate::builtin::StringName;
+
+/// Version of the Godot binary the extension runs in.
+///
+/// Obtained from the GDExtension interface and parsed once, so it is available at every [`InitLevel`][super::InitLevel], including
+/// `Core`, where engine singletons such as `Engine` cannot be used yet. For the version gdext was compiled against, see
+/// [`GdextBuild`][super::GdextBuild].
+///
+/// # Example
+/// ```no_run
+/// use godot::init::GodotVersion;
+///
+/// let version = GodotVersion::current();
+/// if version.is_at_least(4, 2) {
+///     // Use 4.2 features.
+/// }
+/// println!("running on {version}"); // e.g. "4.2.1.stable.official.b09f793f5"
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct GodotVersion {
+    pub major: u8,
+    pub minor: u8,
+    pub patch: u8,
+
+    /// Release status, such as `stable`, `rc2` or `dev`.
+    pub status: String,
+
+    /// Name of the build, `official` for binaries from godotengine.org and `custom_build` by default for self-compiled engines.
+    pub build: String,
+
+    /// Abbreviated commit hash of the engine source, if known.
+    pub hash: Option<String>,
+}
+
+impl GodotVersion {
+    /// ⚠️ The version of the running Godot binary.
+    ///
+    /// # Panics
+    /// If called before the library has been initialized by Godot.
+    pub fn current() -> &'static GodotVersion {
+        static CURRENT: OnceLock<GodotVersion> = OnceLock::new();
+
+        CURRENT.get_or_init(|| {
+            let (major, minor, patch) = super::GdextBuild::godot_runtime_version_triple();
+            let full_name = super::GdextBuild::godot_runtime_version_string();
+
+            Self::parse(major, minor, patch, &full_name)
+        })
+    }
+
+    /// The version as `(major, minor, patch)` triple, which can be compared with `<` and `>`.
+    pub fn triple(&self) -> (u8, u8, u8) {
+        (self.major, self.minor, self.patch)
+    }
+
+    /// Whether this version is `major.minor` or newer.
+    pub fn is_at_least(&self, major: u8, minor: u8) -> bool {
+        (self.major, self.minor) >= (major, minor)
+    }
+
+    /// Whether the binary is a stable release.
+    pub fn is_stable(&self) -> bool {
+        self.status == "stable"
+    }
+
+    /// Builds the version from the numbers and the full name reported by Godot, e.g. `Godot Engine v4.2.stable.official.46dc27791`.
+    ///
+    /// The full name only lists the patch number if it is non-zero, so the numbers are taken from the interface instead.
+    fn parse(major: u8, minor: u8, patch: u8, full_name: &str) -> Self {
+        let name = full_name.rsplit(' ').next().unwrap_or(full_name);
+        let name = name.strip_prefix('v').unwrap_or(name);
+
+        let mut labels = name
+            .split('.')
+            .skip_while(|part| part.parse::<u32>().is_ok());
+
+        let status = labels.next().unwrap_or_default().to_string();
+        let build = labels.next().unwrap_or_default().to_string();
+        let hash = labels.next().map(str::to_string);
+
+        Self {
+            major,
+            minor,
+            patch,
+            status,
+            build,
+            hash,
+        }
+    }
+}
+
+impl fmt::Display for GodotVersion {
+    /// Formats like Godot's `Engine.get_version_info()["string"]`, followed by the hash: `4.2.1.stable.official.b09f793f5`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)?;
+        if self.patch != 0 {
+            write!(f, ".{}", self.patch)?;
+        }
+
+        write!(f, ".{}.{}", self.status, self.build)?;
+        if let Some(hash) = &self.hash {
+            write!(f, ".{hash}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether a class called `class_name` is registered in Godot, e.g. to check for optional modules such as `NavigationServer3D`.
+///
+/// Engine classes are registered level by level, so during [`InitLevel::Core`][super::InitLevel::Core], server and scene classes do
+/// not exist yet. The check does not need any engine singleton and can be used at every level. Classes of other extensions count as
+/// well, once their library has registered them.
+pub fn has_class(class_name: &str) -> bool {
+    let class_name = StringName::from(class_name);
+
+    // SAFETY: the class name is a valid StringName; Godot returns null for unknown classes.
+    let tag = unsafe { sys::interface_fn!(classdb_get_class_tag)(class_name.string_sys()) };
+
+    !tag.is_null()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::GodotVersion;
+
+    #[test]
+    fn parse_official_release() {
+        let version = GodotVersion::parse(4, 2, 1, "Godot Engine v4.2.1.stable.official.b09f793f5");
+
+        assert_eq!(version.status, "stable");
+        assert_eq!(version.build, "official");
+        assert_eq!(version.hash.as_deref(), Some("b09f793f5"));
+        assert_eq!(version.to_string(), "4.2.1.stable.official.b09f793f5");
+    }
+
+    #[test]
+    fn parse_without_patch_and_hash() {
+        let version = GodotVersion::parse(4, 1, 0, "Godot Engine v4.1.rc2.custom_build");
+
+        assert_eq!(version.triple(), (4, 1, 0));
+        assert_eq!(version.status, "rc2");
+        assert_eq!(version.build, "custom_build");
+        assert_eq!(version.hash, None);
+        assert_eq!(version.to_string(), "4.1.rc2.custom_build");
+        assert!(!version.is_stable());
+        assert!(version.is_at_least(4, 1));
+        assert!(!version.is_at_least(4, 2));
+    }
+}
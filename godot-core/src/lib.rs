@@ -67,6 +67,44 @@ pub mod private {
 
     pub struct ClassConfig {
         pub is_tool: bool,
+        pub rename_all: RenameAll,
+    }
+
+    /// Naming convention for registered methods, properties and signals, set with `#[class(rename_all = "...")]`.
+    #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+    pub enum RenameAll {
+        /// Rust names are used unchanged.
+        SnakeCase,
+        CamelCase,
+        PascalCase,
+    }
+
+    /// Converts the snake_case `rust_name` according to `convention`.
+    ///
+    /// Leading underscores are kept, so `_private_helper` becomes `_privateHelper` in camelCase.
+    pub fn apply_rename_all(convention: RenameAll, rust_name: &str) -> String {
+        let capitalize_first = match convention {
+            RenameAll::SnakeCase => return rust_name.to_string(),
+            RenameAll::CamelCase => false,
+            RenameAll::PascalCase => true,
+        };
+
+        let body = rust_name.trim_start_matches('_');
+        let mut result = rust_name[..rust_name.len() - body.len()].to_string();
+
+        for (i, word) in body.split('_').filter(|word| !word.is_empty()).enumerate() {
+            let mut chars = word.chars();
+            let first = chars.next().unwrap(); // unwrap: empty words are filtered out.
+
+            if i > 0 || capitalize_first {
+                result.extend(first.to_uppercase());
+            } else {
+                result.push(first);
+            }
+            result.push_str(chars.as_str());
+        }
+
+        result
     }
 
     pub fn is_class_inactive(is_tool: bool) -> bool {
@@ -0,0 +1,259 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::engine;
+use crate::obj::{Gd, GodotClass, InstanceId};
+
+/// Map from objects to values, which forgets objects once they are destroyed.
+///
+/// Entries are keyed by the object's [`InstanceId`]; the map holds no reference to the object, so it does not keep `RefCounted`
+/// objects alive, and freed nodes do not leave dangling keys behind. Lookups and iteration only see objects that are still alive.
+/// The entries of dead objects are removed (and their values dropped) on [`purge()`][Self::purge], and from time to time on insertion.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::obj::GdMap;
+///
+/// let mut health = GdMap::<Node, i32>::new();
+///
+/// let enemy = Node::new_alloc();
+/// health.insert(&enemy, 100);
+/// assert_eq!(health.get(&enemy), Some(&100));
+///
+/// enemy.free();
+/// assert!(health.is_empty());
+/// ```
+pub struct GdMap<T: GodotClass, V> {
+    entries: HashMap<InstanceId, V>,
+    purge_at: usize,
+    _class: PhantomData<fn() -> T>,
+}
+
+impl<T: GodotClass, V> GdMap<T, V> {
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+            purge_at: MIN_PURGE_LEN,
+            _class: PhantomData,
+        }
+    }
+
+    /// ⚠️ Associates `value` with `object`, returning the previous value.
+    ///
+    /// # Panics
+    /// If `object` is dead.
+    pub fn insert(&mut self, object: &Gd<T>, value: V) -> Option<V> {
+        let id = object.instance_id();
+
+        // Amortized: purging happens only after the map has doubled in size since the last purge.
+        if self.entries.len() >= self.purge_at {
+            self.purge();
+            self.purge_at = (self.entries.len() * 2).max(MIN_PURGE_LEN);
+        }
+
+        self.entries.insert(id, value)
+    }
+
+    /// The value associated with `object`; `None` if there is none, or `object` is dead.
+    pub fn get(&self, object: &Gd<T>) -> Option<&V> {
+        self.entries.get(&object.instance_id_or_none()?)
+    }
+
+    /// The value associated with `object`, mutably; `None` if there is none, or `object` is dead.
+    pub fn get_mut(&mut self, object: &Gd<T>) -> Option<&mut V> {
+        self.entries.get_mut(&object.instance_id_or_none()?)
+    }
+
+    /// Removes the entry of `object`, returning its value.
+    ///
+    /// If `object` is dead, its entry is left for the next purge and `None` is returned.
+    pub fn remove(&mut self, object: &Gd<T>) -> Option<V> {
+        self.entries.remove(&object.instance_id_or_none()?)
+    }
+
+    pub fn contains_key(&self, object: &Gd<T>) -> bool {
+        self.get(object).is_some()
+    }
+
+    /// Number of entries whose objects are alive.
+    ///
+    /// This checks every entry; when counting repeatedly, [`purge()`][Self::purge] first and use [`len_unpurged()`][Self::len_unpurged].
+    pub fn len(&self) -> usize {
+        self.entries.keys().filter(|&&id| is_alive(id)).count()
+    }
+
+    /// Number of entries, including those of objects that died since the last purge.
+    pub fn len_unpurged(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.entries.keys().any(|&id| is_alive(id))
+    }
+
+    /// Objects and values of all entries whose objects are alive, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (Gd<T>, &V)> + '_ {
+        self.entries
+            .iter()
+            .filter_map(|(&id, value)| Some((Gd::try_from_instance_id(id)?, value)))
+    }
+
+    /// Objects and mutable values of all entries whose objects are alive, in arbitrary order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Gd<T>, &mut V)> + '_ {
+        self.entries
+            .iter_mut()
+            .filter_map(|(&id, value)| Some((Gd::try_from_instance_id(id)?, value)))
+    }
+
+    /// All objects that are alive, in arbitrary order.
+    pub fn keys(&self) -> impl Iterator<Item = Gd<T>> + '_ {
+        self.iter().map(|(object, _)| object)
+    }
+
+    /// Keeps only the entries for which `f` returns `true`; entries of dead objects are removed without calling `f`.
+    pub fn retain(&mut self, mut f: impl FnMut(Gd<T>, &mut V) -> bool) {
+        self.entries
+            .retain(|&id, value| match Gd::try_from_instance_id(id) {
+                Some(object) => f(object, value),
+                None => false,
+            });
+    }
+
+    /// Removes the entries of all dead objects, returning how many there were.
+    pub fn purge(&mut self) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|&id, _| is_alive(id));
+
+        before - self.entries.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl<T: GodotClass, V> Default for GdMap<T, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: GodotClass, V: fmt::Debug> fmt::Debug for GdMap<T, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.entries.iter().filter(|(id, _)| is_alive(**id)))
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Set of objects, which forgets objects once they are destroyed.
+///
+/// Like [`GdMap`], the set stores instance IDs and does not keep its objects alive. Dead objects are never reported as
+/// contained, and are removed on [`purge()`][Self::purge] and from time to time on insertion.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::obj::GdSet;
+///
+/// let mut selected = GdSet::<Node>::new();
+///
+/// let node = Node::new_alloc();
+/// assert!(selected.insert(&node));
+/// assert!(selected.contains(&node));
+///
+/// node.free();
+/// assert_eq!(selected.iter().count(), 0);
+/// ```
+pub struct GdSet<T: GodotClass> {
+    map: GdMap<T, ()>,
+}
+
+impl<T: GodotClass> GdSet<T> {
+    pub fn new() -> Self {
+        Self { map: GdMap::new() }
+    }
+
+    /// ⚠️ Adds `object`, returning whether it was not in the set yet.
+    ///
+    /// # Panics
+    /// If `object` is dead.
+    pub fn insert(&mut self, object: &Gd<T>) -> bool {
+        self.map.insert(object, ()).is_none()
+    }
+
+    /// Removes `object`, returning whether it was in the set.
+    pub fn remove(&mut self, object: &Gd<T>) -> bool {
+        self.map.remove(object).is_some()
+    }
+
+    pub fn contains(&self, object: &Gd<T>) -> bool {
+        self.map.contains_key(object)
+    }
+
+    /// Number of objects that are alive; see [`GdMap::len()`].
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Number of objects, including those that died since the last purge.
+    pub fn len_unpurged(&self) -> usize {
+        self.map.len_unpurged()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// All objects that are alive, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = Gd<T>> + '_ {
+        self.map.keys()
+    }
+
+    /// Keeps only the objects for which `f` returns `true`; dead objects are removed without calling `f`.
+    pub fn retain(&mut self, mut f: impl FnMut(Gd<T>) -> bool) {
+        self.map.retain(|object, _| f(object));
+    }
+
+    /// Removes all dead objects, returning how many there were.
+    pub fn purge(&mut self) -> usize {
+        self.map.purge()
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+}
+
+impl<T: GodotClass> Default for GdSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: GodotClass> fmt::Debug for GdSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries(self.map.entries.keys().filter(|&&id| is_alive(id)))
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+/// Smallest map size at which insertion purges dead entries.
+const MIN_PURGE_LEN: usize = 16;
+
+fn is_alive(id: InstanceId) -> bool {
+    !engine::object_ptr_from_id(id).is_null()
+}
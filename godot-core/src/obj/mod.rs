@@ -12,16 +12,22 @@
 
 mod base;
 mod gd;
+mod gd_map;
 mod guards;
 mod instance_id;
+mod ownership;
 mod raw;
+mod rust_handle;
 mod traits;
 
 pub use base::*;
 pub use gd::*;
+pub use gd_map::*;
 pub use guards::*;
 pub use instance_id::*;
+pub use ownership::*;
 pub use raw::*;
+pub use rust_handle::*;
 pub use traits::*;
 
 type GdDerefTarget<T> = <<T as GodotClass>::Declarer as dom::Domain>::DerefTarget<T>;
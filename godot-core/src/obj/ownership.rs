@@ -0,0 +1,352 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::sync::Mutex;
+
+use crate::engine::{Object, RefCounted};
+use crate::obj::{Gd, GodotClass, InstanceId};
+
+/// Debug record of which objects hold references to which other objects.
+///
+/// A `RefCounted` object is only freed once no references to it remain. If two objects reference each other (directly, or through a
+/// longer chain), neither is ever freed, and everything they reference stays alive as well. Such cycles are hard to spot from the
+/// code alone: the graph makes them visible.
+///
+/// Godot cannot tell which object a `Gd` is stored in, so edges are recorded explicitly, usually where a reference is assigned to a
+/// field. Call [`record()`][Self::record] when an object starts to hold a reference, and [`forget()`][Self::forget] when it lets go.
+/// The graph is then available as [DOT](https://graphviz.org/doc/info/lang.html) source with [`to_dot()`][Self::to_dot], and cycles of
+/// live `RefCounted` objects are listed by [`refcounted_cycles()`][Self::refcounted_cycles].
+///
+/// Recording only happens in debug builds. In release builds, all functions are no-ops and the graph stays empty.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::obj::OwnershipGraph;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=RefCounted)]
+/// struct Inventory {
+///     owner: Option<Gd<RefCounted>>,
+///     #[base]
+///     base: Base<RefCounted>,
+/// }
+///
+/// #[godot_api]
+/// impl Inventory {
+///     #[func]
+///     fn set_owner(&mut self, owner: Gd<RefCounted>) {
+///         OwnershipGraph::record(&self.base, &owner, "owner");
+///         self.owner = Some(owner);
+///     }
+/// }
+///
+/// // Later, e.g. from a debug console command:
+/// for cycle in OwnershipGraph::refcounted_cycles() {
+///     godot_warn!("reference cycle: {cycle:?}");
+/// }
+/// std::fs::write("ownership.dot", OwnershipGraph::to_dot()).unwrap();
+/// ```
+pub struct OwnershipGraph {
+    _private: (),
+}
+
+impl OwnershipGraph {
+    /// Records that `owner` holds a reference to `owned`, under the name `label` (e.g. the field name).
+    ///
+    /// Recording the same pair again replaces the label.
+    pub fn record<A, B>(owner: &Gd<A>, owned: &Gd<B>, label: &str)
+    where
+        A: GodotClass,
+        B: GodotClass,
+    {
+        if cfg!(debug_assertions) {
+            let owner = object_node(owner);
+            let owned = object_node(owned);
+
+            with_graph(|graph| graph.insert(owner, owned, label));
+        }
+    }
+
+    /// Removes the edge from `owner` to `owned`, if recorded.
+    pub fn forget<A, B>(owner: &Gd<A>, owned: &Gd<B>)
+    where
+        A: GodotClass,
+        B: GodotClass,
+    {
+        if cfg!(debug_assertions) {
+            let owner = owner.instance_id_unchecked();
+            let owned = owned.instance_id_unchecked();
+
+            with_graph(|graph| graph.remove(owner, owned));
+        }
+    }
+
+    /// Removes all edges from and to `id`, e.g. after the object was freed.
+    pub fn forget_object(id: InstanceId) {
+        if cfg!(debug_assertions) {
+            with_graph(|graph| graph.remove_node(id));
+        }
+    }
+
+    /// Removes all recorded edges.
+    pub fn clear() {
+        with_graph(|graph| *graph = Graph::default());
+    }
+
+    /// The recorded graph in DOT format, for rendering with Graphviz or a compatible viewer.
+    ///
+    /// Each object is labeled with its class and instance ID. `RefCounted` objects also show their current reference count, and
+    /// objects that have been freed in the meantime are drawn dashed.
+    pub fn to_dot() -> String {
+        with_graph(|graph| graph.to_dot(&object_status))
+    }
+
+    /// Groups of live `RefCounted` objects that (transitively) reference each other, according to the recorded edges.
+    ///
+    /// Each group is a strongly connected component of the graph; none of its objects can be freed before the cycle is broken.
+    pub fn refcounted_cycles() -> Vec<Vec<InstanceId>> {
+        with_graph(|graph| {
+            graph.cycles(|id| {
+                id.is_ref_counted() && !matches!(object_status(id), ObjectStatus::Freed)
+            })
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+static GRAPH: Mutex<Option<Graph>> = Mutex::new(None);
+
+fn with_graph<R>(f: impl FnOnce(&mut Graph) -> R) -> R {
+    let mut guard = GRAPH.lock().unwrap();
+    f(guard.get_or_insert_with(Graph::default))
+}
+
+/// Instance ID and dynamic class name, determined when the edge is recorded.
+type Node = (InstanceId, String);
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum ObjectStatus {
+    Alive,
+    RefCounted { references: i32 },
+    Freed,
+}
+
+fn object_node<T: GodotClass>(gd: &Gd<T>) -> Node {
+    let id = gd.instance_id_unchecked();
+    let class_name = Gd::<Object>::try_from_instance_id(id)
+        .map(|object| object.get_class().to_string())
+        .unwrap_or_else(|| T::class_name().to_string());
+
+    (id, class_name)
+}
+
+fn object_status(id: InstanceId) -> ObjectStatus {
+    let Some(object) = Gd::<Object>::try_from_instance_id(id) else {
+        return ObjectStatus::Freed;
+    };
+
+    match object.try_cast::<RefCounted>() {
+        // The reference held by `refcounted` itself is not counted.
+        Some(refcounted) => ObjectStatus::RefCounted {
+            references: refcounted.get_reference_count() - 1,
+        },
+        None => ObjectStatus::Alive,
+    }
+}
+
+#[derive(Default)]
+struct Graph {
+    class_names: BTreeMap<InstanceId, String>,
+    edges: BTreeMap<(InstanceId, InstanceId), String>,
+}
+
+impl Graph {
+    fn insert(&mut self, (owner, owner_class): Node, (owned, owned_class): Node, label: &str) {
+        self.class_names.insert(owner, owner_class);
+        self.class_names.insert(owned, owned_class);
+        self.edges.insert((owner, owned), label.to_string());
+    }
+
+    fn remove(&mut self, owner: InstanceId, owned: InstanceId) {
+        self.edges.remove(&(owner, owned));
+        self.remove_unconnected();
+    }
+
+    fn remove_node(&mut self, id: InstanceId) {
+        self.edges
+            .retain(|&(owner, owned), _| owner != id && owned != id);
+        self.remove_unconnected();
+    }
+
+    fn remove_unconnected(&mut self) {
+        let connected: BTreeSet<InstanceId> = self
+            .edges
+            .keys()
+            .flat_map(|&(owner, owned)| [owner, owned])
+            .collect();
+
+        self.class_names.retain(|id, _| connected.contains(id));
+    }
+
+    fn to_dot(&self, status: &dyn Fn(InstanceId) -> ObjectStatus) -> String {
+        let mut dot = String::from("digraph ownership {\n    node [shape=box];\n");
+
+        for (id, class_name) in &self.class_names {
+            let (details, style) = match status(*id) {
+                ObjectStatus::Alive => (String::new(), ""),
+                ObjectStatus::RefCounted { references } => (format!("\\nrefs: {references}"), ""),
+                ObjectStatus::Freed => ("\\nfreed".to_string(), ", style=dashed"),
+            };
+
+            let _ = writeln!(
+                dot,
+                "    \"{id}\" [label=\"{class_name} #{id}{details}\"{style}];"
+            );
+        }
+
+        for ((owner, owned), label) in &self.edges {
+            let label = label.replace('"', "\\\"");
+            let _ = writeln!(dot, "    \"{owner}\" -> \"{owned}\" [label=\"{label}\"];");
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Strongly connected components with more than one node (or a node referencing itself), among the nodes accepted by `include`.
+    fn cycles(&self, include: impl Fn(InstanceId) -> bool) -> Vec<Vec<InstanceId>> {
+        let nodes: Vec<InstanceId> = self
+            .class_names
+            .keys()
+            .copied()
+            .filter(|&id| include(id))
+            .collect();
+
+        let mut successors: BTreeMap<InstanceId, Vec<InstanceId>> = BTreeMap::new();
+        for &(owner, owned) in self.edges.keys() {
+            if nodes.contains(&owner) && nodes.contains(&owned) {
+                successors.entry(owner).or_default().push(owned);
+            }
+        }
+
+        // Tarjan's algorithm; the graphs are small enough for recursion.
+        #[derive(Default)]
+        struct State {
+            index: BTreeMap<InstanceId, usize>,
+            lowlink: BTreeMap<InstanceId, usize>,
+            stack: Vec<InstanceId>,
+            components: Vec<Vec<InstanceId>>,
+        }
+
+        fn visit(
+            id: InstanceId,
+            successors: &BTreeMap<InstanceId, Vec<InstanceId>>,
+            state: &mut State,
+        ) {
+            let index = state.index.len();
+            state.index.insert(id, index);
+            state.lowlink.insert(id, index);
+            state.stack.push(id);
+
+            for &next in successors.get(&id).into_iter().flatten() {
+                let low = if !state.index.contains_key(&next) {
+                    visit(next, successors, state);
+                    state.lowlink[&next]
+                } else if state.stack.contains(&next) {
+                    state.index[&next]
+                } else {
+                    continue;
+                };
+
+                let own_low = state.lowlink[&id];
+                state.lowlink.insert(id, own_low.min(low));
+            }
+
+            if state.lowlink[&id] == state.index[&id] {
+                let position = state.stack.iter().rposition(|&n| n == id).unwrap();
+                let mut component = state.stack.split_off(position);
+                component.sort();
+                state.components.push(component);
+            }
+        }
+
+        let mut state = State::default();
+        for &id in &nodes {
+            if !state.index.contains_key(&id) {
+                visit(id, &successors, &mut state);
+            }
+        }
+
+        state
+            .components
+            .into_iter()
+            .filter(|component| {
+                component.len() > 1 || self.edges.contains_key(&(component[0], component[0]))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(id: i64) -> Node {
+        (InstanceId::from_i64(id), format!("Class{id}"))
+    }
+
+    fn ids(ids: &[i64]) -> Vec<InstanceId> {
+        ids.iter().map(|&id| InstanceId::from_i64(id)).collect()
+    }
+
+    #[test]
+    fn graph_cycles() {
+        let mut graph = Graph::default();
+        graph.insert(node(1), node(2), "a");
+        graph.insert(node(2), node(3), "b");
+        graph.insert(node(3), node(1), "c");
+        graph.insert(node(3), node(4), "d");
+        graph.insert(node(5), node(5), "self");
+
+        assert_eq!(graph.cycles(|_| true), vec![ids(&[1, 2, 3]), ids(&[5])]);
+
+        // Excluded nodes break the cycle.
+        let without_2 = graph.cycles(|id| id != InstanceId::from_i64(2));
+        assert_eq!(without_2, vec![ids(&[5])]);
+
+        graph.remove(InstanceId::from_i64(3), InstanceId::from_i64(1));
+        assert_eq!(graph.cycles(|_| true), vec![ids(&[5])]);
+    }
+
+    #[test]
+    fn graph_to_dot() {
+        let mut graph = Graph::default();
+        graph.insert(node(1), node(2), "child \"x\"");
+        graph.insert(node(2), node(3), "owner");
+        graph.remove_node(InstanceId::from_i64(3));
+
+        let dot = graph.to_dot(&|id| {
+            if id == InstanceId::from_i64(1) {
+                ObjectStatus::RefCounted { references: 2 }
+            } else {
+                ObjectStatus::Freed
+            }
+        });
+
+        assert_eq!(
+            dot,
+            "digraph ownership {\n    node [shape=box];\n    \
+            \"1\" [label=\"Class1 #1\\nrefs: 2\"];\n    \
+            \"2\" [label=\"Class2 #2\\nfreed\", style=dashed];\n    \
+            \"1\" -> \"2\" [label=\"child \\\"x\\\"\"];\n}\n"
+        );
+    }
+}
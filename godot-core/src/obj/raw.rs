@@ -300,7 +300,7 @@ where
         }
 
         let callbacks = crate::storage::nop_instance_callbacks();
-        let token = sys::get_library() as *mut std::ffi::c_void;
+        let token = sys::get_instance_binding_token();
         let binding = interface_fn!(object_get_instance_binding)(self.obj_sys(), token, &callbacks);
 
         debug_assert!(
@@ -0,0 +1,236 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::any::Any;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+
+use godot_ffi as sys;
+
+use crate::builtin::meta::{ClassName, FromGodot, GodotConvert, ToGodot};
+use crate::engine::{Object, RefCounted};
+use crate::init::InitLevel;
+use crate::obj::{dom, Gd, GdMut, GdRef, GodotClass, Inherits};
+use crate::private::{callbacks, ClassPlugin, PluginComponent};
+
+/// Arbitrary Rust value, passed through Godot as an opaque `RefCounted` object.
+///
+/// Rust types that are not Godot classes cannot be stored in a `Variant`, so they cannot be handed to GDScript directly, e.g. to a
+/// callback that later passes them back to Rust. Wrapping the value in a `RustHandle` gives it an object identity: in GDScript it is
+/// a `RustHandle` object without any methods or properties, which can be stored and passed around like any other reference.
+///
+/// The value lives as long as the object. Reference counting includes GDScript's references, so the value is dropped once neither
+/// Rust nor GDScript refers to it anymore.
+///
+/// Converting back is checked: a `RustHandle<T>` parameter of a `#[func]` only accepts handles that were created with a value of
+/// type `T`; anything else fails like any other argument of the wrong type.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::obj::RustHandle;
+///
+/// struct Inventory {
+///     items: Vec<String>,
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Shop {}
+///
+/// #[godot_api]
+/// impl Shop {
+///     #[func]
+///     fn open_inventory(&self) -> RustHandle<Inventory> {
+///         RustHandle::new(Inventory { items: vec!["sword".into()] })
+///     }
+///
+///     // GDScript: `shop.item_count(shop.open_inventory())`
+///     #[func]
+///     fn item_count(&self, inventory: RustHandle<Inventory>) -> i64 {
+///         inventory.bind().items.len() as i64
+///     }
+/// }
+/// ```
+///
+/// The value is borrowed with the same runtime checks as [`Gd::bind()`] and [`Gd::bind_mut()`].
+pub struct RustHandle<T> {
+    object: Gd<RustHandleObject>,
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<T: 'static> RustHandle<T> {
+    /// Moves `value` into a new handle object.
+    pub fn new(value: T) -> Self {
+        let object = Gd::new(RustHandleObject {
+            value: Box::new(value),
+        });
+
+        Self {
+            object,
+            _value: PhantomData,
+        }
+    }
+
+    /// Recovers the handle from its object; `None` if `object` is not a handle, or holds a value of another type.
+    pub fn try_from_object(object: Gd<RefCounted>) -> Option<Self> {
+        let object = object.try_cast::<RustHandleObject>()?;
+        if !object.bind().value.is::<T>() {
+            return None;
+        }
+
+        Some(Self {
+            object,
+            _value: PhantomData,
+        })
+    }
+
+    /// The object representing this handle in Godot.
+    pub fn to_object(&self) -> Gd<RefCounted> {
+        self.object.clone().upcast()
+    }
+
+    /// ⚠️ Shared access to the value.
+    ///
+    /// # Panics
+    /// If the value is currently borrowed mutably, through this or another handle to the same object.
+    pub fn bind(&self) -> HandleRef<'_, T> {
+        HandleRef {
+            guard: self.object.bind(),
+            _value: PhantomData,
+        }
+    }
+
+    /// ⚠️ Exclusive access to the value.
+    ///
+    /// # Panics
+    /// If the value is currently borrowed, through this or another handle to the same object.
+    pub fn bind_mut(&mut self) -> HandleMut<'_, T> {
+        HandleMut {
+            guard: self.object.bind_mut(),
+            _value: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for RustHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            object: self.object.clone(),
+            _value: PhantomData,
+        }
+    }
+}
+
+impl<T> PartialEq for RustHandle<T> {
+    /// Handles are equal if they refer to the same object.
+    fn eq(&self, other: &Self) -> bool {
+        self.object == other.object
+    }
+}
+
+impl<T> fmt::Debug for RustHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RustHandle")
+            .field("type", &std::any::type_name::<T>())
+            .field("id", &self.object.instance_id())
+            .finish()
+    }
+}
+
+impl<T: 'static> GodotConvert for RustHandle<T> {
+    type Via = Gd<RefCounted>;
+}
+
+impl<T: 'static> ToGodot for RustHandle<T> {
+    fn to_godot(&self) -> Self::Via {
+        self.to_object()
+    }
+}
+
+impl<T: 'static> FromGodot for RustHandle<T> {
+    fn try_from_godot(via: Self::Via) -> Option<Self> {
+        Self::try_from_object(via)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Shared borrow of the value of a [`RustHandle`].
+pub struct HandleRef<'a, T> {
+    guard: GdRef<'a, RustHandleObject>,
+    _value: PhantomData<&'a T>,
+}
+
+impl<T: 'static> Deref for HandleRef<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.guard.value.downcast_ref().expect(TYPE_CHECKED)
+    }
+}
+
+/// Exclusive borrow of the value of a [`RustHandle`].
+pub struct HandleMut<'a, T> {
+    guard: GdMut<'a, RustHandleObject>,
+    _value: PhantomData<&'a mut T>,
+}
+
+impl<T: 'static> Deref for HandleMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.guard.value.downcast_ref().expect(TYPE_CHECKED)
+    }
+}
+
+impl<T: 'static> DerefMut for HandleMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.guard.value.downcast_mut().expect(TYPE_CHECKED)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+const TYPE_CHECKED: &str = "RustHandle value type is checked on construction";
+
+/// The Godot class `RustHandle`, registered by gdext itself.
+///
+/// Not instantiable from Godot, since there is no value to put inside.
+struct RustHandleObject {
+    value: Box<dyn Any>,
+}
+
+unsafe impl GodotClass for RustHandleObject {
+    type Base = RefCounted;
+    type Declarer = dom::UserDomain;
+    type Mem = <RefCounted as GodotClass>::Mem;
+    const INIT_LEVEL: Option<InitLevel> = <RefCounted as GodotClass>::INIT_LEVEL;
+
+    fn class_name() -> ClassName {
+        ClassName::from_ascii_cstr(b"RustHandle\0")
+    }
+}
+
+impl Inherits<RefCounted> for RustHandleObject {}
+impl Inherits<Object> for RustHandleObject {}
+
+sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in crate::private; ClassPlugin {
+    class_name: RustHandleObject::class_name(),
+    component: PluginComponent::ClassDef {
+        base_class_name: RefCounted::class_name(),
+        generated_create_fn: None,
+        generated_recreate_fn: None,
+        free_fn: callbacks::free::<RustHandleObject>,
+        is_instantiable: false,
+        is_auto_registered: true,
+        register_if: None,
+        crate_name: env!("CARGO_PKG_NAME"),
+    },
+    init_level: RustHandleObject::INIT_LEVEL,
+});
@@ -28,7 +28,29 @@ use std::{fmt, ptr};
 // thread - Mutex is just casual way to ensure safety in this performance non-critical path.
 // Note that we panic on concurrent access instead of blocking - that's fail fast approach. If that
 // happen, most likely something changed on Godot side and analysis required to adopt these changes.
-static LOADED_CLASSES: Mutex<Option<HashMap<InitLevel, Vec<ClassName>>>> = Mutex::new(None);
+static LOADED_CLASSES: Mutex<Option<HashMap<InitLevel, Vec<LoadedClass>>>> = Mutex::new(None);
+
+/// Class registered with Godot, together with the entry point that registered it.
+#[derive(Copy, Clone, Debug)]
+struct LoadedClass {
+    name: ClassName,
+
+    /// Address of the `GDExtensionClassLibraryPtr` (raw pointers are not `Send`, which the static `Mutex` requires).
+    library: usize,
+
+    crate_name: &'static str,
+}
+
+/// Class that godot-rust registered with Godot, as returned by [`registered_classes()`].
+#[derive(Copy, Clone, Debug)]
+pub struct RegisteredClass {
+    pub class_name: ClassName,
+
+    /// Name of the package that defines the class, as in its `Cargo.toml`. Empty for classes built at runtime.
+    pub crate_name: &'static str,
+
+    pub init_level: InitLevel,
+}
 
 // TODO(bromeon): some information coming from the proc-macro API is deferred through PluginComponent, while others is directly
 // translated to code. Consider moving more code to the PluginComponent, which allows for more dynamic registration and will
@@ -82,6 +104,20 @@ pub enum PluginComponent {
             _class_user_data: *mut std::ffi::c_void,
             instance: sys::GDExtensionClassInstancePtr,
         ),
+
+        /// Whether Godot may construct the class, e.g. from the editor or via `ClassDB.instantiate()`.
+        ///
+        /// `false` for `#[class(no_init)]`; instances can then only be created from Rust.
+        is_instantiable: bool,
+
+        /// `false` if the class is only registered through an explicit [`register_user_class()`] call.
+        is_auto_registered: bool,
+
+        /// Condition from `#[class(register_if = ...)]`, checked at the class's init level before it is auto-registered.
+        register_if: Option<fn() -> bool>,
+
+        /// Package name of the crate containing the `#[derive(GodotClass)]`.
+        crate_name: &'static str,
     },
 
     /// Collected from `#[godot_api] impl MyClass`
@@ -162,6 +198,10 @@ struct ClassRegistrationInfo {
     godot_params: sys::GDExtensionClassCreationInfo2,
     init_level: InitLevel,
     is_editor_plugin: bool,
+    is_instantiable: bool,
+    is_auto_registered: bool,
+    register_if: Option<fn() -> bool>,
+    crate_name: &'static str,
 }
 
 /// Registers a class with static type information.
@@ -217,13 +257,140 @@ pub fn register_class<
             panic!("Unknown initialization level for class {}", T::class_name())
         }),
         is_editor_plugin: false,
+        is_instantiable: true,
+        is_auto_registered: false,
+        register_if: None,
+        crate_name: "",
     });
 }
 
 /// Lets Godot know about all classes that have self-registered through the plugin system.
-pub fn auto_register_classes(init_level: InitLevel) {
+///
+/// Only classes for which `accepts_class` returns true are registered, and classes already registered by another entry point of
+/// the same binary are skipped, as are classes declared with `#[class(auto_register = false)]` and those whose
+/// `#[class(register_if = ...)]` condition returns false. They are registered with the library that is currently active, see [`sys::with_active_library()`].
+pub fn auto_register_classes(init_level: InitLevel, accepts_class: fn(ClassName) -> bool) {
     out!("Auto-register classes at level `{init_level:?}`...");
 
+    let mut loaded_classes_guard = get_loaded_classes_with_mutex();
+    let loaded_classes_by_level = loaded_classes_guard.get_or_insert_with(HashMap::default);
+    let loaded_classes_current_level = loaded_classes_by_level.entry(init_level).or_default();
+
+    let map = collect_class_infos(init_level, |name| {
+        !is_loaded(loaded_classes_current_level, name) && accepts_class(name)
+    });
+
+    for info in map.into_values() {
+        if !info.is_auto_registered {
+            out!("Skip class:   {} (not auto-registered)", info.class_name);
+            continue;
+        }
+
+        if info.register_if.is_some_and(|condition| !condition()) {
+            out!(
+                "Skip class:   {} (register_if condition not met)",
+                info.class_name
+            );
+            continue;
+        }
+
+        load_class(info, init_level, loaded_classes_current_level);
+    }
+
+    out!("All classes for level `{init_level:?}` auto-registered.");
+}
+
+/// Registers the user class `T`, which is typically declared with `#[class(auto_register = false)]`.
+///
+/// Must be called from [`ExtensionLibrary::on_level_init()`][crate::init::ExtensionLibrary::on_level_init] at the initialization
+/// level of the class (the one of its engine base class, [`InitLevel::Scene`] for most classes). The class is unregistered
+/// automatically when that level is unloaded. Registering a class a second time has no effect.
+///
+/// # Panics
+/// If `T` is not declared with `#[derive(GodotClass)]`.
+pub fn register_user_class<T: GodotClass>() {
+    let class_name = T::class_name();
+    let init_level = T::INIT_LEVEL
+        .unwrap_or_else(|| panic!("Unknown initialization level for class {class_name}"));
+
+    let mut loaded_classes_guard = get_loaded_classes_with_mutex();
+    let loaded_classes_by_level = loaded_classes_guard.get_or_insert_with(HashMap::default);
+    let loaded_classes_current_level = loaded_classes_by_level.entry(init_level).or_default();
+
+    if is_loaded(loaded_classes_current_level, class_name) {
+        out!("Class {class_name} already registered");
+        return;
+    }
+
+    let info = collect_class_infos(init_level, |name| name == class_name)
+        .remove(&class_name)
+        .filter(|info| info.parent_class_name.is_some())
+        .unwrap_or_else(|| {
+            panic!("cannot register class `{class_name}`, which is not declared with #[derive(GodotClass)]")
+        });
+
+    load_class(info, init_level, loaded_classes_current_level);
+}
+
+/// Registers a class built at runtime, see [`DynamicClassBuilder`][crate::builder::DynamicClassBuilder].
+pub(crate) fn register_dynamic_class(
+    class_name: ClassName,
+    parent_class_name: ClassName,
+    init_level: InitLevel,
+    create_fn: unsafe extern "C" fn(*mut std::ffi::c_void) -> sys::GDExtensionObjectPtr,
+    free_fn: unsafe extern "C" fn(*mut std::ffi::c_void, sys::GDExtensionClassInstancePtr),
+    class_userdata: *mut std::ffi::c_void,
+) {
+    let mut info = default_registration_info(class_name);
+    info.parent_class_name = Some(parent_class_name);
+    info.init_level = init_level;
+    info.godot_params.create_instance_func = Some(create_fn);
+    info.godot_params.free_instance_func = Some(free_fn);
+    info.godot_params.class_userdata = class_userdata;
+
+    let mut loaded_classes_guard = get_loaded_classes_with_mutex();
+    let loaded_classes_by_level = loaded_classes_guard.get_or_insert_with(HashMap::default);
+    let loaded_classes_current_level = loaded_classes_by_level.entry(init_level).or_default();
+
+    assert!(
+        !is_loaded(loaded_classes_current_level, class_name),
+        "class `{class_name}` is already registered"
+    );
+
+    load_class(info, init_level, loaded_classes_current_level);
+}
+
+/// Returns all classes that godot-rust has registered so far, in order of registration.
+///
+/// This includes classes of all crates linked into the binary. Dependencies providing classes can be told apart by
+/// [`RegisteredClass::crate_name`].
+pub fn registered_classes() -> Vec<RegisteredClass> {
+    let loaded_classes_guard = get_loaded_classes_with_mutex();
+    let Some(loaded_classes_by_level) = loaded_classes_guard.as_ref() else {
+        return Vec::new();
+    };
+
+    let mut classes: Vec<RegisteredClass> = loaded_classes_by_level
+        .iter()
+        .flat_map(|(&init_level, loaded_classes)| {
+            loaded_classes.iter().map(move |loaded| RegisteredClass {
+                class_name: loaded.name,
+                crate_name: loaded.crate_name,
+                init_level,
+            })
+        })
+        .collect();
+
+    // Stable sort: keeps registration order within each level.
+    classes.sort_by_key(|class| class.init_level);
+    classes
+}
+
+/// Gathers the plugins of all classes at `init_level` for which `filter` returns true.
+fn collect_class_infos(
+    init_level: InitLevel,
+    mut filter: impl FnMut(ClassName) -> bool,
+) -> HashMap<ClassName, ClassRegistrationInfo> {
     // Note: many errors are already caught by the compiler, before this runtime validation even takes place:
     // * missing #[derive(GodotClass)] or impl GodotClass for T
     // * duplicate impl GodotInit for T
@@ -242,6 +409,10 @@ pub fn auto_register_classes(init_level: InitLevel) {
         }
 
         let name = elem.class_name;
+        if !filter(name) {
+            return;
+        }
+
         let class_info = map
             .entry(name)
             .or_insert_with(|| default_registration_info(name));
@@ -249,41 +420,64 @@ pub fn auto_register_classes(init_level: InitLevel) {
         fill_class_info(elem.component.clone(), class_info);
     });
 
-    let mut loaded_classes_guard = get_loaded_classes_with_mutex();
-    let loaded_classes_by_level = loaded_classes_guard.get_or_insert_with(HashMap::default);
+    map
+}
 
-    for info in map.into_values() {
-        out!(
-            "Register class:   {} at level `{init_level:?}`",
-            info.class_name
-        );
-        let class_name = info.class_name;
-        loaded_classes_by_level
-            .entry(init_level)
-            .or_default()
-            .push(info.class_name);
-        register_class_raw(info);
-
-        out!("Class {} loaded", class_name);
+fn is_loaded(loaded_classes: &[LoadedClass], class_name: ClassName) -> bool {
+    loaded_classes
+        .iter()
+        .any(|loaded| loaded.name == class_name)
+}
+
+/// Registers `info` with the active library, and remembers it for unregistration.
+fn load_class(
+    mut info: ClassRegistrationInfo,
+    init_level: InitLevel,
+    loaded_classes: &mut Vec<LoadedClass>,
+) {
+    if !info.is_instantiable {
+        make_non_instantiable(&mut info);
     }
 
-    out!("All classes for level `{init_level:?}` auto-registered.");
+    out!(
+        "Register class:   {} at level `{init_level:?}`",
+        info.class_name
+    );
+    let class_name = info.class_name;
+    loaded_classes.push(LoadedClass {
+        name: class_name,
+        library: unsafe { sys::get_library() } as usize,
+        crate_name: info.crate_name,
+    });
+    register_class_raw(info);
+
+    out!("Class {} loaded", class_name);
 }
 
+/// Unregisters all classes of `init_level` that were registered by the currently active library.
 pub fn unregister_classes(init_level: InitLevel) {
     let mut loaded_classes_guard = get_loaded_classes_with_mutex();
     let loaded_classes_by_level = loaded_classes_guard.get_or_insert_with(HashMap::default);
-    let loaded_classes_current_level = loaded_classes_by_level
-        .remove(&init_level)
-        .unwrap_or_default();
+    let Some(loaded_classes_current_level) = loaded_classes_by_level.get_mut(&init_level) else {
+        return;
+    };
+
+    let library = unsafe { sys::get_library() } as usize;
+
     out!("Unregistering classes of level {init_level:?}...");
-    for class_name in loaded_classes_current_level.iter().rev() {
-        unregister_class_raw(class_name);
+    let (own, others): (Vec<_>, Vec<_>) = loaded_classes_current_level
+        .drain(..)
+        .partition(|loaded| loaded.library == library);
+
+    for loaded in own.iter().rev() {
+        unregister_class_raw(&loaded.name);
     }
+
+    *loaded_classes_current_level = others;
 }
 
-fn get_loaded_classes_with_mutex() -> MutexGuard<'static, Option<HashMap<InitLevel, Vec<ClassName>>>>
-{
+fn get_loaded_classes_with_mutex(
+) -> MutexGuard<'static, Option<HashMap<InitLevel, Vec<LoadedClass>>>> {
     match LOADED_CLASSES.try_lock() {
         Ok(it) => it,
         Err(err) => match err {
@@ -305,8 +499,16 @@ fn fill_class_info(component: PluginComponent, c: &mut ClassRegistrationInfo) {
             generated_create_fn,
             generated_recreate_fn,
             free_fn,
+            is_instantiable,
+            is_auto_registered,
+            register_if,
+            crate_name,
         } => {
             c.parent_class_name = Some(base_class_name);
+            c.is_instantiable = is_instantiable;
+            c.is_auto_registered = is_auto_registered;
+            c.register_if = register_if;
+            c.crate_name = crate_name;
 
             fill_into(
                 &mut c.godot_params.create_instance_func,
@@ -377,6 +579,17 @@ fn fill_class_info(component: PluginComponent, c: &mut ClassRegistrationInfo) {
     // out!();
 }
 
+/// Applies `#[class(no_init)]`: Godot sees the class as abstract and has no way to construct it.
+fn make_non_instantiable(info: &mut ClassRegistrationInfo) {
+    assert!(
+        info.godot_params.create_instance_func.is_none(),
+        "class `{}` is declared with #[class(no_init)], but also provides an init() function",
+        info.class_name,
+    );
+
+    info.godot_params.is_abstract = true as u8;
+}
+
 /// If `src` is occupied, it moves the value into `dst`, while ensuring that no previous value is present in `dst`.
 fn fill_into<T>(dst: &mut Option<T>, src: Option<T>) -> Result<(), ()> {
     match (dst, src) {
@@ -530,7 +743,7 @@ pub mod callbacks {
             interface_fn!(object_set_instance)(base_ptr, class_name.string_sys(), instance_ptr);
             interface_fn!(object_set_instance_binding)(
                 base_ptr,
-                sys::get_library() as *mut std::ffi::c_void,
+                sys::get_instance_binding_token(),
                 instance_ptr as *mut std::ffi::c_void,
                 &binding_data_callbacks,
             );
@@ -667,6 +880,10 @@ fn default_registration_info(class_name: ClassName) -> ClassRegistrationInfo {
         godot_params: default_creation_info(),
         init_level: InitLevel::Scene,
         is_editor_plugin: false,
+        is_instantiable: true,
+        is_auto_registered: true,
+        register_if: None,
+        crate_name: "",
     }
 }
 
@@ -49,6 +49,9 @@ mod single_threaded {
         pub fn construct(user_instance: T, base: Base<T::Base>) -> Self {
             out!("    Storage::construct             <{}>", type_name::<T>());
 
+            #[cfg(debug_assertions)]
+            crate::engine::instance_counts::instance_created(T::class_name());
+
             Self {
                 user_instance: cell::RefCell::new(user_instance),
                 base,
@@ -167,6 +170,9 @@ mod multi_threaded {
         pub fn construct(user_instance: T, base: Base<T::Base>) -> Self {
             out!("    Storage::construct             <{}>", type_name::<T>());
 
+            #[cfg(debug_assertions)]
+            crate::engine::instance_counts::instance_created(T::class_name());
+
             Self {
                 user_instance: sync::RwLock::new(user_instance),
                 base,
@@ -196,6 +202,11 @@ mod multi_threaded {
         }
 
         pub fn get(&self) -> sync::RwLockReadGuard<T> {
+            #[cfg(feature = "debug-overlay")]
+            if let Err(sync::TryLockError::WouldBlock) = self.user_instance.try_read() {
+                crate::engine::metrics::bind_contention();
+            }
+
             self.user_instance.read().unwrap_or_else(|_e| {
                 panic!(
                     "Gd<T>::bind() failed, already bound; T = {}.\n  \
@@ -207,6 +218,11 @@ mod multi_threaded {
         }
 
         pub fn get_mut(&self) -> sync::RwLockWriteGuard<T> {
+            #[cfg(feature = "debug-overlay")]
+            if let Err(sync::TryLockError::WouldBlock) = self.user_instance.try_write() {
+                crate::engine::metrics::bind_contention();
+            }
+
             self.user_instance.write().unwrap_or_else(|_e| {
                 panic!(
                     "Gd<T>::bind_mut() failed, already bound; T = {}.\n  \
@@ -280,6 +296,9 @@ impl<T: GodotClass> InstanceStorage<T> {
 
 impl<T: GodotClass> Drop for InstanceStorage<T> {
     fn drop(&mut self) {
+        #[cfg(debug_assertions)]
+        crate::engine::instance_counts::instance_destroyed(T::class_name());
+
         out!(
             "    Storage::drop (rc={})           <{}>", // -- {:?}",
             self.godot_ref_count(),
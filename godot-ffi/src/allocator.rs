@@ -0,0 +1,147 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::ffi::c_void;
+use std::mem;
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::GDExtensionInterface;
+
+type MemAllocFn = unsafe extern "C" fn(usize) -> *mut c_void;
+type MemFreeFn = unsafe extern "C" fn(*mut c_void);
+
+// Stored separately from the binding, since the allocator can be invoked from any thread, at any time -- including before
+// initialization (static constructors) and after deinitialization.
+static MEM_ALLOC: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+static MEM_FREE: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+
+/// Global allocator that routes Rust heap allocations through Godot's `Memory` API.
+///
+/// Allocations then count towards Godot's memory monitors (e.g. `Performance.MEMORY_STATIC`), and follow the allocator the engine
+/// was built with. To use it, declare it in your extension crate:
+///
+/// ```no_run
+/// #[global_allocator]
+/// static ALLOCATOR: godot::init::GodotAllocator = godot::init::GodotAllocator;
+/// ```
+///
+/// Allocations that happen before the GDExtension interface is loaded (e.g. in static constructors) fall back to the system allocator.
+/// Each block remembers where it came from, so it is always released through the same allocator.
+pub struct GodotAllocator;
+
+/// Prepended to every allocation, directly before the pointer handed out to Rust.
+#[derive(Copy, Clone)]
+struct Header {
+    /// Start of the underlying block, as returned by the backing allocator.
+    base: *mut u8,
+
+    /// Whether the block was obtained from Godot (`true`) or the system allocator (`false`).
+    from_godot: bool,
+}
+
+impl GodotAllocator {
+    /// Total block size and alignment needed to serve `layout`, including header and alignment padding.
+    fn padded_layout(layout: Layout) -> Option<Layout> {
+        let align = layout.align().max(mem::align_of::<Header>());
+        let size = layout
+            .size()
+            .checked_add(mem::size_of::<Header>())?
+            .checked_add(align)?;
+
+        Layout::from_size_align(size, mem::align_of::<Header>()).ok()
+    }
+
+    /// Given the start of a block, returns the aligned user pointer and writes the header before it.
+    unsafe fn finish_alloc(base: *mut u8, layout: Layout, from_godot: bool) -> *mut u8 {
+        let align = layout.align().max(mem::align_of::<Header>());
+        let min_user = base as usize + mem::size_of::<Header>();
+        let user = (min_user + align - 1) & !(align - 1);
+        let user = base.add(user - base as usize);
+
+        let header = user.sub(mem::size_of::<Header>()) as *mut Header;
+        header.write_unaligned(Header { base, from_godot });
+
+        user
+    }
+}
+
+unsafe impl GlobalAlloc for GodotAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let Some(padded) = Self::padded_layout(layout) else {
+            return ptr::null_mut();
+        };
+
+        let mem_alloc = MEM_ALLOC.load(Ordering::Acquire);
+        let (base, from_godot) = if mem_alloc.is_null() {
+            (System.alloc(padded), false)
+        } else {
+            let mem_alloc: MemAllocFn = mem::transmute(mem_alloc);
+            (mem_alloc(padded.size()) as *mut u8, true)
+        };
+
+        if base.is_null() {
+            return ptr::null_mut();
+        }
+
+        Self::finish_alloc(base, layout, from_godot)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let header = (ptr.sub(mem::size_of::<Header>()) as *const Header).read_unaligned();
+
+        if header.from_godot {
+            let mem_free = MEM_FREE.load(Ordering::Acquire);
+            debug_assert!(!mem_free.is_null(), "Godot allocator not bound");
+
+            let mem_free: MemFreeFn = mem::transmute(mem_free);
+            mem_free(header.base as *mut c_void);
+        } else {
+            // unwrap: layout was already validated on allocation.
+            let padded = Self::padded_layout(layout).unwrap();
+            System.dealloc(header.base, padded);
+        }
+    }
+}
+
+/// Makes [`GodotAllocator`] use Godot's allocation functions from now on.
+///
+/// # Safety
+/// The function pointers in `interface` must be valid for the rest of the process' lifetime.
+pub(crate) unsafe fn bind_allocator(interface: &GDExtensionInterface) {
+    let (Some(mem_alloc), Some(mem_free)) = (interface.mem_alloc, interface.mem_free) else {
+        return;
+    };
+
+    // Free function first: once alloc is visible, blocks from Godot can be handed out and must be freeable.
+    MEM_FREE.store(mem_free as *mut c_void, Ordering::Release);
+    MEM_ALLOC.store(mem_alloc as *mut c_void, Ordering::Release);
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Without a loaded interface, all blocks come from the system allocator.
+    #[test]
+    fn alloc_respects_alignment() {
+        for align in [1, 2, 8, 16, 64, 4096] {
+            let layout = Layout::from_size_align(24, align).unwrap();
+
+            unsafe {
+                let ptr = GodotAllocator.alloc(layout);
+                assert!(!ptr.is_null());
+                assert_eq!(ptr as usize % align, 0, "alignment {align}");
+
+                ptr.write_bytes(0xAB, layout.size());
+                GodotAllocator.dealloc(ptr, layout);
+            }
+        }
+    }
+}
@@ -113,8 +113,35 @@ pub fn panic_call_error(
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
-// Lazy method table key types
-// Could reuse them in normal load functions, but less code when passing separate parameters -> faster parsing.
+// Lazy method tables
+
+/// Function pointer that is loaded on first use, stored in a `static` next to the generated code calling it.
+#[cfg(feature = "codegen-lazy-fptrs")]
+pub struct LazyFptr<T> {
+    cell: std::sync::OnceLock<T>,
+}
+
+// SAFETY: the stored values are function pointers and method binds, which Godot keeps valid for all threads. Loading them goes through the
+// lazy method tables, which are not thread-safe; the `godot` crate thus rejects `lazy-function-tables` together with `experimental-threads`.
+#[cfg(feature = "codegen-lazy-fptrs")]
+unsafe impl<T> Sync for LazyFptr<T> {}
+
+#[cfg(feature = "codegen-lazy-fptrs")]
+impl<T: Copy> LazyFptr<T> {
+    #[allow(clippy::new_without_default)] // only used in statics
+    pub const fn new() -> Self {
+        Self {
+            cell: std::sync::OnceLock::new(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn get_or_load(&self, load: impl FnOnce() -> T) -> T {
+        *self.cell.get_or_init(load)
+    }
+}
+
+// Key types: could reuse them in normal load functions, but less code when passing separate parameters -> faster parsing.
 
 #[cfg(feature = "codegen-lazy-fptrs")]
 pub mod lazy_keys {
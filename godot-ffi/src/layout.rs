@@ -0,0 +1,94 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Memory layout of Godot's builtin types, and how gdext maps them to Rust.
+//!
+//! Meant for crates that marshal values on their own (e.g. bridges to other scripting languages) and would otherwise hard-code
+//! type sizes. Like the rest of the raw interface, this module has **no stability guarantees**: its contents follow the Godot
+//! version gdext is compiled against, and may change in any release.
+//!
+//! Sizes are those of the build configuration gdext was compiled for, i.e. the target's pointer width and the precision of `real_t`
+//! (see the `double-precision` feature).
+
+use crate::gen::central::types;
+use crate::gen::central::{META_TYPE_NAMES, VARIANT_TYPE_NAMES};
+
+pub use crate::VariantType;
+
+/// Size of a `Variant` in bytes.
+pub const VARIANT_SIZE: usize = types::VARIANT_SIZE;
+
+/// Alignment of a `Variant` in bytes; that of its largest scalar member (64-bit integer, `double` or pointer).
+pub const VARIANT_ALIGN: usize = max(
+    std::mem::align_of::<i64>(),
+    max(
+        std::mem::align_of::<f64>(),
+        std::mem::align_of::<*const u8>(),
+    ),
+);
+
+/// Layout and naming of one variant type.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BuiltinLayout {
+    /// The variant type.
+    pub variant_type: VariantType,
+
+    /// Name in Godot's API, e.g. `"int"` or `"PackedVector2Array"`.
+    pub godot_name: &'static str,
+
+    /// Name of the Rust type used by gdext, without module path, e.g. `"i64"` or `"PackedVector2Array"`.
+    pub rust_name: &'static str,
+
+    /// Size in bytes of the value's own representation, as used in ptrcalls. Stored inside a `Variant`, it takes [`VARIANT_SIZE`].
+    pub size: usize,
+}
+
+/// All variant types except `Nil`, ordered by `VariantType`.
+pub fn builtin_layouts() -> impl Iterator<Item = BuiltinLayout> {
+    VARIANT_TYPE_NAMES
+        .iter()
+        .map(|&(variant_type, godot_name, rust_name)| BuiltinLayout {
+            variant_type,
+            godot_name,
+            rust_name,
+            size: builtin_size(godot_name),
+        })
+}
+
+/// Layout of `variant_type`, or `None` for `Nil`.
+pub fn builtin_layout(variant_type: VariantType) -> Option<BuiltinLayout> {
+    builtin_layouts().find(|layout| layout.variant_type == variant_type)
+}
+
+/// Rust type used by gdext for a Godot numeric type with argument metadata, such as `("int", "uint8")` -> `"u8"`.
+///
+/// Godot describes narrower integers and single-precision floats in method signatures this way; without metadata, `int` and
+/// `float` map to `i64` and `f64`.
+pub fn meta_rust_name(godot_name: &str, meta: &str) -> Option<&'static str> {
+    META_TYPE_NAMES
+        .iter()
+        .find(|&&(ty, ty_meta, _)| ty == godot_name && ty_meta == meta)
+        .map(|&(_, _, rust_name)| rust_name)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation of this file
+
+fn builtin_size(godot_name: &str) -> usize {
+    types::BUILTIN_SIZES
+        .iter()
+        .find(|&&(name, _)| name == godot_name)
+        .map(|&(_, size)| size)
+        .unwrap_or_else(|| panic!("extension API lists no size for builtin type `{godot_name}`"))
+}
+
+const fn max(a: usize, b: usize) -> usize {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
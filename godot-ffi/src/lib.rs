@@ -30,11 +30,16 @@ pub(crate) mod gen {
     pub mod interface;
 }
 
+mod allocator;
 mod compat;
 mod gdextension_plus;
 mod godot_ffi;
+#[cfg(feature = "experimental-sys")]
+pub mod layout;
 mod opaque;
 mod plugins;
+#[cfg(feature = "experimental-sys")]
+mod raw_interface;
 mod string_cache;
 mod toolbox;
 
@@ -61,10 +66,13 @@ pub use gen::table_servers_classes::*;
 pub use gen::table_utilities::*;
 
 // Other
+pub use allocator::GodotAllocator;
 pub use gdextension_plus::*;
 pub use gen::central::*;
 pub use gen::gdextension_interface::*;
 pub use gen::interface::*;
+#[cfg(feature = "experimental-sys")]
+pub use raw_interface::{interface, try_interface, RawInterface};
 pub use string_cache::StringCache;
 pub use toolbox::*;
 
@@ -105,6 +113,12 @@ pub struct GdextConfig {
 // &mut references are handed out (except for registry, see below). Overall, UnsafeCell/RefCell + Sync might be a safer abstraction.
 static mut BINDING: Option<GodotBinding> = None;
 
+/// Library pointer of the entry point whose init/deinit callback is currently running.
+///
+/// A single binary can define several `#[gdextension]` entry points, which Godot passes different library pointers. The interface
+/// and method tables are shared, but classes must be registered and unregistered with the library of the entry point that owns them.
+static mut ACTIVE_LIBRARY: GDExtensionClassLibraryPtr = std::ptr::null_mut();
+
 /// # Safety
 ///
 /// - The `interface` pointer must be either:
@@ -134,6 +148,8 @@ pub unsafe fn initialize(
     let interface = compat.load_interface();
     out!("Loaded interface.");
 
+    allocator::bind_allocator(&interface);
+
     let global_method_table = BuiltinLifecycleTable::load(&interface);
     out!("Loaded global method table.");
 
@@ -149,7 +165,7 @@ pub unsafe fn initialize(
     let builtin_method_table = {
         #[cfg(feature = "codegen-lazy-fptrs")]
         {
-            None // loaded later
+            Some(BuiltinMethodTable::load()) // function pointers are loaded on first call
         }
         #[cfg(not(feature = "codegen-lazy-fptrs"))]
         {
@@ -175,14 +191,6 @@ pub unsafe fn initialize(
     });
     out!("Assigned binding.");
 
-    // Lazy case: load afterwards because table's internal StringCache stores &'static references to the interface.
-    #[cfg(feature = "codegen-lazy-fptrs")]
-    {
-        let builtin_method_table = BuiltinMethodTable::load();
-        BINDING.as_mut().unwrap().builtin_method_table = Some(builtin_method_table);
-        out!("Loaded builtin method table (lazily).");
-    }
-
     println!(
         "Initialize GDExtension API for Rust: {}",
         CStr::from_ptr(version.string)
@@ -209,9 +217,45 @@ pub unsafe fn get_interface() -> &'static GDExtensionInterface {
 /// # Safety
 ///
 /// The library must have been initialised with [`initialize`] before calling this function.
+///
+/// During the init/deinit callbacks of an entry point, this returns the library of that entry point (see [`with_active_library`]).
+/// Otherwise, it returns the library of the first entry point, which was passed to [`initialize`].
 #[inline(always)]
 pub unsafe fn get_library() -> GDExtensionClassLibraryPtr {
-    unwrap_ref_unchecked(&BINDING).library
+    if ACTIVE_LIBRARY.is_null() {
+        unwrap_ref_unchecked(&BINDING).library
+    } else {
+        ACTIVE_LIBRARY
+    }
+}
+
+/// Token used to look up instance bindings of objects.
+///
+/// Unlike [`get_library`], this stays the same for all entry points, so that objects created during one entry point's
+/// callbacks can be resolved in another one.
+///
+/// # Safety
+///
+/// The library must have been initialised with [`initialize`] before calling this function.
+#[inline(always)]
+pub unsafe fn get_instance_binding_token() -> *mut std::ffi::c_void {
+    unwrap_ref_unchecked(&BINDING).library as *mut std::ffi::c_void
+}
+
+/// Runs `f` with `library` as the result of [`get_library`], restoring the previous library afterwards.
+///
+/// # Safety
+///
+/// Must be called from the main thread, and `library` must be a pointer given by Godot to one of the entry points.
+pub unsafe fn with_active_library<R>(
+    library: GDExtensionClassLibraryPtr,
+    f: impl FnOnce() -> R,
+) -> R {
+    let previous = std::mem::replace(&mut ACTIVE_LIBRARY, library);
+    let result = f();
+    ACTIVE_LIBRARY = previous;
+
+    result
 }
 
 /// # Safety
@@ -290,6 +334,16 @@ pub unsafe fn utility_function_table() -> &'static UtilityFunctionTable {
 pub unsafe fn load_class_method_table(api_level: ClassApiLevel) {
     let binding = unwrap_ref_unchecked_mut(&mut BINDING);
 
+    // With multiple entry points, every one of them initializes each level; the tables only need to be loaded once.
+    let is_loaded = match api_level {
+        ClassApiLevel::Server => binding.class_server_method_table.is_some(),
+        ClassApiLevel::Scene => binding.class_scene_method_table.is_some(),
+        ClassApiLevel::Editor => binding.class_editor_method_table.is_some(),
+    };
+    if is_loaded {
+        return;
+    }
+
     out!("Load class method table for level '{:?}'...", api_level);
     let begin = std::time::Instant::now();
 
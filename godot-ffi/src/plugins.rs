@@ -49,6 +49,8 @@ macro_rules! plugin_add_inner {
             #[cfg_attr(target_os = "linux", link_section = ".init_array")]
             #[cfg_attr(target_os = "netbsd", link_section = ".init_array")]
             #[cfg_attr(target_os = "openbsd", link_section = ".init_array")]
+            // Web (Emscripten side module); constructors are run explicitly on load, see godot-core init.
+            #[cfg_attr(target_os = "emscripten", link_section = ".init_array")]
             static __init: extern "C" fn() = {
                 #[cfg_attr(target_os = "android", link_section = ".text.startup")]
                 #[cfg_attr(target_os = "linux", link_section = ".text.startup")]
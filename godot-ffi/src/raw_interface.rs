@@ -0,0 +1,82 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Opt-in access to the raw GDExtension interface, for functionality that gdext does not wrap (yet).
+
+use crate::GDExtensionInterface;
+
+/// Handle to the raw GDExtension interface table, tagged with the Godot version that provided it.
+///
+/// Obtained via [`interface()`] or [`try_interface()`]. The table contains **all** interface functions of the Godot version gdext was
+/// compiled against, including rarely used ones (script instances, custom callables, placement construction, ...). Calling any of them
+/// is `unsafe` and bypasses all of gdext's invariants; you are responsible for upholding Godot's contracts.
+///
+/// Functions introduced in later Godot versions may be unavailable at runtime, even if they are present in the table. Check the
+/// runtime version with [`since_api()`][Self::since_api], or use [`require()`][Self::require].
+#[derive(Copy, Clone)]
+pub struct RawInterface {
+    table: &'static GDExtensionInterface,
+    runtime_version: (u8, u8, u8),
+}
+
+impl RawInterface {
+    /// Version of this accessor API; incremented whenever its semantics change in a breaking way.
+    ///
+    /// Crates building on top of it can assert this value, in addition to the Godot version.
+    pub const ACCESS_VERSION: u32 = 1;
+
+    /// The interface function table.
+    pub fn table(self) -> &'static GDExtensionInterface {
+        self.table
+    }
+
+    /// Version of the Godot engine which provided the interface, as `(major, minor, patch)` triple.
+    pub fn runtime_version(self) -> (u8, u8, u8) {
+        self.runtime_version
+    }
+
+    /// Returns `true` if the Godot version at runtime is equal or greater to `major.minor`.
+    pub fn since_api(self, major: u8, minor: u8) -> bool {
+        let (runtime_major, runtime_minor, _) = self.runtime_version;
+        (runtime_major, runtime_minor) >= (major, minor)
+    }
+
+    /// Returns the table only if the Godot version at runtime is at least `major.minor`.
+    pub fn require(self, major: u8, minor: u8) -> Option<&'static GDExtensionInterface> {
+        self.since_api(major, minor).then_some(self.table)
+    }
+}
+
+/// ⚠️ Access to the raw GDExtension interface.
+///
+/// # Panics
+/// If the GDExtension library has not been initialized yet.
+pub fn interface() -> RawInterface {
+    try_interface().expect("GDExtension interface accessed before initialization")
+}
+
+/// Access to the raw GDExtension interface (fallible).
+///
+/// Returns `None` if the GDExtension library has not been initialized yet, e.g. when called from a static constructor.
+/// Must be called from the main thread.
+pub fn try_interface() -> Option<RawInterface> {
+    // SAFETY: the binding is only written during initialization on the main thread (see function docs).
+    unsafe {
+        if !crate::is_initialized() {
+            return None;
+        }
+
+        let version = crate::runtime_metadata().godot_version;
+        Some(RawInterface {
+            table: crate::get_interface(),
+            runtime_version: (
+                version.major as u8,
+                version.minor as u8,
+                version.patch as u8,
+            ),
+        })
+    }
+}
@@ -4,7 +4,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::class::{FieldExport, FieldVar};
+use crate::class::{FieldExport, FieldVar, UsageFlags};
 use proc_macro2::{Ident, TokenStream};
 
 pub struct Field {
@@ -13,6 +13,15 @@ pub struct Field {
     pub default: Option<TokenStream>,
     pub var: Option<FieldVar>,
     pub export: Option<FieldExport>,
+
+    /// Usage preset from `#[export(usage = ...)]`, applied if `#[var]` does not specify usage flags.
+    pub export_usage: Option<UsageFlags>,
+
+    /// Signal emitted by the generated setter, from `#[export(notify)]`.
+    pub notify: Option<PropertyNotify>,
+
+    /// Function that checks values before the generated setter stores them, from `#[export(validate = fn)]`.
+    pub validate: Option<Ident>,
 }
 
 impl Field {
@@ -23,10 +32,23 @@ impl Field {
             default: None,
             var: None,
             export: None,
+            export_usage: None,
+            notify: None,
+            validate: None,
         }
     }
 }
 
+/// Signal emitted when an `#[export(notify)]` property changes.
+#[derive(Clone, Debug)]
+pub enum PropertyNotify {
+    /// `#[export(notify)]`: the class-wide `property_changed(property, old_value, new_value)` signal.
+    Shared,
+
+    /// `#[export(notify = signal_name)]`: a dedicated `signal_name(old_value, new_value)` signal.
+    Named(Ident),
+}
+
 pub struct Fields {
     /// All fields except `base_field`.
     pub all_fields: Vec<Field>,
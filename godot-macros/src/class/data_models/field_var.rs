@@ -10,7 +10,7 @@ use quote::{format_ident, quote};
 use crate::class::{
     make_existence_check, make_method_registration, Field, FieldHint, FuncDefinition,
 };
-use crate::util::KvParser;
+use crate::util::{bail, KvParser};
 use crate::{util, ParseResult};
 
 /// Store info from `#[var]` attribute.
@@ -20,6 +20,9 @@ pub struct FieldVar {
     pub setter: GetterSetter,
     pub hint: FieldHint,
     pub usage_flags: UsageFlags,
+
+    /// Name of the property in Godot, overriding `#[class(rename_all)]`.
+    pub rename: Option<Ident>,
 }
 
 impl FieldVar {
@@ -30,7 +33,9 @@ impl FieldVar {
     /// - `set = expr`
     /// - `hint = ident`
     /// - `hint_string = expr`
-    /// - `usage_flags =
+    /// - `usage_flags = [ident, ...]`
+    /// - `usage = ident` (preset, see [`UsageFlags::from_preset`])
+    /// - `rename = ident`
     pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
         let mut getter = GetterSetter::parse(parser, "get")?;
         let mut setter = GetterSetter::parse(parser, "set")?;
@@ -50,7 +55,12 @@ impl FieldVar {
             FieldHint::Inferred
         };
 
+        let usage_preset = parser.handle_ident("usage")?;
         let usage_flags = if let Some(mut parser) = parser.handle_array("usage_flags")? {
+            if let Some(preset) = usage_preset {
+                return bail!(preset, "`usage` and `usage_flags` cannot be combined");
+            }
+
             let mut flags = Vec::new();
 
             while let Some(flag) = parser.next_ident()? {
@@ -60,15 +70,20 @@ impl FieldVar {
             parser.finish()?;
 
             UsageFlags::Custom(flags)
+        } else if let Some(preset) = usage_preset {
+            UsageFlags::from_preset(&preset)?
         } else {
             UsageFlags::Inferred
         };
 
+        let rename = parser.handle_ident("rename")?;
+
         Ok(FieldVar {
             getter,
             setter,
             hint,
             usage_flags,
+            rename,
         })
     }
 }
@@ -87,7 +102,7 @@ pub enum GetterSetter {
 }
 
 impl GetterSetter {
-    pub(super) fn parse(parser: &mut KvParser, key: &str) -> ParseResult<Self> {
+    pub(crate) fn parse(parser: &mut KvParser, key: &str) -> ParseResult<Self> {
         let getter_setter = match parser.handle_any(key) {
             // No `get` argument
             None => GetterSetter::Omitted,
@@ -106,16 +121,20 @@ impl GetterSetter {
     /// given field and getter/setter kind.
     ///
     /// Returns `None` if no getter/setter should be created.
+    ///
+    /// A generated setter first passes the value through the field's `validate` function, if any, and runs `notify` if the property
+    /// value changed, with the `Variant`s `old_value` and `new_value` in scope.
     pub(super) fn to_impl(
         &self,
         class_name: &Ident,
         kind: GetSet,
         field: &Field,
+        notify: Option<&TokenStream>,
     ) -> Option<GetterSetterImpl> {
         match self {
             GetterSetter::Omitted => None,
             GetterSetter::Generated => Some(GetterSetterImpl::from_generated_impl(
-                class_name, kind, field,
+                class_name, kind, field, notify,
             )),
             GetterSetter::Custom(function_name) => {
                 Some(GetterSetterImpl::from_custom_impl(function_name))
@@ -152,7 +171,12 @@ pub struct GetterSetterImpl {
 }
 
 impl GetterSetterImpl {
-    fn from_generated_impl(class_name: &Ident, kind: GetSet, field: &Field) -> Self {
+    fn from_generated_impl(
+        class_name: &Ident,
+        kind: GetSet,
+        field: &Field,
+        notify: Option<&TokenStream>,
+    ) -> Self {
         let Field {
             name: field_name,
             ty: field_type,
@@ -177,8 +201,35 @@ impl GetterSetterImpl {
                 signature = quote! {
                     fn #function_name(&mut self, #field_name: <#field_type as ::godot::bind::property::Property>::Intermediate)
                 };
-                function_body = quote! {
-                    <#field_type as ::godot::bind::property::Property>::set_property(&mut self.#field_name, #field_name);
+                // A rejected value leaves the field unchanged, without notification.
+                let validate = field.validate.as_ref().map(|validate| {
+                    quote! {
+                        let Some(#field_name) = self.#validate(#field_name) else {
+                            return;
+                        };
+                    }
+                });
+
+                function_body = match notify {
+                    None => quote! {
+                        #validate
+                        <#field_type as ::godot::bind::property::Property>::set_property(&mut self.#field_name, #field_name);
+                    },
+                    Some(notify) => quote! {
+                        #validate
+                        use ::godot::bind::property::Property;
+                        use ::godot::builtin::meta::ToGodot;
+
+                        // Moved first, in case the parameter is shadowed below (field named `old_value`).
+                        let value = #field_name;
+                        let old_value = ToGodot::to_variant(&<#field_type as Property>::get_property(&self.#field_name));
+                        <#field_type as Property>::set_property(&mut self.#field_name, value);
+                        let new_value = ToGodot::to_variant(&<#field_type as Property>::get_property(&self.#field_name));
+
+                        if old_value != new_value {
+                            #notify
+                        }
+                    },
                 };
             }
         }
@@ -202,6 +253,7 @@ impl GetterSetterImpl {
                 external_attributes: Vec::new(),
                 rename: None,
                 has_gd_self: false,
+                default_args: Vec::new(),
             },
         );
 
@@ -238,4 +290,32 @@ impl UsageFlags {
     pub fn is_inferred(&self) -> bool {
         matches!(self, Self::Inferred)
     }
+
+    /// Maps a `usage = preset` key to its usage flags.
+    ///
+    /// - `storage`: saved with the scene/resource, but not shown in the inspector.
+    /// - `editor`: shown in the inspector, but not saved.
+    /// - `no_instance_state`: like the default, but the value of an instanced scene is not stored as an override.
+    /// - `internal`: saved, but hidden from the inspector and the class reference.
+    pub(crate) fn from_preset(preset: &Ident) -> ParseResult<Self> {
+        let flags: &[&str] = match preset.to_string().as_str() {
+            "storage" => &["PROPERTY_USAGE_STORAGE"],
+            "editor" => &["PROPERTY_USAGE_EDITOR"],
+            "no_instance_state" => &["PROPERTY_USAGE_DEFAULT", "PROPERTY_USAGE_NO_INSTANCE_STATE"],
+            "internal" => &["PROPERTY_USAGE_STORAGE", "PROPERTY_USAGE_INTERNAL"],
+            _ => {
+                return bail!(
+                    preset,
+                    "unknown usage `{preset}`; expected one of `storage`, `editor`, `no_instance_state`, `internal`"
+                )
+            }
+        };
+
+        let flags = flags
+            .iter()
+            .map(|flag| Ident::new(flag, preset.span()))
+            .collect();
+
+        Ok(Self::Custom(flags))
+    }
 }
@@ -9,6 +9,7 @@ use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 
 /// Information used for registering a Rust function with Godot.
+#[derive(Clone)]
 pub struct FuncDefinition {
     /// Raw information about the Rust function.
     pub func: venial::Function,
@@ -17,6 +18,8 @@ pub struct FuncDefinition {
     /// The name the function will be exposed as in Godot. If `None`, the Rust function name is used.
     pub rename: Option<String>,
     pub has_gd_self: bool,
+    /// Default values of the trailing parameters, from `#[opt(default = ...)]`.
+    pub default_args: Vec<TokenStream>,
 }
 
 /// Returns a C function which acts as the callback when a virtual method of this instance is invoked.
@@ -79,6 +82,17 @@ pub fn make_method_registration(
         method_name.to_string()
     };
     let param_ident_strs = param_idents.iter().map(|ident| ident.to_string());
+    let default_args_call = if func_definition.default_args.is_empty() {
+        quote! { Vec::new() }
+    } else {
+        let default_args_fn = make_default_args_fn_name(method_name);
+        quote! { #class_name::#default_args_fn() }
+    };
+    let registered_name = util::make_registered_name(
+        class_name,
+        &method_name.to_string(),
+        func_definition.rename.as_deref(),
+    );
 
     // Transport #[cfg] attrs to the FFI glue to ensure functions which were conditionally
     // removed from compilation don't cause errors.
@@ -96,7 +110,12 @@ pub fn make_method_registration(
 
             type Sig = #sig_tuple;
 
-            let method_name = StringName::from(#method_name_str);
+            let method_name = StringName::from(#registered_name);
+
+            // Evaluated once for registration, and again for each varcall omitting some of these arguments.
+            fn default_arguments() -> Vec<Variant> {
+                #default_args_call
+            }
 
             let varcall_func = #varcall_func;
             let ptrcall_func = #ptrcall_func;
@@ -114,7 +133,7 @@ pub fn make_method_registration(
                 &[
                     #( #param_ident_strs ),*
                 ],
-                Vec::new()
+                default_arguments()
                 )
             };
 
@@ -130,6 +149,37 @@ pub fn make_method_registration(
     }
 }
 
+/// Returns an associated function of the class that evaluates the `#[opt(default)]` values of a method, or nothing if it has none.
+///
+/// It is generated in an inherent `impl` block of the class, so that the default expressions can refer to `Self`.
+pub fn make_default_args_fn(func_definition: &FuncDefinition) -> TokenStream {
+    let default_arg_exprs = &func_definition.default_args;
+    if default_arg_exprs.is_empty() {
+        return TokenStream::new();
+    }
+
+    let signature_info = get_signature_info(&func_definition.func, func_definition.has_gd_self);
+    let default_arg_types =
+        &signature_info.param_types[signature_info.param_types.len() - default_arg_exprs.len()..];
+    let default_args_fn = make_default_args_fn_name(&signature_info.method_name);
+    let cfg_attrs = util::extract_cfg_attrs(&func_definition.external_attributes)
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    quote! {
+        #(#cfg_attrs)*
+        #[doc(hidden)]
+        fn #default_args_fn() -> ::std::vec::Vec<::godot::builtin::Variant> {
+            vec![
+                #( ::godot::builtin::meta::ToGodot::to_variant(&{
+                    let value: #default_arg_types = #default_arg_exprs;
+                    value
+                }) ),*
+            ]
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Implementation
 
@@ -284,7 +334,7 @@ fn make_varcall_func(
                 _method_data: *mut std::ffi::c_void,
                 instance_ptr: sys::GDExtensionClassInstancePtr,
                 args_ptr: *const sys::GDExtensionConstVariantPtr,
-                _arg_count: sys::GDExtensionInt,
+                arg_count: sys::GDExtensionInt,
                 ret: sys::GDExtensionVariantPtr,
                 err: *mut sys::GDExtensionCallError,
             ) {
@@ -377,10 +427,16 @@ fn make_varcall_invocation(
         <#sig_tuple as ::godot::builtin::meta::VarcallSignatureTuple>::in_varcall(
             instance_ptr,
             args_ptr,
+            arg_count,
             ret,
             err,
             #wrapped_method,
             #method_name_str,
+            default_arguments,
         )
     }
 }
+
+fn make_default_args_fn_name(method_name: &Ident) -> Ident {
+    format_ident!("__godot_default_args_{}", method_name)
+}
@@ -5,7 +5,7 @@
  */
 //! Parsing the `var` and `export` attributes on fields.
 
-use crate::class::{Field, FieldVar, Fields, GetSet, GetterSetterImpl, UsageFlags};
+use crate::class::{Field, FieldVar, Fields, GetSet, GetterSetterImpl, PropertyNotify, UsageFlags};
 use crate::util;
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
@@ -43,6 +43,8 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
 
     let mut getter_setter_impls = Vec::new();
     let mut export_tokens = Vec::new();
+    let mut has_shared_notify = false;
+    let mut named_notify_signals = Vec::new();
 
     for field in &fields.all_fields {
         let Field {
@@ -50,16 +52,14 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             ty: field_type,
             var,
             export,
+            export_usage,
+            notify,
             ..
         } = field;
 
-        // Ensure we add a var if the user only provided a `#[export]`.
+        // Ensure we add a var if the user only provided a `#[export]`; its usage flags are set below.
         let var = match (export, var) {
-            (Some(_), None) => Some(FieldVar {
-                usage_flags: UsageFlags::InferredExport,
-                ..Default::default()
-            }),
-
+            (Some(_), None) => Some(FieldVar::default()),
             (_, var) => var.clone(),
         };
 
@@ -77,13 +77,20 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             setter,
             mut hint,
             mut usage_flags,
+            rename,
         } = var;
 
+        let property_name = util::make_registered_name(
+            class_name,
+            &field_name,
+            rename.map(|rename| rename.to_string()).as_deref(),
+        );
+
         if let Some(export) = export {
             hint = export.to_field_hint();
 
             if usage_flags.is_inferred() {
-                usage_flags = UsageFlags::InferredExport;
+                usage_flags = export_usage.clone().unwrap_or(UsageFlags::InferredExport);
             }
         }
 
@@ -141,23 +148,75 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             },
         };
 
-        let getter_name = if let Some(getter_impl) = getter.to_impl(class_name, GetSet::Get, field)
-        {
-            let GetterSetterImpl {
-                function_name,
-                function_impl,
-                export_token,
-            } = getter_impl;
+        let notify = notify.as_ref().map(|notify| {
+            let (signal_name, emit) = match notify {
+                PropertyNotify::Shared => {
+                    has_shared_notify = true;
+                    let signal_name = shared_notify_signal_name(class_name);
+
+                    (
+                        signal_name,
+                        quote! {
+                            ToGodot::to_variant(&::godot::builtin::StringName::from(#property_name)),
+                            old_value,
+                            new_value
+                        },
+                    )
+                }
+                PropertyNotify::Named(signal) => {
+                    let signal_name =
+                        util::make_registered_name(class_name, &signal.to_string(), None);
+
+                    // Several properties of the same type can share a signal; it is registered once.
+                    if !named_notify_signals.contains(signal) {
+                        named_notify_signals.push(signal.clone());
+                        let value_type = quote! {
+                            <#field_type as ::godot::bind::property::Property>::Intermediate
+                        };
+
+                        export_tokens.push(make_signal_registration(
+                            class_name,
+                            &signal_name,
+                            &[("old_value", value_type.clone()), ("new_value", value_type)],
+                        ));
+                    }
 
-            getter_setter_impls.push(function_impl);
-            export_tokens.push(export_token);
+                    (signal_name, quote! { old_value, new_value })
+                }
+            };
 
-            function_name.to_string()
-        } else {
-            String::new()
-        };
+            let base_field = fields
+                .base_field
+                .as_ref()
+                .map(|field| &field.name)
+                .expect("#[export(notify)] without base field is rejected when parsing");
+
+            quote! {
+                self.#base_field.emit_signal(
+                    ::godot::builtin::StringName::from(#signal_name),
+                    &[#emit],
+                );
+            }
+        });
 
-        let setter_name = if let Some(setter_impl) = setter.to_impl(class_name, GetSet::Set, field)
+        let getter_name =
+            if let Some(getter_impl) = getter.to_impl(class_name, GetSet::Get, field, None) {
+                let GetterSetterImpl {
+                    function_name,
+                    function_impl,
+                    export_token,
+                } = getter_impl;
+
+                getter_setter_impls.push(function_impl);
+                export_tokens.push(export_token);
+
+                function_name.to_string()
+            } else {
+                String::new()
+            };
+
+        let setter_name = if let Some(setter_impl) =
+            setter.to_impl(class_name, GetSet::Set, field, notify.as_ref())
         {
             let GetterSetterImpl {
                 function_name,
@@ -173,6 +232,10 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             String::new()
         };
 
+        // Accessors are registered as methods, so they follow the same naming convention.
+        let getter_name = make_accessor_name(class_name, &getter_name);
+        let setter_name = make_accessor_name(class_name, &setter_name);
+
         export_tokens.push(quote! {
             use ::godot::sys::GodotFfi;
 
@@ -182,7 +245,7 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             let property_info = ::godot::builtin::meta::PropertyInfo {
                 variant_type: #field_variant_type,
                 class_name: #field_class_name,
-                property_name: #field_name.into(),
+                property_name: ::godot::builtin::StringName::from(#property_name),
                 hint,
                 hint_string,
                 usage,
@@ -205,6 +268,18 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
         });
     }
 
+    if has_shared_notify {
+        export_tokens.push(make_signal_registration(
+            class_name,
+            &shared_notify_signal_name(class_name),
+            &[
+                ("property", quote! { ::godot::builtin::StringName }),
+                ("old_value", quote! { ::godot::builtin::Variant }),
+                ("new_value", quote! { ::godot::builtin::Variant }),
+            ],
+        ));
+    }
+
     let enforce_godot_api_impl = if !export_tokens.is_empty() {
         quote! {
             const MUST_HAVE_GODOT_API_IMPL: () = <#class_name as ::godot::private::Cannot_export_without_godot_api_impl>::EXISTS;
@@ -231,3 +306,56 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
         }
     }
 }
+
+/// Registered name of a getter or setter; empty if the accessor is absent.
+fn make_accessor_name(class_name: &Ident, function_name: &str) -> TokenStream {
+    if function_name.is_empty() {
+        quote! { "" }
+    } else {
+        util::make_registered_name(class_name, function_name, None)
+    }
+}
+
+/// Registered name of the signal emitted by `#[export(notify)]` properties without a signal of their own.
+fn shared_notify_signal_name(class_name: &Ident) -> TokenStream {
+    util::make_registered_name(class_name, "property_changed", None)
+}
+
+/// Registers a signal of the class, with the given parameter names and types.
+fn make_signal_registration(
+    class_name: &Ident,
+    signal_name: &TokenStream,
+    params: &[(&str, TokenStream)],
+) -> TokenStream {
+    let class_name_obj = util::class_name_obj(class_name);
+    let param_count = params.len();
+    let param_names = params.iter().map(|(name, _)| name);
+    let param_types = params.iter().map(|(_, ty)| ty);
+    let signature_tuple = quote! { ((), #(#param_types),*) };
+    let indexes = 0..param_count;
+
+    quote! {
+        use ::godot::sys::GodotFfi;
+
+        let parameters_info: [::godot::builtin::meta::PropertyInfo; #param_count] = [
+            #(
+                <#signature_tuple as ::godot::builtin::meta::VarcallSignatureTuple>
+                    ::param_property_info(#indexes, #param_names),
+            )*
+        ];
+        let parameters_info_sys: [::godot::sys::GDExtensionPropertyInfo; #param_count] =
+            std::array::from_fn(|i| parameters_info[i].property_sys());
+
+        let signal_name = ::godot::builtin::StringName::from(#signal_name);
+
+        unsafe {
+            ::godot::sys::interface_fn!(classdb_register_extension_class_signal)(
+                ::godot::sys::get_library(),
+                #class_name_obj.string_sys(),
+                signal_name.string_sys(),
+                parameters_info_sys.as_ptr(),
+                ::godot::sys::GDExtensionInt::from(#param_count as i64),
+            );
+        }
+    }
+}
@@ -4,11 +4,14 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::{Ident, Punct, TokenStream};
-use quote::{format_ident, quote};
+use proc_macro2::{Ident, Punct, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
 use venial::{Declaration, NamedField, Struct, StructFields};
 
-use crate::class::{make_property_impl, Field, FieldExport, FieldVar, Fields};
+use crate::class::{
+    make_property_impl, Field, FieldExport, FieldVar, Fields, GetterSetter, PropertyNotify,
+    UsageFlags,
+};
 use crate::util::{bail, ident, KvParser};
 use crate::{util, ParseResult};
 
@@ -20,12 +23,67 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
     let struct_cfg = parse_struct_attributes(class)?;
     let fields = parse_fields(class)?;
 
-    let class_name = &class.name;
-    let class_name_str: String = struct_cfg
-        .rename
-        .map_or_else(|| class.name.clone(), |rename| rename)
-        .to_string();
-    let class_name_cstr = util::cstr_u8_slice(&class_name_str);
+    // Generic classes are registered once per instantiation, each under the name of its type alias.
+    let instances = match (&class.generic_params, &struct_cfg.instances) {
+        (None, None) => {
+            let class_name_str = struct_cfg
+                .rename
+                .as_ref()
+                .map_or_else(|| class.name.to_string(), |rename| rename.to_string());
+
+            vec![(class.name.clone(), class_name_str)]
+        }
+        (Some(generic_params), None) => {
+            return bail!(
+                generic_params,
+                "generic #[derive(GodotClass)] requires #[class(instances = (...))], listing type aliases of concrete instantiations"
+            )
+        }
+        (None, Some(_)) => {
+            return bail!(
+                &class.name,
+                "#[class(instances)] can only be used on generic structs"
+            )
+        }
+        (Some(_), Some(aliases)) => {
+            if struct_cfg.compat_rename.is_some() {
+                return bail!(
+                    &class.name,
+                    "#[class(compat_rename)] cannot be combined with #[class(instances)]"
+                );
+            }
+
+            if let Some(rename) = &struct_cfg.rename {
+                return bail!(
+                    rename,
+                    "#[class(rename)] cannot be combined with #[class(instances)]; each instance is named after its type alias"
+                );
+            }
+
+            aliases
+                .iter()
+                .map(|alias| (alias.clone(), alias.to_string()))
+                .collect()
+        }
+    };
+
+    let registrations = instances.iter().map(|(class_name, class_name_str)| {
+        make_class_registration(class_name, class_name_str, &struct_cfg, &fields)
+    });
+
+    Ok(quote! {
+        #( #registrations )*
+    })
+}
+
+/// Generates the `GodotClass` impl and all registration glue for one concrete class.
+fn make_class_registration(
+    class_name: &Ident,
+    class_name_str: &str,
+    struct_cfg: &ClassAttributes,
+    fields: &Fields,
+) -> TokenStream {
+    let class_name_cstr = util::cstr_u8_slice(class_name_str);
     let class_name_obj = util::class_name_obj(class_name);
 
     let base_ty = &struct_cfg.base_ty;
@@ -34,7 +92,7 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
     let inherits_macro = format_ident!("inherits_transitive_{}", base_ty);
 
     let prv = quote! { ::godot::private };
-    let godot_exports_impl = make_property_impl(class_name, &fields);
+    let godot_exports_impl = make_property_impl(class_name, fields);
 
     let editor_plugin = if struct_cfg.is_editor_plugin {
         quote! {
@@ -65,9 +123,43 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
         recreate_fn = quote! { None };
     };
 
-    let config_impl = make_config_impl(class_name, struct_cfg.is_tool);
+    let config_impl = make_config_impl(class_name, struct_cfg.is_tool, &struct_cfg.rename_all);
+    let is_instantiable = !struct_cfg.is_no_init;
+    let is_auto_registered = struct_cfg
+        .auto_register
+        .clone()
+        .unwrap_or_else(|| quote! { true });
+    let register_if = match &struct_cfg.register_if {
+        Some(condition) => quote! { Some(#condition) },
+        None => quote! { None },
+    };
+
+    // The alias is a class of its own, whose constructor creates an instance of the actual class. Objects therefore never have
+    // the alias as their class, and resources saved again use the current name.
+    let compat_alias = if let Some(old_name) = &struct_cfg.compat_rename {
+        let old_name_cstr = util::cstr_u8_slice(old_name);
 
-    Ok(quote! {
+        quote! {
+            ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
+                class_name: ::godot::builtin::meta::ClassName::from_ascii_cstr(#old_name_cstr),
+                component: #prv::PluginComponent::ClassDef {
+                    base_class_name: #base_class_name_obj,
+                    generated_create_fn: Some(#prv::callbacks::create::<#class_name>),
+                    generated_recreate_fn: None,
+                    free_fn: #prv::callbacks::free::<#class_name>,
+                    is_instantiable: true,
+                    is_auto_registered: #is_auto_registered,
+                    register_if: #register_if,
+                    crate_name: ::std::env!("CARGO_PKG_NAME"),
+                },
+                init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
+            });
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    quote! {
         unsafe impl ::godot::obj::GodotClass for #class_name {
             type Base = #base_class;
             type Declarer = ::godot::obj::dom::UserDomain;
@@ -90,14 +182,19 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
                 generated_create_fn: #create_fn,
                 generated_recreate_fn: #recreate_fn,
                 free_fn: #prv::callbacks::free::<#class_name>,
+                is_instantiable: #is_instantiable,
+                is_auto_registered: #is_auto_registered,
+                register_if: #register_if,
+                crate_name: ::std::env!("CARGO_PKG_NAME"),
             },
             init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
         });
 
         #editor_plugin
+        #compat_alias
 
         #prv::class_macros::#inherits_macro!(#class_name);
-    })
+    }
 }
 
 /// Checks at compile time that a function with the given name exists on `Self`.
@@ -116,9 +213,15 @@ pub fn make_existence_check(ident: &Ident) -> TokenStream {
 fn parse_struct_attributes(class: &Struct) -> ParseResult<ClassAttributes> {
     let mut base_ty = ident("RefCounted");
     let mut has_generated_init = false;
+    let mut is_no_init = false;
     let mut is_tool = false;
     let mut is_editor_plugin = false;
     let mut rename: Option<Ident> = None;
+    let mut compat_rename: Option<String> = None;
+    let mut instances: Option<Vec<Ident>> = None;
+    let mut rename_all = ident("SnakeCase");
+    let mut auto_register: Option<TokenStream> = None;
+    let mut register_if: Option<TokenStream> = None;
 
     // #[class] attribute on struct
     if let Some(mut parser) = KvParser::parse(&class.attributes, "class")? {
@@ -130,6 +233,13 @@ fn parse_struct_attributes(class: &Struct) -> ParseResult<ClassAttributes> {
             has_generated_init = true;
         }
 
+        if let Some(key) = parser.handle_alone_ident("no_init")? {
+            if has_generated_init {
+                return bail!(key, "#[class(no_init)] cannot be combined with `init`");
+            }
+            is_no_init = true;
+        }
+
         if parser.handle_alone("tool")? {
             is_tool = true;
         }
@@ -140,15 +250,70 @@ fn parse_struct_attributes(class: &Struct) -> ParseResult<ClassAttributes> {
         }
         rename = parser.handle_ident("rename")?;
 
+        if let Some(old_name) = parser.handle_string("compat_rename")? {
+            if is_no_init {
+                return bail!(
+                    parser.span(),
+                    "#[class(compat_rename)] cannot be combined with `no_init`; the alias must be able to create instances"
+                );
+            }
+
+            if !old_name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_')
+                || old_name.is_empty()
+            {
+                return bail!(
+                    parser.span(),
+                    "#[class(compat_rename)] must be a valid class name; got \"{old_name}\""
+                );
+            }
+
+            compat_rename = Some(old_name);
+        }
+
+        if let Some(convention) = parser.handle_string("rename_all")? {
+            rename_all = match convention.as_str() {
+                "snake_case" => ident("SnakeCase"),
+                "camelCase" => ident("CamelCase"),
+                "PascalCase" => ident("PascalCase"),
+                _ => {
+                    return bail!(
+                        parser.span(),
+                        "#[class(rename_all)] must be one of \"snake_case\", \"camelCase\", \"PascalCase\"; got \"{convention}\""
+                    )
+                }
+            };
+        }
+
+        if let Some(mut list) = parser.handle_list("instances")? {
+            let mut aliases = Vec::new();
+            while let Some(alias) = list.next_ident()? {
+                aliases.push(alias);
+            }
+            list.finish()?;
+
+            instances = Some(aliases);
+        }
+
+        auto_register = parser.handle_expr("auto_register")?;
+        register_if = parser.handle_expr("register_if")?;
+
         parser.finish()?;
     }
 
     Ok(ClassAttributes {
         base_ty,
         has_generated_init,
+        is_no_init,
         is_tool,
         is_editor_plugin,
         rename,
+        compat_rename,
+        instances,
+        rename_all,
+        auto_register,
+        register_if,
     })
 }
 
@@ -195,6 +360,40 @@ fn parse_fields(class: &Struct) -> ParseResult<Fields> {
 
         // #[export]
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export")? {
+            // `usage`, `notify`, accessors and `validate` are parsed first, since the export kind returns as soon as it recognizes a key.
+            if let Some(preset) = parser.handle_ident("usage")? {
+                field.export_usage = Some(UsageFlags::from_preset(&preset)?);
+            }
+
+            field.notify = match parser.handle_any("notify") {
+                None => None,
+                Some(None) => Some(PropertyNotify::Shared),
+                Some(Some(signal)) => Some(PropertyNotify::Named(signal.ident()?)),
+            };
+
+            // Unlike in #[var], an accessor that is not specified is generated, so the property stays readable and writable.
+            let getter = GetterSetter::parse(&mut parser, "get")?;
+            let setter = GetterSetter::parse(&mut parser, "set")?;
+            if !getter.is_omitted() || !setter.is_omitted() {
+                let or_generated = |accessor: GetterSetter| {
+                    if accessor.is_omitted() {
+                        GetterSetter::Generated
+                    } else {
+                        accessor
+                    }
+                };
+
+                field.var = Some(FieldVar {
+                    getter: or_generated(getter),
+                    setter: or_generated(setter),
+                    ..FieldVar::default()
+                });
+            }
+
+            if let Some(validate) = parser.handle_ident("validate")? {
+                field.validate = Some(validate);
+            }
+
             let export = FieldExport::new_from_kv(&mut parser)?;
             field.export = Some(export);
             parser.finish()?;
@@ -202,11 +401,40 @@ fn parse_fields(class: &Struct) -> ParseResult<Fields> {
 
         // #[var]
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "var")? {
-            let var = FieldVar::new_from_kv(&mut parser)?;
+            let mut var = FieldVar::new_from_kv(&mut parser)?;
+
+            if field.export_usage.is_some() && !var.usage_flags.is_inferred() {
+                return bail!(
+                    named_field.name,
+                    "usage flags specified in both #[export(usage)] and #[var]"
+                );
+            }
+
+            // Accessors from #[export(get, set)]; #[var] can still contribute hint, usage and rename.
+            if let Some(export_var) = field.var.take() {
+                if var.getter != GetterSetter::Generated || var.setter != GetterSetter::Generated {
+                    return bail!(
+                        named_field.name,
+                        "getter/setter specified in both #[export] and #[var]"
+                    );
+                }
+
+                var.getter = export_var.getter;
+                var.setter = export_var.setter;
+            }
+
             field.var = Some(var);
             parser.finish()?;
         }
 
+        if !is_base && is_base_type(&named_field.ty) {
+            return bail!(
+                &named_field.ty,
+                "field `{}` has type `Base<T>` but no #[base] attribute; did you mean to add #[base]?",
+                named_field.name
+            );
+        }
+
         // Exported or Rust-only fields
         if is_base {
             base_field = Some(field);
@@ -215,35 +443,119 @@ fn parse_fields(class: &Struct) -> ParseResult<Fields> {
         }
     }
 
+    // Checked after all fields are known, as the base field may come last.
+    for field in all_fields.iter().filter(|field| field.notify.is_some()) {
+        if base_field.is_none() {
+            return bail!(
+                &field.name,
+                "#[export(notify)] requires a #[base] field, through which the signal is emitted"
+            );
+        }
+
+        if let Some(var) = &field.var {
+            if var.setter != GetterSetter::Generated {
+                return bail!(
+                    &field.name,
+                    "#[export(notify)] requires a generated setter; a custom setter can emit the signal itself"
+                );
+            }
+        }
+    }
+
+    // A named signal is registered with the type of the first property using it, so all of them must have that type.
+    let mut named_notify_fields: Vec<(&Ident, &Field)> = vec![];
+    for field in all_fields.iter() {
+        let Some(PropertyNotify::Named(signal)) = &field.notify else {
+            continue;
+        };
+
+        match named_notify_fields.iter().find(|(name, _)| *name == signal) {
+            Some((_, first)) => {
+                let first_ty = first.ty.to_token_stream().to_string();
+                if first_ty != field.ty.to_token_stream().to_string() {
+                    return bail!(
+                        &field.name,
+                        "#[export(notify = {signal})]: property `{}` has a different type than `{}` (`{first_ty}`), which uses the same signal",
+                        field.name,
+                        first.name,
+                    );
+                }
+            }
+            None => named_notify_fields.push((signal, field)),
+        }
+    }
+
+    for field in all_fields.iter().filter(|field| field.validate.is_some()) {
+        if let Some(var) = &field.var {
+            if var.setter != GetterSetter::Generated {
+                return bail!(
+                    &field.name,
+                    "#[export(validate)] requires a generated setter; a custom setter can validate the value itself"
+                );
+            }
+        }
+    }
+
     Ok(Fields {
         all_fields,
         base_field,
     })
 }
 
+/// Whether the type is (a path ending in) `Base<...>`.
+fn is_base_type(ty: &venial::TyExpr) -> bool {
+    let Some(open) = ty
+        .tokens
+        .iter()
+        .position(|tt| matches!(tt, TokenTree::Punct(p) if p.as_char() == '<'))
+    else {
+        return false;
+    };
+
+    open > 0 && matches!(&ty.tokens[open - 1], TokenTree::Ident(id) if id == "Base")
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // General helpers
 
 struct ClassAttributes {
     base_ty: Ident,
     has_generated_init: bool,
+
+    /// Godot cannot construct the class; only Rust code can (e.g. through `Gd::with_base()`).
+    is_no_init: bool,
     is_tool: bool,
     is_editor_plugin: bool,
     rename: Option<Ident>,
+
+    /// Former class name, still accepted when loading scenes and resources.
+    compat_rename: Option<String>,
+
+    /// For generic classes: type aliases of the instantiations to register.
+    instances: Option<Vec<Ident>>,
+
+    /// Variant of `RenameAll` applied to registered names.
+    rename_all: Ident,
+
+    /// Boolean expression, evaluated in the user crate; `false` leaves registration to `register_user_class()`.
+    auto_register: Option<TokenStream>,
+
+    /// Path to a `fn() -> bool`, called at runtime before the class is auto-registered.
+    register_if: Option<TokenStream>,
 }
 
-fn make_godot_init_impl(class_name: &Ident, fields: Fields) -> TokenStream {
-    let base_init = if let Some(Field { name, .. }) = fields.base_field {
+fn make_godot_init_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
+    let base_init = if let Some(Field { name, .. }) = &fields.base_field {
         quote! { #name: base, }
     } else {
         TokenStream::new()
     };
 
-    let rest_init = fields.all_fields.into_iter().map(|field| {
-        let field_name = field.name;
-        let value_expr = match field.default {
+    let rest_init = fields.all_fields.iter().map(|field| {
+        let field_name = &field.name;
+        let value_expr = match &field.default {
             None => quote! { ::std::default::Default::default() },
-            Some(default) => default,
+            Some(default) => default.clone(),
         };
         quote! { #field_name: #value_expr, }
     });
@@ -260,13 +572,14 @@ fn make_godot_init_impl(class_name: &Ident, fields: Fields) -> TokenStream {
     }
 }
 
-fn make_config_impl(class_name: &Ident, is_tool: bool) -> TokenStream {
+fn make_config_impl(class_name: &Ident, is_tool: bool, rename_all: &Ident) -> TokenStream {
     quote! {
         impl #class_name {
             #[doc(hidden)]
             pub fn __config() -> ::godot::private::ClassConfig {
                 ::godot::private::ClassConfig {
                     is_tool: #is_tool,
+                    rename_all: ::godot::private::RenameAll::#rename_all,
                 }
             }
         }
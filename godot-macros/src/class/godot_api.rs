@@ -4,7 +4,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, TokenStream, TokenTree};
 use quote::quote;
 use quote::spanned::Spanned;
 use venial::{
@@ -12,12 +12,14 @@ use venial::{
     TyExpr,
 };
 
-use crate::class::{make_method_registration, make_virtual_method_callback, FuncDefinition};
+use crate::class::{
+    make_default_args_fn, make_method_registration, make_virtual_method_callback, FuncDefinition,
+};
 use crate::util;
 use crate::util::{bail, KvParser};
 
 pub fn attribute_godot_api(input_decl: Declaration) -> Result<TokenStream, Error> {
-    let decl = match input_decl {
+    let mut decl = match input_decl {
         Declaration::Impl(decl) => decl,
         _ => bail!(
             input_decl,
@@ -25,21 +27,52 @@ pub fn attribute_godot_api(input_decl: Declaration) -> Result<TokenStream, Error
         )?,
     };
 
-    if decl.impl_generic_params.is_some() {
-        bail!(
-            &decl,
-            "#[godot_api] currently does not support generic parameters",
-        )?;
-    }
+    let instances = parse_instances(&mut decl)?;
 
     if decl.self_ty.as_path().is_none() {
         return bail!(decl, "invalid Self type for #[godot_api] impl");
     };
 
     if decl.trait_ty.is_some() {
-        transform_trait_impl(decl)
+        transform_trait_impl(decl, instances)
     } else {
-        transform_inherent_impl(decl)
+        transform_inherent_impl(decl, instances)
+    }
+}
+
+/// Parses (and removes) the `#[godot_api(instances = (...))]` attribute.
+///
+/// For generic impls, returns the type aliases of the instantiations to register; otherwise `None`.
+fn parse_instances(decl: &mut Impl) -> Result<Option<Vec<Ident>>, Error> {
+    let mut instances = None;
+    if let Some(mut parser) = KvParser::parse(&decl.attributes, "godot_api")? {
+        if let Some(mut list) = parser.handle_list("instances")? {
+            let mut aliases = Vec::new();
+            while let Some(alias) = list.next_ident()? {
+                aliases.push(alias);
+            }
+            list.finish()?;
+
+            instances = Some(aliases);
+        }
+
+        parser.finish()?;
+    }
+
+    decl.attributes
+        .retain(|attr| !util::path_is_single(&attr.path, "godot_api"));
+
+    match (&decl.impl_generic_params, instances) {
+        (None, None) => Ok(None),
+        (Some(generic_params), None) => bail!(
+            generic_params,
+            "generic #[godot_api] impls require #[godot_api(instances = (...))], listing type aliases of concrete instantiations",
+        ),
+        (None, Some(_)) => bail!(
+            &decl.self_ty,
+            "#[godot_api(instances)] can only be used on generic impls",
+        ),
+        (Some(_), Some(aliases)) => Ok(Some(aliases)),
     }
 }
 
@@ -51,7 +84,9 @@ enum BoundAttrType {
         rename: Option<String>,
         has_gd_self: bool,
     },
-    Signal(AttributeValue),
+    Signal {
+        rename: Option<String>,
+    },
     Const(AttributeValue),
 }
 
@@ -62,8 +97,9 @@ struct BoundAttr {
 }
 
 impl BoundAttr {
-    fn bail<R>(self, msg: &str, method: &Function) -> Result<R, Error> {
-        bail!(&method.name, "#[{}]: {}", self.attr_name, msg)
+    /// Reports `msg` at `tokens`, which should be the offending part of the declaration rather than the whole function.
+    fn bail<R>(self, msg: &str, tokens: impl Spanned) -> Result<R, Error> {
+        bail!(tokens, "#[{}]: {}", self.attr_name, msg)
     }
 }
 
@@ -74,16 +110,46 @@ struct SignalDefinition {
 
     /// The signal's non-gdext attributes (all except #[signal]).
     external_attributes: Vec<Attribute>,
+
+    /// The name the signal will be exposed as in Godot. If `None`, the Rust function name is used.
+    rename: Option<String>,
 }
 
 /// Codegen for `#[godot_api] impl MyType`
-fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
-    let class_name = util::validate_impl(&decl, None, "godot_api")?;
-    let class_name_obj = util::class_name_obj(&class_name);
+fn transform_inherent_impl(
+    mut decl: Impl,
+    instances: Option<Vec<Ident>>,
+) -> Result<TokenStream, Error> {
+    let class_names = match instances {
+        Some(aliases) => aliases,
+        None => vec![util::validate_impl(&decl, None, "godot_api")?],
+    };
+
     let (funcs, signals) = process_godot_fns(&mut decl)?;
+    let consts = process_godot_constants(&mut decl)?;
+
+    let registrations = class_names
+        .iter()
+        .map(|class_name| make_inherent_registration(class_name, &funcs, &signals, &consts))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(quote! {
+        #decl
+        #( #registrations )*
+    })
+}
+
+/// Registration glue of `#[godot_api] impl MyType` for one concrete class.
+fn make_inherent_registration(
+    class_name: &Ident,
+    funcs: &[FuncDefinition],
+    signals: &[SignalDefinition],
+    consts: &[Constant],
+) -> Result<TokenStream, Error> {
+    let class_name_obj = util::class_name_obj(class_name);
 
     let mut signal_cfg_attrs: Vec<Vec<&Attribute>> = Vec::new();
-    let mut signal_name_strs: Vec<String> = Vec::new();
+    let mut signal_name_strs: Vec<TokenStream> = Vec::new();
     let mut signal_parameters_count: Vec<usize> = Vec::new();
     let mut signal_parameters: Vec<TokenStream> = Vec::new();
 
@@ -91,6 +157,7 @@ fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
         let SignalDefinition {
             signature,
             external_attributes,
+            rename,
         } = signal;
         let mut param_types: Vec<TyExpr> = Vec::new();
         let mut param_names: Vec<String> = Vec::new();
@@ -124,7 +191,11 @@ fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
                 .into_iter()
                 .collect(),
         );
-        signal_name_strs.push(signature.name.to_string());
+        signal_name_strs.push(util::make_registered_name(
+            class_name,
+            &signature.name.to_string(),
+            rename.as_deref(),
+        ));
         signal_parameters_count.push(param_names.len());
         signal_parameters.push(param_array_decl);
     }
@@ -132,10 +203,10 @@ fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
     let prv = quote! { ::godot::private };
 
     let methods_registration = funcs
-        .into_iter()
-        .map(|func_def| make_method_registration(&class_name, func_def));
+        .iter()
+        .map(|func_def| make_method_registration(class_name, func_def.clone()));
+    let default_args_fns = funcs.iter().map(make_default_args_fn);
 
-    let consts = process_godot_constants(&mut decl)?;
     let mut integer_constant_cfg_attrs = Vec::new();
     let mut integer_constant_names = Vec::new();
     let mut integer_constant_values = Vec::new();
@@ -184,7 +255,9 @@ fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
     };
 
     let result = quote! {
-        #decl
+        impl #class_name {
+            #( #default_args_fns )*
+        }
 
         impl ::godot::obj::cap::ImplementsGodotApi for #class_name {
             fn __register_methods() {
@@ -256,18 +329,24 @@ fn process_godot_fns(
             // Remaining code no longer has attribute -- rest stays
             method.attributes.remove(attr.index);
 
-            if method.qualifiers.tk_default.is_some()
-                || method.qualifiers.tk_const.is_some()
-                || method.qualifiers.tk_async.is_some()
-                || method.qualifiers.tk_unsafe.is_some()
-                || method.qualifiers.tk_extern.is_some()
-                || method.qualifiers.extern_abi.is_some()
-            {
-                return attr.bail("fn qualifiers are not allowed", method);
+            let qualifiers = &method.qualifiers;
+            let first_qualifier = [
+                &qualifiers.tk_default,
+                &qualifiers.tk_const,
+                &qualifiers.tk_async,
+                &qualifiers.tk_unsafe,
+                &qualifiers.tk_extern,
+            ]
+            .into_iter()
+            .flatten()
+            .next();
+
+            if let Some(qualifier) = first_qualifier {
+                return attr.bail("fn qualifiers are not allowed", qualifier);
             }
 
-            if method.generic_params.is_some() {
-                return attr.bail("generic fn parameters are not supported", method);
+            if let Some(generic_params) = &method.generic_params {
+                return attr.bail("generic fn parameters are not supported", generic_params);
             }
 
             match &attr.ty {
@@ -275,12 +354,15 @@ fn process_godot_fns(
                     rename,
                     has_gd_self,
                 } => {
+                    validate_func_params(method, *has_gd_self)?;
+                    let default_args = extract_param_defaults(method, *has_gd_self)?;
+
                     let external_attributes = method.attributes.clone();
                     // Signatures are the same thing without body
                     let mut sig = util::reduce_to_signature(method);
                     if *has_gd_self {
                         if sig.params.is_empty() {
-                            return attr.bail("with attribute key `gd_self`, the method must have a first parameter of type Gd<Self>", method);
+                            return attr.bail("with attribute key `gd_self`, the method must have a first parameter of type Gd<Self>", &method.name);
                         } else {
                             sig.params.inner.remove(0);
                         }
@@ -290,11 +372,12 @@ fn process_godot_fns(
                         external_attributes,
                         rename: rename.clone(),
                         has_gd_self: *has_gd_self,
+                        default_args,
                     });
                 }
-                BoundAttrType::Signal(ref _attr_val) => {
-                    if method.return_ty.is_some() {
-                        return attr.bail("return types are not supported", method);
+                BoundAttrType::Signal { rename } => {
+                    if let Some(return_ty) = &method.return_ty {
+                        return attr.bail("return types are not supported", return_ty);
                     }
                     let external_attributes = method.attributes.clone();
                     let sig = util::reduce_to_signature(method);
@@ -302,13 +385,14 @@ fn process_godot_fns(
                     signal_definitions.push(SignalDefinition {
                         signature: sig,
                         external_attributes,
+                        rename: rename.clone(),
                     });
                     removed_indexes.push(index);
                 }
                 BoundAttrType::Const(_) => {
                     return attr.bail(
                         "#[constant] can only be used on associated constant",
-                        method,
+                        &method.name,
                     )
                 }
             }
@@ -324,6 +408,87 @@ fn process_godot_fns(
     Ok((func_definitions, signal_definitions))
 }
 
+/// Rejects parameters that cannot be registered, pointing at the parameter itself.
+fn validate_func_params(method: &Function, has_gd_self: bool) -> Result<(), Error> {
+    for (param, _) in method.params.inner.iter() {
+        match param {
+            FnParam::Receiver(receiver) => {
+                if has_gd_self {
+                    return bail!(
+                        &receiver.tk_self,
+                        "#[func]: with attribute key `gd_self`, the method cannot have a `self` receiver; \
+                        did you mean `this: Gd<Self>`?"
+                    );
+                }
+
+                if receiver.tk_ref.is_none() {
+                    return bail!(
+                        &receiver.tk_self,
+                        "#[func]: `self` by value is not supported; did you mean `&self` or `&mut self`?"
+                    );
+                }
+            }
+            FnParam::Typed(typed) => {
+                if let Some(TokenTree::Ident(first)) = typed.ty.tokens.first() {
+                    if first == "impl" {
+                        return bail!(
+                            &typed.ty,
+                            "#[func]: `impl Trait` parameters are not supported; use a concrete type"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Removes `#[opt(default = ...)]` from the parameters of a `#[func]`, returning the default values of the trailing parameters.
+fn extract_param_defaults(
+    method: &mut Function,
+    has_gd_self: bool,
+) -> Result<Vec<TokenStream>, Error> {
+    let mut default_args = vec![];
+
+    for (index, (param, _)) in method.params.inner.iter_mut().enumerate() {
+        let FnParam::Typed(typed) = param else {
+            continue;
+        };
+
+        let default = match KvParser::parse(&typed.attributes, "opt")? {
+            Some(mut parser) => {
+                let expr = parser.handle_expr_required("default")?;
+                parser.finish()?;
+                Some(expr)
+            }
+            None => None,
+        };
+        typed
+            .attributes
+            .retain(|attr| !util::path_is_single(&attr.path, "opt"));
+
+        match default {
+            Some(_) if has_gd_self && index == 0 => {
+                return bail!(
+                    &typed.name,
+                    "#[opt]: the `Gd<Self>` parameter of a `gd_self` method cannot have a default value"
+                );
+            }
+            Some(expr) => default_args.push(expr),
+            None if !default_args.is_empty() => {
+                return bail!(
+                    &typed.name,
+                    "#[opt]: parameters without default value cannot follow parameters with one"
+                );
+            }
+            None => {}
+        }
+    }
+
+    Ok(default_args)
+}
+
 fn process_godot_constants(decl: &mut Impl) -> Result<Vec<Constant>, Error> {
     let mut constant_signatures = vec![];
 
@@ -340,7 +505,7 @@ fn process_godot_constants(decl: &mut Impl) -> Result<Vec<Constant>, Error> {
                 BoundAttrType::Func { .. } => {
                     return bail!(constant, "#[func] can only be used on functions")
                 }
-                BoundAttrType::Signal(_) => {
+                BoundAttrType::Signal { .. } => {
                     return bail!(constant, "#[signal] can only be used on functions")
                 }
                 BoundAttrType::Const(_) => {
@@ -372,8 +537,6 @@ where
 
         let new_found = match attr_name {
             name if name == "func" => {
-                // TODO you-win (August 8, 2023): handle default values here as well?
-
                 // Safe unwrap since #[func] must be present if we got to this point
                 let mut parser = KvParser::parse(attributes, "func")?.unwrap();
 
@@ -393,10 +556,14 @@ where
                 // TODO once parameters are supported, this should probably be moved to the struct definition
                 // E.g. a zero-sized type Signal<(i32, String)> with a provided emit(i32, String) method
                 // This could even be made public (callable on the struct obj itself)
+                let mut parser = KvParser::parse(attributes, "signal")?.unwrap();
+                let rename = parser.handle_expr("rename")?.map(|ts| ts.to_string());
+                parser.finish()?;
+
                 BoundAttr {
                     attr_name: attr_name.clone(),
                     index,
-                    ty: BoundAttrType::Signal(attr.value.clone()),
+                    ty: BoundAttrType::Signal { rename },
                 }
             }
             name if name == "constant" => BoundAttr {
@@ -449,9 +616,39 @@ fn convert_to_match_expression_or_none(tokens: Option<TokenStream>) -> TokenStre
 }
 
 /// Codegen for `#[godot_api] impl GodotExt for MyType`
-fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
-    let (class_name, trait_name) = util::validate_trait_impl_virtual(&original_impl, "godot_api")?;
-    let class_name_obj = util::class_name_obj(&class_name);
+fn transform_trait_impl(
+    original_impl: Impl,
+    instances: Option<Vec<Ident>>,
+) -> Result<TokenStream, Error> {
+    let (class_names, trait_name) = match instances {
+        Some(aliases) => {
+            let trait_name = util::validate_virtual_trait(&original_impl, "godot_api")?;
+            (aliases, trait_name)
+        }
+        None => {
+            let (class_name, trait_name) =
+                util::validate_trait_impl_virtual(&original_impl, "godot_api")?;
+            (vec![class_name], trait_name)
+        }
+    };
+
+    let registrations = class_names
+        .iter()
+        .map(|class_name| make_trait_registration(&original_impl, class_name, &trait_name));
+
+    Ok(quote! {
+        #original_impl
+        #( #registrations )*
+    })
+}
+
+/// Registration glue of `#[godot_api] impl GodotExt for MyType` for one concrete class.
+fn make_trait_registration(
+    original_impl: &Impl,
+    class_name: &Ident,
+    trait_name: &Ident,
+) -> TokenStream {
+    let class_name_obj = util::class_name_obj(class_name);
 
     let mut godot_init_impl = TokenStream::new();
     let mut to_string_impl = TokenStream::new();
@@ -617,7 +814,7 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
 
     let virtual_method_callbacks: Vec<TokenStream> = virtual_methods
         .iter()
-        .map(|method| make_virtual_method_callback(&class_name, method))
+        .map(|method| make_virtual_method_callback(class_name, method))
         .collect();
 
     // Use 'match' as a way to only emit 'Some(...)' if the given cfg attrs allow.
@@ -631,8 +828,7 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
     let to_string_fn = convert_to_match_expression_or_none(to_string_fn);
     let on_notification_fn = convert_to_match_expression_or_none(on_notification_fn);
 
-    let result = quote! {
-        #original_impl
+    quote! {
         #godot_init_impl
         #to_string_impl
         #on_notification_impl
@@ -670,7 +866,5 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
             },
             init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
         });
-    };
-
-    Ok(result)
+    }
 }
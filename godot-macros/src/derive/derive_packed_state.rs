@@ -0,0 +1,119 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use venial::{Declaration, StructFields};
+
+use crate::util::{bail, decl_get_info, DeclInfo, KvParser};
+use crate::ParseResult;
+
+pub fn derive_packed_state(decl: Declaration) -> ParseResult<TokenStream> {
+    let DeclInfo {
+        where_,
+        generic_params,
+        name,
+        ..
+    } = decl_get_info(&decl);
+
+    let struct_ = match decl {
+        Declaration::Struct(s) => s,
+        Declaration::Enum(e) => {
+            return bail!(e.tk_enum, "PackedState can only be derived on structs")
+        }
+        _ => unreachable!(),
+    };
+
+    let mut version = 0;
+    if let Some(mut parser) = KvParser::parse(&struct_.attributes, "packed")? {
+        if let Some(value) = parser.handle_usize("version")? {
+            let Ok(value) = u8::try_from(value) else {
+                return bail!(parser.span(), "packed version must be in 0..=255");
+            };
+            version = value;
+        }
+        parser.finish()?;
+    }
+
+    let fields = match struct_.fields {
+        StructFields::Named(fields) => fields,
+        _ => {
+            return bail!(
+                struct_.name,
+                "PackedState can only be derived on structs with named fields"
+            )
+        }
+    };
+
+    let mut sizes = Vec::new();
+    let mut writers = Vec::new();
+    let mut readers = Vec::new();
+    let mut initializers = Vec::new();
+    for (field, _) in fields.fields.inner {
+        let field_name = field.name;
+
+        if let Some(mut parser) = KvParser::parse(&field.attributes, "packed")? {
+            let skip = parser.handle_alone("skip")?;
+            parser.finish()?;
+
+            if skip {
+                initializers.push(quote! {
+                    #field_name: ::std::default::Default::default()
+                });
+                continue;
+            }
+        }
+
+        let field_ty = field.ty;
+        let field_str = field_name.to_string();
+        let read = format_ident!("__read_{}", field_name);
+        let packed_field = quote! { <#field_ty as ::godot::engine::PackedField> };
+
+        sizes.push(quote! { #packed_field::SIZE });
+
+        writers.push(quote! {
+            #packed_field::write(&self.#field_name, bytes);
+        });
+
+        readers.push(quote! {
+            let #read = {
+                let (field_bytes, rest) = bytes.split_at(#packed_field::SIZE);
+                bytes = rest;
+                #packed_field::read(field_bytes).ok_or(
+                    ::godot::engine::PackedStateError::InvalidField { field: #field_str }
+                )?
+            };
+        });
+
+        initializers.push(quote! { #field_name: #read });
+    }
+
+    let gen = generic_params.as_ref().map(|x| x.as_inline_args());
+
+    Ok(quote! {
+        impl #generic_params ::godot::engine::PackedState for #name #gen #where_ {
+            const VERSION: u8 = #version;
+            const SIZE: usize = 1 #( + #sizes )*;
+
+            #[allow(unused_variables)]
+            fn pack_fields(&self, bytes: &mut ::std::vec::Vec<u8>) {
+                #( #writers )*
+            }
+
+            #[allow(unused_mut)]
+            fn unpack_fields(
+                mut bytes: &[u8],
+            ) -> ::std::result::Result<Self, ::godot::engine::PackedStateError> {
+                #( #readers )*
+                let _ = bytes;
+
+                Ok(Self {
+                    #( #initializers, )*
+                })
+            }
+        }
+    })
+}
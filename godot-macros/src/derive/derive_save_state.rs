@@ -0,0 +1,101 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use venial::{Declaration, StructFields};
+
+use crate::util::{bail, decl_get_info, DeclInfo, KvParser};
+use crate::ParseResult;
+
+pub fn derive_save_state(decl: Declaration) -> ParseResult<TokenStream> {
+    let DeclInfo {
+        where_,
+        generic_params,
+        name,
+        ..
+    } = decl_get_info(&decl);
+
+    let struct_ = match decl {
+        Declaration::Struct(s) => s,
+        Declaration::Enum(e) => {
+            return bail!(e.tk_enum, "SaveState can only be derived on structs")
+        }
+        _ => unreachable!(),
+    };
+
+    let fields = match struct_.fields {
+        StructFields::Named(fields) => fields,
+        _ => {
+            return bail!(
+                struct_.name,
+                "SaveState can only be derived on structs with named fields"
+            )
+        }
+    };
+
+    let mut savers = Vec::new();
+    let mut loaders = Vec::new();
+    let mut assigners = Vec::new();
+    for (field, _) in fields.fields.inner {
+        let Some(mut parser) = KvParser::parse(&field.attributes, "save")? else {
+            continue;
+        };
+
+        let key = parser
+            .handle_string("key")?
+            .unwrap_or_else(|| field.name.to_string());
+        parser.finish()?;
+
+        let field_name = field.name;
+        let field_ty = field.ty;
+        let loaded = format_ident!("__loaded_{}", field_name);
+
+        savers.push(quote! {
+            state.set(#key, ::godot::builtin::meta::ToGodot::to_variant(&self.#field_name));
+        });
+
+        // Convert everything first, so that a bad value leaves the object untouched.
+        loaders.push(quote! {
+            let #loaded = match state.get(#key) {
+                Some(value) => Some(value.try_to::<#field_ty>().map_err(|error| {
+                    ::godot::engine::SaveStateError::InvalidValue {
+                        key: ::std::string::String::from(#key),
+                        error,
+                    }
+                })?),
+                None => None,
+            };
+        });
+
+        assigners.push(quote! {
+            if let Some(value) = #loaded {
+                self.#field_name = value;
+            }
+        });
+    }
+
+    let gen = generic_params.as_ref().map(|x| x.as_inline_args());
+
+    Ok(quote! {
+        impl #generic_params ::godot::engine::SaveState for #name #gen #where_ {
+            fn save_state(&self) -> ::godot::builtin::Dictionary {
+                let mut state = ::godot::builtin::Dictionary::new();
+                #( #savers )*
+                state
+            }
+
+            fn load_state(
+                &mut self,
+                state: &::godot::builtin::Dictionary,
+            ) -> ::std::result::Result<(), ::godot::engine::SaveStateError> {
+                #( #loaders )*
+                #( #assigners )*
+                Ok(())
+            }
+        }
+    })
+}
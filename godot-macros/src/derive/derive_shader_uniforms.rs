@@ -0,0 +1,76 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use venial::{Declaration, StructFields};
+
+use crate::util::{bail, decl_get_info, DeclInfo, KvParser};
+use crate::ParseResult;
+
+pub fn derive_shader_uniforms(decl: Declaration) -> ParseResult<TokenStream> {
+    let DeclInfo {
+        where_,
+        generic_params,
+        name,
+        ..
+    } = decl_get_info(&decl);
+
+    let struct_ = match decl {
+        Declaration::Struct(s) => s,
+        Declaration::Enum(e) => {
+            return bail!(e.tk_enum, "ShaderUniforms can only be derived on structs")
+        }
+        _ => unreachable!(),
+    };
+
+    let fields = match struct_.fields {
+        StructFields::Named(fields) => fields,
+        _ => {
+            return bail!(
+                struct_.name,
+                "ShaderUniforms can only be derived on structs with named fields"
+            )
+        }
+    };
+
+    let mut setters = Vec::new();
+    for (field, _) in fields.fields.inner {
+        let field_name = field.name;
+        let mut uniform_name = quote! { stringify!(#field_name) };
+
+        if let Some(mut parser) = KvParser::parse(&field.attributes, "uniform")? {
+            if parser.handle_alone("skip")? {
+                parser.finish()?;
+                continue;
+            }
+
+            if let Some(name) = parser.handle_expr("name")? {
+                uniform_name = name;
+            }
+
+            parser.finish()?;
+        }
+
+        setters.push(quote! {
+            ::godot::engine::ShaderMaterialExt::set_parameter_typed(
+                material,
+                #uniform_name,
+                ::godot::builtin::meta::ToGodot::to_variant(&self.#field_name),
+            );
+        });
+    }
+
+    let gen = generic_params.as_ref().map(|x| x.as_inline_args());
+
+    Ok(quote! {
+        impl #generic_params ::godot::engine::ShaderUniforms for #name #gen #where_ {
+            fn apply_to<M: ::godot::engine::ShaderMaterialExt + ?Sized>(&self, material: &mut M) {
+                #( #setters )*
+            }
+        }
+    })
+}
@@ -9,11 +9,17 @@
 mod derive_export;
 mod derive_from_variant;
 mod derive_godot_convert;
+mod derive_packed_state;
 mod derive_property;
+mod derive_save_state;
+mod derive_shader_uniforms;
 mod derive_to_variant;
 
 pub(crate) use derive_export::*;
 pub(crate) use derive_from_variant::*;
 pub(crate) use derive_godot_convert::*;
+pub(crate) use derive_packed_state::*;
 pub(crate) use derive_property::*;
+pub(crate) use derive_save_state::*;
+pub(crate) use derive_shader_uniforms::*;
 pub(crate) use derive_to_variant::*;
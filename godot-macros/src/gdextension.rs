@@ -8,7 +8,7 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use venial::Declaration;
 
-use crate::util::{bail, ident, validate_impl, KvParser};
+use crate::util::{bail, validate_impl, KvParser};
 use crate::ParseResult;
 
 pub fn attribute_gdextension(decl: Declaration) -> ParseResult<TokenStream> {
@@ -28,30 +28,54 @@ pub fn attribute_gdextension(decl: Declaration) -> ParseResult<TokenStream> {
     let drained_attributes = std::mem::take(&mut impl_decl.attributes);
     let mut parser = KvParser::parse_required(&drained_attributes, "gdextension", &impl_decl)?;
     let entry_point = parser.handle_ident("entry_point")?;
+    let entry_symbol = parser.handle_string("entry_symbol")?;
     parser.finish()?;
 
-    let entry_point = entry_point.unwrap_or_else(|| ident("gdext_rust_init"));
+    let entry_symbol = match (entry_point, entry_symbol) {
+        (Some(entry_point), None) => entry_point.to_string(),
+        (None, Some(entry_symbol)) => {
+            if entry_symbol.is_empty() || entry_symbol.contains(char::is_whitespace) {
+                return bail!(
+                    impl_decl.tk_impl,
+                    "`entry_symbol` must be a non-empty symbol name without whitespace"
+                );
+            }
+            entry_symbol
+        }
+        (None, None) => "gdext_rust_init".to_string(),
+        (Some(entry_point), Some(_)) => {
+            return bail!(
+                entry_point,
+                "`entry_point` and `entry_symbol` cannot be specified at the same time"
+            )
+        }
+    };
+
     let impl_ty = &impl_decl.self_ty;
 
+    // The function itself lives in an anonymous scope, so that several #[gdextension] impls (with different symbols) can coexist
+    // in the same module. `export_name` makes it reachable by Godot regardless of that scope.
     Ok(quote! {
         #impl_decl
 
-        #[no_mangle]
-        unsafe extern "C" fn #entry_point(
-            interface_or_get_proc_address: ::godot::sys::InitCompat,
-            library: ::godot::sys::GDExtensionClassLibraryPtr,
-            init: *mut ::godot::sys::GDExtensionInitialization,
-        ) -> ::godot::sys::GDExtensionBool {
-            ::godot::init::__gdext_load_library::<#impl_ty>(
-                interface_or_get_proc_address,
-                library,
-                init
-            )
-        }
+        const _: () = {
+            #[export_name = #entry_symbol]
+            unsafe extern "C" fn __gdext_entry_point(
+                interface_or_get_proc_address: ::godot::sys::InitCompat,
+                library: ::godot::sys::GDExtensionClassLibraryPtr,
+                init: *mut ::godot::sys::GDExtensionInitialization,
+            ) -> ::godot::sys::GDExtensionBool {
+                ::godot::init::__gdext_load_library::<#impl_ty>(
+                    interface_or_get_proc_address,
+                    library,
+                    init
+                )
+            }
 
-        fn __static_type_check() {
-            // Ensures that the init function matches the signature advertised in FFI header
-            let _unused: ::godot::sys::GDExtensionInitializationFunction = Some(#entry_point);
-        }
+            fn __static_type_check() {
+                // Ensures that the init function matches the signature advertised in FFI header
+                let _unused: ::godot::sys::GDExtensionInitializationFunction = Some(__gdext_entry_point);
+            }
+        };
     })
 }
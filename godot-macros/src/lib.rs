@@ -9,6 +9,7 @@ mod class;
 mod derive;
 mod gdextension;
 mod itest;
+mod res_path;
 mod util;
 
 use proc_macro::TokenStream;
@@ -70,6 +71,26 @@ use crate::util::ident;
 /// # }
 /// ```
 ///
+/// Some objects cannot be meaningfully constructed without arguments, for example handles whose invariants are established by
+/// a Rust factory function. Annotate those with `#[class(no_init)]`: Godot then treats the class as abstract, so it can neither
+/// be created in the editor nor through `ClassDB.instantiate()` or `MyStruct.new()`. Rust code creates instances with
+/// `Gd::with_base()` or `Gd::new()`. Providing an `init` function in addition is an error.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(no_init, base=RefCounted)]
+/// struct FileHandle {
+///     path: GodotString,
+/// }
+///
+/// impl FileHandle {
+///     fn open(path: GodotString) -> Gd<Self> {
+///         Gd::new(Self { path })
+///     }
+/// }
+/// ```
+///
 /// # Inheritance
 ///
 /// Unlike C++, Rust doesn't really have inheritance, but the GDExtension API lets us "inherit"
@@ -302,6 +323,133 @@ use crate::util::ident;
 /// impl MyStruct {}
 /// ```
 ///
+/// Fields of type `Gd<T>` or `Option<Gd<T>>` are exported with a resource or node picker, depending on whether `T` inherits
+/// `Resource` or `Node`. Exported nodes are restricted to `T` (and derived classes) in the inspector, and are stored in the scene as
+/// paths relative to the owning node, so designers can wire up references without the class resolving `NodePath`s by hand.
+/// This is only supported by Godot for classes inheriting `Node`:
+///
+/// ```
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Turret {
+///     // Shown as a node picker accepting Node2D and derived classes.
+///     #[export]
+///     target: Option<Gd<Node2D>>,
+/// }
+///
+/// #[godot_api]
+/// impl Turret {}
+/// ```
+///
+/// For the most common combinations of usage flags, both `#[var]` and `#[export]` accept a `usage` preset instead:
+///
+/// - `usage = storage`: saved with the scene or resource, but not shown in the inspector.
+/// - `usage = editor`: shown in the inspector, but not saved.
+/// - `usage = no_instance_state`: default usage, but instanced scenes do not store the value as an override.
+/// - `usage = internal`: saved, but hidden from the inspector and the class reference.
+///
+/// A plain `#[var]` without `#[export]` already uses storage-only usage, so such fields are saved and accessible from GDScript,
+/// without appearing in the inspector.
+///
+/// ```
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// struct MyStruct {
+///     // Edited in the inspector, but computed at runtime instead of being saved.
+///     #[export(range = (0.0, 1.0), usage = editor)]
+///     preview_weight: f32,
+///
+///     #[var(usage = internal)]
+///     cache_version: i64,
+/// }
+///
+/// #[godot_api]
+/// impl MyStruct {}
+/// ```
+///
+/// ## Change notifications
+///
+/// With `#[export(notify)]`, the generated setter emits a signal whenever it changes the value, so that UI and other listeners can
+/// react without polling and without hand-written setters. By default, the class gets a `property_changed(property, old_value,
+/// new_value)` signal, shared by all such properties; `#[export(notify = signal_name)]` instead emits a dedicated
+/// `signal_name(old_value, new_value)` signal, with parameters of the property's type. Several properties can use the same named signal
+/// only if they have the same type.
+///
+/// ```
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     // Emits `property_changed("health", old, new)`.
+///     #[export(notify, range = (0.0, 100.0))]
+///     health: f64,
+///
+///     // Emits `nickname_changed(old, new)`.
+///     #[export(notify = nickname_changed)]
+///     nickname: GodotString,
+///
+///     #[base]
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Player {}
+/// ```
+///
+/// The signal is emitted through the `#[base]` field, which is therefore required, and only if the new value differs from the old
+/// one. This needs the generated setter, so `notify` cannot be combined with `#[var(set = ...)]`. The signals are registered
+/// automatically and must not be declared with `#[signal]` as well.
+///
+/// Since the setter still has `&mut self` while the signal is emitted, connected handlers must not access the same object again
+/// (that would panic because it is already bound); use the values passed to the handler, or connect with `CONNECT_DEFERRED`.
+///
+/// ## Validation
+///
+/// `#[export(validate = fn_name)]` passes every value assigned from Godot -- the inspector, GDScript, `Object::set()`, loading a
+/// scene -- through a method of the class before the generated setter stores it. The method receives the new value and returns
+/// the value to store, e.g. clamped, or `None` to reject it and keep the current value:
+///
+/// ```
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     #[export(validate = clamp_health, range = (0.0, 100.0))]
+///     health: f64,
+///
+///     #[export(validate = reject_empty)]
+///     nickname: GodotString,
+///
+///     #[base]
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Player {}
+///
+/// impl Player {
+///     fn clamp_health(&self, health: f64) -> Option<f64> {
+///         Some(health.clamp(0.0, 100.0))
+///     }
+///
+///     fn reject_empty(&self, nickname: GodotString) -> Option<GodotString> {
+///         (!nickname.is_empty()).then_some(nickname)
+///     }
+/// }
+/// ```
+///
+/// The validator does not need `#[func]`. Validation happens in the generated `set_health()`, before `notify`, so rejected values
+/// emit no signal. Rust code that assigns the field directly is not validated; call the setter instead.
+///
+/// For logic beyond that, `#[export]` also accepts `get = ...` and `set = ...` like `#[var]`, pointing to `#[func]` methods of
+/// the class. An accessor that is not specified is generated, so `#[export(set = set_health)]` keeps the property readable.
+/// `validate` needs the generated setter: a custom setter is expected to check the value itself.
+///
 ///
 /// # Signals
 ///
@@ -333,6 +481,66 @@ use crate::util::ident;
 /// This should usually be combined with `#[class(tool)]` so that the code you write will actually run in the
 /// editor.
 ///
+/// # Registration
+///
+/// Classes are registered with Godot automatically when the extension is loaded, no matter which crate of the dependency graph
+/// defines them. Crates providing classes to others (e.g. plugin libraries) can opt out with `auto_register = false`; consumers then
+/// pick the classes they need with [`register_user_class()`](../init/fn.register_user_class.html), called from
+/// `ExtensionLibrary::on_level_init()`. The value can be any constant boolean expression, which makes it possible to tie
+/// registration to a cargo feature of the defining crate:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node, auto_register = cfg!(feature = "debug-overlay"))]
+/// pub struct DebugOverlay {}
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node, auto_register = false)]
+/// pub struct Minimap {}
+///
+/// struct MyExtension;
+///
+/// #[gdextension]
+/// unsafe impl ExtensionLibrary for MyExtension {
+///     fn on_level_init(level: InitLevel) {
+///         if level == InitLevel::Scene {
+///             godot::init::register_user_class::<Minimap>();
+///         }
+///     }
+/// }
+/// ```
+///
+/// Conditions that are only known at runtime go into `register_if`, a path to a `fn() -> bool`. It is called when the class's
+/// initialization level is loaded, before the class is registered, so engine singletons are available to decide whether the
+/// game runs in the editor, which feature tags the export has, or which command-line arguments were passed. Classes for which
+/// it returns `false` do not exist in Godot, so they cannot be found in scenes, and subclasses need the same condition.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::engine::{cmdline_user_args, has_feature, Engine};
+///
+/// fn is_debug_build() -> bool {
+///     Engine::singleton().is_editor_hint() || has_feature("debug")
+/// }
+///
+/// fn has_cheats_flag() -> bool {
+///     cmdline_user_args().iter().any(|arg| arg == "--cheats")
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node, register_if = is_debug_build)]
+/// pub struct LevelInspector {}
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node, register_if = has_cheats_flag)]
+/// pub struct CheatConsole {}
+/// ```
+///
+/// The condition only applies to automatic registration; `register_user_class()` registers the class regardless.
+///
+/// [`registered_classes()`](../init/fn.registered_classes.html) lists the classes registered so far, together with their crates.
+///
 /// # Class Renaming
 ///
 /// You may want to have structs with the same name. With Rust, this is allowed using `mod`. However in GDScript,
@@ -356,6 +564,101 @@ use crate::util::ident;
 /// ```
 ///
 /// These classes will appear in the Godot editor and GDScript as "AnimalToad" or "NpcToad".
+///
+/// Scenes and resources store the class name of each object, so renaming a class that is already used in a project breaks
+/// loading them. To keep the old name working, declare it with `compat_rename`:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// // Was `struct Enemy` before.
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node2D, compat_rename = "Enemy")]
+/// struct Opponent {}
+/// ```
+///
+/// The old name is registered as an alias class, whose instances are created as `Opponent`. Scenes saved with `Enemy` load, and
+/// are saved as `Opponent` from then on. The alias is still visible in the editor's class list, and scripts declaring
+/// `extends Enemy` need to be updated. It requires the class to be instantiable (not `no_init`), and is only registered
+/// automatically, not through `register_user_class()`.
+///
+/// # Naming conventions
+///
+/// By default, methods, properties and signals keep their Rust (snake_case) names in Godot. APIs consumed by tooling that
+/// mandates another convention can set `rename_all` to `"camelCase"` or `"PascalCase"`, which converts all registered names of
+/// the class. Individual items can override that with `#[func(rename = ...)]`, `#[var(rename = ...)]` or `#[signal(rename = ...)]`.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node, rename_all = "camelCase")]
+/// struct Inventory {
+///     // Property "maxItems", accessors "getMaxItems" and "setMaxItems".
+///     #[var]
+///     max_items: i32,
+/// }
+///
+/// #[godot_api]
+/// impl Inventory {
+///     // Signal "itemAdded".
+///     #[signal]
+///     fn item_added();
+///
+///     // Method "addItem".
+///     #[func]
+///     fn add_item(&mut self) {}
+///
+///     // Method "remove_all", as explicitly renamed.
+///     #[func(rename = remove_all)]
+///     fn clear(&mut self) {}
+/// }
+/// ```
+///
+/// # Generic classes
+///
+/// Godot has no notion of generics, so a generic struct cannot be registered as such. Instead, each instantiation that
+/// should be visible to Godot is registered as a separate class. Declare a type alias per instantiation, and list the
+/// aliases with the `instances` key; the alias names become the Godot class names.
+///
+/// `#[godot_api]` impl blocks of generic classes need the same list, as `#[godot_api(instances = (...))]`.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// pub trait Spawnable: 'static {
+///     const SCENE: &'static str;
+/// }
+///
+/// pub struct Enemy;
+/// impl Spawnable for Enemy {
+///     const SCENE: &'static str = "res://enemy.tscn";
+/// }
+///
+/// pub struct Coin;
+/// impl Spawnable for Coin {
+///     const SCENE: &'static str = "res://coin.tscn";
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node, instances = (EnemySpawner, CoinSpawner))]
+/// pub struct Spawner<T: Spawnable> {
+///     #[export]
+///     interval: f64,
+///     _spawned: std::marker::PhantomData<T>,
+/// }
+///
+/// pub type EnemySpawner = Spawner<Enemy>;
+/// pub type CoinSpawner = Spawner<Coin>;
+///
+/// #[godot_api(instances = (EnemySpawner, CoinSpawner))]
+/// impl<T: Spawnable> Spawner<T> {
+///     #[func]
+///     fn scene_path(&self) -> GodotString {
+///         T::SCENE.into()
+///     }
+/// }
+/// ```
+///
+/// The registration glue is generated separately for each alias, outside the generic context. Therefore, the types of
+/// `#[var]`/`#[export]` fields and of `#[func]` parameters and return values cannot mention the generic parameters themselves.
 #[proc_macro_derive(GodotClass, attributes(class, base, var, export, init, signal))]
 pub fn derive_godot_class(input: TokenStream) -> TokenStream {
     translate(input, class::derive_godot_class)
@@ -437,9 +740,67 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 ///     }
 /// }
 /// ```
+///
+/// # Numeric parameters
+///
+/// GDScript only knows 64-bit `int` and `float`. Narrower parameter types of `#[func]` methods are range-checked: passing `300` to a
+/// `u8` parameter, or `1e100` to an `f32` one, fails the call with an error naming the method and parameter, instead of silently
+/// truncating the value. (`u64` is an exception; it accepts negative values, which stand for those above `i64::MAX`.)
+///
+/// To truncate or clamp out-of-range values instead, use the [`Wrapping`](std::num::Wrapping) or
+/// [`Clamped`](../builtin/meta/struct.Clamped.html) wrappers as parameter type:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::builtin::meta::Clamped;
+/// use std::num::Wrapping;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Mixer {
+///     volume: u8,
+///     sequence: u16,
+/// }
+///
+/// #[godot_api]
+/// impl Mixer {
+///     #[func]
+///     fn set_volume(&mut self, volume: Clamped<u8>) {
+///         self.volume = volume.0; // 300 -> 255, -5 -> 0.
+///     }
+///
+///     #[func]
+///     fn set_sequence(&mut self, sequence: Wrapping<u16>) {
+///         self.sequence = sequence.0; // 65537 -> 1.
+///     }
+/// }
+/// ```
+///
+/// # Default arguments
+///
+/// Trailing parameters of a `#[func]` can be given a default value with `#[opt(default = ...)]`. Callers may then omit them,
+/// and the editor shows the defaults in autocompletion and the documentation panel, like for engine methods. The expression
+/// has the type of the parameter and may refer to `Self`, e.g. `Self::DEFAULT_COUNT`; it is evaluated at registration, and again
+/// for each call that omits the argument.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Spawner;
+///
+/// #[godot_api]
+/// impl Spawner {
+///     // GDScript: spawn(name: String, count: int = 1, delay: float = 0.5) -> int
+///     #[func]
+///     fn spawn(&mut self, name: GodotString, #[opt(default = 1)] count: i64, #[opt(default = 0.5)] delay: f64) -> i64 {
+///         count
+///     }
+/// }
+/// ```
 #[proc_macro_attribute]
-pub fn godot_api(_meta: TokenStream, input: TokenStream) -> TokenStream {
-    translate(input, class::attribute_godot_api)
+pub fn godot_api(meta: TokenStream, input: TokenStream) -> TokenStream {
+    translate_meta("godot_api", meta, input, class::attribute_godot_api)
 }
 
 #[proc_macro_derive(GodotConvert)]
@@ -565,6 +926,97 @@ pub fn derive_export(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_export)
 }
 
+/// Derive macro for [ShaderUniforms](../engine/trait.ShaderUniforms.html) on structs.
+///
+/// Each named field is written to the shader uniform of the same name. Fields can be customized with a `#[uniform]` attribute:
+/// - `#[uniform(name = "other_name")]` writes the field to a differently-named uniform.
+/// - `#[uniform(skip)]` excludes the field.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::engine::{ShaderMaterial, ShaderMaterialExt};
+/// #[derive(ShaderUniforms)]
+/// struct Outline {
+///     #[uniform(name = "outline_color")]
+///     color: Color,
+///     width: f32,
+///     #[uniform(skip)]
+///     cached: bool,
+/// }
+/// ```
+#[proc_macro_derive(ShaderUniforms, attributes(uniform))]
+pub fn derive_shader_uniforms(input: TokenStream) -> TokenStream {
+    translate(input, derive::derive_shader_uniforms)
+}
+
+/// Checks at compile time that a `res://` path exists in the Godot project, and returns it as a [`ResPath`].
+///
+/// This turns typos in asset paths into build errors, instead of load failures at runtime.
+///
+/// ```ignore
+/// use godot::prelude::*;
+///
+/// const PLAYER: godot::engine::ResPath = res!("res://scenes/player.tscn");
+///
+/// let scene = PLAYER.load::<PackedScene>();
+/// ```
+///
+/// The project directory is the closest ancestor of the crate's manifest directory that contains a `project.godot` (or a
+/// `godot/project.godot`). To use another location, set the `GODOT_PROJECT_DIR` environment variable, e.g. in a
+/// `[env]` section of `.cargo/config.toml`; relative paths are resolved against the manifest directory.
+///
+/// [`ResPath`]: ../engine/struct.ResPath.html
+// FIXME intra-doc link
+#[proc_macro]
+pub fn res(input: TokenStream) -> TokenStream {
+    let result2 =
+        res_path::res_path(TokenStream2::from(input)).unwrap_or_else(|e| e.to_compile_error());
+
+    TokenStream::from(result2)
+}
+
+/// Derive macro for [the `SaveState` trait](../engine/trait.SaveState.html) on structs with named fields.
+///
+/// Fields annotated with `#[save]` are persisted under their own name; `#[save(key = "...")]` chooses a different dictionary
+/// key, e.g. to keep save files compatible after renaming a field. All other fields are ignored.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(SaveState)]
+/// struct Settings {
+///     #[save]
+///     volume: f32,
+///     #[save(key = "lang")]
+///     language: GodotString,
+///     dirty: bool,
+/// }
+/// ```
+#[proc_macro_derive(SaveState, attributes(save))]
+pub fn derive_save_state(input: TokenStream) -> TokenStream {
+    translate(input, derive::derive_save_state)
+}
+
+/// Derive macro for [the `PackedState` trait](../engine/trait.PackedState.html) on structs with named fields.
+///
+/// All fields are packed in declaration order, except those annotated with `#[packed(skip)]`, which are set to their default
+/// value when unpacking. `#[packed(version = N)]` on the struct sets the version byte (0 to 255, default 0).
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(PackedState)]
+/// #[packed(version = 2)]
+/// struct Movement {
+///     velocity: Vector3,
+///     on_floor: bool,
+///     #[packed(skip)]
+///     interpolated: Vector3,
+/// }
+/// ```
+#[proc_macro_derive(PackedState, attributes(packed))]
+pub fn derive_packed_state(input: TokenStream) -> TokenStream {
+    translate(input, derive::derive_packed_state)
+}
+
 /// Similar to `#[test]`, but runs an integration test with Godot.
 ///
 /// Transforms the `fn` into one returning `bool` (success of the test), which must be called explicitly.
@@ -583,7 +1035,14 @@ pub fn bench(meta: TokenStream, input: TokenStream) -> TokenStream {
 
 /// Proc-macro attribute to be used in combination with the [`ExtensionLibrary`] trait.
 ///
+/// By default, the entry point is exported under the symbol `gdext_rust_init`, which must match the `entry_symbol` in the
+/// `.gdextension` file. A different symbol can be chosen with `#[gdextension(entry_symbol = "my_lib_init")]`.
+///
+/// A binary can contain several `#[gdextension]` impls with distinct symbols, each of which is loaded by Godot as its own extension.
+/// Use [`ExtensionLibrary::registers_class()`] to decide which extension registers which classes.
+///
 /// [`ExtensionLibrary`]: trait.ExtensionLibrary.html
+/// [`ExtensionLibrary::registers_class()`]: trait.ExtensionLibrary.html#method.registers_class
 // FIXME intra-doc link
 #[proc_macro_attribute]
 pub fn gdextension(meta: TokenStream, input: TokenStream) -> TokenStream {
@@ -0,0 +1,92 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::path::{Path, PathBuf};
+
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::quote;
+
+use crate::util::bail;
+use crate::ParseResult;
+
+const PROJECT_DIR_VAR: &str = "GODOT_PROJECT_DIR";
+
+pub fn res_path(input: TokenStream) -> ParseResult<TokenStream> {
+    let mut tokens = input.into_iter();
+    let (Some(TokenTree::Literal(lit)), None) = (tokens.next(), tokens.next()) else {
+        return bail!(Span::call_site(), "res!() expects a single string literal");
+    };
+
+    let repr = lit.to_string();
+    let Some(path) = repr
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .filter(|s| !s.contains('\\'))
+    else {
+        return bail!(
+            &lit,
+            "res!() expects a plain string literal without escape sequences"
+        );
+    };
+
+    let Some(relative) = path.strip_prefix("res://") else {
+        return bail!(&lit, "path must start with `res://`");
+    };
+
+    let project_dir = match find_project_dir() {
+        Ok(dir) => dir,
+        Err(msg) => return bail!(&lit, "{msg}"),
+    };
+
+    let file = project_dir.join(relative);
+    if !file.exists() {
+        return bail!(
+            &lit,
+            "`{path}` does not exist in the Godot project at `{}`",
+            project_dir.display()
+        );
+    }
+
+    Ok(quote! {
+        ::godot::engine::ResPath::__new_unchecked(#path)
+    })
+}
+
+/// Directory containing `project.godot`, either from the environment or found relative to the crate being compiled.
+fn find_project_dir() -> Result<PathBuf, String> {
+    let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .ok_or_else(|| {
+            "CARGO_MANIFEST_DIR not set; res!() must be used in a Cargo build".to_string()
+        })?;
+
+    if let Some(dir) = std::env::var_os(PROJECT_DIR_VAR) {
+        let dir = manifest_dir.join(dir);
+        return if is_project_dir(&dir) {
+            Ok(dir)
+        } else {
+            Err(format!(
+                "{PROJECT_DIR_VAR} points to `{}`, which contains no project.godot",
+                dir.display()
+            ))
+        };
+    }
+
+    manifest_dir
+        .ancestors()
+        .flat_map(|dir| [dir.to_path_buf(), dir.join("godot")])
+        .find(|dir| is_project_dir(dir))
+        .ok_or_else(|| {
+            format!(
+                "no project.godot found in any parent directory of `{}`; set {PROJECT_DIR_VAR} to the Godot project directory",
+                manifest_dir.display()
+            )
+        })
+}
+
+fn is_project_dir(dir: &Path) -> bool {
+    dir.join("project.godot").is_file()
+}
@@ -10,7 +10,7 @@ use quote::ToTokens;
 use std::collections::HashMap;
 use venial::Attribute;
 
-use super::{bail, error, ident, is_punct, path_is_single, ListParser};
+use super::{bail, error, ident, is_punct, path_is_single, suggest_similar, ListParser};
 
 pub(crate) type KvMap = HashMap<Ident, Option<KvValue>>;
 
@@ -18,6 +18,9 @@ pub(crate) type KvMap = HashMap<Ident, Option<KvValue>>;
 pub(crate) struct KvParser {
     map: KvMap,
     span: Span,
+
+    /// Keys that were queried so far, used to suggest corrections for unrecognized keys.
+    known_keys: Vec<String>,
 }
 
 #[allow(dead_code)] // some functions will be used later
@@ -52,6 +55,7 @@ impl KvParser {
                 found_attr = Some(Self {
                     span: attr.tk_brackets.span,
                     map: ParserState::parse(attr_name, &attr.value)?,
+                    known_keys: Vec::new(),
                 });
             }
         }
@@ -67,7 +71,7 @@ impl KvParser {
     /// - For a key with no value, returns `Some(None)`.
     /// - For a key with a value, returns `Some(value)`.
     pub fn handle_any(&mut self, key: &str) -> Option<Option<KvValue>> {
-        self.map.remove(&ident(key))
+        self.handle_any_entry(key).map(|(_key, value)| value)
     }
 
     /// Handle any value, returning both the key and value.
@@ -76,6 +80,7 @@ impl KvParser {
     /// - For a key with no value, returns `Some((key, None))`.
     /// - For a key with a value, returns `Some((key, Some(value)))`.
     pub fn handle_any_entry(&mut self, key: &str) -> Option<(Ident, Option<KvValue>)> {
+        self.known_keys.push(key.to_string());
         self.map.remove_entry(&ident(key))
     }
 
@@ -99,7 +104,7 @@ impl KvParser {
 
     /// Handles an optional key that can only occur with an identifier as the value.
     pub fn handle_ident(&mut self, key: &str) -> ParseResult<Option<Ident>> {
-        match self.map.remove_entry(&ident(key)) {
+        match self.handle_any_entry(key) {
             None => Ok(None),
             // The `key` that was removed from the map has the correct span.
             Some((key, value)) => match value {
@@ -121,7 +126,7 @@ impl KvParser {
 
     /// Handles an optional key that can occur with arbitrary tokens as the value.
     pub fn handle_expr(&mut self, key: &str) -> ParseResult<Option<TokenStream>> {
-        match self.map.remove_entry(&ident(key)) {
+        match self.handle_any_entry(key) {
             None => Ok(None),
             // The `key` that was removed from the map has the correct span.
             Some((key, value)) => match value {
@@ -131,6 +136,30 @@ impl KvParser {
         }
     }
 
+    /// Handles an optional key that can only occur with a string literal as the value, e.g. `#[attr(key = "value")]`.
+    ///
+    /// Returns the contents of the literal, without quotes.
+    pub fn handle_string(&mut self, key: &str) -> ParseResult<Option<String>> {
+        let Some(expr) = self.handle_expr(key)? else {
+            return Ok(None);
+        };
+
+        let mut tokens = expr.into_iter();
+        let (Some(TokenTree::Literal(lit)), None) = (tokens.next(), tokens.next()) else {
+            return bail!(key, "value for '{key}' must be a string literal");
+        };
+
+        let repr = lit.to_string();
+        match repr.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+            // Escape sequences are not interpreted; reject them rather than silently keeping the backslashes.
+            Some(content) if !content.contains('\\') => Ok(Some(content.to_string())),
+            _ => bail!(
+                lit,
+                "value for '{key}' must be a plain string literal without escape sequences"
+            ),
+        }
+    }
+
     pub fn handle_usize(&mut self, key: &str) -> ParseResult<Option<usize>> {
         let Some(expr) = self.handle_expr(key)? else {
             return Ok(None);
@@ -182,10 +211,18 @@ impl KvParser {
         if self.map.is_empty() {
             Ok(())
         } else {
-            let errors = self
-                .map
-                .keys()
-                .map(|ident| error!(ident, "unrecognized key `{ident}`"));
+            let errors = self.map.keys().map(|ident| {
+                let key = ident.to_string();
+                match suggest_similar(&key, self.known_keys.iter().map(String::as_str)) {
+                    Some(suggestion) => {
+                        error!(
+                            ident,
+                            "unrecognized key `{key}`; did you mean `{suggestion}`?"
+                        )
+                    }
+                    None => error!(ident, "unrecognized key `{key}`"),
+                }
+            });
             Err(errors
                 .reduce(|mut a, b| {
                     a.combine(b);
@@ -559,4 +596,14 @@ mod tests {
             ),
         );
     }
+
+    #[test]
+    fn test_suggest_similar() {
+        let keys = ["base", "init", "rename", "tool", "editor_plugin"];
+
+        assert_eq!(suggest_similar("bsae", keys), Some("base"));
+        assert_eq!(suggest_similar("renam", keys), Some("rename"));
+        assert_eq!(suggest_similar("editor_plgin", keys), Some("editor_plugin"));
+        assert_eq!(suggest_similar("completely_different", keys), None);
+    }
 }
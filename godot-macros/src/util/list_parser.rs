@@ -9,7 +9,9 @@
 use proc_macro2::{Delimiter, Ident, Span, TokenStream, TokenTree};
 use std::collections::VecDeque;
 
-use crate::util::{bail, delimiter_opening_char, is_punct, kv_parser::KvValue, KvParser};
+use crate::util::{
+    bail, delimiter_opening_char, is_punct, kv_parser::KvValue, suggest_similar, KvParser,
+};
 use crate::ParseResult;
 
 /// Parses a list of tokens as an ordered list of values. Unlike [`KvParser`] which treats the tokens as a
@@ -162,7 +164,13 @@ impl ListParser {
         }
 
         let allowed_values = ids.join(",");
-        bail!(next_id, "expected one of: \"{allowed_values}\"")
+        match suggest_similar(&next_id.to_string(), ids.iter().copied()) {
+            Some(suggestion) => bail!(
+                next_id,
+                "expected one of: \"{allowed_values}\"; did you mean `{suggestion}`?"
+            ),
+            None => bail!(next_id, "expected one of: \"{allowed_values}\""),
+        }
     }
 
     /// Take the next element of the list, if it is a key-value pair of the form `key = expression`.
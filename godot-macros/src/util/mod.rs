@@ -32,6 +32,22 @@ pub fn class_name_obj(class: &impl ToTokens) -> TokenStream {
     quote! { <#class as ::godot::obj::GodotClass>::class_name() }
 }
 
+/// Expression (of type `String`) for the name under which a method, property or signal is registered with Godot.
+///
+/// An explicit `rename` is used verbatim; otherwise the class' `#[class(rename_all)]` convention is applied to `rust_name`.
+pub fn make_registered_name(
+    class_name: &Ident,
+    rust_name: &str,
+    rename: Option<&str>,
+) -> TokenStream {
+    match rename {
+        Some(rename) => quote! { ::std::string::String::from(#rename) },
+        None => quote! {
+            ::godot::private::apply_rename_all(<#class_name>::__config().rename_all, #rust_name)
+        },
+    }
+}
+
 pub fn property_variant_type(property_type: &impl ToTokens) -> TokenStream {
     let property_type = property_type.to_token_stream();
     quote! { <<<#property_type as ::godot::bind::property::Property>::Intermediate as ::godot::builtin::meta::GodotConvert>::Via as ::godot::builtin::meta::GodotType>::Ffi::variant_type() }
@@ -167,25 +183,24 @@ pub(crate) fn validate_trait_impl_virtual(
     original_impl: &Impl,
     attr: &str,
 ) -> ParseResult<(Ident, Ident)> {
+    let trait_name = validate_virtual_trait(original_impl, attr)?;
+
+    // Validate self
+    validate_self(original_impl, attr).map(|class_name| (class_name, trait_name))
+}
+
+/// Validates that the trait of `impl Trait for SomeType` is a virtual method trait, and returns its name.
+pub(crate) fn validate_virtual_trait(original_impl: &Impl, attr: &str) -> ParseResult<Ident> {
     let trait_name = original_impl.trait_ty.as_ref().unwrap(); // unwrap: already checked outside
     let typename = extract_typename(trait_name);
 
-    // Validate trait
-    if !typename
-        .as_ref()
-        .map_or(false, |seg| seg.ident.to_string().ends_with("Virtual"))
-    {
-        return bail!(
+    match typename {
+        Some(segment) if segment.ident.to_string().ends_with("Virtual") => Ok(segment.ident),
+        _ => bail!(
             original_impl,
             "#[{attr}] for trait impls requires a virtual method trait (trait name should end in 'Virtual')",
-        );
+        ),
     }
-
-    // Validate self
-    validate_self(original_impl, attr).map(|class_name| {
-        let trait_name = typename.unwrap(); // unwrap: already checked in 'Validate trait'
-        (class_name, trait_name.ident)
-    })
 }
 
 fn validate_self(original_impl: &Impl, attr: &str) -> ParseResult<Ident> {
@@ -227,6 +242,39 @@ pub(crate) fn path_ends_with(path: &[TokenTree], expected: &str) -> bool {
         .unwrap_or(false)
 }
 
+/// Returns the candidate closest to `input`, if it is close enough to likely be a typo.
+pub(crate) fn suggest_similar<'a>(
+    input: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    // Allow up to half of the characters to differ, so that swapped letters in short keys are still caught.
+    let max_distance = (input.chars().count() / 2).max(1);
+
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(input, candidate), candidate))
+        .filter(|&(distance, _)| distance <= max_distance)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance between two strings, counted in chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev_row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut row = vec![i + 1; b.len() + 1];
+        for (j, &b_char) in b.iter().enumerate() {
+            let substitution = prev_row[j] + usize::from(a_char != b_char);
+            row[j + 1] = substitution.min(prev_row[j + 1] + 1).min(row[j] + 1);
+        }
+        prev_row = row;
+    }
+
+    prev_row[b.len()]
+}
+
 pub(crate) fn extract_cfg_attrs(
     attrs: &[venial::Attribute],
 ) -> impl IntoIterator<Item = &venial::Attribute> {
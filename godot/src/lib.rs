@@ -102,6 +102,57 @@
 //! The Cargo feature `experimental-threads` provides experimental support for multithreading. The underlying safety
 //! rules are still being worked out, as such you may encounter unsoundness and an unstable API.
 //!
+//! # Mobile platforms
+//!
+//! * **Android**: build a `cdylib` for each ABI you export, and reference it in the `.gdextension` file with the Godot ABI name:
+//!   `aarch64-linux-android` as `android.arm64`, `armv7-linux-androideabi` as `android.arm32`, `x86_64-linux-android` as
+//!   `android.x86_64` and `i686-linux-android` as `android.x86_32`. Standard output is not visible on Android;
+//!   use the [`godot_print!`][crate::log::godot_print] family of macros, which end up in `logcat`.
+//!
+//! * **iOS**: Godot links extensions statically into the app. Add `"staticlib"` to `crate-type` and reference the `.a` file
+//!   (or an `.xcframework`) under `ios.debug`/`ios.release`. Because all static libraries end up in one binary, give each extension
+//!   a unique entry point with `#[gdextension(entry_symbol = "...")]`. Class registration relies on static constructors in the
+//!   library, so pass `-force_load` for it to the Xcode linker; otherwise unreferenced object files are stripped and classes go missing.
+//!
+//! On both platforms, the OS can suspend or kill the app at any time; see [`AppLifecycle`][crate::engine::AppLifecycle]
+//! for handling pause/resume notifications.
+//!
+//! # Selecting engine classes
+//!
+//! Binding all of Godot's classes takes a significant share of compile time. A `gdext-codegen.toml` file restricts code generation
+//! to the parts of the engine API your project uses. Place it next to the `Cargo.toml` of your workspace (or package); it is found
+//! as long as the target directory is inside that workspace. A file elsewhere can be selected with the `GDEXT_CODEGEN_CONFIG`
+//! environment variable, e.g. in `.cargo/config.toml`:
+//! ```toml
+//! [env]
+//! GDEXT_CODEGEN_CONFIG = { value = "config/gdext-codegen.toml", relative = true }
+//! ```
+//! After creating the file, run `cargo clean -p godot-ffi -p godot-core` once, since Cargo cannot watch files that do not exist yet.
+//!
+//! If `[include]` lists classes or areas, only those are generated. Otherwise, the default selection is used (all classes, or
+//! a minimal set without the `codegen-full` feature). In both cases, `[exclude]` removes classes from the selection. Classes are
+//! named as in Godot:
+//! ```toml
+//! [include]
+//! classes = ["Sprite2D", "AnimationPlayer"]
+//! areas = ["physics_2d"]
+//!
+//! [exclude]
+//! classes = ["WebRTCPeerConnection"]
+//! areas = ["editor", "xr"]
+//! ```
+//! Available areas are `editor`, `xr`, `physics_2d`, `physics_3d`, `navigation`, `audio`, `gltf` and `visual_shader`.
+//! Base classes of selected classes, as well as the minimal set that gdext itself relies on, are always generated. Methods that
+//! take or return a class which is not generated are left out. Unknown class or area names fail the build.
+//!
+//! To see the effect of a selection, set the `GDEXT_CODEGEN_REPORT` environment variable (to any value) while building. Code
+//! generation then prints, as Cargo warnings, how many classes were generated, the files and lines emitted, and the time spent
+//! in each phase.
+//!
+//! Similarly, setting `GDEXT_STARTUP_REPORT` when running Godot logs how long each initialization level takes, split into
+//! gdext's own work (loading method tables, registering classes) and your
+//! [`ExtensionLibrary::on_level_init()`][crate::init::ExtensionLibrary::on_level_init].
+//!
 //! # Cargo features
 //!
 //! The following features can be enabled for this crate. All off them are off by default.
@@ -121,6 +172,15 @@
 //!   Use a custom Godot build instead of the latest official release. This is useful when you like to use a
 //!   version compiled yourself, with custom flags.
 //!
+//!   The Godot binary is located through the `GODOT4_BIN` environment variable, or a `godot4` executable in `PATH`.
+//!   By default, the C header `gdextension_interface.h` and the `extension_api.json` are dumped from that binary.
+//!   Engine forks that change the GDExtension interface can instead point these environment variables to their own files:
+//!   * `GODOT4_GDEXTENSION_H`: the header in the fork's source tree (`core/extension/gdextension_interface.h`). It is parsed with
+//!     bindgen at build time, so that added or modified interface functions are picked up.
+//!   * `GODOT4_EXTENSION_API_JSON`: the API JSON, e.g. from the fork's build artifacts. The Godot version is then read from it.
+//!
+//!   If both are set, no Godot binary is needed. If a binary is found anyway, the header is checked against its version.
+//!
 //!   If you simply want to use a different official release, use this pattern instead (here e.g. for version `4.0`):
 //!   ```toml
 //!   # Trick Cargo into seeing a different URL; https://github.com/rust-lang/cargo/issues/5478
@@ -135,6 +195,26 @@
 //!   The serialized representation underlies **no stability guarantees** and may change at any time, even without a SemVer-breaking change.
 //!   <br><br>
 //!
+//! * **`rand`**
+//!
+//!   Implement the [rand_core](https://docs.rs/rand_core) traits `RngCore` and `SeedableRng` for adapters around Godot's random
+//!   number generators, so that distributions from the `rand` ecosystem draw from the same (seeded) source as the engine.
+//!   <br><br>
+//!
+//! * **`debug-overlay`**
+//!
+//!   Count the work done by the binding layer (calls into Godot, `Variant` conversions, bind contention and pending signal futures),
+//!   available as `godot::engine::BindingMetrics`. Also registers the class `GdextDebugOverlay`, which shows these counts per frame
+//!   on screen. Counting adds a small cost to every engine call, so this is meant for development builds.
+//!   <br><br>
+//!
+//! * **`cargo-build-plugin`**
+//!
+//!   Register the editor plugin `GdextCargoBuild` (`godot::engine::CargoBuildPlugin`), which adds a toolbar button to run
+//!   `cargo build` and hot-reloads the extension once the library has changed. Requires Godot 4.2 and `reloadable = true` in the
+//!   `.gdextension` file.
+//!   <br><br>
+//!
 //! * **`experimental-threads`**
 //!
 //!   Experimental threading support. This enables `Send`/`Sync` traits for `Gd<T>` and makes the guard types `Gd`/`GdMut` aware of
@@ -146,11 +226,43 @@
 //!   Access to `godot::engine` APIs that Godot marks "experimental". These are under heavy development and may change at any time.
 //!   If you opt in to this feature, expect breaking changes at compile and runtime.
 //!
+//! * **`experimental-sys`**
+//!
+//!   Access to the raw GDExtension interface via `godot::sys::interface()`, for functionality that gdext does not wrap yet
+//!   (e.g. script instances or placement construction). Everything exposed this way is unsafe to use and may change between versions;
+//!   the accessor carries a version number (`RawInterface::ACCESS_VERSION`) and the Godot runtime version to help detect this.
+//!   The module `godot::sys::layout` describes the sizes of `Variant` and the builtin types, and their mapping to Rust types, for
+//!   crates that implement their own marshalling.
+//!
+//! * **`experimental-wasm`**
+//!
+//!   Support for Godot's web export, by compiling the extension as an Emscripten side module (target `wasm32-unknown-emscripten`).
+//!   This currently requires a nightly toolchain, since the standard library must be rebuilt with atomics enabled. Godot loads
+//!   web extensions with threads, so the side module must be linked accordingly. In your project's `.cargo/config.toml`:
+//!   ```toml
+//!   [target.wasm32-unknown-emscripten]
+//!   rustflags = [
+//!       "-C", "link-args=-sSIDE_MODULE=2",
+//!       "-C", "link-args=-pthread",
+//!       "-C", "target-feature=+atomics,+bulk-memory,+mutable-globals",
+//!       "-Zlink-native-libraries=no",
+//!   ]
+//!   ```
+//!   Then build with `cargo +nightly build -Zbuild-std --target wasm32-unknown-emscripten`. The build script warns if one of these
+//!   flags is missing.
+//!
 //! * **`lazy-function-tables`**
 //!
 //!   Instead of loading all engine function pointers at startup, load them lazily on first use. This reduces startup time and RAM usage, but
-//!   incurs additional overhead in each FFI call. Also, you lose the guarantee that once the library has booted, all function pointers are
-//!   truly available. Function calls may thus panic only at runtime, possibly in deeply nested code paths.
+//!   incurs a small overhead in each FFI call (checking whether the pointer is already loaded). Also, you lose the guarantee that once the
+//!   library has booted, all function pointers are truly available. Function calls may thus panic only at runtime, possibly in deeply nested
+//!   code paths.
+//!
+//!   With this feature, each generated method caches its own function pointer, instead of all of them being loaded into one table. The
+//!   code loading an engine method is thus only linked into the binary if your extension calls that method. Without this feature, the
+//!   method tables reference every method of the generated classes; to reduce their size, generate fewer classes through
+//!   `gdext-codegen.toml`.
+//!
 //!   This feature is not yet thread-safe and can thus not be combined with `experimental-threads`.
 //!
 //! # Public API
@@ -178,6 +290,9 @@ pub use godot_core::sys;
 #[cfg(all(feature = "lazy-function-tables", feature = "experimental-threads"))]
 compile_error!("Thread safety for lazy function pointers is not yet implemented.");
 
+#[cfg(all(target_family = "wasm", not(feature = "experimental-wasm")))]
+compile_error!("Must opt-in using `experimental-wasm` Cargo feature; keep in mind that this is work in progress");
+
 pub mod init {
     pub use godot_core::init::*;
 
@@ -189,7 +304,8 @@ pub mod init {
 pub mod bind {
     pub use godot_core::property;
     pub use godot_macros::{
-        godot_api, Export, FromGodot, GodotClass, GodotConvert, Property, ToGodot,
+        godot_api, Export, FromGodot, GodotClass, GodotConvert, PackedState, Property, SaveState,
+        ShaderUniforms, ToGodot,
     };
 }
 
@@ -206,23 +322,26 @@ pub use godot_core::private;
 pub mod prelude {
     pub use super::bind::property::{Export, Property, TypeStringHint};
     pub use super::bind::{
-        godot_api, Export, FromGodot, GodotClass, GodotConvert, Property, ToGodot,
+        godot_api, Export, FromGodot, GodotClass, GodotConvert, PackedState, Property, SaveState,
+        ShaderUniforms, ToGodot,
     };
 
     pub use super::builtin::math::FloatExt as _;
     pub use super::builtin::meta::{FromGodot, ToGodot};
     pub use super::builtin::*;
     pub use super::builtin::{array, dict, varray}; // Re-export macros.
+    pub use super::engine::tr; // Re-export macro.
     pub use super::engine::{
-        load, try_load, utilities, AudioStreamPlayer, AudioStreamPlayerVirtual, Camera2D,
-        Camera2DVirtual, Camera3D, Camera3DVirtual, Input, Node, Node2D, Node2DVirtual, Node3D,
-        Node3DVirtual, NodeVirtual, Object, ObjectVirtual, PackedScene, PackedSceneExt,
-        PackedSceneVirtual, RefCounted, RefCountedVirtual, Resource, ResourceVirtual, SceneTree,
-        SceneTreeVirtual,
+        load, load_scene, try_load, try_load_scene, utilities, AudioStreamPlayer,
+        AudioStreamPlayerVirtual, Camera2D, Camera2DVirtual, Camera3D, Camera3DVirtual, Input,
+        Node, Node2D, Node2DVirtual, Node3D, Node3DVirtual, NodeVirtual, Object, ObjectVirtual,
+        PackedScene, PackedSceneExt, PackedSceneVirtual, RefCounted, RefCountedVirtual, Resource,
+        ResourceVirtual, SceneTree, SceneTreeVirtual,
     };
     pub use super::init::{gdextension, ExtensionLibrary, InitLevel};
     pub use super::log::*;
     pub use super::obj::{Base, Gd, GdMut, GdRef, GodotClass, Inherits, InstanceId, Share};
+    pub use godot_macros::res;
 
     // Make trait methods available
     pub use super::engine::NodeExt as _;
@@ -5,6 +5,7 @@
  */
 
 use crate::framework::itest;
+use godot::builtin::inner::InnerColor;
 use godot::builtin::{Color, ColorChannelOrder};
 
 #[itest]
@@ -110,3 +111,22 @@ fn color_to_u64() {
     assert_eq!(c.to_u64(ColorChannelOrder::Abgr), 0x0404_0303_0202_0101);
     assert_eq!(c.to_u64(ColorChannelOrder::Argb), 0x0404_0101_0202_0303);
 }
+
+#[itest]
+fn color_is_equal_approx() {
+    let c = Color::from_rgba(0.2, 0.4, 0.6, 0.8);
+    let inner = InnerColor::from_outer(&c);
+
+    for other in [
+        c,
+        Color::from_rgba(0.200001, 0.4, 0.6, 0.8),
+        Color::from_rgba(0.2, 0.41, 0.6, 0.8),
+        Color::from_rgba(0.2, 0.4, 0.6, 0.79),
+    ] {
+        assert_eq!(
+            c.is_equal_approx(other),
+            inner.is_equal_approx(other),
+            "color {other:?}"
+        );
+    }
+}
@@ -148,6 +148,7 @@ mod custom_callable {
     use godot::builtin::Dictionary;
     use std::fmt;
     use std::hash::Hash;
+    use std::sync::atomic::{AtomicBool, Ordering};
     use std::sync::{Arc, Mutex};
 
     #[itest]
@@ -266,6 +267,22 @@ mod custom_callable {
         assert_eq!(eq_count(&bt), 1, "hash collision, eq for b needed");
     }
 
+    #[itest]
+    fn callable_custom_cancel() {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let callable = Callable::from_custom(Cancellable {
+            calls: 0,
+            cancelled: cancelled.clone(),
+        });
+
+        assert!(callable.is_valid());
+        assert_eq!(callable.callv(varray![]), 1.to_variant());
+
+        cancelled.store(true, Ordering::SeqCst);
+        assert!(!callable.is_valid());
+        assert_eq!(callable.callv(varray![]), Variant::nil());
+    }
+
     struct Adder {
         sum: i32,
 
@@ -323,6 +340,41 @@ mod custom_callable {
         }
     }
 
+    struct Cancellable {
+        calls: i32,
+        cancelled: Arc<AtomicBool>,
+    }
+
+    // Callables sharing the same cancellation flag are equal.
+    impl PartialEq for Cancellable {
+        fn eq(&self, other: &Self) -> bool {
+            Arc::ptr_eq(&self.cancelled, &other.cancelled)
+        }
+    }
+
+    impl Hash for Cancellable {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            Arc::as_ptr(&self.cancelled).hash(state);
+        }
+    }
+
+    impl fmt::Display for Cancellable {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "Cancellable(calls={})", self.calls)
+        }
+    }
+
+    impl godot::builtin::RustCallable for Cancellable {
+        fn invoke(&mut self, _args: &[&Variant]) -> Result<Variant, ()> {
+            self.calls += 1;
+            Ok(self.calls.to_variant())
+        }
+
+        fn is_valid(&self) -> bool {
+            !self.cancelled.load(Ordering::SeqCst)
+        }
+    }
+
     struct Tracker {
         eq_counter: usize,
         hash_counter: usize,
@@ -5,7 +5,10 @@
  */
 
 use crate::framework::{expect_panic, itest};
-use godot::builtin::{PackedByteArray, PackedFloat32Array, PackedStringArray};
+use godot::builtin::{
+    Color, PackedByteArray, PackedColorArray, PackedFloat32Array, PackedStringArray,
+    PackedVector2Array, Vector2,
+};
 
 #[itest]
 fn packed_array_default() {
@@ -210,6 +213,40 @@ fn packed_array_extend() {
     assert_eq!(array.to_vec(), vec![1, 2, 3, 4]);
 }
 
+#[itest]
+fn packed_array_extend_from_slice() {
+    let mut array = PackedStringArray::from(&["a".into()]);
+    array.extend_from_slice(&["b".into(), "c".into()]);
+    array.extend_from_slice(&[]);
+    assert_eq!(array.to_vec(), vec!["a".into(), "b".into(), "c".into()]);
+}
+
+#[itest]
+fn packed_array_set_from_slice() {
+    let polygon = [
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(0.0, 1.0),
+    ];
+
+    let mut array = PackedVector2Array::from(vec![Vector2::ONE; 5]);
+    array.set_from_slice(&polygon);
+    assert_eq!(array.as_slice(), &polygon);
+
+    array.set_from_slice(&[]);
+    assert!(array.is_empty());
+}
+
+#[itest]
+fn packed_array_math_type_slices() {
+    let colors = vec![Color::from_rgba(1.0, 0.5, 0.25, 1.0), Color::BLACK];
+    let mut array = PackedColorArray::from(colors.clone());
+    assert_eq!(array.as_slice(), colors.as_slice());
+
+    array.as_mut_slice()[1].a = 0.5;
+    assert_eq!(array.get(1), Color::from_rgba(0.0, 0.0, 0.0, 0.5));
+}
+
 #[itest]
 fn packed_array_sort() {
     let mut array = PackedByteArray::from(&[2, 1]);
@@ -9,7 +9,7 @@ use std::cell::Cell;
 use godot::bind::{godot_api, GodotClass};
 use godot::builtin::{GodotString, Variant};
 
-use godot::engine::Object;
+use godot::engine::{ConnectError, ConnectExt, Node2D, Object, TypedConnectExt};
 use godot::obj::{Base, Gd};
 use godot::sys;
 
@@ -55,6 +55,9 @@ impl Receiver {
 
         self.used[2].set(true);
     }
+
+    #[func]
+    fn receive_string(&self, _arg1: GodotString) {}
 }
 
 const SIGNAL_ARG_STRING: &str = "Signal string arg";
@@ -87,3 +90,98 @@ fn signals() {
     receiver.free();
     emitter.free();
 }
+
+#[itest]
+fn signal_connect_checked() {
+    let mut emitter = Gd::<Emitter>::new_default();
+    let receiver = Gd::<Receiver>::new_default();
+
+    let callable = receiver.callable("receive_1_arg");
+    assert_eq!(
+        emitter.validate_connection("signal_1_arg", &callable),
+        Ok(())
+    );
+
+    emitter.connect_checked("signal_1_arg", callable);
+    emitter.emit_signal("signal_1_arg".into(), &[Variant::from(987)]);
+    assert!(receiver.bind().used[1].get());
+
+    receiver.free();
+    emitter.free();
+}
+
+#[itest]
+fn signal_connect_typed() {
+    let mut emitter = Gd::<Emitter>::new_default();
+    let receiver = Gd::<Receiver>::new_default();
+
+    let signal = Object::SIGNAL_PROPERTY_LIST_CHANGED;
+    assert_eq!(signal.as_str(), "property_list_changed");
+
+    let callable = receiver.callable("receive_0_arg");
+    emitter.connect_signal(signal, callable.clone());
+    assert!(emitter.is_signal_connected(signal, &callable));
+
+    emitter.notify_property_list_changed();
+    assert!(receiver.bind().used[0].get());
+
+    emitter.disconnect_signal(signal, &callable);
+    assert!(!emitter.is_signal_connected(signal, &callable));
+
+    receiver.free();
+    emitter.free();
+}
+
+#[itest]
+fn signal_connect_mismatch() {
+    let emitter = Gd::<Emitter>::new_default();
+    let receiver = Gd::<Receiver>::new_default();
+
+    let err = emitter
+        .validate_connection("signal_0_arg", &receiver.callable("receive_1_arg"))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ConnectError::ArgCount {
+            signal: "signal_0_arg".to_string(),
+            method: "receive_1_arg".to_string(),
+            signal_args: 0,
+            method_min: 1,
+            method_max: 1,
+        }
+    );
+
+    let err = emitter
+        .validate_connection("signal_1_arg", &receiver.callable("receive_string"))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "argument #0 of signal `signal_1_arg` has type `Int`, but method `receive_string` expects `String`"
+    );
+
+    let err = emitter
+        .validate_connection("signal_3_arg", &receiver.callable("receive_0_arg"))
+        .unwrap_err();
+    assert!(matches!(err, ConnectError::UnknownSignal { .. }));
+
+    let err = emitter
+        .validate_connection("signal_0_arg", &receiver.callable("receive_nothing"))
+        .unwrap_err();
+    assert!(matches!(err, ConnectError::UnknownMethod { .. }));
+
+    receiver.free();
+    emitter.free();
+}
+
+// Engine methods coerce arguments, so an `int` signal argument can be received by a `float` parameter.
+#[itest]
+fn signal_connect_engine_method_coerces() {
+    let emitter = Gd::<Emitter>::new_default();
+    let node = Node2D::new_alloc();
+
+    let result = emitter.validate_connection("signal_1_arg", &node.callable("set_rotation"));
+    assert_eq!(result, Ok(()));
+
+    node.free();
+    emitter.free();
+}
@@ -206,6 +206,20 @@ fn plane_is_equal_approx() {
     );
 }
 
+#[itest]
+fn plane_is_equal_approx_negated() {
+    let a = Plane::new(Vector3::new(1.5, 6.3, 2.2).normalized(), 5.2);
+    let inner_a = InnerPlane::from_outer(&a);
+    check_mapping_eq(
+        "is_equal_approx",
+        a.is_equal_approx(&-a),
+        inner_a.is_equal_approx(-a),
+    );
+
+    assert!(!a.is_equal_approx(&-a));
+    assert!(a.is_equal_approx_any_side(&-a));
+}
+
 #[itest]
 fn plane_intersect_3() {
     let a = Plane::new(Vector3::new(1.0, 2.0, 0.0).normalized(), 0.0);
@@ -70,6 +70,24 @@ fn string_name_ordering() {
      */
 }
 
+#[itest]
+fn string_name_from_str_cached() {
+    // Repeated conversions are served from the cache and must yield equal names.
+    for _ in 0..3 {
+        assert_eq!(
+            StringName::from("cached_name"),
+            StringName::from("cached_name")
+        );
+    }
+
+    // Long strings bypass the cache.
+    let long = "x".repeat(200);
+    assert_eq!(
+        GodotString::from(&StringName::from(long.as_str())),
+        GodotString::from(long)
+    );
+}
+
 #[itest]
 fn string_name_clone() {
     let first = StringName::from("some string");
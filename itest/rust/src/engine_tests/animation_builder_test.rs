@@ -0,0 +1,59 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::meta::ToGodot;
+use godot::builtin::{varray, Color, NodePath, Vector3};
+use godot::engine::animation::{InterpolationType, LoopMode, TrackType};
+use godot::engine::{AnimationBuilder, MethodKey};
+
+use crate::framework::itest;
+
+#[itest]
+fn animation_builder_tracks() {
+    let animation = AnimationBuilder::new(2.0)
+        .loop_mode(LoopMode::LOOP_LINEAR)
+        .value_track::<Color>("Sprite2D:modulate")
+        .interpolation(InterpolationType::INTERPOLATION_CUBIC)
+        .key(0.0, Color::WHITE)
+        .key(2.0, Color::BLACK)
+        .done()
+        .position_track("Body")
+        .keys([
+            (0.0, Vector3::ZERO),
+            (1.0, Vector3::UP),
+            (2.0, Vector3::ZERO),
+        ])
+        .done()
+        .method_track(".")
+        .key(1.0, MethodKey::new("queue_free", varray![]))
+        .done()
+        .build();
+
+    assert_eq!(animation.get_length(), 2.0);
+    assert_eq!(animation.get_loop_mode(), LoopMode::LOOP_LINEAR);
+    assert_eq!(animation.get_track_count(), 3);
+
+    assert_eq!(animation.track_get_type(0), TrackType::TYPE_VALUE);
+    assert_eq!(
+        animation.track_get_path(0),
+        NodePath::from("Sprite2D:modulate")
+    );
+    assert_eq!(
+        animation.track_get_interpolation_type(0),
+        InterpolationType::INTERPOLATION_CUBIC
+    );
+    assert_eq!(animation.track_get_key_count(0), 2);
+    assert_eq!(
+        animation.track_get_key_value(0, 1),
+        Color::BLACK.to_variant()
+    );
+
+    assert_eq!(animation.track_get_type(1), TrackType::TYPE_POSITION_3D);
+    assert_eq!(animation.track_get_key_count(1), 3);
+
+    assert_eq!(animation.track_get_type(2), TrackType::TYPE_METHOD);
+    assert_eq!(animation.method_track_get_name(2, 0), "queue_free".into());
+}
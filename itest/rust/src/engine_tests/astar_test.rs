@@ -0,0 +1,144 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::{
+    AStar2D, AStar2DVirtual, AStar3D, AStarExt, AStarGrid2D, AStarGridExt, PointId,
+};
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+/// Makes the direct connection between the first and the last point expensive.
+#[derive(GodotClass)]
+#[class(init, base=AStar2D)]
+struct DetourAStar {
+    #[base]
+    base: Base<AStar2D>,
+}
+
+#[godot_api]
+impl AStar2DVirtual for DetourAStar {
+    fn compute_cost(&self, from_id: i64, to_id: i64) -> f64 {
+        let distance = self
+            .base
+            .get_point_position(from_id)
+            .distance_to(self.base.get_point_position(to_id));
+
+        if from_id.min(to_id) == 0 && from_id.max(to_id) == 2 {
+            distance as f64 * 100.0
+        } else {
+            distance as f64
+        }
+    }
+}
+
+fn ids(ids: &[u64]) -> Vec<PointId> {
+    ids.iter().copied().map(PointId::new).collect()
+}
+
+#[itest]
+fn astar_2d_bulk_and_paths() {
+    let mut graph = AStar2D::new();
+    let points = ids(&[0, 1, 2, 3]);
+
+    graph.add_points(
+        points
+            .iter()
+            .map(|&id| (id, Vector2::new(id.to_u64() as f32, 0.0))),
+    );
+    graph.connect_points_from(points.windows(2).map(|pair| (pair[0], pair[1])), true);
+
+    assert_eq!(graph.point_ids(), points);
+    assert_eq!(graph.next_point_id(), PointId::new(4));
+    assert_eq!(graph.id_path(points[0], points[3]), points);
+    assert_eq!(graph.id_path(points[3], points[0]), ids(&[3, 2, 1, 0]));
+    assert_eq!(
+        graph.point_path(points[1], points[2]),
+        vec![Vector2::new(1.0, 0.0), Vector2::new(2.0, 0.0)]
+    );
+    assert_eq!(
+        graph.closest_point_id(Vector2::new(2.2, 1.0)),
+        Some(points[2])
+    );
+
+    assert_eq!(AStar2D::new().closest_point_id(Vector2::ZERO), None);
+}
+
+#[itest]
+fn astar_3d_one_way_connections() {
+    let mut graph = AStar3D::new();
+    graph.add_points([
+        (PointId::new(10), Vector3::ZERO),
+        (PointId::new(20), Vector3::new(0.0, 0.0, 5.0)),
+    ]);
+    graph.connect_points_from([(PointId::new(10), PointId::new(20))], false);
+
+    assert_eq!(
+        graph.id_path(PointId::new(10), PointId::new(20)),
+        ids(&[10, 20])
+    );
+    assert!(graph.id_path(PointId::new(20), PointId::new(10)).is_empty());
+}
+
+#[itest]
+fn astar_custom_cost() {
+    let detour = Gd::<DetourAStar>::new_default();
+    let mut graph = detour.upcast::<AStar2D>();
+
+    graph.add_points([
+        (PointId::new(0), Vector2::new(0.0, 0.0)),
+        (PointId::new(1), Vector2::new(1.0, 1.0)),
+        (PointId::new(2), Vector2::new(2.0, 0.0)),
+    ]);
+    graph.connect_points_from(
+        [
+            (PointId::new(0), PointId::new(1)),
+            (PointId::new(1), PointId::new(2)),
+            (PointId::new(0), PointId::new(2)),
+        ],
+        true,
+    );
+
+    assert_eq!(
+        graph.id_path(PointId::new(0), PointId::new(2)),
+        ids(&[0, 1, 2])
+    );
+}
+
+#[itest]
+fn astar_grid_solid_cells() {
+    let mut grid = AStarGrid2D::new();
+    grid.set_region(Rect2i::new(Vector2i::ZERO, Vector2i::new(3, 3)));
+    grid.set_diagonal_mode(godot::engine::a_star_grid_2d::DiagonalMode::NEVER);
+    grid.update();
+
+    // Wall in the middle column, except for the bottom row.
+    grid.set_solid_cells([Vector2i::new(1, 0), Vector2i::new(1, 1)], true);
+
+    let path = grid.cell_path(Vector2i::new(0, 0), Vector2i::new(2, 0));
+    assert_eq!(
+        path,
+        vec![
+            Vector2i::new(0, 0),
+            Vector2i::new(0, 1),
+            Vector2i::new(0, 2),
+            Vector2i::new(1, 2),
+            Vector2i::new(2, 2),
+            Vector2i::new(2, 1),
+            Vector2i::new(2, 0),
+        ]
+    );
+    assert_eq!(
+        grid.point_path(Vector2i::new(0, 0), Vector2i::new(0, 1))
+            .len(),
+        2
+    );
+
+    grid.set_solid_cells([Vector2i::new(1, 2)], true);
+    assert!(grid
+        .cell_path(Vector2i::new(0, 0), Vector2i::new(2, 0))
+        .is_empty());
+}
@@ -0,0 +1,77 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+use godot::engine::global::Error;
+use godot::engine::http::HttpError;
+use godot::engine::{
+    Animation, AnimationLibrary, AnimationPlayer, AnimationPlayerAsyncExt, HttpRequest,
+    HttpRequestAsyncExt,
+};
+use godot::prelude::*;
+
+use crate::framework::{itest, TestContext};
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+fn poll_once<F: Future + Unpin>(future: &mut F) -> Poll<F::Output> {
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut cx = Context::from_waker(&waker);
+
+    Pin::new(future).poll(&mut cx)
+}
+
+#[itest]
+fn animation_play_async_resolves_with_name() {
+    let mut animation = Animation::new();
+    animation.set_length(1.0);
+
+    let mut library = AnimationLibrary::new();
+    library.add_animation("idle".into(), animation);
+
+    let mut player = AnimationPlayer::new_alloc();
+    player.add_animation_library("".into(), library);
+
+    let mut future = player.play_async("idle");
+    assert!(poll_once(&mut future).is_pending());
+
+    // Simulates the end of playback, which would otherwise need processed frames.
+    player.emit_signal("animation_finished".into(), &["idle".to_variant()]);
+    assert_eq!(poll_once(&mut future), Poll::Ready("idle".to_string()));
+
+    player.free();
+}
+
+#[itest]
+fn http_request_async_keeps_node(ctx: &TestContext) {
+    let mut node = HttpRequest::new_alloc();
+    ctx.scene_tree.clone().add_child(node.clone().upcast());
+
+    let mut future = node.request_async("not a url");
+    match poll_once(&mut future) {
+        Poll::Ready(Err(HttpError::Request(error))) => assert_ne!(error, Error::OK),
+        other => panic!(
+            "expected an immediate error, got {:?}",
+            other.map(|r| r.is_ok())
+        ),
+    }
+
+    // The node belongs to the caller and stays usable; the failed request left no connection behind.
+    assert!(node.is_instance_valid());
+    assert!(node
+        .get_signal_connection_list("request_completed".into())
+        .is_empty());
+
+    node.free();
+}
@@ -0,0 +1,76 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::{Amplitude, AudioBus, AudioEffectReverb, Decibels};
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[itest]
+fn audio_bus_decibel_conversion() {
+    assert_eq!(Decibels::UNITY.to_amplitude(), Amplitude::UNITY);
+    assert_eq!(Amplitude::UNITY.to_decibels(), Decibels::UNITY);
+
+    let half = Decibels::from(Amplitude(0.5));
+    assert!((half.0 + 6.0206).abs() < 1e-3);
+    assert!((Amplitude::from(half).0 - 0.5).abs() < 1e-5);
+
+    assert_eq!(Amplitude(0.0).to_decibels().0, f32::NEG_INFINITY);
+}
+
+#[itest]
+fn audio_bus_create_and_remove() {
+    let master = AudioBus::master();
+    assert_eq!(master.index(), Some(0));
+
+    let mut bus = AudioBus::create("ItestBus");
+    assert_eq!(bus.name(), &StringName::from("ItestBus"));
+    assert_eq!(AudioBus::find("ItestBus"), Some(bus.clone()));
+    assert!(AudioBus::all().contains(&bus));
+
+    bus.set_volume(Decibels(-12.0));
+    assert_eq!(bus.volume(), Decibels(-12.0));
+
+    bus.set_volume(Amplitude::UNITY);
+    assert_eq!(bus.volume(), Decibels::UNITY);
+
+    bus.set_muted(true);
+    assert!(bus.is_muted());
+
+    bus.set_send(&master);
+    assert_eq!(bus.send(), Some(master));
+
+    let stale = bus.clone();
+    bus.remove();
+    assert_eq!(stale.index(), None);
+    assert_eq!(AudioBus::find("ItestBus"), None);
+}
+
+#[itest]
+fn audio_bus_effects() {
+    let mut bus = AudioBus::create("ItestEffects");
+    assert_eq!(bus.effect_count(), 0);
+    assert!(bus.find_effect::<AudioEffectReverb>().is_none());
+
+    let mut reverb = AudioEffectReverb::new();
+    reverb.set_room_size(0.25);
+
+    let effect_index = bus.add_effect(reverb);
+    assert_eq!(effect_index, 0);
+    assert_eq!(bus.effect_count(), 1);
+
+    let (found_index, found) = bus
+        .find_effect::<AudioEffectReverb>()
+        .expect("reverb on bus");
+    assert_eq!(found_index, effect_index);
+    assert_eq!(found.get_room_size(), 0.25);
+    assert!(bus.effect::<AudioEffectReverb>(1).is_none());
+
+    bus.remove_effect(effect_index);
+    assert_eq!(bus.effect_count(), 0);
+
+    bus.remove();
+}
@@ -92,5 +92,5 @@ impl HttpRequestVirtual for TestBaseRenamed {
     }
 
     // Test unnamed parameter in virtual function
-    fn process(&mut self, _: f64) {}
+    fn process(&mut self, _: real) {}
 }
@@ -0,0 +1,57 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::{layer_name, CollisionLayers, LayerKind, ProjectSettings};
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[itest]
+fn collision_layers_bit_math() {
+    let player = CollisionLayers::layer(1);
+    let walls = CollisionLayers::layer(2);
+    let mask = player | walls.with(32);
+
+    assert_eq!(mask.bits(), 0b11 | 1 << 31);
+    assert!(mask.contains(32));
+    assert!(!mask.contains(3));
+    assert_eq!(mask.numbers().collect::<Vec<_>>(), vec![1, 2, 32]);
+
+    assert_eq!(mask.without(1) & player, CollisionLayers::NONE);
+    assert!(mask.intersects(walls));
+    assert_eq!(u32::from(!CollisionLayers::ALL), 0);
+    assert_eq!(
+        CollisionLayers::from(0b100).bits(),
+        CollisionLayers::layer(3).bits()
+    );
+}
+
+#[itest]
+fn collision_layers_named() {
+    let mut settings = ProjectSettings::singleton();
+    let setting = GodotString::from("layer_names/3d_physics/layer_7");
+    let previous = settings.get_setting(setting.clone());
+    settings.set_setting(setting.clone(), "itest_hazards".to_variant());
+
+    assert_eq!(
+        layer_name(LayerKind::Physics3D, 7).as_deref(),
+        Some("itest_hazards")
+    );
+    assert_eq!(
+        CollisionLayers::named(LayerKind::Physics3D, "itest_hazards"),
+        Some(CollisionLayers::layer(7))
+    );
+    assert_eq!(
+        CollisionLayers::named(LayerKind::Physics2D, "itest_hazards"),
+        None
+    );
+    assert_eq!(
+        CollisionLayers::from_names(LayerKind::Physics3D, &["itest_hazards", "itest_missing"]),
+        Err("itest_missing")
+    );
+
+    settings.set_setting(setting, previous);
+}
@@ -0,0 +1,81 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+
+use godot::engine::{Event, EventBus, Object};
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+struct Scored(i64);
+
+impl GodotConvert for Scored {
+    type Via = i64;
+}
+
+impl ToGodot for Scored {
+    fn to_godot(&self) -> i64 {
+        self.0
+    }
+}
+
+impl FromGodot for Scored {
+    fn try_from_godot(via: i64) -> Option<Self> {
+        Some(Self(via))
+    }
+}
+
+impl Event for Scored {
+    const NAME: &'static str = "scored";
+}
+
+fn summing_listener(total: &Arc<AtomicI64>) -> impl FnMut(Scored) + Send + Sync + 'static {
+    let total = Arc::clone(total);
+    move |event: Scored| {
+        total.fetch_add(event.0, Ordering::SeqCst);
+    }
+}
+
+#[itest]
+fn event_bus_subscribe_emit() {
+    let mut bus = EventBus::new();
+    let total = Arc::new(AtomicI64::new(0));
+
+    let subscription = bus.subscribe(summing_listener(&total));
+    assert_eq!(subscription.event_name(), "scored");
+    assert!(bus.object().has_signal("scored".into()));
+
+    bus.emit(Scored(3));
+    bus.emit(Scored(4));
+    assert_eq!(total.load(Ordering::SeqCst), 7);
+
+    assert!(bus.unsubscribe(subscription.clone()));
+    assert!(!bus.unsubscribe(subscription));
+
+    bus.emit(Scored(100));
+    assert_eq!(total.load(Ordering::SeqCst), 7);
+    assert_eq!(bus.subscription_count(), 0);
+}
+
+#[itest]
+fn event_bus_owner_freed() {
+    let mut bus = EventBus::new();
+    let total = Arc::new(AtomicI64::new(0));
+
+    let owner = Object::new_alloc();
+    bus.subscribe_for(&owner, summing_listener(&total));
+    bus.emit(Scored(5));
+    assert_eq!(total.load(Ordering::SeqCst), 5);
+
+    owner.free();
+    assert_eq!(bus.subscription_count(), 1);
+
+    bus.emit(Scored(5));
+    assert_eq!(total.load(Ordering::SeqCst), 5);
+    assert_eq!(bus.subscription_count(), 0);
+}
@@ -0,0 +1,83 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::{ExpressionCache, ExpressionError, ParsedExpression};
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[itest]
+fn expression_bind_and_execute() {
+    let formula = ParsedExpression::parse("base * level + bonus", &["base", "level", "bonus"])
+        .expect("valid expression");
+
+    let value: i64 = formula
+        .bind("base", 10)
+        .bind("level", 3)
+        .bind("bonus", 2)
+        .execute()
+        .unwrap();
+    assert_eq!(value, 32);
+
+    // Evaluations are independent; unbound inputs are null, so the multiplication fails.
+    assert!(matches!(
+        formula.bind("base", 1).execute::<i64>(),
+        Err(ExpressionError::Execution { .. })
+    ));
+
+    let text = ParsedExpression::parse("str(1 + 1)", &[]).unwrap();
+    assert_eq!(text.execute::<GodotString>(), Ok(GodotString::from("2")));
+    assert!(matches!(
+        text.execute::<i64>(),
+        Err(ExpressionError::InvalidResult(_))
+    ));
+}
+
+#[itest]
+fn expression_errors() {
+    let parse_error = ParsedExpression::parse("1 +", &[]).unwrap_err();
+    assert!(matches!(parse_error, ExpressionError::Parse { .. }));
+
+    // Parsed as method call on the base instance, which is missing.
+    let unknown = ParsedExpression::parse("no_such_function(1)", &[]).unwrap();
+    assert!(matches!(
+        unknown.execute::<Variant>(),
+        Err(ExpressionError::Execution { .. })
+    ));
+}
+
+#[itest]
+fn expression_base_instance() {
+    let node = Node::new_alloc();
+    let mut renamed = node.clone();
+    renamed.set_name("Hero".into());
+
+    let expression = ParsedExpression::parse("get_name()", &[]).unwrap();
+    let name: StringName = expression
+        .evaluation()
+        .with_base_instance(&node)
+        .execute()
+        .unwrap();
+    assert_eq!(name, StringName::from("Hero"));
+
+    node.free();
+}
+
+#[itest]
+fn expression_cache_reuses_parsed() {
+    let mut cache = ExpressionCache::new();
+
+    let first = cache.get_or_parse("x * 2", &["x"]).unwrap().clone();
+    let again = cache.get_or_parse("x * 2", &["x"]).unwrap();
+    assert_eq!(again.source(), first.source());
+    assert_eq!(cache.len(), 1);
+
+    cache.get_or_parse("x * 2", &["y"]).unwrap();
+    assert_eq!(cache.len(), 2);
+
+    assert!(cache.get_or_parse("x *", &["x"]).is_err());
+    assert_eq!(cache.len(), 2);
+}
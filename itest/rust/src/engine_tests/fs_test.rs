@@ -0,0 +1,98 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::io::{ErrorKind, Read, Seek, SeekFrom, Write};
+
+use godot::engine::file_access::ModeFlags;
+use godot::engine::global::Error;
+use godot::engine::{fs, DirAccess};
+
+use crate::framework::itest;
+
+const ROOT: &str = "user://itest_fs";
+
+fn remove(path: &str) {
+    DirAccess::remove_absolute(path.into());
+}
+
+#[itest]
+fn fs_file_read_write_seek() {
+    let path = "user://itest_fs_file.txt";
+
+    let mut file = fs::open(path, ModeFlags::WRITE).expect("open for writing");
+    file.write_all(b"hello world").expect("write_all");
+    Write::flush(&mut file).expect("flush");
+    drop(file);
+
+    let mut file = fs::open(path, ModeFlags::READ).expect("open for reading");
+    let mut text = String::new();
+    file.read_to_string(&mut text).expect("read_to_string");
+    assert_eq!(text, "hello world");
+
+    assert_eq!(file.seek(SeekFrom::End(-5)).unwrap(), 6);
+    let mut word = [0u8; 5];
+    file.read_exact(&mut word).expect("read_exact");
+    assert_eq!(&word, b"world");
+
+    assert_eq!(file.seek(SeekFrom::Current(-11)).unwrap(), 0);
+    let error = file.seek(SeekFrom::Current(-1)).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidInput);
+
+    drop(file);
+    remove(path);
+}
+
+#[itest]
+fn fs_open_missing_file() {
+    let error = fs::open("user://itest_fs_missing.txt", ModeFlags::READ).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::NotFound);
+}
+
+#[itest]
+fn fs_read_and_walk_dir() {
+    let nested = format!("{ROOT}/nested");
+    assert_eq!(
+        DirAccess::make_dir_recursive_absolute(nested.as_str().into()),
+        Error::OK
+    );
+
+    let files = [format!("{ROOT}/a.txt"), format!("{nested}/b.txt")];
+    for path in &files {
+        fs::open(path.as_str(), ModeFlags::WRITE).expect("create file");
+    }
+
+    let entries: Vec<_> = fs::read_dir(ROOT).expect("read_dir").collect();
+    let names: Vec<_> = entries.iter().map(|e| e.file_name()).collect();
+    assert_eq!(names, ["nested", "a.txt"]);
+    assert!(entries[0].is_dir());
+    assert_eq!(entries[1].path(), "user://itest_fs/a.txt");
+
+    let mut paths: Vec<_> = fs::walk_dir(ROOT)
+        .expect("walk_dir")
+        .map(|entry| entry.expect("entry").path().to_string())
+        .collect();
+    paths.sort();
+    assert_eq!(
+        paths,
+        [
+            "user://itest_fs/a.txt",
+            "user://itest_fs/nested",
+            "user://itest_fs/nested/b.txt"
+        ]
+    );
+
+    for path in &files {
+        remove(path);
+    }
+    remove(&nested);
+    remove(ROOT);
+}
+
+#[itest]
+fn fs_read_res_dir() {
+    let mut entries = fs::read_dir("res://").expect("read_dir res://");
+    assert!(entries.any(|entry| entry.file_name() == "project.godot"));
+}
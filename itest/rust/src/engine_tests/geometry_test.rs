@@ -0,0 +1,124 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Vector2, Vector3};
+use godot::engine::geometry_2d::PolyJoinType;
+use godot::engine::{Geometry2D, Geometry2DExt, Geometry3D, Geometry3DExt};
+
+use crate::framework::itest;
+
+fn square(min: f32, max: f32) -> Vec<Vector2> {
+    vec![
+        Vector2::new(min, min),
+        Vector2::new(max, min),
+        Vector2::new(max, max),
+        Vector2::new(min, max),
+    ]
+}
+
+#[itest]
+fn geometry_2d_boolean_ops() {
+    let mut geometry = Geometry2D::singleton();
+    let a = square(0.0, 2.0);
+    let b = square(1.0, 3.0);
+
+    assert_eq!(geometry.merge(&a, &b).len(), 1);
+    assert_eq!(geometry.intersect(&a, &b).len(), 1);
+    assert_eq!(geometry.clip(&a, &b).len(), 1);
+
+    // Disjoint polygons have no intersection.
+    let far = square(10.0, 11.0);
+    assert!(geometry.intersect(&a, &far).is_empty());
+
+    let grown = geometry.offset(&a, 1.0, PolyJoinType::JOIN_MITER);
+    assert_eq!(grown.len(), 1);
+    assert!(grown[0].contains(&Vector2::new(-1.0, -1.0)));
+}
+
+#[itest]
+fn geometry_2d_triangulation() {
+    let mut geometry = Geometry2D::singleton();
+
+    let triangles = geometry
+        .triangulate(&square(0.0, 1.0))
+        .expect("square triangulates");
+    assert_eq!(triangles.len(), 2);
+    assert!(triangles.iter().flatten().all(|&index| index < 4));
+
+    let collinear = [
+        Vector2::ZERO,
+        Vector2::new(1.0, 0.0),
+        Vector2::new(2.0, 0.0),
+    ];
+    assert_eq!(geometry.delaunay(&collinear), None);
+}
+
+#[itest]
+fn geometry_2d_hull_and_intersections() {
+    let mut geometry = Geometry2D::singleton();
+
+    let mut points = square(0.0, 2.0);
+    points.push(Vector2::new(1.0, 1.0));
+    let hull = geometry.hull(&points);
+    assert_eq!(hull.len(), 4);
+    assert!(!hull.contains(&Vector2::new(1.0, 1.0)));
+
+    let crossing = geometry.segment_intersection(
+        (Vector2::new(0.0, 0.0), Vector2::new(2.0, 2.0)),
+        (Vector2::new(0.0, 2.0), Vector2::new(2.0, 0.0)),
+    );
+    assert_eq!(crossing, Some(Vector2::new(1.0, 1.0)));
+
+    let parallel = geometry.segment_intersection(
+        (Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0)),
+        (Vector2::new(0.0, 1.0), Vector2::new(1.0, 1.0)),
+    );
+    assert_eq!(parallel, None);
+
+    let miss = geometry.segment_circle_intersection(
+        Vector2::new(0.0, 5.0),
+        Vector2::new(10.0, 5.0),
+        Vector2::ZERO,
+        1.0,
+    );
+    assert_eq!(miss, None);
+    assert!(geometry.contains_point(&square(0.0, 2.0), Vector2::new(1.0, 1.0)));
+}
+
+#[itest]
+fn geometry_3d_intersections() {
+    let mut geometry = Geometry3D::singleton();
+
+    let triangle = [
+        Vector3::new(-1.0, 0.0, -1.0),
+        Vector3::new(1.0, 0.0, -1.0),
+        Vector3::new(0.0, 0.0, 1.0),
+    ];
+    let hit = geometry.segment_triangle_intersection(
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(0.0, -1.0, 0.0),
+        triangle,
+    );
+    assert_eq!(hit, Some(Vector3::ZERO));
+
+    let miss = geometry.segment_triangle_intersection(
+        Vector3::new(5.0, 1.0, 0.0),
+        Vector3::new(5.0, -1.0, 0.0),
+        triangle,
+    );
+    assert_eq!(miss, None);
+
+    let sphere = geometry
+        .segment_sphere_intersection(
+            Vector3::new(0.0, 0.0, 5.0),
+            Vector3::ZERO,
+            Vector3::ZERO,
+            1.0,
+        )
+        .expect("segment enters sphere");
+    assert!((sphere.point - Vector3::new(0.0, 0.0, 1.0)).length() < 0.001);
+    assert!((sphere.normal - Vector3::new(0.0, 0.0, 1.0)).length() < 0.001);
+}
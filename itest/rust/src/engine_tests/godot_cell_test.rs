@@ -0,0 +1,56 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::init::{is_main_thread, main_thread_static, InitLevel};
+use godot::prelude::*;
+
+use crate::framework::{expect_panic, itest};
+
+main_thread_static! {
+    static VISITED: Vec<GodotString> = vec![GodotString::from("start")];
+    static SERVER_STATE: i32 = 7, level = InitLevel::Servers;
+    static EDITOR_STATE: i32 = 0, level = InitLevel::Editor;
+}
+
+#[itest]
+fn godot_cell_lazy_init_and_borrow() {
+    assert!(is_main_thread());
+
+    VISITED.borrow_mut().push("next".into());
+    assert!(VISITED.is_initialized());
+    assert_eq!(VISITED.borrow().last(), Some(&GodotString::from("next")));
+    assert_eq!(VISITED.borrow()[0], GodotString::from("start"));
+
+    assert_eq!(SERVER_STATE.level(), InitLevel::Servers);
+    assert_eq!(*SERVER_STATE.borrow(), 7);
+
+    let _shared = VISITED.borrow();
+    expect_panic("mutable borrow while borrowed", || {
+        let _ = VISITED.borrow_mut();
+    });
+}
+
+#[itest]
+fn godot_cell_level_not_loaded() {
+    // Integration tests run without the editor level.
+    expect_panic("access before level is loaded", || {
+        let _ = *EDITOR_STATE.borrow();
+    });
+    assert!(!EDITOR_STATE.is_initialized());
+}
+
+#[itest]
+fn godot_cell_other_thread() {
+    let handle = std::thread::spawn(|| {
+        assert!(!is_main_thread());
+
+        expect_panic("access from other thread", || {
+            let _ = SERVER_STATE.borrow();
+        });
+    });
+
+    handle.join().expect("thread completes");
+}
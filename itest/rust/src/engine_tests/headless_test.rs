@@ -0,0 +1,24 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::{is_dedicated_server, is_headless, DisplayServer, Os};
+
+use crate::framework::itest;
+
+#[itest]
+fn headless_detection() {
+    let display_name = DisplayServer::singleton().get_name().to_string();
+    assert_eq!(is_headless(), display_name == "headless");
+
+    // Integration tests run a regular (non-exported) build.
+    assert!(!is_dedicated_server());
+}
+
+#[itest]
+fn singleton_try_singleton() {
+    let os = Os::try_singleton().expect("OS singleton is always registered");
+    assert_eq!(os.instance_id(), Os::singleton().instance_id());
+}
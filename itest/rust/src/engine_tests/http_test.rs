@@ -0,0 +1,75 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+use godot::builtin::{dict, Variant};
+use godot::engine::global::Error;
+use godot::engine::http::{self, HttpError, Response};
+use godot::prelude::ToGodot;
+
+use crate::framework::{itest, TestContext};
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+#[itest]
+fn http_invalid_url_fails_immediately(ctx: &TestContext) {
+    let mut future = http::get("not a url")
+        .header("Accept", "text/plain")
+        .send_from(&ctx.scene_tree);
+
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut cx = Context::from_waker(&waker);
+
+    match Pin::new(&mut future).poll(&mut cx) {
+        Poll::Ready(Err(HttpError::Request(error))) => assert_ne!(error, Error::OK),
+        other => panic!(
+            "expected an immediate error, got {:?}",
+            other.map(|r| r.is_ok())
+        ),
+    }
+}
+
+#[itest]
+fn http_response_helpers() {
+    let body = r#"{"score": 42}"#;
+    let response = Response {
+        status: 201,
+        headers: vec![
+            "Content-Type: application/json".to_string(),
+            "X-Count:  3 ".to_string(),
+        ],
+        body: body.as_bytes().to_vec(),
+    };
+
+    assert!(response.is_success());
+    assert_eq!(
+        response.header("content-type").as_deref(),
+        Some("application/json")
+    );
+    assert_eq!(response.header("x-count").as_deref(), Some("3"));
+    assert_eq!(response.header("missing"), None);
+    assert_eq!(response.text(), body);
+    assert_eq!(response.body_packed().len(), body.len());
+
+    let expected: Variant = dict! { "score": 42.0 }.to_variant();
+    assert_eq!(response.json(), Some(expected));
+
+    let broken = Response {
+        status: 500,
+        headers: Vec::new(),
+        body: b"{ nope".to_vec(),
+    };
+    assert!(!broken.is_success());
+    assert_eq!(broken.json(), None);
+}
@@ -0,0 +1,60 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::global::{JoyAxis, JoyButton, Key, MouseButton};
+use godot::engine::{InputBinding, InputDevice, InputMap, InputMapExt};
+
+use crate::framework::itest;
+
+#[itest]
+fn input_binding_event_roundtrip() {
+    let bindings = [
+        InputBinding::key(Key::KEY_S).with_ctrl().with_shift(),
+        InputBinding::physical_key(Key::KEY_W),
+        InputBinding::mouse_button(MouseButton::MOUSE_BUTTON_RIGHT).with_alt(),
+        InputBinding::joy_button(JoyButton::JOY_BUTTON_A).with_device(InputDevice::Id(2)),
+        InputBinding::joy_axis(JoyAxis::JOY_AXIS_LEFT_X, false),
+    ];
+
+    for binding in bindings {
+        let event = binding.to_event();
+        assert_eq!(InputBinding::from_event(&event), Some(binding));
+    }
+}
+
+#[itest]
+fn input_map_define_and_rebind() {
+    let mut input_map = InputMap::singleton();
+    let jump = InputBinding::key(Key::KEY_SPACE);
+    let pad_jump = InputBinding::joy_button(JoyButton::JOY_BUTTON_A);
+
+    let action = input_map
+        .define_action("itest_jump")
+        .deadzone(0.3)
+        .bind(jump)
+        .bind(pad_jump)
+        .done();
+    assert!(input_map.has_action(action.clone()));
+    assert_eq!(
+        input_map.action_bindings("itest_jump"),
+        vec![jump, pad_jump]
+    );
+
+    let new_jump = InputBinding::key(Key::KEY_W);
+    assert!(input_map.rebind("itest_jump", &jump, new_jump));
+    assert!(!input_map.rebind("itest_jump", &jump, new_jump));
+    assert_eq!(
+        input_map.action_bindings("itest_jump"),
+        vec![new_jump, pad_jump]
+    );
+
+    // Redefining clears previous bindings.
+    input_map.define_action("itest_jump").done();
+    assert!(input_map.action_bindings("itest_jump").is_empty());
+
+    input_map.erase_action(action);
+    assert!(input_map.action_bindings("itest_jump").is_empty());
+}
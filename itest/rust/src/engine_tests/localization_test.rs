@@ -0,0 +1,61 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::GodotString;
+use godot::engine::{tr, TranslatableString, Translation, TranslationServer};
+use godot::obj::Gd;
+
+use crate::framework::itest;
+
+fn with_translation(f: impl FnOnce()) {
+    let mut server = TranslationServer::singleton();
+
+    let mut translation = Translation::new();
+    translation.set_locale(server.get_locale());
+    translation.add_message("GREETING".into(), "Hello, {name}!".into());
+    translation.add_message("EXCLAIM".into(), "{greeting} {greeting}".into());
+
+    server.add_translation(Gd::clone(&translation));
+    f();
+    server.remove_translation(translation);
+}
+
+#[itest]
+fn tr_untranslated_key() {
+    assert_eq!(tr!("UNKNOWN_KEY"), GodotString::from("UNKNOWN_KEY"));
+    assert_eq!(
+        tr!("{a} and {b}", a = 1, b = "two"),
+        GodotString::from("1 and two")
+    );
+}
+
+#[itest]
+fn tr_placeholders() {
+    with_translation(|| {
+        assert_eq!(
+            tr!("GREETING", name = "Ferris"),
+            GodotString::from("Hello, Ferris!")
+        );
+
+        // Missing arguments are kept, and substituted values are not expanded again.
+        assert_eq!(tr!("GREETING"), GodotString::from("Hello, {name}!"));
+        assert_eq!(
+            tr!("EXCLAIM", greeting = "{greeting}"),
+            GodotString::from("{greeting} {greeting}")
+        );
+    });
+}
+
+#[itest]
+fn translatable_string_resolves_key() {
+    with_translation(|| {
+        let text = TranslatableString::new("GREETING");
+
+        assert_eq!(text.key(), &GodotString::from("GREETING"));
+        assert_eq!(text.translated(), GodotString::from("Hello, {name}!"));
+        assert_eq!(text.to_string(), "Hello, {name}!");
+    });
+}
@@ -0,0 +1,61 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::{set_main_loop_type, ClassDb, MainLoop, MainLoopVirtual, ProjectSettings};
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=MainLoop)]
+struct CustomMainLoop {
+    frames: u32,
+}
+
+#[godot_api]
+impl MainLoopVirtual for CustomMainLoop {
+    fn process(&mut self, _delta: real) -> bool {
+        self.frames += 1;
+        false
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=SceneTree)]
+struct CustomSceneTree {}
+
+#[godot_api]
+impl SceneTreeVirtual for CustomSceneTree {
+    fn initialize(&mut self) {}
+}
+
+#[itest]
+fn main_loop_subclass() {
+    let main_loop = Gd::<CustomMainLoop>::new_default();
+    assert!(main_loop.is_class("MainLoop".into()));
+    assert_eq!(main_loop.bind().frames, 0);
+    main_loop.free();
+
+    // Not instantiated: a SceneTree creates its own root window.
+    let db = ClassDb::singleton();
+    assert!(db.is_parent_class("CustomSceneTree".into(), "SceneTree".into()));
+    assert!(db.is_parent_class("CustomSceneTree".into(), "MainLoop".into()));
+}
+
+#[itest]
+fn main_loop_type_setting() {
+    let setting = GodotString::from("application/run/main_loop_type");
+    let mut settings = ProjectSettings::singleton();
+    let previous = settings.get_setting(setting.clone());
+
+    set_main_loop_type::<CustomMainLoop>();
+    assert_eq!(
+        settings.get_setting(setting.clone()),
+        "CustomMainLoop".to_variant()
+    );
+
+    settings.set_setting(setting, previous);
+}
@@ -4,6 +4,56 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+mod animation_builder_test;
+mod astar_test;
+#[cfg(since_api = "4.2")]
+mod async_ext_test;
+mod audio_bus_test;
+mod collision_layers_test;
+#[cfg(since_api = "4.2")]
+mod event_bus_test;
+mod expression_eval_test;
+mod fs_test;
+mod geometry_test;
+mod godot_cell_test;
+mod headless_test;
+#[cfg(since_api = "4.2")]
+mod http_test;
+mod input_map_test;
+mod localization_test;
+mod main_loop_test;
+mod multimesh_test;
 mod native_structures_test;
+mod navigation_test;
 mod node_test;
+mod os_env_test;
+mod packed_scene_test;
+mod packed_state_test;
+mod physics_query_test;
+#[cfg(since_api = "4.2")]
+mod profiling_test;
+#[cfg(since_api = "4.2")]
+mod property_binding_test;
+mod res_path_test;
+mod res_uid_test;
+mod sampling_test;
+mod save_state_test;
+mod scene_snapshot_test;
+mod script_instance_test;
+mod script_interop_test;
+mod shader_material_test;
+mod shader_source_test;
+#[cfg(since_api = "4.2")]
+mod signal_future_test;
+mod skeleton_test;
+#[cfg(since_api = "4.2")]
+mod structural_defer_test;
+mod theme_test;
+mod tile_map_test;
+mod typed_config_test;
+mod undo_redo_test;
 mod utilities_test;
+mod variant_codec_test;
+mod window_test;
+#[cfg(since_api = "4.2")]
+mod worker_pool_test;
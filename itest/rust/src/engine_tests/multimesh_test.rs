@@ -0,0 +1,76 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Basis, Color, Transform2D, Transform3D, Vector2, Vector3};
+use godot::engine::multi_mesh::TransformFormat;
+use godot::engine::{InstanceData, MultiMesh, MultiMeshExt};
+
+use crate::framework::{expect_panic, itest};
+
+#[itest]
+fn multimesh_upload_3d() {
+    let mut multimesh = MultiMesh::new();
+    multimesh.set_transform_format(TransformFormat::TRANSFORM_3D);
+    multimesh.set_use_colors(true);
+
+    let transforms = [
+        // Asymmetric basis, so that a transposed layout would be detected.
+        Transform3D::new(
+            Basis::from_rows(
+                Vector3::new(1.0, 2.0, 0.0),
+                Vector3::new(0.0, 1.0, 3.0),
+                Vector3::new(4.0, 0.0, 1.0),
+            ),
+            Vector3::new(1.0, 2.0, 3.0),
+        ),
+        Transform3D::IDENTITY.translated(Vector3::new(-5.0, 0.0, 5.0)),
+    ];
+    let red = Color::from_rgba(1.0, 0.0, 0.0, 1.0);
+
+    multimesh.upload_3d(
+        transforms
+            .iter()
+            .map(|&t| InstanceData::new(t).with_color(red)),
+    );
+
+    assert_eq!(multimesh.get_instance_count(), 2);
+    assert_eq!(multimesh.get_buffer().len(), 2 * (12 + 4));
+    assert_eq!(multimesh.get_instance_transform(0), transforms[0]);
+    assert_eq!(multimesh.get_instance_transform(1), transforms[1]);
+    assert_eq!(multimesh.get_instance_color(1), red);
+
+    // Plain transforms shrink the instance count as well.
+    multimesh.upload_3d([Transform3D::IDENTITY]);
+    assert_eq!(multimesh.get_instance_count(), 1);
+    assert_eq!(multimesh.get_instance_color(0), Color::WHITE);
+}
+
+#[itest]
+fn multimesh_upload_2d() {
+    let mut multimesh = MultiMesh::new();
+    multimesh.set_transform_format(TransformFormat::TRANSFORM_2D);
+    multimesh.set_use_custom_data(true);
+
+    let transform = Transform2D::from_angle_origin(0.5, Vector2::new(10.0, 20.0));
+    let custom = Color::from_rgba(0.1, 0.2, 0.3, 0.4);
+    multimesh.upload_2d([InstanceData::new(transform).with_custom_data(custom)]);
+
+    assert_eq!(multimesh.get_buffer().len(), 8 + 4);
+    assert!(multimesh
+        .get_instance_transform_2d(0)
+        .is_equal_approx(&transform));
+    assert_eq!(multimesh.get_instance_custom_data(0), custom);
+}
+
+#[itest]
+fn multimesh_upload_wrong_format() {
+    let mut multimesh = MultiMesh::new();
+    multimesh.set_transform_format(TransformFormat::TRANSFORM_2D);
+
+    expect_panic("3D upload to 2D multimesh", move || {
+        multimesh.upload_3d([Transform3D::IDENTITY]);
+    });
+}
@@ -0,0 +1,57 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Vector2, Vector3};
+use godot::engine::{NavigationAgent2D, NavigationAgentExt, NavigationQuery3D, NavigationServer3D};
+
+use crate::framework::itest;
+
+#[itest]
+fn navigation_query_empty_map() {
+    let mut server = NavigationServer3D::singleton();
+    let map = server.map_create();
+
+    let query = NavigationQuery3D::new(map)
+        .with_optimize(false)
+        .with_navigation_layers(0b11);
+    assert_eq!(query.map(), map);
+    assert!(query
+        .path(Vector3::ZERO, Vector3::new(5.0, 0.0, 5.0))
+        .is_empty());
+
+    server.free_rid(map);
+}
+
+#[itest]
+fn navigation_agent_without_path() {
+    let agent = NavigationAgent2D::new_alloc();
+
+    assert!(agent.current_path().is_empty());
+    assert!(agent.remaining_path().is_empty());
+
+    agent.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn navigation_agent_velocity_callback() {
+    use godot::prelude::ToGodot;
+    use std::sync::{Arc, Mutex};
+
+    let mut agent = NavigationAgent2D::new_alloc();
+    let received = Arc::new(Mutex::new(Vec::new()));
+
+    let sink = Arc::clone(&received);
+    agent.on_velocity_computed(move |velocity| sink.lock().unwrap().push(velocity));
+
+    agent.emit_signal(
+        "velocity_computed".into(),
+        &[Vector2::new(3.0, 4.0).to_variant()],
+    );
+    assert_eq!(*received.lock().unwrap(), vec![Vector2::new(3.0, 4.0)]);
+
+    agent.free();
+}
@@ -0,0 +1,101 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::{
+    cmdline_args, cmdline_user_args, env_var, has_feature, remove_env_var, set_env_var, Engine,
+    FeatureTag, FeatureTags, FrameInfo,
+};
+
+use godot::init::{has_class, GodotVersion};
+use godot::prelude::ToGodot;
+
+use crate::framework::itest;
+
+#[itest]
+fn os_env_var_roundtrip() {
+    let name = "GDEXT_ITEST_ENV_VAR";
+    assert_eq!(env_var(name), None);
+
+    set_env_var(name, "value");
+    assert_eq!(env_var(name).as_deref(), Some("value"));
+    assert_eq!(std::env::var(name).as_deref(), Ok("value"));
+
+    remove_env_var(name);
+    assert_eq!(env_var(name), None);
+}
+
+#[itest]
+fn os_cmdline_args_exclude_user_args() {
+    let args = cmdline_args();
+    for user_arg in cmdline_user_args() {
+        assert!(!args.contains(&user_arg), "user arg `{user_arg}` leaked");
+    }
+}
+
+#[itest]
+fn os_feature_tags() {
+    let features = FeatureTags::current();
+
+    for &tag in FeatureTag::ALL {
+        assert_eq!(features.contains(tag), has_feature(tag.name()), "{tag}");
+    }
+
+    // Exactly one of each pair is active.
+    assert_ne!(
+        features.contains(FeatureTag::Debug),
+        features.contains(FeatureTag::Release)
+    );
+    assert_ne!(
+        features.contains(FeatureTag::Double),
+        features.contains(FeatureTag::Single)
+    );
+}
+
+#[itest]
+fn os_feature_tag_set() {
+    let mut tags: FeatureTags = [FeatureTag::Web, FeatureTag::Debug].into_iter().collect();
+    assert_eq!(tags.len(), 2);
+    assert_eq!(
+        tags.iter().collect::<Vec<_>>(),
+        [FeatureTag::Debug, FeatureTag::Web]
+    );
+
+    tags.remove(FeatureTag::Web);
+    assert!(!tags.contains(FeatureTag::Web));
+    assert_eq!(format!("{tags:?}"), "{Debug}");
+}
+
+#[itest]
+fn os_frame_info() {
+    let info = FrameInfo::current();
+    let engine = Engine::singleton();
+
+    assert_eq!(
+        info.physics_ticks_per_second,
+        engine.get_physics_ticks_per_second()
+    );
+    assert_eq!(info.time_scale, engine.get_time_scale());
+    assert!(info.process_frames <= engine.get_process_frames());
+}
+
+#[itest]
+fn os_godot_version_matches_engine() {
+    let version = GodotVersion::current();
+    let info = Engine::singleton().get_version_info();
+
+    assert_eq!(info.get("major"), Some(version.major.to_variant()));
+    assert_eq!(info.get("minor"), Some(version.minor.to_variant()));
+    assert_eq!(info.get("patch"), Some(version.patch.to_variant()));
+    assert_eq!(info.get("status"), Some(version.status.to_variant()));
+    assert_eq!(info.get("build"), Some(version.build.to_variant()));
+}
+
+#[itest]
+fn os_has_class() {
+    assert!(has_class("Node"));
+    assert!(has_class("NavigationServer3D"));
+    assert!(!has_class("NoSuchClassInGodot"));
+}
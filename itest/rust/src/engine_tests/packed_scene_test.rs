@@ -0,0 +1,57 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::{Node2D, Node3D, PackedScene, PackedSceneExt, SceneError};
+use godot::obj::Gd;
+use godot::prelude::Node;
+
+use crate::framework::itest;
+
+fn make_scene() -> Gd<PackedScene> {
+    let root = Node3D::new_alloc();
+
+    let mut scene = PackedScene::new();
+    scene.pack(root.clone().upcast());
+    root.free();
+
+    scene
+}
+
+#[itest]
+fn packed_scene_instantiate_as() {
+    let scene = make_scene();
+
+    let node = scene.instantiate_as::<Node3D>();
+    node.free();
+
+    // Base classes of the root are accepted as well.
+    let node = scene
+        .try_instantiate_as::<Node>()
+        .expect("instantiate as base class");
+    assert_eq!(node.get_class(), "Node3D".into());
+    node.free();
+}
+
+#[itest]
+fn packed_scene_instantiate_as_wrong_type() {
+    let scene = make_scene();
+
+    let err = scene
+        .try_instantiate_as::<Node2D>()
+        .expect_err("root is not Node2D");
+
+    assert_eq!(
+        err,
+        SceneError::RootType {
+            expected: "Node2D".to_string(),
+            actual: "Node3D".to_string(),
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "scene root has class `Node3D`, expected `Node2D` or derived"
+    );
+}
@@ -0,0 +1,131 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::bind::PackedState;
+use godot::builtin::{NodePath, PackedByteArray, Vector2, Vector3i};
+use godot::engine::{
+    MultiplayerSynchronizer, PackedState, PackedStateError, PackedSyncExt, PackedSyncMode,
+};
+
+use crate::framework::itest;
+
+#[derive(PackedState, Debug, PartialEq)]
+#[packed(version = 3)]
+struct PlayerState {
+    position: Vector2,
+    health: u16,
+    is_crouching: bool,
+    cell: Vector3i,
+    inputs: [i8; 2],
+    #[packed(skip)]
+    predicted: bool,
+}
+
+fn make_state() -> PlayerState {
+    PlayerState {
+        position: Vector2::new(1.5, -2.0),
+        health: 300,
+        is_crouching: true,
+        cell: Vector3i::new(4, -5, 6),
+        inputs: [-1, 1],
+        predicted: true,
+    }
+}
+
+#[itest]
+fn packed_state_roundtrip() {
+    let bytes = make_state().pack();
+
+    assert_eq!(PlayerState::VERSION, 3);
+    assert_eq!(bytes.len(), PlayerState::SIZE);
+    assert_eq!(bytes.get(0), 3);
+
+    // Little-endian `u16` right after the version byte and position.
+    let health_offset = 1 + 2 * std::mem::size_of::<godot::builtin::real>();
+    assert_eq!(bytes.get(health_offset), 44);
+    assert_eq!(bytes.get(health_offset + 1), 1);
+
+    let unpacked = PlayerState::unpack(&bytes).expect("unpack");
+    assert_eq!(
+        unpacked,
+        PlayerState {
+            predicted: false,
+            ..make_state()
+        }
+    );
+}
+
+#[itest]
+fn packed_state_errors() {
+    let bytes = make_state().pack();
+
+    assert_eq!(
+        PlayerState::unpack(&PackedByteArray::new()),
+        Err(PackedStateError::WrongSize {
+            expected: PlayerState::SIZE,
+            found: 0,
+        })
+    );
+
+    let mut other_version = bytes.clone();
+    other_version.set(0, 2);
+    assert_eq!(
+        PlayerState::unpack(&other_version),
+        Err(PackedStateError::VersionMismatch {
+            expected: 3,
+            found: 2,
+        })
+    );
+
+    let mut truncated = bytes.clone();
+    truncated.resize(PlayerState::SIZE - 1);
+    assert!(matches!(
+        PlayerState::unpack(&truncated),
+        Err(PackedStateError::WrongSize { .. })
+    ));
+
+    let crouching_offset = 1 + 2 * std::mem::size_of::<godot::builtin::real>() + 2;
+    let mut invalid = bytes;
+    invalid.set(crouching_offset, 7);
+    assert_eq!(
+        PlayerState::unpack(&invalid),
+        Err(PackedStateError::InvalidField {
+            field: "is_crouching"
+        })
+    );
+}
+
+#[itest]
+fn packed_state_unpack_into_keeps_state_on_error() {
+    let mut state = make_state();
+
+    let result = state.unpack_into(&PackedByteArray::from(&[3, 1, 2]));
+    assert!(result.is_err());
+    assert_eq!(state, make_state());
+}
+
+#[itest]
+fn packed_state_add_sync_property() {
+    let mut synchronizer = MultiplayerSynchronizer::new_alloc();
+    assert!(synchronizer.get_replication_config().is_none());
+
+    synchronizer.add_packed_property(".:net_state", PackedSyncMode::Always);
+    let config = synchronizer
+        .get_replication_config()
+        .expect("replication config created");
+
+    let path = NodePath::from(".:net_state");
+    assert!(config.has_property(path.clone()));
+    assert!(config.property_get_spawn(path.clone()));
+    assert!(config.property_get_sync(path.clone()));
+
+    // Registering again changes the mode of the existing property.
+    synchronizer.add_packed_property(".:net_state", PackedSyncMode::SpawnOnly);
+    assert_eq!(config.get_properties().len(), 1);
+    assert!(!config.property_get_sync(path));
+
+    synchronizer.free();
+}
@@ -0,0 +1,77 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{dict, Dictionary, Rid, Vector2, Vector3};
+use godot::engine::{Node2D, Object, PhysicsQuery2D, QueryHit, RayHit2D, RayHit3D};
+
+use crate::framework::{itest, TestContext};
+
+#[itest]
+fn physics_ray_hit_from_dictionary() {
+    let collider = Object::new_alloc();
+    let result = dict! {
+        "position": Vector2::new(1.0, 2.0),
+        "normal": Vector2::new(0.0, -1.0),
+        "collider": collider.clone(),
+        "collider_id": collider.instance_id().to_i64(),
+        "rid": Rid::new(7),
+        "shape": 2,
+    };
+
+    let hit = RayHit2D::from_dictionary(&result).expect("hit");
+    assert_eq!(hit.position, Vector2::new(1.0, 2.0));
+    assert_eq!(hit.normal, Vector2::new(0.0, -1.0));
+    assert_eq!(hit.collider, Some(collider.clone()));
+    assert_eq!(hit.collider_id, Some(collider.instance_id()));
+    assert_eq!(hit.rid, Rid::new(7));
+    assert_eq!(hit.shape_index, 2);
+
+    // A 2D result lacks the 3D position type.
+    assert!(RayHit3D::from_dictionary(&result).is_none());
+    assert!(RayHit2D::from_dictionary(&Dictionary::new()).is_none());
+
+    collider.free();
+}
+
+#[itest]
+fn physics_query_hit_from_dictionary() {
+    let result = dict! {
+        "collider_id": 0,
+        "rid": Rid::new(3),
+        "shape": 0,
+    };
+
+    let hit = QueryHit::from_dictionary(&result).expect("hit");
+    assert_eq!(hit.collider, None);
+    assert_eq!(hit.collider_id, None);
+    assert_eq!(hit.rid, Rid::new(3));
+
+    let missing = dict! { "position": Vector3::ZERO };
+    assert!(QueryHit::from_dictionary(&missing).is_none());
+}
+
+#[itest]
+fn physics_query_empty_space(ctx: &TestContext) {
+    let node = Node2D::new_alloc();
+    ctx.scene_tree.clone().add_child(node.clone().upcast());
+
+    let mut query = PhysicsQuery2D::for_node(&node);
+    let far = Vector2::new(1e6, 1e6);
+
+    let hit = query
+        .ray(far, far + Vector2::new(100.0, 0.0))
+        .collision_mask(1)
+        .exclude(&[Rid::new(1)])
+        .collide_with_areas(true)
+        .cast();
+    assert!(hit.is_none());
+
+    // The previous exclusion list does not leak into the next query.
+    assert!(query.ray(far, far + Vector2::UP).cast().is_none());
+    assert!(query.point(far).max_results(4).intersect().is_empty());
+
+    node.free();
+}
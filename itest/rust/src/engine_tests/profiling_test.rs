@@ -0,0 +1,50 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use godot::builtin::StringName;
+use godot::engine::{profile_scope, Performance, PerformanceExt, ProfileScope};
+
+use crate::framework::{expect_panic, itest};
+
+#[itest]
+fn performance_add_monitor() {
+    static READS: AtomicU32 = AtomicU32::new(0);
+
+    let id = StringName::from("ITest/Reads");
+    let mut performance = Performance::singleton();
+    performance.add_monitor(id.clone(), || {
+        READS.fetch_add(1, Ordering::Relaxed) as f64 + 10.0
+    });
+
+    assert!(performance.has_custom_monitor(id.clone()));
+    assert_eq!(performance.get_custom_monitor(id.clone()).to::<f64>(), 10.0);
+    assert_eq!(performance.get_custom_monitor(id.clone()).to::<f64>(), 11.0);
+
+    expect_panic("duplicate monitor", || {
+        Performance::singleton().add_monitor(id.clone(), || 0.0);
+    });
+
+    performance.remove_custom_monitor(id.clone());
+    assert!(!performance.has_custom_monitor(id));
+}
+
+#[itest]
+fn profile_scope_registers_monitor() {
+    {
+        profile_scope!("ITest/Scope");
+    }
+
+    let performance = Performance::singleton();
+    assert!(performance.has_custom_monitor("Rust/ITest/Scope".into()));
+
+    // Timings are reported for completed frames only; the test runs within a single frame.
+    let timing = ProfileScope::last_frame("ITest/Scope").expect("scope was entered");
+    assert_eq!(timing.calls, 0);
+
+    assert_eq!(ProfileScope::last_frame("ITest/Never"), None);
+}
@@ -0,0 +1,110 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::PropertyBinding;
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct BoundModel {
+    #[export(notify)]
+    score: i64,
+
+    #[export(notify)]
+    lives: i64,
+
+    #[export(notify = title_changed)]
+    title: GodotString,
+
+    #[base]
+    base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl BoundModel {}
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct BoundView {
+    #[var]
+    text: GodotString,
+
+    calls: Vec<i64>,
+}
+
+#[godot_api]
+impl BoundView {
+    #[func]
+    fn show_lives(&mut self, lives: i64) {
+        self.calls.push(lives);
+    }
+}
+
+#[itest]
+fn property_binding_to_property() {
+    let mut model = Gd::<BoundModel>::new_default();
+    model.bind_mut().set_score(7);
+
+    let view = Gd::<BoundView>::new_default();
+    let handle = PropertyBinding::new(&model, "score")
+        .with_transform(|score: i64| GodotString::from(format!("Score: {score}")))
+        .to_property(&view, "text");
+
+    // Initial value is applied immediately.
+    assert_eq!(view.bind().text, GodotString::from("Score: 7"));
+
+    model.bind_mut().set_score(12);
+    assert_eq!(view.bind().text, GodotString::from("Score: 12"));
+
+    // Changes of other properties on the shared signal are ignored.
+    model.bind_mut().set_lives(2);
+    assert_eq!(view.bind().text, GodotString::from("Score: 12"));
+
+    assert!(handle.is_bound());
+    assert!(handle.clone().unbind());
+    assert!(!handle.is_bound());
+    assert!(!handle.unbind());
+
+    model.bind_mut().set_score(99);
+    assert_eq!(view.bind().text, GodotString::from("Score: 12"));
+
+    view.free();
+}
+
+#[itest]
+fn property_binding_to_method_and_signal() {
+    let mut model = Gd::<BoundModel>::new_default();
+    let view = Gd::<BoundView>::new_default();
+
+    PropertyBinding::new(&model, "lives").to_method(&view, "show_lives");
+    PropertyBinding::new(&model, "title")
+        .with_signal("title_changed")
+        .to_property(&view, "text");
+
+    model.bind_mut().set_lives(3);
+    model.bind_mut().set_title("Level 2".into());
+
+    assert_eq!(view.bind().calls, vec![0, 3]);
+    assert_eq!(view.bind().text, GodotString::from("Level 2"));
+
+    view.free();
+}
+
+#[itest]
+fn property_binding_target_freed() {
+    let mut model = Gd::<BoundModel>::new_default();
+    let view = Gd::<BoundView>::new_default();
+
+    let handle = PropertyBinding::new(&model, "lives").to_method(&view, "show_lives");
+    view.free();
+    assert!(handle.is_bound());
+
+    // The next change notices the freed target and disconnects.
+    model.bind_mut().set_lives(1);
+    assert!(!handle.is_bound());
+}
@@ -0,0 +1,27 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+use godot::engine::ResPath;
+use godot::prelude::*;
+
+const RUNNER: ResPath = res!("res://TestRunner.tscn");
+
+#[itest]
+fn res_path_as_str() {
+    assert_eq!(RUNNER.as_str(), "res://TestRunner.tscn");
+    assert_eq!(RUNNER.to_string(), "res://TestRunner.tscn");
+    assert_eq!(
+        GodotString::from(RUNNER),
+        GodotString::from("res://TestRunner.tscn")
+    );
+}
+
+#[itest]
+fn res_path_load() {
+    let scene = RUNNER.load::<PackedScene>();
+    assert!(scene.can_instantiate());
+}
@@ -0,0 +1,35 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::{try_load, ResUid};
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[itest]
+fn res_uid_matches_godot_text() {
+    let uid = ResUid::create();
+    let godot_text = godot::engine::ResourceUid::singleton().id_to_text(uid.to_i64());
+
+    assert_eq!(uid.to_string(), godot_text.to_string());
+    assert_eq!(ResUid::parse(&godot_text.to_string()), Some(uid));
+}
+
+#[itest]
+fn res_uid_register_and_load() {
+    let uid = ResUid::create();
+    assert_eq!(uid.path(), None);
+    assert!(try_load::<PackedScene>(uid).is_none());
+
+    uid.register("res://TestRunner.tscn");
+    assert_eq!(uid.path().as_deref(), Some("res://TestRunner.tscn"));
+
+    let scene = uid.try_load::<PackedScene>();
+    assert!(scene.is_some());
+
+    uid.unregister();
+    assert_eq!(uid.path(), None);
+}
@@ -0,0 +1,102 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::math::assert_eq_approx;
+use godot::builtin::{Color, Vector2, Vector2i};
+use godot::engine::gradient::InterpolationMode;
+use godot::engine::{
+    BakedCurve2D, Curve, Curve2D, CurveSampler, FastNoiseLite, Gradient, GradientSampler, NoiseExt,
+};
+
+use crate::framework::itest;
+
+#[itest]
+fn curve_sampler_matches_godot() {
+    let mut curve = Curve::new();
+    curve.add_point(Vector2::new(0.0, 0.0));
+    curve.add_point(Vector2::new(0.5, 1.0));
+    curve.add_point(Vector2::new(1.0, 0.25));
+
+    let sampler = CurveSampler::with_resolution(&curve, 200);
+    assert_eq!(sampler.values().len(), 201);
+
+    for offset in [0.0, 0.1, 0.33, 0.5, 0.75, 1.0] {
+        assert_eq_approx!(
+            sampler.sample(offset),
+            curve.sample(offset),
+            fn = |a: &f32, b: &f32| (a - b).abs() < 0.01,
+            "offset {offset}"
+        );
+    }
+
+    // Out of range is clamped.
+    assert_eq!(sampler.sample(-1.0), sampler.values()[0]);
+    assert_eq!(sampler.sample(2.0), sampler.values()[200]);
+}
+
+#[itest]
+fn baked_curve_2d_sample() {
+    let mut curve = Curve2D::new();
+    curve.set_bake_interval(1.0);
+    curve.add_point(Vector2::new(0.0, 0.0));
+    curve.add_point(Vector2::new(10.0, 0.0));
+    curve.add_point(Vector2::new(10.0, 10.0));
+
+    let baked = BakedCurve2D::new(&curve);
+    assert_eq!(baked.points(), curve.get_baked_points().as_slice());
+    assert_eq_approx!(baked.length(), 20.0);
+
+    assert_eq_approx!(baked.sample(0.0), Vector2::new(0.0, 0.0));
+    assert_eq_approx!(baked.sample(5.0), Vector2::new(5.0, 0.0));
+    assert_eq_approx!(baked.sample(15.0), Vector2::new(10.0, 5.0));
+    assert_eq_approx!(baked.sample(100.0), Vector2::new(10.0, 10.0));
+}
+
+#[itest]
+fn baked_curve_2d_empty() {
+    let baked = BakedCurve2D::new(&Curve2D::new());
+
+    assert!(baked.points().is_empty());
+    assert_eq!(baked.length(), 0.0);
+    assert_eq!(baked.sample(1.0), Vector2::ZERO);
+}
+
+#[itest]
+fn gradient_sampler_matches_godot() {
+    let mut gradient = Gradient::new();
+    gradient.add_point(0.3, Color::from_rgb(1.0, 0.0, 0.0));
+    gradient.add_point(0.8, Color::from_rgb(0.0, 0.0, 1.0));
+
+    for mode in [
+        InterpolationMode::GRADIENT_INTERPOLATE_LINEAR,
+        InterpolationMode::GRADIENT_INTERPOLATE_CONSTANT,
+        InterpolationMode::GRADIENT_INTERPOLATE_CUBIC,
+    ] {
+        gradient.set_interpolation_mode(mode);
+        let sampler = GradientSampler::new(&gradient);
+
+        assert_eq!(sampler.offsets(), &[0.0, 0.3, 0.8, 1.0]);
+        for offset in [-0.5, 0.0, 0.15, 0.3, 0.42, 0.8, 0.9, 1.0, 1.5] {
+            assert_eq_approx!(
+                sampler.sample(offset),
+                gradient.sample(offset),
+                "mode {mode:?}, offset {offset}"
+            );
+        }
+    }
+}
+
+#[itest]
+fn noise_sample_grid_2d() {
+    let noise = FastNoiseLite::new();
+    let origin = Vector2::new(3.0, -2.0);
+
+    let values = noise.sample_grid_2d(origin, Vector2i::new(4, 3), 0.5);
+    assert_eq!(values.len(), 12);
+
+    // Cell (1, 2) is at origin + (0.5, 1.0).
+    assert_eq!(values[2 * 4 + 1], noise.get_noise_2d(3.5, -1.0));
+}
@@ -0,0 +1,99 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::bind::SaveState;
+use godot::builtin::meta::ToGodot;
+use godot::builtin::{dict, GodotString, Vector2};
+use godot::engine::{ConfigFile, SaveState, SaveStateError};
+
+use crate::framework::itest;
+
+#[derive(SaveState, Debug, PartialEq)]
+struct PlayerState {
+    #[save]
+    health: i64,
+    #[save(key = "pos")]
+    position: Vector2,
+    #[save]
+    name: GodotString,
+    is_dashing: bool,
+}
+
+fn make_state() -> PlayerState {
+    PlayerState {
+        health: 75,
+        position: Vector2::new(1.5, -2.0),
+        name: "Hero".into(),
+        is_dashing: true,
+    }
+}
+
+#[itest]
+fn save_state_roundtrip() {
+    let saved = make_state();
+    let state = saved.save_state();
+
+    assert_eq!(state.len(), 3);
+    assert_eq!(state.get("pos"), Some(Vector2::new(1.5, -2.0).to_variant()));
+    assert!(!state.contains_key("is_dashing"));
+
+    let mut loaded = PlayerState {
+        health: 0,
+        position: Vector2::ZERO,
+        name: GodotString::new(),
+        is_dashing: false,
+    };
+    loaded.load_state(&state).expect("load_state");
+
+    assert_eq!(loaded.health, 75);
+    assert_eq!(loaded.position, saved.position);
+    assert_eq!(loaded.name, saved.name);
+    assert!(!loaded.is_dashing, "unsaved field must not be touched");
+}
+
+#[itest]
+fn save_state_missing_key() {
+    let mut player = make_state();
+    player
+        .load_state(&dict! { "health": 10 })
+        .expect("load_state");
+
+    assert_eq!(player.health, 10);
+    assert_eq!(player.position, Vector2::new(1.5, -2.0));
+}
+
+#[itest]
+fn save_state_invalid_value() {
+    let mut player = make_state();
+    let err = player
+        .load_state(&dict! { "health": 10, "pos": "not a vector" })
+        .expect_err("type mismatch");
+
+    assert!(matches!(err, SaveStateError::InvalidValue { ref key, .. } if key == "pos"));
+    assert_eq!(
+        player,
+        make_state(),
+        "failed load must not modify any field"
+    );
+}
+
+#[itest]
+fn save_state_config_file() {
+    let mut config = ConfigFile::new();
+    make_state().save_to_config(&mut config, "player");
+
+    assert_eq!(
+        config.get_value("player".into(), "health".into()),
+        75.to_variant()
+    );
+
+    let mut loaded = make_state();
+    loaded.health = 1;
+    loaded
+        .load_from_config(&config, "player")
+        .expect("load_from_config");
+    assert_eq!(loaded, make_state());
+}
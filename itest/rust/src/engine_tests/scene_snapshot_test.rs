@@ -0,0 +1,103 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::{BoxMesh, MeshInstance3D, NodeSnapshot, SnapshotDiff, SnapshotOptions};
+use godot::prelude::*;
+
+use crate::framework::{expect_panic, itest};
+
+fn make_scene() -> Gd<Node3D> {
+    let mut root = Node3D::new_alloc();
+    root.set_name("Level".into());
+    root.set_position(Vector3::new(1.0, 2.0, 3.0));
+
+    let mut floor = MeshInstance3D::new_alloc();
+    floor.set_name("Floor".into());
+    floor.set_mesh(BoxMesh::new().upcast());
+    root.add_child(floor.upcast());
+
+    let mut marker = Node3D::new_alloc();
+    marker.set_name("Marker".into());
+    marker.set_visible(false);
+    root.add_child(marker.upcast());
+
+    root
+}
+
+#[itest]
+fn scene_snapshot_capture() {
+    let root = make_scene();
+    let snapshot = NodeSnapshot::capture(&root);
+
+    assert_eq!(snapshot.name, "Level");
+    assert_eq!(snapshot.class, "Node3D");
+    assert!(snapshot.properties.contains_key("position"));
+    assert!(!snapshot.properties.contains_key("visible"));
+
+    let floor = snapshot.find("Floor").expect("Floor captured");
+    assert_eq!(floor.class, "MeshInstance3D");
+    assert_eq!(
+        floor.properties.get("mesh").map(String::as_str),
+        Some("<BoxMesh>")
+    );
+
+    let marker = snapshot.find("Marker").expect("Marker captured");
+    assert_eq!(
+        marker.properties.get("visible").map(String::as_str),
+        Some("false")
+    );
+
+    let full = NodeSnapshot::capture_with(
+        &root,
+        &SnapshotOptions::new()
+            .include_defaults(true)
+            .ignore("position"),
+    );
+    assert!(full.properties.contains_key("visible"));
+    assert!(!full.properties.contains_key("position"));
+
+    root.free();
+}
+
+#[itest]
+fn scene_snapshot_text_roundtrip() {
+    let root = make_scene();
+    let snapshot = NodeSnapshot::capture(&root);
+
+    let parsed = NodeSnapshot::from_text(&snapshot.to_text()).expect("parse own output");
+    assert_eq!(parsed, snapshot);
+    snapshot.assert_matches(&parsed);
+
+    root.free();
+}
+
+#[itest]
+fn scene_snapshot_diff() {
+    let mut root = make_scene();
+    let expected = NodeSnapshot::capture(&root);
+
+    root.set_position(Vector3::ZERO);
+    let mut marker = root.get_node_as::<Node3D>("Marker");
+    root.remove_child(marker.clone().upcast());
+    marker.free();
+
+    let actual = NodeSnapshot::capture(&root);
+    let diffs = expected.diff(&actual);
+
+    assert!(diffs.contains(&SnapshotDiff::NodeRemoved {
+        path: "Marker".to_string(),
+        class: "Node3D".to_string(),
+    }));
+    assert!(diffs.iter().any(|diff| matches!(
+        diff,
+        SnapshotDiff::PropertyChanged { path, property, actual: None, .. }
+            if path == "." && property == "position"
+    )));
+
+    expect_panic("snapshot mismatch", || actual.assert_matches(&expected));
+
+    root.free();
+}
@@ -0,0 +1,151 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::ffi::c_void;
+
+use crate::framework::itest;
+use godot::builtin::meta::{GodotType, PropertyInfo};
+use godot::engine::{
+    create_script_instance, Engine, Object, Script, ScriptExtension, ScriptExtensionVirtual,
+    ScriptInstance, ScriptLanguage, ScriptMethodInfo,
+};
+use godot::prelude::*;
+use godot::sys;
+
+#[derive(GodotClass)]
+#[class(init, base=ScriptExtension)]
+struct TestScript {
+    #[base]
+    base: Base<ScriptExtension>,
+}
+
+#[godot_api]
+impl ScriptExtensionVirtual for TestScript {
+    fn can_instantiate(&self) -> bool {
+        true
+    }
+
+    unsafe fn instance_create(&self, for_object: Gd<Object>) -> *mut c_void {
+        let instance = TestScriptInstance {
+            script: self.base.clone().upcast(),
+            greeting: "hello".into(),
+            calls: 0,
+        };
+
+        create_script_instance(instance, &for_object)
+    }
+}
+
+struct TestScriptInstance {
+    script: Gd<Script>,
+    greeting: GodotString,
+    calls: i64,
+}
+
+impl ScriptInstance for TestScriptInstance {
+    fn set(&mut self, name: StringName, value: &Variant) -> bool {
+        if name != StringName::from("greeting") {
+            return false;
+        }
+
+        match value.try_to::<GodotString>() {
+            Ok(greeting) => {
+                self.greeting = greeting;
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    fn get(&self, name: StringName) -> Option<Variant> {
+        match name.to_string().as_str() {
+            "greeting" => Some(self.greeting.to_variant()),
+            "calls" => Some(self.calls.to_variant()),
+            _ => None,
+        }
+    }
+
+    fn call(
+        &mut self,
+        method: StringName,
+        args: &[&Variant],
+    ) -> Result<Variant, sys::GDExtensionCallErrorType> {
+        if method != StringName::from("greet") {
+            return Err(sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD);
+        }
+
+        let [name] = args else {
+            return Err(sys::GDEXTENSION_CALL_ERROR_TOO_MANY_ARGUMENTS);
+        };
+
+        self.calls += 1;
+        Ok(format!("{} {name}", self.greeting).to_variant())
+    }
+
+    fn get_script(&self) -> &Gd<Script> {
+        &self.script
+    }
+
+    fn get_language(&self) -> Gd<ScriptLanguage> {
+        Engine::singleton()
+            .get_script_language(0)
+            .expect("at least one script language")
+    }
+
+    fn get_property_list(&self) -> Vec<PropertyInfo> {
+        vec![
+            GodotString::property_info("greeting"),
+            i64::property_info("calls"),
+        ]
+    }
+
+    fn get_method_list(&self) -> Vec<ScriptMethodInfo> {
+        vec![ScriptMethodInfo::new("greet", &["name"])]
+    }
+}
+
+fn new_scripted_object() -> Gd<Object> {
+    let script = Gd::<TestScript>::new_default();
+
+    let mut object = Object::new_alloc();
+    object.set_script(script.to_variant());
+    object
+}
+
+#[itest]
+fn script_instance_properties() {
+    let mut object = new_scripted_object();
+
+    assert_eq!(object.get("greeting".into()), "hello".to_variant());
+    object.set("greeting".into(), "hi".to_variant());
+    assert_eq!(object.get("greeting".into()), "hi".to_variant());
+
+    let names: Vec<String> = object
+        .get_property_list()
+        .iter_shared()
+        .map(|property| property.get("name").unwrap().to_string())
+        .collect();
+    assert!(names.contains(&"greeting".to_string()));
+    assert!(names.contains(&"calls".to_string()));
+
+    object.free();
+}
+
+#[itest]
+fn script_instance_methods() {
+    let mut object = new_scripted_object();
+
+    assert!(object.has_method("greet".into()));
+    let result = object.call("greet".into(), &["Godot".to_variant()]);
+    assert_eq!(result, "hello Godot".to_variant());
+    assert_eq!(object.get("calls".into()), 1.to_variant());
+
+    // Methods unknown to the script fall back to the class.
+    let result = object.call("get_class".into(), &[]);
+    assert_eq!(result, "Object".to_variant());
+
+    object.free();
+}
@@ -0,0 +1,60 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::{ClassDb, DynamicCallExt};
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct DynamicTarget {
+    #[var]
+    health: i32,
+}
+
+#[godot_api]
+impl DynamicTarget {
+    #[func]
+    fn take_damage(&mut self, amount: i32) -> i32 {
+        self.health -= amount;
+        self.health
+    }
+}
+
+#[itest]
+fn dynamic_call_typed() {
+    let mut target = Gd::<DynamicTarget>::new_default();
+    target.set_typed("health", 100);
+
+    let remaining: i32 = target.call_typed("take_damage", &[30.to_variant()]);
+    assert_eq!(remaining, 70);
+    assert_eq!(target.get_typed::<i32>("health"), 70);
+    assert!(target.try_get_typed::<GodotString>("health").is_err());
+}
+
+#[itest]
+fn dynamic_call_borrowed_name() {
+    let mut target = Gd::<DynamicTarget>::new_default();
+    let health = StringName::from("health");
+
+    target.set_typed(&health, 40);
+    assert_eq!(target.get_typed::<i32>(&health), 40);
+    assert_eq!(target.get_typed::<i32>(String::from("health")), 40);
+}
+
+// Scripts in other languages (e.g. C#) see user classes through ClassDB.
+#[itest]
+fn dynamic_class_visible_in_classdb() {
+    let db = ClassDb::singleton();
+    assert!(db.class_exists("DynamicTarget".into()));
+    assert!(db.can_instantiate("DynamicTarget".into()));
+    assert!(db.class_has_method("DynamicTarget".into(), "take_damage".into()));
+
+    let instance = db.instantiate("DynamicTarget".into());
+    let target = instance.to::<Gd<DynamicTarget>>();
+    assert_eq!(target.bind().health, 0);
+}
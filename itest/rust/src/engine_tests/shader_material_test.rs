@@ -0,0 +1,107 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::bind::ShaderUniforms;
+use godot::builtin::Color;
+use godot::engine::{Shader, ShaderMaterial, ShaderMaterialExt};
+use godot::obj::Gd;
+
+use crate::framework::itest;
+
+#[cfg(debug_assertions)]
+use crate::framework::expect_panic;
+
+const SHADER_CODE: &str = "
+shader_type canvas_item;
+uniform vec4 albedo_tint : source_color;
+uniform float wave_speed;
+";
+
+#[derive(ShaderUniforms)]
+struct WaterUniforms {
+    albedo_tint: Color,
+    #[uniform(name = "wave_speed")]
+    speed: f64,
+    #[uniform(skip)]
+    _unused: bool,
+}
+
+fn make_material() -> Gd<ShaderMaterial> {
+    let mut shader = Shader::new();
+    shader.set_code(SHADER_CODE.into());
+
+    let mut material = ShaderMaterial::new();
+    material.set_shader(shader);
+    material
+}
+
+#[itest]
+fn shader_material_parameter_typed() {
+    let mut material = make_material();
+    let tint = Color::from_rgb(1.0, 0.5, 0.25);
+
+    material.set_parameter_typed("albedo_tint", tint);
+    material.set_parameter_typed("wave_speed", 2.5);
+
+    assert_eq!(material.get_parameter_typed::<Color>("albedo_tint"), tint);
+    assert_eq!(material.get_parameter_typed::<f64>("wave_speed"), 2.5);
+    assert_eq!(
+        material.try_get_parameter_typed::<Color>("wave_speed"),
+        None
+    );
+}
+
+#[itest]
+fn shader_material_derive_uniforms() {
+    let mut material = make_material();
+    let uniforms = WaterUniforms {
+        albedo_tint: Color::WHITE,
+        speed: 0.75,
+        _unused: true,
+    };
+
+    material.set_uniforms(&uniforms);
+
+    assert_eq!(
+        material.get_parameter_typed::<Color>("albedo_tint"),
+        Color::WHITE
+    );
+    assert_eq!(material.get_parameter_typed::<f64>("wave_speed"), 0.75);
+}
+
+#[cfg(debug_assertions)]
+#[itest]
+fn shader_material_rejects_unknown_uniform() {
+    let mut material = make_material();
+    if !reports_uniforms(&material) {
+        return;
+    }
+
+    expect_panic("misspelled uniform", move || {
+        material.set_parameter_typed("wave_sped", 2.5);
+    });
+}
+
+#[cfg(debug_assertions)]
+#[itest]
+fn shader_material_rejects_wrong_type() {
+    let mut material = make_material();
+    if !reports_uniforms(&material) {
+        return;
+    }
+
+    expect_panic("string assigned to float uniform", move || {
+        material.set_parameter_typed("wave_speed", godot::builtin::GodotString::from("fast"));
+    });
+}
+
+/// Whether the rendering server reports the shader's uniforms; the dummy renderer of headless runs may not, skipping validation.
+#[cfg(debug_assertions)]
+fn reports_uniforms(material: &Gd<ShaderMaterial>) -> bool {
+    material
+        .get_shader()
+        .map_or(false, |shader| !shader.get_shader_uniform_list().is_empty())
+}
@@ -0,0 +1,24 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::{shader_source, ShaderSource};
+
+use crate::framework::itest;
+
+#[itest]
+fn shader_source_to_shader() {
+    let tint = ShaderSource::new("uniform vec4 tint : source_color;");
+    let shader = shader_source!(
+        "shader_type canvas_item;\n#include \"tint.gdshaderinc\"\n\nvoid fragment() {\n#ifdef INVERT\n    COLOR.rgb = vec3(1.0) - COLOR.rgb;\n#endif\n    COLOR *= tint;\n}"
+    )
+    .include("tint.gdshaderinc", tint)
+    .define("INVERT")
+    .to_shader()
+    .expect("no include cycles");
+
+    let code = shader.get_code().to_string();
+    assert!(code.starts_with("shader_type canvas_item;\n#define INVERT\nuniform vec4 tint"));
+}
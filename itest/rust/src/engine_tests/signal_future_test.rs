@@ -0,0 +1,66 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+use godot::engine::{Object, SignalFuture};
+
+use crate::framework::itest;
+
+#[derive(Default)]
+struct CountingWaker {
+    wakes: AtomicUsize,
+}
+
+impl Wake for CountingWaker {
+    fn wake(self: Arc<Self>) {
+        self.wakes.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[itest]
+fn signal_future_completes_on_emit() {
+    let mut object = Object::new_alloc();
+    object.add_user_signal("ping".into());
+
+    let counter = Arc::new(CountingWaker::default());
+    let waker = Waker::from(Arc::clone(&counter));
+    let mut cx = Context::from_waker(&waker);
+
+    let mut future = SignalFuture::new(object.clone(), "ping");
+    assert!(!future.is_emitted());
+    assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Pending);
+
+    object.emit_signal("ping".into(), &[]);
+    assert!(future.is_emitted());
+    assert_eq!(counter.wakes.load(Ordering::SeqCst), 1);
+    assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Ready(()));
+
+    // One-shot: emitting again does not wake anymore.
+    object.emit_signal("ping".into(), &[]);
+    assert_eq!(counter.wakes.load(Ordering::SeqCst), 1);
+
+    object.free();
+}
+
+#[itest]
+fn signal_future_emitted_before_poll() {
+    let mut object = Object::new_alloc();
+    object.add_user_signal("ping".into());
+
+    let mut future = SignalFuture::new(object.clone(), "ping");
+    object.emit_signal("ping".into(), &[]);
+
+    let waker = Waker::from(Arc::new(CountingWaker::default()));
+    let mut cx = Context::from_waker(&waker);
+    assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Ready(()));
+
+    object.free();
+}
@@ -0,0 +1,79 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::{BoneId, Skeleton3D, SkeletonExt};
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+/// Hips, with children Spine (with child Head) and Leg.
+fn make_skeleton() -> Gd<Skeleton3D> {
+    let mut skeleton = Skeleton3D::new_alloc();
+    for name in ["Hips", "Spine", "Head", "Leg"] {
+        skeleton.add_bone(name.into());
+    }
+
+    skeleton.set_bone_parent(1, 0);
+    skeleton.set_bone_parent(2, 1);
+    skeleton.set_bone_parent(3, 0);
+    skeleton
+}
+
+#[itest]
+fn skeleton_bone_lookup() {
+    let skeleton = make_skeleton();
+
+    let spine = skeleton.find_bone_id("Spine").expect("bone Spine");
+    assert_eq!(spine, BoneId::new(1));
+    assert_eq!(skeleton.bone_name(spine), GodotString::from("Spine"));
+    assert_eq!(skeleton.find_bone_id("Tail"), None);
+    assert_eq!(BoneId::try_from_i32(-1), None);
+
+    skeleton.free();
+}
+
+#[itest]
+fn skeleton_bone_hierarchy() {
+    let skeleton = make_skeleton();
+    let [hips, spine, head, leg] = [0, 1, 2, 3].map(BoneId::new);
+
+    assert_eq!(skeleton.bone_ids(), vec![hips, spine, head, leg]);
+    assert_eq!(skeleton.root_bones(), vec![hips]);
+    assert_eq!(skeleton.bone_parent(hips), None);
+    assert_eq!(skeleton.bone_parent(head), Some(spine));
+    assert_eq!(skeleton.bone_children(hips), vec![spine, leg]);
+    assert_eq!(skeleton.bones_top_down(), vec![hips, spine, head, leg]);
+
+    assert_eq!(
+        skeleton.bone_chain(hips, head),
+        Some(vec![hips, spine, head])
+    );
+    assert_eq!(skeleton.bone_chain(head, head), Some(vec![head]));
+    assert_eq!(skeleton.bone_chain(leg, head), None);
+
+    skeleton.free();
+}
+
+#[itest]
+fn skeleton_poses() {
+    let mut skeleton = make_skeleton();
+    let [hips, spine, head, _leg] = [0, 1, 2, 3].map(BoneId::new);
+
+    let offset = Transform3D::IDENTITY.translated(Vector3::new(0.0, 1.0, 0.0));
+    skeleton.set_poses([(hips, offset), (spine, offset)]);
+    assert_eq!(skeleton.pose(spine), offset);
+    assert_eq!(
+        skeleton.global_pose(spine).origin,
+        Vector3::new(0.0, 2.0, 0.0)
+    );
+
+    let target = Transform3D::IDENTITY.translated(Vector3::new(1.0, 2.5, 0.0));
+    skeleton.set_global_pose(head, target);
+    assert_eq!(skeleton.global_pose(head).origin, target.origin);
+    assert_eq!(skeleton.pose(head).origin, Vector3::new(1.0, 0.5, 0.0));
+
+    skeleton.free();
+}
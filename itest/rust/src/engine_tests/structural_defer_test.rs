@@ -0,0 +1,68 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::DeferStructuralExt;
+use godot::prelude::*;
+
+use crate::framework::{itest, TestContext};
+
+#[itest]
+fn defer_structural_applies_in_order(ctx: &TestContext) {
+    let tree = ctx
+        .scene_tree
+        .get_tree()
+        .expect("test runner is in the tree");
+
+    let mut parent = Node::new_alloc();
+    let mut other_parent = Node::new_alloc();
+    let child = Node::new_alloc();
+    let doomed = Node::new_alloc();
+    parent.add_child(doomed.clone());
+
+    let queued = tree.defer_structural(|batch| {
+        batch.add_child(&parent, child.clone());
+        batch.reparent(&child, &other_parent);
+        batch.free(doomed.clone());
+        batch.len()
+    });
+
+    assert_eq!(queued, 3);
+    assert_eq!(tree.pending_structural(), 3);
+    assert_eq!(child.get_parent(), None);
+    assert!(doomed.is_instance_valid());
+
+    tree.flush_structural();
+
+    assert_eq!(tree.pending_structural(), 0);
+    assert_eq!(child.get_parent(), Some(other_parent.clone()));
+    assert!(!doomed.is_instance_valid());
+    assert_eq!(parent.get_child_count(), 0);
+
+    parent.free();
+    other_parent.free();
+}
+
+#[itest]
+fn defer_structural_skips_freed_nodes(ctx: &TestContext) {
+    let tree = ctx
+        .scene_tree
+        .get_tree()
+        .expect("test runner is in the tree");
+
+    let mut parent = Node::new_alloc();
+    let child = Node::new_alloc();
+
+    tree.defer_structural(|batch| {
+        batch.add_child(&parent, child.clone());
+        batch.remove_child(&parent, &child);
+    });
+    child.clone().free();
+
+    tree.flush_structural();
+    assert_eq!(parent.get_child_count(), 0);
+
+    parent.free();
+}
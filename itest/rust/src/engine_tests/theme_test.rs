@@ -0,0 +1,64 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Color, Vector2};
+use godot::engine::global::{Corner, Side};
+use godot::engine::{Label, StyleBoxFlatBuilder, ThemeExt, ThemeItem};
+
+use crate::framework::itest;
+
+#[itest]
+fn theme_color_override() {
+    let mut label = Label::new_alloc();
+    let red = Color::from_rgb(1.0, 0.0, 0.0);
+
+    assert!(!label.has_theme_override(ThemeItem::Color, "font_color"));
+    label.override_theme_color("font_color", red);
+    assert!(label.has_theme_override(ThemeItem::Color, "font_color"));
+    assert_eq!(label.theme_color("font_color", ""), red);
+
+    label.override_theme_constant("outline_size", 3);
+    assert_eq!(label.theme_constant("outline_size", "Label"), 3);
+
+    label.remove_theme_override(ThemeItem::Color, "font_color");
+    assert!(!label.has_theme_override(ThemeItem::Color, "font_color"));
+
+    label.free();
+}
+
+#[itest]
+fn theme_stylebox_builder() {
+    let green = Color::from_rgb(0.0, 1.0, 0.0);
+    let stylebox = StyleBoxFlatBuilder::new()
+        .bg_color(green)
+        .border(2, Color::BLACK)
+        .border_width(Side::SIDE_TOP, 4)
+        .corner_radius(6)
+        .corner_radius_at(Corner::CORNER_BOTTOM_LEFT, 0)
+        .shadow(Color::BLACK, 3, Vector2::new(1.0, 1.0))
+        .build();
+
+    assert_eq!(stylebox.get_bg_color(), green);
+    assert_eq!(stylebox.get_border_width(Side::SIDE_LEFT), 2);
+    assert_eq!(stylebox.get_border_width(Side::SIDE_TOP), 4);
+    assert_eq!(stylebox.get_corner_radius(Corner::CORNER_TOP_LEFT), 6);
+    assert_eq!(stylebox.get_corner_radius(Corner::CORNER_BOTTOM_LEFT), 0);
+    assert_eq!(stylebox.get_shadow_size(), 3);
+
+    // Copies leave the original unchanged.
+    let copy = StyleBoxFlatBuilder::from_stylebox(&stylebox)
+        .bg_color(Color::BLACK)
+        .build();
+    assert_eq!(copy.get_border_width(Side::SIDE_TOP), 4);
+    assert_eq!(stylebox.get_bg_color(), green);
+
+    let mut label = Label::new_alloc();
+    label.override_theme_stylebox("normal", stylebox.clone());
+    let stored = label.theme_stylebox("normal", "").expect("stylebox");
+    assert_eq!(stored.instance_id(), stylebox.instance_id());
+
+    label.free();
+}
@@ -0,0 +1,86 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::image::Format;
+use godot::engine::{
+    AtlasSourceBuilder, Image, ImageTexture, TileCell, TileMap, TileMapExt, TileSet,
+};
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+/// 4x2 tiles of 8x8 pixels, with 1 pixel separation.
+fn atlas_texture() -> Gd<ImageTexture> {
+    let image = Image::create(35, 17, false, Format::RGBA8).expect("image created");
+    ImageTexture::create_from_image(image).expect("texture created")
+}
+
+fn tile_map() -> (Gd<TileMap>, i32) {
+    let mut tile_set = TileSet::new();
+    tile_set.set_tile_size(Vector2i::new(8, 8));
+
+    let source_id = AtlasSourceBuilder::new(atlas_texture().upcast(), Vector2i::new(8, 8))
+        .separation(Vector2i::new(1, 1))
+        .all_tiles()
+        .add_to(&mut tile_set);
+
+    let mut map = TileMap::new_alloc();
+    map.set_tileset(tile_set);
+    (map, source_id)
+}
+
+#[itest]
+fn atlas_source_builder_tiles() {
+    let source = AtlasSourceBuilder::new(atlas_texture().upcast(), Vector2i::new(8, 8))
+        .separation(Vector2i::new(1, 1))
+        .large_tile(Vector2i::new(0, 0), Vector2i::new(2, 2))
+        .all_tiles()
+        .build();
+
+    assert_eq!(source.get_atlas_grid_size(), Vector2i::new(4, 2));
+
+    // One 2x2 tile, plus single tiles for the remaining 4 cells.
+    assert_eq!(source.get_tiles_count(), 5);
+    assert_eq!(
+        source.get_tile_size_in_atlas(Vector2i::new(0, 0)),
+        Vector2i::new(2, 2)
+    );
+    assert_eq!(
+        source.get_tile_at_coords(Vector2i::new(1, 1)),
+        Vector2i::new(0, 0)
+    );
+    assert!(source.has_tile(Vector2i::new(3, 1)));
+}
+
+#[itest]
+fn tile_map_bulk_cells() {
+    let (mut map, source) = tile_map();
+    let wall = Some(TileCell::new(source, Vector2i::new(0, 0)));
+    let floor = Some(TileCell::new(source, Vector2i::new(1, 0)));
+
+    map.set_cells_from_grid(
+        0,
+        Vector2i::new(-1, 2),
+        &[vec![wall, wall, wall], vec![wall, floor, None]],
+    );
+
+    assert_eq!(map.cell(0, Vector2i::new(0, 3)), floor);
+    assert_eq!(map.cell(0, Vector2i::new(1, 3)), None);
+    assert_eq!(map.used_cells_vec(0).len(), 5);
+    assert_eq!(map.used_cell_ranges(), (-1..2, 2..4));
+
+    let tile = TileCell::new(source, Vector2i::new(2, 1));
+    map.set_cells(0, [(Vector2i::new(5, 5), tile)]);
+    assert_eq!(map.cell(0, Vector2i::new(5, 5)), Some(tile));
+    assert_eq!(map.used_cell_ranges(), (-1..6, 2..6));
+
+    map.erase_cells(0, map.used_cells_vec(0));
+    assert!(map.used_cells_vec(0).is_empty());
+    let (columns, rows) = map.used_cell_ranges();
+    assert!(columns.is_empty() && rows.is_empty());
+
+    map.free();
+}
@@ -0,0 +1,101 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::bind::{FromGodot, GodotConvert, ToGodot};
+use godot::builtin::meta::{FromGodot, ToGodot};
+use godot::builtin::{GodotString, VariantConversionError};
+use godot::engine::{ConfigError, TypedConfig};
+
+use crate::framework::itest;
+
+#[derive(FromGodot, ToGodot, GodotConvert, PartialEq, Debug)]
+struct VideoSettings {
+    fullscreen: bool,
+    scale: f64,
+}
+
+#[itest]
+fn typed_config_get_set() {
+    let mut config = TypedConfig::new();
+    config.set("audio", "volume", 0.5);
+    config.set("audio", "device", GodotString::from("default"));
+
+    assert_eq!(config.get::<f64>("audio", "volume"), Ok(0.5));
+    assert_eq!(
+        config.get::<GodotString>("audio", "device"),
+        Ok(GodotString::from("default"))
+    );
+    assert_eq!(config.get_or("audio", "muted", true), Ok(true));
+    assert_eq!(config.get_or_default::<i64>("audio", "latency"), Ok(0));
+
+    config.remove("audio", "device");
+    assert!(!config.has("audio", "device"));
+}
+
+#[itest]
+fn typed_config_errors_name_key() {
+    let mut config = TypedConfig::new();
+    config.set("audio", "volume", GodotString::from("loud"));
+
+    let error = config.get_or("audio", "volume", 1.0).unwrap_err();
+    assert_eq!(
+        error,
+        ConfigError::InvalidValue {
+            section: "audio".to_string(),
+            key: "volume".to_string(),
+            error: VariantConversionError::BadType,
+        }
+    );
+    assert!(error.to_string().contains("[audio] volume"));
+
+    let error = config.get::<bool>("audio", "muted").unwrap_err();
+    assert_eq!(
+        error,
+        ConfigError::MissingKey {
+            section: "audio".to_string(),
+            key: "muted".to_string(),
+        }
+    );
+}
+
+#[itest]
+fn typed_config_struct_roundtrip() {
+    let video = VideoSettings {
+        fullscreen: true,
+        scale: 1.5,
+    };
+
+    let mut config = TypedConfig::new();
+    config.set_struct("video", &video);
+
+    assert_eq!(config.get::<bool>("video", "fullscreen"), Ok(true));
+    assert_eq!(config.get_struct::<VideoSettings>("video"), Ok(video));
+
+    config.remove("video", "scale");
+    assert!(matches!(
+        config.get_struct::<VideoSettings>("video"),
+        Err(ConfigError::InvalidStruct { .. })
+    ));
+    assert!(matches!(
+        config.get_struct::<VideoSettings>("missing"),
+        Err(ConfigError::MissingSection { .. })
+    ));
+}
+
+#[itest]
+fn typed_config_file_roundtrip() {
+    let path = "user://itest_typed_config.cfg";
+
+    let mut config = TypedConfig::new();
+    config.set("game", "level", 3);
+    config.save(path).expect("save");
+
+    let loaded = TypedConfig::load(path).expect("load");
+    assert_eq!(loaded.get::<i64>("game", "level"), Ok(3));
+
+    let error = TypedConfig::load("user://itest_typed_config_missing.cfg").unwrap_err();
+    assert!(matches!(error, ConfigError::Io { .. }));
+}
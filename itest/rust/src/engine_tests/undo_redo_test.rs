@@ -0,0 +1,54 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::meta::ToGodot;
+use godot::builtin::GodotString;
+use godot::engine::{Resource, UndoRedo, UndoRedoExt};
+
+use crate::framework::itest;
+
+#[itest]
+fn undo_redo_action_property() {
+    let mut undo_redo = UndoRedo::new_alloc();
+    let mut resource = Resource::new();
+    resource.set_name("old".into());
+
+    undo_redo.action("Rename", |do_, undo| {
+        do_.property(&resource, "resource_name", GodotString::from("new"));
+        undo.property(&resource, "resource_name", GodotString::from("old"));
+    });
+    assert_eq!(resource.get_name(), GodotString::from("new"));
+
+    assert!(undo_redo.undo());
+    assert_eq!(resource.get_name(), GodotString::from("old"));
+
+    assert!(undo_redo.redo());
+    assert_eq!(resource.get_name(), GodotString::from("new"));
+
+    undo_redo.free();
+}
+
+#[itest]
+fn undo_redo_action_method() {
+    let mut undo_redo = UndoRedo::new_alloc();
+    let mut resource = Resource::new();
+    resource.set_name("old".into());
+
+    undo_redo.action("Rename", |do_, undo| {
+        do_.method(&resource, "set_name", &["new".to_variant()]);
+        undo.method(&resource, "set_name", &["old".to_variant()]);
+    });
+    assert_eq!(resource.get_name(), GodotString::from("new"));
+    assert_eq!(
+        undo_redo.get_current_action_name(),
+        GodotString::from("Rename")
+    );
+
+    assert!(undo_redo.undo());
+    assert_eq!(resource.get_name(), GodotString::from("old"));
+
+    undo_redo.free();
+}
@@ -0,0 +1,59 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::{Gradient, VariantCodec, VariantDecodeError};
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[itest]
+fn variant_codec_roundtrip() {
+    let codec = VariantCodec::new();
+    let state = dict! { "level": 3, "name": "Ada", "spawn": Vector2::new(1.0, 2.5) };
+
+    let bytes = codec.encode(&state);
+    let decoded: Dictionary = codec.decode(&bytes).expect("valid data");
+    assert_eq!(decoded, state);
+
+    assert!(matches!(
+        codec.decode::<i64>(&bytes),
+        Err(VariantDecodeError::InvalidValue(_))
+    ));
+
+    let truncated = bytes.subarray(0, bytes.len() - 4);
+    assert_eq!(
+        codec.decode::<Dictionary>(&truncated),
+        Err(VariantDecodeError::Malformed)
+    );
+}
+
+#[itest]
+fn variant_codec_allowed_classes() {
+    let mut gradient = Gradient::new();
+    gradient.set_offset(0, 0.25);
+
+    let with_gradients = VariantCodec::new().allow_class::<Gradient>();
+    let bytes = with_gradients.encode(&gradient);
+
+    let decoded: Gd<Gradient> = with_gradients.decode(&bytes).expect("allowed class");
+    assert_eq!(decoded.get_offset(0), 0.25);
+
+    // Same bytes, but not allowed.
+    assert_eq!(
+        VariantCodec::new().decode::<Gd<Gradient>>(&bytes),
+        Err(VariantDecodeError::ClassNotAllowed {
+            class: "Gradient".to_string()
+        })
+    );
+    assert_eq!(
+        VariantCodec::new()
+            .allow_class_name("Resource")
+            .decode::<Gd<Gradient>>(&bytes),
+        Err(VariantDecodeError::ClassNotAllowed {
+            class: "Gradient".to_string()
+        })
+    );
+}
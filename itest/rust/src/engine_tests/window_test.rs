@@ -0,0 +1,55 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::{WindowBuilder, WindowExt};
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[itest]
+fn window_builder_applies_settings() {
+    let window = WindowBuilder::new("Tools")
+        .size(Vector2i::new(320, 240))
+        .min_size(Vector2i::new(100, 80))
+        .position(Vector2i::new(30, 40))
+        .borderless(true)
+        .resizable(false)
+        .visible(false)
+        .build();
+
+    assert_eq!(window.get_title(), "Tools".into());
+    assert_eq!(window.get_size(), Vector2i::new(320, 240));
+    assert_eq!(window.get_min_size(), Vector2i::new(100, 80));
+    assert_eq!(window.get_position(), Vector2i::new(30, 40));
+    assert_eq!(window.get("borderless".into()), true.to_variant());
+    assert_eq!(window.get("unresizable".into()), true.to_variant());
+    assert!(!window.is_visible());
+
+    window.free();
+}
+
+#[itest]
+fn window_coordinate_conversions() {
+    let mut window = WindowBuilder::new("Coords")
+        .position(Vector2i::new(100, 50))
+        .visible(false)
+        .build();
+    window.set_canvas_transform(Transform2D::from_angle_origin(
+        0.0,
+        Vector2::new(10.0, 20.0),
+    ));
+
+    let screen = Vector2i::new(150, 80);
+    let in_window = window.screen_to_window(screen);
+    assert_eq!(in_window, Vector2i::new(50, 30));
+    assert_eq!(window.window_to_screen(in_window), screen);
+
+    let canvas = window.screen_to_canvas(screen);
+    assert_eq!(canvas, Vector2::new(40.0, 10.0));
+    assert_eq!(window.canvas_to_window(canvas), Vector2::new(50.0, 30.0));
+
+    window.free();
+}
@@ -0,0 +1,59 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use godot::engine::PoolTask;
+
+use crate::framework::itest;
+
+#[itest]
+fn worker_pool_spawn_join() {
+    let task = PoolTask::new()
+        .description("itest sum")
+        .spawn(|| (1..=100).sum::<u32>());
+
+    assert_eq!(task.join().expect("task does not panic"), 5050);
+}
+
+#[itest]
+fn worker_pool_spawn_panic() {
+    let task = PoolTask::new().spawn(|| -> u32 { panic!("worker failure") });
+
+    let payload = task.join().expect_err("task panics");
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"worker failure"));
+}
+
+#[itest]
+fn worker_pool_group() {
+    let visited = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&visited);
+
+    let group = PoolTask::new()
+        .high_priority(true)
+        .spawn_group(64, move |index| {
+            counter.fetch_add(index + 1, Ordering::Relaxed);
+        });
+
+    assert!(group.join().is_ok());
+    assert_eq!(visited.load(Ordering::Relaxed), 64 * 65 / 2);
+}
+
+#[itest]
+fn worker_pool_for_each_borrows() {
+    let input: Vec<u64> = (0..1000).collect();
+    let output: Vec<AtomicUsize> = (0..1000).map(|_| AtomicUsize::new(0)).collect();
+
+    PoolTask::new().for_each(input.len(), |index| {
+        output[index].store(input[index] as usize * 2, Ordering::Relaxed);
+    });
+
+    assert!(output
+        .iter()
+        .enumerate()
+        .all(|(index, value)| value.load(Ordering::Relaxed) == index * 2));
+}
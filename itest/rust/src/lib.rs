@@ -4,7 +4,8 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use godot::init::{gdextension, ExtensionLibrary};
+use godot::builtin::meta::ClassName;
+use godot::init::{gdextension, ExtensionLibrary, InitLevel};
 
 mod benchmarks;
 mod builtin_tests;
@@ -18,4 +19,17 @@ mod register_tests;
 // Entry point
 
 #[gdextension(entry_point=itest_init)]
-unsafe impl ExtensionLibrary for framework::IntegrationTests {}
+unsafe impl ExtensionLibrary for framework::IntegrationTests {
+    // Classes of the secondary entry point are registered by that one.
+    fn registers_class(class_name: ClassName) -> bool {
+        !register_tests::is_secondary_class(class_name)
+    }
+
+    fn on_level_init(level: InitLevel) {
+        if level == InitLevel::Scene {
+            register_tests::register_manual_classes();
+            register_tests::register_dynamic_classes();
+            register_tests::register_plugin_classes();
+        }
+    }
+}
@@ -5,6 +5,7 @@
  */
 
 use crate::framework::itest;
+use godot::engine::ClassDb;
 use godot::prelude::*;
 
 pub mod dont_rename {
@@ -22,6 +23,13 @@ pub mod rename {
     pub struct RepeatMe {}
 }
 
+#[derive(GodotClass)]
+#[class(init, base=RefCounted, compat_rename = "FormerlyRenamed")]
+struct CompatRenamed {
+    #[var]
+    value: i64,
+}
+
 #[itest]
 fn renaming_changes_the_name() {
     assert_ne!(
@@ -31,3 +39,17 @@ fn renaming_changes_the_name() {
     assert_eq!(dont_rename::RepeatMe::class_name().as_str(), "RepeatMe");
     assert_eq!(rename::RepeatMe::class_name().as_str(), "NoRepeat");
 }
+
+#[itest]
+fn compat_rename_creates_current_class() {
+    let db = ClassDb::singleton();
+    assert!(db.class_exists("FormerlyRenamed".into()));
+
+    let object = db
+        .instantiate("FormerlyRenamed".into())
+        .to::<Gd<RefCounted>>();
+    assert_eq!(object.get_class(), GodotString::from("CompatRenamed"));
+
+    let renamed = object.cast::<CompatRenamed>();
+    assert_eq!(renamed.bind().value, 0);
+}
@@ -6,8 +6,10 @@
 
 use crate::framework::itest;
 use godot::builtin::varray;
+use godot::engine::global::{Error, MouseButtonMask};
 use godot::engine::input::CursorShape;
 use godot::engine::mesh::PrimitiveType;
+use godot::engine::node::ProcessMode;
 use godot::engine::{time, ArrayMesh};
 use std::collections::HashSet;
 
@@ -62,6 +64,49 @@ fn enum_hash() {
     assert_eq!(months.len(), 12);
 }
 
+#[itest]
+fn enum_default() {
+    assert_eq!(CursorShape::default(), CursorShape::CURSOR_ARROW);
+    assert_eq!(Error::default(), Error::OK);
+    assert_eq!(ProcessMode::default(), ProcessMode::PROCESS_MODE_INHERIT);
+}
+
+#[itest]
+fn enum_ordered() {
+    assert!(Error::OK < Error::FAILED);
+    assert!(ProcessMode::PROCESS_MODE_INHERIT < ProcessMode::PROCESS_MODE_ALWAYS);
+
+    let mut errors = vec![Error::ERR_BUG, Error::OK, Error::FAILED];
+    errors.sort();
+    assert_eq!(errors, [Error::OK, Error::FAILED, Error::ERR_BUG]);
+}
+
+#[itest]
+fn bitfield_iter() {
+    let mask = MouseButtonMask::MOUSE_BUTTON_MASK_LEFT | MouseButtonMask::MOUSE_BUTTON_MASK_MIDDLE;
+    let flags: Vec<_> = mask.iter().collect();
+
+    assert_eq!(
+        flags,
+        [
+            MouseButtonMask::MOUSE_BUTTON_MASK_LEFT,
+            MouseButtonMask::MOUSE_BUTTON_MASK_MIDDLE
+        ]
+    );
+    assert_eq!(MouseButtonMask::default().iter().count(), 0);
+}
+
+#[itest]
+fn bitfield_debug() {
+    let mask = MouseButtonMask::MOUSE_BUTTON_MASK_LEFT | MouseButtonMask::MOUSE_BUTTON_MASK_RIGHT;
+
+    assert_eq!(
+        format!("{mask:?}"),
+        "MOUSE_BUTTON_MASK_LEFT | MOUSE_BUTTON_MASK_RIGHT"
+    );
+    assert_eq!(format!("{:?}", MouseButtonMask::default()), "0");
+}
+
 // Testing https://github.com/godot-rust/gdext/issues/335
 // This fails upon calling the function, we dont actually need to make a good call.
 #[itest]
@@ -0,0 +1,83 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::obj::{GdMap, GdSet};
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[itest]
+fn gd_map_insert_get_remove() {
+    let mut map = GdMap::<Node, i32>::new();
+    let a = Node::new_alloc();
+    let b = Node::new_alloc();
+
+    assert_eq!(map.insert(&a, 1), None);
+    assert_eq!(map.insert(&a, 2), Some(1));
+    map.insert(&b, 3);
+
+    *map.get_mut(&b).unwrap() += 1;
+    assert_eq!(map.get(&a), Some(&2));
+    assert_eq!(map.get(&b), Some(&4));
+    assert_eq!(map.len(), 2);
+
+    assert_eq!(map.remove(&a), Some(2));
+    assert!(!map.contains_key(&a));
+
+    a.free();
+    b.free();
+}
+
+#[itest]
+fn gd_map_forgets_freed_objects() {
+    let mut map = GdMap::<Node, &str>::new();
+    let alive = Node::new_alloc();
+    let dead = Node::new_alloc();
+
+    map.insert(&alive, "alive");
+    map.insert(&dead, "dead");
+    dead.free();
+
+    assert_eq!(map.len(), 1);
+    assert_eq!(map.len_unpurged(), 2);
+
+    let entries: Vec<_> = map.iter().collect();
+    assert_eq!(entries, vec![(alive.clone(), &"alive")]);
+
+    assert_eq!(map.purge(), 1);
+    assert_eq!(map.len_unpurged(), 1);
+
+    alive.free();
+    assert!(map.is_empty());
+}
+
+#[itest]
+fn gd_map_does_not_keep_refcounted_alive() {
+    let mut map = GdMap::<RefCounted, i32>::new();
+    let object = RefCounted::new();
+    let id = object.instance_id();
+
+    map.insert(&object, 7);
+    drop(object);
+
+    assert!(Gd::<RefCounted>::try_from_instance_id(id).is_none());
+    assert!(map.is_empty());
+}
+
+#[itest]
+fn gd_set_forgets_freed_objects() {
+    let mut set = GdSet::<Node>::new();
+    let node = Node::new_alloc();
+
+    assert!(set.insert(&node));
+    assert!(!set.insert(&node));
+    assert!(set.contains(&node));
+
+    node.free();
+    assert_eq!(set.iter().count(), 0);
+    assert_eq!(set.purge(), 1);
+    assert_eq!(set.len_unpurged(), 0);
+}
@@ -0,0 +1,62 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::InstanceStats;
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct StatsCountedNode {}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct StatsCountedRef {}
+
+#[itest]
+fn instance_stats_counts_nodes() {
+    if !InstanceStats::is_enabled() {
+        return;
+    }
+
+    let before = InstanceStats::of::<StatsCountedNode>();
+    let nodes: Vec<_> = (0..3)
+        .map(|_| Gd::<StatsCountedNode>::new_default())
+        .collect();
+
+    let during = InstanceStats::of::<StatsCountedNode>();
+    assert_eq!(during.live, before.live + 3);
+    assert_eq!(during.created, before.created + 3);
+    assert!(during.peak >= 3);
+
+    for node in nodes {
+        node.free();
+    }
+
+    let after = InstanceStats::of::<StatsCountedNode>();
+    assert_eq!(after.live, before.live);
+    assert_eq!(after.created, before.created + 3);
+    assert!(InstanceStats::all().contains(&after));
+}
+
+#[itest]
+fn instance_stats_counts_ref_counted() {
+    if !InstanceStats::is_enabled() {
+        return;
+    }
+
+    let before = InstanceStats::of::<StatsCountedRef>().live;
+    let object = Gd::<StatsCountedRef>::new_default();
+    let copy = object.clone();
+    assert_eq!(InstanceStats::of::<StatsCountedRef>().live, before + 1);
+
+    drop(object);
+    assert_eq!(InstanceStats::of::<StatsCountedRef>().live, before + 1);
+
+    drop(copy);
+    assert_eq!(InstanceStats::of::<StatsCountedRef>().live, before);
+}
@@ -6,7 +6,12 @@
 
 mod base_test;
 mod class_rename_test;
+mod gd_map_test;
+mod instance_stats_test;
 mod object_test;
+mod ownership_test;
 mod property_test;
+mod reflection_test;
+mod rust_handle_test;
 mod singleton_test;
 mod virtual_methods_test;
@@ -0,0 +1,62 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+use godot::engine::{Node, RefCounted};
+use godot::obj::{Gd, OwnershipGraph};
+
+#[itest]
+fn ownership_graph_finds_cycles() {
+    OwnershipGraph::clear();
+
+    let a = RefCounted::new();
+    let b = RefCounted::new();
+    let c = RefCounted::new();
+    OwnershipGraph::record(&a, &b, "next");
+    OwnershipGraph::record(&b, &a, "prev");
+    OwnershipGraph::record(&b, &c, "child");
+
+    let mut expected = vec![a.instance_id(), b.instance_id()];
+    expected.sort();
+    assert_eq!(OwnershipGraph::refcounted_cycles(), vec![expected]);
+
+    OwnershipGraph::forget(&b, &a);
+    assert!(OwnershipGraph::refcounted_cycles().is_empty());
+
+    OwnershipGraph::clear();
+}
+
+#[itest]
+fn ownership_graph_to_dot() {
+    OwnershipGraph::clear();
+
+    let parent: Gd<Node> = Node::new_alloc();
+    let child = RefCounted::new();
+    OwnershipGraph::record(&parent, &child, "state");
+
+    let dot = OwnershipGraph::to_dot();
+    let child_id = child.instance_id();
+    assert!(dot.starts_with("digraph ownership {"), "{dot}");
+    assert!(
+        dot.contains(&format!("RefCounted #{child_id}\\nrefs: 1")),
+        "{dot}"
+    );
+    assert!(dot.contains("[label=\"state\"]"), "{dot}");
+
+    let parent_id = parent.instance_id();
+    parent.free();
+    let dot = OwnershipGraph::to_dot();
+    assert!(
+        dot.contains(&format!("Node #{parent_id}\\nfreed\", style=dashed")),
+        "{dot}"
+    );
+
+    OwnershipGraph::forget_object(parent_id);
+    assert_eq!(
+        OwnershipGraph::to_dot(),
+        "digraph ownership {\n    node [shape=box];\n}\n"
+    );
+}
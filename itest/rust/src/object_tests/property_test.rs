@@ -8,9 +8,10 @@ use godot::{
     bind::property::ExportInfo,
     engine::{
         global::{PropertyHint, PropertyUsageFlags},
-        Texture,
+        Node2D, Node3D, Texture, Texture2D,
     },
     prelude::*,
+    sys,
     test::itest,
 };
 
@@ -395,6 +396,9 @@ pub struct ExportResource {
 
     #[export]
     pub bar: Option<Gd<RenamedCustomResource>>,
+
+    #[export]
+    pub texture: Option<Gd<Texture2D>>,
 }
 
 #[godot_api]
@@ -446,6 +450,151 @@ fn export_resource() {
         PropertyUsageFlags::PROPERTY_USAGE_DEFAULT.ord(),
     );
 
+    let property = class
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "texture".to_variant())
+        .unwrap();
+    check_property(&property, "class_name", "Texture2D");
+    check_property(
+        &property,
+        "hint",
+        PropertyHint::PROPERTY_HINT_RESOURCE_TYPE.ord(),
+    );
+    check_property(&property, "hint_string", "Texture2D");
+
+    class.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+pub struct ExportNode {
+    #[export]
+    target: Option<Gd<Node2D>>,
+}
+
+#[godot_api]
+impl ExportNode {}
+
+#[itest]
+fn export_node() {
+    let mut class: Gd<ExportNode> = Gd::new_default();
+
+    let property = class
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "target".to_variant())
+        .unwrap();
+    check_property(&property, "class_name", "Node2D");
+    check_property(&property, "type", VariantType::Object as i32);
+    check_property(
+        &property,
+        "hint",
+        PropertyHint::PROPERTY_HINT_NODE_TYPE.ord(),
+    );
+    check_property(&property, "hint_string", "Node2D");
+
+    let target = Node2D::new_alloc();
+    class.set("target".into(), target.to_variant());
+    assert_eq!(class.bind().target.as_ref(), Some(&target));
+    assert_eq!(class.get("target".into()), target.to_variant());
+
+    class.free();
+    target.free();
+}
+
+// Ptrcalls hand over raw object pointers, so an object of the wrong class must be rejected before it is used as `Gd<Node2D>`.
+#[cfg(debug_assertions)]
+#[itest]
+fn export_node_setter_rejects_wrong_class() {
+    use godot::builtin::meta::PtrcallSignatureTuple;
+
+    // Signature of the generated `set_target()`, as invoked by Godot.
+    fn set_target(_instance: sys::GDExtensionClassInstancePtr, _args: (Option<Gd<Node2D>>,)) {
+        panic!("setter must not be reached");
+    }
+
+    let wrong = Node3D::new_alloc();
+    let wrong_ptr = wrong.obj_sys();
+    let args = [std::ptr::addr_of!(wrong_ptr) as sys::GDExtensionConstTypePtr];
+
+    let err = std::panic::catch_unwind(|| unsafe {
+        <((), Option<Gd<Node2D>>) as PtrcallSignatureTuple>::in_ptrcall(
+            std::ptr::null_mut(),
+            args.as_ptr(),
+            std::ptr::null_mut(),
+            set_target,
+            "set_target",
+            sys::PtrcallType::Standard,
+        );
+    })
+    .expect_err("ptrcall with a Node3D for a Gd<Node2D> parameter must panic");
+
+    let message = err.downcast_ref::<String>().expect("panic message");
+    assert_eq!(
+        message,
+        "set_target: parameter [0] expects an object of class Node2D, but received Node3D"
+    );
+
+    wrong.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+pub struct ExportUsage {
+    #[export(usage = storage)]
+    storage: i32,
+
+    #[export(range = (0.0, 1.0), usage = editor)]
+    editor: f32,
+
+    #[export(usage = no_instance_state)]
+    no_instance_state: i32,
+
+    #[var(usage = internal)]
+    internal: i32,
+}
+
+#[godot_api]
+impl ExportUsage {}
+
+#[itest]
+fn export_usage_presets() {
+    let class: Gd<ExportUsage> = Gd::new_default();
+    let properties = class.get_property_list();
+
+    let usage_of = |name: &str| {
+        let property = properties
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == name.to_variant())
+            .unwrap_or_else(|| panic!("property `{name}` not registered"));
+
+        property.get_or_nil("usage")
+    };
+
+    assert_eq!(
+        usage_of("storage"),
+        PropertyUsageFlags::PROPERTY_USAGE_STORAGE
+            .ord()
+            .to_variant()
+    );
+    assert_eq!(
+        usage_of("editor"),
+        PropertyUsageFlags::PROPERTY_USAGE_EDITOR.ord().to_variant()
+    );
+    assert_eq!(
+        usage_of("no_instance_state"),
+        (PropertyUsageFlags::PROPERTY_USAGE_DEFAULT.ord()
+            | PropertyUsageFlags::PROPERTY_USAGE_NO_INSTANCE_STATE.ord())
+        .to_variant()
+    );
+    assert_eq!(
+        usage_of("internal"),
+        (PropertyUsageFlags::PROPERTY_USAGE_STORAGE.ord()
+            | PropertyUsageFlags::PROPERTY_USAGE_INTERNAL.ord())
+        .to_variant()
+    );
+
     class.free();
 }
 
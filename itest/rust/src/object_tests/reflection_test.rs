@@ -0,0 +1,174 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::meta::{ClassName, MethodInfo, PropertyInfo};
+use godot::engine::global::{PropertyHint, PropertyUsageFlags};
+use godot::engine::ClassDb;
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[itest]
+fn property_info_dictionary_roundtrip() {
+    let info = PropertyInfo::new("speed", VariantType::Float)
+        .with_hint(PropertyHint::PROPERTY_HINT_RANGE, "0,100,0.5")
+        .with_usage(PropertyUsageFlags::PROPERTY_USAGE_STORAGE);
+
+    let dict = info.to_dictionary();
+    assert_eq!(dict.get_or_nil("name"), "speed".to_variant());
+    assert_eq!(dict.get_or_nil("hint_string"), "0,100,0.5".to_variant());
+
+    let parsed = PropertyInfo::from_dictionary(&dict).expect("has name");
+    assert_eq!(parsed.property_name, StringName::from("speed"));
+    assert_eq!(parsed.variant_type, VariantType::Float);
+    assert_eq!(parsed.class_name, ClassName::none());
+    assert_eq!(parsed.hint, PropertyHint::PROPERTY_HINT_RANGE);
+    assert_eq!(parsed.hint_string, GodotString::from("0,100,0.5"));
+    assert_eq!(parsed.usage, PropertyUsageFlags::PROPERTY_USAGE_STORAGE);
+
+    assert!(PropertyInfo::from_dictionary(&Dictionary::new()).is_none());
+}
+
+#[itest]
+fn property_info_from_property_list() {
+    let node = Node::new_alloc();
+
+    let name = node
+        .get_property_list()
+        .iter_shared()
+        .find_map(|dict| {
+            PropertyInfo::from_dictionary(&dict).filter(|p| p.property_name == "name".into())
+        })
+        .expect("Node has property `name`");
+
+    assert_eq!(name.variant_type, VariantType::StringName);
+
+    node.free();
+}
+
+#[itest]
+fn method_info_add_user_signal() {
+    let mut object = Object::new_alloc();
+
+    let signal = MethodInfo::new("hit")
+        .with_argument(PropertyInfo::new_object::<Node>("by"))
+        .with_argument(PropertyInfo::new("damage", VariantType::Int));
+
+    object
+        .add_user_signal_ex("hit".into())
+        .arguments(signal.arguments_array())
+        .done();
+
+    let registered = object
+        .get_signal_list()
+        .iter_shared()
+        .find_map(|dict| {
+            MethodInfo::from_dictionary(&dict).filter(|m| m.method_name == "hit".into())
+        })
+        .expect("signal `hit` registered");
+
+    let args: Vec<_> = registered
+        .arguments
+        .iter()
+        .map(|arg| {
+            (
+                arg.property_name.to_string(),
+                arg.variant_type,
+                arg.class_name,
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        args,
+        vec![
+            ("by".to_string(), VariantType::Object, Node::class_name()),
+            ("damage".to_string(), VariantType::Int, ClassName::none()),
+        ]
+    );
+
+    object.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ReflectedNode {
+    #[var]
+    speed: f64,
+}
+
+#[godot_api]
+impl ReflectedNode {
+    #[constant]
+    const MAX_SPEED: i64 = 10;
+
+    #[signal]
+    fn hit(damage: i64);
+
+    #[func]
+    fn heal(&mut self, amount: i64) -> bool {
+        amount > 0
+    }
+}
+
+#[itest]
+fn class_info_engine_class() {
+    let node = ClassDb::class_info::<Node>();
+
+    assert_eq!(node.class_name, StringName::from("Node"));
+    assert_eq!(node.parent, Some(StringName::from("Object")));
+    assert!(node.is_instantiable);
+    assert!(node.method("add_child").is_some());
+    assert!(node.signal("ready").is_some());
+    assert!(node.property("process_mode").is_some());
+
+    let inherit = node
+        .constants
+        .iter()
+        .find(|constant| constant.name == "PROCESS_MODE_INHERIT".into())
+        .expect("Node has constant PROCESS_MODE_INHERIT");
+    assert_eq!(inherit.value, 0);
+    assert_eq!(inherit.enum_name, Some(StringName::from("ProcessMode")));
+
+    // Only own members are listed.
+    assert!(node.method("get_class").is_none());
+
+    let ancestors: Vec<_> = ClassDb::class_info::<Node3D>()
+        .with_ancestors()
+        .into_iter()
+        .map(|class| class.class_name.to_string())
+        .collect();
+    assert_eq!(ancestors, ["Node3D", "Node", "Object"]);
+
+    assert!(ClassDb::class_info_by_name("NoSuchClass").is_none());
+}
+
+#[itest]
+fn class_info_rust_class() {
+    let info = ClassDb::class_info::<ReflectedNode>();
+
+    assert_eq!(info.parent, Some(StringName::from("Node")));
+
+    let heal = info.method("heal").expect("#[func] heal");
+    let args: Vec<_> = heal
+        .arguments
+        .iter()
+        .map(|arg| (arg.property_name.to_string(), arg.variant_type))
+        .collect();
+    assert_eq!(args, [("amount".to_string(), VariantType::Int)]);
+    assert_eq!(heal.return_type.variant_type, VariantType::Bool);
+
+    let hit = info.signal("hit").expect("#[signal] hit");
+    assert_eq!(hit.arguments.len(), 1);
+
+    let speed = info.property("speed").expect("#[var] speed");
+    assert_eq!(speed.variant_type, VariantType::Float);
+
+    assert!(info
+        .constants
+        .iter()
+        .any(|constant| constant.name == "MAX_SPEED".into() && constant.value == 10));
+}
@@ -0,0 +1,65 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use godot::engine::ClassDb;
+use godot::obj::RustHandle;
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[derive(Debug, PartialEq)]
+struct Score {
+    points: i64,
+}
+
+struct DropFlag(Arc<AtomicBool>);
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+#[itest]
+fn rust_handle_class_registered() {
+    let db = ClassDb::singleton();
+
+    assert!(db.class_exists("RustHandle".into()));
+    assert!(!db.can_instantiate("RustHandle".into()));
+}
+
+#[itest]
+fn rust_handle_variant_roundtrip() {
+    let mut handle = RustHandle::new(Score { points: 10 });
+    handle.bind_mut().points += 5;
+
+    let variant = handle.to_variant();
+    assert_eq!(variant.get_type(), VariantType::Object);
+
+    let recovered = RustHandle::<Score>::try_from_variant(&variant).expect("same value type");
+    assert_eq!(*recovered.bind(), Score { points: 15 });
+    assert_eq!(recovered, handle);
+
+    // Wrong value type, and objects that are no handles, are rejected.
+    assert!(RustHandle::<String>::try_from_variant(&variant).is_err());
+    assert!(RustHandle::<Score>::try_from_object(RefCounted::new()).is_none());
+}
+
+#[itest]
+fn rust_handle_dropped_with_last_reference() {
+    let dropped = Arc::new(AtomicBool::new(false));
+
+    let handle = RustHandle::new(DropFlag(Arc::clone(&dropped)));
+    let object = handle.to_object();
+    drop(handle);
+    assert!(!dropped.load(Ordering::SeqCst));
+
+    drop(object);
+    assert!(dropped.load(Ordering::SeqCst));
+}
@@ -0,0 +1,76 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+use godot::engine::ClassDb;
+use godot::init::DynamicClassBuilder;
+use godot::prelude::*;
+
+/// Called by the integration tests' `on_level_init()`.
+pub(crate) fn register_dynamic_classes() {
+    DynamicClassBuilder::new::<RefCounted>("DynamicCounter")
+        .property("count", 0.to_variant())
+        .method("add", &["amount"], |instance, args| {
+            let amount = args[0].try_to::<i64>().map_err(|_| ())?;
+            let count = instance.get("count").unwrap().to::<i64>() + amount;
+
+            instance.set("count", count.to_variant());
+            instance
+                .base_mut()
+                .emit_signal("changed".into(), &[count.to_variant()]);
+
+            Ok(count.to_variant())
+        })
+        .signal("changed", &[("count", VariantType::Int)])
+        .register();
+}
+
+fn new_counter() -> Gd<RefCounted> {
+    ClassDb::singleton()
+        .instantiate("DynamicCounter".into())
+        .to::<Gd<RefCounted>>()
+}
+
+#[itest]
+fn dynamic_class_in_classdb() {
+    let db = ClassDb::singleton();
+    let class_name = StringName::from("DynamicCounter");
+
+    assert!(db.class_exists(class_name.clone()));
+    assert!(db.can_instantiate(class_name.clone()));
+    assert_eq!(
+        db.get_parent_class(class_name.clone()),
+        StringName::from("RefCounted")
+    );
+    assert!(db.class_has_method(class_name.clone(), "add".into()));
+    assert!(db.class_has_method(class_name.clone(), "get_count".into()));
+    assert!(db.class_has_signal(class_name, "changed".into()));
+}
+
+#[itest]
+fn dynamic_class_methods() {
+    let mut counter = new_counter();
+
+    let result = counter.call("add".into(), &[3.to_variant()]);
+    assert_eq!(result, 3.to_variant());
+
+    let result = counter.call("add".into(), &[4.to_variant()]);
+    assert_eq!(result, 7.to_variant());
+}
+
+#[itest]
+fn dynamic_class_properties() {
+    let mut counter = new_counter();
+    assert_eq!(counter.get("count".into()), 0.to_variant());
+
+    counter.set("count".into(), 10.to_variant());
+    assert_eq!(counter.get("count".into()), 10.to_variant());
+    assert_eq!(counter.call("get_count".into(), &[]), 10.to_variant());
+
+    // Instances have separate state.
+    let other = new_counter();
+    assert_eq!(other.get("count".into()), 0.to_variant());
+}
@@ -0,0 +1,51 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::meta::ClassName;
+use godot::engine::ClassDb;
+use godot::init::{gdextension, registered_classes, ExtensionLibrary};
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+/// Second entry point of the itest library, loaded by Godot as the separate extension `itest_secondary.gdextension`.
+struct SecondaryEntryPoint;
+
+#[gdextension(entry_symbol = "itest_secondary_init")]
+unsafe impl ExtensionLibrary for SecondaryEntryPoint {
+    fn registers_class(class_name: ClassName) -> bool {
+        is_secondary_class(class_name)
+    }
+}
+
+/// Class registered only by [`SecondaryEntryPoint`]; the main entry point skips it.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct SecondaryEntryClass {
+    #[var]
+    value: i32,
+}
+
+/// Whether `class_name` belongs to the secondary entry point instead of the main one.
+pub(crate) fn is_secondary_class(class_name: ClassName) -> bool {
+    class_name == SecondaryEntryClass::class_name()
+}
+
+#[itest]
+fn entry_point_secondary_registers_class() {
+    let db = ClassDb::singleton();
+    let class_name = SecondaryEntryClass::class_name();
+
+    // The main entry point rejects the class, so it only exists if the secondary one registered it.
+    assert!(db.class_exists(class_name.to_string_name()));
+    assert!(registered_classes()
+        .iter()
+        .any(|class| class.class_name == class_name));
+
+    let mut object = db.instantiate(class_name.to_string_name());
+    object.set("value".into(), 7.to_variant());
+    assert_eq!(object.get("value".into()), 7.to_variant());
+}
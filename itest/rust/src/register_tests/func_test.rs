@@ -7,9 +7,11 @@
 // Needed for Clippy to accept #[cfg(all())]
 #![allow(clippy::non_minimal_cfg)]
 
-use crate::framework::itest;
+use crate::framework::{itest, suppress_godot_print};
+use godot::builtin::meta::Clamped;
 use godot::engine::ClassDb;
 use godot::prelude::*;
+use std::num::Wrapping;
 
 #[derive(GodotClass)]
 #[class(init, base=RefCounted)]
@@ -303,3 +305,188 @@ fn cfg_removes_or_keeps_signals() {
     ));
     assert!(!class_has_signal::<GdSelfReference>("cfg_removes_signal"));
 }
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct NarrowParams {
+    value: i64,
+}
+
+#[godot_api]
+impl NarrowParams {
+    #[func]
+    fn take_u8(&mut self, value: u8) {
+        self.value = value.into();
+    }
+
+    #[func]
+    fn take_clamped_u8(&mut self, value: Clamped<u8>) {
+        self.value = value.0.into();
+    }
+
+    #[func]
+    fn take_wrapping_i8(&mut self, value: Wrapping<i8>) {
+        self.value = value.0.into();
+    }
+}
+
+#[itest]
+fn func_narrow_params_checked() {
+    let object = Gd::<NarrowParams>::new_default();
+    let mut base = object.clone().upcast::<RefCounted>();
+
+    base.call("take_u8".into(), &[200.to_variant()]);
+    assert_eq!(object.bind().value, 200);
+
+    suppress_godot_print(|| {
+        let result = base.call("take_u8".into(), &[300.to_variant()]);
+        assert_eq!(result, Variant::nil());
+    });
+    assert_eq!(object.bind().value, 200, "out-of-range call must fail");
+}
+
+#[itest]
+fn func_narrow_params_unchecked() {
+    let object = Gd::<NarrowParams>::new_default();
+    let mut base = object.clone().upcast::<RefCounted>();
+
+    base.call("take_clamped_u8".into(), &[300.to_variant()]);
+    assert_eq!(object.bind().value, 255);
+
+    base.call("take_clamped_u8".into(), &[(-5).to_variant()]);
+    assert_eq!(object.bind().value, 0);
+
+    base.call("take_wrapping_i8".into(), &[130.to_variant()]);
+    assert_eq!(object.bind().value, -126);
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct FuncDefaults;
+
+#[godot_api]
+impl FuncDefaults {
+    #[func]
+    fn describe(
+        &self,
+        value: i64,
+        #[opt(default = 2)] factor: i64,
+        #[opt(default = GodotString::from("x"))] label: GodotString,
+    ) -> GodotString {
+        format!("{label}{}", value * factor).into()
+    }
+
+    #[func]
+    fn count_names(names: Array<GodotString>) -> i64 {
+        names.len() as i64
+    }
+
+    #[func]
+    fn advance(&self, value: i64, #[opt(default = Self::DEFAULT_STEP)] step: i64) -> i64 {
+        value + step
+    }
+}
+
+impl FuncDefaults {
+    const DEFAULT_STEP: i64 = 5;
+}
+
+/// Returns the method list entry of `method` in the class `T`, with keys `name`, `args`, `default_args`, `return` etc.
+fn class_method_info<T: GodotClass>(method: &str) -> Dictionary {
+    ClassDb::singleton()
+        .class_get_method_list_ex(T::class_name().to_string_name())
+        .no_inheritance(true)
+        .done()
+        .iter_shared()
+        .find(|info| info.get_or_nil("name").to::<String>() == method)
+        .unwrap_or_else(|| panic!("method `{method}` not registered"))
+}
+
+#[itest]
+fn func_default_args_filled_in() {
+    let mut object = Gd::<FuncDefaults>::new_default().upcast::<RefCounted>();
+
+    let result = object.call("describe".into(), &[4.to_variant()]);
+    assert_eq!(result, "x8".to_variant());
+
+    let result = object.call("describe".into(), &[4.to_variant(), 3.to_variant()]);
+    assert_eq!(result, "x12".to_variant());
+
+    let result = object.call(
+        "describe".into(),
+        &[4.to_variant(), 3.to_variant(), "y".to_variant()],
+    );
+    assert_eq!(result, "y12".to_variant());
+
+    let result = object.call("advance".into(), &[1.to_variant()]);
+    assert_eq!(result, 6.to_variant(), "default may refer to `Self`");
+
+    suppress_godot_print(|| {
+        let result = object.call("describe".into(), &[]);
+        assert_eq!(
+            result,
+            Variant::nil(),
+            "required argument must not be omitted"
+        );
+    });
+}
+
+#[itest]
+fn func_signature_metadata() {
+    let info = class_method_info::<FuncDefaults>("describe");
+
+    // Argument lists are untyped arrays of dictionaries.
+    let args: Vec<Dictionary> = info
+        .get_or_nil("args")
+        .to::<VariantArray>()
+        .iter_shared()
+        .map(|arg| arg.to::<Dictionary>())
+        .collect();
+
+    let names: Vec<String> = args
+        .iter()
+        .map(|arg| arg.get_or_nil("name").to::<String>())
+        .collect();
+    assert_eq!(names, ["value", "factor", "label"]);
+
+    let types: Vec<i64> = args
+        .iter()
+        .map(|arg| arg.get_or_nil("type").to::<i64>())
+        .collect();
+    assert_eq!(
+        types,
+        [
+            VariantType::Int as i64,
+            VariantType::Int as i64,
+            VariantType::String as i64
+        ]
+    );
+
+    let defaults = info.get_or_nil("default_args").to::<VariantArray>();
+    assert_eq!(defaults, varray![2, "x"]);
+
+    let ret = info.get_or_nil("return").to::<Dictionary>();
+    assert_eq!(
+        ret.get_or_nil("type").to::<i64>(),
+        VariantType::String as i64
+    );
+}
+
+#[itest]
+fn func_typed_array_param_metadata() {
+    use godot::engine::global::PropertyHint;
+    use godot::obj::EngineEnum;
+
+    let info = class_method_info::<FuncDefaults>("count_names");
+    let arg = info
+        .get_or_nil("args")
+        .to::<VariantArray>()
+        .get(0)
+        .to::<Dictionary>();
+
+    assert_eq!(
+        arg.get_or_nil("hint").to::<i32>(),
+        PropertyHint::PROPERTY_HINT_ARRAY_TYPE.ord()
+    );
+    assert_eq!(arg.get_or_nil("hint_string"), "String".to_variant());
+}
@@ -0,0 +1,67 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::marker::PhantomData;
+
+use crate::framework::itest;
+use godot::engine::ClassDb;
+use godot::prelude::*;
+
+trait Flavor: 'static {
+    const NAME: &'static str;
+}
+
+struct Sweet;
+impl Flavor for Sweet {
+    const NAME: &'static str = "sweet";
+}
+
+struct Sour;
+impl Flavor for Sour {
+    const NAME: &'static str = "sour";
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted, instances = (SweetCandy, SourCandy))]
+struct Candy<F: Flavor> {
+    #[var]
+    weight: i32,
+    _flavor: PhantomData<F>,
+}
+
+type SweetCandy = Candy<Sweet>;
+type SourCandy = Candy<Sour>;
+
+#[godot_api(instances = (SweetCandy, SourCandy))]
+impl<F: Flavor> Candy<F> {
+    #[func]
+    fn flavor(&self) -> GodotString {
+        F::NAME.into()
+    }
+}
+
+#[itest]
+fn generic_class_instances_registered() {
+    assert_eq!(SweetCandy::class_name().to_string(), "SweetCandy");
+    assert_eq!(SourCandy::class_name().to_string(), "SourCandy");
+
+    let db = ClassDb::singleton();
+    assert!(db.class_exists("SweetCandy".into()));
+    assert!(db.class_exists("SourCandy".into()));
+    assert!(!db.class_exists("Candy".into()));
+}
+
+#[itest]
+fn generic_class_methods_monomorphized() {
+    let mut sweet = Gd::<SweetCandy>::new_default().upcast::<RefCounted>();
+    let mut sour = Gd::<SourCandy>::new_default().upcast::<RefCounted>();
+
+    assert_eq!(sweet.call("flavor".into(), &[]), "sweet".to_variant());
+    assert_eq!(sour.call("flavor".into(), &[]), "sour".to_variant());
+
+    sweet.set("weight".into(), 12.to_variant());
+    assert_eq!(sweet.get("weight".into()), 12.to_variant());
+}
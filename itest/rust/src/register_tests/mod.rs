@@ -6,7 +6,21 @@
 
 mod constant_test;
 mod derive_variant_test;
+mod dynamic_class_test;
+mod entry_point_test;
 mod func_test;
 mod gdscript_ffi_test;
+mod generic_class_test;
+mod no_init_test;
 mod option_ffi_test;
+mod plugin_abi_test;
+mod property_notify_test;
+mod property_validate_test;
+mod registration_test;
+mod rename_all_test;
 mod var_test;
+
+pub(crate) use dynamic_class_test::register_dynamic_classes;
+pub(crate) use entry_point_test::is_secondary_class;
+pub(crate) use plugin_abi_test::register_plugin_classes;
+pub(crate) use registration_test::register_manual_classes;
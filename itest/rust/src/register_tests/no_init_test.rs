@@ -0,0 +1,39 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+use godot::engine::ClassDb;
+use godot::prelude::*;
+
+#[derive(GodotClass)]
+#[class(no_init, base=RefCounted)]
+struct NoInitHandle {
+    id: i64,
+}
+
+#[godot_api]
+impl NoInitHandle {
+    #[func]
+    fn id(&self) -> i64 {
+        self.id
+    }
+}
+
+#[itest]
+fn no_init_not_instantiable_from_godot() {
+    let db = ClassDb::singleton();
+    let class_name = StringName::from("NoInitHandle");
+
+    assert!(db.class_exists(class_name.clone()));
+    assert!(!db.can_instantiate(class_name));
+}
+
+#[itest]
+fn no_init_constructible_from_rust() {
+    let handle = Gd::new(NoInitHandle { id: 7 });
+
+    assert_eq!(handle.bind().id(), 7);
+}
@@ -0,0 +1,203 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::ffi::{c_char, c_void};
+
+use crate::framework::itest;
+use godot::engine::ClassDb;
+use godot::init::plugin_abi::{self, PluginApi, PluginInstance, PluginVariant};
+use godot::prelude::*;
+
+// Written like a mod would be in C: only the function table and raw pointers, no godot-rust types.
+
+static mut API: *const PluginApi = std::ptr::null();
+
+unsafe fn api() -> &'static PluginApi {
+    &*API
+}
+
+unsafe fn read_string(api: &PluginApi, variant: *const PluginVariant) -> Option<String> {
+    let mut buffer = [0u8; 64];
+    let mut len = 0;
+    if !(api.variant_to_string)(
+        variant,
+        buffer.as_mut_ptr() as *mut c_char,
+        buffer.len(),
+        &mut len,
+    ) {
+        return None;
+    }
+
+    String::from_utf8(buffer[..len].to_vec()).ok()
+}
+
+/// `greet(name)`: returns `"<greeting>, <name>!"` and emits `greeted(name)`.
+unsafe extern "C" fn greet(
+    _userdata: *mut c_void,
+    instance: *mut PluginInstance,
+    args: *const *const PluginVariant,
+    arg_count: usize,
+    ret: *mut PluginVariant,
+) -> bool {
+    let api = api();
+    let args = std::slice::from_raw_parts(args, arg_count);
+
+    let Some(name) = read_string(api, args[0]) else {
+        return false;
+    };
+
+    let greeting = (api.variant_new)();
+    let ok = (api.instance_get)(instance, b"greeting\0".as_ptr() as *const c_char, greeting);
+    let greeting_text = read_string(api, greeting);
+    (api.variant_free)(greeting);
+
+    let (true, Some(greeting_text)) = (ok, greeting_text) else {
+        return false;
+    };
+
+    let text = format!("{greeting_text}, {name}!");
+    (api.variant_set_string)(ret, text.as_ptr() as *const c_char, text.len());
+
+    (api.instance_emit_signal)(
+        instance,
+        b"greeted\0".as_ptr() as *const c_char,
+        args.as_ptr(),
+        1,
+    )
+}
+
+/// `twice(value)`: returns `2 * value`, or fails for non-integers.
+unsafe extern "C" fn twice(
+    userdata: *mut c_void,
+    _instance: *mut PluginInstance,
+    args: *const *const PluginVariant,
+    _arg_count: usize,
+    ret: *mut PluginVariant,
+) -> bool {
+    let api = api();
+    let factor = *(userdata as *const i64);
+
+    let mut value = 0;
+    if !(api.variant_to_int)(*args, &mut value) {
+        return false;
+    }
+
+    (api.variant_set_int)(ret, value * factor);
+    true
+}
+
+static FACTOR: i64 = 2;
+
+unsafe extern "C" fn test_plugin_init(api_ptr: *const PluginApi) -> bool {
+    API = api_ptr;
+    let api = api();
+    if api.version < 1 {
+        return false;
+    }
+
+    let class = (api.class_begin)(
+        b"PluginGreeter\0".as_ptr() as *const c_char,
+        b"RefCounted\0".as_ptr() as *const c_char,
+    );
+    if class.is_null() {
+        return false;
+    }
+
+    let greeting = (api.variant_new)();
+    let hello = "Hello";
+    (api.variant_set_string)(greeting, hello.as_ptr() as *const c_char, hello.len());
+    let property_ok =
+        (api.class_add_property)(class, b"greeting\0".as_ptr() as *const c_char, greeting);
+    (api.variant_free)(greeting);
+
+    let params = [b"name\0".as_ptr() as *const c_char];
+    let value_params = [b"value\0".as_ptr() as *const c_char];
+
+    let ok = property_ok
+        && (api.class_add_method)(
+            class,
+            b"greet\0".as_ptr() as *const c_char,
+            params.as_ptr(),
+            params.len(),
+            greet,
+            std::ptr::null_mut(),
+        )
+        && (api.class_add_method)(
+            class,
+            b"twice\0".as_ptr() as *const c_char,
+            value_params.as_ptr(),
+            value_params.len(),
+            twice,
+            &FACTOR as *const i64 as *mut c_void,
+        )
+        && (api.class_add_signal)(
+            class,
+            b"greeted\0".as_ptr() as *const c_char,
+            params.as_ptr(),
+            params.len(),
+        );
+
+    if !ok {
+        (api.class_discard)(class);
+        return false;
+    }
+
+    (api.class_register)(class)
+}
+
+/// Called by the integration tests' `on_level_init()`.
+pub(crate) fn register_plugin_classes() {
+    // SAFETY: the test plugin follows the ABI conventions.
+    let ok = unsafe { plugin_abi::init_plugin(test_plugin_init) };
+    assert!(ok, "test plugin failed to initialize");
+
+    // Invalid base classes are rejected instead of registered.
+    let api = plugin_abi::plugin_api();
+    let class = unsafe {
+        (api.class_begin)(
+            b"PluginOrphan\0".as_ptr() as *const c_char,
+            b"NoSuchClass\0".as_ptr() as *const c_char,
+        )
+    };
+    assert!(class.is_null());
+}
+
+#[itest]
+fn plugin_abi_version() {
+    let api = plugin_abi::plugin_api();
+
+    assert_eq!(api.version, plugin_abi::PLUGIN_API_VERSION);
+    assert_eq!(api.size, std::mem::size_of::<PluginApi>());
+}
+
+#[itest]
+fn plugin_abi_class_registered() {
+    let db = ClassDb::singleton();
+    let class_name = StringName::from("PluginGreeter");
+
+    assert!(db.class_exists(class_name.clone()));
+    assert!(db.class_has_method(class_name.clone(), "greet".into()));
+    assert!(db.class_has_method(class_name.clone(), "get_greeting".into()));
+    assert!(db.class_has_signal(class_name, "greeted".into()));
+    assert!(!db.class_exists("PluginOrphan".into()));
+}
+
+#[itest]
+fn plugin_abi_methods() {
+    let mut greeter = ClassDb::singleton()
+        .instantiate("PluginGreeter".into())
+        .to::<Gd<RefCounted>>();
+
+    let result = greeter.call("greet".into(), &["Godot".to_variant()]);
+    assert_eq!(result, "Hello, Godot!".to_variant());
+
+    greeter.set("greeting".into(), "Welcome".to_variant());
+    let result = greeter.call("greet".into(), &["modder".to_variant()]);
+    assert_eq!(result, "Welcome, modder!".to_variant());
+
+    let result = greeter.call("twice".into(), &[21.to_variant()]);
+    assert_eq!(result, 42.to_variant());
+}
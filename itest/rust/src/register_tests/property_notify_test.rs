@@ -0,0 +1,112 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+use godot::engine::ClassDb;
+use godot::prelude::*;
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct NotifyingStats {
+    #[export(notify)]
+    health: i64,
+
+    #[export(notify, range = (0.0, 1.0))]
+    armor: f64,
+
+    #[export(notify = name_changed)]
+    name: GodotString,
+
+    #[base]
+    base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl NotifyingStats {}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct NotifyRecorder {
+    changes: Vec<(String, Variant, Variant)>,
+}
+
+#[godot_api]
+impl NotifyRecorder {
+    #[func]
+    fn on_property_changed(
+        &mut self,
+        property: StringName,
+        old_value: Variant,
+        new_value: Variant,
+    ) {
+        self.changes
+            .push((property.to_string(), old_value, new_value));
+    }
+
+    #[func]
+    fn on_name_changed(&mut self, old_value: GodotString, new_value: GodotString) {
+        self.changes.push((
+            "name".to_string(),
+            old_value.to_variant(),
+            new_value.to_variant(),
+        ));
+    }
+}
+
+fn connect_recorder(stats: &mut Gd<NotifyingStats>) -> Gd<NotifyRecorder> {
+    let recorder = Gd::<NotifyRecorder>::new_default();
+
+    stats.connect(
+        "property_changed".into(),
+        recorder.callable("on_property_changed"),
+    );
+    stats.connect("name_changed".into(), recorder.callable("on_name_changed"));
+
+    recorder
+}
+
+#[itest]
+fn property_notify_signals_registered() {
+    let db = ClassDb::singleton();
+    let class_name = NotifyingStats::class_name().to_string_name();
+
+    assert!(db.class_has_signal(class_name.clone(), "property_changed".into()));
+    assert!(db.class_has_signal(class_name, "name_changed".into()));
+}
+
+#[itest]
+fn property_notify_emits_old_and_new_value() {
+    let mut stats = Gd::<NotifyingStats>::new_default();
+    let recorder = connect_recorder(&mut stats);
+
+    stats.bind_mut().set_health(10);
+    stats.set("armor".into(), 0.5.to_variant());
+    stats.bind_mut().set_name("Ada".into());
+
+    assert_eq!(
+        recorder.bind().changes,
+        vec![
+            ("health".to_string(), 0.to_variant(), 10.to_variant()),
+            ("armor".to_string(), 0.0.to_variant(), 0.5.to_variant()),
+            (
+                "name".to_string(),
+                GodotString::new().to_variant(),
+                GodotString::from("Ada").to_variant(),
+            ),
+        ]
+    );
+}
+
+#[itest]
+fn property_notify_skips_unchanged_value() {
+    let mut stats = Gd::<NotifyingStats>::new_default();
+    let recorder = connect_recorder(&mut stats);
+
+    stats.bind_mut().set_health(3);
+    stats.bind_mut().set_health(3);
+
+    assert_eq!(recorder.bind().changes.len(), 1);
+}
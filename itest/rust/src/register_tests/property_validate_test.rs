@@ -0,0 +1,94 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+use godot::prelude::*;
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct ValidatedStats {
+    #[export(validate = clamp_health, range = (0.0, 100.0))]
+    health: i64,
+
+    #[export(validate = reject_empty, notify)]
+    name: GodotString,
+
+    #[export(set = set_level_doubled)]
+    level: i64,
+
+    #[base]
+    base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl ValidatedStats {
+    #[func]
+    fn set_level_doubled(&mut self, level: i64) {
+        self.level = level * 2;
+    }
+}
+
+impl ValidatedStats {
+    fn clamp_health(&self, health: i64) -> Option<i64> {
+        Some(health.clamp(0, 100))
+    }
+
+    fn reject_empty(&self, name: GodotString) -> Option<GodotString> {
+        (!name.is_empty()).then_some(name)
+    }
+}
+
+#[itest]
+fn property_validate_clamps_from_godot() {
+    let mut stats = Gd::<ValidatedStats>::new_default();
+
+    stats.set("health".into(), 250.to_variant());
+    assert_eq!(stats.get("health".into()), 100.to_variant());
+
+    stats.bind_mut().set_health(-5);
+    assert_eq!(stats.bind().health, 0);
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct ChangeCounter {
+    count: usize,
+}
+
+#[godot_api]
+impl ChangeCounter {
+    #[func]
+    fn on_property_changed(&mut self, _property: StringName, _old: Variant, _new: Variant) {
+        self.count += 1;
+    }
+}
+
+#[itest]
+fn property_validate_rejects_without_notify() {
+    let mut stats = Gd::<ValidatedStats>::new_default();
+    stats.set("name".into(), "Ada".to_variant());
+
+    let counter = Gd::<ChangeCounter>::new_default();
+    stats.connect(
+        "property_changed".into(),
+        counter.callable("on_property_changed"),
+    );
+
+    stats.set("name".into(), GodotString::new().to_variant());
+
+    assert_eq!(stats.bind().name, GodotString::from("Ada"));
+    assert_eq!(counter.bind().count, 0);
+}
+
+#[itest]
+fn property_export_custom_setter() {
+    let mut stats = Gd::<ValidatedStats>::new_default();
+
+    stats.set("level".into(), 3.to_variant());
+
+    // Custom setter, generated getter.
+    assert_eq!(stats.get("level".into()), 6.to_variant());
+}
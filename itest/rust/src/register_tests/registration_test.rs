@@ -0,0 +1,92 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+use godot::engine::ClassDb;
+use godot::init::registered_classes;
+use godot::prelude::*;
+
+#[derive(GodotClass)]
+#[class(init, base=Node, auto_register = false)]
+struct ManuallyRegistered {}
+
+// Any constant expression works, typically `cfg!(feature = "...")`.
+const REGISTER_OPTIONAL: bool = false;
+
+#[derive(GodotClass)]
+#[class(init, base=Node, auto_register = REGISTER_OPTIONAL)]
+struct NeverRegistered {}
+
+fn is_in_editor() -> bool {
+    godot::engine::Engine::singleton().is_editor_hint()
+}
+
+fn is_outside_editor() -> bool {
+    !is_in_editor()
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node, register_if = is_in_editor)]
+struct EditorOnlyTool {}
+
+#[derive(GodotClass)]
+#[class(init, base=Node, register_if = is_outside_editor)]
+struct GameOnlyTool {}
+
+/// Called by the integration tests' `on_level_init()`.
+pub(crate) fn register_manual_classes() {
+    godot::init::register_user_class::<ManuallyRegistered>();
+
+    // Second registration is ignored.
+    godot::init::register_user_class::<ManuallyRegistered>();
+}
+
+#[itest]
+fn registration_manual() {
+    let db = ClassDb::singleton();
+
+    assert!(db.class_exists("ManuallyRegistered".into()));
+    assert!(db.can_instantiate("ManuallyRegistered".into()));
+}
+
+#[itest]
+fn registration_excluded() {
+    let db = ClassDb::singleton();
+
+    assert!(!db.class_exists("NeverRegistered".into()));
+}
+
+#[itest]
+fn registration_conditional() {
+    let db = ClassDb::singleton();
+
+    // Tests run outside the editor.
+    assert!(!db.class_exists("EditorOnlyTool".into()));
+    assert!(db.class_exists("GameOnlyTool".into()));
+}
+
+#[itest]
+fn registration_query() {
+    let classes = registered_classes();
+    let find = |name: &str| {
+        classes
+            .iter()
+            .find(|class| class.class_name.to_string() == name)
+    };
+
+    let manual = find("ManuallyRegistered").expect("manually registered class listed");
+    assert_eq!(manual.crate_name, "itest");
+    assert_eq!(manual.init_level, InitLevel::Scene);
+
+    assert!(find("NoInitHandle").is_some());
+    assert!(find("NeverRegistered").is_none());
+
+    let count = classes
+        .iter()
+        .filter(|class| class.class_name.to_string() == "ManuallyRegistered")
+        .count();
+    assert_eq!(count, 1);
+}
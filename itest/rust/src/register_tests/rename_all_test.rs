@@ -0,0 +1,71 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+use godot::engine::ClassDb;
+use godot::prelude::*;
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted, rename_all = "camelCase")]
+struct CamelCaseApi {
+    #[var]
+    max_items: i32,
+
+    #[var(rename = legacy_value)]
+    old_value: i32,
+}
+
+#[godot_api]
+impl CamelCaseApi {
+    #[signal]
+    fn item_added();
+
+    #[signal(rename = on_cleared)]
+    fn cleared();
+
+    #[func]
+    fn add_item(&mut self) {}
+
+    #[func(rename = remove_all)]
+    fn clear(&mut self) {}
+}
+
+fn class_name() -> StringName {
+    CamelCaseApi::class_name().to_string_name()
+}
+
+#[itest]
+fn rename_all_methods() {
+    let db = ClassDb::singleton();
+
+    assert!(db.class_has_method(class_name(), "addItem".into()));
+    assert!(!db.class_has_method(class_name(), "add_item".into()));
+    assert!(db.class_has_method(class_name(), "remove_all".into()));
+
+    // Generated accessors follow the convention as well.
+    assert!(db.class_has_method(class_name(), "getMaxItems".into()));
+    assert!(db.class_has_method(class_name(), "setMaxItems".into()));
+}
+
+#[itest]
+fn rename_all_properties() {
+    let mut obj = Gd::<CamelCaseApi>::new_default().upcast::<RefCounted>();
+
+    obj.set("maxItems".into(), 5.to_variant());
+    assert_eq!(obj.get("maxItems".into()), 5.to_variant());
+    assert_eq!(obj.get("max_items".into()), Variant::nil());
+
+    obj.set("legacy_value".into(), 3.to_variant());
+    assert_eq!(obj.get("legacy_value".into()), 3.to_variant());
+}
+
+#[itest]
+fn rename_all_signals() {
+    let db = ClassDb::singleton();
+
+    assert!(db.class_has_signal(class_name(), "itemAdded".into()));
+    assert!(db.class_has_signal(class_name(), "on_cleared".into()));
+}